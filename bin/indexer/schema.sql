@@ -0,0 +1,71 @@
+-- Tables written by the indexer. Created on startup if they don't exist.
+--
+-- Pubkeys are base58 strings, timestamps unix seconds. Prices and quantities
+-- ending in _lots are in the market's lots, amounts ending in _native in native
+-- token units. u64 client order ids are stored with the same bits as BIGINT.
+
+-- One row per FillLog, i.e. per maker order matched by a taker
+CREATE TABLE IF NOT EXISTS fills (
+    market TEXT NOT NULL,
+    event_seq_num BIGINT NOT NULL,
+    signature TEXT NOT NULL,
+    slot BIGINT NOT NULL,
+    timestamp BIGINT NOT NULL,
+    -- 0 when the taker bought, 1 when it sold
+    taker_side SMALLINT NOT NULL,
+    -- open orders account of the maker
+    maker TEXT NOT NULL,
+    maker_client_order_id BIGINT NOT NULL,
+    -- true if the maker order was completely filled
+    maker_out BOOLEAN NOT NULL,
+    maker_fee_native BIGINT NOT NULL,
+    -- open orders account of the taker, or its wallet for place_take_order
+    taker TEXT NOT NULL,
+    taker_client_order_id BIGINT NOT NULL,
+    taker_fee_native BIGINT NOT NULL,
+    price_lots BIGINT NOT NULL,
+    quantity_lots BIGINT NOT NULL,
+    base_native BIGINT NOT NULL,
+    quote_native BIGINT NOT NULL,
+    PRIMARY KEY (market, event_seq_num)
+);
+CREATE INDEX IF NOT EXISTS fills_market_timestamp ON fills (market, timestamp);
+CREATE INDEX IF NOT EXISTS fills_maker ON fills (maker);
+CREATE INDEX IF NOT EXISTS fills_taker ON fills (taker);
+
+-- One row per OutLog, i.e. per order removed from the book without a fill:
+-- expired, or dropped to make space on a full book
+CREATE TABLE IF NOT EXISTS outs (
+    market TEXT NOT NULL,
+    event_seq_num BIGINT NOT NULL,
+    signature TEXT NOT NULL,
+    slot BIGINT NOT NULL,
+    timestamp BIGINT NOT NULL,
+    -- side of the removed order, 0 bid, 1 ask
+    side SMALLINT NOT NULL,
+    owner TEXT NOT NULL,
+    owner_slot SMALLINT NOT NULL,
+    quantity_lots BIGINT NOT NULL,
+    PRIMARY KEY (market, event_seq_num)
+);
+
+-- Resting orders of a market, sampled every --open-interest-interval-secs
+CREATE TABLE IF NOT EXISTS open_interest (
+    market TEXT NOT NULL,
+    timestamp BIGINT NOT NULL,
+    bid_orders BIGINT NOT NULL,
+    bids_base_lots BIGINT NOT NULL,
+    ask_orders BIGINT NOT NULL,
+    asks_base_lots BIGINT NOT NULL,
+    PRIMARY KEY (market, timestamp)
+);
+
+-- Cumulative volume per market and account, updated with every new fill
+CREATE TABLE IF NOT EXISTS account_volume (
+    market TEXT NOT NULL,
+    account TEXT NOT NULL,
+    maker_volume_quote_native BIGINT NOT NULL,
+    taker_volume_quote_native BIGINT NOT NULL,
+    fills BIGINT NOT NULL,
+    PRIMARY KEY (market, account)
+);
@@ -0,0 +1,169 @@
+use log::*;
+use openbook_v2::logs::{FillLog, OutLog};
+use openbook_v2_client::events::OpenBookEvent;
+use solana_sdk::pubkey::Pubkey;
+use tokio_postgres::{NoTls, Transaction};
+
+pub struct Database {
+    client: tokio_postgres::Client,
+}
+
+impl Database {
+    pub async fn connect(url: &str) -> anyhow::Result<Self> {
+        let (client, connection) = tokio_postgres::connect(url, NoTls).await?;
+        tokio::spawn(async move {
+            if let Err(e) = connection.await {
+                error!("postgres connection error: {}", e);
+            }
+        });
+        Ok(Self { client })
+    }
+
+    pub async fn create_schema(&self) -> anyhow::Result<()> {
+        self.client
+            .batch_execute(include_str!("../schema.sql"))
+            .await?;
+        Ok(())
+    }
+
+    /// Store the fills and outs of a transaction, events that were already stored
+    /// are skipped
+    pub async fn insert_events(
+        &mut self,
+        signature: &str,
+        slot: u64,
+        events: &[OpenBookEvent],
+    ) -> anyhow::Result<()> {
+        let tx = self.client.transaction().await?;
+        for event in events {
+            match event {
+                OpenBookEvent::Fill(fill) => insert_fill(&tx, signature, slot, fill).await?,
+                OpenBookEvent::Out(out) => insert_out(&tx, signature, slot, out).await?,
+                OpenBookEvent::Place(_) | OpenBookEvent::Cancel(_) => {}
+            }
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    pub async fn insert_open_interest(
+        &self,
+        market: Pubkey,
+        timestamp: u64,
+        bid_orders: u64,
+        bids_base_lots: i64,
+        ask_orders: u64,
+        asks_base_lots: i64,
+    ) -> anyhow::Result<()> {
+        self.client
+            .execute(
+                "INSERT INTO open_interest
+                    (market, timestamp, bid_orders, bids_base_lots, ask_orders, asks_base_lots)
+                VALUES ($1, $2, $3, $4, $5, $6)
+                ON CONFLICT DO NOTHING",
+                &[
+                    &market.to_string(),
+                    &(timestamp as i64),
+                    &(bid_orders as i64),
+                    &bids_base_lots,
+                    &(ask_orders as i64),
+                    &asks_base_lots,
+                ],
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+async fn insert_fill(
+    tx: &Transaction<'_>,
+    signature: &str,
+    slot: u64,
+    fill: &FillLog,
+) -> anyhow::Result<()> {
+    let market = fill.market.to_string();
+    let inserted = tx
+        .execute(
+            "INSERT INTO fills
+                (market, event_seq_num, signature, slot, timestamp, taker_side,
+                 maker, maker_client_order_id, maker_out, maker_fee_native,
+                 taker, taker_client_order_id, taker_fee_native,
+                 price_lots, quantity_lots, base_native, quote_native)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17)
+            ON CONFLICT DO NOTHING",
+            &[
+                &market,
+                &(fill.event_seq_num as i64),
+                &signature,
+                &(slot as i64),
+                &(fill.timestamp as i64),
+                &(fill.taker_side as i16),
+                &fill.maker.to_string(),
+                &(fill.maker_client_order_id as i64),
+                &fill.maker_out,
+                &(fill.maker_fee as i64),
+                &fill.taker.to_string(),
+                &(fill.taker_client_order_id as i64),
+                &(fill.taker_fee_ceil as i64),
+                &fill.price,
+                &fill.quantity,
+                &(fill.base_native as i64),
+                &(fill.quote_native as i64),
+            ],
+        )
+        .await?;
+    if inserted == 0 {
+        return Ok(());
+    }
+
+    let quote_native = fill.quote_native as i64;
+    let volumes = if fill.maker == fill.taker {
+        vec![(fill.maker, quote_native, quote_native)]
+    } else {
+        vec![(fill.maker, quote_native, 0), (fill.taker, 0, quote_native)]
+    };
+    for (account, maker_volume, taker_volume) in volumes {
+        tx.execute(
+            "INSERT INTO account_volume
+                (market, account, maker_volume_quote_native, taker_volume_quote_native, fills)
+            VALUES ($1, $2, $3, $4, 1)
+            ON CONFLICT (market, account) DO UPDATE SET
+                maker_volume_quote_native =
+                    account_volume.maker_volume_quote_native + EXCLUDED.maker_volume_quote_native,
+                taker_volume_quote_native =
+                    account_volume.taker_volume_quote_native + EXCLUDED.taker_volume_quote_native,
+                fills = account_volume.fills + 1",
+            &[&market, &account.to_string(), &maker_volume, &taker_volume],
+        )
+        .await?;
+    }
+    Ok(())
+}
+
+async fn insert_out(
+    tx: &Transaction<'_>,
+    signature: &str,
+    slot: u64,
+    out: &OutLog,
+) -> anyhow::Result<()> {
+    tx.execute(
+        "INSERT INTO outs
+            (market, event_seq_num, signature, slot, timestamp, side, owner, owner_slot,
+             quantity_lots)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        ON CONFLICT DO NOTHING",
+        &[
+            &out.market.to_string(),
+            &(out.event_seq_num as i64),
+            &signature,
+            &(slot as i64),
+            &(out.timestamp as i64),
+            &(out.side as i16),
+            &out.owner.to_string(),
+            &(out.owner_slot as i16),
+            &out.quantity,
+        ],
+    )
+    .await?;
+    Ok(())
+}
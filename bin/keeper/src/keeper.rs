@@ -0,0 +1,218 @@
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anchor_lang::{Id, InstructionData, ToAccountMetas};
+use anchor_spl::token::Token;
+use itertools::Itertools;
+use log::*;
+use openbook_v2::state::{
+    BinaryOutcome, BookSide, BookSideOrderTree, EventHeapSnapshot, EventHeapStorage, Market,
+};
+use openbook_v2_client::instructions::ConsumeEvents;
+use openbook_v2_client::{Client, TransactionBuilder};
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signature};
+use solana_sdk::signer::Signer;
+use spl_associated_token_account::get_associated_token_address;
+
+use crate::metrics::Metrics;
+
+// open orders accounts passed to one settle_expired_market instruction
+const SETTLE_EXPIRED_ACCOUNTS_PER_IX: usize = 8;
+
+pub struct Keeper {
+    pub client: Client,
+    /// Enables sweeping fees on markets with this collect_fee_admin
+    pub collect_fee_admin: Option<Arc<Keypair>>,
+    pub consume_events_limit: usize,
+    pub settle_expired_limit: u8,
+    pub min_fees_to_sweep: u64,
+    pub metrics: Metrics,
+}
+
+impl Keeper {
+    pub async fn run(&self, market_address: Pubkey, interval: Duration) {
+        let mut interval = tokio::time::interval(interval);
+        loop {
+            interval.tick().await;
+            if let Err(e) = self.crank(market_address).await {
+                self.metrics.increment("errors", market_address, 1);
+                warn!("market {}: {:?}", market_address, e);
+            }
+        }
+    }
+
+    async fn crank(&self, market_address: Pubkey) -> anyhow::Result<()> {
+        let market: Market = self.client.rpc_anchor_account(&market_address).await?;
+        self.consume_events(market_address, &market).await?;
+        self.settle_expired_market(market_address, &market).await?;
+        self.sweep_fees(market_address, &market).await?;
+        Ok(())
+    }
+
+    async fn send(
+        &self,
+        instructions: Vec<Instruction>,
+        signer: Option<&Keypair>,
+    ) -> anyhow::Result<Signature> {
+        let payer = &*self.client.fee_payer;
+        TransactionBuilder {
+            instructions,
+            address_lookup_tables: vec![],
+            payer: payer.pubkey(),
+            signers: signer.into_iter().chain([payer]).collect(),
+            config: self.client.transaction_builder_config,
+        }
+        .send_and_confirm(&self.client)
+        .await
+    }
+
+    /// Consume all events on the heap, `consume_events_limit` per transaction
+    async fn consume_events(&self, market_address: Pubkey, market: &Market) -> anyhow::Result<()> {
+        if let Some(admin) = Option::<Pubkey>::from(market.consume_events_admin) {
+            if admin != self.client.fee_payer.pubkey() {
+                debug!(
+                    "market {}: consume events admin is {}, skipping",
+                    market_address, admin
+                );
+                return Ok(());
+            }
+        }
+
+        let data = self
+            .client
+            .rpc_async()
+            .get_account_data(&market.event_heap)
+            .await?;
+        let heap = EventHeapSnapshot::from_bytes(&data)?;
+        self.metrics
+            .set("event_heap_len", market_address, heap.len() as u64);
+
+        // in the order the program consumes them, fills first
+        let events = heap
+            .iter_by_priority()
+            .map(|(event, _slot)| *event)
+            .collect_vec();
+        for chunk in events.chunks(self.consume_events_limit) {
+            let open_orders_accounts = chunk
+                .iter()
+                .filter_map(|event| event.open_orders_account())
+                .unique()
+                .collect_vec();
+            let ix = ConsumeEvents::new(market_address, market)
+                .open_orders_accounts(open_orders_accounts)
+                .build(chunk.len());
+            let signature = self.send(vec![ix], None).await?;
+            self.metrics
+                .increment("events_consumed", market_address, chunk.len() as u64);
+            info!(
+                "market {}: consumed {} events in {}",
+                market_address,
+                chunk.len(),
+                signature
+            );
+        }
+        Ok(())
+    }
+
+    /// Cancel the resting orders of an expired market, so that their owners can settle
+    async fn settle_expired_market(
+        &self,
+        market_address: Pubkey,
+        market: &Market,
+    ) -> anyhow::Result<()> {
+        let now_ts = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        if !market.is_expired(now_ts as i64) {
+            return Ok(());
+        }
+        if !market.is_settled() && market.binary_outcome() != BinaryOutcome::Disabled {
+            debug!("market {}: waiting for the binary outcome", market_address);
+            return Ok(());
+        }
+
+        let bids: BookSide = self.client.rpc_anchor_account(&market.bids).await?;
+        let asks: BookSide = self.client.rpc_anchor_account(&market.asks).await?;
+        let owners = [&bids, &asks]
+            .into_iter()
+            .flat_map(|bookside| {
+                [BookSideOrderTree::Fixed, BookSideOrderTree::OraclePegged]
+                    .into_iter()
+                    .flat_map(move |component| bookside.nodes.iter(bookside.root(component)))
+            })
+            .map(|(_handle, leaf)| leaf.owner)
+            .unique()
+            .collect_vec();
+
+        for chunk in owners.chunks(SETTLE_EXPIRED_ACCOUNTS_PER_IX) {
+            let mut accounts = openbook_v2::accounts::SettleExpiredMarket {
+                market: market_address,
+                bids: market.bids,
+                asks: market.asks,
+                oracle_a: market.oracle_a.into(),
+                oracle_b: market.oracle_b.into(),
+            }
+            .to_account_metas(None);
+            accounts.extend(chunk.iter().map(|&owner| AccountMeta::new(owner, false)));
+            let ix = Instruction {
+                program_id: openbook_v2::id(),
+                accounts,
+                data: openbook_v2::instruction::SettleExpiredMarket {
+                    limit: self.settle_expired_limit,
+                }
+                .data(),
+            };
+            let signature = self.send(vec![ix], None).await?;
+            self.metrics.increment(
+                "expired_accounts_settled",
+                market_address,
+                chunk.len() as u64,
+            );
+            info!(
+                "market {}: cancelled the orders of {} accounts in {}",
+                market_address,
+                chunk.len(),
+                signature
+            );
+        }
+        Ok(())
+    }
+
+    /// Sweep the collected fees to the collect fee admin's associated token account
+    async fn sweep_fees(&self, market_address: Pubkey, market: &Market) -> anyhow::Result<()> {
+        let admin = match &self.collect_fee_admin {
+            Some(admin) if admin.pubkey() == market.collect_fee_admin => admin,
+            _ => return Ok(()),
+        };
+        self.metrics
+            .set("fees_available", market_address, market.fees_available);
+        if market.fees_available == 0 || market.fees_available < self.min_fees_to_sweep {
+            return Ok(());
+        }
+
+        let ix = Instruction {
+            program_id: openbook_v2::id(),
+            accounts: openbook_v2::accounts::SweepFees {
+                collect_fee_admin: admin.pubkey(),
+                market: market_address,
+                market_authority: market.market_authority,
+                market_quote_vault: market.market_quote_vault,
+                token_receiver_account: get_associated_token_address(
+                    &admin.pubkey(),
+                    &market.quote_mint,
+                ),
+                token_program: Token::id(),
+            }
+            .to_account_metas(None),
+            data: openbook_v2::instruction::SweepFees {}.data(),
+        };
+        let signature = self.send(vec![ix], Some(admin)).await?;
+        self.metrics
+            .increment("fees_swept", market_address, market.fees_available);
+        info!(
+            "market {}: swept {} native quote in {}",
+            market_address, market.fees_available, signature
+        );
+        Ok(())
+    }
+}
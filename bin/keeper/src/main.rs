@@ -0,0 +1,126 @@
+//! Cranks OpenBook v2 markets.
+//!
+//! Every `--interval-secs`, for each market given with `--markets`, the keeper
+//! - consumes the events on the event heap,
+//! - cancels the resting orders once the market has expired,
+//! - sweeps the collected fees when `--collect-fee-admin` is the market's collect fee admin.
+//!
+//! With `--metrics-port` it serves counters like `openbook_keeper_events_consumed`
+//! in the Prometheus text format.
+
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anchor_client::Cluster;
+use clap::Parser;
+use futures::future::join_all;
+use openbook_v2_client::{
+    keypair_from_cli, pubkey_from_cli, Client, PriorityFeeConfig, TransactionBuilderConfig,
+};
+use solana_sdk::commitment_config::CommitmentConfig;
+
+mod keeper;
+mod metrics;
+
+use keeper::Keeper;
+use metrics::Metrics;
+
+#[derive(Parser, Debug)]
+#[clap(about = "Cranks OpenBook v2 markets")]
+struct Cli {
+    #[clap(long, env, default_value = "https://api.mainnet-beta.solana.com")]
+    rpc_url: String,
+
+    /// Pays for the transactions, and consumes events on markets where it is the
+    /// consume events admin
+    #[clap(long, env)]
+    keypair: String,
+
+    #[clap(long, env, required = true, use_value_delimiter = true)]
+    markets: Vec<String>,
+
+    #[clap(long, env, default_value = "5")]
+    interval_secs: u64,
+
+    /// Events consumed per transaction
+    #[clap(long, env, default_value = "8")]
+    consume_events_limit: usize,
+
+    /// Orders cancelled per open orders account and transaction on expired markets
+    #[clap(long, env, default_value = "10")]
+    settle_expired_limit: u8,
+
+    /// Keypair of the markets' collect fee admin, sweeps fees when set
+    #[clap(long, env)]
+    collect_fee_admin: Option<String>,
+
+    /// Only sweep once this many native quote tokens have been collected
+    #[clap(long, env, default_value = "0")]
+    min_fees_to_sweep: u64,
+
+    /// Fixed compute unit price
+    #[clap(long, env)]
+    prioritization_micro_lamports: Option<u64>,
+
+    /// Pay this percentile of the recent prioritization fees instead, from 0 to 100
+    #[clap(long, env)]
+    priority_fee_percentile: Option<u8>,
+
+    #[clap(long, env, default_value = "1000000")]
+    max_priority_fee_micro_lamports: u64,
+
+    #[clap(long, env)]
+    compute_unit_limit: Option<u32>,
+
+    #[clap(long, env)]
+    metrics_port: Option<u16>,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+    let cli = Cli::parse();
+
+    let client = Client::new(
+        Cluster::from_str(&cli.rpc_url)?,
+        CommitmentConfig::confirmed(),
+        Arc::new(keypair_from_cli(&cli.keypair)),
+        Some(Duration::from_secs(30)),
+        TransactionBuilderConfig {
+            prioritization_micro_lamports: cli.prioritization_micro_lamports,
+            compute_unit_limit: cli.compute_unit_limit,
+            priority_fee: cli
+                .priority_fee_percentile
+                .map(|percentile| PriorityFeeConfig {
+                    percentile,
+                    max_micro_lamports: cli.max_priority_fee_micro_lamports,
+                }),
+        },
+    );
+    let metrics = Metrics::default();
+    let keeper = Arc::new(Keeper {
+        client,
+        collect_fee_admin: cli
+            .collect_fee_admin
+            .as_deref()
+            .map(|keypair| Arc::new(keypair_from_cli(keypair))),
+        consume_events_limit: cli.consume_events_limit,
+        settle_expired_limit: cli.settle_expired_limit,
+        min_fees_to_sweep: cli.min_fees_to_sweep,
+        metrics: metrics.clone(),
+    });
+
+    if let Some(port) = cli.metrics_port {
+        tokio::spawn(metrics.serve(port));
+    }
+
+    let interval = Duration::from_secs(cli.interval_secs);
+    let jobs = cli.markets.iter().map(|market| {
+        let keeper = keeper.clone();
+        let market = pubkey_from_cli(market);
+        tokio::spawn(async move { keeper.run(market, interval).await })
+    });
+    join_all(jobs).await;
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use std::collections::BTreeMap;
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Response, Server};
+use solana_sdk::pubkey::Pubkey;
+
+/// Per-market counters and gauges, served in the Prometheus text format.
+#[derive(Clone, Default)]
+pub struct Metrics {
+    values: Arc<Mutex<BTreeMap<(&'static str, Pubkey), u64>>>,
+}
+
+impl Metrics {
+    pub fn increment(&self, name: &'static str, market: Pubkey, by: u64) {
+        *self
+            .values
+            .lock()
+            .unwrap()
+            .entry((name, market))
+            .or_default() += by;
+    }
+
+    pub fn set(&self, name: &'static str, market: Pubkey, value: u64) {
+        self.values.lock().unwrap().insert((name, market), value);
+    }
+
+    fn render(&self) -> String {
+        self.values
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|((name, market), value)| {
+                format!(
+                    "openbook_keeper_{}{{market=\"{}\"}} {}\n",
+                    name, market, value
+                )
+            })
+            .collect()
+    }
+
+    pub async fn serve(self, port: u16) -> anyhow::Result<()> {
+        let make_service = make_service_fn(move |_conn| {
+            let metrics = self.clone();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |_request| {
+                    let body = metrics.render();
+                    async move { Ok::<_, Infallible>(Response::new(Body::from(body))) }
+                }))
+            }
+        });
+        Server::bind(&SocketAddr::from(([0, 0, 0, 0], port)))
+            .serve(make_service)
+            .await?;
+        Ok(())
+    }
+}
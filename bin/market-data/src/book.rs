@@ -0,0 +1,211 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::Context;
+use futures::StreamExt;
+use log::*;
+use openbook_v2::accounts_zerocopy::FromBytes;
+use openbook_v2::pricemath;
+use openbook_v2::state::{BookLevel, BookSide, Market, Side};
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::nonblocking::pubsub_client::PubsubClient;
+use solana_client::nonblocking::rpc_client::RpcClient as RpcClientAsync;
+use solana_client::rpc_config::RpcAccountInfoConfig;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::pubkey::Pubkey;
+use tokio::sync::broadcast;
+
+use crate::messages::ServerMessage;
+
+/// Top of the book of a market, as of `slot`
+pub struct MarketBook {
+    pub market: Market,
+    pub slot: u64,
+    pub bids: Vec<BookLevel>,
+    pub asks: Vec<BookLevel>,
+}
+
+pub type Books = Arc<RwLock<HashMap<Pubkey, MarketBook>>>;
+
+impl MarketBook {
+    pub fn new(market: Market) -> Self {
+        Self {
+            market,
+            slot: 0,
+            bids: vec![],
+            asks: vec![],
+        }
+    }
+
+    /// [price, size] in UI units
+    pub fn ui_level(&self, price_lots: i64, quantity: i64) -> [f64; 2] {
+        let market = &self.market;
+        [
+            pricemath::price_lots_to_ui(
+                price_lots,
+                market.base_decimals,
+                market.quote_decimals,
+                market.base_lot_size,
+                market.quote_lot_size,
+            ),
+            pricemath::size_lots_to_ui(quantity, market.base_decimals, market.base_lot_size),
+        ]
+    }
+
+    fn ui_levels(&self, levels: &[BookLevel]) -> Vec<[f64; 2]> {
+        levels
+            .iter()
+            .map(|level| self.ui_level(level.price_lots, level.quantity))
+            .collect()
+    }
+
+    pub fn snapshot(&self, market_address: Pubkey) -> ServerMessage {
+        ServerMessage::L2Snapshot {
+            market: market_address.to_string(),
+            slot: self.slot,
+            bids: self.ui_levels(&self.bids),
+            asks: self.ui_levels(&self.asks),
+        }
+    }
+}
+
+/// Levels that differ between `old` and `new` as (price_lots, quantity), with
+/// quantity 0 for removed levels
+fn diff_levels(old: &[BookLevel], new: &[BookLevel]) -> Vec<(i64, i64)> {
+    let changed = new
+        .iter()
+        .filter(|level| {
+            !old.iter()
+                .any(|o| o.price_lots == level.price_lots && o.quantity == level.quantity)
+        })
+        .map(|level| (level.price_lots, level.quantity));
+    let removed = old
+        .iter()
+        .filter(|level| !new.iter().any(|n| n.price_lots == level.price_lots))
+        .map(|level| (level.price_lots, 0));
+    changed.chain(removed).collect()
+}
+
+fn update_bookside(
+    books: &Books,
+    sender: &broadcast::Sender<ServerMessage>,
+    market_address: Pubkey,
+    side: Side,
+    depth: usize,
+    slot: u64,
+    data: &[u8],
+) -> anyhow::Result<()> {
+    let bookside = Box::new(BookSide::from_bytes(data)?);
+    let now_ts = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    let levels = bookside.levels(depth, now_ts, None);
+
+    let mut books = books.write().unwrap();
+    let book = books.get_mut(&market_address).unwrap();
+    let old_levels = match side {
+        Side::Bid => &mut book.bids,
+        Side::Ask => &mut book.asks,
+    };
+    let changes = diff_levels(old_levels, &levels);
+    *old_levels = levels;
+    book.slot = book.slot.max(slot);
+    if changes.is_empty() {
+        return Ok(());
+    }
+
+    let changes = changes
+        .into_iter()
+        .map(|(price_lots, quantity)| book.ui_level(price_lots, quantity))
+        .collect();
+    let (bids, asks) = match side {
+        Side::Bid => (changes, vec![]),
+        Side::Ask => (vec![], changes),
+    };
+    // fails only when no client is connected
+    let _ = sender.send(ServerMessage::L2Update {
+        market: market_address.to_string(),
+        slot: book.slot,
+        bids,
+        asks,
+    });
+    Ok(())
+}
+
+async fn subscribe_bookside(
+    ws_url: &str,
+    rpc: &RpcClientAsync,
+    books: &Books,
+    sender: &broadcast::Sender<ServerMessage>,
+    market_address: Pubkey,
+    side: Side,
+    depth: usize,
+) -> anyhow::Result<()> {
+    let address = {
+        let market = &books.read().unwrap()[&market_address].market;
+        match side {
+            Side::Bid => market.bids,
+            Side::Ask => market.asks,
+        }
+    };
+    let pubsub = PubsubClient::new(ws_url).await?;
+    let config = RpcAccountInfoConfig {
+        encoding: Some(UiAccountEncoding::Base64),
+        commitment: Some(CommitmentConfig::confirmed()),
+        ..Default::default()
+    };
+    let (mut updates, _unsubscribe) = pubsub.account_subscribe(&address, Some(config)).await?;
+
+    // updates only arrive when the account changes, start from its current state
+    let response = rpc
+        .get_account_with_commitment(&address, CommitmentConfig::confirmed())
+        .await?;
+    let account = response.value.context("book side not found")?;
+    update_bookside(
+        books,
+        sender,
+        market_address,
+        side,
+        depth,
+        response.context.slot,
+        &account.data,
+    )?;
+
+    while let Some(update) = updates.next().await {
+        let data = update
+            .value
+            .data
+            .decode()
+            .context("decoding book side data")?;
+        update_bookside(
+            books,
+            sender,
+            market_address,
+            side,
+            depth,
+            update.context.slot,
+            &data,
+        )?;
+    }
+    Ok(())
+}
+
+/// Keep one side of a market's book up to date, resubscribing on errors
+pub async fn watch_bookside(
+    ws_url: String,
+    rpc_url: String,
+    books: Books,
+    sender: broadcast::Sender<ServerMessage>,
+    market_address: Pubkey,
+    side: Side,
+    depth: usize,
+) {
+    let rpc = RpcClientAsync::new_with_commitment(rpc_url, CommitmentConfig::confirmed());
+    loop {
+        if let Err(e) =
+            subscribe_bookside(&ws_url, &rpc, &books, &sender, market_address, side, depth).await
+        {
+            warn!("{:?} of market {}: {:?}", side, market_address, e);
+        }
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+}
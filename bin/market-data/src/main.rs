@@ -0,0 +1,90 @@
+//! Streams OpenBook v2 order books and trades over WebSocket.
+//!
+//! The book sides of the markets given with `--markets` are kept up to date from
+//! account subscriptions, trades are read from the program's transactions. Clients
+//! subscribe per market and receive a depth snapshot followed by updates, see
+//! README.md for the message format.
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::str::FromStr;
+use std::sync::{Arc, RwLock};
+
+use anchor_client::Cluster;
+use clap::Parser;
+use openbook_v2::state::{Market, Side};
+use openbook_v2_client::gpa::fetch_anchor_account;
+use openbook_v2_client::pubkey_from_cli;
+use solana_client::nonblocking::rpc_client::RpcClient as RpcClientAsync;
+use solana_sdk::commitment_config::CommitmentConfig;
+use tokio::sync::broadcast;
+
+mod book;
+mod messages;
+mod server;
+mod trades;
+
+use book::{Books, MarketBook};
+
+// messages buffered per client before it's considered lagging
+const CHANNEL_CAPACITY: usize = 4096;
+
+#[derive(Parser, Debug)]
+#[clap(about = "Streams OpenBook v2 order books and trades over WebSocket")]
+struct Cli {
+    #[clap(long, env, default_value = "https://api.mainnet-beta.solana.com")]
+    rpc_url: String,
+
+    #[clap(long, env, required = true, use_value_delimiter = true)]
+    markets: Vec<String>,
+
+    #[clap(long, env, default_value = "0.0.0.0:8080")]
+    bind: SocketAddr,
+
+    /// Price levels per side
+    #[clap(long, env, default_value = "50")]
+    depth: usize,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+    let cli = Cli::parse();
+    let cluster = Cluster::from_str(&cli.rpc_url)?;
+    let rpc_url = cluster.url().to_string();
+    let ws_url = cluster.ws_url().to_string();
+
+    let rpc = RpcClientAsync::new_with_commitment(rpc_url.clone(), CommitmentConfig::confirmed());
+    let books: Books = Arc::new(RwLock::new(HashMap::new()));
+    let market_addresses = cli.markets.iter().map(|market| pubkey_from_cli(market));
+    for market_address in market_addresses.clone() {
+        let market: Market = fetch_anchor_account(&rpc, &market_address).await?;
+        books
+            .write()
+            .unwrap()
+            .insert(market_address, MarketBook::new(market));
+    }
+
+    let (sender, _) = broadcast::channel(CHANNEL_CAPACITY);
+    for market_address in market_addresses {
+        for side in [Side::Bid, Side::Ask] {
+            tokio::spawn(book::watch_bookside(
+                ws_url.clone(),
+                rpc_url.clone(),
+                books.clone(),
+                sender.clone(),
+                market_address,
+                side,
+                cli.depth,
+            ));
+        }
+    }
+    tokio::spawn(trades::watch_trades(
+        ws_url,
+        rpc_url,
+        books.clone(),
+        sender.clone(),
+    ));
+
+    server::serve(cli.bind, books, sender).await
+}
@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+
+/// Commands sent by clients, see README.md
+#[derive(Deserialize, Debug)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum ClientMessage {
+    Subscribe { market: String },
+    Unsubscribe { market: String },
+}
+
+/// Messages sent to clients, see README.md
+///
+/// Levels are [price, size] in UI units, bids from the highest price, asks from
+/// the lowest.
+#[derive(Serialize, Clone, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ServerMessage {
+    L2Snapshot {
+        market: String,
+        slot: u64,
+        bids: Vec<[f64; 2]>,
+        asks: Vec<[f64; 2]>,
+    },
+    /// Levels that changed since the last message, a size of 0 removes the level
+    L2Update {
+        market: String,
+        slot: u64,
+        bids: Vec<[f64; 2]>,
+        asks: Vec<[f64; 2]>,
+    },
+    Trade {
+        market: String,
+        signature: String,
+        slot: u64,
+        timestamp: u64,
+        /// "buy" when the taker bought, "sell" when it sold
+        taker_side: &'static str,
+        price: f64,
+        size: f64,
+        maker: String,
+        taker: String,
+    },
+    Error {
+        message: String,
+    },
+}
+
+impl ServerMessage {
+    pub fn market(&self) -> Option<&str> {
+        match self {
+            Self::L2Snapshot { market, .. }
+            | Self::L2Update { market, .. }
+            | Self::Trade { market, .. } => Some(market),
+            Self::Error { .. } => None,
+        }
+    }
+}
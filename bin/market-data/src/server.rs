@@ -0,0 +1,122 @@
+use std::collections::HashSet;
+use std::net::SocketAddr;
+use std::str::FromStr;
+
+use futures::stream::SplitSink;
+use futures::{SinkExt, StreamExt};
+use log::*;
+use solana_sdk::pubkey::Pubkey;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::broadcast::{self, error::RecvError};
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::WebSocketStream;
+
+use crate::book::Books;
+use crate::messages::{ClientMessage, ServerMessage};
+
+type Sink = SplitSink<WebSocketStream<TcpStream>, Message>;
+
+async fn send(sink: &mut Sink, message: &ServerMessage) -> anyhow::Result<()> {
+    sink.send(Message::Text(serde_json::to_string(message)?))
+        .await?;
+    Ok(())
+}
+
+fn snapshot(books: &Books, market: &str) -> Option<ServerMessage> {
+    let market_address = Pubkey::from_str(market).ok()?;
+    books
+        .read()
+        .unwrap()
+        .get(&market_address)
+        .map(|book| book.snapshot(market_address))
+}
+
+/// Apply a client command, returning the reply to send
+fn handle_command(
+    text: &str,
+    books: &Books,
+    subscriptions: &mut HashSet<String>,
+) -> Option<ServerMessage> {
+    let command = match serde_json::from_str::<ClientMessage>(text) {
+        Ok(command) => command,
+        Err(e) => {
+            return Some(ServerMessage::Error {
+                message: format!("invalid command: {}", e),
+            })
+        }
+    };
+    match command {
+        ClientMessage::Subscribe { market } => match snapshot(books, &market) {
+            Some(snapshot) => {
+                subscriptions.insert(market);
+                Some(snapshot)
+            }
+            None => Some(ServerMessage::Error {
+                message: format!("unknown market {}", market),
+            }),
+        },
+        ClientMessage::Unsubscribe { market } => {
+            subscriptions.remove(&market);
+            None
+        }
+    }
+}
+
+async fn handle_connection(
+    stream: TcpStream,
+    books: Books,
+    mut updates: broadcast::Receiver<ServerMessage>,
+) -> anyhow::Result<()> {
+    let (mut sink, mut incoming) = tokio_tungstenite::accept_async(stream).await?.split();
+    let mut subscriptions = HashSet::new();
+    loop {
+        tokio::select! {
+            message = incoming.next() => {
+                let text = match message {
+                    Some(Ok(Message::Text(text))) => text,
+                    Some(Ok(Message::Close(_))) | None => return Ok(()),
+                    Some(Ok(_)) => continue,
+                    Some(Err(e)) => return Err(e.into()),
+                };
+                if let Some(reply) = handle_command(&text, &books, &mut subscriptions) {
+                    send(&mut sink, &reply).await?;
+                }
+            }
+            update = updates.recv() => match update {
+                Ok(update) => {
+                    if update.market().map_or(false, |market| subscriptions.contains(market)) {
+                        send(&mut sink, &update).await?;
+                    }
+                }
+                // the client is too slow and missed updates, start over from snapshots
+                Err(RecvError::Lagged(_)) => {
+                    for market in subscriptions.iter() {
+                        if let Some(snapshot) = snapshot(&books, market) {
+                            send(&mut sink, &snapshot).await?;
+                        }
+                    }
+                }
+                Err(RecvError::Closed) => return Ok(()),
+            }
+        }
+    }
+}
+
+pub async fn serve(
+    address: SocketAddr,
+    books: Books,
+    sender: broadcast::Sender<ServerMessage>,
+) -> anyhow::Result<()> {
+    let listener = TcpListener::bind(address).await?;
+    info!("listening on {}", address);
+    loop {
+        let (stream, peer) = listener.accept().await?;
+        let books = books.clone();
+        let updates = sender.subscribe();
+        tokio::spawn(async move {
+            if let Err(e) = handle_connection(stream, books, updates).await {
+                debug!("connection {}: {:?}", peer, e);
+            }
+        });
+    }
+}
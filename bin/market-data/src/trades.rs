@@ -0,0 +1,106 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+use futures::StreamExt;
+use log::*;
+use openbook_v2::state::Side;
+use openbook_v2_client::events::{transaction_events, OpenBookEvent};
+use solana_client::nonblocking::pubsub_client::PubsubClient;
+use solana_client::nonblocking::rpc_client::RpcClient as RpcClientAsync;
+use solana_client::rpc_config::{
+    RpcTransactionConfig, RpcTransactionLogsConfig, RpcTransactionLogsFilter,
+};
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::signature::Signature;
+use solana_transaction_status::UiTransactionEncoding;
+use tokio::sync::broadcast;
+
+use crate::book::Books;
+use crate::messages::ServerMessage;
+
+async fn subscribe_trades(
+    ws_url: &str,
+    rpc: &RpcClientAsync,
+    books: &Books,
+    sender: &broadcast::Sender<ServerMessage>,
+) -> anyhow::Result<()> {
+    let pubsub = PubsubClient::new(ws_url).await?;
+    let (mut logs, _unsubscribe) = pubsub
+        .logs_subscribe(
+            RpcTransactionLogsFilter::Mentions(vec![openbook_v2::id().to_string()]),
+            RpcTransactionLogsConfig {
+                commitment: Some(CommitmentConfig::confirmed()),
+            },
+        )
+        .await?;
+    let config = RpcTransactionConfig {
+        encoding: Some(UiTransactionEncoding::Base64),
+        commitment: Some(CommitmentConfig::confirmed()),
+        max_supported_transaction_version: Some(0),
+    };
+
+    while let Some(response) = logs.next().await {
+        if response.value.err.is_some() {
+            continue;
+        }
+        let signature = response.value.signature;
+        let tx = match rpc
+            .get_transaction_with_config(&Signature::from_str(&signature)?, config)
+            .await
+        {
+            Ok(tx) => tx,
+            Err(e) => {
+                debug!("fetching {}: {}", signature, e);
+                continue;
+            }
+        };
+
+        let books = books.read().unwrap();
+        for event in transaction_events(&tx)? {
+            let fill = match event {
+                OpenBookEvent::Fill(fill) => fill,
+                _ => continue,
+            };
+            let book = match books.get(&fill.market) {
+                Some(book) => book,
+                None => continue,
+            };
+            let [price, size] = book.ui_level(fill.price, fill.quantity);
+            let taker_side = match Side::try_from(fill.taker_side) {
+                Ok(Side::Bid) => "buy",
+                _ => "sell",
+            };
+            let _ = sender.send(ServerMessage::Trade {
+                market: fill.market.to_string(),
+                signature: signature.clone(),
+                slot: tx.slot,
+                timestamp: fill.timestamp,
+                taker_side,
+                price,
+                size,
+                maker: fill.maker.to_string(),
+                taker: fill.taker.to_string(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Broadcast the fills of the watched markets, resubscribing on errors
+///
+/// Transactions are fetched once they're seen in the logs, trades whose
+/// transaction can't be fetched are skipped.
+pub async fn watch_trades(
+    ws_url: String,
+    rpc_url: String,
+    books: Books,
+    sender: broadcast::Sender<ServerMessage>,
+) {
+    let rpc = RpcClientAsync::new_with_commitment(rpc_url, CommitmentConfig::confirmed());
+    loop {
+        if let Err(e) = subscribe_trades(&ws_url, &rpc, &books, &sender).await {
+            warn!("trades: {:?}", e);
+        }
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+}
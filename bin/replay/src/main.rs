@@ -0,0 +1,188 @@
+//! Replays the event logs of an OpenBook v2 market and checks the rebuilt book
+//! against the book side accounts.
+//!
+//! The market's transactions are fetched from the RPC node, oldest first, up to the
+//! slot of the snapshot. Without `--slot` the snapshot is the current book sides,
+//! with it the book side account data saved at that slot must be given, as written
+//! by `solana account <ADDRESS> --output-file <FILE>`. The node has to serve the
+//! whole history of the market, see `openbook_v2_client::replay` for what can't be
+//! replayed.
+
+use std::str::FromStr;
+use std::time::Duration;
+
+use anchor_client::Cluster;
+use anchor_lang::AccountDeserialize;
+use clap::Parser;
+use log::*;
+use openbook_v2::state::{BookSide, Market};
+use openbook_v2_client::events::transaction_events;
+use openbook_v2_client::gpa::fetch_anchor_account;
+use openbook_v2_client::pubkey_from_cli;
+use openbook_v2_client::replay::ReplayBook;
+use solana_client::nonblocking::rpc_client::RpcClient as RpcClientAsync;
+use solana_client::rpc_client::GetConfirmedSignaturesForAddress2Config;
+use solana_client::rpc_config::RpcTransactionConfig;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_transaction_status::{EncodedConfirmedTransactionWithStatusMeta, UiTransactionEncoding};
+
+// confirmed transactions can take a moment to be served by getTransaction
+const GET_TRANSACTION_RETRIES: usize = 5;
+
+#[derive(Parser, Debug)]
+#[clap(about = "Rebuilds the book of an OpenBook v2 market from its event logs")]
+struct Cli {
+    #[clap(long, env, default_value = "https://api.mainnet-beta.solana.com")]
+    rpc_url: String,
+
+    #[clap(long, env)]
+    market: String,
+
+    /// Replay up to this slot, requires the book sides at that slot
+    #[clap(long, requires_all = &["bids_file", "asks_file"])]
+    slot: Option<u64>,
+
+    /// Account data of the bids at `--slot`
+    #[clap(long)]
+    bids_file: Option<String>,
+
+    /// Account data of the asks at `--slot`
+    #[clap(long)]
+    asks_file: Option<String>,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+    let cli = Cli::parse();
+    let cluster = Cluster::from_str(&cli.rpc_url)?;
+    let rpc = RpcClientAsync::new_with_commitment(
+        cluster.url().to_string(),
+        CommitmentConfig::confirmed(),
+    );
+    let market_address = pubkey_from_cli(&cli.market);
+    let market: Market = fetch_anchor_account(&rpc, &market_address).await?;
+
+    let (slot, bids, asks) = match cli.slot {
+        Some(slot) => (
+            slot,
+            read_bookside(cli.bids_file.as_ref().unwrap())?,
+            read_bookside(cli.asks_file.as_ref().unwrap())?,
+        ),
+        None => {
+            let response = rpc
+                .get_multiple_accounts_with_commitment(
+                    &[market.bids, market.asks],
+                    CommitmentConfig::confirmed(),
+                )
+                .await?;
+            let mut booksides = response.value.into_iter().map(|account| {
+                let account = account.ok_or_else(|| anyhow::anyhow!("book side not found"))?;
+                anyhow::Ok(BookSide::try_deserialize(&mut (&account.data as &[u8]))?)
+            });
+            (
+                response.context.slot,
+                booksides.next().unwrap()?,
+                booksides.next().unwrap()?,
+            )
+        }
+    };
+    if bids.paged_leaf_count > 0 || asks.paged_leaf_count > 0 {
+        warn!("orders on book side pages aren't compared");
+    }
+    let snapshot = ReplayBook::from_book_sides(market_address, market.base_lot_size, &bids, &asks);
+
+    let signatures = fetch_signatures(&rpc, &market_address, slot).await?;
+    info!(
+        "replaying {} transactions up to slot {}",
+        signatures.len(),
+        slot
+    );
+    let mut book = ReplayBook::new(market_address, market.base_lot_size);
+    for signature in signatures.iter() {
+        let tx = fetch_transaction(&rpc, signature).await?;
+        for event in transaction_events(&tx)? {
+            book.apply(&event)
+                .map_err(|e| e.context(format!("transaction {}", signature)))?;
+        }
+    }
+
+    let mismatches = book.diff(&snapshot);
+    for mismatch in mismatches.iter() {
+        warn!(
+            "replayed {:?}, snapshot {:?}",
+            mismatch.replayed, mismatch.snapshot
+        );
+    }
+    anyhow::ensure!(
+        mismatches.is_empty(),
+        "{} of {} orders don't match",
+        mismatches.len(),
+        snapshot.orders.len()
+    );
+    info!("the {} resting orders match", snapshot.orders.len());
+    Ok(())
+}
+
+fn read_bookside(path: &str) -> anyhow::Result<BookSide> {
+    let data = std::fs::read(path)?;
+    Ok(BookSide::try_deserialize(&mut (&data as &[u8]))?)
+}
+
+/// Successful transactions mentioning `address` up to `slot`, oldest first
+async fn fetch_signatures(
+    rpc: &RpcClientAsync,
+    address: &Pubkey,
+    slot: u64,
+) -> anyhow::Result<Vec<Signature>> {
+    let mut signatures = vec![];
+    let mut before = None;
+    loop {
+        let config = GetConfirmedSignaturesForAddress2Config {
+            before,
+            until: None,
+            limit: None,
+            commitment: Some(CommitmentConfig::confirmed()),
+        };
+        let page = rpc
+            .get_signatures_for_address_with_config(address, config)
+            .await?;
+        let last = match page.last() {
+            Some(last) => Signature::from_str(&last.signature)?,
+            None => break,
+        };
+        for status in page {
+            if status.slot <= slot && status.err.is_none() {
+                signatures.push(Signature::from_str(&status.signature)?);
+            }
+        }
+        before = Some(last);
+    }
+    signatures.reverse();
+    Ok(signatures)
+}
+
+async fn fetch_transaction(
+    rpc: &RpcClientAsync,
+    signature: &Signature,
+) -> anyhow::Result<EncodedConfirmedTransactionWithStatusMeta> {
+    let config = RpcTransactionConfig {
+        encoding: Some(UiTransactionEncoding::Base64),
+        commitment: Some(CommitmentConfig::confirmed()),
+        max_supported_transaction_version: Some(0),
+    };
+    let mut retries = 0;
+    loop {
+        match rpc.get_transaction_with_config(signature, config).await {
+            Ok(tx) => return Ok(tx),
+            Err(e) if retries < GET_TRANSACTION_RETRIES => {
+                debug!("fetching {}: {}, retrying", signature, e);
+                retries += 1;
+                tokio::time::sleep(Duration::from_millis(500)).await;
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
+}
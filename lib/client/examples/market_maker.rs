@@ -0,0 +1,295 @@
+//! Quotes around the oracle price of a market.
+//!
+//! Every `--interval-secs` the bot replaces all its orders with `--levels` bids and
+//! asks in a single `cancel_all_and_place_orders` transaction:
+//!
+//! - the innermost bid and ask are `--spread-bps` apart, further levels are
+//!   `--level-spacing-bps` apart, each one `--size` base tokens,
+//! - the quotes are shifted away from the side that grows the inventory, by up to
+//!   `--skew-bps` when it is `--max-inventory` base tokens off `--target-inventory`,
+//! - once the inventory reaches that limit only the side reducing it is quoted.
+//!
+//! The inventory includes the base tokens in the owner's associated token account,
+//! in the open orders account and in its resting asks. Orders expire after
+//! `--order-lifetime-secs`, so the market is left without quotes if the bot stops.
+//! When the oracle price is stale, all orders are cancelled.
+//!
+//! ```bash
+//! cargo run --example market_maker -- --rpc-url https://a.b.c --keypair ~/mm.json \
+//!     --market <MARKET> --spread-bps 20 --size 1.5 --levels 3 --max-inventory 50
+//! ```
+
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anchor_client::Cluster;
+use clap::Parser;
+use log::*;
+use openbook_v2::accounts_zerocopy::KeyedAccount;
+use openbook_v2::pricemath;
+use openbook_v2::state::{Market, OpenOrdersAccount, PlaceOrderType, Side};
+use openbook_v2::PlaceMultipleOrdersArgs;
+use openbook_v2_client::{
+    keypair_from_cli, pubkey_from_cli, Client, OpenBookClient, TransactionBuilderConfig,
+};
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::Signer;
+use spl_associated_token_account::get_associated_token_address;
+
+#[derive(Parser, Debug)]
+#[clap(about = "Quotes around the oracle price of an OpenBook v2 market")]
+struct Cli {
+    #[clap(long, env, default_value = "https://api.mainnet-beta.solana.com")]
+    rpc_url: String,
+
+    /// Owns the open orders account and pays for the transactions
+    #[clap(long, env)]
+    keypair: String,
+
+    #[clap(long, env)]
+    market: String,
+
+    /// Defaults to an account named "market-maker", created if it doesn't exist
+    #[clap(long, env)]
+    open_orders_account: Option<String>,
+
+    #[clap(long, env, default_value = "20")]
+    spread_bps: f64,
+
+    /// Base tokens per order
+    #[clap(long, env, default_value = "1")]
+    size: f64,
+
+    /// Orders per side
+    #[clap(long, env, default_value = "1")]
+    levels: usize,
+
+    #[clap(long, env, default_value = "10")]
+    level_spacing_bps: f64,
+
+    /// Base tokens to hold
+    #[clap(long, env, default_value = "0")]
+    target_inventory: f64,
+
+    /// Base tokens above or below the target at which quoting stops on one side
+    #[clap(long, env, default_value = "10")]
+    max_inventory: f64,
+
+    /// Price shift at max inventory
+    #[clap(long, env, default_value = "50")]
+    skew_bps: f64,
+
+    #[clap(long, env, default_value = "10")]
+    interval_secs: u64,
+
+    #[clap(long, env, default_value = "60")]
+    order_lifetime_secs: u64,
+}
+
+struct Quote {
+    side: Side,
+    price_lots: i64,
+    base_lots: i64,
+}
+
+/// Quotes for the given oracle price in lots and inventory in base tokens
+fn quotes(cli: &Cli, market: &Market, oracle_price_lots: f64, inventory: f64) -> Vec<Quote> {
+    let skew = ((inventory - cli.target_inventory) / cli.max_inventory).clamp(-1.0, 1.0);
+    let center = oracle_price_lots * (1.0 - skew * cli.skew_bps / 10_000.0);
+    let base_lots =
+        pricemath::size_ui_to_lots(cli.size, market.base_decimals, market.base_lot_size);
+
+    let mut quotes = vec![];
+    for level in 0..cli.levels {
+        let offset = (cli.spread_bps / 2.0 + level as f64 * cli.level_spacing_bps) / 10_000.0;
+        if skew < 1.0 {
+            quotes.push(Quote {
+                side: Side::Bid,
+                price_lots: (center * (1.0 - offset)).floor() as i64,
+                base_lots,
+            });
+        }
+        if skew > -1.0 {
+            quotes.push(Quote {
+                side: Side::Ask,
+                price_lots: (center * (1.0 + offset)).ceil() as i64,
+                base_lots,
+            });
+        }
+    }
+    quotes.retain(|quote| quote.price_lots > 0 && quote.base_lots > 0);
+    quotes
+}
+
+async fn oracle_price_lots(client: &Client, market: &Market) -> anyhow::Result<Option<f64>> {
+    let oracles: Vec<Pubkey> = [market.oracle_a, market.oracle_b]
+        .into_iter()
+        .filter_map(Option::<Pubkey>::from)
+        .collect();
+    anyhow::ensure!(!oracles.is_empty(), "the market has no oracle");
+
+    let rpc = client.rpc_async();
+    let slot = rpc.get_slot().await?;
+    let mut accounts = vec![];
+    for (key, account) in oracles
+        .iter()
+        .zip(rpc.get_multiple_accounts(&oracles).await?)
+    {
+        let account = account.ok_or_else(|| anyhow::anyhow!("oracle {} not found", key))?;
+        accounts.push(KeyedAccount { key: *key, account });
+    }
+
+    let price = market.oracle_price(accounts.first(), accounts.get(1), slot)?;
+    Ok(price.map(|price| {
+        price.price.to_num::<f64>() * market.base_lot_size as f64 / market.quote_lot_size as f64
+    }))
+}
+
+async fn inventory(
+    openbook: &OpenBookClient,
+    market: &Market,
+    base_account: &Pubkey,
+) -> anyhow::Result<f64> {
+    let open_orders_account: OpenOrdersAccount = openbook
+        .client
+        .rpc_anchor_account(&openbook.open_orders_account)
+        .await?;
+    let position = open_orders_account.position;
+    let wallet = openbook
+        .client
+        .rpc_async()
+        .get_token_account_balance(base_account)
+        .await
+        .map_or(0, |balance| balance.amount.parse::<i64>().unwrap_or(0));
+    let native =
+        wallet + position.base_free_native as i64 + position.asks_base_lots * market.base_lot_size;
+    Ok(pricemath::native_to_ui(native, market.base_decimals))
+}
+
+async fn update_quotes(
+    cli: &Cli,
+    openbook: &OpenBookClient,
+    market_address: Pubkey,
+) -> anyhow::Result<()> {
+    let market: Market = openbook.client.rpc_anchor_account(&market_address).await?;
+    let base_account = get_associated_token_address(&openbook.owner(), &market.base_mint);
+    let quote_account = get_associated_token_address(&openbook.owner(), &market.quote_mint);
+
+    let oracle_price_lots = match oracle_price_lots(&openbook.client, &market).await? {
+        Some(price) => price,
+        None => {
+            warn!("oracle price unavailable, cancelling all orders");
+            openbook
+                .cancel_all_and_place_orders(
+                    market,
+                    market_address,
+                    base_account,
+                    quote_account,
+                    PlaceOrderType::PostOnlySlide,
+                    vec![],
+                    vec![],
+                    u8::MAX,
+                )
+                .await?;
+            return Ok(());
+        }
+    };
+    let inventory = inventory(openbook, &market, &base_account).await?;
+
+    let expiry_timestamp =
+        SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() + cli.order_lifetime_secs;
+    let (mut bids, mut asks) = (vec![], vec![]);
+    for quote in quotes(cli, &market, oracle_price_lots, inventory) {
+        let quote_lots = quote.price_lots * quote.base_lots;
+        let order = PlaceMultipleOrdersArgs {
+            price_lots: quote.price_lots,
+            max_quote_lots_including_fees: match quote.side {
+                Side::Bid => quote_lots + market.maker_fees_ceil(quote_lots),
+                Side::Ask => quote_lots,
+            },
+            expiry_timestamp,
+        };
+        match quote.side {
+            Side::Bid => bids.push(order),
+            Side::Ask => asks.push(order),
+        }
+    }
+
+    let signature = openbook
+        .cancel_all_and_place_orders(
+            market,
+            market_address,
+            base_account,
+            quote_account,
+            PlaceOrderType::PostOnlySlide,
+            bids,
+            asks,
+            u8::MAX,
+        )
+        .await?;
+    info!(
+        "oracle {:.4} inventory {:.4}: quoted in {}",
+        pricemath::price_lots_to_ui(
+            oracle_price_lots.round() as i64,
+            market.base_decimals,
+            market.quote_decimals,
+            market.base_lot_size,
+            market.quote_lot_size,
+        ),
+        inventory,
+        signature
+    );
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+    let cli = Cli::parse();
+    let owner = Arc::new(keypair_from_cli(&cli.keypair));
+    let market_address = pubkey_from_cli(&cli.market);
+
+    let client = Client::new(
+        Cluster::from_str(&cli.rpc_url)?,
+        CommitmentConfig::confirmed(),
+        owner.clone(),
+        Some(Duration::from_secs(30)),
+        TransactionBuilderConfig {
+            prioritization_micro_lamports: None,
+            compute_unit_limit: None,
+            priority_fee: None,
+        },
+    );
+    let open_orders_account = match &cli.open_orders_account {
+        Some(account) => pubkey_from_cli(account),
+        None => {
+            OpenBookClient::find_or_create_account(
+                &client,
+                &owner,
+                &owner,
+                market_address,
+                "market-maker",
+            )
+            .await?
+        }
+    };
+    let openbook =
+        OpenBookClient::new_for_existing_account(client, open_orders_account, owner.clone())
+            .await?;
+    info!(
+        "quoting on market {} with open orders account {} of {}",
+        market_address,
+        open_orders_account,
+        owner.pubkey()
+    );
+
+    let mut interval = tokio::time::interval(Duration::from_secs(cli.interval_secs));
+    loop {
+        interval.tick().await;
+        if let Err(e) = update_quotes(&cli, &openbook, market_address).await {
+            warn!("updating quotes: {:?}", e);
+        }
+    }
+}
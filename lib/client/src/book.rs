@@ -5,7 +5,8 @@ use itertools::Itertools;
 use openbook_v2::state::{
     Market, Orderbook, Side, DROP_EXPIRED_ORDER_LIMIT, FILL_EVENT_REMAINING_LIMIT,
 };
-use std::collections::HashSet;
+use spl_associated_token_account::get_associated_token_address;
+use std::collections::{HashMap, HashSet};
 
 pub const MAXIMUM_TAKEN_ORDERS: u8 = 45;
 const MAXIMUM_REMAINING_ACCOUNTS: usize = 3;
@@ -81,6 +82,125 @@ pub fn amounts_from_book(
     })
 }
 
+/// Remaining accounts for a taker order, so the makers of its expected fills are
+/// settled without waiting for the crank: their open orders accounts in matching
+/// order, each once, up to the number of fills the program processes in place.
+///
+/// With `maker_owners`, mapping open orders accounts to their owners, each one is
+/// followed by its owner's associated token account for the token the taker pays
+/// with, and the maker's proceeds are transferred to it right away. Makers missing
+/// from the map only get their open orders account.
+#[allow(clippy::too_many_arguments)]
+pub fn maker_remaining_accounts(
+    book: Orderbook,
+    side: Side,
+    max_base_lots: i64,
+    max_quote_lots_including_fees: i64,
+    market: &Market,
+    oracle_price: Option<I80F48>,
+    now_ts: u64,
+    maker_owners: Option<&HashMap<Pubkey, Pubkey>>,
+) -> Result<Vec<Pubkey>> {
+    let oracle_price_lots = if let Some(oracle_price) = oracle_price {
+        Some(market.native_price_to_lot(oracle_price)?)
+    } else {
+        None
+    };
+    let mut makers = Vec::new();
+    iterate_book(
+        book,
+        side,
+        max_base_lots,
+        max_quote_lots_including_fees,
+        market,
+        oracle_price_lots,
+        now_ts,
+        &mut makers,
+    );
+
+    let paid_mint = match side {
+        Side::Bid => market.quote_mint,
+        Side::Ask => market.base_mint,
+    };
+    let mut remaining_accounts = Vec::new();
+    for maker in makers.into_iter().unique() {
+        remaining_accounts.push(maker);
+        if let Some(owner) = maker_owners.and_then(|owners| owners.get(&maker)) {
+            remaining_accounts.push(get_associated_token_address(owner, &paid_mint));
+        }
+    }
+    Ok(remaining_accounts)
+}
+
+pub struct TakerSimulation {
+    pub total_base_taken_native: u64,
+    /// Quote exchanged with the makers, without the taker fees.
+    pub total_quote_taken_native: u64,
+    pub taker_fees_native: u64,
+    /// Native quote per native base, without fees. `None` if nothing would fill.
+    pub average_price: Option<I80F48>,
+    /// Whether the book can't fill `max_base_lots` within `limit_price_lots`.
+    pub not_enough_liquidity: bool,
+}
+
+/// Walk the opposing side of the book like a taker order of `max_base_lots` with
+/// a limit price of `limit_price_lots` would, without sending a transaction.
+pub fn simulate_taker(
+    book: Orderbook,
+    side: Side,
+    max_base_lots: i64,
+    limit_price_lots: i64,
+    market: &Market,
+    oracle_price: Option<I80F48>,
+    now_ts: u64,
+) -> Result<TakerSimulation> {
+    let oracle_price_lots = if let Some(oracle_price) = oracle_price {
+        Some(market.native_price_to_lot(oracle_price)?)
+    } else {
+        None
+    };
+
+    let mut limit = MAXIMUM_TAKEN_ORDERS;
+    let mut remaining_base_lots = max_base_lots;
+    let mut total_quote_lots_taken: i64 = 0;
+
+    let opposing_bookside = book.bookside(side.invert_side());
+    for best_opposing in opposing_bookside.iter_valid(now_ts, oracle_price_lots) {
+        if remaining_base_lots == 0 || limit == 0 {
+            break;
+        }
+
+        let best_opposing_price = best_opposing.price_lots;
+        let crosses = match side {
+            Side::Bid => best_opposing_price <= limit_price_lots,
+            Side::Ask => best_opposing_price >= limit_price_lots,
+        };
+        if !crosses {
+            break;
+        }
+
+        let match_base_lots = remaining_base_lots.min(best_opposing.node.quantity);
+        remaining_base_lots -= match_base_lots;
+        total_quote_lots_taken += match_base_lots * best_opposing_price;
+        limit -= 1;
+    }
+
+    let total_base_lots_taken = max_base_lots - remaining_base_lots;
+    let total_base_taken_native = (total_base_lots_taken * market.base_lot_size) as u64;
+    let total_quote_taken_native = (total_quote_lots_taken * market.quote_lot_size) as u64;
+    let average_price = (total_base_taken_native > 0).then(|| {
+        I80F48::from_num(total_quote_taken_native) / I80F48::from_num(total_base_taken_native)
+    });
+
+    Ok(TakerSimulation {
+        total_base_taken_native,
+        total_quote_taken_native,
+        taker_fees_native: market.taker_fees(total_quote_taken_native),
+        average_price,
+        not_enough_liquidity: remaining_base_lots != 0,
+    })
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn iterate_book(
     book: Orderbook,
@@ -13,7 +13,10 @@ use itertools::Itertools;
 
 use openbook_v2::state::OracleConfigParams;
 use openbook_v2::{
-    state::{Market, OpenOrdersAccount, PlaceOrderType, SelfTradeBehavior, Side},
+    state::{
+        Market, OpenOrder, OpenOrdersAccount, OpenOrdersIndexer, PlaceOrderType, SelfTradeBehavior,
+        Side,
+    },
     PlaceMultipleOrdersArgs, PlaceOrderArgs, PlaceOrderPeggedArgs,
 };
 
@@ -26,7 +29,9 @@ use solana_sdk::signer::keypair;
 use solana_sdk::transaction::TransactionError;
 
 use crate::account_fetcher::*;
-use crate::gpa::{fetch_anchor_account, fetch_openbook_accounts};
+use crate::context::MarketContext;
+use crate::gpa::{fetch_anchor_account, fetch_anchor_account_opt, fetch_indexed_openbook_accounts};
+use crate::util::event_authority;
 
 use anyhow::Context;
 use solana_sdk::instruction::Instruction;
@@ -106,7 +111,7 @@ impl OpenBookClient {
         client: &Client,
         owner: &Keypair,
     ) -> anyhow::Result<Vec<(Pubkey, OpenOrdersAccount)>> {
-        fetch_openbook_accounts(&client.rpc_async(), openbook_v2::ID, owner.pubkey()).await
+        fetch_indexed_openbook_accounts(&client.rpc_async(), openbook_v2::ID, owner.pubkey()).await
     }
 
     pub async fn find_or_create_account(
@@ -119,18 +124,30 @@ impl OpenBookClient {
         let rpc = client.rpc_async();
         let program = openbook_v2::ID;
 
-        let mut openbook_account_tuples =
-            fetch_openbook_accounts(&rpc, program, owner.pubkey()).await?;
+        let openbook_account_tuples =
+            fetch_indexed_openbook_accounts(&rpc, program, owner.pubkey()).await?;
         let openbook_account_opt = openbook_account_tuples
             .iter()
             .find(|(_, account)| account.name() == openbook_account_name);
         if openbook_account_opt.is_none() {
-            openbook_account_tuples
-                .sort_by(|a, b| a.1.account_num.partial_cmp(&b.1.account_num).unwrap());
-            let account_num = match openbook_account_tuples.last() {
-                Some(tuple) => tuple.1.account_num + 1,
-                None => 0u32,
-            };
+            let open_orders_indexer = Pubkey::find_program_address(
+                &[b"OpenOrdersIndexer".as_ref(), owner.pubkey().as_ref()],
+                &program,
+            )
+            .0;
+            // the account number is taken from the indexer's counter, see CreateOpenOrdersAccount
+            let account_num =
+                match fetch_anchor_account_opt::<OpenOrdersIndexer>(&rpc, &open_orders_indexer)
+                    .await?
+                {
+                    Some(indexer) => indexer.created_counter + 1,
+                    None => {
+                        Self::create_open_orders_indexer(client, owner, payer)
+                            .await
+                            .context("Failed to create indexer...")?;
+                        1
+                    }
+                };
             Self::create_open_orders_account(
                 client,
                 market,
@@ -144,7 +161,7 @@ impl OpenBookClient {
             .context("Failed to create account...")?;
         }
         let openbook_account_tuples =
-            fetch_openbook_accounts(&rpc, program, owner.pubkey()).await?;
+            fetch_indexed_openbook_accounts(&rpc, program, owner.pubkey()).await?;
         let index = openbook_account_tuples
             .iter()
             .position(|tuple| tuple.1.name() == openbook_account_name)
@@ -295,11 +312,29 @@ impl OpenBookClient {
         self.owner.pubkey()
     }
 
+    pub async fn market_context(&self, market: Pubkey) -> anyhow::Result<MarketContext> {
+        let market_data =
+            account_fetcher_fetch_anchor_account::<Market>(&*self.account_fetcher, &market).await?;
+        Ok(MarketContext {
+            address: market,
+            market: market_data,
+        })
+    }
+
     pub async fn openorders_account(&self) -> anyhow::Result<OpenOrdersAccount> {
         account_fetcher_fetch_openorders_account(&*self.account_fetcher, &self.open_orders_account)
             .await
     }
 
+    /// The order with `client_order_id` of the open orders account, e.g. to recover
+    /// the order ids of a bot's resting orders after a restart
+    pub async fn find_order(&self, client_order_id: u64) -> anyhow::Result<Option<OpenOrder>> {
+        let account = self.openorders_account().await?;
+        Ok(account
+            .find_order_with_client_order_id(client_order_id)
+            .copied())
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub async fn create_market(
         &self,
@@ -324,6 +359,8 @@ impl OpenBookClient {
         maker_fee: i64,
         taker_fee: i64,
         time_expiry: i64,
+        min_order_notional: u64,
+        max_resting_orders: u8,
     ) -> anyhow::Result<Signature> {
         let ix = Instruction {
             program_id: openbook_v2::id(),
@@ -371,11 +408,47 @@ impl OpenBookClient {
                 maker_fee,
                 taker_fee,
                 time_expiry,
+                min_order_notional,
+                max_resting_orders,
             }),
         };
         self.send_and_confirm_owner_tx(vec![ix]).await
     }
 
+    /// Link `market` to its `successor_market`, or unlink it with `None`, as the
+    /// market's collect fee admin. See [`crate::migration`].
+    ///
+    /// The market's metadata has to exist already.
+    pub async fn set_successor_market(
+        &self,
+        market: Pubkey,
+        successor_market: Option<Pubkey>,
+    ) -> anyhow::Result<Signature> {
+        let market_metadata = Pubkey::find_program_address(
+            &[b"MarketMetadata".as_ref(), market.as_ref()],
+            &openbook_v2::id(),
+        )
+        .0;
+        let ix = Instruction {
+            program_id: openbook_v2::id(),
+            accounts: {
+                anchor_lang::ToAccountMetas::to_account_metas(
+                    &openbook_v2::accounts::SetSuccessorMarket {
+                        collect_fee_admin: self.owner(),
+                        market,
+                        market_metadata,
+                        successor_market,
+                    },
+                    None,
+                )
+            },
+            data: anchor_lang::InstructionData::data(
+                &openbook_v2::instruction::SetSuccessorMarket {},
+            ),
+        };
+        self.send_and_confirm_owner_tx(vec![ix]).await
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub async fn place_order(
         &self,
@@ -410,6 +483,10 @@ impl OpenBookClient {
                         user_token_account,
                         market_vault,
                         token_program: Token::id(),
+                        token_gate: None,
+                        gate_token_account: None,
+                        event_authority: event_authority(),
+                        program: openbook_v2::id(),
                     },
                     None,
                 )
@@ -466,6 +543,10 @@ impl OpenBookClient {
                         user_token_account,
                         market_vault,
                         token_program: Token::id(),
+                        token_gate: None,
+                        gate_token_account: None,
+                        event_authority: event_authority(),
+                        program: openbook_v2::id(),
                     },
                     None,
                 )
@@ -577,6 +658,10 @@ impl OpenBookClient {
                         oracle_a: market.oracle_a.into(),
                         oracle_b: market.oracle_b.into(),
                         token_program: Token::id(),
+                        token_gate: None,
+                        gate_token_account: None,
+                        event_authority: event_authority(),
+                        program: openbook_v2::id(),
                     },
                     None,
                 )
@@ -707,6 +792,24 @@ impl OpenBookClient {
         .await
     }
 
+    /// Like send_and_confirm_owner_tx(), but compiles a v0 transaction resolving
+    /// accounts through the lookup tables, see crate::lookup_table
+    pub async fn send_and_confirm_owner_tx_with_lookup_tables(
+        &self,
+        instructions: Vec<Instruction>,
+        address_lookup_tables: Vec<AddressLookupTableAccount>,
+    ) -> anyhow::Result<Signature> {
+        TransactionBuilder {
+            instructions,
+            address_lookup_tables,
+            payer: self.client.fee_payer.pubkey(),
+            signers: vec![&*self.owner, &*self.client.fee_payer],
+            config: self.client.transaction_builder_config,
+        }
+        .send_and_confirm(&self.client)
+        .await
+    }
+
     pub async fn send_and_confirm_permissionless_tx(
         &self,
         instructions: Vec<Instruction>,
@@ -738,8 +841,28 @@ pub enum OpenBookClientError {
 pub struct TransactionBuilderConfig {
     // adds a SetComputeUnitPrice instruction in front
     pub prioritization_micro_lamports: Option<u64>,
+    // adds a SetComputeUnitLimit instruction in front
+    pub compute_unit_limit: Option<u32>,
+    // derives the SetComputeUnitPrice from recent fees instead, see PriorityFeeConfig
+    pub priority_fee: Option<PriorityFeeConfig>,
+}
+
+/// Pay a compute unit price based on the recent prioritization fees of the
+/// accounts the transaction writes to, so it lands when they are contended.
+#[derive(Copy, Clone, Debug)]
+pub struct PriorityFeeConfig {
+    // percentile of the recent fees to pay, from 0 to 100
+    pub percentile: u8,
+    // upper bound for the compute unit price, in micro-lamports
+    pub max_micro_lamports: u64,
 }
 
+// getRecentPrioritizationFees accepts at most this many accounts
+const MAX_PRIORITIZATION_FEE_ACCOUNTS: usize = 128;
+
+/// How many times send_and_confirm() re-signs with a new blockhash when the previous one expired
+const BLOCKHASH_EXPIRED_RETRIES: usize = 2;
+
 pub struct TransactionBuilder<'a> {
     pub instructions: Vec<Instruction>,
     pub address_lookup_tables: Vec<AddressLookupTableAccount>,
@@ -750,35 +873,80 @@ pub struct TransactionBuilder<'a> {
 
 impl<'a> TransactionBuilder<'a> {
     pub async fn transaction(
-        self,
+        &self,
         rpc: &RpcClientAsync,
     ) -> anyhow::Result<solana_sdk::transaction::VersionedTransaction> {
         let latest_blockhash = rpc.get_latest_blockhash().await?;
-        self.transaction_with_blockhash(latest_blockhash)
+        let prio_price = match self.config.priority_fee {
+            Some(priority_fee) => Some(self.recent_prioritization_fee(rpc, priority_fee).await?),
+            None => self.config.prioritization_micro_lamports,
+        };
+        self.compile(latest_blockhash, prio_price)
     }
 
     pub fn transaction_with_blockhash(
-        mut self,
+        &self,
         blockhash: Hash,
     ) -> anyhow::Result<solana_sdk::transaction::VersionedTransaction> {
-        if let Some(prio_price) = self.config.prioritization_micro_lamports {
-            self.instructions.insert(
-                0,
-                solana_sdk::compute_budget::ComputeBudgetInstruction::set_compute_unit_price(
-                    prio_price,
-                ),
-            )
+        self.compile(blockhash, self.config.prioritization_micro_lamports)
+    }
+
+    /// The compute unit price to pay according to `config`, in micro-lamports
+    pub async fn recent_prioritization_fee(
+        &self,
+        rpc: &RpcClientAsync,
+        config: PriorityFeeConfig,
+    ) -> anyhow::Result<u64> {
+        let writable_accounts = self
+            .instructions
+            .iter()
+            .flat_map(|ix| ix.accounts.iter())
+            .filter(|meta| meta.is_writable)
+            .map(|meta| meta.pubkey)
+            .unique()
+            .take(MAX_PRIORITIZATION_FEE_ACCOUNTS)
+            .collect_vec();
+        let fees = rpc
+            .get_recent_prioritization_fees(&writable_accounts)
+            .await?
+            .iter()
+            .map(|fee| fee.prioritization_fee)
+            .sorted()
+            .collect_vec();
+        if fees.is_empty() {
+            return Ok(0);
         }
+        let index = (fees.len() - 1) * usize::from(config.percentile.min(100)) / 100;
+        Ok(fees[index].min(config.max_micro_lamports))
+    }
+
+    fn compile(
+        &self,
+        blockhash: Hash,
+        prio_price: Option<u64>,
+    ) -> anyhow::Result<solana_sdk::transaction::VersionedTransaction> {
+        use solana_sdk::compute_budget::ComputeBudgetInstruction;
+
+        let mut instructions = vec![];
+        if let Some(cu_limit) = self.config.compute_unit_limit {
+            instructions.push(ComputeBudgetInstruction::set_compute_unit_limit(cu_limit));
+        }
+        if let Some(prio_price) = prio_price {
+            instructions.push(ComputeBudgetInstruction::set_compute_unit_price(prio_price));
+        }
+        instructions.extend(self.instructions.iter().cloned());
+
         let v0_message = solana_sdk::message::v0::Message::try_compile(
             &self.payer,
-            &self.instructions,
+            &instructions,
             &self.address_lookup_tables,
             blockhash,
         )?;
         let versioned_message = solana_sdk::message::VersionedMessage::V0(v0_message);
         let signers = self
             .signers
-            .into_iter()
+            .iter()
+            .copied()
             .unique_by(|s| s.pubkey())
             .collect::<Vec<_>>();
         let tx =
@@ -796,14 +964,57 @@ impl<'a> TransactionBuilder<'a> {
             .map_err(prettify_solana_client_error)
     }
 
+    /// Re-signs with a new blockhash and sends again if the transaction expired before confirming.
     pub async fn send_and_confirm(self, client: &Client) -> anyhow::Result<Signature> {
+        let rpc = client.rpc_async();
+        let mut retries_left = BLOCKHASH_EXPIRED_RETRIES;
+        loop {
+            let tx = self.transaction(&rpc).await?;
+            // TODO: Wish we could use client.rpc_send_transaction_config here too!
+            match rpc.send_and_confirm_transaction(&tx).await {
+                Ok(signature) => return Ok(signature),
+                Err(err) if retries_left > 0 && is_blockhash_expired(&err) => {
+                    retries_left -= 1;
+                }
+                Err(err) => return Err(prettify_solana_client_error(err)),
+            }
+        }
+    }
+
+    /// Simulate the transaction, failing with OpenBookClientError::SendTransactionPreflightFailure
+    /// like a preflight check would.
+    pub async fn simulate(
+        &self,
+        client: &Client,
+    ) -> anyhow::Result<solana_client::rpc_response::RpcSimulateTransactionResult> {
         let rpc = client.rpc_async();
         let tx = self.transaction(&rpc).await?;
-        // TODO: Wish we could use client.rpc_send_transaction_config here too!
-        rpc.send_and_confirm_transaction(&tx)
+        let result = rpc
+            .simulate_transaction(&tx)
             .await
-            .map_err(prettify_solana_client_error)
+            .map_err(prettify_solana_client_error)?
+            .value;
+        if result.err.is_some() {
+            return Err(OpenBookClientError::SendTransactionPreflightFailure {
+                err: result.err,
+                logs: result.logs.unwrap_or_default(),
+            }
+            .into());
+        }
+        Ok(result)
+    }
+}
+
+fn is_blockhash_expired(err: &solana_client::client_error::ClientError) -> bool {
+    use solana_client::client_error::ClientErrorKind;
+    use solana_client::rpc_request::RpcError;
+
+    if err.get_transaction_error() == Some(TransactionError::BlockhashNotFound) {
+        return true;
     }
+    // send_and_confirm_transaction() gives up with this once the blockhash is no longer valid
+    matches!(err.kind(), ClientErrorKind::RpcError(RpcError::ForUser(msg))
+        if msg.starts_with("unable to confirm transaction"))
 }
 
 /// Do some manual unpacking on some ClientErrors
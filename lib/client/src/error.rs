@@ -0,0 +1,73 @@
+use anchor_lang::error::ERROR_CODE_OFFSET;
+use openbook_v2::error::OpenBookError;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::transaction::TransactionError;
+
+use crate::OpenBookClientError;
+
+/// The openbook-v2 error a transaction failed with, if any
+///
+/// Anchor returns the program's errors as custom instruction errors, offset by
+/// `ERROR_CODE_OFFSET`. Codes below it are anchor's own, like failed account
+/// constraints, and map to `None`.
+///
+/// The failing instruction is assumed to be an openbook-v2 one: custom errors
+/// of other programs in the same transaction can't be told apart by code.
+pub fn decode_program_error(err: TransactionError) -> Option<OpenBookError> {
+    match err {
+        TransactionError::InstructionError(_, InstructionError::Custom(code)) => code
+            .checked_sub(ERROR_CODE_OFFSET)
+            .and_then(|index| OpenBookError::try_from(index).ok()),
+        _ => None,
+    }
+}
+
+impl OpenBookClientError {
+    /// The openbook-v2 error the transaction failed with, see [`decode_program_error`]
+    pub fn program_error(&self) -> Option<OpenBookError> {
+        match self {
+            Self::SendTransactionPreflightFailure { err, .. } => {
+                err.clone().and_then(decode_program_error)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_program_error() {
+        let instruction_error =
+            |err| TransactionError::InstructionError(1, InstructionError::Custom(err));
+
+        for err in [
+            OpenBookError::SomeError,
+            OpenBookError::WouldSelfTrade,
+            OpenBookError::WouldCross,
+            OpenBookError::ExpiredBeforePlacement,
+        ] {
+            assert_eq!(
+                decode_program_error(instruction_error(err.into())),
+                Some(err)
+            );
+        }
+
+        // anchor's own errors
+        assert_eq!(decode_program_error(instruction_error(2003)), None);
+        // past the last variant
+        assert_eq!(decode_program_error(instruction_error(u32::MAX)), None);
+        assert_eq!(
+            decode_program_error(TransactionError::BlockhashNotFound),
+            None
+        );
+        assert_eq!(
+            decode_program_error(TransactionError::InstructionError(
+                0,
+                InstructionError::InvalidAccountData
+            )),
+            None
+        );
+    }
+}
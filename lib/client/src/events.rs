@@ -0,0 +1,122 @@
+//! Decoding of the events the program emits.
+//!
+//! Fills, outs and cancels are logged with `emit_stack`, so they are found in the
+//! `Program data:` lines of the logs. Places are emitted with `emit_cpi!`, which is a
+//! self-CPI to the program found in the inner instructions of the transaction.
+
+use std::str::FromStr;
+
+use anchor_lang::event::EVENT_IX_TAG_LE;
+use anchor_lang::{AnchorDeserialize, Discriminator};
+use anyhow::Context;
+use openbook_v2::logs::{CancelLog, FillLog, OutLog, PlaceLog};
+use solana_sdk::bs58;
+use solana_sdk::pubkey::Pubkey;
+use solana_transaction_status::{
+    EncodedConfirmedTransactionWithStatusMeta, UiInnerInstructions, UiInstruction,
+    UiLoadedAddresses,
+};
+
+#[derive(Debug)]
+pub enum OpenBookEvent {
+    Fill(FillLog),
+    Out(OutLog),
+    Place(PlaceLog),
+    Cancel(CancelLog),
+}
+
+/// Decode the data of an event instruction, None for other instructions and events
+pub fn decode_event(data: &[u8]) -> Option<OpenBookEvent> {
+    decode_log_event(data.strip_prefix(&EVENT_IX_TAG_LE)?)
+}
+
+/// Decode the data of a `Program data:` log line, None for other events
+pub fn decode_log_event(data: &[u8]) -> Option<OpenBookEvent> {
+    let (discriminator, mut event) = (data.get(..8)?, data.get(8..)?);
+    let event = if discriminator == FillLog::DISCRIMINATOR {
+        OpenBookEvent::Fill(FillLog::deserialize(&mut event).ok()?)
+    } else if discriminator == OutLog::DISCRIMINATOR {
+        OpenBookEvent::Out(OutLog::deserialize(&mut event).ok()?)
+    } else if discriminator == PlaceLog::DISCRIMINATOR {
+        OpenBookEvent::Place(PlaceLog::deserialize(&mut event).ok()?)
+    } else if discriminator == CancelLog::DISCRIMINATOR {
+        OpenBookEvent::Cancel(CancelLog::deserialize(&mut event).ok()?)
+    } else {
+        return None;
+    };
+    Some(event)
+}
+
+/// Events of a successful transaction, in the order they were emitted
+///
+/// The transaction must be fetched with a binary encoding.
+pub fn transaction_events(
+    tx: &EncodedConfirmedTransactionWithStatusMeta,
+) -> anyhow::Result<Vec<OpenBookEvent>> {
+    let meta = match &tx.transaction.meta {
+        Some(meta) if meta.err.is_none() => meta,
+        _ => return Ok(vec![]),
+    };
+    let transaction = tx
+        .transaction
+        .transaction
+        .decode()
+        .context("transaction must be binary encoded")?;
+
+    let mut account_keys = transaction.message.static_account_keys().to_vec();
+    let loaded_addresses: Option<&UiLoadedAddresses> = meta.loaded_addresses.as_ref().into();
+    if let Some(loaded) = loaded_addresses {
+        for key in loaded.writable.iter().chain(loaded.readonly.iter()) {
+            account_keys.push(Pubkey::from_str(key)?);
+        }
+    }
+
+    let mut event_instructions = vec![];
+    let inner_instructions: Option<&Vec<UiInnerInstructions>> =
+        meta.inner_instructions.as_ref().into();
+    for ix in inner_instructions
+        .into_iter()
+        .flatten()
+        .flat_map(|inner| &inner.instructions)
+    {
+        let ix = match ix {
+            UiInstruction::Compiled(ix) => ix,
+            UiInstruction::Parsed(_) => continue,
+        };
+        if account_keys.get(ix.program_id_index as usize) == Some(&openbook_v2::id()) {
+            event_instructions.push(bs58::decode(&ix.data).into_vec()?);
+        }
+    }
+    let mut event_instructions = event_instructions.into_iter();
+
+    // Walk the logs to interleave logged events with the self-CPI ones: every invoke of
+    // the program below the top level is one of its inner instructions, in order.
+    let program_id = openbook_v2::id().to_string();
+    let invoke_prefix = format!("Program {program_id} invoke [");
+    let mut invoked = vec![];
+    let mut events = vec![];
+    let log_messages: Option<&Vec<String>> = meta.log_messages.as_ref().into();
+    for log in log_messages.into_iter().flatten() {
+        if let Some(depth) = log.strip_prefix(&invoke_prefix) {
+            invoked.push(true);
+            if depth != "1]" {
+                let data = event_instructions
+                    .next()
+                    .context("missing inner instruction for invoke")?;
+                events.extend(decode_event(&data));
+            }
+        } else if log.starts_with("Program ") && log.contains(" invoke [") {
+            invoked.push(false);
+        } else if log.starts_with("Program ")
+            && (log.ends_with(" success") || log.contains(" failed: "))
+        {
+            invoked.pop();
+        } else if let Some(data) = log.strip_prefix("Program data: ") {
+            if invoked.last() == Some(&true) {
+                let data = base64::decode(data)?;
+                events.extend(decode_log_event(&data));
+            }
+        }
+    }
+    Ok(events)
+}
@@ -0,0 +1,148 @@
+//! Previewing the fees of a trade exactly as the program charges them, so a UI
+//! can show the amounts that will be debited and credited before sending the
+//! order.
+//!
+//! The taker pays its fee when the order matches, rounded up unless the market's
+//! [`FeeRounding`](openbook_v2::state::FeeRounding) says otherwise. The maker's
+//! fee or rebate is rounded down and paid once the fill event is consumed. A
+//! maker bid locks its fee rounded up when posted, the difference to the fee
+//! charged goes to the market when the maker settles.
+
+use openbook_v2::math;
+use openbook_v2::state::{Market, Side};
+
+/// Native amounts of a fill of `base_lots` at `price_lots`
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FeeBreakdown {
+    pub base_native: u64,
+    pub quote_native: u64,
+    pub taker_fees_native: u64,
+    pub maker_fees_native: u64,
+    pub maker_rebate_native: u64,
+    /// Maker fees locked by a maker bid in excess of the fees charged
+    pub maker_fees_roundoff_native: u64,
+    /// Share of the taker fees left once the maker rebate is paid
+    pub referrer_native: u64,
+    /// Tokens leaving the taker: quote for bids, base for asks
+    pub taker_debit_native: u64,
+    /// Tokens received by the taker: base for bids, quote for asks
+    pub taker_credit_native: u64,
+    /// Tokens leaving the maker: quote including the locked fees for bids, base
+    /// for asks
+    pub maker_debit_native: u64,
+    /// Tokens received by the maker: base for bids, with the rebate paid in quote
+    /// besides, quote net of fees and rebate for asks
+    pub maker_credit_native: u64,
+}
+
+/// Fees of a taker order on `side` filling `base_lots` of a resting order at
+/// `price_lots`, rounded like the program does
+///
+/// Self trades don't pay fees, they aren't covered. Panics on amounts the
+/// program would reject with `MathOverflow`.
+pub fn compute_fees(market: &Market, side: Side, price_lots: i64, base_lots: i64) -> FeeBreakdown {
+    let base_native = math::lots_to_native(base_lots, market.base_lot_size).unwrap();
+    let quote_native = math::quote_native(base_lots, price_lots, market.quote_lot_size).unwrap();
+
+    let taker_fees_native = market.taker_fees(quote_native);
+    let maker_fees_native = market.maker_fees_floor(quote_native);
+    let maker_rebate_native = market.maker_rebate_floor(quote_native);
+
+    let mut breakdown = FeeBreakdown {
+        base_native,
+        quote_native,
+        taker_fees_native,
+        maker_fees_native,
+        maker_rebate_native,
+        referrer_native: taker_fees_native - maker_rebate_native,
+        ..FeeBreakdown::default()
+    };
+    match side {
+        Side::Bid => {
+            breakdown.taker_debit_native = quote_native + taker_fees_native;
+            breakdown.taker_credit_native = base_native;
+            breakdown.maker_debit_native = base_native;
+            breakdown.maker_credit_native = quote_native + maker_rebate_native - maker_fees_native;
+        }
+        Side::Ask => {
+            let maker_fees_locked = market.maker_fees_ceil(quote_native);
+            breakdown.maker_fees_roundoff_native = maker_fees_locked - maker_fees_native;
+            breakdown.taker_debit_native = base_native;
+            breakdown.taker_credit_native = quote_native - taker_fees_native;
+            breakdown.maker_debit_native = quote_native + maker_fees_locked;
+            breakdown.maker_credit_native = base_native;
+        }
+    }
+    breakdown
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::__private::bytemuck::Zeroable;
+    use openbook_v2::state::FeeRounding;
+
+    // Lot sizes of the program tests, a price of 1000 quote lots per base lot is
+    // 100_000 quote native for a single base lot
+    fn market(maker_fee: i64, taker_fee: i64) -> Market {
+        let mut market = Market::zeroed();
+        market.base_lot_size = 100;
+        market.quote_lot_size = 10;
+        market.maker_fee = maker_fee;
+        market.taker_fee = taker_fee;
+        market
+    }
+
+    // Golden values, as asserted on the open orders accounts by the program tests
+    // in programs/openbook-v2/tests/cases/test_fees.rs
+    #[test]
+    fn test_compute_fees_golden() {
+        // test_fees_accrued: maker rebate, taker asks
+        let fees = compute_fees(&market(-100, 200), Side::Ask, 10_000, 1);
+        assert_eq!(fees.quote_native, 100_000);
+        assert_eq!(fees.taker_fees_native, 20);
+        assert_eq!(fees.maker_rebate_native, 10);
+        assert_eq!(fees.referrer_native, 10);
+        assert_eq!(fees.taker_credit_native, 99_980);
+        assert_eq!(fees.maker_debit_native, 100_000);
+        assert_eq!(fees.maker_credit_native, 100);
+
+        // test_maker_fees: maker bids, taker asks
+        let fees = compute_fees(&market(200, 400), Side::Ask, 10_000, 1);
+        assert_eq!(fees.taker_fees_native, 40);
+        assert_eq!(fees.maker_fees_native, 20);
+        assert_eq!(fees.maker_fees_roundoff_native, 0);
+        assert_eq!(fees.maker_debit_native, 100_020);
+        assert_eq!(fees.taker_credit_native, 99_960);
+
+        // test_maker_fees_ask: maker asks, taker bids
+        let fees = compute_fees(&market(200, 400), Side::Bid, 10_000, 1);
+        assert_eq!(fees.taker_debit_native, 100_040);
+        assert_eq!(fees.taker_credit_native, 100);
+        assert_eq!(fees.maker_credit_native, 99_980);
+
+        // test_taker_fee_rounding
+        let mut market = market(0, 205);
+        assert_eq!(
+            compute_fees(&market, Side::Bid, 10_000, 1).taker_fees_native,
+            21
+        );
+        market.taker_fee_rounding = FeeRounding::Down.into();
+        assert_eq!(
+            compute_fees(&market, Side::Bid, 10_000, 1).taker_fees_native,
+            20
+        );
+    }
+
+    #[test]
+    fn test_compute_fees_maker_roundoff() {
+        // 1 bps of 12_340 is 1.234, the maker bid locks 2 and pays 1
+        let fees = compute_fees(&market(100, 100), Side::Ask, 1_234, 1);
+        assert_eq!(fees.quote_native, 12_340);
+        assert_eq!(fees.maker_fees_native, 1);
+        assert_eq!(fees.maker_fees_roundoff_native, 1);
+        assert_eq!(fees.maker_debit_native, 12_342);
+        assert_eq!(fees.taker_fees_native, 2);
+        assert_eq!(fees.taker_credit_native, 12_338);
+    }
+}
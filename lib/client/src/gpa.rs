@@ -1,6 +1,6 @@
 use anchor_lang::{AccountDeserialize, Discriminator};
 
-use openbook_v2::state::OpenOrdersAccount;
+use openbook_v2::state::{OpenOrdersAccount, OpenOrdersIndexer};
 
 use solana_account_decoder::UiAccountEncoding;
 use solana_client::nonblocking::rpc_client::RpcClient as RpcClientAsync;
@@ -39,6 +39,35 @@ pub async fn fetch_openbook_accounts(
         .collect()
 }
 
+/// Like `fetch_openbook_accounts()`, but uses the owner's `OpenOrdersIndexer`
+/// instead of scanning all program accounts.
+pub async fn fetch_indexed_openbook_accounts(
+    rpc: &RpcClientAsync,
+    program: Pubkey,
+    owner: Pubkey,
+) -> anyhow::Result<Vec<(Pubkey, OpenOrdersAccount)>> {
+    let indexer_address =
+        Pubkey::find_program_address(&[b"OpenOrdersIndexer".as_ref(), owner.as_ref()], &program).0;
+    let indexer: OpenOrdersIndexer = match fetch_anchor_account_opt(rpc, &indexer_address).await? {
+        Some(indexer) => indexer,
+        None => return Ok(vec![]),
+    };
+
+    let mut accounts = vec![];
+    for chunk in indexer.addresses.chunks(100) {
+        let fetched = rpc.get_multiple_accounts(chunk).await?;
+        for (key, account) in chunk.iter().zip(fetched) {
+            let account = account
+                .ok_or_else(|| anyhow::anyhow!("indexed open orders account {} not found", key))?;
+            accounts.push((
+                *key,
+                OpenOrdersAccount::try_deserialize(&mut (&account.data as &[u8]))?,
+            ));
+        }
+    }
+    Ok(accounts)
+}
+
 pub async fn fetch_anchor_account<T: AccountDeserialize>(
     rpc: &RpcClientAsync,
     address: &Pubkey,
@@ -47,6 +76,21 @@ pub async fn fetch_anchor_account<T: AccountDeserialize>(
     Ok(T::try_deserialize(&mut (&account.data as &[u8]))?)
 }
 
+/// Like `fetch_anchor_account`, `None` if the account doesn't exist
+pub async fn fetch_anchor_account_opt<T: AccountDeserialize>(
+    rpc: &RpcClientAsync,
+    address: &Pubkey,
+) -> anyhow::Result<Option<T>> {
+    match rpc
+        .get_account_with_commitment(address, rpc.commitment())
+        .await?
+        .value
+    {
+        Some(account) => Ok(Some(T::try_deserialize(&mut (&account.data as &[u8]))?)),
+        None => Ok(None),
+    }
+}
+
 async fn _fetch_anchor_accounts<T: AccountDeserialize + Discriminator>(
     rpc: &RpcClientAsync,
     program: Pubkey,
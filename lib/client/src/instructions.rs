@@ -0,0 +1,409 @@
+//! Builders for OpenBook instructions.
+//!
+//! They fill in the accounts that can be derived from the [`Market`] (book sides,
+//! event heap, vaults, oracles, event authority) and the owner's associated token
+//! accounts, so only the order itself needs to be described:
+//!
+//! ```ignore
+//! let ix = PlaceOrder::new(market_address, &market, open_orders_account, owner)
+//!     .side(Side::Bid)
+//!     .price_ui(1.23)
+//!     .size_ui(10.0)
+//!     .build()?;
+//! ```
+
+use anchor_lang::prelude::System;
+use anchor_lang::{Id, InstructionData, ToAccountMetas};
+use anchor_spl::token::Token;
+use anyhow::Result;
+use fixed::types::I80F48;
+use openbook_v2::state::{
+    Market, Orderbook, PlaceOrderType, SelfTradeBehavior, Side, FEES_SCALE_FACTOR,
+};
+use openbook_v2::{pricemath, PlaceOrderArgs};
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::pubkey::Pubkey;
+use spl_associated_token_account::get_associated_token_address;
+
+use crate::book::remaining_accounts_to_crank;
+use crate::util::event_authority;
+
+pub struct PlaceOrder {
+    market_address: Pubkey,
+    market: Market,
+    open_orders_account: Pubkey,
+    owner: Pubkey,
+    user_token_account: Option<Pubkey>,
+    open_orders_admin: Option<Pubkey>,
+    price_ui: Option<f64>,
+    size_ui: Option<f64>,
+    max_quote_lots_including_fees: Option<i64>,
+    args: PlaceOrderArgs,
+    remaining_accounts: Vec<Pubkey>,
+}
+
+impl PlaceOrder {
+    pub fn new(
+        market_address: Pubkey,
+        market: &Market,
+        open_orders_account: Pubkey,
+        owner: Pubkey,
+    ) -> Self {
+        Self {
+            market_address,
+            market: *market,
+            open_orders_account,
+            owner,
+            user_token_account: None,
+            open_orders_admin: None,
+            price_ui: None,
+            size_ui: None,
+            max_quote_lots_including_fees: None,
+            args: PlaceOrderArgs {
+                side: Side::Bid,
+                price_lots: 0,
+                max_base_lots: 0,
+                max_quote_lots_including_fees: 0,
+                client_order_id: 0,
+                order_type: PlaceOrderType::Limit,
+                expiry_timestamp: 0,
+                self_trade_behavior: SelfTradeBehavior::default(),
+                limit: 10,
+            },
+            remaining_accounts: vec![],
+        }
+    }
+
+    pub fn side(mut self, side: Side) -> Self {
+        self.args.side = side;
+        self
+    }
+
+    pub fn price_lots(mut self, price_lots: i64) -> Self {
+        self.args.price_lots = price_lots;
+        self.price_ui = None;
+        self
+    }
+
+    pub fn price_ui(mut self, price: f64) -> Self {
+        self.price_ui = Some(price);
+        self
+    }
+
+    pub fn max_base_lots(mut self, max_base_lots: i64) -> Self {
+        self.args.max_base_lots = max_base_lots;
+        self.size_ui = None;
+        self
+    }
+
+    pub fn size_ui(mut self, size: f64) -> Self {
+        self.size_ui = Some(size);
+        self
+    }
+
+    /// Defaults to the quote needed to buy `max_base_lots` at the limit price,
+    /// including taker fees, for bids and to no limit for asks.
+    pub fn max_quote_lots_including_fees(mut self, max_quote_lots_including_fees: i64) -> Self {
+        self.max_quote_lots_including_fees = Some(max_quote_lots_including_fees);
+        self
+    }
+
+    pub fn client_order_id(mut self, client_order_id: u64) -> Self {
+        self.args.client_order_id = client_order_id;
+        self
+    }
+
+    pub fn order_type(mut self, order_type: PlaceOrderType) -> Self {
+        self.args.order_type = order_type;
+        self
+    }
+
+    pub fn expiry_timestamp(mut self, expiry_timestamp: u64) -> Self {
+        self.args.expiry_timestamp = expiry_timestamp;
+        self
+    }
+
+    pub fn self_trade_behavior(mut self, self_trade_behavior: SelfTradeBehavior) -> Self {
+        self.args.self_trade_behavior = self_trade_behavior;
+        self
+    }
+
+    pub fn limit(mut self, limit: u8) -> Self {
+        self.args.limit = limit;
+        self
+    }
+
+    /// Defaults to the owner's associated token account of the mint paid with.
+    pub fn user_token_account(mut self, user_token_account: Pubkey) -> Self {
+        self.user_token_account = Some(user_token_account);
+        self
+    }
+
+    /// Required to co-sign on permissioned markets, unless the open orders account
+    /// is allowlisted.
+    pub fn open_orders_admin(mut self, open_orders_admin: Pubkey) -> Self {
+        self.open_orders_admin = Some(open_orders_admin);
+        self
+    }
+
+    /// Pass the open orders accounts of the makers this order would match
+    /// against, so their fills are processed right away.
+    pub fn crank_makers(
+        mut self,
+        book: Orderbook,
+        oracle_price: Option<I80F48>,
+        now_ts: u64,
+    ) -> Result<Self> {
+        self.remaining_accounts =
+            remaining_accounts_to_crank(book, self.args.side, &self.market, oracle_price, now_ts)?;
+        Ok(self)
+    }
+
+    pub fn build(self) -> Result<Instruction> {
+        let market = &self.market;
+        let mut args = self.args;
+        if let Some(price) = self.price_ui {
+            args.price_lots = pricemath::price_ui_to_lots(
+                price,
+                market.base_decimals,
+                market.quote_decimals,
+                market.base_lot_size,
+                market.quote_lot_size,
+            );
+        }
+        if let Some(size) = self.size_ui {
+            args.max_base_lots =
+                pricemath::size_ui_to_lots(size, market.base_decimals, market.base_lot_size);
+        }
+        args.max_quote_lots_including_fees = match self.max_quote_lots_including_fees {
+            Some(max_quote_lots) => max_quote_lots,
+            None if args.side == Side::Ask => market.max_quote_lots(),
+            None => {
+                let quote_lots = (args.price_lots as i128) * (args.max_base_lots as i128);
+                let fees = (quote_lots * market.taker_fee as i128 + FEES_SCALE_FACTOR - 1)
+                    / FEES_SCALE_FACTOR;
+                i64::try_from(quote_lots + fees.max(0))?
+            }
+        };
+
+        let (market_vault, mint) = match args.side {
+            Side::Bid => (market.market_quote_vault, market.quote_mint),
+            Side::Ask => (market.market_base_vault, market.base_mint),
+        };
+        let user_token_account = self
+            .user_token_account
+            .unwrap_or_else(|| get_associated_token_address(&self.owner, &mint));
+
+        let mut accounts = openbook_v2::accounts::PlaceOrder {
+            signer: self.owner,
+            open_orders_account: self.open_orders_account,
+            open_orders_admin: self.open_orders_admin,
+            user_token_account,
+            market: self.market_address,
+            bids: market.bids,
+            asks: market.asks,
+            event_heap: market.event_heap,
+            market_vault,
+            oracle_a: market.oracle_a.into(),
+            oracle_b: market.oracle_b.into(),
+            token_program: Token::id(),
+            token_gate: None,
+            gate_token_account: None,
+            event_authority: event_authority(),
+            program: openbook_v2::id(),
+        }
+        .to_account_metas(None);
+        accounts.extend(
+            self.remaining_accounts
+                .iter()
+                .map(|&pubkey| AccountMeta::new(pubkey, false)),
+        );
+
+        Ok(Instruction {
+            program_id: openbook_v2::id(),
+            accounts,
+            data: openbook_v2::instruction::PlaceOrder { args }.data(),
+        })
+    }
+}
+
+pub struct CancelOrder {
+    market_address: Pubkey,
+    market: Market,
+    open_orders_account: Pubkey,
+    signer: Pubkey,
+}
+
+impl CancelOrder {
+    pub fn new(
+        market_address: Pubkey,
+        market: &Market,
+        open_orders_account: Pubkey,
+        signer: Pubkey,
+    ) -> Self {
+        Self {
+            market_address,
+            market: *market,
+            open_orders_account,
+            signer,
+        }
+    }
+
+    fn accounts(&self) -> Vec<AccountMeta> {
+        openbook_v2::accounts::CancelOrder {
+            signer: self.signer,
+            open_orders_account: self.open_orders_account,
+            market: self.market_address,
+            bids: self.market.bids,
+            asks: self.market.asks,
+        }
+        .to_account_metas(None)
+    }
+
+    pub fn by_order_id(self, order_id: u128) -> Instruction {
+        Instruction {
+            program_id: openbook_v2::id(),
+            accounts: self.accounts(),
+            data: openbook_v2::instruction::CancelOrder { order_id }.data(),
+        }
+    }
+
+    pub fn by_client_order_id(self, client_order_id: u64) -> Instruction {
+        Instruction {
+            program_id: openbook_v2::id(),
+            accounts: self.accounts(),
+            data: openbook_v2::instruction::CancelOrderByClientOrderId { client_order_id }.data(),
+        }
+    }
+
+    pub fn all(self, side_option: Option<Side>, limit: u8) -> Instruction {
+        Instruction {
+            program_id: openbook_v2::id(),
+            accounts: self.accounts(),
+            data: openbook_v2::instruction::CancelAllOrders { side_option, limit }.data(),
+        }
+    }
+}
+
+pub struct SettleFunds {
+    market_address: Pubkey,
+    market: Market,
+    open_orders_account: Pubkey,
+    owner: Pubkey,
+    penalty_payer: Pubkey,
+    user_base_account: Option<Pubkey>,
+    user_quote_account: Option<Pubkey>,
+    referrer_account: Option<Pubkey>,
+}
+
+impl SettleFunds {
+    pub fn new(
+        market_address: Pubkey,
+        market: &Market,
+        open_orders_account: Pubkey,
+        owner: Pubkey,
+    ) -> Self {
+        Self {
+            market_address,
+            market: *market,
+            open_orders_account,
+            owner,
+            penalty_payer: owner,
+            user_base_account: None,
+            user_quote_account: None,
+            referrer_account: None,
+        }
+    }
+
+    pub fn penalty_payer(mut self, penalty_payer: Pubkey) -> Self {
+        self.penalty_payer = penalty_payer;
+        self
+    }
+
+    /// Defaults to the owner's associated token account.
+    pub fn user_base_account(mut self, user_base_account: Pubkey) -> Self {
+        self.user_base_account = Some(user_base_account);
+        self
+    }
+
+    /// Defaults to the owner's associated token account.
+    pub fn user_quote_account(mut self, user_quote_account: Pubkey) -> Self {
+        self.user_quote_account = Some(user_quote_account);
+        self
+    }
+
+    pub fn referrer_account(mut self, referrer_account: Pubkey) -> Self {
+        self.referrer_account = Some(referrer_account);
+        self
+    }
+
+    pub fn build(self) -> Instruction {
+        let market = &self.market;
+        let accounts = openbook_v2::accounts::SettleFunds {
+            owner: self.owner,
+            penalty_payer: self.penalty_payer,
+            open_orders_account: self.open_orders_account,
+            market: self.market_address,
+            market_authority: market.market_authority,
+            market_base_vault: market.market_base_vault,
+            market_quote_vault: market.market_quote_vault,
+            user_base_account: self
+                .user_base_account
+                .unwrap_or_else(|| get_associated_token_address(&self.owner, &market.base_mint)),
+            user_quote_account: self
+                .user_quote_account
+                .unwrap_or_else(|| get_associated_token_address(&self.owner, &market.quote_mint)),
+            referrer_account: self.referrer_account,
+            token_program: Token::id(),
+            system_program: System::id(),
+        };
+
+        Instruction {
+            program_id: openbook_v2::id(),
+            accounts: accounts.to_account_metas(None),
+            data: openbook_v2::instruction::SettleFunds {}.data(),
+        }
+    }
+}
+
+pub struct ConsumeEvents {
+    market_address: Pubkey,
+    market: Market,
+    open_orders_accounts: Vec<Pubkey>,
+}
+
+impl ConsumeEvents {
+    pub fn new(market_address: Pubkey, market: &Market) -> Self {
+        Self {
+            market_address,
+            market: *market,
+            open_orders_accounts: vec![],
+        }
+    }
+
+    /// Open orders accounts referenced by the events to consume.
+    pub fn open_orders_accounts(mut self, open_orders_accounts: Vec<Pubkey>) -> Self {
+        self.open_orders_accounts = open_orders_accounts;
+        self
+    }
+
+    pub fn build(self, limit: usize) -> Instruction {
+        let mut accounts = openbook_v2::accounts::ConsumeEvents {
+            consume_events_admin: self.market.consume_events_admin.into(),
+            market: self.market_address,
+            event_heap: self.market.event_heap,
+        }
+        .to_account_metas(None);
+        accounts.extend(
+            self.open_orders_accounts
+                .iter()
+                .map(|&pubkey| AccountMeta::new(pubkey, false)),
+        );
+
+        Instruction {
+            program_id: openbook_v2::id(),
+            accounts,
+            data: openbook_v2::instruction::ConsumeEvents { limit }.data(),
+        }
+    }
+}
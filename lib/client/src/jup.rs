@@ -13,7 +13,7 @@ use openbook_v2::{
 use crate::{
     book::{amounts_from_book, Amounts},
     remaining_accounts_to_crank,
-    util::ZeroCopyDeserialize,
+    util::{event_authority, ZeroCopyDeserialize},
 };
 use jupiter_amm_interface::{
     AccountMap, Amm, KeyedAccount, Quote, QuoteParams, Side as JupiterSide, Swap,
@@ -117,11 +117,14 @@ impl Amm for OpenBookMarket {
                 Some(accounts_zerocopy::KeyedAccount { key, account })
             };
 
-        self.oracle_price = self.market.oracle_price(
-            oracle_acc(self.market.oracle_a).as_ref(),
-            oracle_acc(self.market.oracle_b).as_ref(),
-            clock.slot,
-        )?;
+        self.oracle_price = self
+            .market
+            .oracle_price(
+                oracle_acc(self.market.oracle_a).as_ref(),
+                oracle_acc(self.market.oracle_b).as_ref(),
+                clock.slot,
+            )?
+            .map(|oracle_price| oracle_price.price);
 
         self.timestamp = clock.unix_timestamp.try_into().unwrap();
 
@@ -146,13 +149,14 @@ impl Amm for OpenBookMarket {
 
         // quote params can have exact in (which is implemented here) and exact out which is not implemented
         // check with jupiter to add to their API exact_out support
+        // the input is rounded down to whole lots, so the swap never takes more than in_amount
         let (max_base_lots, max_quote_lots_including_fees) = match side {
             Side::Bid => (
                 self.market.max_base_lots(),
-                input_amount + (self.market.quote_lot_size - 1) / self.market.quote_lot_size,
+                input_amount / self.market.quote_lot_size,
             ),
             Side::Ask => (
-                input_amount + (self.market.base_lot_size - 1) / self.market.base_lot_size,
+                input_amount / self.market.base_lot_size,
                 self.market.max_quote_lots(),
             ),
         };
@@ -171,7 +175,7 @@ impl Amm for OpenBookMarket {
             max_quote_lots_including_fees,
             &self.market,
             self.oracle_price,
-            0,
+            self.timestamp,
         )?;
 
         let (in_amount, out_amount) = match side {
@@ -241,6 +245,10 @@ impl Amm for OpenBookMarket {
                 token_program: Token::id(),
                 system_program: System::id(),
                 open_orders_admin: None,
+                taker_program_allowlist: None,
+                instructions_sysvar: None,
+                event_authority: event_authority(),
+                program: openbook_v2::id(),
             };
 
             let mut account_metas = accounts.to_account_metas(None);
@@ -464,6 +472,10 @@ mod test {
                             token_program: Token::id(),
                             system_program: System::id(),
                             open_orders_admin: None,
+                            taker_program_allowlist: None,
+                            instructions_sysvar: None,
+                            event_authority: event_authority(),
+                            program: openbook_v2::id(),
                         },
                         None,
                     ),
@@ -474,6 +486,7 @@ mod test {
                                 price_lots: i64::MAX,
                                 max_base_lots,
                                 max_quote_lots_including_fees,
+                                client_order_id: 0,
                                 order_type: openbook_v2::state::PlaceOrderType::Market,
                                 limit: MAXIMUM_TAKEN_ORDERS,
                             },
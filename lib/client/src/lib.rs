@@ -2,6 +2,7 @@ pub use account_fetcher::*;
 pub use book::*;
 pub use client::*;
 pub use context::*;
+pub use error::*;
 pub use jup::*;
 pub use util::*;
 
@@ -12,7 +13,14 @@ pub mod chain_data;
 mod chain_data_fetcher;
 mod client;
 mod context;
-mod gpa;
+mod error;
+pub mod events;
+pub mod fees;
+pub mod gpa;
+pub mod instructions;
 mod jup;
+pub mod lookup_table;
+pub mod migration;
+pub mod replay;
 pub mod snapshot_source;
 mod util;
@@ -0,0 +1,145 @@
+//! Address lookup tables holding a market's static accounts.
+//!
+//! Transactions placing several orders or cranking many makers exceed the legacy
+//! account limit. With the market's accounts in a lookup table, pass the
+//! [`AddressLookupTableAccount`] to [`TransactionBuilder`] to send a v0 transaction.
+
+use anchor_lang::prelude::System;
+use anchor_lang::Id;
+use anchor_spl::token::Token;
+use anyhow::Context;
+use itertools::Itertools;
+use openbook_v2::state::Market;
+use solana_client::nonblocking::rpc_client::RpcClient as RpcClientAsync;
+use solana_sdk::address_lookup_table::instruction::{create_lookup_table, extend_lookup_table};
+use solana_sdk::address_lookup_table::state::AddressLookupTable;
+use solana_sdk::address_lookup_table_account::AddressLookupTableAccount;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signature};
+use solana_sdk::signer::Signer;
+
+use crate::client::{Client, TransactionBuilder};
+use crate::util::event_authority;
+
+// keeps extend transactions below the packet size limit
+const MAX_ADDRESSES_PER_EXTEND: usize = 20;
+
+/// Accounts of a market that don't depend on the user
+pub fn market_lookup_table_addresses(market_address: Pubkey, market: &Market) -> Vec<Pubkey> {
+    let mut addresses = vec![
+        market_address,
+        market.market_authority,
+        market.bids,
+        market.asks,
+        market.event_heap,
+        market.market_base_vault,
+        market.market_quote_vault,
+        market.base_mint,
+        market.quote_mint,
+        event_authority(),
+        openbook_v2::id(),
+        Token::id(),
+        System::id(),
+    ];
+    addresses.extend(
+        [market.oracle_a, market.oracle_b]
+            .into_iter()
+            .filter_map(Option::<Pubkey>::from),
+    );
+    addresses
+}
+
+/// Instructions adding `addresses` to an existing lookup table, in chunks that fit
+/// in one transaction each
+pub fn extend_lookup_table_instructions(
+    lookup_table: Pubkey,
+    authority: Pubkey,
+    payer: Pubkey,
+    addresses: &[Pubkey],
+) -> Vec<Instruction> {
+    addresses
+        .chunks(MAX_ADDRESSES_PER_EXTEND)
+        .map(|chunk| extend_lookup_table(lookup_table, authority, Some(payer), chunk.to_vec()))
+        .collect()
+}
+
+/// Add the market's accounts to `lookup_table`, skipping the ones already in it
+pub async fn extend_market_lookup_table(
+    client: &Client,
+    lookup_table: Pubkey,
+    authority: &Keypair,
+    market_address: Pubkey,
+    market: &Market,
+) -> anyhow::Result<Vec<Signature>> {
+    let rpc = client.rpc_async();
+    let existing = fetch_lookup_table(&rpc, lookup_table).await?.addresses;
+    let new_addresses = market_lookup_table_addresses(market_address, market)
+        .into_iter()
+        .filter(|address| !existing.contains(address))
+        .unique()
+        .collect_vec();
+
+    let payer = &*client.fee_payer;
+    let mut signatures = vec![];
+    for ix in extend_lookup_table_instructions(
+        lookup_table,
+        authority.pubkey(),
+        payer.pubkey(),
+        &new_addresses,
+    ) {
+        let signature = TransactionBuilder {
+            instructions: vec![ix],
+            address_lookup_tables: vec![],
+            payer: payer.pubkey(),
+            signers: vec![authority, payer],
+            config: client.transaction_builder_config,
+        }
+        .send_and_confirm(client)
+        .await?;
+        signatures.push(signature);
+    }
+    Ok(signatures)
+}
+
+/// Create a lookup table owned by `authority` and fill it with the market's accounts
+///
+/// A new table can only be used in transactions from the slot after it was extended.
+pub async fn create_market_lookup_table(
+    client: &Client,
+    authority: &Keypair,
+    market_address: Pubkey,
+    market: &Market,
+) -> anyhow::Result<Pubkey> {
+    let rpc = client.rpc_async();
+    let payer = &*client.fee_payer;
+    let recent_slot = rpc.get_slot().await?;
+    let (create_ix, lookup_table) =
+        create_lookup_table(authority.pubkey(), payer.pubkey(), recent_slot);
+
+    TransactionBuilder {
+        instructions: vec![create_ix],
+        address_lookup_tables: vec![],
+        payer: payer.pubkey(),
+        signers: vec![authority, payer],
+        config: client.transaction_builder_config,
+    }
+    .send_and_confirm(client)
+    .await?;
+
+    extend_market_lookup_table(client, lookup_table, authority, market_address, market).await?;
+    Ok(lookup_table)
+}
+
+pub async fn fetch_lookup_table(
+    rpc: &RpcClientAsync,
+    address: Pubkey,
+) -> anyhow::Result<AddressLookupTableAccount> {
+    let account = rpc.get_account(&address).await?;
+    let table = AddressLookupTable::deserialize(&account.data)
+        .with_context(|| format!("deserializing lookup table {}", address))?;
+    Ok(AddressLookupTableAccount {
+        key: address,
+        addresses: table.addresses.to_vec(),
+    })
+}
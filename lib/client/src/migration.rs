@@ -0,0 +1,105 @@
+//! Migrating the resting orders of a market to a successor with other lot sizes.
+//!
+//! Lot sizes can't change once a market is created. When prices moved so much that
+//! they don't fit anymore, the market's admin:
+//! 1. creates a market of the same token pair with new lot sizes, see
+//!    [`OpenBookClient::create_market`](crate::OpenBookClient::create_market),
+//! 2. links both in the old market's metadata with
+//!    [`OpenBookClient::set_successor_market`](crate::OpenBookClient::set_successor_market)
+//!    and switches the old market to cancel only,
+//! 3. publishes a [`snapshot_resting_orders`] of the old book, so makers can place
+//!    their orders again on the successor with [`rescale_order`].
+
+use anchor_lang::prelude::Pubkey;
+use fixed::types::I80F48;
+use openbook_v2::state::{BookSideOrderTree, Market, Orderbook, Side};
+
+/// A resting order in native units, independent of the lot sizes of its market
+#[derive(Clone, Debug, PartialEq)]
+pub struct RestingOrderSnapshot {
+    /// Open orders account owning the order
+    pub owner: Pubkey,
+    pub side: Side,
+    pub order_id: u128,
+    pub client_order_id: u64,
+    /// Quote native per base native, at the oracle price of the snapshot for
+    /// oracle pegged orders
+    pub price: I80F48,
+    pub base_native: u64,
+    pub is_oracle_pegged: bool,
+}
+
+/// Valid resting orders of the book, best bids first then best asks
+///
+/// Orders moved to book side pages aren't included.
+pub fn snapshot_resting_orders(
+    book: &Orderbook,
+    market: &Market,
+    oracle_price_lots: Option<i64>,
+    now_ts: u64,
+) -> Vec<RestingOrderSnapshot> {
+    [Side::Bid, Side::Ask]
+        .into_iter()
+        .flat_map(|side| {
+            book.bookside(side)
+                .iter_valid(now_ts, oracle_price_lots)
+                .map(move |item| RestingOrderSnapshot {
+                    owner: item.node.owner,
+                    side,
+                    order_id: item.node.key,
+                    client_order_id: item.node.client_order_id,
+                    price: market.lot_to_native_price(item.price_lots),
+                    base_native: (item.node.quantity * market.base_lot_size) as u64,
+                    is_oracle_pegged: item.handle.order_tree == BookSideOrderTree::OraclePegged,
+                })
+        })
+        .collect()
+}
+
+/// Price and base lots of `order` on the `successor` market, `None` if it's less
+/// than a base lot or its price is less than a price lot
+///
+/// Prices are rounded away from the spread, bids down and asks up, and quantities
+/// down, so no rescaled order is more aggressive than the original.
+pub fn rescale_order(order: &RestingOrderSnapshot, successor: &Market) -> Option<(i64, i64)> {
+    let price_lots = order.price * I80F48::from_num(successor.base_lot_size)
+        / I80F48::from_num(successor.quote_lot_size);
+    let price_lots: i64 = match order.side {
+        Side::Bid => price_lots.floor(),
+        Side::Ask => price_lots.ceil(),
+    }
+    .checked_to_num()?;
+    let base_lots = (order.base_native / successor.base_lot_size as u64) as i64;
+
+    (price_lots > 0 && base_lots > 0).then_some((price_lots, base_lots))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::__private::bytemuck::Zeroable;
+
+    #[test]
+    fn test_rescale_order() {
+        let mut successor = Market::zeroed();
+        successor.base_lot_size = 1_000;
+        successor.quote_lot_size = 100;
+
+        let mut order = RestingOrderSnapshot {
+            owner: Pubkey::default(),
+            side: Side::Bid,
+            order_id: 0,
+            client_order_id: 0,
+            price: I80F48::from_num(1.25),
+            base_native: 2_500,
+            is_oracle_pegged: false,
+        };
+        assert_eq!(rescale_order(&order, &successor), Some((12, 2)));
+
+        order.side = Side::Ask;
+        assert_eq!(rescale_order(&order, &successor), Some((13, 2)));
+
+        order.base_native = 999;
+        assert_eq!(rescale_order(&order, &successor), None);
+    }
+}
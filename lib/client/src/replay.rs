@@ -0,0 +1,384 @@
+//! Rebuilding the resting orders of a market from its event logs, to check an
+//! indexer against the chain or to find out how a book got into some state.
+//!
+//! Orders are added by `PlaceLog`, reduced by the maker side of `FillLog` and
+//! removed by `OutLog` and `CancelLog`. Fill and out logs don't carry the order id,
+//! their order is the one of the maker with the same side and client order id,
+//! preferring the one at the logged price and then the oldest one, the same the
+//! matching would pick.
+//!
+//! Orders removed without an event, by `prune_orders`, the `expire_orders` crank or
+//! when a market is settled expired, and the orders on book side pages aren't
+//! tracked: they show up as mismatches against a snapshot.
+
+use std::collections::BTreeMap;
+
+use anchor_lang::prelude::Pubkey;
+use openbook_v2::state::{
+    fixed_price_data, oracle_pegged_price_data, BookSide, BookSideOrderTree, Side,
+};
+
+use crate::events::OpenBookEvent;
+
+/// A resting order, as known to the replay or read from a book side
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReplayedOrder {
+    pub side: Side,
+    pub order_id: u128,
+    /// Open orders account owning the order
+    pub owner: Pubkey,
+    pub client_order_id: u64,
+    /// Base lots left
+    pub quantity: i64,
+}
+
+impl ReplayedOrder {
+    fn price_data(&self) -> u64 {
+        (self.order_id >> 64) as u64
+    }
+
+    /// Sequence number of the order, lower for older orders
+    fn seq_num(&self) -> u64 {
+        let seq_num = self.order_id as u64;
+        match self.side {
+            Side::Bid => !seq_num,
+            Side::Ask => seq_num,
+        }
+    }
+}
+
+/// Key of [`ReplayBook::orders`]
+pub fn order_key(side: Side, order_id: u128) -> (u8, u128) {
+    (side.into(), order_id)
+}
+
+/// Resting orders of a market, by [`order_key`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReplayBook {
+    pub market: Pubkey,
+    pub base_lot_size: i64,
+    pub orders: BTreeMap<(u8, u128), ReplayedOrder>,
+}
+
+/// Differences between the replayed book and a snapshot, see [`ReplayBook::diff`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReplayMismatch {
+    pub replayed: Option<ReplayedOrder>,
+    pub snapshot: Option<ReplayedOrder>,
+}
+
+impl ReplayBook {
+    /// The empty book of a new market
+    pub fn new(market: Pubkey, base_lot_size: i64) -> Self {
+        Self {
+            market,
+            base_lot_size,
+            orders: BTreeMap::new(),
+        }
+    }
+
+    /// The orders on the book sides of `market`, including expired ones
+    pub fn from_book_sides(
+        market: Pubkey,
+        base_lot_size: i64,
+        bids: &BookSide,
+        asks: &BookSide,
+    ) -> Self {
+        let mut book = Self::new(market, base_lot_size);
+        for (side, bookside) in [(Side::Bid, bids), (Side::Ask, asks)] {
+            for tree in [BookSideOrderTree::Fixed, BookSideOrderTree::OraclePegged] {
+                for (_, node) in bookside.nodes.iter(bookside.root(tree)) {
+                    book.insert(ReplayedOrder {
+                        side,
+                        order_id: node.key,
+                        owner: node.owner,
+                        client_order_id: node.client_order_id,
+                        quantity: node.quantity,
+                    });
+                }
+            }
+        }
+        book
+    }
+
+    fn insert(&mut self, order: ReplayedOrder) {
+        self.orders
+            .insert(order_key(order.side, order.order_id), order);
+    }
+
+    /// The order a fill or out log of `owner` refers to, see the module docs
+    fn find(
+        &self,
+        side: Side,
+        owner: Pubkey,
+        client_order_id: u64,
+        price_data: Option<u64>,
+    ) -> Option<(u8, u128)> {
+        let candidates = || {
+            self.orders.values().filter(move |order| {
+                order.side == side
+                    && order.owner == owner
+                    && order.client_order_id == client_order_id
+            })
+        };
+        let at_price = candidates()
+            .filter(|order| Some(order.price_data()) == price_data)
+            .min_by_key(|order| order.seq_num());
+        at_price
+            .or_else(|| candidates().min_by_key(|order| order.seq_num()))
+            .map(|order| order_key(order.side, order.order_id))
+    }
+
+    /// Apply an event, ignoring the ones of other markets
+    ///
+    /// Fails when the event refers to an order that isn't on the book, meaning
+    /// events are missing or out of order.
+    pub fn apply(&mut self, event: &OpenBookEvent) -> anyhow::Result<()> {
+        match event {
+            OpenBookEvent::Place(place) if place.market == self.market => {
+                if let Some(order_id) = place.order_id {
+                    let quantity = place.base_posted_native as i64 / self.base_lot_size;
+                    if quantity > 0 {
+                        self.insert(ReplayedOrder {
+                            side: Side::try_from(place.side)?,
+                            order_id,
+                            owner: place.owner,
+                            client_order_id: place.client_order_id,
+                            quantity,
+                        });
+                    }
+                }
+            }
+            OpenBookEvent::Fill(fill) if fill.market == self.market => {
+                let side = Side::try_from(fill.taker_side)?.invert_side();
+                let price_data = fixed_price_data(fill.price).ok();
+                let key = self
+                    .find(side, fill.maker, fill.maker_client_order_id, price_data)
+                    .ok_or_else(|| {
+                        anyhow::anyhow!("fill {} of a missing maker order", fill.seq_num)
+                    })?;
+                let order = self.orders.get_mut(&key).unwrap();
+                order.quantity -= fill.quantity;
+                if fill.maker_out || order.quantity <= 0 {
+                    self.orders.remove(&key);
+                }
+            }
+            OpenBookEvent::Out(out) if out.market == self.market => {
+                let price_data = if out.peg_limit == -1 {
+                    fixed_price_data(out.price).ok()
+                } else {
+                    Some(oracle_pegged_price_data(out.price))
+                };
+                let key = self
+                    .find(
+                        Side::try_from(out.side)?,
+                        out.owner,
+                        out.client_order_id,
+                        price_data,
+                    )
+                    .ok_or_else(|| anyhow::anyhow!("out {} of a missing order", out.seq_num))?;
+                self.orders.remove(&key);
+            }
+            OpenBookEvent::Cancel(cancel) if cancel.market == self.market => {
+                let key = order_key(Side::try_from(cancel.side)?, cancel.order_id);
+                self.orders.remove(&key).ok_or_else(|| {
+                    anyhow::anyhow!("cancel of the missing order {}", cancel.order_id)
+                })?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Orders missing on either book or with another quantity or owner
+    pub fn diff(&self, snapshot: &ReplayBook) -> Vec<ReplayMismatch> {
+        let replayed = self
+            .orders
+            .iter()
+            .filter(|(key, order)| snapshot.orders.get(key) != Some(order))
+            .map(|(key, order)| ReplayMismatch {
+                replayed: Some(order.clone()),
+                snapshot: snapshot.orders.get(key).cloned(),
+            });
+        let missing = snapshot
+            .orders
+            .iter()
+            .filter(|(key, _)| !self.orders.contains_key(key))
+            .map(|(_, order)| ReplayMismatch {
+                replayed: None,
+                snapshot: Some(order.clone()),
+            });
+        replayed.chain(missing).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::__private::bytemuck::Zeroable;
+    use openbook_v2::logs::{CancelLog, FillLog, OutLog, PlaceLog};
+    use openbook_v2::state::{new_node_key, LeafNode, OrderTreeType};
+
+    const BASE_LOT_SIZE: i64 = 100;
+
+    fn place(
+        market: Pubkey,
+        owner: Pubkey,
+        side: Side,
+        order_id: u128,
+        base_lots: u64,
+    ) -> OpenBookEvent {
+        OpenBookEvent::Place(PlaceLog {
+            market,
+            side: side.into(),
+            seq_num: 0,
+            owner,
+            client_order_id: 7,
+            order_id: Some(order_id),
+            base_taken_native: 0,
+            quote_taken_native: 0,
+            base_posted_native: base_lots * BASE_LOT_SIZE as u64,
+            quote_posted_native: 0,
+            taker_fees: 0,
+            maker_fees: 0,
+        })
+    }
+
+    fn fill(
+        market: Pubkey,
+        maker: Pubkey,
+        taker_side: Side,
+        price: i64,
+        quantity: i64,
+    ) -> OpenBookEvent {
+        OpenBookEvent::Fill(FillLog {
+            market,
+            taker_side: taker_side.into(),
+            maker_slot: 0,
+            maker_out: false,
+            timestamp: 0,
+            seq_num: 0,
+            maker,
+            maker_client_order_id: 7,
+            maker_fee: 0,
+            maker_timestamp: 0,
+            taker: Pubkey::new_unique(),
+            taker_client_order_id: 0,
+            taker_fee_ceil: 0,
+            price,
+            quantity,
+            base_native: 0,
+            quote_native: 0,
+            event_seq_num: 0,
+        })
+    }
+
+    #[test]
+    fn test_replay() {
+        let market = Pubkey::new_unique();
+        let maker = Pubkey::new_unique();
+        let mut book = ReplayBook::new(market, BASE_LOT_SIZE);
+
+        let old_ask = new_node_key(Side::Ask, 1_000, 1);
+        let new_ask = new_node_key(Side::Ask, 1_000, 2);
+        let bid = new_node_key(Side::Bid, 900, 3);
+        book.apply(&place(market, maker, Side::Ask, old_ask, 5))
+            .unwrap();
+        book.apply(&place(market, maker, Side::Ask, new_ask, 5))
+            .unwrap();
+        book.apply(&place(market, maker, Side::Bid, bid, 2))
+            .unwrap();
+        // other markets are ignored
+        book.apply(&place(Pubkey::new_unique(), maker, Side::Bid, 1, 1))
+            .unwrap();
+        assert_eq!(book.orders.len(), 3);
+
+        // fills take the oldest order at the price first
+        book.apply(&fill(market, maker, Side::Bid, 1_000, 3))
+            .unwrap();
+        assert_eq!(book.orders[&order_key(Side::Ask, old_ask)].quantity, 2);
+        book.apply(&fill(market, maker, Side::Bid, 1_000, 2))
+            .unwrap();
+        assert!(!book.orders.contains_key(&order_key(Side::Ask, old_ask)));
+        assert_eq!(book.orders[&order_key(Side::Ask, new_ask)].quantity, 5);
+
+        book.apply(&OpenBookEvent::Out(OutLog {
+            market,
+            side: Side::Ask.into(),
+            owner_slot: 1,
+            timestamp: 0,
+            seq_num: 0,
+            owner: maker,
+            quantity: 5,
+            event_seq_num: 0,
+            reason: 0,
+            price: 1_000,
+            peg_limit: -1,
+            client_order_id: 7,
+        }))
+        .unwrap();
+        let cancel = OpenBookEvent::Cancel(CancelLog {
+            market,
+            side: Side::Bid.into(),
+            seq_num: 0,
+            open_orders_account: maker,
+            order_id: bid,
+            client_order_id: 7,
+            quantity: 2,
+            price: 900,
+            peg_limit: -1,
+        });
+        book.apply(&cancel).unwrap();
+        assert!(book.orders.is_empty());
+
+        // events of orders that aren't on the book mean some are missing
+        assert!(book.apply(&cancel).is_err());
+        assert!(book
+            .apply(&fill(market, maker, Side::Bid, 1_000, 1))
+            .is_err());
+    }
+
+    #[test]
+    fn test_diff_against_book_sides() {
+        let market = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let mut bids = BookSide::zeroed();
+        bids.nodes.order_tree_type = OrderTreeType::Bids.into();
+        let mut asks = BookSide::zeroed();
+        asks.nodes.order_tree_type = OrderTreeType::Asks.into();
+
+        let bid = new_node_key(Side::Bid, 900, 1);
+        let ask = new_node_key(Side::Ask, 1_000, 2);
+        bids.insert_leaf(
+            BookSideOrderTree::Fixed,
+            &LeafNode::new(0, bid, owner, 4, 0, 0, -1, 7),
+        )
+        .unwrap();
+        asks.insert_leaf(
+            BookSideOrderTree::Fixed,
+            &LeafNode::new(1, ask, owner, 5, 0, 0, -1, 7),
+        )
+        .unwrap();
+        let snapshot = ReplayBook::from_book_sides(market, BASE_LOT_SIZE, &bids, &asks);
+
+        let mut book = ReplayBook::new(market, BASE_LOT_SIZE);
+        book.apply(&place(market, owner, Side::Bid, bid, 4))
+            .unwrap();
+        book.apply(&place(market, owner, Side::Ask, ask, 6))
+            .unwrap();
+        let mismatches = book.diff(&snapshot);
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].replayed.as_ref().unwrap().quantity, 6);
+        assert_eq!(mismatches[0].snapshot.as_ref().unwrap().quantity, 5);
+
+        book.apply(&fill(market, owner, Side::Bid, 1_000, 1))
+            .unwrap();
+        assert!(book.diff(&snapshot).is_empty());
+
+        book.apply(&fill(market, owner, Side::Bid, 1_000, 5))
+            .unwrap();
+        let mismatches = book.diff(&snapshot);
+        assert_eq!(mismatches.len(), 1);
+        assert!(mismatches[0].replayed.is_none());
+    }
+}
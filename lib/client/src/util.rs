@@ -2,6 +2,7 @@ use anchor_lang::{error::Error, AccountDeserialize, ZeroCopy};
 use solana_client::{
     client_error::Result as ClientResult, rpc_client::RpcClient, rpc_request::RpcError,
 };
+use solana_sdk::pubkey::Pubkey;
 use solana_sdk::transaction::Transaction;
 use solana_sdk::{
     clock::Slot, commitment_config::CommitmentConfig, signature::Signature,
@@ -24,6 +25,11 @@ impl<T: AccountDeserialize + ZeroCopy> ZeroCopyDeserialize for T {
     }
 }
 
+/// Address of the PDA anchor uses to sign the program's self-CPI event logs.
+pub fn event_authority() -> Pubkey {
+    Pubkey::find_program_address(&[b"__event_authority".as_ref()], &openbook_v2::id()).0
+}
+
 /// Some Result<> types don't convert to anyhow::Result nicely. Force them through stringification.
 pub trait AnyhowWrap {
     type Value;
@@ -0,0 +1,291 @@
+//! Compute unit consumption of the matching engine's hot paths.
+//!
+//! Every case runs against the SBF build of the program and fails the run when it
+//! consumes more than its budget, so changes to the matching engine can't silently
+//! regress compute. Run with `just bench-cu`, which builds the program first.
+//!
+//! The budgets are upper bounds: lower them when an optimization lands, raise them
+//! only when the extra compute is intended.
+
+use anchor_lang::InstructionData;
+use openbook_v2::state::*;
+use solana_program_test::*;
+use solana_sdk::pubkey::Pubkey;
+
+#[path = "../tests/program_test/mod.rs"]
+mod program_test;
+
+use program_test::setup::create_open_orders_account;
+use program_test::*;
+
+/// Resting orders on the book before the measured order is placed
+const BOOK_DEPTHS: [usize; 4] = [0, 24, 96, 240];
+/// Maker orders matched by a single taker order, each one producing a fill event
+const FILLS: [usize; 3] = [1, 4, 8];
+/// Orders of the account whose orders are all cancelled
+const CANCEL_ALL_ORDERS: [usize; 3] = [1, 8, MAX_OPEN_ORDERS];
+
+const PLACE_ORDER_BUDGET: u64 = 60_000;
+const TAKE_ORDER_BUDGET: u64 = 50_000;
+const TAKE_ORDER_BUDGET_PER_FILL: u64 = 8_000;
+const CONSUME_EVENTS_BUDGET: u64 = 15_000;
+const CONSUME_EVENTS_BUDGET_PER_EVENT: u64 = 8_000;
+const CANCEL_ALL_BUDGET: u64 = 20_000;
+const CANCEL_ALL_BUDGET_PER_ORDER: u64 = 3_000;
+
+struct Measurement {
+    case: String,
+    units: u64,
+    budget: u64,
+}
+
+struct Bench {
+    test: TestInitialize,
+    next_account_num: u32,
+}
+
+impl Bench {
+    async fn new() -> Self {
+        let test = TestContext::new_with_market(TestNewMarketInitialize {
+            with_oracle: false,
+            ..TestNewMarketInitialize::default()
+        })
+        .await
+        .unwrap();
+        Self {
+            test,
+            // accounts 1 and 2 are created with the market
+            next_account_num: 3,
+        }
+    }
+
+    async fn create_account(&mut self) -> Pubkey {
+        let test = &self.test;
+        let account = create_open_orders_account(
+            &test.context.solana,
+            test.owner,
+            test.market,
+            self.next_account_num,
+            &test.context.users[1],
+            None,
+        )
+        .await;
+        self.next_account_num += 1;
+        account
+    }
+
+    fn place_order(
+        &self,
+        open_orders_account: Pubkey,
+        side: Side,
+        price_lots: i64,
+    ) -> PlaceOrderInstruction {
+        let test = &self.test;
+        let (user_token_account, market_vault) = match side {
+            Side::Bid => (test.owner_token_1, test.market_quote_vault),
+            Side::Ask => (test.owner_token_0, test.market_base_vault),
+        };
+        PlaceOrderInstruction {
+            open_orders_account,
+            open_orders_admin: None,
+            market: test.market,
+            signer: test.owner,
+            user_token_account,
+            market_vault,
+            side,
+            price_lots,
+            max_base_lots: 1,
+            max_quote_lots_including_fees: 1_000_000,
+            client_order_id: 0,
+            expiry_timestamp: 0,
+            order_type: PlaceOrderType::Limit,
+            self_trade_behavior: SelfTradeBehavior::default(),
+            remainings: vec![],
+        }
+    }
+
+    /// Rest `count` orders of one lot on `side`, one price level each, moving away
+    /// from the test price. Returns the accounts holding them.
+    async fn fill_book(&mut self, side: Side, count: usize) -> Vec<Pubkey> {
+        let mut accounts = vec![];
+        for i in 0..count {
+            if i % MAX_OPEN_ORDERS == 0 {
+                accounts.push(self.create_account().await);
+            }
+            let offset = i as i64 + 1;
+            let price_lots = match side {
+                Side::Bid => self.test.price_lots - offset,
+                Side::Ask => self.test.price_lots + offset,
+            };
+            let ix = self.place_order(*accounts.last().unwrap(), side, price_lots);
+            send_tx(&self.test.context.solana, ix).await.unwrap();
+        }
+        accounts
+    }
+}
+
+async fn place_order_on_book(depth: usize) -> Measurement {
+    let mut bench = Bench::new().await;
+    bench.fill_book(Side::Bid, depth).await;
+
+    // the worst price, so the new order ends up deepest in the tree
+    let price_lots = bench.test.price_lots - depth as i64 - 1;
+    let ix = bench.place_order(bench.test.account_1, Side::Bid, price_lots);
+    let (_, units) = send_tx_and_get_compute_units(&bench.test.context.solana, ix)
+        .await
+        .unwrap();
+    Measurement {
+        case: format!("place_order, {} resting orders", depth),
+        units,
+        budget: PLACE_ORDER_BUDGET,
+    }
+}
+
+/// Match `fills` asks of distinct makers, returning the makers and the units of
+/// the taker order. With `settle_makers` the makers are passed along, so their fills
+/// are processed right away instead of going to the event heap.
+async fn take_orders(bench: &mut Bench, fills: usize, settle_makers: bool) -> (Vec<Pubkey>, u64) {
+    let mut makers = vec![];
+    for i in 0..fills {
+        let maker = bench.create_account().await;
+        let ix = bench.place_order(maker, Side::Ask, bench.test.price_lots + i as i64);
+        send_tx(&bench.test.context.solana, ix).await.unwrap();
+        makers.push(maker);
+    }
+
+    let mut ix = bench.place_order(
+        bench.test.account_1,
+        Side::Bid,
+        bench.test.price_lots + fills as i64,
+    );
+    ix.max_base_lots = fills as i64;
+    if settle_makers {
+        ix.remainings = makers.clone();
+    }
+    let (_, units) = send_tx_and_get_compute_units(&bench.test.context.solana, ix)
+        .await
+        .unwrap();
+    (makers, units)
+}
+
+async fn place_order_taking(fills: usize, settle_makers: bool) -> Measurement {
+    let mut bench = Bench::new().await;
+    let (_, units) = take_orders(&mut bench, fills, settle_makers).await;
+    let settled = if settle_makers {
+        ", makers settled"
+    } else {
+        ""
+    };
+    Measurement {
+        case: format!("place_order, {} fills{}", fills, settled),
+        units,
+        budget: TAKE_ORDER_BUDGET + fills as u64 * TAKE_ORDER_BUDGET_PER_FILL,
+    }
+}
+
+async fn consume_events(events: usize) -> Measurement {
+    let mut bench = Bench::new().await;
+    let (mut makers, _) = take_orders(&mut bench, events, false).await;
+    makers.push(bench.test.account_1);
+
+    let (_, units) = send_tx_and_get_compute_units(
+        &bench.test.context.solana,
+        ConsumeEventsInstruction {
+            consume_events_admin: None,
+            market: bench.test.market,
+            open_orders_accounts: makers,
+        },
+    )
+    .await
+    .unwrap();
+    Measurement {
+        case: format!("consume_events, {} events", events),
+        units,
+        budget: CONSUME_EVENTS_BUDGET + events as u64 * CONSUME_EVENTS_BUDGET_PER_EVENT,
+    }
+}
+
+async fn cancel_all(orders: usize) -> Measurement {
+    let mut bench = Bench::new().await;
+    // other orders on the book, so the cancelled ones aren't the only leaves
+    bench
+        .fill_book(Side::Ask, BOOK_DEPTHS[BOOK_DEPTHS.len() - 1])
+        .await;
+    for i in 0..orders {
+        let ix = bench.place_order(
+            bench.test.account_1,
+            Side::Bid,
+            bench.test.price_lots - i as i64 - 1,
+        );
+        send_tx(&bench.test.context.solana, ix).await.unwrap();
+    }
+
+    let solana = &bench.test.context.solana;
+    let cancel_all = CancelAllOrdersInstruction {
+        open_orders_account: bench.test.account_1,
+        market: bench.test.market,
+        signer: bench.test.owner,
+    };
+    let (_, mut instruction) = cancel_all.to_instruction(&**solana).await;
+    instruction.data = openbook_v2::instruction::CancelAllOrders {
+        side_option: None,
+        limit: orders as u8,
+    }
+    .data();
+    let instructions = vec![instruction];
+    let signers = cancel_all.signers();
+    let units = solana
+        .simulate_compute_units(&instructions, Some(&signers[..]))
+        .await
+        .unwrap();
+    solana
+        .process_transaction(&instructions, Some(&signers[..]))
+        .await
+        .unwrap();
+
+    let account = solana
+        .get_account::<OpenOrdersAccount>(bench.test.account_1)
+        .await;
+    assert_eq!(account.all_orders_in_use().count(), 0);
+    Measurement {
+        case: format!("cancel_all, {} orders", orders),
+        units,
+        budget: CANCEL_ALL_BUDGET + orders as u64 * CANCEL_ALL_BUDGET_PER_ORDER,
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    // the native processor isn't metered, the program must be loaded from its SBF build
+    if std::env::var("SBF_OUT_DIR").is_err() && std::env::var("BPF_OUT_DIR").is_err() {
+        panic!("SBF_OUT_DIR must point at the directory of openbook_v2.so, see `just bench-cu`");
+    }
+
+    let mut measurements = vec![];
+    for depth in BOOK_DEPTHS {
+        measurements.push(place_order_on_book(depth).await);
+    }
+    for fills in FILLS {
+        measurements.push(place_order_taking(fills, false).await);
+        measurements.push(place_order_taking(fills, true).await);
+    }
+    for events in FILLS {
+        measurements.push(consume_events(events).await);
+    }
+    for orders in CANCEL_ALL_ORDERS {
+        measurements.push(cancel_all(orders).await);
+    }
+
+    let mut over_budget = 0;
+    println!("{:<36} {:>10} {:>10}", "case", "units", "budget");
+    for m in measurements.iter() {
+        let marker = if m.units > m.budget {
+            over_budget += 1;
+            "  OVER BUDGET"
+        } else {
+            ""
+        };
+        println!("{:<36} {:>10} {:>10}{}", m.case, m.units, m.budget, marker);
+    }
+    assert_eq!(over_budget, 0, "cases over their compute budget");
+}
@@ -0,0 +1,613 @@
+//! Random sequences of instructions sent to the program, with arguments, accounts
+//! and signers an attacker could pick.
+//!
+//! Unlike `multiple_orders`, which drives the matching engine directly, this goes
+//! through the instruction handlers, account constraints and token transfers on
+//! `solana-program-test`. Every instruction starts from the accounts an honest user
+//! would pass, then some of them are swapped for other accounts of the market, of
+//! other users or unknown ones. A swapped signer only signs if its key is known.
+//!
+//! Whether the instruction succeeds or not, afterwards:
+//! - `verify_market_invariants`, given all the open orders accounts, finds the
+//!   vaults holding at least the deposits and nothing owed but not deposited,
+//! - the book sides are sorted, only hold orders of the market's open orders
+//!   accounts, which agree on them, and aren't crossed.
+//!
+//! Run with `just fuzz instructions`, reproduce a crash with
+//! `just fuzz-reproduce <artifact> instructions`.
+
+#![no_main]
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{Discriminator, InstructionData};
+use arbitrary::Arbitrary;
+use bytemuck::Zeroable;
+use libfuzzer_sys::fuzz_target;
+use openbook_v2::logs::MarketInvariantsLog;
+use openbook_v2::state::*;
+use openbook_v2::PlaceOrderArgs;
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::account::Account;
+use solana_sdk::program_pack::Pack;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::Transaction;
+
+const USERS: usize = 3;
+const TOKENS: u64 = 1_000_000_000_000;
+
+#[derive(Arbitrary, Debug)]
+struct FuzzData {
+    base_lot_size: u8,
+    quote_lot_size: u8,
+    maker_fee: i16,
+    taker_fee: u16,
+    instructions: Vec<FuzzInstruction>,
+}
+
+#[derive(Arbitrary, Debug)]
+struct FuzzInstruction {
+    /// Signs and owns the accounts passed, unless they are swapped
+    user: u8,
+    kind: InstructionKind,
+    /// Accounts swapped, as (position in the instruction, index in the pool)
+    swaps: Vec<(u8, u8)>,
+}
+
+#[derive(Arbitrary, Debug)]
+enum InstructionKind {
+    Deposit {
+        base_amount: u64,
+        quote_amount: u64,
+    },
+    PlaceOrder {
+        args: PlaceOrderArgs,
+    },
+    /// Cancel the order in a slot of the user's account, a made up order id if
+    /// the slot is free
+    CancelOrder {
+        slot: u8,
+        order_id: u128,
+    },
+    CancelAllOrders {
+        side_option: Option<Side>,
+        limit: u8,
+    },
+    ConsumeEvents {
+        limit: u8,
+    },
+    SettleFunds,
+    ExpireOrders {
+        limit: u8,
+    },
+    /// Advance the clock, expiring orders
+    Wait {
+        seconds: u16,
+    },
+}
+
+struct User {
+    key: Keypair,
+    base_account: Pubkey,
+    quote_account: Pubkey,
+    open_orders_account: Pubkey,
+}
+
+struct FuzzMarket {
+    context: ProgramTestContext,
+    market: Pubkey,
+    market_authority: Pubkey,
+    bids: Pubkey,
+    asks: Pubkey,
+    event_heap: Pubkey,
+    base_vault: Pubkey,
+    quote_vault: Pubkey,
+    users: Vec<User>,
+    /// Accounts swapped into instructions
+    pool: Vec<Pubkey>,
+}
+
+// see https://github.com/coral-xyz/anchor/issues/2738
+fn fixed_entry(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> anchor_lang::solana_program::entrypoint::ProgramResult {
+    let extended_lifetime_accs = unsafe { core::mem::transmute::<_, &[AccountInfo<'_>]>(accounts) };
+    openbook_v2::entry(program_id, extended_lifetime_accs, data)
+}
+
+fn program_account<T: Discriminator + bytemuck::Pod>(value: &T) -> Account {
+    let mut data = T::discriminator().to_vec();
+    data.extend_from_slice(bytemuck::bytes_of(value));
+    Account {
+        lamports: u32::MAX as u64,
+        data,
+        owner: openbook_v2::id(),
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+fn packed_account<T: Pack>(value: T) -> Account {
+    let mut data = vec![0; T::get_packed_len()];
+    value.pack_into_slice(&mut data);
+    Account {
+        lamports: u32::MAX as u64,
+        data,
+        owner: spl_token::id(),
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+fn token_account(mint: Pubkey, owner: Pubkey, amount: u64) -> Account {
+    packed_account(spl_token::state::Account {
+        mint,
+        owner,
+        amount,
+        state: spl_token::state::AccountState::Initialized,
+        ..spl_token::state::Account::default()
+    })
+}
+
+fn event_authority() -> Pubkey {
+    Pubkey::find_program_address(&[b"__event_authority"], &openbook_v2::id()).0
+}
+
+impl FuzzMarket {
+    /// A market like `create_market` sets up, without an oracle or admins, and a
+    /// funded open orders account for each user
+    async fn new(data: &FuzzData) -> Self {
+        let mut test = ProgramTest::new("openbook_v2", openbook_v2::id(), processor!(fixed_entry));
+        test.prefer_bpf(false);
+
+        let market = Pubkey::new_unique();
+        let (market_authority, bump) =
+            Pubkey::find_program_address(&[b"Market", market.as_ref()], &openbook_v2::id());
+        let (bids, asks, event_heap) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let (base_mint, quote_mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let (base_vault, quote_vault) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        for mint in [base_mint, quote_mint] {
+            test.add_account(
+                mint,
+                packed_account(spl_token::state::Mint {
+                    is_initialized: true,
+                    decimals: 6,
+                    ..spl_token::state::Mint::default()
+                }),
+            );
+        }
+        test.add_account(base_vault, token_account(base_mint, market_authority, 0));
+        test.add_account(quote_vault, token_account(quote_mint, market_authority, 0));
+
+        let mut market_acc = Market::zeroed();
+        market_acc.market_authority = market_authority;
+        market_acc.bump = bump;
+        market_acc.base_decimals = 6;
+        market_acc.quote_decimals = 6;
+        market_acc.market_state = MarketState::Active.into();
+        market_acc.bids = bids;
+        market_acc.asks = asks;
+        market_acc.event_heap = event_heap;
+        market_acc.base_lot_size = data.base_lot_size.max(1) as i64;
+        market_acc.quote_lot_size = data.quote_lot_size.max(1) as i64;
+        // the bounds of create_market: a maker rebate is at most the taker fee
+        market_acc.taker_fee = data.taker_fee as i64;
+        market_acc.maker_fee = (data.maker_fee as i64).max(-market_acc.taker_fee);
+        market_acc.base_mint = base_mint;
+        market_acc.quote_mint = quote_mint;
+        market_acc.market_base_vault = base_vault;
+        market_acc.market_quote_vault = quote_vault;
+        market_acc.event_heap_capacity = MAX_NUM_EVENTS;
+        market_acc.version = MARKET_VERSION;
+        test.add_account(market, program_account(&market_acc));
+
+        for (key, side) in [(bids, OrderTreeType::Bids), (asks, OrderTreeType::Asks)] {
+            let mut bookside = Box::new(BookSide::zeroed());
+            bookside.nodes.order_tree_type = side.into();
+            test.add_account(key, program_account(&*bookside));
+        }
+        let mut event_heap_acc = Box::new(EventHeap::zeroed());
+        event_heap_acc.init();
+        test.add_account(event_heap, program_account(&*event_heap_acc));
+
+        let mut users = vec![];
+        for _ in 0..USERS {
+            let key = Keypair::new();
+            let user = User {
+                base_account: Pubkey::new_unique(),
+                quote_account: Pubkey::new_unique(),
+                open_orders_account: Pubkey::new_unique(),
+                key,
+            };
+            test.add_account(
+                user.key.pubkey(),
+                Account::new(u32::MAX as u64, 0, &solana_sdk::system_program::id()),
+            );
+            test.add_account(
+                user.base_account,
+                token_account(base_mint, user.key.pubkey(), TOKENS),
+            );
+            test.add_account(
+                user.quote_account,
+                token_account(quote_mint, user.key.pubkey(), TOKENS),
+            );
+            let mut open_orders_account = OpenOrdersAccount::default_for_tests();
+            open_orders_account.owner = user.key.pubkey();
+            open_orders_account.market = market;
+            test.add_account(
+                user.open_orders_account,
+                program_account(&*open_orders_account),
+            );
+            users.push(user);
+        }
+
+        let mut pool = vec![
+            market,
+            market_authority,
+            bids,
+            asks,
+            event_heap,
+            base_vault,
+            quote_vault,
+            base_mint,
+            quote_mint,
+            event_authority(),
+            openbook_v2::id(),
+            spl_token::id(),
+            solana_sdk::system_program::id(),
+            Pubkey::new_unique(),
+        ];
+        for user in users.iter() {
+            pool.extend([
+                user.key.pubkey(),
+                user.base_account,
+                user.quote_account,
+                user.open_orders_account,
+            ]);
+        }
+
+        Self {
+            context: test.start_with_context().await,
+            market,
+            market_authority,
+            bids,
+            asks,
+            event_heap,
+            base_vault,
+            quote_vault,
+            users,
+            pool,
+        }
+    }
+
+    async fn account_data(&mut self, address: Pubkey) -> Vec<u8> {
+        self.context
+            .banks_client
+            .get_account(address)
+            .await
+            .unwrap()
+            .unwrap()
+            .data
+    }
+
+    async fn load<T: bytemuck::Pod>(&mut self, address: Pubkey) -> Box<T> {
+        let data = self.account_data(address).await;
+        Box::new(bytemuck::pod_read_unaligned(
+            &data[8..8 + std::mem::size_of::<T>()],
+        ))
+    }
+
+    fn open_orders_metas(&self) -> Vec<AccountMeta> {
+        self.users
+            .iter()
+            .map(|user| AccountMeta::new(user.open_orders_account, false))
+            .collect()
+    }
+
+    async fn instruction(&mut self, fuzz_ix: &FuzzInstruction) -> Option<Instruction> {
+        let user = &self.users[fuzz_ix.user as usize % USERS];
+        let owner = user.key.pubkey();
+        let (accounts, data) = match fuzz_ix.kind {
+            InstructionKind::Deposit {
+                base_amount,
+                quote_amount,
+            } => (
+                openbook_v2::accounts::Deposit {
+                    owner,
+                    user_base_account: user.base_account,
+                    user_quote_account: user.quote_account,
+                    open_orders_account: user.open_orders_account,
+                    market: self.market,
+                    market_base_vault: self.base_vault,
+                    market_quote_vault: self.quote_vault,
+                    token_program: spl_token::id(),
+                }
+                .to_account_metas(None),
+                openbook_v2::instruction::Deposit {
+                    base_amount,
+                    quote_amount,
+                }
+                .data(),
+            ),
+            InstructionKind::PlaceOrder { args } => {
+                let (user_token_account, market_vault) = match args.side {
+                    Side::Bid => (user.quote_account, self.quote_vault),
+                    Side::Ask => (user.base_account, self.base_vault),
+                };
+                (
+                    openbook_v2::accounts::PlaceOrder {
+                        signer: owner,
+                        open_orders_account: user.open_orders_account,
+                        open_orders_admin: None,
+                        user_token_account,
+                        market: self.market,
+                        bids: self.bids,
+                        asks: self.asks,
+                        event_heap: self.event_heap,
+                        market_vault,
+                        oracle_a: None,
+                        oracle_b: None,
+                        token_program: spl_token::id(),
+                        event_authority: event_authority(),
+                        program: openbook_v2::id(),
+                    }
+                    .to_account_metas(None),
+                    openbook_v2::instruction::PlaceOrder { args }.data(),
+                )
+            }
+            InstructionKind::CancelOrder { slot, order_id } => {
+                let open_orders_account = user.open_orders_account;
+                let account = self.load::<OpenOrdersAccount>(open_orders_account).await;
+                let oo = account.open_order_by_raw_index(slot as usize % MAX_OPEN_ORDERS);
+                let order_id = if oo.is_free() { order_id } else { oo.id };
+                let user = &self.users[fuzz_ix.user as usize % USERS];
+                (
+                    self.cancel_order_accounts(user),
+                    openbook_v2::instruction::CancelOrder { order_id }.data(),
+                )
+            }
+            InstructionKind::CancelAllOrders { side_option, limit } => (
+                self.cancel_order_accounts(user),
+                openbook_v2::instruction::CancelAllOrders { side_option, limit }.data(),
+            ),
+            InstructionKind::ConsumeEvents { limit } => {
+                let mut accounts = openbook_v2::accounts::ConsumeEvents {
+                    consume_events_admin: None,
+                    market: self.market,
+                    event_heap: self.event_heap,
+                }
+                .to_account_metas(None);
+                accounts.extend(self.open_orders_metas());
+                (
+                    accounts,
+                    openbook_v2::instruction::ConsumeEvents {
+                        limit: limit as usize,
+                    }
+                    .data(),
+                )
+            }
+            InstructionKind::SettleFunds => (
+                openbook_v2::accounts::SettleFunds {
+                    owner,
+                    penalty_payer: owner,
+                    open_orders_account: user.open_orders_account,
+                    market: self.market,
+                    market_authority: self.market_authority,
+                    market_base_vault: self.base_vault,
+                    market_quote_vault: self.quote_vault,
+                    user_base_account: user.base_account,
+                    user_quote_account: user.quote_account,
+                    referrer_account: None,
+                    token_program: spl_token::id(),
+                    system_program: solana_sdk::system_program::id(),
+                }
+                .to_account_metas(None),
+                openbook_v2::instruction::SettleFunds {}.data(),
+            ),
+            InstructionKind::ExpireOrders { limit } => (
+                openbook_v2::accounts::ExpireOrders {
+                    market: self.market,
+                    bids: self.bids,
+                    asks: self.asks,
+                    event_heap: self.event_heap,
+                }
+                .to_account_metas(None),
+                openbook_v2::instruction::ExpireOrders { limit }.data(),
+            ),
+            InstructionKind::Wait { .. } => return None,
+        };
+
+        let mut accounts = accounts;
+        for (position, pool_index) in fuzz_ix.swaps.iter() {
+            let position = *position as usize % accounts.len();
+            accounts[position].pubkey = self.pool[*pool_index as usize % self.pool.len()];
+        }
+        Some(Instruction {
+            program_id: openbook_v2::id(),
+            accounts,
+            data,
+        })
+    }
+
+    fn cancel_order_accounts(&self, user: &User) -> Vec<AccountMeta> {
+        openbook_v2::accounts::CancelOrder {
+            signer: user.key.pubkey(),
+            open_orders_account: user.open_orders_account,
+            market: self.market,
+            bids: self.bids,
+            asks: self.asks,
+        }
+        .to_account_metas(None)
+    }
+
+    /// Sends `ix` signed by the known keys among its signers, the others don't
+    /// sign. Returns the logs of the transaction.
+    async fn send(&mut self, mut ix: Instruction) -> (Result<(), BanksClientError>, Vec<String>) {
+        let mut signers: Vec<&Keypair> = vec![&self.context.payer];
+        for meta in ix.accounts.iter_mut().filter(|meta| meta.is_signer) {
+            match self
+                .users
+                .iter()
+                .find(|user| user.key.pubkey() == meta.pubkey)
+            {
+                Some(user) => {
+                    if !signers.iter().any(|signer| signer.pubkey() == meta.pubkey) {
+                        signers.push(&user.key);
+                    }
+                }
+                None => meta.is_signer = false,
+            }
+        }
+
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&self.context.payer.pubkey()));
+        tx.sign(&signers, self.context.last_blockhash);
+        let result = self
+            .context
+            .banks_client
+            .process_transaction_with_metadata(tx)
+            .await
+            .unwrap();
+        let logs = result
+            .metadata
+            .map(|metadata| metadata.log_messages)
+            .unwrap_or_default();
+
+        // a new blockhash, so sending the same transaction again isn't a duplicate
+        let slot = self.context.banks_client.get_root_slot().await.unwrap();
+        self.context.warp_to_slot(slot + 2).unwrap();
+
+        (result.result.map_err(BanksClientError::from), logs)
+    }
+
+    async fn wait(&mut self, seconds: u16) {
+        let mut clock = self
+            .context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap();
+        clock.unix_timestamp += seconds as i64;
+        self.context.set_sysvar(&clock);
+    }
+
+    async fn check_conservation(&mut self) {
+        let mut accounts = openbook_v2::accounts::VerifyMarketInvariants {
+            market: self.market,
+            event_heap: self.event_heap,
+            market_base_vault: self.base_vault,
+            market_quote_vault: self.quote_vault,
+        }
+        .to_account_metas(None);
+        accounts.extend(self.open_orders_metas());
+        let ix = Instruction {
+            program_id: openbook_v2::id(),
+            accounts,
+            data: openbook_v2::instruction::VerifyMarketInvariants {}.data(),
+        };
+        let (result, logs) = self.send(ix).await;
+        result.unwrap();
+
+        let log = logs
+            .iter()
+            .filter_map(|line| line.strip_prefix("Program data: "))
+            .filter_map(|data| base64::decode(data).ok())
+            .find(|bytes| bytes.starts_with(&MarketInvariantsLog::discriminator()))
+            .map(|bytes| MarketInvariantsLog::try_from_slice(&bytes[8..]).unwrap())
+            .expect("no MarketInvariantsLog");
+        assert!(log.base_vault >= log.base_deposit_total);
+        assert!(log.quote_vault >= log.quote_deposit_total);
+        assert_eq!(log.base_unaccounted, 0);
+        // fee rounding leaves dust in the market, it must never be short
+        assert!(log.quote_unaccounted >= 0, "{}", log.quote_unaccounted);
+    }
+
+    async fn check_book(&mut self) {
+        let now_ts = self
+            .context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+            .unix_timestamp as u64;
+        let mut accounts = vec![];
+        for user in 0..USERS {
+            let key = self.users[user].open_orders_account;
+            accounts.push((key, self.load::<OpenOrdersAccount>(key).await));
+        }
+
+        let mut best_valid = [None, None];
+        for (i, (key, side)) in [(self.bids, Side::Bid), (self.asks, Side::Ask)]
+            .into_iter()
+            .enumerate()
+        {
+            let bookside = self.load::<BookSide>(key).await;
+            assert_eq!(bookside.side(), side);
+
+            let mut leaf_count = 0;
+            let mut previous_price = None;
+            for item in bookside.iter_all_including_invalid(now_ts, None) {
+                let leaf = item.node;
+                leaf_count += 1;
+                assert!(leaf.quantity > 0, "empty order {} on the book", leaf.key);
+                if let Some(previous) = previous_price {
+                    assert!(
+                        !side.is_price_better(item.price_lots, previous),
+                        "book side out of order"
+                    );
+                }
+                previous_price = Some(item.price_lots);
+                if item.is_valid() && best_valid[i].is_none() {
+                    best_valid[i] = Some(item.price_lots);
+                }
+
+                let (_, account) = accounts
+                    .iter()
+                    .find(|(key, _)| *key == leaf.owner)
+                    .expect("order of an unknown open orders account");
+                let oo = account.open_order_by_raw_index(leaf.owner_slot as usize);
+                assert_eq!(oo.id, leaf.key, "open order slot doesn't hold the order");
+                assert_eq!(oo.side_and_tree().side(), side);
+            }
+            let root_leaf_count: u32 = bookside.roots.iter().map(|root| root.leaf_count).sum();
+            assert_eq!(leaf_count, root_leaf_count);
+        }
+
+        if let [Some(best_bid), Some(best_ask)] = best_valid {
+            assert!(
+                best_bid < best_ask,
+                "crossed book: {} / {}",
+                best_bid,
+                best_ask
+            );
+        }
+    }
+}
+
+fuzz_target!(|data: FuzzData| {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    runtime.block_on(async {
+        let mut market = FuzzMarket::new(&data).await;
+        for fuzz_ix in data.instructions.iter() {
+            match fuzz_ix.kind {
+                InstructionKind::Wait { seconds } => market.wait(seconds).await,
+                _ => {
+                    let ix = market.instruction(fuzz_ix).await.unwrap();
+                    // failing is fine, leaving the market inconsistent is not
+                    let _ = market.send(ix).await;
+                }
+            }
+            market.check_conservation().await;
+            market.check_book().await;
+        }
+    });
+});
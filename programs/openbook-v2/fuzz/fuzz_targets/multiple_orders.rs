@@ -0,0 +1,462 @@
+//! Random sequences of place, cancel, expire and consume operations against an
+//! in-memory market.
+//!
+//! Operations go through the same matching engine and account updates as the
+//! instructions, token transfers are replaced by the deposit totals they update. An
+//! operation that fails leaves the market as it was, like a failed transaction.
+//! After every step the market has to hold what its accounts are owed, and the book
+//! and the open orders accounts have to agree on the resting orders.
+//!
+//! Run with `just fuzz`, reproduce a crash with `just fuzz-reproduce <artifact>`.
+
+#![no_main]
+
+use std::cell::RefCell;
+use std::collections::HashSet;
+
+use anchor_lang::prelude::*;
+use arbitrary::Arbitrary;
+use bytemuck::{cast_ref, Zeroable};
+use libfuzzer_sys::fuzz_target;
+use openbook_v2::state::*;
+
+const USERS: usize = 4;
+
+#[derive(Arbitrary, Debug)]
+struct FuzzData {
+    base_lot_size: u8,
+    quote_lot_size: u8,
+    maker_fee: i16,
+    taker_fee: u16,
+    actions: Vec<Action>,
+}
+
+#[derive(Arbitrary, Debug)]
+enum Action {
+    PlaceOrder {
+        user: u8,
+        side: Side,
+        order_type: PlaceOrderType,
+        self_trade_behavior: SelfTradeBehavior,
+        price_lots: u8,
+        max_base_lots: u8,
+        max_quote_lots_including_fees: u16,
+        client_order_id: u8,
+        /// Seconds until the order expires, never when zero
+        time_in_force: u8,
+    },
+    CancelOrder {
+        user: u8,
+        slot: u8,
+    },
+    CancelAllOrders {
+        user: u8,
+        side: Option<Side>,
+    },
+    /// Advance the clock, expiring orders
+    Wait {
+        seconds: u8,
+    },
+    ConsumeEvents {
+        limit: u8,
+    },
+    SettleFunds {
+        user: u8,
+    },
+}
+
+#[derive(Clone)]
+struct FuzzMarket {
+    market_pk: Pubkey,
+    market: Box<Market>,
+    bids: Box<RefCell<BookSide>>,
+    asks: Box<RefCell<BookSide>>,
+    event_heap: Box<EventHeap>,
+    accounts: Vec<Box<OpenOrdersAccount>>,
+    now_ts: u64,
+}
+
+fn user_key(user: usize) -> Pubkey {
+    Pubkey::new_from_array([user as u8 + 1; 32])
+}
+
+fn user_index(key: &Pubkey) -> usize {
+    let user = key.to_bytes()[0] as usize - 1;
+    assert_eq!(*key, user_key(user), "unknown open orders account {}", key);
+    user
+}
+
+impl FuzzMarket {
+    fn new(data: &FuzzData) -> Self {
+        let market_pk = Pubkey::new_unique();
+
+        let mut market = Box::new(Market::zeroed());
+        market.base_lot_size = data.base_lot_size.max(1) as i64;
+        market.quote_lot_size = data.quote_lot_size.max(1) as i64;
+        // the bounds of create_market: a maker rebate is at most the taker fee
+        market.taker_fee = data.taker_fee as i64;
+        market.maker_fee = (data.maker_fee as i64).max(-market.taker_fee);
+
+        let mut bids = BookSide::zeroed();
+        bids.nodes.order_tree_type = OrderTreeType::Bids.into();
+        let mut asks = BookSide::zeroed();
+        asks.nodes.order_tree_type = OrderTreeType::Asks.into();
+
+        let mut event_heap = Box::new(EventHeap::zeroed());
+        event_heap.init();
+
+        let accounts = (0..USERS)
+            .map(|user| {
+                let mut account = OpenOrdersAccount::default_for_tests();
+                account.owner = user_key(user);
+                account.market = market_pk;
+                account
+            })
+            .collect();
+
+        Self {
+            market_pk,
+            market,
+            bids: Box::new(RefCell::new(bids)),
+            asks: Box::new(RefCell::new(asks)),
+            event_heap,
+            accounts,
+            now_ts: 1_000_000,
+        }
+    }
+
+    fn run(&mut self, action: &Action) -> Result<()> {
+        match *action {
+            Action::PlaceOrder {
+                user,
+                side,
+                order_type,
+                self_trade_behavior,
+                price_lots,
+                max_base_lots,
+                max_quote_lots_including_fees,
+                client_order_id,
+                time_in_force,
+            } => {
+                let price_lots = (price_lots as i64).max(1);
+                let order = Order {
+                    side,
+                    max_base_lots: max_base_lots as i64,
+                    max_quote_lots_including_fees: max_quote_lots_including_fees as i64,
+                    client_order_id: client_order_id as u64,
+                    time_in_force: time_in_force as u16,
+                    self_trade_behavior,
+                    params: match order_type {
+                        PlaceOrderType::Market => OrderParams::Market,
+                        PlaceOrderType::ImmediateOrCancel => {
+                            OrderParams::ImmediateOrCancel { price_lots }
+                        }
+                        PlaceOrderType::FillOrKill => OrderParams::FillOrKill { price_lots },
+                        _ => OrderParams::Fixed {
+                            price_lots,
+                            order_type: order_type.to_post_order_type()?,
+                        },
+                    },
+                };
+                self.place_order(user as usize % USERS, &order)
+            }
+            Action::CancelOrder { user, slot } => {
+                let user = user as usize % USERS;
+                let oo =
+                    *self.accounts[user].open_order_by_raw_index(slot as usize % MAX_OPEN_ORDERS);
+                if oo.is_free() {
+                    return Ok(());
+                }
+                let mut book = Orderbook {
+                    bids: self.bids.borrow_mut(),
+                    asks: self.asks.borrow_mut(),
+                };
+                book.cancel_order(
+                    &mut self.accounts[user],
+                    oo.id,
+                    oo.side_and_tree(),
+                    &mut self.market,
+                    Some(user_key(user)),
+                    self.now_ts,
+                    &[],
+                )?;
+                Ok(())
+            }
+            Action::CancelAllOrders { user, side } => {
+                let mut book = Orderbook {
+                    bids: self.bids.borrow_mut(),
+                    asks: self.asks.borrow_mut(),
+                };
+                book.cancel_all_orders(
+                    &mut self.accounts[user as usize % USERS],
+                    &mut self.market,
+                    u8::MAX,
+                    side,
+                    None,
+                    self.now_ts,
+                    &[],
+                )?;
+                Ok(())
+            }
+            Action::Wait { seconds } => {
+                self.now_ts += seconds as u64;
+                Ok(())
+            }
+            Action::ConsumeEvents { limit } => {
+                self.consume_events(limit as usize);
+                Ok(())
+            }
+            Action::SettleFunds { user } => {
+                self.settle_funds(user as usize % USERS);
+                Ok(())
+            }
+        }
+    }
+
+    /// `place_order` without the token transfer
+    fn place_order(&mut self, user: usize, order: &Order) -> Result<()> {
+        let mut book = Orderbook {
+            bids: self.bids.borrow_mut(),
+            asks: self.asks.borrow_mut(),
+        };
+        let market: &mut Market = &mut self.market;
+        let open_orders_account: &mut OpenOrdersAccount = &mut self.accounts[user];
+        let OrderWithAmounts {
+            total_base_taken_native,
+            total_quote_taken_native,
+            posted_base_native,
+            posted_quote_native,
+            taker_fees,
+            maker_fees,
+            ..
+        } = book.new_order(
+            order,
+            market,
+            &self.market_pk,
+            &mut *self.event_heap,
+            None,
+            Some(&mut *open_orders_account),
+            &user_key(user),
+            self.now_ts,
+            u8::MAX,
+            &[],
+        )?;
+
+        let position = &mut open_orders_account.position;
+        match order.side {
+            Side::Bid => {
+                let max_quote_including_fees =
+                    total_quote_taken_native + posted_quote_native + taker_fees + maker_fees;
+                let free_qty_to_lock = max_quote_including_fees.min(position.quote_free_native);
+                position.quote_free_native -= free_qty_to_lock;
+                market.quote_deposit_total += max_quote_including_fees - free_qty_to_lock;
+            }
+            Side::Ask => {
+                let max_base_native = total_base_taken_native + posted_base_native;
+                let free_qty_to_lock = max_base_native.min(position.base_free_native);
+                position.base_free_native -= free_qty_to_lock;
+                market.base_deposit_total += max_base_native - free_qty_to_lock;
+            }
+        }
+        Ok(())
+    }
+
+    /// `consume_events` with all the open orders accounts passed
+    fn consume_events(&mut self, limit: usize) {
+        let slots: Vec<usize> = self
+            .event_heap
+            .iter_by_priority()
+            .map(|(_event, slot)| slot)
+            .take(limit)
+            .collect();
+        for slot in slots {
+            let event = *self.event_heap.at_slot(slot).unwrap();
+            match EventType::try_from(event.event_type).unwrap() {
+                EventType::Fill => {
+                    let fill: &FillEvent = cast_ref(&event);
+                    self.accounts[user_index(&fill.maker)]
+                        .execute_maker(&mut self.market, fill, None)
+                        .unwrap();
+                }
+                EventType::Out => {
+                    let out: &OutEvent = cast_ref(&event);
+                    self.accounts[user_index(&out.owner)]
+                        .cancel_order(out.owner_slot as usize, out.quantity, &mut self.market)
+                        .unwrap();
+                }
+            }
+            self.event_heap.delete_slot(slot).unwrap();
+        }
+        self.market.update_event_heap_stats(&*self.event_heap);
+    }
+
+    /// `settle_funds` without a referrer and the token transfers
+    fn settle_funds(&mut self, user: usize) {
+        let market = &mut self.market;
+        let pa = &mut self.accounts[user].position;
+
+        let mut roundoff_maker_fees = 0;
+        if market.maker_fee.is_positive() && pa.bids_base_lots == 0 {
+            roundoff_maker_fees = pa.locked_maker_fees;
+            pa.locked_maker_fees = 0;
+        }
+        market.fees_available += pa.referrer_rebates_available + roundoff_maker_fees;
+        market.base_deposit_total -= pa.base_free_native;
+        market.quote_deposit_total -= pa.quote_free_native;
+        market.referrer_rebates_accrued -= pa.referrer_rebates_available;
+
+        pa.base_free_native = 0;
+        pa.quote_free_native = 0;
+        pa.referrer_rebates_available = 0;
+    }
+
+    /// The deposits cover what the accounts can withdraw, as in `verify_market_invariants`
+    fn check_conservation(&self) {
+        let market = &self.market;
+        let mut base_owed: i128 = 0;
+        let mut quote_owed: i128 = 0;
+        for account in self.accounts.iter() {
+            let pa = &account.position;
+            base_owed +=
+                pa.base_free_native as i128 + (pa.asks_base_lots * market.base_lot_size) as i128;
+            quote_owed += pa.quote_free_native as i128
+                + (pa.bids_quote_lots * market.quote_lot_size) as i128
+                + pa.locked_maker_fees as i128
+                + pa.referrer_rebates_available as i128;
+        }
+
+        // the taker side of a fill is settled right away, the maker side once consumed
+        for (event, _slot) in self.event_heap.iter() {
+            if event.event_type != EventType::Fill as u8 {
+                continue;
+            }
+            let fill: &FillEvent = cast_ref(event);
+            let base_native = (fill.quantity * market.base_lot_size) as i128;
+            let quote_native = (fill.quantity * fill.price * market.quote_lot_size) as u64;
+            let maker_rebate = if fill.maker == fill.taker {
+                0
+            } else {
+                market.maker_rebate_floor(quote_native) as i128
+            };
+            match fill.taker_side() {
+                Side::Ask => {
+                    base_owed += base_native;
+                    quote_owed += maker_rebate - quote_native as i128;
+                }
+                Side::Bid => {
+                    base_owed -= base_native;
+                    quote_owed += quote_native as i128 + maker_rebate;
+                }
+            }
+        }
+
+        assert_eq!(
+            market.base_deposit_total as i128, base_owed,
+            "base deposits differ from what the accounts are owed"
+        );
+        // fee rounding leaves dust in the market, it must never be short
+        let quote_held = market.quote_deposit_total as i128 - market.fees_available as i128;
+        assert!(
+            quote_held >= quote_owed,
+            "quote deposits of {} don't cover the {} the accounts are owed",
+            quote_held,
+            quote_owed
+        );
+    }
+
+    /// Every order on the book is an open order of its owner, every open order is on
+    /// the book or has an event on the heap that frees it
+    fn check_book(&self) {
+        let mut on_book = HashSet::new();
+        let mut best_valid = [None, None];
+        for (i, side) in [Side::Bid, Side::Ask].into_iter().enumerate() {
+            let bookside = match side {
+                Side::Bid => self.bids.borrow(),
+                Side::Ask => self.asks.borrow(),
+            };
+            assert_eq!(bookside.side(), side);
+
+            let mut leaf_count = 0;
+            let mut previous_price = None;
+            for item in bookside.iter_all_including_invalid(self.now_ts, None) {
+                let leaf = item.node;
+                leaf_count += 1;
+                assert!(leaf.quantity > 0, "empty order {} on the book", leaf.key);
+
+                if let Some(previous) = previous_price {
+                    match side {
+                        Side::Bid => assert!(item.price_lots <= previous, "bids out of order"),
+                        Side::Ask => assert!(item.price_lots >= previous, "asks out of order"),
+                    }
+                }
+                previous_price = Some(item.price_lots);
+                if item.is_valid() && best_valid[i].is_none() {
+                    best_valid[i] = Some(item.price_lots);
+                }
+
+                let user = user_index(&leaf.owner);
+                let oo = self.accounts[user].open_order_by_raw_index(leaf.owner_slot as usize);
+                assert!(!oo.is_free(), "order {} has no open order slot", leaf.key);
+                assert_eq!(oo.id, leaf.key);
+                assert_eq!(oo.side_and_tree().side(), side);
+                assert!(
+                    on_book.insert((user, leaf.owner_slot as usize)),
+                    "open order slot used by two orders"
+                );
+            }
+            let root_leaf_count: u32 = bookside.roots.iter().map(|root| root.leaf_count).sum();
+            assert_eq!(leaf_count, root_leaf_count);
+        }
+
+        if let [Some(best_bid), Some(best_ask)] = best_valid {
+            assert!(
+                best_bid < best_ask,
+                "crossed book: {} / {}",
+                best_bid,
+                best_ask
+            );
+        }
+
+        let mut pending = HashSet::new();
+        for (event, _slot) in self.event_heap.iter() {
+            match EventType::try_from(event.event_type).unwrap() {
+                EventType::Fill => {
+                    let fill: &FillEvent = cast_ref(event);
+                    if fill.maker_out() {
+                        pending.insert((user_index(&fill.maker), fill.maker_slot as usize));
+                    }
+                }
+                EventType::Out => {
+                    let out: &OutEvent = cast_ref(event);
+                    pending.insert((user_index(&out.owner), out.owner_slot as usize));
+                }
+            }
+        }
+
+        for (user, account) in self.accounts.iter().enumerate() {
+            for slot in 0..MAX_OPEN_ORDERS {
+                if account.open_order_by_raw_index(slot).is_free() {
+                    continue;
+                }
+                assert!(
+                    on_book.contains(&(user, slot)) || pending.contains(&(user, slot)),
+                    "open order {} of user {} is neither on the book nor pending",
+                    slot,
+                    user
+                );
+            }
+        }
+    }
+}
+
+fuzz_target!(|data: FuzzData| {
+    let mut market = FuzzMarket::new(&data);
+    for action in data.actions.iter() {
+        let before = market.clone();
+        if market.run(action).is_err() {
+            market = before;
+        }
+        market.check_conservation();
+        market.check_book();
+    }
+});
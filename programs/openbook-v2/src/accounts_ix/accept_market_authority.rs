@@ -0,0 +1,21 @@
+use crate::error::OpenBookError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AcceptMarketAuthority<'info> {
+    pub pending_admin: Signer<'info>,
+    #[account(mut)]
+    pub market: AccountLoader<'info, Market>,
+    #[account(
+        mut,
+        has_one = market,
+        has_one = payer,
+        constraint = pending_market_admin.new_admin == pending_admin.key() @ OpenBookError::InvalidPendingMarketAdmin,
+        close = payer,
+    )]
+    pub pending_market_admin: Account<'info, PendingMarketAdmin>,
+    /// CHECK: Receives the rent of pending_market_admin, checked by its has_one
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+}
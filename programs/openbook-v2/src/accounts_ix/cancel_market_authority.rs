@@ -0,0 +1,18 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CancelMarketAuthority<'info> {
+    pub admin: Signer<'info>,
+    pub market: AccountLoader<'info, Market>,
+    #[account(
+        mut,
+        has_one = market,
+        has_one = payer,
+        close = payer,
+    )]
+    pub pending_market_admin: Account<'info, PendingMarketAdmin>,
+    /// CHECK: Receives the rent of pending_market_admin, checked by its has_one
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+}
@@ -12,6 +12,7 @@ pub struct CancelOrder<'info> {
     )]
     pub open_orders_account: AccountLoader<'info, OpenOrdersAccount>,
     #[account(
+        mut,
         has_one = bids,
         has_one = asks,
     )]
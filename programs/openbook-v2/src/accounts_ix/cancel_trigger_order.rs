@@ -0,0 +1,21 @@
+use crate::error::OpenBookError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CancelTriggerOrder<'info> {
+    pub signer: Signer<'info>,
+    #[account(
+        constraint = open_orders_account.load()?.is_owner_or_delegate(signer.key()) @ OpenBookError::NoOwnerOrDelegate
+    )]
+    pub open_orders_account: AccountLoader<'info, OpenOrdersAccount>,
+    #[account(
+        mut,
+        has_one = open_orders_account,
+        close = sol_destination,
+    )]
+    pub trigger_order: AccountLoader<'info, TriggerOrder>,
+    #[account(mut)]
+    /// CHECK: target for account rent needs no checks
+    pub sol_destination: UncheckedAccount<'info>,
+}
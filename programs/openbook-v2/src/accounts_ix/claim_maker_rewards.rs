@@ -0,0 +1,27 @@
+use crate::error::OpenBookError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct ClaimMakerRewards<'info> {
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        has_one = market,
+        constraint = open_orders_account.load()?.is_owner_or_delegate(owner.key()) @ OpenBookError::NoOwnerOrDelegate
+    )]
+    pub open_orders_account: AccountLoader<'info, OpenOrdersAccount>,
+    pub market: AccountLoader<'info, Market>,
+    #[account(mut, has_one = market, has_one = reward_vault)]
+    pub maker_rewards: AccountLoader<'info, MakerRewards>,
+    #[account(mut)]
+    pub reward_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = reward_vault.mint,
+        constraint = open_orders_account.load()?.is_settle_destination_allowed(owner.key(), user_reward_account.owner)
+    )]
+    pub user_reward_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
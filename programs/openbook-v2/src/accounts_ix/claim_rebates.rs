@@ -0,0 +1,32 @@
+use crate::error::OpenBookError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct ClaimRebates<'info> {
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        has_one = market,
+        constraint = open_orders_account.load()?.is_owner_or_delegate(owner.key()) @ OpenBookError::NoOwnerOrDelegate
+    )]
+    pub open_orders_account: AccountLoader<'info, OpenOrdersAccount>,
+    #[account(
+        mut,
+        has_one = market_quote_vault,
+        has_one = market_authority,
+    )]
+    pub market: AccountLoader<'info, Market>,
+    /// CHECK: checked on has_one in market
+    pub market_authority: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub market_quote_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = market_quote_vault.mint,
+        constraint = open_orders_account.load()?.is_settle_destination_allowed(owner.key(), rebate_receiver_account.owner)
+    )]
+    pub rebate_receiver_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
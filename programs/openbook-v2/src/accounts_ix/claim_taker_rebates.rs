@@ -0,0 +1,27 @@
+use crate::error::OpenBookError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct ClaimTakerRebates<'info> {
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        has_one = market,
+        constraint = open_orders_account.load()?.is_owner_or_delegate(owner.key()) @ OpenBookError::NoOwnerOrDelegate
+    )]
+    pub open_orders_account: AccountLoader<'info, OpenOrdersAccount>,
+    pub market: AccountLoader<'info, Market>,
+    #[account(mut, has_one = market, has_one = rebate_vault)]
+    pub taker_rebate_campaign: AccountLoader<'info, TakerRebateCampaign>,
+    #[account(mut)]
+    pub rebate_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = rebate_vault.mint,
+        constraint = open_orders_account.load()?.is_settle_destination_allowed(owner.key(), user_quote_account.owner)
+    )]
+    pub user_quote_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
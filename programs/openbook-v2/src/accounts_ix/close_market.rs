@@ -1,7 +1,7 @@
 use crate::error::OpenBookError;
 use crate::state::*;
 use anchor_lang::prelude::*;
-use anchor_spl::token::Token;
+use anchor_spl::token::{Token, TokenAccount};
 
 #[derive(Accounts)]
 pub struct CloseMarket<'info> {
@@ -11,11 +11,16 @@ pub struct CloseMarket<'info> {
         has_one = bids,
         has_one = asks,
         has_one = event_heap,
+        has_one = market_authority,
+        has_one = market_base_vault,
+        has_one = market_quote_vault,
         close = sol_destination,
         constraint = market.load()?.close_market_admin.is_some() @ OpenBookError::NoCloseMarketAdmin,
         constraint = market.load()?.close_market_admin == close_market_admin.key() @ OpenBookError::InvalidCloseMarketAdmin
     )]
     pub market: AccountLoader<'info, Market>,
+    /// CHECK: checked on has_one in market
+    pub market_authority: UncheckedAccount<'info>,
 
     #[account(
         mut,
@@ -35,6 +40,26 @@ pub struct CloseMarket<'info> {
     )]
     pub event_heap: AccountLoader<'info, EventHeap>,
 
+    #[account(mut)]
+    pub market_base_vault: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub market_quote_vault: Account<'info, TokenAccount>,
+
+    /// Collect fee admin's account receiving what's left in the base vault
+    #[account(
+        mut,
+        token::mint = market_base_vault.mint,
+        constraint = base_dust_receiver.owner == market.load()?.collect_fee_admin @ OpenBookError::InvalidDustReceiver
+    )]
+    pub base_dust_receiver: Account<'info, TokenAccount>,
+    /// Collect fee admin's account receiving what's left in the quote vault
+    #[account(
+        mut,
+        token::mint = market_quote_vault.mint,
+        constraint = quote_dust_receiver.owner == market.load()?.collect_fee_admin @ OpenBookError::InvalidDustReceiver
+    )]
+    pub quote_dust_receiver: Account<'info, TokenAccount>,
+
     #[account(mut)]
     /// CHECK: target for account rent needs no checks
     pub sol_destination: UncheckedAccount<'info>,
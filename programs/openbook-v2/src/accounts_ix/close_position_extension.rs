@@ -0,0 +1,19 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ClosePositionExtension<'info> {
+    pub owner: Signer<'info>,
+    #[account(mut, has_one = owner)]
+    pub open_orders_account: AccountLoader<'info, OpenOrdersAccount>,
+    #[account(
+        mut,
+        seeds = [b"PositionExtension".as_ref(), open_orders_account.key().as_ref()],
+        bump = position_extension.load()?.bump,
+        close = sol_destination,
+    )]
+    pub position_extension: AccountLoader<'info, PositionExtension>,
+    #[account(mut)]
+    /// CHECK: target for account rent needs no checks
+    pub sol_destination: UncheckedAccount<'info>,
+}
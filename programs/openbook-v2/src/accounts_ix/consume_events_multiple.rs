@@ -0,0 +1,60 @@
+use crate::accounts_ix::ConsumeEvents;
+use crate::error::OpenBookError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Number of remaining accounts every market to crank starts with, in order:
+/// market, event_heap. Its open orders accounts follow.
+pub const CONSUME_EVENTS_MULTIPLE_ACCOUNTS: usize = 2;
+
+#[derive(Accounts)]
+pub struct ConsumeEventsMultiple<'info> {
+    /// Needed for the markets with a `consume_events_admin`, which has to be this signer
+    pub consume_events_admin: Option<Signer<'info>>,
+}
+
+impl<'info> ConsumeEventsMultiple<'info> {
+    /// Build a [`ConsumeEvents`] out of the market and event heap of one group of
+    /// remaining accounts, running the same checks anchor would run on the
+    /// single-market instruction.
+    pub fn to_consume_events(
+        &self,
+        ais: &'info [AccountInfo<'info>],
+    ) -> Result<ConsumeEvents<'info>> {
+        require_eq!(
+            ais.len(),
+            CONSUME_EVENTS_MULTIPLE_ACCOUNTS,
+            OpenBookError::InvalidInputRemainingAccounts
+        );
+
+        let market: AccountLoader<Market> = AccountLoader::try_from(&ais[0])?;
+        let event_heap: AccountLoader<EventHeap> = AccountLoader::try_from(&ais[1])?;
+
+        let consume_events_admin = {
+            let market = market.load()?;
+            require_keys_eq!(
+                market.event_heap,
+                event_heap.key(),
+                ErrorCode::ConstraintHasOne
+            );
+            match Option::<Pubkey>::from(market.consume_events_admin) {
+                Some(admin) => {
+                    require!(
+                        self.consume_events_admin
+                            .as_ref()
+                            .map_or(false, |signer| signer.key() == admin),
+                        OpenBookError::InvalidConsumeEventsAdmin
+                    );
+                    self.consume_events_admin.clone()
+                }
+                None => None,
+            }
+        };
+
+        Ok(ConsumeEvents {
+            consume_events_admin,
+            market,
+            event_heap,
+        })
+    }
+}
@@ -0,0 +1,49 @@
+use crate::accounts_ix::{ConsumeEvents, ConsumeEventsBumps};
+use crate::error::OpenBookError;
+use crate::fill_callback::FILL_CALLBACK_AUTHORITY_SEED;
+use crate::pubkey_option::NonZeroKey;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ConsumeEventsWithFillCallback<'info> {
+    pub consume_events_admin: Option<Signer<'info>>,
+    #[account(
+        mut,
+        has_one = event_heap,
+        constraint = market.load()?.consume_events_admin == consume_events_admin.non_zero_key() @ OpenBookError::InvalidConsumeEventsAdmin
+    )]
+    pub market: AccountLoader<'info, Market>,
+    #[account(mut)]
+    pub event_heap: AccountLoader<'info, EventHeap>,
+    #[account(
+        has_one = market,
+        constraint = fill_callback.program == Some(fill_callback_program.key()) @ OpenBookError::InvalidFillCallbackProgram
+    )]
+    pub fill_callback: Account<'info, FillCallback>,
+    /// CHECK: Only signs the callback, checked by the seeds
+    #[account(
+        seeds = [FILL_CALLBACK_AUTHORITY_SEED, market.key().as_ref()],
+        bump,
+    )]
+    pub fill_callback_authority: UncheckedAccount<'info>,
+    /// CHECK: The market's fill callback program, checked above
+    #[account(executable)]
+    pub fill_callback_program: UncheckedAccount<'info>,
+}
+
+impl<'info> ConsumeEventsWithFillCallback<'info> {
+    pub fn to_consume_events(&self) -> ConsumeEvents<'info> {
+        ConsumeEvents {
+            consume_events_admin: self.consume_events_admin.clone(),
+            market: self.market.clone(),
+            event_heap: self.event_heap.clone(),
+        }
+    }
+}
+
+impl ConsumeEventsWithFillCallbackBumps {
+    pub fn to_consume_events(&self) -> ConsumeEventsBumps {
+        ConsumeEventsBumps {}
+    }
+}
@@ -0,0 +1,37 @@
+use crate::accounts_ix::{ConsumeEvents, ConsumeEventsBumps};
+use crate::error::OpenBookError;
+use crate::pubkey_option::NonZeroKey;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ConsumeEventsWithReward<'info> {
+    /// Receives the reward for the consumed events
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+    pub consume_events_admin: Option<Signer<'info>>,
+    #[account(
+        mut,
+        has_one = event_heap,
+        constraint = market.load()?.consume_events_admin == consume_events_admin.non_zero_key() @ OpenBookError::InvalidConsumeEventsAdmin
+    )]
+    pub market: AccountLoader<'info, Market>,
+    #[account(mut)]
+    pub event_heap: AccountLoader<'info, EventHeap>,
+}
+
+impl<'info> ConsumeEventsWithReward<'info> {
+    pub fn to_consume_events(&self) -> ConsumeEvents<'info> {
+        ConsumeEvents {
+            consume_events_admin: self.consume_events_admin.clone(),
+            market: self.market.clone(),
+            event_heap: self.event_heap.clone(),
+        }
+    }
+}
+
+impl ConsumeEventsWithRewardBumps {
+    pub fn to_consume_events(&self) -> ConsumeEventsBumps {
+        ConsumeEventsBumps {}
+    }
+}
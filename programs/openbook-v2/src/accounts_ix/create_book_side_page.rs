@@ -0,0 +1,19 @@
+use crate::error::OpenBookError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CreateBookSidePage<'info> {
+    pub market: AccountLoader<'info, Market>,
+    /// The market's bids or asks
+    #[account(
+        mut,
+        constraint = book_side.key() == market.load()?.bids
+            || book_side.key() == market.load()?.asks @ OpenBookError::InvalidBookSidePage,
+    )]
+    pub book_side: AccountLoader<'info, BookSide>,
+    /// Account is initialized by client,
+    /// anchor discriminator is set first when ix exits,
+    #[account(zero)]
+    pub page: AccountLoader<'info, BookSide>,
+}
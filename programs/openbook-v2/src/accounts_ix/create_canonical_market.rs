@@ -0,0 +1,118 @@
+use crate::accounts_ix::{CreateMarket, CreateMarketBumps};
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(name: String, oracle_config: OracleConfigParams, quote_lot_size: i64, base_lot_size: i64)]
+pub struct CreateCanonicalMarket<'info> {
+    #[account(
+        init,
+        seeds = [
+            b"CanonicalMarket".as_ref(),
+            base_mint.key().as_ref(),
+            quote_mint.key().as_ref(),
+            &base_lot_size.to_le_bytes(),
+            &quote_lot_size.to_le_bytes(),
+        ],
+        bump,
+        payer = payer,
+        space = 8 + std::mem::size_of::<Market>(),
+    )]
+    pub market: AccountLoader<'info, Market>,
+    #[account(
+        seeds = [b"Market".as_ref(), market.key().to_bytes().as_ref()],
+        bump,
+    )]
+    /// CHECK:
+    pub market_authority: UncheckedAccount<'info>,
+
+    /// Accounts are initialized by client,
+    /// anchor discriminator is set first when ix exits,
+    #[account(zero)]
+    pub bids: AccountLoader<'info, BookSide>,
+    #[account(zero)]
+    pub asks: AccountLoader<'info, BookSide>,
+    #[account(zero)]
+    pub event_heap: AccountLoader<'info, EventHeap>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = base_mint,
+        associated_token::authority = market_authority,
+    )]
+    pub market_base_vault: Account<'info, TokenAccount>,
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = quote_mint,
+        associated_token::authority = market_authority,
+    )]
+    pub market_quote_vault: Account<'info, TokenAccount>,
+
+    #[account(constraint = base_mint.key() != quote_mint.key())]
+    pub base_mint: Box<Account<'info, Mint>>,
+    pub quote_mint: Box<Account<'info, Mint>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    /// CHECK: The oracle can be one of several different account types
+    pub oracle_a: Option<UncheckedAccount<'info>>,
+    /// CHECK: The oracle can be one of several different account types
+    pub oracle_b: Option<UncheckedAccount<'info>>,
+
+    /// CHECK:
+    pub collect_fee_admin: UncheckedAccount<'info>,
+    /// CHECK:
+    pub open_orders_admin: Option<UncheckedAccount<'info>>,
+    /// CHECK:
+    pub consume_events_admin: Option<UncheckedAccount<'info>>,
+    /// CHECK:
+    pub close_market_admin: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> CreateCanonicalMarket<'info> {
+    pub fn to_create_market(&self) -> CreateMarket<'info> {
+        CreateMarket {
+            market: self.market.clone(),
+            market_authority: self.market_authority.clone(),
+            bids: self.bids.clone(),
+            asks: self.asks.clone(),
+            event_heap: self.event_heap.clone(),
+            payer: self.payer.clone(),
+            market_base_vault: self.market_base_vault.clone(),
+            market_quote_vault: self.market_quote_vault.clone(),
+            base_mint: self.base_mint.clone(),
+            quote_mint: self.quote_mint.clone(),
+            system_program: self.system_program.clone(),
+            token_program: self.token_program.clone(),
+            associated_token_program: self.associated_token_program.clone(),
+            oracle_a: self.oracle_a.clone(),
+            oracle_b: self.oracle_b.clone(),
+            collect_fee_admin: self.collect_fee_admin.clone(),
+            open_orders_admin: self.open_orders_admin.clone(),
+            consume_events_admin: self.consume_events_admin.clone(),
+            close_market_admin: self.close_market_admin.clone(),
+            event_authority: self.event_authority.clone(),
+            program: self.program.clone(),
+        }
+    }
+}
+
+impl CreateCanonicalMarketBumps {
+    pub fn to_create_market(&self) -> CreateMarketBumps {
+        CreateMarketBumps {
+            market_authority: self.market_authority,
+            event_authority: self.event_authority,
+        }
+    }
+}
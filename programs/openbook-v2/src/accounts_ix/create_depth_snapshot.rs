@@ -0,0 +1,19 @@
+use crate::state::{DepthSnapshot, Market};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CreateDepthSnapshot<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub owner: Signer<'info>,
+    pub market: AccountLoader<'info, Market>,
+    #[account(
+        init,
+        seeds = [b"DepthSnapshot".as_ref(), market.key().as_ref(), owner.key().as_ref()],
+        bump,
+        payer = payer,
+        space = 8 + std::mem::size_of::<DepthSnapshot>(),
+    )]
+    pub depth_snapshot: AccountLoader<'info, DepthSnapshot>,
+    pub system_program: Program<'info, System>,
+}
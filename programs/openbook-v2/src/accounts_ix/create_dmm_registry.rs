@@ -0,0 +1,20 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CreateDmmRegistry<'info> {
+    pub collect_fee_admin: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(has_one = collect_fee_admin)]
+    pub market: AccountLoader<'info, Market>,
+    #[account(
+        init,
+        seeds = [b"DmmRegistry".as_ref(), market.key().as_ref()],
+        bump,
+        payer = payer,
+        space = 8 + std::mem::size_of::<DmmRegistry>(),
+    )]
+    pub dmm_registry: AccountLoader<'info, DmmRegistry>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,20 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CreateFillCallback<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub collect_fee_admin: Signer<'info>,
+    #[account(has_one = collect_fee_admin)]
+    pub market: AccountLoader<'info, Market>,
+    #[account(
+        init,
+        seeds = [b"FillCallback".as_ref(), market.key().as_ref()],
+        bump,
+        payer = payer,
+        space = FillCallback::SPACE,
+    )]
+    pub fill_callback: Account<'info, FillCallback>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,18 @@
+use crate::state::{FillHistory, Market};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CreateFillHistory<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub market: AccountLoader<'info, Market>,
+    #[account(
+        init,
+        seeds = [b"FillHistory".as_ref(), market.key().as_ref()],
+        bump,
+        payer = payer,
+        space = 8 + std::mem::size_of::<FillHistory>(),
+    )]
+    pub fill_history: AccountLoader<'info, FillHistory>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,34 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+#[derive(Accounts)]
+pub struct CreateMakerRewards<'info> {
+    pub collect_fee_admin: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut, has_one = collect_fee_admin)]
+    pub market: AccountLoader<'info, Market>,
+    #[account(
+        init,
+        seeds = [b"MakerRewards".as_ref(), market.key().as_ref()],
+        bump,
+        payer = payer,
+        space = 8 + std::mem::size_of::<MakerRewards>(),
+    )]
+    pub maker_rewards: AccountLoader<'info, MakerRewards>,
+    pub reward_mint: Box<Account<'info, Mint>>,
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = reward_mint,
+        associated_token::authority = maker_rewards,
+    )]
+    pub reward_vault: Box<Account<'info, TokenAccount>>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
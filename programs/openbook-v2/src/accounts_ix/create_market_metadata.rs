@@ -0,0 +1,20 @@
+use crate::state::{Market, MarketMetadata};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CreateMarketMetadata<'info> {
+    pub collect_fee_admin: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(has_one = collect_fee_admin)]
+    pub market: AccountLoader<'info, Market>,
+    #[account(
+        init,
+        seeds = [b"MarketMetadata".as_ref(), market.key().as_ref()],
+        bump,
+        payer = payer,
+        space = 8 + std::mem::size_of::<MarketMetadata>(),
+    )]
+    pub market_metadata: AccountLoader<'info, MarketMetadata>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,25 @@
+use crate::error::OpenBookError;
+use crate::state::MarketRegistryPage;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(page_index: u32)]
+pub struct CreateMarketRegistryPage<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// Required for every page but the first one.
+    #[account(
+        constraint = previous_page.load()?.page_index + 1 == page_index @ OpenBookError::InvalidMarketRegistryPage,
+        constraint = previous_page.load()?.is_full() @ OpenBookError::InvalidMarketRegistryPage,
+    )]
+    pub previous_page: Option<AccountLoader<'info, MarketRegistryPage>>,
+    #[account(
+        init,
+        seeds = [b"MarketRegistry".as_ref(), &page_index.to_le_bytes()],
+        bump,
+        payer = payer,
+        space = 8 + std::mem::size_of::<MarketRegistryPage>(),
+    )]
+    pub registry_page: AccountLoader<'info, MarketRegistryPage>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,18 @@
+use crate::state::{Market, MarketStats};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CreateMarketStats<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub market: AccountLoader<'info, Market>,
+    #[account(
+        init,
+        seeds = [b"MarketStats".as_ref(), market.key().as_ref()],
+        bump,
+        payer = payer,
+        space = 8 + std::mem::size_of::<MarketStats>(),
+    )]
+    pub market_stats: AccountLoader<'info, MarketStats>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,23 @@
+use crate::error::OpenBookError;
+use crate::state::{Market, MerkleAllowlist};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CreateMerkleAllowlist<'info> {
+    pub open_orders_admin: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        constraint = market.load()?.open_orders_admin == open_orders_admin.key() @ OpenBookError::InvalidOpenOrdersAdmin
+    )]
+    pub market: AccountLoader<'info, Market>,
+    #[account(
+        init,
+        seeds = [b"MerkleAllowlist".as_ref(), market.key().as_ref()],
+        bump,
+        payer = payer,
+        space = 8 + std::mem::size_of::<MerkleAllowlist>(),
+    )]
+    pub merkle_allowlist: AccountLoader<'info, MerkleAllowlist>,
+    pub system_program: Program<'info, System>,
+}
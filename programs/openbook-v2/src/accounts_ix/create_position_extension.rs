@@ -0,0 +1,20 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CreatePositionExtension<'info> {
+    pub owner: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut, has_one = owner)]
+    pub open_orders_account: AccountLoader<'info, OpenOrdersAccount>,
+    #[account(
+        init,
+        seeds = [b"PositionExtension".as_ref(), open_orders_account.key().as_ref()],
+        bump,
+        payer = payer,
+        space = 8 + std::mem::size_of::<PositionExtension>(),
+    )]
+    pub position_extension: AccountLoader<'info, PositionExtension>,
+    pub system_program: Program<'info, System>,
+}
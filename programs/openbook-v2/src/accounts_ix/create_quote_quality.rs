@@ -0,0 +1,22 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CreateQuoteQuality<'info> {
+    pub collect_fee_admin: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(has_one = collect_fee_admin)]
+    pub market: AccountLoader<'info, Market>,
+    #[account(has_one = market)]
+    pub open_orders_account: AccountLoader<'info, OpenOrdersAccount>,
+    #[account(
+        init,
+        seeds = [b"QuoteQuality".as_ref(), open_orders_account.key().as_ref()],
+        bump,
+        payer = payer,
+        space = 8 + std::mem::size_of::<QuoteQuality>(),
+    )]
+    pub quote_quality: AccountLoader<'info, QuoteQuality>,
+    pub system_program: Program<'info, System>,
+}
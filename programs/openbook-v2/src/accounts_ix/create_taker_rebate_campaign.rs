@@ -0,0 +1,34 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+#[derive(Accounts)]
+pub struct CreateTakerRebateCampaign<'info> {
+    pub collect_fee_admin: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(has_one = collect_fee_admin, has_one = quote_mint)]
+    pub market: AccountLoader<'info, Market>,
+    #[account(
+        init,
+        seeds = [b"TakerRebateCampaign".as_ref(), market.key().as_ref()],
+        bump,
+        payer = payer,
+        space = 8 + std::mem::size_of::<TakerRebateCampaign>(),
+    )]
+    pub taker_rebate_campaign: AccountLoader<'info, TakerRebateCampaign>,
+    pub quote_mint: Box<Account<'info, Mint>>,
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = quote_mint,
+        associated_token::authority = taker_rebate_campaign,
+    )]
+    pub rebate_vault: Box<Account<'info, TokenAccount>>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
@@ -0,0 +1,25 @@
+use crate::error::OpenBookError;
+use crate::state::{Market, TokenGate};
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+#[derive(Accounts)]
+pub struct CreateTokenGate<'info> {
+    pub open_orders_admin: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        constraint = market.load()?.open_orders_admin == open_orders_admin.key() @ OpenBookError::InvalidOpenOrdersAdmin
+    )]
+    pub market: AccountLoader<'info, Market>,
+    pub mint: Box<Account<'info, Mint>>,
+    #[account(
+        init,
+        seeds = [b"TokenGate".as_ref(), market.key().as_ref()],
+        bump,
+        payer = payer,
+        space = 8 + std::mem::size_of::<TokenGate>(),
+    )]
+    pub token_gate: AccountLoader<'info, TokenGate>,
+    pub system_program: Program<'info, System>,
+}
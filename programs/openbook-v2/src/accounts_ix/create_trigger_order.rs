@@ -0,0 +1,26 @@
+use crate::error::OpenBookError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(trigger_id: u64)]
+pub struct CreateTriggerOrder<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub signer: Signer<'info>,
+    #[account(
+        has_one = market,
+        constraint = open_orders_account.load()?.is_owner_or_delegate(signer.key()) @ OpenBookError::NoOwnerOrDelegate
+    )]
+    pub open_orders_account: AccountLoader<'info, OpenOrdersAccount>,
+    pub market: AccountLoader<'info, Market>,
+    #[account(
+        init,
+        seeds = [b"TriggerOrder".as_ref(), open_orders_account.key().as_ref(), &trigger_id.to_le_bytes()],
+        bump,
+        payer = payer,
+        space = 8 + std::mem::size_of::<TriggerOrder>(),
+    )]
+    pub trigger_order: AccountLoader<'info, TriggerOrder>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,22 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct EnforceDmmObligation<'info> {
+    pub market: AccountLoader<'info, Market>,
+    #[account(
+        mut,
+        has_one = market,
+    )]
+    pub dmm_registry: AccountLoader<'info, DmmRegistry>,
+    #[account(
+        mut,
+        has_one = market,
+    )]
+    pub open_orders_account: AccountLoader<'info, OpenOrdersAccount>,
+    #[account(
+        has_one = market,
+        has_one = open_orders_account,
+    )]
+    pub quote_quality: AccountLoader<'info, QuoteQuality>,
+}
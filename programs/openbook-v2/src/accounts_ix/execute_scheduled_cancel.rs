@@ -0,0 +1,29 @@
+use crate::state::{BookSide, Market, OpenOrdersAccount, PositionExtension};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ExecuteScheduledCancel<'info> {
+    /// Receives the heartbeat bounty, if any
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+    #[account(
+        mut,
+        has_one = market,
+    )]
+    pub open_orders_account: AccountLoader<'info, OpenOrdersAccount>,
+    #[account(
+        mut,
+        has_one = open_orders_account,
+    )]
+    pub position_extension: AccountLoader<'info, PositionExtension>,
+    #[account(
+        mut,
+        has_one = bids,
+        has_one = asks,
+    )]
+    pub market: AccountLoader<'info, Market>,
+    #[account(mut)]
+    pub bids: AccountLoader<'info, BookSide>,
+    #[account(mut)]
+    pub asks: AccountLoader<'info, BookSide>,
+}
@@ -0,0 +1,48 @@
+use crate::error::OpenBookError;
+use crate::pubkey_option::NonZeroKey;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteTrigger<'info> {
+    /// Receives the rent of the trigger order
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+    #[account(
+        mut,
+        has_one = open_orders_account,
+        has_one = market,
+        close = keeper,
+    )]
+    pub trigger_order: AccountLoader<'info, TriggerOrder>,
+    #[account(
+        mut,
+        has_one = market,
+    )]
+    pub open_orders_account: AccountLoader<'info, OpenOrdersAccount>,
+    pub open_orders_admin: Option<Signer<'info>>,
+
+    #[account(
+        mut,
+        has_one = bids,
+        has_one = asks,
+        has_one = event_heap,
+        constraint = market.load()?.oracle_a == oracle_a.non_zero_key(),
+        constraint = market.load()?.oracle_b == oracle_b.non_zero_key(),
+        constraint = market.load()?.open_orders_admin == open_orders_admin.non_zero_key()
+            || open_orders_account.load()?.is_allowlisted() @ OpenBookError::InvalidOpenOrdersAdmin
+    )]
+    pub market: AccountLoader<'info, Market>,
+    #[account(mut)]
+    pub bids: AccountLoader<'info, BookSide>,
+    #[account(mut)]
+    pub asks: AccountLoader<'info, BookSide>,
+    #[account(mut)]
+    pub event_heap: AccountLoader<'info, EventHeap>,
+
+    /// CHECK: The oracle can be one of several different account types and the pubkey is checked above
+    pub oracle_a: Option<UncheckedAccount<'info>>,
+    /// CHECK: The oracle can be one of several different account types and the pubkey is checked above
+    pub oracle_b: Option<UncheckedAccount<'info>>,
+}
@@ -0,0 +1,19 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ExpireOrders<'info> {
+    #[account(
+        mut,
+        has_one = bids,
+        has_one = asks,
+        has_one = event_heap,
+    )]
+    pub market: AccountLoader<'info, Market>,
+    #[account(mut)]
+    pub bids: AccountLoader<'info, BookSide>,
+    #[account(mut)]
+    pub asks: AccountLoader<'info, BookSide>,
+    #[account(mut)]
+    pub event_heap: AccountLoader<'info, EventHeap>,
+}
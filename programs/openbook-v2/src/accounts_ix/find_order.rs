@@ -0,0 +1,15 @@
+use crate::state::{BookSide, Market, OpenOrdersAccount};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct FindOrder<'info> {
+    #[account(has_one = market)]
+    pub open_orders_account: AccountLoader<'info, OpenOrdersAccount>,
+    #[account(
+        has_one = bids,
+        has_one = asks,
+    )]
+    pub market: AccountLoader<'info, Market>,
+    pub bids: AccountLoader<'info, BookSide>,
+    pub asks: AccountLoader<'info, BookSide>,
+}
@@ -0,0 +1,21 @@
+use crate::pubkey_option::NonZeroKey;
+use crate::state::{BookSide, Market};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct GetMarketQuote<'info> {
+    #[account(
+        has_one = bids,
+        has_one = asks,
+        constraint = market.load()?.oracle_a == oracle_a.non_zero_key(),
+        constraint = market.load()?.oracle_b == oracle_b.non_zero_key(),
+    )]
+    pub market: AccountLoader<'info, Market>,
+    pub bids: AccountLoader<'info, BookSide>,
+    pub asks: AccountLoader<'info, BookSide>,
+
+    /// CHECK: The oracle can be one of several different account types and the pubkey is checked above
+    pub oracle_a: Option<UncheckedAccount<'info>>,
+    /// CHECK: The oracle can be one of several different account types and the pubkey is checked above
+    pub oracle_b: Option<UncheckedAccount<'info>>,
+}
@@ -0,0 +1,17 @@
+use crate::error::OpenBookError;
+use crate::state::{OpenOrdersAccount, PositionExtension};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct Heartbeat<'info> {
+    pub signer: Signer<'info>,
+    #[account(
+        constraint = open_orders_account.load()?.is_owner_or_delegate(signer.key()) @ OpenBookError::NoOwnerOrDelegate
+    )]
+    pub open_orders_account: AccountLoader<'info, OpenOrdersAccount>,
+    #[account(
+        mut,
+        has_one = open_orders_account,
+    )]
+    pub position_extension: AccountLoader<'info, PositionExtension>,
+}
@@ -0,0 +1,16 @@
+use crate::state::{Market, MerkleAllowlist, OpenOrdersAccount};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct JoinMerkleAllowlist<'info> {
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        has_one = market,
+        has_one = owner,
+    )]
+    pub open_orders_account: AccountLoader<'info, OpenOrdersAccount>,
+    pub market: AccountLoader<'info, Market>,
+    #[account(has_one = market)]
+    pub merkle_allowlist: AccountLoader<'info, MerkleAllowlist>,
+}
@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    /// CHECK: A market or open orders account, the discriminator is checked in the
+    /// instruction.
+    #[account(mut, owner = crate::id())]
+    pub account: UncheckedAccount<'info>,
+}
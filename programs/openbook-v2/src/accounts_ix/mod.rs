@@ -1,43 +1,193 @@
+pub use accept_market_authority::*;
 pub use cancel_all_and_place_orders::*;
+pub use cancel_market_authority::*;
 pub use cancel_order::*;
+pub use cancel_trigger_order::*;
+pub use claim_maker_rewards::*;
+pub use claim_rebates::*;
+pub use claim_taker_rebates::*;
 pub use close_market::*;
 pub use close_open_orders_account::*;
 pub use close_open_orders_indexer::*;
+pub use close_position_extension::*;
 pub use consume_events::*;
+pub use consume_events_multiple::*;
+pub use consume_events_with_fill_callback::*;
+pub use consume_events_with_reward::*;
+pub use create_book_side_page::*;
+pub use create_canonical_market::*;
+pub use create_depth_snapshot::*;
+pub use create_dmm_registry::*;
+pub use create_fill_callback::*;
+pub use create_fill_history::*;
+pub use create_maker_rewards::*;
 pub use create_market::*;
+pub use create_market_metadata::*;
+pub use create_market_registry_page::*;
+pub use create_market_stats::*;
+pub use create_merkle_allowlist::*;
 pub use create_open_orders_account::*;
 pub use create_open_orders_indexer::*;
+pub use create_position_extension::*;
+pub use create_quote_quality::*;
+pub use create_taker_program_allowlist::*;
+pub use create_taker_rebate_campaign::*;
+pub use create_token_gate::*;
+pub use create_trigger_order::*;
+pub use defragment_book::*;
 pub use deposit::*;
+pub use enforce_dmm_obligation::*;
+pub use execute_scheduled_cancel::*;
+pub use execute_trigger::*;
+pub use expire_orders::*;
+pub use find_order::*;
+pub use force_cancel_orders::*;
+pub use fund_taker_rebate_campaign::*;
+pub use get_market_quote::*;
+pub use heartbeat::*;
+pub use join_merkle_allowlist::*;
+pub use migrate_account::*;
 pub use place_order::*;
+pub use place_order_and_settle::*;
+pub use place_order_relayed::*;
+pub use place_signed_order::*;
 pub use place_take_order::*;
 pub use prune_orders::*;
+pub use register_market::*;
+pub use resize_event_heap::*;
+pub use revoke_merkle_allowlist_approval::*;
+pub use sample_quote_quality::*;
+pub use schedule_cancel_all::*;
+pub use set_binary_outcome::*;
+pub use set_consume_events_reward::*;
 pub use set_delegate::*;
+pub use set_direct_settlement::*;
+pub use set_dmm_slot::*;
+pub use set_eviction_policy::*;
+pub use set_fee_rounding::*;
+pub use set_fill_callback::*;
+pub use set_heartbeat_interval::*;
+pub use set_maker_rewards::*;
+pub use set_market_authority::*;
 pub use set_market_expired::*;
+pub use set_market_metadata::*;
+pub use set_market_state::*;
+pub use set_max_resting_orders::*;
+pub use set_merkle_allowlist_root::*;
+pub use set_min_order_notional::*;
+pub use set_order_rate_limit::*;
+pub use set_risk_program::*;
+pub use set_settle_receiver::*;
+pub use set_successor_market::*;
+pub use set_taker_program_allowlist::*;
+pub use set_taker_rebate_campaign::*;
+pub use settle_expired_market::*;
 pub use settle_funds::*;
 pub use settle_funds_expired::*;
+pub use settle_funds_multiple::*;
+pub use slash_market_bond::*;
+pub use snapshot_depth::*;
 pub use stub_oracle_close::*;
 pub use stub_oracle_create::*;
 pub use stub_oracle_set::*;
 pub use sweep_fees::*;
+pub use sweep_quote_dust::*;
+pub use verify_market_invariants::*;
+pub use withdraw_taker_rebate_budget::*;
 
+mod accept_market_authority;
 mod cancel_all_and_place_orders;
+mod cancel_market_authority;
 mod cancel_order;
+mod cancel_trigger_order;
+mod claim_maker_rewards;
+mod claim_rebates;
+mod claim_taker_rebates;
 mod close_market;
 mod close_open_orders_account;
 mod close_open_orders_indexer;
+mod close_position_extension;
 mod consume_events;
+mod consume_events_multiple;
+mod consume_events_with_fill_callback;
+mod consume_events_with_reward;
+mod create_book_side_page;
+mod create_canonical_market;
+mod create_depth_snapshot;
+mod create_dmm_registry;
+mod create_fill_callback;
+mod create_fill_history;
+mod create_maker_rewards;
 mod create_market;
+mod create_market_metadata;
+mod create_market_registry_page;
+mod create_market_stats;
+mod create_merkle_allowlist;
 mod create_open_orders_account;
 mod create_open_orders_indexer;
+mod create_position_extension;
+mod create_quote_quality;
+mod create_taker_program_allowlist;
+mod create_taker_rebate_campaign;
+mod create_token_gate;
+mod create_trigger_order;
+mod defragment_book;
 mod deposit;
+mod enforce_dmm_obligation;
+mod execute_scheduled_cancel;
+mod execute_trigger;
+mod expire_orders;
+mod find_order;
+mod force_cancel_orders;
+mod fund_taker_rebate_campaign;
+mod get_market_quote;
+mod heartbeat;
+mod join_merkle_allowlist;
+mod migrate_account;
 mod place_order;
+mod place_order_and_settle;
+mod place_order_relayed;
+mod place_signed_order;
 mod place_take_order;
 mod prune_orders;
+mod register_market;
+mod resize_event_heap;
+mod revoke_merkle_allowlist_approval;
+mod sample_quote_quality;
+mod schedule_cancel_all;
+mod set_binary_outcome;
+mod set_consume_events_reward;
 mod set_delegate;
+mod set_direct_settlement;
+mod set_dmm_slot;
+mod set_eviction_policy;
+mod set_fee_rounding;
+mod set_fill_callback;
+mod set_heartbeat_interval;
+mod set_maker_rewards;
+mod set_market_authority;
 mod set_market_expired;
+mod set_market_metadata;
+mod set_market_state;
+mod set_max_resting_orders;
+mod set_merkle_allowlist_root;
+mod set_min_order_notional;
+mod set_order_rate_limit;
+mod set_risk_program;
+mod set_settle_receiver;
+mod set_successor_market;
+mod set_taker_program_allowlist;
+mod set_taker_rebate_campaign;
+mod settle_expired_market;
 mod settle_funds;
 mod settle_funds_expired;
+mod settle_funds_multiple;
+mod slash_market_bond;
+mod snapshot_depth;
 mod stub_oracle_close;
 mod stub_oracle_create;
 mod stub_oracle_set;
 mod sweep_fees;
+mod sweep_quote_dust;
+mod verify_market_invariants;
+mod withdraw_taker_rebate_budget;
@@ -5,6 +5,7 @@ use crate::state::*;
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Token, TokenAccount};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct PlaceOrder<'info> {
     pub signer: Signer<'info>,
@@ -29,7 +30,9 @@ pub struct PlaceOrder<'info> {
         has_one = event_heap,
         constraint = market.load()?.oracle_a == oracle_a.non_zero_key(),
         constraint = market.load()?.oracle_b == oracle_b.non_zero_key(),
-        constraint = market.load()?.open_orders_admin == open_orders_admin.non_zero_key() @ OpenBookError::InvalidOpenOrdersAdmin
+        constraint = market.load()?.open_orders_admin == open_orders_admin.non_zero_key()
+            || open_orders_account.load()?.is_allowlisted()
+            || holds_gate_token(&token_gate, &gate_token_account, &open_orders_account.load()?.owner)? @ OpenBookError::InvalidOpenOrdersAdmin
     )]
     pub market: AccountLoader<'info, Market>,
     #[account(mut)]
@@ -51,6 +54,10 @@ pub struct PlaceOrder<'info> {
     pub oracle_b: Option<UncheckedAccount<'info>>,
 
     pub token_program: Program<'info, Token>,
+
+    #[account(has_one = market)]
+    pub token_gate: Option<AccountLoader<'info, TokenGate>>,
+    pub gate_token_account: Option<Box<Account<'info, TokenAccount>>>,
 }
 
 impl<'info> PlaceOrder<'info> {
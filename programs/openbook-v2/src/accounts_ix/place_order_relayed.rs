@@ -0,0 +1,100 @@
+use crate::accounts_ix::{PlaceOrder, PlaceOrderBumps};
+use crate::error::OpenBookError;
+use crate::pubkey_option::NonZeroKey;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct PlaceOrderRelayed<'info> {
+    /// Owner or delegate of the open orders account, e.g. a session key
+    pub signer: Signer<'info>,
+    /// Pays the transaction fee, receiving `relayer_fee` in exchange
+    pub relayer: Signer<'info>,
+    #[account(
+        mut,
+        token::mint = user_token_account.mint
+    )]
+    pub relayer_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        has_one = market,
+        constraint = open_orders_account.load()?.is_owner_or_delegate(signer.key()) @ OpenBookError::NoOwnerOrDelegate
+    )]
+    pub open_orders_account: AccountLoader<'info, OpenOrdersAccount>,
+    pub open_orders_admin: Option<Signer<'info>>,
+
+    #[account(
+        mut,
+        token::mint = market_vault.mint
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        has_one = bids,
+        has_one = asks,
+        has_one = event_heap,
+        constraint = market.load()?.oracle_a == oracle_a.non_zero_key(),
+        constraint = market.load()?.oracle_b == oracle_b.non_zero_key(),
+        constraint = market.load()?.open_orders_admin == open_orders_admin.non_zero_key()
+            || open_orders_account.load()?.is_allowlisted()
+            || holds_gate_token(&token_gate, &gate_token_account, &open_orders_account.load()?.owner)? @ OpenBookError::InvalidOpenOrdersAdmin
+    )]
+    pub market: AccountLoader<'info, Market>,
+    #[account(mut)]
+    pub bids: AccountLoader<'info, BookSide>,
+    #[account(mut)]
+    pub asks: AccountLoader<'info, BookSide>,
+    #[account(mut)]
+    pub event_heap: AccountLoader<'info, EventHeap>,
+    #[account(
+        mut,
+        // The side of the vault is checked inside the ix
+        constraint = market.load()?.is_market_vault(market_vault.key())
+    )]
+    pub market_vault: Account<'info, TokenAccount>,
+
+    /// CHECK: The oracle can be one of several different account types and the pubkey is checked above
+    pub oracle_a: Option<UncheckedAccount<'info>>,
+    /// CHECK: The oracle can be one of several different account types and the pubkey is checked above
+    pub oracle_b: Option<UncheckedAccount<'info>>,
+
+    pub token_program: Program<'info, Token>,
+
+    #[account(has_one = market)]
+    pub token_gate: Option<AccountLoader<'info, TokenGate>>,
+    pub gate_token_account: Option<Box<Account<'info, TokenAccount>>>,
+}
+
+impl<'info> PlaceOrderRelayed<'info> {
+    pub fn to_place_order(&self) -> PlaceOrder<'info> {
+        PlaceOrder {
+            signer: self.signer.clone(),
+            open_orders_account: self.open_orders_account.clone(),
+            open_orders_admin: self.open_orders_admin.clone(),
+            user_token_account: self.user_token_account.clone(),
+            market: self.market.clone(),
+            bids: self.bids.clone(),
+            asks: self.asks.clone(),
+            event_heap: self.event_heap.clone(),
+            market_vault: self.market_vault.clone(),
+            oracle_a: self.oracle_a.clone(),
+            oracle_b: self.oracle_b.clone(),
+            token_program: self.token_program.clone(),
+            token_gate: self.token_gate.clone(),
+            gate_token_account: self.gate_token_account.clone(),
+            event_authority: self.event_authority.clone(),
+            program: self.program.clone(),
+        }
+    }
+}
+
+impl PlaceOrderRelayedBumps {
+    pub fn to_place_order(&self) -> PlaceOrderBumps {
+        PlaceOrderBumps {
+            event_authority: self.event_authority,
+        }
+    }
+}
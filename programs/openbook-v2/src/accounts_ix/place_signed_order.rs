@@ -0,0 +1,51 @@
+use crate::error::OpenBookError;
+use crate::pubkey_option::NonZeroKey;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+use anchor_spl::token::TokenAccount;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct PlaceSignedOrder<'info> {
+    /// Submits the order signed by the owner or delegate, doesn't need to be
+    /// either
+    pub relayer: Signer<'info>,
+    #[account(
+        mut,
+        has_one = market,
+    )]
+    pub open_orders_account: AccountLoader<'info, OpenOrdersAccount>,
+    pub open_orders_admin: Option<Signer<'info>>,
+
+    #[account(
+        mut,
+        has_one = bids,
+        has_one = asks,
+        has_one = event_heap,
+        constraint = market.load()?.oracle_a == oracle_a.non_zero_key(),
+        constraint = market.load()?.oracle_b == oracle_b.non_zero_key(),
+        constraint = market.load()?.open_orders_admin == open_orders_admin.non_zero_key()
+            || open_orders_account.load()?.is_allowlisted()
+            || holds_gate_token(&token_gate, &gate_token_account, &open_orders_account.load()?.owner)? @ OpenBookError::InvalidOpenOrdersAdmin
+    )]
+    pub market: AccountLoader<'info, Market>,
+    #[account(mut)]
+    pub bids: AccountLoader<'info, BookSide>,
+    #[account(mut)]
+    pub asks: AccountLoader<'info, BookSide>,
+    #[account(mut)]
+    pub event_heap: AccountLoader<'info, EventHeap>,
+
+    /// CHECK: The oracle can be one of several different account types and the pubkey is checked above
+    pub oracle_a: Option<UncheckedAccount<'info>>,
+    /// CHECK: The oracle can be one of several different account types and the pubkey is checked above
+    pub oracle_b: Option<UncheckedAccount<'info>>,
+    /// CHECK: address is checked, holds the ed25519 signature of the order
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    #[account(has_one = market)]
+    pub token_gate: Option<AccountLoader<'info, TokenGate>>,
+    pub gate_token_account: Option<Box<Account<'info, TokenAccount>>>,
+}
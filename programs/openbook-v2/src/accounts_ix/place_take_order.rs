@@ -2,11 +2,12 @@ use crate::error::OpenBookError;
 use crate::pubkey_option::NonZeroKey;
 use crate::state::*;
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
 use anchor_spl::token::{Token, TokenAccount};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct PlaceTakeOrder<'info> {
-    #[account(mut)]
     pub signer: Signer<'info>,
     #[account(mut)]
     pub penalty_payer: Signer<'info>,
@@ -21,7 +22,8 @@ pub struct PlaceTakeOrder<'info> {
         has_one = market_authority,
         constraint = market.load()?.oracle_a == oracle_a.non_zero_key(),
         constraint = market.load()?.oracle_b == oracle_b.non_zero_key(),
-        constraint = market.load()?.open_orders_admin == open_orders_admin.non_zero_key() @ OpenBookError::InvalidOpenOrdersAdmin
+        constraint = market.load()?.open_orders_admin == open_orders_admin.non_zero_key()
+            || is_cpi_from_allowed_taker_program(&taker_program_allowlist, &instructions_sysvar)? @ OpenBookError::InvalidOpenOrdersAdmin
     )]
     pub market: AccountLoader<'info, Market>,
     /// CHECK: checked on has_one in market
@@ -56,4 +58,21 @@ pub struct PlaceTakeOrder<'info> {
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
     pub open_orders_admin: Option<Signer<'info>>,
+    #[account(has_one = market)]
+    pub taker_program_allowlist: Option<AccountLoader<'info, TakerProgramAllowlist>>,
+    /// CHECK: address is checked, only needed along with taker_program_allowlist
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+}
+
+fn is_cpi_from_allowed_taker_program(
+    taker_program_allowlist: &Option<AccountLoader<TakerProgramAllowlist>>,
+    instructions_sysvar: &Option<UncheckedAccount>,
+) -> Result<bool> {
+    match (taker_program_allowlist, instructions_sysvar) {
+        (Some(taker_program_allowlist), Some(instructions_sysvar)) => taker_program_allowlist
+            .load()?
+            .is_cpi_from_allowed_program(instructions_sysvar),
+        _ => Ok(false),
+    }
 }
@@ -11,6 +11,7 @@ pub struct PruneOrders<'info> {
     )]
     pub open_orders_account: AccountLoader<'info, OpenOrdersAccount>,
     #[account(
+        mut,
         has_one = bids,
         has_one = asks,
         constraint = market.load()?.close_market_admin.is_some() @ OpenBookError::NoCloseMarketAdmin,
@@ -0,0 +1,17 @@
+use crate::error::OpenBookError;
+use crate::state::{Market, MarketRegistryPage};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RegisterMarket<'info> {
+    #[account(
+        mut,
+        constraint = market.load()?.registered == 0 @ OpenBookError::MarketAlreadyRegistered,
+    )]
+    pub market: AccountLoader<'info, Market>,
+    #[account(
+        mut,
+        constraint = !registry_page.load()?.is_full() @ OpenBookError::InvalidMarketRegistryPage,
+    )]
+    pub registry_page: AccountLoader<'info, MarketRegistryPage>,
+}
@@ -0,0 +1,24 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(additional_nodes: u16)]
+pub struct ResizeEventHeap<'info> {
+    pub collect_fee_admin: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        has_one = collect_fee_admin,
+        has_one = event_heap,
+    )]
+    pub market: AccountLoader<'info, Market>,
+    #[account(
+        mut,
+        realloc = event_heap.to_account_info().data_len() + additional_nodes as usize * std::mem::size_of::<EventNode>(),
+        realloc::payer = payer,
+        realloc::zero = true,
+    )]
+    pub event_heap: AccountLoader<'info, EventHeap>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,17 @@
+use crate::error::OpenBookError;
+use crate::state::{Market, OpenOrdersAccount};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RevokeMerkleAllowlistApproval<'info> {
+    pub open_orders_admin: Signer<'info>,
+    #[account(
+        constraint = market.load()?.open_orders_admin == open_orders_admin.key() @ OpenBookError::InvalidOpenOrdersAdmin
+    )]
+    pub market: AccountLoader<'info, Market>,
+    #[account(
+        mut,
+        has_one = market,
+    )]
+    pub open_orders_account: AccountLoader<'info, OpenOrdersAccount>,
+}
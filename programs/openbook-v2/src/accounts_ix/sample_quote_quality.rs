@@ -0,0 +1,23 @@
+use crate::pubkey_option::NonZeroKey;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SampleQuoteQuality<'info> {
+    #[account(mut, has_one = market)]
+    pub quote_quality: AccountLoader<'info, QuoteQuality>,
+    #[account(
+        has_one = bids,
+        has_one = asks,
+        constraint = market.load()?.oracle_a == oracle_a.non_zero_key(),
+        constraint = market.load()?.oracle_b == oracle_b.non_zero_key(),
+    )]
+    pub market: AccountLoader<'info, Market>,
+    pub bids: AccountLoader<'info, BookSide>,
+    pub asks: AccountLoader<'info, BookSide>,
+
+    /// CHECK: The oracle can be one of several different account types and the pubkey is checked above
+    pub oracle_a: Option<UncheckedAccount<'info>>,
+    /// CHECK: The oracle can be one of several different account types and the pubkey is checked above
+    pub oracle_b: Option<UncheckedAccount<'info>>,
+}
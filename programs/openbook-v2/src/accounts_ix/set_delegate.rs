@@ -4,7 +4,6 @@ use crate::state::OpenOrdersAccount;
 
 #[derive(Accounts)]
 pub struct SetDelegate<'info> {
-    #[account(mut)]
     pub owner: Signer<'info>,
     #[account(
         mut,
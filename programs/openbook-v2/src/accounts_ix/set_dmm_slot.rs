@@ -0,0 +1,19 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetDmmSlot<'info> {
+    pub collect_fee_admin: Signer<'info>,
+    #[account(has_one = collect_fee_admin)]
+    pub market: AccountLoader<'info, Market>,
+    #[account(
+        mut,
+        has_one = market,
+    )]
+    pub dmm_registry: AccountLoader<'info, DmmRegistry>,
+    #[account(
+        mut,
+        has_one = market,
+    )]
+    pub open_orders_account: AccountLoader<'info, OpenOrdersAccount>,
+}
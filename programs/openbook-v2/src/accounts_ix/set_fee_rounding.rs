@@ -0,0 +1,12 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetFeeRounding<'info> {
+    pub collect_fee_admin: Signer<'info>,
+    #[account(
+        mut,
+        has_one = collect_fee_admin,
+    )]
+    pub market: AccountLoader<'info, Market>,
+}
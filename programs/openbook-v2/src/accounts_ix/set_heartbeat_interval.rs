@@ -0,0 +1,25 @@
+use crate::error::OpenBookError;
+use crate::state::{OpenOrdersAccount, PositionExtension};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetHeartbeatInterval<'info> {
+    /// Pays the bounty
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    /// Gets the previous bounty back
+    /// CHECK: checked on has_one in open_orders_account
+    #[account(mut)]
+    pub owner: UncheckedAccount<'info>,
+    #[account(
+        has_one = owner,
+        constraint = open_orders_account.load()?.is_owner_or_delegate(signer.key()) @ OpenBookError::NoOwnerOrDelegate
+    )]
+    pub open_orders_account: AccountLoader<'info, OpenOrdersAccount>,
+    #[account(
+        mut,
+        has_one = open_orders_account,
+    )]
+    pub position_extension: AccountLoader<'info, PositionExtension>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,19 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetMarketAuthority<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub admin: Signer<'info>,
+    pub market: AccountLoader<'info, Market>,
+    #[account(
+        init,
+        seeds = [b"PendingMarketAdmin".as_ref(), market.key().as_ref()],
+        bump,
+        payer = payer,
+        space = PendingMarketAdmin::SPACE,
+    )]
+    pub pending_market_admin: Account<'info, PendingMarketAdmin>,
+    pub system_program: Program<'info, System>,
+}
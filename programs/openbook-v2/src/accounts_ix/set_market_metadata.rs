@@ -0,0 +1,14 @@
+use crate::state::{Market, MarketMetadata};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetMarketMetadata<'info> {
+    pub collect_fee_admin: Signer<'info>,
+    #[account(has_one = collect_fee_admin)]
+    pub market: AccountLoader<'info, Market>,
+    #[account(
+        mut,
+        has_one = market,
+    )]
+    pub market_metadata: AccountLoader<'info, MarketMetadata>,
+}
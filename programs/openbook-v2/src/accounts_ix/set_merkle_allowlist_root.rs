@@ -0,0 +1,17 @@
+use crate::error::OpenBookError;
+use crate::state::{Market, MerkleAllowlist};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetMerkleAllowlistRoot<'info> {
+    pub open_orders_admin: Signer<'info>,
+    #[account(
+        constraint = market.load()?.open_orders_admin == open_orders_admin.key() @ OpenBookError::InvalidOpenOrdersAdmin
+    )]
+    pub market: AccountLoader<'info, Market>,
+    #[account(
+        mut,
+        has_one = market,
+    )]
+    pub merkle_allowlist: AccountLoader<'info, MerkleAllowlist>,
+}
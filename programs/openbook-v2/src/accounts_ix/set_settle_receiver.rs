@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{OpenOrdersAccount, PositionExtension};
+
+#[derive(Accounts)]
+pub struct SetSettleReceiver<'info> {
+    pub owner: Signer<'info>,
+    #[account(has_one = owner)]
+    pub open_orders_account: AccountLoader<'info, OpenOrdersAccount>,
+    #[account(
+        mut,
+        has_one = open_orders_account,
+    )]
+    pub position_extension: AccountLoader<'info, PositionExtension>,
+}
@@ -0,0 +1,16 @@
+use crate::state::{Market, MarketMetadata};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetSuccessorMarket<'info> {
+    pub collect_fee_admin: Signer<'info>,
+    #[account(has_one = collect_fee_admin)]
+    pub market: AccountLoader<'info, Market>,
+    #[account(
+        mut,
+        has_one = market,
+    )]
+    pub market_metadata: AccountLoader<'info, MarketMetadata>,
+    /// None to unlink the market from its successor
+    pub successor_market: Option<AccountLoader<'info, Market>>,
+}
@@ -0,0 +1,11 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetTakerRebateCampaign<'info> {
+    pub collect_fee_admin: Signer<'info>,
+    #[account(has_one = collect_fee_admin)]
+    pub market: AccountLoader<'info, Market>,
+    #[account(mut, has_one = market)]
+    pub taker_rebate_campaign: AccountLoader<'info, TakerRebateCampaign>,
+}
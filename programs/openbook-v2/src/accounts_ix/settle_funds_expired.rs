@@ -7,7 +7,6 @@ use anchor_spl::token::{Token, TokenAccount};
 #[derive(Accounts)]
 pub struct SettleFundsExpired<'info> {
     pub close_market_admin: Signer<'info>,
-    #[account(mut)]
     pub owner: Signer<'info>,
     #[account(mut)]
     pub penalty_payer: Signer<'info>,
@@ -35,12 +34,14 @@ pub struct SettleFundsExpired<'info> {
         mut,
         token::mint = market_base_vault.mint,
         constraint = user_base_account.owner == open_orders_account.load()?.owner
+            || owner.key() == open_orders_account.load()?.owner
     )]
     pub user_base_account: Account<'info, TokenAccount>,
     #[account(
         mut,
         token::mint = market_quote_vault.mint,
         constraint = user_quote_account.owner == open_orders_account.load()?.owner
+            || owner.key() == open_orders_account.load()?.owner
     )]
     pub user_quote_account: Account<'info, TokenAccount>,
     #[account(
@@ -0,0 +1,100 @@
+use crate::accounts_ix::SettleFunds;
+use crate::error::OpenBookError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+/// Number of remaining accounts expected for every market to settle, in order:
+/// open_orders_account, market, market_authority, market_base_vault,
+/// market_quote_vault, user_base_account, user_quote_account.
+pub const SETTLE_FUNDS_MULTIPLE_ACCOUNTS: usize = 7;
+
+#[derive(Accounts)]
+pub struct SettleFundsMultiple<'info> {
+    pub owner: Signer<'info>,
+    #[account(mut)]
+    pub penalty_payer: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SettleFundsMultiple<'info> {
+    /// Build a [`SettleFunds`] out of one group of remaining accounts, running
+    /// the same checks anchor would run on the single-market instruction.
+    pub fn to_settle_funds(&self, ais: &'info [AccountInfo<'info>]) -> Result<SettleFunds<'info>> {
+        require_eq!(
+            ais.len(),
+            SETTLE_FUNDS_MULTIPLE_ACCOUNTS,
+            OpenBookError::InvalidInputRemainingAccounts
+        );
+
+        let open_orders_account: AccountLoader<OpenOrdersAccount> =
+            AccountLoader::try_from(&ais[0])?;
+        let market: AccountLoader<Market> = AccountLoader::try_from(&ais[1])?;
+        let market_authority = UncheckedAccount::try_from(&ais[2]);
+        let market_base_vault: Account<TokenAccount> = Account::try_from(&ais[3])?;
+        let market_quote_vault: Account<TokenAccount> = Account::try_from(&ais[4])?;
+        let user_base_account: Account<TokenAccount> = Account::try_from(&ais[5])?;
+        let user_quote_account: Account<TokenAccount> = Account::try_from(&ais[6])?;
+
+        {
+            let oo = open_orders_account.load()?;
+            require_keys_eq!(oo.market, market.key(), ErrorCode::ConstraintHasOne);
+            require!(
+                oo.is_owner_or_delegate(self.owner.key()),
+                OpenBookError::NoOwnerOrDelegate
+            );
+            require!(
+                oo.is_settle_destination_allowed(self.owner.key(), user_base_account.owner),
+                ErrorCode::ConstraintRaw
+            );
+            require!(
+                oo.is_settle_destination_allowed(self.owner.key(), user_quote_account.owner),
+                ErrorCode::ConstraintRaw
+            );
+
+            let market = market.load()?;
+            require_keys_eq!(
+                market.market_base_vault,
+                market_base_vault.key(),
+                ErrorCode::ConstraintHasOne
+            );
+            require_keys_eq!(
+                market.market_quote_vault,
+                market_quote_vault.key(),
+                ErrorCode::ConstraintHasOne
+            );
+            require_keys_eq!(
+                market.market_authority,
+                market_authority.key(),
+                ErrorCode::ConstraintHasOne
+            );
+        }
+
+        require_keys_eq!(
+            user_base_account.mint,
+            market_base_vault.mint,
+            ErrorCode::ConstraintTokenMint
+        );
+        require_keys_eq!(
+            user_quote_account.mint,
+            market_quote_vault.mint,
+            ErrorCode::ConstraintTokenMint
+        );
+
+        Ok(SettleFunds {
+            owner: self.owner.clone(),
+            penalty_payer: self.penalty_payer.clone(),
+            open_orders_account,
+            market,
+            market_authority,
+            market_base_vault,
+            market_quote_vault,
+            user_base_account,
+            user_quote_account,
+            referrer_account: None,
+            token_program: self.token_program.clone(),
+            system_program: self.system_program.clone(),
+        })
+    }
+}
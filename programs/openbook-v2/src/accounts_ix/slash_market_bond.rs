@@ -0,0 +1,21 @@
+use crate::error::OpenBookError;
+use crate::program::OpenbookV2;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SlashMarketBond<'info> {
+    /// The program's upgrade authority
+    pub governance: Signer<'info>,
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, OpenbookV2>,
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(governance.key()) @ OpenBookError::InvalidGovernance
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    #[account(mut)]
+    pub market: AccountLoader<'info, Market>,
+    #[account(mut)]
+    /// CHECK: target for the slashed bond needs no checks
+    pub destination: UncheckedAccount<'info>,
+}
@@ -0,0 +1,13 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+#[derive(Accounts)]
+pub struct SweepQuoteDust<'info> {
+    #[account(
+        mut,
+        has_one = market_quote_vault,
+    )]
+    pub market: AccountLoader<'info, Market>,
+    pub market_quote_vault: Account<'info, TokenAccount>,
+}
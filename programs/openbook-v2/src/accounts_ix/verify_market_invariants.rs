@@ -0,0 +1,18 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+#[derive(Accounts)]
+pub struct VerifyMarketInvariants<'info> {
+    #[account(
+        has_one = event_heap,
+        has_one = market_base_vault,
+        has_one = market_quote_vault,
+    )]
+    pub market: AccountLoader<'info, Market>,
+    /// Only read, writable for loading the nodes added by `resize_event_heap`
+    #[account(mut)]
+    pub event_heap: AccountLoader<'info, EventHeap>,
+    pub market_base_vault: Account<'info, TokenAccount>,
+    pub market_quote_vault: Account<'info, TokenAccount>,
+}
@@ -0,0 +1,20 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct WithdrawTakerRebateBudget<'info> {
+    pub collect_fee_admin: Signer<'info>,
+    #[account(has_one = collect_fee_admin)]
+    pub market: AccountLoader<'info, Market>,
+    #[account(mut, has_one = market, has_one = rebate_vault)]
+    pub taker_rebate_campaign: AccountLoader<'info, TakerRebateCampaign>,
+    #[account(mut)]
+    pub rebate_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = rebate_vault.mint,
+    )]
+    pub token_receiver_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
@@ -178,6 +178,36 @@ impl KeyedAccountReader for KeyedAccountSharedData {
 // Common traits for loading from account data.
 //
 
+/// Parse accounts from raw data, e.g. as returned by an RPC node, without an
+/// AccountInfo or anchor's AccountDeserialize.
+///
+/// Only the discriminator is checked. The data doesn't need to be aligned, and is
+/// copied into the returned value.
+#[cfg(any(feature = "client", feature = "wasm"))]
+pub trait FromBytes: Sized {
+    fn from_bytes(data: &[u8]) -> Result<Self>;
+}
+
+#[cfg(any(feature = "client", feature = "wasm"))]
+impl<T: ZeroCopy> FromBytes for T {
+    fn from_bytes(data: &[u8]) -> Result<Self> {
+        if data.len() < 8 {
+            return Err(ErrorCode::AccountDiscriminatorNotFound.into());
+        }
+        let disc_bytes = array_ref![data, 0, 8];
+        if disc_bytes != &T::discriminator() {
+            return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+        }
+        if data.len() < mem::size_of::<T>() + 8 {
+            return Err(ErrorCode::AccountDidNotDeserialize.into());
+        }
+
+        Ok(bytemuck::pod_read_unaligned(
+            &data[8..mem::size_of::<T>() + 8],
+        ))
+    }
+}
+
 pub trait LoadZeroCopy {
     /// Using AccountLoader forces a AccountInfo.clone() and then binds the loaded
     /// lifetime to the AccountLoader's lifetime. This function avoids both.
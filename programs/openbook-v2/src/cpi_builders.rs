@@ -0,0 +1,176 @@
+//! Typed [`CpiContext`] builders for programs composing with OpenBook.
+//!
+//! Only needs the `cpi` feature. [`MarketCpiAccounts`] holds the accounts of a
+//! market that most instructions share, each method then takes the accounts
+//! specific to one instruction and returns a context ready for the matching
+//! function in [`crate::cpi`].
+
+use anchor_lang::prelude::*;
+
+use crate::cpi::accounts::{CancelOrder, ConsumeEvents, PlaceOrder, PlaceTakeOrder, SettleFunds};
+use crate::state::Side;
+
+/// Accounts of a market, matching the keys stored in [`Market`](crate::state::Market).
+#[derive(Clone)]
+pub struct MarketCpiAccounts<'info> {
+    /// The OpenBook program itself.
+    pub program: AccountInfo<'info>,
+    /// PDA with seeds `[b"__event_authority"]`, needed by instructions emitting events through CPI.
+    pub event_authority: AccountInfo<'info>,
+    pub market: AccountInfo<'info>,
+    pub market_authority: AccountInfo<'info>,
+    pub bids: AccountInfo<'info>,
+    pub asks: AccountInfo<'info>,
+    pub event_heap: AccountInfo<'info>,
+    pub market_base_vault: AccountInfo<'info>,
+    pub market_quote_vault: AccountInfo<'info>,
+    pub oracle_a: Option<AccountInfo<'info>>,
+    pub oracle_b: Option<AccountInfo<'info>>,
+    pub token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> MarketCpiAccounts<'info> {
+    fn vault(&self, side: Side) -> AccountInfo<'info> {
+        match side {
+            Side::Bid => self.market_quote_vault.clone(),
+            Side::Ask => self.market_base_vault.clone(),
+        }
+    }
+
+    /// Context for `place_order`, `place_orders` and `edit_order`.
+    ///
+    /// `user_token_account` holds quote tokens for bids and base tokens for asks.
+    pub fn place_order<'a, 'b, 'c>(
+        &self,
+        side: Side,
+        signer: AccountInfo<'info>,
+        open_orders_account: AccountInfo<'info>,
+        open_orders_admin: Option<AccountInfo<'info>>,
+        user_token_account: AccountInfo<'info>,
+    ) -> CpiContext<'a, 'b, 'c, 'info, PlaceOrder<'info>> {
+        CpiContext::new(
+            self.program.clone(),
+            PlaceOrder {
+                signer,
+                open_orders_account,
+                open_orders_admin,
+                user_token_account,
+                market: self.market.clone(),
+                bids: self.bids.clone(),
+                asks: self.asks.clone(),
+                event_heap: self.event_heap.clone(),
+                market_vault: self.vault(side),
+                oracle_a: self.oracle_a.clone(),
+                oracle_b: self.oracle_b.clone(),
+                token_program: self.token_program.clone(),
+                event_authority: self.event_authority.clone(),
+                program: self.program.clone(),
+            },
+        )
+    }
+
+    /// Context for `place_take_order`.
+    pub fn place_take_order<'a, 'b, 'c>(
+        &self,
+        signer: AccountInfo<'info>,
+        penalty_payer: AccountInfo<'info>,
+        user_base_account: AccountInfo<'info>,
+        user_quote_account: AccountInfo<'info>,
+        open_orders_admin: Option<AccountInfo<'info>>,
+    ) -> CpiContext<'a, 'b, 'c, 'info, PlaceTakeOrder<'info>> {
+        CpiContext::new(
+            self.program.clone(),
+            PlaceTakeOrder {
+                signer,
+                penalty_payer,
+                market: self.market.clone(),
+                market_authority: self.market_authority.clone(),
+                bids: self.bids.clone(),
+                asks: self.asks.clone(),
+                market_base_vault: self.market_base_vault.clone(),
+                market_quote_vault: self.market_quote_vault.clone(),
+                event_heap: self.event_heap.clone(),
+                user_base_account,
+                user_quote_account,
+                oracle_a: self.oracle_a.clone(),
+                oracle_b: self.oracle_b.clone(),
+                token_program: self.token_program.clone(),
+                system_program: self.system_program.clone(),
+                open_orders_admin,
+                taker_program_allowlist: None,
+                instructions_sysvar: None,
+                event_authority: self.event_authority.clone(),
+                program: self.program.clone(),
+            },
+        )
+    }
+
+    /// Context for `cancel_order`, `cancel_order_by_client_order_id` and
+    /// `cancel_all_orders`.
+    pub fn cancel_order<'a, 'b, 'c>(
+        &self,
+        signer: AccountInfo<'info>,
+        open_orders_account: AccountInfo<'info>,
+    ) -> CpiContext<'a, 'b, 'c, 'info, CancelOrder<'info>> {
+        CpiContext::new(
+            self.program.clone(),
+            CancelOrder {
+                signer,
+                open_orders_account,
+                market: self.market.clone(),
+                bids: self.bids.clone(),
+                asks: self.asks.clone(),
+                event_authority: self.event_authority.clone(),
+                program: self.program.clone(),
+            },
+        )
+    }
+
+    /// Context for `settle_funds`.
+    pub fn settle_funds<'a, 'b, 'c>(
+        &self,
+        owner: AccountInfo<'info>,
+        penalty_payer: AccountInfo<'info>,
+        open_orders_account: AccountInfo<'info>,
+        user_base_account: AccountInfo<'info>,
+        user_quote_account: AccountInfo<'info>,
+        referrer_account: Option<AccountInfo<'info>>,
+    ) -> CpiContext<'a, 'b, 'c, 'info, SettleFunds<'info>> {
+        CpiContext::new(
+            self.program.clone(),
+            SettleFunds {
+                owner,
+                penalty_payer,
+                open_orders_account,
+                market: self.market.clone(),
+                market_authority: self.market_authority.clone(),
+                market_base_vault: self.market_base_vault.clone(),
+                market_quote_vault: self.market_quote_vault.clone(),
+                user_base_account,
+                user_quote_account,
+                referrer_account,
+                token_program: self.token_program.clone(),
+                system_program: self.system_program.clone(),
+            },
+        )
+    }
+
+    /// Context for `consume_events` and `consume_given_events`.
+    ///
+    /// The open orders accounts of the makers go in the remaining accounts, see
+    /// [`CpiContext::with_remaining_accounts`].
+    pub fn consume_events<'a, 'b, 'c>(
+        &self,
+        consume_events_admin: Option<AccountInfo<'info>>,
+    ) -> CpiContext<'a, 'b, 'c, 'info, ConsumeEvents<'info>> {
+        CpiContext::new(
+            self.program.clone(),
+            ConsumeEvents {
+                consume_events_admin,
+                market: self.market.clone(),
+                event_heap: self.event_heap.clone(),
+            },
+        )
+    }
+}
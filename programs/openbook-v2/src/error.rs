@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
 use core::fmt::Display;
+use num_enum::TryFromPrimitive;
 
 #[error_code]
+#[derive(PartialEq, Eq, TryFromPrimitive)]
 pub enum OpenBookError {
     #[msg("")]
     SomeError,
@@ -95,6 +97,144 @@ pub enum OpenBookError {
     NonEmptyOpenOrdersPosition,
     #[msg("Fill-Or-Kill order would generate a partial execution")]
     WouldExecutePartially,
+    #[msg("Remaining accounts are missing or not grouped as expected")]
+    InvalidInputRemainingAccounts,
+    #[msg("Open orders account is not registered in the owner's indexer")]
+    OpenOrdersAccountNotIndexed,
+    #[msg("Event heap is full and its oldest event's open orders account is missing")]
+    EventHeapFull,
+    #[msg("Invalid fill callback program")]
+    InvalidFillCallbackProgram,
+    #[msg("Fill history belongs to another market")]
+    InvalidFillHistory,
+    #[msg("Market stats belong to another market")]
+    InvalidMarketStats,
+    #[msg("Market only accepts post only orders")]
+    MarketIsPostOnly,
+    #[msg("Market only allows cancelling orders")]
+    MarketIsCancelOnly,
+    #[msg("Market is paused")]
+    MarketIsPaused,
+    #[msg("No valid oracle price to settle the market at")]
+    SettlementPriceUnavailable,
+    #[msg("Market has been settled")]
+    MarketIsSettled,
+    #[msg("Settlement price is only given, and should be positive, for markets without an oracle")]
+    InvalidInputSettlementPrice,
+    #[msg("Market is not a binary outcome market")]
+    NotBinaryOutcomeMarket,
+    #[msg("Binary outcome market has not been resolved")]
+    BinaryOutcomeUnresolved,
+    #[msg("Signer doesn't hold this market admin role")]
+    InvalidMarketAdmin,
+    #[msg("Signer isn't the pending market admin")]
+    InvalidPendingMarketAdmin,
+    #[msg("Market registry page is full or out of order")]
+    InvalidMarketRegistryPage,
+    #[msg("Market is already in the market registry")]
+    MarketAlreadyRegistered,
+    #[msg("Signer is not the program's upgrade authority")]
+    InvalidGovernance,
+    #[msg("Dust receiver must be owned by the collect fee admin")]
+    InvalidDustReceiver,
+    #[msg("Owner is not in the market's allowlist")]
+    InvalidMerkleProof,
+    #[msg("Token account does not hold enough of the market's gate token")]
+    InvalidTokenGateAccount,
+    #[msg("Too many taker programs")]
+    TooManyTakerPrograms,
+    #[msg("Book side page is not the next page of the chain")]
+    InvalidBookSidePage,
+    #[msg("Book side has orders on pages that weren't passed")]
+    MissingBookSidePages,
+    #[msg("Book side has the maximum number of pages")]
+    TooManyBookSidePages,
+    #[msg("Amount overflows its type")]
+    MathOverflow,
+    #[msg("Depth levels above snapshot limit")]
+    InvalidInputDepthLevels,
+    #[msg("Quote quality tracking requires a market with an oracle")]
+    QuoteQualityWithoutOracle,
+    #[msg("Epoch duration should be greater than zero")]
+    InvalidInputEpochDuration,
+    #[msg("Taker rebate campaign belongs to another market")]
+    InvalidTakerRebateCampaign,
+    #[msg("Campaign should end after it starts and rebate at most 10000 bps")]
+    InvalidInputTakerRebateCampaign,
+    #[msg("Taker rebate campaign is still running")]
+    TakerRebateCampaignActive,
+    #[msg("Open orders account has a position extension that wasn't passed")]
+    MissingPositionExtension,
+    #[msg("Open orders account has a position extension that must be closed first")]
+    PositionExtensionNotClosed,
+    #[msg("Rate limited markets require a position extension on the open orders account")]
+    OrderRateLimitWithoutPositionExtension,
+    #[msg("Open orders account placed too many orders in the market's rate limit window")]
+    OrderRateLimitExceeded,
+    #[msg("Order rate limit window should be greater than zero")]
+    InvalidInputOrderRateLimit,
+    #[msg("Order is worth less than the market's minimum order notional")]
+    OrderNotionalTooSmall,
+    #[msg("Max resting orders above the size of open orders accounts")]
+    InvalidInputMaxRestingOrders,
+    #[msg("Open orders account has the market's max number of resting orders")]
+    MaxRestingOrdersReached,
+    #[msg("Successor market should be another market of the same token pair")]
+    InvalidSuccessorMarket,
+    #[msg("Version 0 open orders accounts need to cancel their bids before being migrated")]
+    MigrateAccountWithBids,
+    #[msg("Order price is outside the range of prices the book accepts")]
+    PriceOutsideBand,
+    #[msg("Token account doesn't hold enough tokens for the order's deposit")]
+    InsufficientFunds,
+    #[msg("Book side is full and the order isn't better than its worst order")]
+    BookFull,
+    #[msg("Event heap contains an event of unknown type")]
+    InvalidEventType,
+    #[msg("Event heap slot is out of range or empty")]
+    InvalidEventHeapSlot,
+    #[msg("Oracle exponent should be between -12 and 12")]
+    InvalidInputOracleExponent,
+    #[msg("Risk program of the open orders account missing from the remaining accounts")]
+    MissingRiskProgramAccount,
+    #[msg("Invalid risk program")]
+    InvalidRiskProgram,
+    #[msg("Invalid settle receiver program")]
+    InvalidSettleReceiverProgram,
+    #[msg("Settle receiver of the open orders account missing from the remaining accounts")]
+    MissingSettleReceiverAccount,
+    #[msg("Settle receiver authority missing or invalid")]
+    InvalidSettleReceiverAuthority,
+    #[msg("The market authority and vaults can't be passed to a settle receiver")]
+    InvalidSettleReceiverAccount,
+    #[msg("Trigger order condition is not met")]
+    TriggerConditionNotMet,
+    #[msg("Trigger order is already expired")]
+    TriggerOrderExpired,
+    #[msg("Free funds of the open orders account don't cover the order")]
+    InsufficientFreeFunds,
+    #[msg("No cancel is scheduled or its time hasn't come yet")]
+    ScheduledCancelNotDue,
+    #[msg("Heartbeat interval not set for the open orders account")]
+    HeartbeatNotEnabled,
+    #[msg("The previous instruction isn't an ed25519 signature of the signed order")]
+    InvalidSignedOrderSignature,
+    #[msg("Signed order signer is neither owner nor delegate")]
+    InvalidSignedOrderSigner,
+    #[msg("Signed order nonce already used")]
+    SignedOrderNonceUsed,
+    #[msg("Signed order is no longer valid")]
+    SignedOrderExpired,
+    #[msg("All designated market maker slots of the market are taken")]
+    DmmRegistryFull,
+    #[msg("The worst order of the full book side belongs to a designated market maker of higher priority")]
+    DmmOrderProtected,
+    #[msg("Open orders account is not a designated market maker of the market")]
+    NotADmm,
+    #[msg("Designated market maker met its quoting obligation")]
+    DmmObligationMet,
+    #[msg("Eviction band should be greater than zero")]
+    InvalidInputEvictionBand,
 }
 
 impl From<OpenBookError> for ProgramError {
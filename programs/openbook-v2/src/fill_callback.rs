@@ -0,0 +1,73 @@
+//! Interface of the program a [`Market`](crate::state::Market)'s admin can register
+//! in a [`FillCallback`](crate::state::FillCallback) account to be notified about
+//! consumed fills.
+//!
+//! After `consume_events_with_fill_callback` processed fills, it CPIs into the
+//! callback program with an anchor-style instruction: `sha256("global:on_fill")[..8]`
+//! followed by the borsh-serialized [`OnFillArgs`]. The only account passed is the
+//! market's fill callback authority, a PDA of seeds
+//! `[FILL_CALLBACK_AUTHORITY_SEED, market]` that signs nothing else, so the callback
+//! can check the call comes from the market it claims.
+//!
+//! The plain `consume_events` instructions never call back, so a failing callback
+//! can't keep the market's events from being processed.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::invoke_signed;
+
+use crate::state::FillEvent;
+
+pub const FILL_CALLBACK_AUTHORITY_SEED: &[u8] = b"FillCallbackAuthority";
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct OnFillArgs {
+    pub market: Pubkey,
+    pub fills: Vec<FillEvent>,
+}
+
+pub fn on_fill_discriminator() -> [u8; 8] {
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash(b"global:on_fill").to_bytes()[..8]);
+    discriminator
+}
+
+/// Notify `callback_program` about `fills`, signing with the market's fill
+/// callback authority.
+pub fn invoke_fill_callback<'info>(
+    market_pk: &Pubkey,
+    fills: Vec<FillEvent>,
+    callback_program: &AccountInfo<'info>,
+    callback_authority: &AccountInfo<'info>,
+    callback_authority_bump: u8,
+) -> Result<()> {
+    if fills.is_empty() {
+        return Ok(());
+    }
+
+    let mut data = on_fill_discriminator().to_vec();
+    OnFillArgs {
+        market: *market_pk,
+        fills,
+    }
+    .serialize(&mut data)?;
+
+    let ix = Instruction {
+        program_id: callback_program.key(),
+        accounts: vec![AccountMeta::new_readonly(callback_authority.key(), true)],
+        data,
+    };
+    let seeds = [
+        FILL_CALLBACK_AUTHORITY_SEED,
+        market_pk.as_ref(),
+        &[callback_authority_bump],
+    ];
+    invoke_signed(
+        &ix,
+        &[callback_authority.clone(), callback_program.clone()],
+        &[&seeds],
+    )?;
+
+    Ok(())
+}
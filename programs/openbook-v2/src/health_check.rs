@@ -0,0 +1,119 @@
+//! Interface of the program an [`OpenOrdersAccount`] can register to approve its
+//! new orders, so margin protocols can use open orders accounts directly as
+//! collateralized trading accounts.
+//!
+//! Before placing orders for an account with a risk program, OpenBook CPIs into
+//! it with an anchor-style instruction: `sha256("global:check_health")[..8]`
+//! followed by the borsh-serialized [`CheckHealthArgs`]. The accounts passed are
+//! the open orders account and the market, both read-only, holding the state
+//! from before the orders. Failing the instruction rejects the orders.
+//!
+//! The risk program is kept in the account's [`PositionExtension`], which is
+//! among the remaining accounts of every order placement anyway. Only the owner
+//! can change it, so a margin protocol keeps control over it by owning the open
+//! orders account through a PDA.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::invoke;
+
+use crate::error::OpenBookError;
+use crate::state::{load_position_extension, Market, OpenOrdersAccount, Order, OrderParams, Side};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HealthCheckOrder {
+    pub side: Side,
+    /// Worst price the order can execute at: the limit price, or the peg limit of
+    /// oracle pegged orders. `None` for market orders and unlimited pegged orders.
+    pub price_lots: Option<i64>,
+    pub max_base_lots: i64,
+    pub max_quote_lots_including_fees: i64,
+}
+
+impl From<&Order> for HealthCheckOrder {
+    fn from(order: &Order) -> Self {
+        let price_lots = match order.params {
+            OrderParams::Market => None,
+            OrderParams::ImmediateOrCancel { price_lots }
+            | OrderParams::Fixed { price_lots, .. }
+            | OrderParams::FillOrKill { price_lots } => Some(price_lots),
+            OrderParams::OraclePegged { peg_limit, .. } => (peg_limit != -1).then_some(peg_limit),
+        };
+        Self {
+            side: order.side,
+            price_lots,
+            max_base_lots: order.max_base_lots,
+            max_quote_lots_including_fees: order.max_quote_lots_including_fees,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CheckHealthArgs {
+    pub market: Pubkey,
+    pub open_orders_account: Pubkey,
+    pub orders: Vec<HealthCheckOrder>,
+}
+
+pub fn check_health_discriminator() -> [u8; 8] {
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash(b"global:check_health").to_bytes()[..8]);
+    discriminator
+}
+
+/// Ask the risk program of `open_orders_account`, if any, to approve `orders`.
+///
+/// The risk program has to be among the remaining accounts, along with the
+/// position extension. Neither account may be borrowed while calling this.
+pub fn invoke_health_check<'info>(
+    open_orders_account: &AccountLoader<'info, OpenOrdersAccount>,
+    market: &AccountLoader<'info, Market>,
+    orders: &[Order],
+    remaining_accs: &[AccountInfo<'info>],
+) -> Result<()> {
+    if orders.is_empty() {
+        return Ok(());
+    }
+    let risk_program = {
+        let account = open_orders_account.load()?;
+        let position_ext =
+            load_position_extension(&account, &open_orders_account.key(), remaining_accs)?;
+        match position_ext.and_then(|ext| Option::<Pubkey>::from(ext.risk_program)) {
+            Some(program) => program,
+            None => return Ok(()),
+        }
+    };
+
+    let program_ai = remaining_accs
+        .iter()
+        .find(|ai| ai.key == &risk_program)
+        .ok_or_else(|| error!(OpenBookError::MissingRiskProgramAccount))?;
+
+    let mut data = check_health_discriminator().to_vec();
+    CheckHealthArgs {
+        market: market.key(),
+        open_orders_account: open_orders_account.key(),
+        orders: orders.iter().map(HealthCheckOrder::from).collect(),
+    }
+    .serialize(&mut data)?;
+
+    let ix = Instruction {
+        program_id: risk_program,
+        accounts: vec![
+            AccountMeta::new_readonly(open_orders_account.key(), false),
+            AccountMeta::new_readonly(market.key(), false),
+        ],
+        data,
+    };
+    invoke(
+        &ix,
+        &[
+            open_orders_account.to_account_info(),
+            market.to_account_info(),
+            program_ai.clone(),
+        ],
+    )?;
+
+    Ok(())
+}
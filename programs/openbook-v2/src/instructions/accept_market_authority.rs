@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+
+pub fn accept_market_authority(ctx: Context<AcceptMarketAuthority>) -> Result<()> {
+    let mut market = ctx.accounts.market.load_mut()?;
+    let role = ctx.accounts.pending_market_admin.role();
+    market.set_admin(role, ctx.accounts.pending_admin.key());
+    Ok(())
+}
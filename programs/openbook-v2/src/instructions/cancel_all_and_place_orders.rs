@@ -4,6 +4,8 @@ use std::cmp;
 use crate::accounts_ix::*;
 use crate::accounts_zerocopy::AccountInfoRef;
 use crate::error::*;
+use crate::health_check::invoke_health_check;
+use crate::logs::{emit_stack, CancelLog, FillLog, OutLog, PlaceLog};
 use crate::state::*;
 use crate::token_utils::*;
 
@@ -14,6 +16,13 @@ pub fn cancel_all_and_place_orders<'c: 'info, 'info>(
     mut orders: Vec<Order>,
     limit: u8,
 ) -> Result<Vec<Option<u128>>> {
+    invoke_health_check(
+        &ctx.accounts.open_orders_account,
+        &ctx.accounts.market,
+        &orders,
+        ctx.remaining_accounts,
+    )?;
+
     let mut open_orders_account = ctx.accounts.open_orders_account.load_mut()?;
     let open_orders_account_pk = ctx.accounts.open_orders_account.key();
 
@@ -29,7 +38,7 @@ pub fn cancel_all_and_place_orders<'c: 'info, 'info>(
         bids: ctx.accounts.bids.load_mut()?,
         asks: ctx.accounts.asks.load_mut()?,
     };
-    let mut event_heap = ctx.accounts.event_heap.load_mut()?;
+    let mut event_heap = EventHeapRefMut::load_mut(&ctx.accounts.event_heap)?;
     let event_heap_size_before = event_heap.len();
 
     let now_ts: u64 = clock.unix_timestamp.try_into().unwrap();
@@ -40,8 +49,27 @@ pub fn cancel_all_and_place_orders<'c: 'info, 'info>(
         clock.slot,
     )?;
 
+    let market_pk = ctx.accounts.market.key();
     if cancel {
-        book.cancel_all_orders(&mut open_orders_account, *market, u8::MAX, None, None)?;
+        market.market_state().check_cancel_order()?;
+        let cancelled = book.cancel_all_orders(
+            &mut open_orders_account,
+            &mut market,
+            u8::MAX,
+            None,
+            None,
+            now_ts,
+            ctx.remaining_accounts,
+        )?;
+        for (side, leaf_node) in cancelled.iter() {
+            emit_stack(CancelLog::new(
+                market_pk,
+                &market,
+                open_orders_account_pk,
+                *side,
+                leaf_node,
+            ));
+        }
     }
 
     let mut base_amount = 0_u64;
@@ -76,19 +104,10 @@ pub fn cancel_all_and_place_orders<'c: 'info, 'info>(
             }
         }
 
-        let OrderWithAmounts {
-            order_id,
-            total_base_taken_native,
-            total_quote_taken_native,
-            posted_base_native,
-            posted_quote_native,
-            taker_fees,
-            maker_fees,
-            ..
-        } = book.new_order(
+        let order_with_amounts = book.new_order(
             order,
             &mut market,
-            &ctx.accounts.market.key(),
+            &market_pk,
             &mut event_heap,
             oracle_price_lots,
             Some(&mut open_orders_account),
@@ -98,6 +117,31 @@ pub fn cancel_all_and_place_orders<'c: 'info, 'info>(
             ctx.remaining_accounts,
         )?;
 
+        for fill in order_with_amounts.fills.iter() {
+            emit_stack(FillLog::new(market_pk, &market, fill)?);
+        }
+        for out in order_with_amounts.outs.iter() {
+            emit_stack(OutLog::new(market_pk, market.seq_num, out));
+        }
+        emit_cpi!(PlaceLog::new(
+            market_pk,
+            &market,
+            open_orders_account_pk,
+            order,
+            &order_with_amounts
+        ));
+
+        let OrderWithAmounts {
+            order_id,
+            total_base_taken_native,
+            total_quote_taken_native,
+            posted_base_native,
+            posted_quote_native,
+            taker_fees,
+            maker_fees,
+            ..
+        } = order_with_amounts;
+
         match order.side {
             Side::Bid => {
                 quote_amount = quote_amount
@@ -115,6 +159,7 @@ pub fn cancel_all_and_place_orders<'c: 'info, 'info>(
 
         order_ids.push(order_id);
     }
+    book.update_best_levels(now_ts);
 
     let position = &mut open_orders_account.position;
 
@@ -126,6 +171,8 @@ pub fn cancel_all_and_place_orders<'c: 'info, 'info>(
 
     position.base_free_native -= free_base_to_lock;
     position.quote_free_native -= free_quote_to_lock;
+    market.base_free_native_total -= free_base_to_lock as i64;
+    market.quote_free_native_total -= free_quote_to_lock as i64;
 
     market.base_deposit_total += deposit_base_amount;
     market.quote_deposit_total += deposit_quote_amount;
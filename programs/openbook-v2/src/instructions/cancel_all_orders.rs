@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::accounts_ix::*;
+use crate::logs::{emit_stack, CancelLog};
 use crate::state::*;
 
 pub fn cancel_all_orders(
@@ -10,13 +11,33 @@ pub fn cancel_all_orders(
 ) -> Result<()> {
     let mut account = ctx.accounts.open_orders_account.load_mut()?;
 
-    let market = ctx.accounts.market.load()?;
+    let mut market = ctx.accounts.market.load_mut()?;
+    market.market_state().check_cancel_order()?;
     let mut book = Orderbook {
         bids: ctx.accounts.bids.load_mut()?,
         asks: ctx.accounts.asks.load_mut()?,
     };
+    let now_ts = Clock::get()?.unix_timestamp as u64;
 
-    book.cancel_all_orders(&mut account, *market, limit, side_option, None)?;
+    let cancelled = book.cancel_all_orders(
+        &mut account,
+        &mut market,
+        limit,
+        side_option,
+        None,
+        now_ts,
+        ctx.remaining_accounts,
+    )?;
+    book.update_best_levels(now_ts);
+    for (side, leaf_node) in cancelled.iter() {
+        emit_stack(CancelLog::new(
+            ctx.accounts.market.key(),
+            &market,
+            ctx.accounts.open_orders_account.key(),
+            *side,
+            leaf_node,
+        ));
+    }
 
     Ok(())
 }
@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::error::*;
+
+pub fn cancel_market_authority(ctx: Context<CancelMarketAuthority>) -> Result<()> {
+    let market = ctx.accounts.market.load()?;
+    let role = ctx.accounts.pending_market_admin.role();
+    require!(
+        market.admin(role) == ctx.accounts.admin.key(),
+        OpenBookError::InvalidMarketAdmin
+    );
+    Ok(())
+}
@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 
 use crate::accounts_ix::*;
 use crate::error::*;
+use crate::logs::{emit_stack, CancelLog};
 use crate::state::*;
 
 pub fn cancel_order(ctx: Context<CancelOrder>, order_id: u128) -> Result<()> {
@@ -17,19 +18,32 @@ pub fn cancel_order(ctx: Context<CancelOrder>, order_id: u128) -> Result<()> {
     let order_id = oo.id;
     let order_side_and_tree = oo.side_and_tree();
 
-    let market = ctx.accounts.market.load()?;
+    let mut market = ctx.accounts.market.load_mut()?;
+    market.market_state().check_cancel_order()?;
     let mut book = Orderbook {
         bids: ctx.accounts.bids.load_mut()?,
         asks: ctx.accounts.asks.load_mut()?,
     };
+    let now_ts = Clock::get()?.unix_timestamp as u64;
 
-    book.cancel_order(
+    let leaf_node = book.cancel_order(
         &mut open_orders_account,
         order_id,
         order_side_and_tree,
-        *market,
+        &mut market,
         Some(ctx.accounts.open_orders_account.key()),
+        now_ts,
+        ctx.remaining_accounts,
     )?;
+    book.update_best_levels(now_ts);
+
+    emit_stack(CancelLog::new(
+        ctx.accounts.market.key(),
+        &market,
+        ctx.accounts.open_orders_account.key(),
+        order_side_and_tree.side(),
+        &leaf_node,
+    ));
 
     Ok(())
 }
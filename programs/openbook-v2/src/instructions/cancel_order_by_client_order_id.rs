@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::accounts_ix::*;
+use crate::logs::{emit_stack, CancelLog};
 use crate::state::*;
 
 pub fn cancel_order_by_client_order_id(
@@ -9,11 +10,36 @@ pub fn cancel_order_by_client_order_id(
 ) -> Result<i64> {
     let mut account = ctx.accounts.open_orders_account.load_mut()?;
 
-    let market = ctx.accounts.market.load()?;
+    let mut market = ctx.accounts.market.load_mut()?;
+    market.market_state().check_cancel_order()?;
     let mut book = Orderbook {
         bids: ctx.accounts.bids.load_mut()?,
         asks: ctx.accounts.asks.load_mut()?,
     };
+    let now_ts = Clock::get()?.unix_timestamp as u64;
 
-    book.cancel_all_orders(&mut account, *market, u8::MAX, None, Some(client_order_id))
+    let cancelled = book.cancel_all_orders(
+        &mut account,
+        &mut market,
+        u8::MAX,
+        None,
+        Some(client_order_id),
+        now_ts,
+        ctx.remaining_accounts,
+    )?;
+    book.update_best_levels(now_ts);
+    for (side, leaf_node) in cancelled.iter() {
+        emit_stack(CancelLog::new(
+            ctx.accounts.market.key(),
+            &market,
+            ctx.accounts.open_orders_account.key(),
+            *side,
+            leaf_node,
+        ));
+    }
+
+    Ok(cancelled
+        .iter()
+        .map(|(_side, leaf_node)| leaf_node.quantity)
+        .sum())
 }
@@ -0,0 +1,7 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+
+pub fn cancel_trigger_order(_ctx: Context<CancelTriggerOrder>) -> Result<()> {
+    Ok(())
+}
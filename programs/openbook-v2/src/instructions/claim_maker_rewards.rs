@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::logs::{emit_stack, MakerRewardsClaimLog};
+use crate::token_utils::*;
+
+pub fn claim_maker_rewards(ctx: Context<ClaimMakerRewards>) -> Result<()> {
+    let mut open_orders_account = ctx.accounts.open_orders_account.load_mut()?;
+    let mut maker_rewards = ctx.accounts.maker_rewards.load_mut()?;
+
+    let position = &mut open_orders_account.position;
+    let (amount, points) = maker_rewards.claimable(
+        position.maker_reward_points,
+        ctx.accounts.reward_vault.amount,
+    );
+    position.maker_reward_points -= points;
+    position.maker_rewards_claimed += amount;
+    maker_rewards.rewards_claimed_total += amount;
+
+    let market_key = ctx.accounts.market.key();
+    let seeds: &[&[u8]] = &[
+        b"MakerRewards".as_ref(),
+        market_key.as_ref(),
+        &[maker_rewards.bump],
+    ];
+    drop(maker_rewards);
+
+    token_transfer_signed(
+        amount,
+        &ctx.accounts.token_program,
+        &ctx.accounts.reward_vault,
+        &ctx.accounts.user_reward_account,
+        &ctx.accounts.maker_rewards,
+        seeds,
+    )?;
+
+    emit_stack(MakerRewardsClaimLog {
+        market: market_key,
+        open_orders_account: ctx.accounts.open_orders_account.key(),
+        amount,
+        receiver: ctx.accounts.user_reward_account.key(),
+    });
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use std::cmp;
+
+use crate::accounts_ix::*;
+use crate::logs::{emit_stack, ClaimRebatesLog};
+use crate::state::*;
+use crate::token_utils::*;
+
+pub fn claim_rebates(ctx: Context<ClaimRebates>) -> Result<()> {
+    let mut open_orders_account = ctx.accounts.open_orders_account.load_mut()?;
+    let mut market = ctx.accounts.market.load_mut()?;
+
+    let mut roundoff_maker_fees = 0;
+
+    if market.maker_fee.is_positive() && open_orders_account.position.bids_base_lots == 0 {
+        roundoff_maker_fees = open_orders_account.position.locked_maker_fees;
+        open_orders_account.position.locked_maker_fees = 0;
+    }
+
+    let pa = &mut open_orders_account.position;
+    let referrer_rebate = pa.referrer_rebates_available + roundoff_maker_fees;
+    // Free quote may have been spent on new orders since the rebates were credited
+    let maker_rebate = cmp::min(pa.maker_rebates_available, pa.quote_free_native);
+
+    market.fees_to_referrers += referrer_rebate as u128;
+    market.quote_deposit_total -= referrer_rebate + maker_rebate;
+    market.referrer_rebates_accrued -= pa.referrer_rebates_available;
+
+    pa.quote_free_native -= maker_rebate;
+    market.quote_free_native_total -= maker_rebate as i64;
+    pa.maker_rebates_available = 0;
+    pa.referrer_rebates_available = 0;
+
+    let seeds = market_seeds!(market, ctx.accounts.market.key());
+    drop(market);
+
+    token_transfer_signed(
+        referrer_rebate + maker_rebate,
+        &ctx.accounts.token_program,
+        &ctx.accounts.market_quote_vault,
+        &ctx.accounts.rebate_receiver_account,
+        &ctx.accounts.market_authority,
+        seeds,
+    )?;
+
+    emit_stack(ClaimRebatesLog {
+        open_orders_account: ctx.accounts.open_orders_account.key(),
+        maker_rebate,
+        referrer_rebate,
+        receiver: ctx.accounts.rebate_receiver_account.key(),
+    });
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::logs::{emit_stack, TakerRebatesClaimLog};
+use crate::token_utils::*;
+
+pub fn claim_taker_rebates(ctx: Context<ClaimTakerRebates>) -> Result<()> {
+    let mut open_orders_account = ctx.accounts.open_orders_account.load_mut()?;
+    let mut campaign = ctx.accounts.taker_rebate_campaign.load_mut()?;
+
+    let amount = open_orders_account.position.taker_rebates_accrued;
+    open_orders_account.position.taker_rebates_accrued = 0;
+    campaign.rebates_outstanding -= amount;
+
+    let market_key = ctx.accounts.market.key();
+    let seeds: &[&[u8]] = &[
+        b"TakerRebateCampaign".as_ref(),
+        market_key.as_ref(),
+        &[campaign.bump],
+    ];
+    drop(campaign);
+
+    token_transfer_signed(
+        amount,
+        &ctx.accounts.token_program,
+        &ctx.accounts.rebate_vault,
+        &ctx.accounts.user_quote_account,
+        &ctx.accounts.taker_rebate_campaign,
+        seeds,
+    )?;
+
+    emit_stack(TakerRebatesClaimLog {
+        market: market_key,
+        open_orders_account: ctx.accounts.open_orders_account.key(),
+        amount,
+        receiver: ctx.accounts.user_quote_account.key(),
+    });
+    Ok(())
+}
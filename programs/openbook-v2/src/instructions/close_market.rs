@@ -1,6 +1,7 @@
 use crate::accounts_ix::*;
 use crate::error::OpenBookError;
 use crate::state::*;
+use crate::token_utils::*;
 use anchor_lang::prelude::*;
 
 pub fn close_market(ctx: Context<CloseMarket>) -> Result<()> {
@@ -23,5 +24,35 @@ pub fn close_market(ctx: Context<CloseMarket>) -> Result<()> {
         OpenBookError::EventHeapContainsElements
     );
 
+    let seeds = market_seeds!(market, ctx.accounts.market.key());
+
+    // Nothing is owed to anyone anymore, whatever is left in the vaults is rounding dust
+    for (vault, dust_receiver) in [
+        (
+            &ctx.accounts.market_base_vault,
+            &ctx.accounts.base_dust_receiver,
+        ),
+        (
+            &ctx.accounts.market_quote_vault,
+            &ctx.accounts.quote_dust_receiver,
+        ),
+    ] {
+        token_transfer_signed(
+            vault.amount,
+            &ctx.accounts.token_program,
+            vault,
+            dust_receiver,
+            &ctx.accounts.market_authority,
+            seeds,
+        )?;
+        token_close_account_signed(
+            &ctx.accounts.token_program,
+            vault,
+            &ctx.accounts.sol_destination,
+            &ctx.accounts.market_authority,
+            seeds,
+        )?;
+    }
+
     Ok(())
 }
@@ -11,13 +11,17 @@ pub fn close_open_orders_account(ctx: Context<CloseOpenOrdersAccount>) -> Result
             .is_empty(open_orders_account.version),
         OpenBookError::NonEmptyOpenOrdersPosition
     );
+    require!(
+        !open_orders_account.has_position_extension(),
+        OpenBookError::PositionExtensionNotClosed
+    );
 
     let indexer = &mut ctx.accounts.open_orders_indexer;
     let index = indexer
         .addresses
         .iter()
         .position(|x| *x == ctx.accounts.open_orders_account.key())
-        .unwrap();
+        .ok_or(OpenBookError::OpenOrdersAccountNotIndexed)?;
     indexer.addresses.remove(index);
 
     Ok(())
@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+
+pub fn close_position_extension(ctx: Context<ClosePositionExtension>) -> Result<()> {
+    ctx.accounts
+        .open_orders_account
+        .load_mut()?
+        .set_has_position_extension(false);
+    Ok(())
+}
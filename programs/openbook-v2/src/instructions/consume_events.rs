@@ -3,6 +3,7 @@ use bytemuck::cast_ref;
 use itertools::Itertools;
 
 use crate::error::OpenBookError;
+use crate::logs::{emit_stack, ConsumeEventsSkippedLog};
 use crate::state::*;
 
 use crate::accounts_ix::*;
@@ -10,6 +11,10 @@ use crate::accounts_ix::*;
 // Max events to consume per ix.
 pub const MAX_EVENTS_CONSUME: usize = 8;
 
+/// Max events whose open orders account is missing to step over per ix, they
+/// don't count towards the limit of consumed events
+pub const MAX_EVENTS_SKIP: usize = 16;
+
 /// Load a open_orders account by key from the list of account infos.
 ///
 /// Message and return Ok() if it's missing, to lock in successful processing
@@ -35,39 +40,143 @@ macro_rules! load_open_orders_account {
     };
 }
 
+/// Load the position extension of the open orders account `$ooa` at `$key`, if it
+/// has one.
+///
+/// Message and skip the event if it's missing, like a missing open orders account.
+macro_rules! load_position_extension {
+    ($name:ident, $ooa:expr, $key:expr, $ais:expr) => {
+        let mut $name = if $ooa.has_position_extension() {
+            match find_position_extension(&$key, $ais)? {
+                None => {
+                    msg!(
+                        "Unable to find position extension of {}, skipping",
+                        $key.to_string()
+                    );
+                    continue;
+                }
+                Some(position_ext) => Some(position_ext),
+            }
+        } else {
+            None
+        };
+    };
+}
+
 pub fn consume_events<'c: 'info, 'info>(
     ctx: Context<'_, '_, 'c, 'info, ConsumeEvents>,
     limit: usize,
     slots: Option<Vec<usize>>,
-) -> Result<()> {
+) -> Result<Vec<FillEvent>> {
     let limit = std::cmp::min(limit, MAX_EVENTS_CONSUME);
 
     let mut market = ctx.accounts.market.load_mut()?;
-    let mut event_heap = ctx.accounts.event_heap.load_mut()?;
-    let remaining_accs = &ctx.remaining_accounts;
+    let mut event_heap = EventHeapRefMut::load_mut(&ctx.accounts.event_heap)?;
+
+    let slots = slots.unwrap_or_default();
+    require!(
+        slots.iter().all(|slot| *slot < event_heap.capacity()),
+        OpenBookError::InvalidInputHeapSlots
+    );
 
-    let slots_to_consume = slots
-        .unwrap_or_default()
+    // Events of open orders accounts that weren't passed stay on the heap and
+    // get reported, so the accounts can be in any order and some can be missing
+    let remaining_accs = &ctx.remaining_accounts;
+    let mut slots_to_consume = vec![];
+    let mut events_skipped = 0;
+    let mut missing_accounts = vec![];
+    for slot in slots
         .into_iter()
-        .filter(|slot| !event_heap.nodes[*slot].is_free())
-        .chain(event_heap.iter().map(|(_event, slot)| slot))
+        .filter(|slot| event_heap.at_slot(*slot).is_some())
+        .chain(event_heap.iter_by_priority().map(|(_event, slot)| slot))
         .unique()
+    {
+        if slots_to_consume.len() == limit || events_skipped == MAX_EVENTS_SKIP {
+            break;
+        }
+        match event_heap.at_slot(slot).unwrap().open_orders_account() {
+            Some(key) if !remaining_accs.iter().any(|ai| ai.key == &key) => {
+                events_skipped += 1;
+                if !missing_accounts.contains(&key) {
+                    missing_accounts.push(key);
+                }
+            }
+            _ => slots_to_consume.push(slot),
+        }
+    }
+
+    if events_skipped > 0 {
+        emit_stack(ConsumeEventsSkippedLog {
+            market: ctx.accounts.market.key(),
+            events_skipped: events_skipped as u8,
+            missing_open_orders_accounts: missing_accounts,
+        });
+    }
+
+    consume_slots(
+        &mut market,
+        &mut event_heap,
+        remaining_accs,
+        slots_to_consume,
+    )
+}
+
+/// Like `consume_events`, but only processes events that involve one of the
+/// passed open orders accounts. Other events are left on the heap.
+pub fn consume_events_for_accounts<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ConsumeEvents>,
+    limit: usize,
+) -> Result<Vec<FillEvent>> {
+    let limit = std::cmp::min(limit, MAX_EVENTS_CONSUME);
+
+    let mut market = ctx.accounts.market.load_mut()?;
+    let mut event_heap = EventHeapRefMut::load_mut(&ctx.accounts.event_heap)?;
+    let remaining_accs = &ctx.remaining_accounts;
+
+    let slots_to_consume = event_heap
+        .iter_by_priority()
+        .filter(|(event, _slot)| {
+            event
+                .open_orders_account()
+                .map_or(false, |key| remaining_accs.iter().any(|ai| ai.key == &key))
+        })
+        .map(|(_event, slot)| slot)
         .take(limit)
         .collect_vec();
 
-    for slot in slots_to_consume {
+    consume_slots(
+        &mut market,
+        &mut event_heap,
+        remaining_accs,
+        slots_to_consume,
+    )
+}
+
+/// Process the events at `slots`, returning the fills among them
+fn consume_slots<'info>(
+    market: &mut Market,
+    event_heap: &mut impl EventHeapStorage,
+    remaining_accs: &'info [AccountInfo<'info>],
+    slots: Vec<usize>,
+) -> Result<Vec<FillEvent>> {
+    let mut fills = vec![];
+    for slot in slots {
         let event = event_heap.at_slot(slot).unwrap();
 
-        match EventType::try_from(event.event_type).map_err(|_| error!(OpenBookError::SomeError))? {
+        match EventType::try_from(event.event_type)
+            .map_err(|_| error!(OpenBookError::InvalidEventType))?
+        {
             EventType::Fill => {
                 let fill: &FillEvent = cast_ref(event);
                 load_open_orders_account!(maker, fill.maker, remaining_accs);
-                maker.execute_maker(&mut market, fill);
+                load_position_extension!(maker_ext, maker, fill.maker, remaining_accs);
+                maker.execute_maker(market, fill, maker_ext.as_deref_mut())?;
+                fills.push(*fill);
             }
             EventType::Out => {
                 let out: &OutEvent = cast_ref(event);
                 load_open_orders_account!(owner, out.owner, remaining_accs);
-                owner.cancel_order(out.owner_slot as usize, out.quantity, *market);
+                owner.cancel_order(out.owner_slot as usize, out.quantity, market)?;
             }
         }
 
@@ -75,5 +184,6 @@ pub fn consume_events<'c: 'info, 'info>(
         event_heap.delete_slot(slot)?;
     }
 
-    Ok(())
+    market.update_event_heap_stats(event_heap);
+    Ok(fills)
 }
@@ -0,0 +1,46 @@
+use crate::accounts_ix::*;
+use crate::error::OpenBookError;
+use crate::ConsumeEventsGroup;
+use anchor_lang::prelude::*;
+
+pub fn consume_events_multiple<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ConsumeEventsMultiple<'info>>,
+    groups: Vec<ConsumeEventsGroup>,
+) -> Result<()> {
+    let mut remaining_accs: &'info [AccountInfo<'info>] = ctx.remaining_accounts;
+    require!(
+        !groups.is_empty(),
+        OpenBookError::InvalidInputRemainingAccounts
+    );
+
+    for group in groups {
+        let group_len = CONSUME_EVENTS_MULTIPLE_ACCOUNTS + usize::from(group.num_accounts);
+        require_gte!(
+            remaining_accs.len(),
+            group_len,
+            OpenBookError::InvalidInputRemainingAccounts
+        );
+        let (group_accs, rest) = remaining_accs.split_at(group_len);
+        remaining_accs = rest;
+
+        let (market_accs, open_orders_accs) = group_accs.split_at(CONSUME_EVENTS_MULTIPLE_ACCOUNTS);
+        let mut accounts = ctx.accounts.to_consume_events(market_accs)?;
+        crate::instructions::consume_events(
+            Context::new(
+                ctx.program_id,
+                &mut accounts,
+                open_orders_accs,
+                ConsumeEventsBumps {},
+            ),
+            group.limit.into(),
+            None,
+        )?;
+    }
+
+    require!(
+        remaining_accs.is_empty(),
+        OpenBookError::InvalidInputRemainingAccounts
+    );
+
+    Ok(())
+}
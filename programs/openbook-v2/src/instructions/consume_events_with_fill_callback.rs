@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::fill_callback::invoke_fill_callback;
+
+pub fn consume_events_with_fill_callback<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ConsumeEventsWithFillCallback<'info>>,
+    limit: usize,
+) -> Result<()> {
+    let fills = crate::instructions::consume_events(
+        Context::new(
+            ctx.program_id,
+            &mut ctx.accounts.to_consume_events(),
+            ctx.remaining_accounts,
+            ctx.bumps.to_consume_events(),
+        ),
+        limit,
+        None,
+    )?;
+
+    invoke_fill_callback(
+        &ctx.accounts.market.key(),
+        fills,
+        &ctx.accounts.fill_callback_program,
+        &ctx.accounts.fill_callback_authority,
+        ctx.bumps.fill_callback_authority,
+    )
+}
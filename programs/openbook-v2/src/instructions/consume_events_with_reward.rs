@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::logs::{emit_stack, ConsumeEventsRewardLog};
+
+pub fn consume_events_with_reward<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ConsumeEventsWithReward<'info>>,
+    limit: usize,
+) -> Result<()> {
+    let events_before = ctx.accounts.event_heap.load()?.header.count();
+
+    crate::instructions::consume_events(
+        Context::new(
+            ctx.program_id,
+            &mut ctx.accounts.to_consume_events(),
+            ctx.remaining_accounts,
+            ctx.bumps.to_consume_events(),
+        ),
+        limit,
+        None,
+    )?;
+
+    let events_consumed = events_before - ctx.accounts.event_heap.load()?.header.count();
+    let reward_per_event = ctx.accounts.market.load()?.consume_events_reward;
+
+    // Only pay out what the market collected on top of its rent exemption,
+    // i.e. the event heap penalties paid by takers
+    let market_ai = ctx.accounts.market.to_account_info();
+    let available = market_ai
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(market_ai.data_len()));
+    let reward = std::cmp::min(
+        reward_per_event.saturating_mul(events_consumed as u64),
+        available,
+    );
+
+    if reward > 0 {
+        **market_ai.try_borrow_mut_lamports()? -= reward;
+        **ctx.accounts.cranker.try_borrow_mut_lamports()? += reward;
+
+        emit_stack(ConsumeEventsRewardLog {
+            market: ctx.accounts.market.key(),
+            cranker: ctx.accounts.cranker.key(),
+            events_consumed: events_consumed as u64,
+            reward,
+        });
+    }
+
+    Ok(())
+}
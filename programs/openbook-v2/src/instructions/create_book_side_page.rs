@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::error::*;
+use crate::state::*;
+
+pub fn create_book_side_page<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, CreateBookSidePage<'info>>,
+) -> Result<()> {
+    let page_key = ctx.accounts.page.key();
+    let mut book_side = ctx.accounts.book_side.load_mut()?;
+    let mut pages = load_book_side_pages(&book_side, ctx.remaining_accounts)?;
+
+    let tail_next_page = pages
+        .last()
+        .map_or(book_side.next_page, |page| page.next_page);
+    require_keys_eq!(
+        tail_next_page,
+        Pubkey::default(),
+        OpenBookError::MissingBookSidePages
+    );
+    require_gt!(
+        MAX_BOOK_SIDE_PAGES,
+        pages.len(),
+        OpenBookError::TooManyBookSidePages
+    );
+
+    let mut page = ctx.accounts.page.load_init()?;
+    page.nodes.order_tree_type = book_side.nodes.order_tree_type;
+    match pages.last_mut() {
+        Some(tail) => tail.next_page = page_key,
+        None => book_side.next_page = page_key,
+    }
+
+    msg!(
+        "page {} of {}",
+        pages.len() + 1,
+        ctx.accounts.book_side.key()
+    );
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::error::*;
+use crate::state::*;
+
+#[allow(clippy::too_many_arguments)]
+pub fn create_canonical_market<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateCanonicalMarket<'info>>,
+    name: String,
+    oracle_config: OracleConfigParams,
+    quote_lot_size: i64,
+    base_lot_size: i64,
+    maker_fee: i64,
+    taker_fee: i64,
+    time_expiry: i64,
+    min_order_notional: u64,
+    max_resting_orders: u8,
+) -> Result<()> {
+    // there's only one canonical market per configuration, it must be open to everyone
+    require!(
+        ctx.accounts.open_orders_admin.is_none(),
+        OpenBookError::InvalidOpenOrdersAdmin
+    );
+
+    crate::instructions::create_market(
+        Context::new(
+            ctx.program_id,
+            &mut ctx.accounts.to_create_market(),
+            ctx.remaining_accounts,
+            ctx.bumps.to_create_market(),
+        ),
+        name,
+        oracle_config,
+        quote_lot_size,
+        base_lot_size,
+        maker_fee,
+        taker_fee,
+        time_expiry,
+        min_order_notional,
+        max_resting_orders,
+    )
+}
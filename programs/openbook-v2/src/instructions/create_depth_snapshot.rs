@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+
+pub fn create_depth_snapshot(ctx: Context<CreateDepthSnapshot>) -> Result<()> {
+    let mut depth_snapshot = ctx.accounts.depth_snapshot.load_init()?;
+    depth_snapshot.market = ctx.accounts.market.key();
+    depth_snapshot.owner = ctx.accounts.owner.key();
+    depth_snapshot.bump = ctx.bumps.depth_snapshot;
+    Ok(())
+}
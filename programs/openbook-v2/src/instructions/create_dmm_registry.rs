@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+
+pub fn create_dmm_registry(ctx: Context<CreateDmmRegistry>) -> Result<()> {
+    let mut dmm_registry = ctx.accounts.dmm_registry.load_init()?;
+    dmm_registry.market = ctx.accounts.market.key();
+    dmm_registry.bump = ctx.bumps.dmm_registry;
+    Ok(())
+}
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::error::*;
+
+pub fn create_fill_callback(ctx: Context<CreateFillCallback>, program: Pubkey) -> Result<()> {
+    require!(
+        program != crate::id(),
+        OpenBookError::InvalidFillCallbackProgram
+    );
+
+    let fill_callback = &mut ctx.accounts.fill_callback;
+    fill_callback.bump = ctx.bumps.fill_callback;
+    fill_callback.market = ctx.accounts.market.key();
+    fill_callback.program = Some(program);
+    Ok(())
+}
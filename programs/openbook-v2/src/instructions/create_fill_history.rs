@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+
+pub fn create_fill_history(ctx: Context<CreateFillHistory>) -> Result<()> {
+    let mut fill_history = ctx.accounts.fill_history.load_init()?;
+    fill_history.market = ctx.accounts.market.key();
+    fill_history.bump = ctx.bumps.fill_history;
+    Ok(())
+}
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+
+pub fn create_maker_rewards(
+    ctx: Context<CreateMakerRewards>,
+    rewards_per_million_points: u64,
+) -> Result<()> {
+    // points only accrue from now on
+    let mut market = ctx.accounts.market.load_mut()?;
+    market.maker_rewards_start_ts = Clock::get()?.unix_timestamp.try_into().unwrap();
+
+    let mut maker_rewards = ctx.accounts.maker_rewards.load_init()?;
+    maker_rewards.market = ctx.accounts.market.key();
+    maker_rewards.reward_mint = ctx.accounts.reward_mint.key();
+    maker_rewards.reward_vault = ctx.accounts.reward_vault.key();
+    maker_rewards.rewards_per_million_points = rewards_per_million_points;
+    maker_rewards.bump = ctx.bumps.maker_rewards;
+    Ok(())
+}
@@ -6,6 +6,7 @@ use crate::error::*;
 use crate::logs::MarketMetaDataLog;
 use crate::pubkey_option::NonZeroKey;
 use crate::state::*;
+use crate::token_utils::system_program_transfer;
 use crate::util::fill_from_str;
 
 #[allow(clippy::too_many_arguments)]
@@ -18,6 +19,8 @@ pub fn create_market(
     maker_fee: i64,
     taker_fee: i64,
     time_expiry: i64,
+    min_order_notional: u64,
+    max_resting_orders: u8,
 ) -> Result<()> {
     let registration_time = Clock::get()?.unix_timestamp;
 
@@ -42,6 +45,12 @@ pub fn create_market(
     require_gt!(quote_lot_size, 0, OpenBookError::InvalidInputLots);
     require_gt!(base_lot_size, 0, OpenBookError::InvalidInputLots);
 
+    require_gte!(
+        MAX_OPEN_ORDERS,
+        max_resting_orders as usize,
+        OpenBookError::InvalidInputMaxRestingOrders
+    );
+
     let oracle_a = ctx.accounts.oracle_a.non_zero_key();
     let oracle_b = ctx.accounts.oracle_b.non_zero_key();
 
@@ -61,6 +70,13 @@ pub fn create_market(
         return Err(OpenBookError::InvalidSecondOracle.into());
     }
 
+    system_program_transfer(
+        MARKET_CREATION_BOND,
+        &ctx.accounts.system_program,
+        &ctx.accounts.payer,
+        &ctx.accounts.market,
+    )?;
+
     let mut openbook_market = ctx.accounts.market.load_init()?;
     *openbook_market = Market {
         market_authority: ctx.accounts.market_authority.key(),
@@ -71,6 +87,9 @@ pub fn create_market(
         bump: ctx.bumps.market_authority,
         base_decimals: ctx.accounts.base_mint.decimals,
         quote_decimals: ctx.accounts.quote_mint.decimals,
+        market_state: MarketState::Active.into(),
+        binary_outcome: BinaryOutcome::Disabled.into(),
+        registered: 0,
         padding1: Default::default(),
         time_expiry,
         name: fill_from_str(&name)?,
@@ -98,8 +117,29 @@ pub fn create_market(
         quote_deposit_total: 0,
         fees_available: 0,
         referrer_rebates_accrued: 0,
-
-        reserved: [0; 128],
+        consume_events_reward: 0,
+        event_heap_oldest_timestamp: 0,
+        event_heap_count: 0,
+        event_heap_capacity: MAX_NUM_EVENTS,
+        padding2: Default::default(),
+        event_seq_num: 0,
+        settlement_price_lots: 0,
+        creation_bond: MARKET_CREATION_BOND,
+        maker_rewards_start_ts: 0,
+        asks_base_lots_total: 0,
+        bids_quote_lots_total: 0,
+        base_free_native_total: 0,
+        quote_free_native_total: 0,
+        order_rate_limit: 0,
+        order_rate_limit_window: 0,
+        min_order_notional,
+        max_resting_orders,
+        version: MARKET_VERSION,
+        padding3: Default::default(),
+        eviction_policy: EvictionPolicy::default().into(),
+        taker_fee_rounding: FeeRounding::default().into(),
+        eviction_band_bps: 0,
+        reserved: [0; 12],
     };
 
     let mut orderbook = Orderbook {
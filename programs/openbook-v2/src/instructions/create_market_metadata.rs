@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::instructions::write_market_metadata;
+use crate::MarketMetadataArgs;
+
+pub fn create_market_metadata(
+    ctx: Context<CreateMarketMetadata>,
+    args: MarketMetadataArgs,
+) -> Result<()> {
+    let mut market_metadata = ctx.accounts.market_metadata.load_init()?;
+    market_metadata.market = ctx.accounts.market.key();
+    market_metadata.bump = ctx.bumps.market_metadata;
+    write_market_metadata(&mut market_metadata, &args)
+}
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::error::*;
+
+pub fn create_market_registry_page(
+    ctx: Context<CreateMarketRegistryPage>,
+    page_index: u32,
+) -> Result<()> {
+    require!(
+        page_index == 0 || ctx.accounts.previous_page.is_some(),
+        OpenBookError::InvalidMarketRegistryPage
+    );
+
+    let mut registry_page = ctx.accounts.registry_page.load_init()?;
+    registry_page.page_index = page_index;
+    registry_page.bump = ctx.bumps.registry_page;
+    Ok(())
+}
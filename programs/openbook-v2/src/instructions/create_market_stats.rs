@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+
+pub fn create_market_stats(ctx: Context<CreateMarketStats>) -> Result<()> {
+    let mut market_stats = ctx.accounts.market_stats.load_init()?;
+    market_stats.market = ctx.accounts.market.key();
+    market_stats.bump = ctx.bumps.market_stats;
+    Ok(())
+}
@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+
+pub fn create_merkle_allowlist(ctx: Context<CreateMerkleAllowlist>, root: [u8; 32]) -> Result<()> {
+    let mut merkle_allowlist = ctx.accounts.merkle_allowlist.load_init()?;
+    merkle_allowlist.market = ctx.accounts.market.key();
+    merkle_allowlist.root = root;
+    merkle_allowlist.bump = ctx.bumps.merkle_allowlist;
+    Ok(())
+}
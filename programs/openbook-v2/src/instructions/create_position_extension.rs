@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+
+pub fn create_position_extension(ctx: Context<CreatePositionExtension>) -> Result<()> {
+    let mut position_extension = ctx.accounts.position_extension.load_init()?;
+    position_extension.open_orders_account = ctx.accounts.open_orders_account.key();
+    position_extension.bump = ctx.bumps.position_extension;
+
+    ctx.accounts
+        .open_orders_account
+        .load_mut()?
+        .set_has_position_extension(true);
+    Ok(())
+}
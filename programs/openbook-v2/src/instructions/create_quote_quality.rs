@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::error::OpenBookError;
+
+pub fn create_quote_quality(
+    ctx: Context<CreateQuoteQuality>,
+    max_oracle_distance_bps: u16,
+    min_size_lots: i64,
+    epoch_duration: u64,
+) -> Result<()> {
+    require_gt!(epoch_duration, 0, OpenBookError::InvalidInputEpochDuration);
+    require_gte!(min_size_lots, 0, OpenBookError::InvalidInputLots);
+    let market = ctx.accounts.market.load()?;
+    require!(
+        market.oracle_a.is_some(),
+        OpenBookError::QuoteQualityWithoutOracle
+    );
+
+    let mut quote_quality = ctx.accounts.quote_quality.load_init()?;
+    quote_quality.market = ctx.accounts.market.key();
+    quote_quality.open_orders_account = ctx.accounts.open_orders_account.key();
+    quote_quality.max_oracle_distance_bps = max_oracle_distance_bps;
+    quote_quality.min_size_lots = min_size_lots;
+    quote_quality.epoch_duration = epoch_duration;
+    quote_quality.bump = ctx.bumps.quote_quality;
+    quote_quality.start(Clock::get()?.unix_timestamp.try_into().unwrap());
+    Ok(())
+}
@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+
+pub fn create_taker_program_allowlist(
+    ctx: Context<CreateTakerProgramAllowlist>,
+    programs: Vec<Pubkey>,
+) -> Result<()> {
+    let mut taker_program_allowlist = ctx.accounts.taker_program_allowlist.load_init()?;
+    taker_program_allowlist.market = ctx.accounts.market.key();
+    taker_program_allowlist.bump = ctx.bumps.taker_program_allowlist;
+    taker_program_allowlist.set_programs(&programs)
+}
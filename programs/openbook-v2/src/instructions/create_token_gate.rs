@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+
+pub fn create_token_gate(ctx: Context<CreateTokenGate>, min_amount: u64) -> Result<()> {
+    let mut token_gate = ctx.accounts.token_gate.load_init()?;
+    token_gate.market = ctx.accounts.market.key();
+    token_gate.mint = ctx.accounts.mint.key();
+    token_gate.min_amount = min_amount.max(1);
+    token_gate.bump = ctx.bumps.token_gate;
+    Ok(())
+}
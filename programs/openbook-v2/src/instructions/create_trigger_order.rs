@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::error::*;
+use crate::state::*;
+use crate::PlaceOrderArgs;
+
+pub fn create_trigger_order(
+    ctx: Context<CreateTriggerOrder>,
+    trigger_id: u64,
+    condition: TriggerCondition,
+    trigger_price_lots: i64,
+    order: PlaceOrderArgs,
+) -> Result<()> {
+    require_gte!(trigger_price_lots, 1, OpenBookError::InvalidInputPriceLots);
+    require_gte!(order.price_lots, 1, OpenBookError::InvalidInputPriceLots);
+    require_gte!(order.max_base_lots, 0, OpenBookError::InvalidInputLots);
+    require_gte!(
+        order.max_quote_lots_including_fees,
+        0,
+        OpenBookError::InvalidInputLots
+    );
+
+    let mut trigger_order = ctx.accounts.trigger_order.load_init()?;
+    *trigger_order = TriggerOrder {
+        owner: ctx.accounts.open_orders_account.load()?.owner,
+        market: ctx.accounts.market.key(),
+        open_orders_account: ctx.accounts.open_orders_account.key(),
+        trigger_id,
+        trigger_price_lots,
+        price_lots: order.price_lots,
+        max_base_lots: order.max_base_lots,
+        max_quote_lots_including_fees: order.max_quote_lots_including_fees,
+        client_order_id: order.client_order_id,
+        expiry_timestamp: order.expiry_timestamp,
+        condition: condition.into(),
+        side: order.side.into(),
+        order_type: order.order_type.into(),
+        self_trade_behavior: order.self_trade_behavior.into(),
+        limit: order.limit,
+        bump: ctx.bumps.trigger_order,
+        padding: Default::default(),
+        reserved: [0; 64],
+    };
+
+    // fail early if the order can't be built or is already expired
+    require!(
+        trigger_order.order()?.is_some(),
+        OpenBookError::TriggerOrderExpired
+    );
+
+    Ok(())
+}
@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+
+pub fn defragment_book(ctx: Context<DefragmentBook>, limit: u8) -> Result<()> {
+    let moved_bids = ctx.accounts.bids.load_mut()?.defragment(limit.into());
+    let moved_asks = ctx.accounts.asks.load_mut()?.defragment(limit.into());
+    msg!("moved {} bid and {} ask nodes", moved_bids, moved_asks);
+    Ok(())
+}
@@ -20,6 +20,7 @@ pub fn deposit(ctx: Context<Deposit>, base_amount: u64, quote_amount: u64) -> Re
         &ctx.accounts.owner,
     )?;
     open_orders_account.position.base_free_native += base_amount;
+    market.base_free_native_total += base_amount as i64;
     market.base_deposit_total += base_amount;
 
     token_transfer(
@@ -30,6 +31,7 @@ pub fn deposit(ctx: Context<Deposit>, base_amount: u64, quote_amount: u64) -> Re
         &ctx.accounts.owner,
     )?;
     open_orders_account.position.quote_free_native += quote_amount;
+    market.quote_free_native_total += quote_amount as i64;
     market.quote_deposit_total += quote_amount;
 
     if base_amount > 0 || quote_amount > 0 {
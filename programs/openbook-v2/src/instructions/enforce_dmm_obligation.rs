@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::error::*;
+
+pub fn enforce_dmm_obligation(ctx: Context<EnforceDmmObligation>) -> Result<()> {
+    let open_orders_account_pk = ctx.accounts.open_orders_account.key();
+    let mut dmm_registry = ctx.accounts.dmm_registry.load_mut()?;
+    let slot = *dmm_registry
+        .find(&open_orders_account_pk)
+        .ok_or_else(|| error!(OpenBookError::NotADmm))?;
+
+    // only the last complete epoch counts, the current one can still improve
+    let quote_quality = ctx.accounts.quote_quality.load()?;
+    require!(
+        slot.is_obligation_missed(&quote_quality.previous_epoch),
+        OpenBookError::DmmObligationMet
+    );
+
+    dmm_registry.set(open_orders_account_pk, 0, 0)?;
+    ctx.accounts.open_orders_account.load_mut()?.dmm_priority = 0;
+    Ok(())
+}
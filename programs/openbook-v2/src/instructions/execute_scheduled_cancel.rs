@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::error::*;
+use crate::logs::{emit_stack, CancelLog, HeartbeatBountyLog};
+use crate::state::*;
+
+pub fn execute_scheduled_cancel(ctx: Context<ExecuteScheduledCancel>, limit: u8) -> Result<()> {
+    let mut account = ctx.accounts.open_orders_account.load_mut()?;
+    let mut position_ext = ctx.accounts.position_extension.load_mut()?;
+
+    let now_ts = Clock::get()?.unix_timestamp as u64;
+    let cancel_all_after_ts = position_ext.cancel_all_after_ts;
+    require!(
+        cancel_all_after_ts != 0 && now_ts >= cancel_all_after_ts,
+        OpenBookError::ScheduledCancelNotDue
+    );
+
+    let mut market = ctx.accounts.market.load_mut()?;
+    market.market_state().check_cancel_order()?;
+    let mut book = Orderbook {
+        bids: ctx.accounts.bids.load_mut()?,
+        asks: ctx.accounts.asks.load_mut()?,
+    };
+
+    let cancelled = book.cancel_all_orders(
+        &mut account,
+        &mut market,
+        limit,
+        None,
+        None,
+        now_ts,
+        ctx.remaining_accounts,
+    )?;
+    book.update_best_levels(now_ts);
+    for (side, leaf_node) in cancelled.iter() {
+        emit_stack(CancelLog::new(
+            ctx.accounts.market.key(),
+            &market,
+            ctx.accounts.open_orders_account.key(),
+            *side,
+            leaf_node,
+        ));
+    }
+
+    // Stay scheduled until the limit allowed to cancel everything
+    let done = account.all_orders_in_use().next().is_none();
+    if done {
+        position_ext.cancel_all_after_ts = 0;
+    }
+
+    // The bounty is paid once, to the call cancelling the last orders
+    let bounty = position_ext.heartbeat_bounty;
+    if bounty > 0 && done && !cancelled.is_empty() {
+        position_ext.heartbeat_bounty = 0;
+        drop(position_ext);
+
+        let extension_ai = ctx.accounts.position_extension.to_account_info();
+        **extension_ai.try_borrow_mut_lamports()? -= bounty;
+        **ctx.accounts.keeper.try_borrow_mut_lamports()? += bounty;
+
+        emit_stack(HeartbeatBountyLog {
+            open_orders_account: ctx.accounts.open_orders_account.key(),
+            keeper: ctx.accounts.keeper.key(),
+            bounty,
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::accounts_zerocopy::AccountInfoRef;
+use crate::error::*;
+use crate::health_check::invoke_health_check;
+use crate::instructions::place_order_from_free_funds;
+use crate::logs::{emit_stack, ExecuteTriggerLog, PlaceLog};
+use crate::state::*;
+
+pub fn execute_trigger<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ExecuteTrigger<'info>>,
+) -> Result<Option<u128>> {
+    let (order, trigger_id, limit) = {
+        let trigger_order = ctx.accounts.trigger_order.load()?;
+        (
+            trigger_order.order()?,
+            trigger_order.trigger_id,
+            trigger_order.limit,
+        )
+    };
+    let order = match order {
+        Some(order) => order,
+        None => {
+            msg!("Trigger order is expired");
+            return Ok(None);
+        }
+    };
+
+    invoke_health_check(
+        &ctx.accounts.open_orders_account,
+        &ctx.accounts.market,
+        std::slice::from_ref(&order),
+        ctx.remaining_accounts,
+    )?;
+
+    let mut open_orders_account = ctx.accounts.open_orders_account.load_mut()?;
+    let open_orders_account_pk = ctx.accounts.open_orders_account.key();
+
+    let clock = Clock::get()?;
+
+    let mut market = ctx.accounts.market.load_mut()?;
+    require!(
+        !market.is_expired(clock.unix_timestamp),
+        OpenBookError::MarketHasExpired
+    );
+
+    let mut book = Orderbook {
+        bids: ctx.accounts.bids.load_mut()?,
+        asks: ctx.accounts.asks.load_mut()?,
+    };
+    let mut event_heap = EventHeapRefMut::load_mut(&ctx.accounts.event_heap)?;
+
+    let now_ts: u64 = clock.unix_timestamp.try_into().unwrap();
+
+    let oracle_price_lots = market.oracle_price_lots(
+        AccountInfoRef::borrow_some(ctx.accounts.oracle_a.as_ref())?.as_ref(),
+        AccountInfoRef::borrow_some(ctx.accounts.oracle_b.as_ref())?.as_ref(),
+        clock.slot,
+    )?;
+
+    require!(
+        ctx.accounts.trigger_order.load()?.is_triggered(
+            oracle_price_lots,
+            book.bids.best_price(now_ts, oracle_price_lots),
+            book.asks.best_price(now_ts, oracle_price_lots),
+        ),
+        OpenBookError::TriggerConditionNotMet
+    );
+
+    let market_pk = ctx.accounts.market.key();
+    let order_with_amounts = place_order_from_free_funds(
+        &order,
+        limit,
+        &mut market,
+        market_pk,
+        &mut book,
+        &mut event_heap,
+        oracle_price_lots,
+        &mut open_orders_account,
+        open_orders_account_pk,
+        now_ts,
+        ctx.remaining_accounts,
+    )?;
+    emit_cpi!(PlaceLog::new(
+        market_pk,
+        &market,
+        open_orders_account_pk,
+        &order,
+        &order_with_amounts
+    ));
+    let order_id = order_with_amounts.order_id;
+
+    emit_stack(ExecuteTriggerLog {
+        market: market_pk,
+        open_orders_account: open_orders_account_pk,
+        trigger_id,
+        keeper: ctx.accounts.keeper.key(),
+        order_id,
+    });
+
+    Ok(order_id)
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::state::*;
+
+pub fn expire_orders<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ExpireOrders<'info>>,
+    limit: u8,
+) -> Result<()> {
+    let now_ts: u64 = Clock::get()?.unix_timestamp.try_into().unwrap();
+    let mut market = ctx.accounts.market.load_mut()?;
+    let mut book = Orderbook {
+        bids: ctx.accounts.bids.load_mut()?,
+        asks: ctx.accounts.asks.load_mut()?,
+    };
+    let mut event_heap = EventHeapRefMut::load_mut(&ctx.accounts.event_heap)?;
+
+    let outs = book.expire_orders(
+        &mut market,
+        &mut event_heap,
+        now_ts,
+        limit,
+        ctx.remaining_accounts,
+    )?;
+    book.update_best_levels(now_ts);
+
+    msg!("expired {} orders", outs.len());
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::state::*;
+use crate::OrderInfo;
+
+pub fn find_order<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, FindOrder<'info>>,
+    client_order_id: u64,
+) -> Result<Option<OrderInfo>> {
+    let open_orders_account = ctx.accounts.open_orders_account.load()?;
+    let oo = match open_orders_account.find_order_with_client_order_id(client_order_id) {
+        Some(oo) => *oo,
+        None => return Ok(None),
+    };
+
+    let side_and_tree = oo.side_and_tree();
+    let bookside = match side_and_tree.side() {
+        Side::Bid => ctx.accounts.bids.load()?,
+        Side::Ask => ctx.accounts.asks.load()?,
+    };
+    let order_tree = side_and_tree.order_tree();
+    let mut leaf_node = bookside.find_by_key(order_tree, oo.id).copied();
+    if leaf_node.is_none() && bookside.paged_leaf_count > 0 {
+        for page in load_book_side_pages(&bookside, ctx.remaining_accounts)? {
+            leaf_node = page.find_by_key(order_tree, oo.id).copied();
+            if leaf_node.is_some() {
+                break;
+            }
+        }
+    }
+
+    Ok(Some(OrderInfo {
+        order_id: oo.id,
+        client_order_id: oo.client_id,
+        side: side_and_tree.side(),
+        is_oracle_pegged: order_tree == BookSideOrderTree::OraclePegged,
+        locked_price: oo.locked_price,
+        quantity: leaf_node.map_or(0, |leaf| leaf.quantity),
+        timestamp: leaf_node.map_or(0, |leaf| leaf.timestamp),
+    }))
+}
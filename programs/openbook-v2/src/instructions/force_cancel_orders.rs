@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::logs::{emit_stack, OutLog};
+use crate::state::*;
+
+pub fn force_cancel_orders(ctx: Context<ForceCancelOrders>, limit: u8) -> Result<()> {
+    let market_pk = ctx.accounts.market.key();
+    let mut account = ctx.accounts.open_orders_account.load_mut()?;
+    let mut market = ctx.accounts.market.load_mut()?;
+
+    let mut book = Orderbook {
+        bids: ctx.accounts.bids.load_mut()?,
+        asks: ctx.accounts.asks.load_mut()?,
+    };
+    let now_ts = Clock::get()?.unix_timestamp as u64;
+
+    let cancelled = book.cancel_all_orders(
+        &mut account,
+        &mut market,
+        limit,
+        None,
+        None,
+        now_ts,
+        ctx.remaining_accounts,
+    )?;
+    book.update_best_levels(now_ts);
+
+    // The orders are out of the book like booted ones, but the account is released
+    // right away so nothing goes to the event heap
+    for (side, leaf_node) in cancelled.iter() {
+        let out = OutEvent::new(
+            *side,
+            leaf_node,
+            OutReason::AdminCancel,
+            now_ts,
+            market.seq_num,
+            market.gen_event_seq_num(),
+        );
+        emit_stack(OutLog::new(market_pk, market.seq_num, &out));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::token_utils::*;
+
+pub fn fund_taker_rebate_campaign(
+    ctx: Context<FundTakerRebateCampaign>,
+    amount: u64,
+) -> Result<()> {
+    let mut campaign = ctx.accounts.taker_rebate_campaign.load_mut()?;
+    campaign.budget_remaining += amount;
+
+    token_transfer(
+        amount,
+        &ctx.accounts.token_program,
+        &ctx.accounts.admin_quote_account,
+        &ctx.accounts.rebate_vault,
+        &ctx.accounts.collect_fee_admin,
+    )
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use fixed::types::I80F48;
+
+use crate::accounts_ix::*;
+use crate::accounts_zerocopy::AccountInfoRef;
+use crate::MarketQuote;
+
+pub fn get_market_quote(ctx: Context<GetMarketQuote>) -> Result<MarketQuote> {
+    let clock = Clock::get()?;
+    let now_ts: u64 = clock.unix_timestamp.try_into().unwrap();
+    let market = ctx.accounts.market.load()?;
+
+    let oracle_price = market
+        .oracle_price(
+            AccountInfoRef::borrow_some(ctx.accounts.oracle_a.as_ref())?.as_ref(),
+            AccountInfoRef::borrow_some(ctx.accounts.oracle_b.as_ref())?.as_ref(),
+            clock.slot,
+        )?
+        .map(|oracle_price| oracle_price.price);
+    let oracle_price_lots = match oracle_price {
+        Some(price) => Some(market.native_price_to_lot(price)?),
+        None => None,
+    };
+
+    let bids = ctx.accounts.bids.load()?;
+    let asks = ctx.accounts.asks.load()?;
+    let best_bid = bids.levels(1, now_ts, oracle_price_lots).first().copied();
+    let best_ask = asks.levels(1, now_ts, oracle_price_lots).first().copied();
+
+    let (mid_price, spread_bps) = match (best_bid, best_ask) {
+        (Some(bid), Some(ask)) => {
+            let bid_ask_sum = i128::from(bid.price_lots) + i128::from(ask.price_lots);
+            let spread = i128::from(ask.price_lots) - i128::from(bid.price_lots);
+            let mid_price_lots = I80F48::from_num(bid_ask_sum) / 2;
+            (
+                Some(mid_price_lots * market.lot_to_native_price(1)),
+                Some((spread * 20_000 / bid_ask_sum) as i64),
+            )
+        }
+        _ => (None, None),
+    };
+
+    let oracle_deviation_bps = match (mid_price, oracle_price) {
+        (Some(mid_price), Some(oracle_price)) if oracle_price > 0 => {
+            ((mid_price - oracle_price) * 10_000 / oracle_price).checked_to_num()
+        }
+        _ => None,
+    };
+
+    Ok(MarketQuote {
+        best_bid_lots: best_bid.map(|level| level.price_lots),
+        best_bid_quantity: best_bid.map_or(0, |level| level.quantity),
+        best_ask_lots: best_ask.map(|level| level.price_lots),
+        best_ask_quantity: best_ask.map_or(0, |level| level.quantity),
+        mid_price,
+        spread_bps,
+        oracle_price,
+        oracle_deviation_bps,
+    })
+}
@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::error::*;
+
+pub fn heartbeat(ctx: Context<Heartbeat>) -> Result<()> {
+    let mut position_ext = ctx.accounts.position_extension.load_mut()?;
+    require!(
+        position_ext.heartbeat_interval > 0,
+        OpenBookError::HeartbeatNotEnabled
+    );
+
+    position_ext.cancel_all_after_ts =
+        Clock::get()?.unix_timestamp as u64 + u64::from(position_ext.heartbeat_interval);
+    Ok(())
+}
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::error::*;
+
+pub fn join_merkle_allowlist(
+    ctx: Context<JoinMerkleAllowlist>,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let merkle_allowlist = ctx.accounts.merkle_allowlist.load()?;
+    require!(
+        merkle_allowlist.contains(&ctx.accounts.owner.key(), &proof),
+        OpenBookError::InvalidMerkleProof
+    );
+
+    let mut open_orders_account = ctx.accounts.open_orders_account.load_mut()?;
+    open_orders_account.set_allowlisted(true);
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::accounts_ix::*;
+use crate::accounts_zerocopy::LoadMutZeroCopyRef;
+use crate::error::OpenBookError;
+use crate::state::*;
+
+pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+    let account = ctx.accounts.account.to_account_info();
+
+    let is_market = account
+        .try_borrow_data()?
+        .starts_with(&Market::discriminator());
+    if is_market {
+        let mut market = account.load_mut::<Market>()?;
+        // Nothing to initialize yet, new fields of the reserved space start zeroed
+        market.version = market.version.max(MARKET_VERSION);
+        return Ok(());
+    }
+
+    let mut open_orders_account = account.load_mut::<OpenOrdersAccount>()?;
+    if open_orders_account.version == 0 {
+        // Version 0 accounts didn't track bids_quote_lots and it can't be recovered
+        // from their orders, it's only known to be 0 without bids
+        require_eq!(
+            open_orders_account.position.bids_base_lots,
+            0,
+            OpenBookError::MigrateAccountWithBids
+        );
+        open_orders_account.position.bids_quote_lots = 0;
+    }
+    open_orders_account.version = open_orders_account.version.max(OPEN_ORDERS_ACCOUNT_VERSION);
+    Ok(())
+}
@@ -4,6 +4,9 @@ use std::cmp;
 use crate::accounts_ix::*;
 use crate::accounts_zerocopy::AccountInfoRef;
 use crate::error::*;
+use crate::health_check::invoke_health_check;
+use crate::logs::{emit_stack, FillLog, OutLog, PlaceLog};
+use crate::maker_settlement::settle_makers_directly;
 use crate::state::*;
 use crate::token_utils::*;
 
@@ -20,6 +23,13 @@ pub fn place_order<'c: 'info, 'info>(
         OpenBookError::InvalidInputLots
     );
 
+    invoke_health_check(
+        &ctx.accounts.open_orders_account,
+        &ctx.accounts.market,
+        std::slice::from_ref(&order),
+        ctx.remaining_accounts,
+    )?;
+
     let mut open_orders_account = ctx.accounts.open_orders_account.load_mut()?;
     let open_orders_account_pk = ctx.accounts.open_orders_account.key();
 
@@ -40,8 +50,7 @@ pub fn place_order<'c: 'info, 'info>(
         bids: ctx.accounts.bids.load_mut()?,
         asks: ctx.accounts.asks.load_mut()?,
     };
-    let mut event_heap = ctx.accounts.event_heap.load_mut()?;
-    let event_heap_size_before = event_heap.len();
+    let mut event_heap = EventHeapRefMut::load_mut(&ctx.accounts.event_heap)?;
 
     let now_ts: u64 = clock.unix_timestamp.try_into().unwrap();
 
@@ -51,6 +60,28 @@ pub fn place_order<'c: 'info, 'info>(
         clock.slot,
     )?;
 
+    let market_pk = ctx.accounts.market.key();
+    let order_with_amounts = new_order_and_log(
+        &order,
+        limit,
+        &mut market,
+        market_pk,
+        &mut book,
+        &mut event_heap,
+        oracle_price_lots,
+        &mut open_orders_account,
+        open_orders_account_pk,
+        now_ts,
+        ctx.remaining_accounts,
+    )?;
+    emit_cpi!(PlaceLog::new(
+        market_pk,
+        &market,
+        open_orders_account_pk,
+        &order,
+        &order_with_amounts
+    ));
+
     let OrderWithAmounts {
         order_id,
         total_base_taken_native,
@@ -59,19 +90,9 @@ pub fn place_order<'c: 'info, 'info>(
         posted_quote_native,
         taker_fees,
         maker_fees,
+        processed_fills,
         ..
-    } = book.new_order(
-        &order,
-        &mut market,
-        &ctx.accounts.market.key(),
-        &mut event_heap,
-        oracle_price_lots,
-        Some(&mut open_orders_account),
-        &open_orders_account_pk,
-        now_ts,
-        limit,
-        ctx.remaining_accounts,
-    )?;
+    } = order_with_amounts;
 
     let position = &mut open_orders_account.position;
     let deposit_amount = match order.side {
@@ -85,6 +106,7 @@ pub fn place_order<'c: 'info, 'info>(
 
             // Update market deposit total
             position.quote_free_native -= free_qty_to_lock;
+            market.quote_free_native_total -= free_qty_to_lock as i64;
             market.quote_deposit_total += deposit_amount;
 
             deposit_amount
@@ -99,18 +121,36 @@ pub fn place_order<'c: 'info, 'info>(
 
             // Update market deposit total
             position.base_free_native -= free_qty_to_lock;
+            market.base_free_native_total -= free_qty_to_lock as i64;
             market.base_deposit_total += deposit_amount;
 
             deposit_amount
         }
     };
 
-    if event_heap.len() > event_heap_size_before {
-        position.penalty_heap_count += 1;
+    require_gte!(
+        ctx.accounts.user_token_account.amount,
+        deposit_amount,
+        OpenBookError::InsufficientFunds
+    );
+
+    let paid_to_makers = settle_makers_directly(
+        &mut market,
+        order.side,
+        &processed_fills,
+        deposit_amount,
+        &ctx.accounts.user_token_account,
+        &ctx.accounts.signer,
+        &ctx.accounts.token_program,
+        ctx.remaining_accounts,
+    )?;
+    match order.side {
+        Side::Bid => market.quote_deposit_total -= paid_to_makers,
+        Side::Ask => market.base_deposit_total -= paid_to_makers,
     }
 
     token_transfer(
-        deposit_amount,
+        deposit_amount - paid_to_makers,
         &ctx.accounts.token_program,
         &ctx.accounts.user_token_account,
         &ctx.accounts.market_vault,
@@ -119,3 +159,111 @@ pub fn place_order<'c: 'info, 'info>(
 
     Ok(order_id)
 }
+
+/// Matches `order` against the book, posts what is left and logs the fills and outs.
+/// The account is charged the event heap penalty when the order queued an event, the
+/// caller still has to lock the funds for `OrderWithAmounts`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn new_order_and_log<'c: 'info, 'info>(
+    order: &Order,
+    limit: u8,
+    market: &mut Market,
+    market_pk: Pubkey,
+    book: &mut Orderbook,
+    event_heap: &mut EventHeapRefMut,
+    oracle_price_lots: Option<i64>,
+    open_orders_account: &mut OpenOrdersAccount,
+    open_orders_account_pk: Pubkey,
+    now_ts: u64,
+    remaining_accounts: &'c [AccountInfo<'info>],
+) -> Result<OrderWithAmounts> {
+    let event_heap_size_before = event_heap.len();
+
+    let order_with_amounts = book.new_order(
+        order,
+        market,
+        &market_pk,
+        event_heap,
+        oracle_price_lots,
+        Some(open_orders_account),
+        &open_orders_account_pk,
+        now_ts,
+        limit,
+        remaining_accounts,
+    )?;
+    book.update_best_levels(now_ts);
+
+    for fill in order_with_amounts.fills.iter() {
+        emit_stack(FillLog::new(market_pk, market, fill)?);
+    }
+    for out in order_with_amounts.outs.iter() {
+        emit_stack(OutLog::new(market_pk, market.seq_num, out));
+    }
+
+    if event_heap.len() > event_heap_size_before {
+        open_orders_account.position.penalty_heap_count += 1;
+    }
+
+    Ok(order_with_amounts)
+}
+
+/// Places `order` without a token account to deposit from: nobody signs for one, so
+/// everything the order takes or posts is locked from the free funds of the account.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn place_order_from_free_funds<'c: 'info, 'info>(
+    order: &Order,
+    limit: u8,
+    market: &mut Market,
+    market_pk: Pubkey,
+    book: &mut Orderbook,
+    event_heap: &mut EventHeapRefMut,
+    oracle_price_lots: Option<i64>,
+    open_orders_account: &mut OpenOrdersAccount,
+    open_orders_account_pk: Pubkey,
+    now_ts: u64,
+    remaining_accounts: &'c [AccountInfo<'info>],
+) -> Result<OrderWithAmounts> {
+    let order_with_amounts = new_order_and_log(
+        order,
+        limit,
+        market,
+        market_pk,
+        book,
+        event_heap,
+        oracle_price_lots,
+        open_orders_account,
+        open_orders_account_pk,
+        now_ts,
+        remaining_accounts,
+    )?;
+
+    let position = &mut open_orders_account.position;
+    match order.side {
+        Side::Bid => {
+            let max_quote_including_fees = order_with_amounts.total_quote_taken_native
+                + order_with_amounts.posted_quote_native
+                + order_with_amounts.taker_fees
+                + order_with_amounts.maker_fees;
+            require_gte!(
+                position.quote_free_native,
+                max_quote_including_fees,
+                OpenBookError::InsufficientFreeFunds
+            );
+            position.quote_free_native -= max_quote_including_fees;
+            market.quote_free_native_total -= max_quote_including_fees as i64;
+        }
+        Side::Ask => {
+            let max_base_native =
+                order_with_amounts.total_base_taken_native + order_with_amounts.posted_base_native;
+            require_gte!(
+                position.base_free_native,
+                max_base_native,
+                OpenBookError::InsufficientFreeFunds
+            );
+            position.base_free_native -= max_base_native;
+            market.base_free_native_total -= max_base_native as i64;
+        }
+    };
+
+    Ok(order_with_amounts)
+}
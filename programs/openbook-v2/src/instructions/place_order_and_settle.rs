@@ -0,0 +1,165 @@
+use anchor_lang::prelude::*;
+use std::cmp;
+
+use crate::accounts_ix::*;
+use crate::accounts_zerocopy::AccountInfoRef;
+use crate::error::*;
+use crate::health_check::invoke_health_check;
+use crate::instructions::new_order_and_log;
+use crate::logs::PlaceLog;
+use crate::state::*;
+use crate::token_utils::*;
+
+pub fn place_order_and_settle<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, PlaceOrderAndSettle<'info>>,
+    order: Order,
+    limit: u8,
+) -> Result<()> {
+    require_gte!(order.max_base_lots, 0, OpenBookError::InvalidInputLots);
+    require_gte!(
+        order.max_quote_lots_including_fees,
+        0,
+        OpenBookError::InvalidInputLots
+    );
+
+    invoke_health_check(
+        &ctx.accounts.open_orders_account,
+        &ctx.accounts.market,
+        std::slice::from_ref(&order),
+        ctx.remaining_accounts,
+    )?;
+
+    let mut open_orders_account = ctx.accounts.open_orders_account.load_mut()?;
+    let open_orders_account_pk = ctx.accounts.open_orders_account.key();
+
+    let clock = Clock::get()?;
+
+    let mut market = ctx.accounts.market.load_mut()?;
+    require!(
+        !market.is_expired(clock.unix_timestamp),
+        OpenBookError::MarketHasExpired
+    );
+
+    let mut book = Orderbook {
+        bids: ctx.accounts.bids.load_mut()?,
+        asks: ctx.accounts.asks.load_mut()?,
+    };
+    let mut event_heap = EventHeapRefMut::load_mut(&ctx.accounts.event_heap)?;
+
+    let now_ts: u64 = clock.unix_timestamp.try_into().unwrap();
+
+    let oracle_price_lots = market.oracle_price_lots(
+        AccountInfoRef::borrow_some(ctx.accounts.oracle_a.as_ref())?.as_ref(),
+        AccountInfoRef::borrow_some(ctx.accounts.oracle_b.as_ref())?.as_ref(),
+        clock.slot,
+    )?;
+
+    let market_pk = ctx.accounts.market.key();
+    let order_with_amounts = new_order_and_log(
+        &order,
+        limit,
+        &mut market,
+        market_pk,
+        &mut book,
+        &mut event_heap,
+        oracle_price_lots,
+        &mut open_orders_account,
+        open_orders_account_pk,
+        now_ts,
+        ctx.remaining_accounts,
+    )?;
+    emit_cpi!(PlaceLog::new(
+        market_pk,
+        &market,
+        open_orders_account_pk,
+        &order,
+        &order_with_amounts
+    ));
+
+    let OrderWithAmounts {
+        total_base_taken_native,
+        total_quote_taken_native,
+        taker_fees,
+        ..
+    } = order_with_amounts;
+
+    // Nothing is posted: the deposit only covers what was taken, and what the taker
+    // received was credited to the free funds of the account by the matching
+    let position = &mut open_orders_account.position;
+    let (deposit_amount, withdraw_amount) = match order.side {
+        Side::Bid => {
+            let max_quote_including_fees = total_quote_taken_native + taker_fees;
+            let free_qty_to_lock = cmp::min(max_quote_including_fees, position.quote_free_native);
+            let deposit_amount = max_quote_including_fees - free_qty_to_lock;
+
+            position.quote_free_native -= free_qty_to_lock;
+            market.quote_free_native_total -= free_qty_to_lock as i64;
+            market.quote_deposit_total += deposit_amount;
+
+            position.base_free_native -= total_base_taken_native;
+            market.base_free_native_total -= total_base_taken_native as i64;
+            market.base_deposit_total -= total_base_taken_native;
+
+            (deposit_amount, total_base_taken_native)
+        }
+        Side::Ask => {
+            let free_qty_to_lock = cmp::min(total_base_taken_native, position.base_free_native);
+            let deposit_amount = total_base_taken_native - free_qty_to_lock;
+
+            position.base_free_native -= free_qty_to_lock;
+            market.base_free_native_total -= free_qty_to_lock as i64;
+            market.base_deposit_total += deposit_amount;
+
+            let quote_received = total_quote_taken_native - taker_fees;
+            position.quote_free_native -= quote_received;
+            market.quote_free_native_total -= quote_received as i64;
+            market.quote_deposit_total -= quote_received;
+
+            (deposit_amount, quote_received)
+        }
+    };
+
+    let seeds = market_seeds!(market, ctx.accounts.market.key());
+
+    drop(market);
+
+    let (user_deposit_acc, user_withdraw_acc, market_deposit_acc, market_withdraw_acc) =
+        match order.side {
+            Side::Bid => (
+                &ctx.accounts.user_quote_account,
+                &ctx.accounts.user_base_account,
+                &ctx.accounts.market_quote_vault,
+                &ctx.accounts.market_base_vault,
+            ),
+            Side::Ask => (
+                &ctx.accounts.user_base_account,
+                &ctx.accounts.user_quote_account,
+                &ctx.accounts.market_base_vault,
+                &ctx.accounts.market_quote_vault,
+            ),
+        };
+
+    require_gte!(
+        user_deposit_acc.amount,
+        deposit_amount,
+        OpenBookError::InsufficientFunds
+    );
+    token_transfer(
+        deposit_amount,
+        &ctx.accounts.token_program,
+        user_deposit_acc.as_ref(),
+        market_deposit_acc,
+        &ctx.accounts.signer,
+    )?;
+
+    token_transfer_signed(
+        withdraw_amount,
+        &ctx.accounts.token_program,
+        market_withdraw_acc,
+        user_withdraw_acc.as_ref(),
+        &ctx.accounts.market_authority,
+        seeds,
+    )?;
+
+    Ok(())
+}
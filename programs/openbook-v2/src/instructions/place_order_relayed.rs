@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::logs::{emit_stack, RelayerFeeLog};
+use crate::state::*;
+use crate::token_utils::*;
+
+pub fn place_order_relayed<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, PlaceOrderRelayed<'info>>,
+    order: Order,
+    limit: u8,
+    relayer_fee: u64,
+) -> Result<Option<u128>> {
+    let order_id = crate::instructions::place_order(
+        Context::new(
+            ctx.program_id,
+            &mut ctx.accounts.to_place_order(),
+            ctx.remaining_accounts,
+            ctx.bumps.to_place_order(),
+        ),
+        order,
+        limit,
+    )?;
+
+    // Paid out of the same token account, on top of what the order locked
+    if relayer_fee > 0 {
+        token_transfer(
+            relayer_fee,
+            &ctx.accounts.token_program,
+            &ctx.accounts.user_token_account,
+            &ctx.accounts.relayer_token_account,
+            &ctx.accounts.signer,
+        )?;
+
+        emit_stack(RelayerFeeLog {
+            open_orders_account: ctx.accounts.open_orders_account.key(),
+            relayer: ctx.accounts.relayer.key(),
+            mint: ctx.accounts.user_token_account.mint,
+            relayer_fee,
+        });
+    }
+
+    Ok(order_id)
+}
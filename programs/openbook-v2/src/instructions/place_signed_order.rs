@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::accounts_zerocopy::AccountInfoRef;
+use crate::error::*;
+use crate::health_check::invoke_health_check;
+use crate::instructions::place_order_from_free_funds;
+use crate::logs::PlaceLog;
+use crate::signed_order::{ed25519_signer, SignedOrder};
+use crate::state::*;
+
+pub fn place_signed_order<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, PlaceSignedOrder<'info>>,
+    signed_order: SignedOrder,
+    order: Order,
+) -> Result<Option<u128>> {
+    require_keys_eq!(signed_order.market, ctx.accounts.market.key());
+    require_keys_eq!(
+        signed_order.open_orders_account,
+        ctx.accounts.open_orders_account.key()
+    );
+    let open_orders_account_pk = ctx.accounts.open_orders_account.key();
+
+    let signer = ed25519_signer(
+        &ctx.accounts.instructions_sysvar,
+        &signed_order.try_to_vec()?,
+    )?;
+
+    let clock = Clock::get()?;
+    let now_ts: u64 = clock.unix_timestamp.try_into().unwrap();
+    require!(
+        signed_order.valid_until_ts == 0 || now_ts <= signed_order.valid_until_ts,
+        OpenBookError::SignedOrderExpired
+    );
+
+    // The nonce is kept in the position extension, which signed orders need
+    {
+        let open_orders_account = ctx.accounts.open_orders_account.load()?;
+        require!(
+            open_orders_account.is_owner_or_delegate(signer),
+            OpenBookError::InvalidSignedOrderSigner
+        );
+        let mut position_ext = load_position_extension(
+            &open_orders_account,
+            &open_orders_account_pk,
+            ctx.remaining_accounts,
+        )?
+        .ok_or_else(|| error!(OpenBookError::MissingPositionExtension))?;
+        require_gt!(
+            signed_order.nonce,
+            position_ext.signed_order_nonce,
+            OpenBookError::SignedOrderNonceUsed
+        );
+        position_ext.signed_order_nonce = signed_order.nonce;
+    }
+
+    invoke_health_check(
+        &ctx.accounts.open_orders_account,
+        &ctx.accounts.market,
+        std::slice::from_ref(&order),
+        ctx.remaining_accounts,
+    )?;
+
+    let mut open_orders_account = ctx.accounts.open_orders_account.load_mut()?;
+
+    let mut market = ctx.accounts.market.load_mut()?;
+    require!(
+        !market.is_expired(clock.unix_timestamp),
+        OpenBookError::MarketHasExpired
+    );
+
+    let mut book = Orderbook {
+        bids: ctx.accounts.bids.load_mut()?,
+        asks: ctx.accounts.asks.load_mut()?,
+    };
+    let mut event_heap = EventHeapRefMut::load_mut(&ctx.accounts.event_heap)?;
+
+    let oracle_price_lots = market.oracle_price_lots(
+        AccountInfoRef::borrow_some(ctx.accounts.oracle_a.as_ref())?.as_ref(),
+        AccountInfoRef::borrow_some(ctx.accounts.oracle_b.as_ref())?.as_ref(),
+        clock.slot,
+    )?;
+
+    let market_pk = ctx.accounts.market.key();
+    let order_with_amounts = place_order_from_free_funds(
+        &order,
+        signed_order.order.limit,
+        &mut market,
+        market_pk,
+        &mut book,
+        &mut event_heap,
+        oracle_price_lots,
+        &mut open_orders_account,
+        open_orders_account_pk,
+        now_ts,
+        ctx.remaining_accounts,
+    )?;
+    emit_cpi!(PlaceLog::new(
+        market_pk,
+        &market,
+        open_orders_account_pk,
+        &order,
+        &order_with_amounts
+    ));
+
+    Ok(order_with_amounts.order_id)
+}
@@ -3,6 +3,8 @@ use anchor_lang::prelude::*;
 use crate::accounts_ix::*;
 use crate::accounts_zerocopy::AccountInfoRef;
 use crate::error::*;
+use crate::logs::{emit_stack, FillLog, OutLog, PlaceLog};
+use crate::maker_settlement::settle_makers_directly;
 use crate::state::*;
 use crate::token_utils::*;
 
@@ -32,7 +34,7 @@ pub fn place_take_order<'c: 'info, 'info>(
         asks: ctx.accounts.asks.load_mut()?,
     };
 
-    let mut event_heap = ctx.accounts.event_heap.load_mut()?;
+    let mut event_heap = EventHeapRefMut::load_mut(&ctx.accounts.event_heap)?;
     let event_heap_size_before = event_heap.len();
 
     let now_ts: u64 = clock.unix_timestamp.try_into().unwrap();
@@ -45,13 +47,7 @@ pub fn place_take_order<'c: 'info, 'info>(
 
     let side = order.side;
 
-    let OrderWithAmounts {
-        total_base_taken_native,
-        total_quote_taken_native,
-        referrer_amount,
-        taker_fees,
-        ..
-    } = book.new_order(
+    let order_with_amounts = book.new_order(
         &order,
         &mut market,
         &ctx.accounts.market.key(),
@@ -63,6 +59,31 @@ pub fn place_take_order<'c: 'info, 'info>(
         limit,
         ctx.remaining_accounts,
     )?;
+    book.update_best_levels(now_ts);
+
+    let market_pk = ctx.accounts.market.key();
+    for fill in order_with_amounts.fills.iter() {
+        emit_stack(FillLog::new(market_pk, &market, fill)?);
+    }
+    for out in order_with_amounts.outs.iter() {
+        emit_stack(OutLog::new(market_pk, market.seq_num, out));
+    }
+    emit_cpi!(PlaceLog::new(
+        market_pk,
+        &market,
+        ctx.accounts.signer.key(),
+        &order,
+        &order_with_amounts
+    ));
+
+    let OrderWithAmounts {
+        total_base_taken_native,
+        total_quote_taken_native,
+        referrer_amount,
+        taker_fees,
+        processed_fills,
+        ..
+    } = order_with_amounts;
 
     // place_take_orders doesnt pay to referrers
     let makers_rebates = taker_fees - referrer_amount;
@@ -82,19 +103,6 @@ pub fn place_take_order<'c: 'info, 'info>(
         }
     };
 
-    let seeds = market_seeds!(market, ctx.accounts.market.key());
-
-    drop(market);
-
-    if event_heap.len() > event_heap_size_before {
-        system_program_transfer(
-            PENALTY_EVENT_HEAP,
-            &ctx.accounts.system_program,
-            &ctx.accounts.penalty_payer,
-            &ctx.accounts.market,
-        )?;
-    }
-
     let (user_deposit_acc, user_withdraw_acc, market_deposit_acc, market_withdraw_acc) = match side
     {
         Side::Bid => (
@@ -111,8 +119,42 @@ pub fn place_take_order<'c: 'info, 'info>(
         ),
     };
 
-    token_transfer(
+    require_gte!(
+        user_deposit_acc.amount,
+        deposit_amount,
+        OpenBookError::InsufficientFunds
+    );
+
+    let paid_to_makers = settle_makers_directly(
+        &mut market,
+        side,
+        &processed_fills,
         deposit_amount,
+        user_deposit_acc,
+        &ctx.accounts.signer,
+        &ctx.accounts.token_program,
+        ctx.remaining_accounts,
+    )?;
+    match side {
+        Side::Bid => market.quote_deposit_total -= paid_to_makers,
+        Side::Ask => market.base_deposit_total -= paid_to_makers,
+    }
+
+    let seeds = market_seeds!(market, ctx.accounts.market.key());
+
+    drop(market);
+
+    if event_heap.len() > event_heap_size_before {
+        system_program_transfer(
+            PENALTY_EVENT_HEAP,
+            &ctx.accounts.system_program,
+            &ctx.accounts.penalty_payer,
+            &ctx.accounts.market,
+        )?;
+    }
+
+    token_transfer(
+        deposit_amount - paid_to_makers,
         &ctx.accounts.token_program,
         user_deposit_acc.as_ref(),
         market_deposit_acc,
@@ -6,10 +6,11 @@ use crate::state::*;
 
 pub fn prune_orders(ctx: Context<PruneOrders>, limit: u8) -> Result<()> {
     let mut account = ctx.accounts.open_orders_account.load_mut()?;
-    let market = ctx.accounts.market.load()?;
+    let mut market = ctx.accounts.market.load_mut()?;
+    let now_ts = Clock::get()?.unix_timestamp;
 
     require!(
-        market.is_expired(Clock::get()?.unix_timestamp),
+        market.is_expired(now_ts),
         OpenBookError::MarketHasNotExpired
     );
 
@@ -18,7 +19,16 @@ pub fn prune_orders(ctx: Context<PruneOrders>, limit: u8) -> Result<()> {
         asks: ctx.accounts.asks.load_mut()?,
     };
 
-    book.cancel_all_orders(&mut account, *market, limit, None, None)?;
+    book.cancel_all_orders(
+        &mut account,
+        &mut market,
+        limit,
+        None,
+        None,
+        now_ts as u64,
+        ctx.remaining_accounts,
+    )?;
+    book.update_best_levels(now_ts as u64);
 
     Ok(())
 }
@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+
+pub fn register_market(ctx: Context<RegisterMarket>) -> Result<()> {
+    let mut market = ctx.accounts.market.load_mut()?;
+    let mut registry_page = ctx.accounts.registry_page.load_mut()?;
+
+    registry_page.push(ctx.accounts.market.key(), &market);
+    market.registered = 1;
+    Ok(())
+}
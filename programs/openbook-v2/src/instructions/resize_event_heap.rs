@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::error::OpenBookError;
+use crate::state::*;
+
+pub fn resize_event_heap(ctx: Context<ResizeEventHeap>, additional_nodes: u16) -> Result<()> {
+    require_gt!(additional_nodes, 0, OpenBookError::InvalidInputHeapSlots);
+
+    let mut event_heap = EventHeapRefMut::load_mut(&ctx.accounts.event_heap)?;
+    event_heap.init_extension_nodes()?;
+
+    let mut market = ctx.accounts.market.load_mut()?;
+    market.update_event_heap_stats(&event_heap);
+    Ok(())
+}
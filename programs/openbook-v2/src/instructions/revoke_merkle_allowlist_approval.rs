@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+
+pub fn revoke_merkle_allowlist_approval(ctx: Context<RevokeMerkleAllowlistApproval>) -> Result<()> {
+    let mut open_orders_account = ctx.accounts.open_orders_account.load_mut()?;
+    open_orders_account.set_allowlisted(false);
+    Ok(())
+}
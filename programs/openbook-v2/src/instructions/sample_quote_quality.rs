@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::accounts_zerocopy::AccountInfoRef;
+
+pub fn sample_quote_quality(ctx: Context<SampleQuoteQuality>) -> Result<()> {
+    let clock = Clock::get()?;
+    let now_ts: u64 = clock.unix_timestamp.try_into().unwrap();
+
+    let market = ctx.accounts.market.load()?;
+    let mut quote_quality = ctx.accounts.quote_quality.load_mut()?;
+
+    let oracle_price = market.oracle_price(
+        AccountInfoRef::borrow_some(ctx.accounts.oracle_a.as_ref())?.as_ref(),
+        AccountInfoRef::borrow_some(ctx.accounts.oracle_b.as_ref())?.as_ref(),
+        clock.slot,
+    )?;
+    let oracle_price_lots = match oracle_price {
+        Some(oracle_price) => market.native_price_to_lot(oracle_price.price)?,
+        None => {
+            // quotes can't be measured, the time until the next sample isn't counted
+            msg!("Oracle price unavailable, skipping sample");
+            quote_quality.last_sample_timestamp = now_ts;
+            return Ok(());
+        }
+    };
+
+    let bid_size_lots =
+        quote_quality.quoted_size_lots(&*ctx.accounts.bids.load()?, now_ts, oracle_price_lots);
+    let ask_size_lots =
+        quote_quality.quoted_size_lots(&*ctx.accounts.asks.load()?, now_ts, oracle_price_lots);
+    quote_quality.record_sample(now_ts, bid_size_lots, ask_size_lots);
+    Ok(())
+}
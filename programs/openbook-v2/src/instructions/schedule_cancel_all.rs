@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+
+pub fn schedule_cancel_all(ctx: Context<ScheduleCancelAll>, cancel_after_ts: u64) -> Result<()> {
+    let mut position_ext = ctx.accounts.position_extension.load_mut()?;
+    position_ext.cancel_all_after_ts = cancel_after_ts;
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::error::*;
+use crate::state::*;
+
+pub fn set_binary_outcome(ctx: Context<SetBinaryOutcome>, outcome: BinaryOutcome) -> Result<()> {
+    let mut market = ctx.accounts.market.load_mut()?;
+    require!(!market.is_settled(), OpenBookError::MarketIsSettled);
+    let expired = market.is_expired(Clock::get()?.unix_timestamp);
+
+    if outcome.is_resolved() {
+        require!(expired, OpenBookError::MarketHasNotExpired);
+        require!(
+            market.binary_outcome() == BinaryOutcome::Unresolved,
+            OpenBookError::NotBinaryOutcomeMarket
+        );
+
+        market.settlement_price_lots = match outcome {
+            BinaryOutcome::Yes => market.binary_outcome_price_lots()?,
+            _ => 0,
+        };
+        market.market_state = MarketState::CancelOnly.into();
+    } else {
+        // the mode can't change once trading stopped
+        require!(!expired, OpenBookError::MarketHasExpired);
+    }
+
+    market.binary_outcome = outcome.into();
+    Ok(())
+}
@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+
+pub fn set_consume_events_reward(
+    ctx: Context<SetConsumeEventsReward>,
+    reward_lamports: u64,
+) -> Result<()> {
+    let mut market = ctx.accounts.market.load_mut()?;
+    market.consume_events_reward = reward_lamports;
+    Ok(())
+}
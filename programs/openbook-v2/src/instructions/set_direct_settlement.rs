@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+
+pub fn set_direct_settlement(ctx: Context<SetDirectSettlement>, enabled: bool) -> Result<()> {
+    let mut account = ctx.accounts.open_orders_account.load_mut()?;
+    account.set_accepts_direct_settlement(enabled);
+    Ok(())
+}
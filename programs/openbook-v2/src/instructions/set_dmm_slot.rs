@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+
+pub fn set_dmm_slot(ctx: Context<SetDmmSlot>, priority: u8, min_uptime_bps: u16) -> Result<()> {
+    let mut dmm_registry = ctx.accounts.dmm_registry.load_mut()?;
+    dmm_registry.set(
+        ctx.accounts.open_orders_account.key(),
+        priority,
+        min_uptime_bps,
+    )?;
+    ctx.accounts.open_orders_account.load_mut()?.dmm_priority = priority;
+    Ok(())
+}
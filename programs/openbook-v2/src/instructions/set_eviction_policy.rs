@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::error::*;
+use crate::state::*;
+
+pub fn set_eviction_policy(
+    ctx: Context<SetEvictionPolicy>,
+    policy: EvictionPolicy,
+    band_bps: u16,
+) -> Result<()> {
+    require!(
+        policy != EvictionPolicy::EvictOldestOutOfBand || band_bps > 0,
+        OpenBookError::InvalidInputEvictionBand
+    );
+
+    let mut market = ctx.accounts.market.load_mut()?;
+    market.eviction_policy = policy.into();
+    market.eviction_band_bps = band_bps;
+    Ok(())
+}
@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::state::*;
+
+pub fn set_fee_rounding(
+    ctx: Context<SetFeeRounding>,
+    taker_fee_rounding: FeeRounding,
+) -> Result<()> {
+    let mut market = ctx.accounts.market.load_mut()?;
+    market.taker_fee_rounding = taker_fee_rounding.into();
+    Ok(())
+}
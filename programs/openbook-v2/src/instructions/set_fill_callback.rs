@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::error::*;
+
+pub fn set_fill_callback(ctx: Context<SetFillCallback>, program: Option<Pubkey>) -> Result<()> {
+    require!(
+        program != Some(crate::id()),
+        OpenBookError::InvalidFillCallbackProgram
+    );
+
+    ctx.accounts.fill_callback.program = program;
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::token_utils::system_program_transfer;
+
+pub fn set_heartbeat_interval(
+    ctx: Context<SetHeartbeatInterval>,
+    interval: u32,
+    bounty: u64,
+) -> Result<()> {
+    let extension_ai = ctx.accounts.position_extension.to_account_info();
+    let previous_bounty = {
+        let mut position_ext = ctx.accounts.position_extension.load_mut()?;
+        let previous_bounty = position_ext.heartbeat_bounty;
+
+        position_ext.heartbeat_interval = interval;
+        position_ext.heartbeat_bounty = bounty;
+        position_ext.cancel_all_after_ts = if interval > 0 {
+            Clock::get()?.unix_timestamp as u64 + u64::from(interval)
+        } else {
+            0
+        };
+
+        previous_bounty
+    };
+
+    if previous_bounty > 0 {
+        **extension_ai.try_borrow_mut_lamports()? -= previous_bounty;
+        **ctx.accounts.owner.try_borrow_mut_lamports()? += previous_bounty;
+    }
+    system_program_transfer(
+        bounty,
+        &ctx.accounts.system_program,
+        &ctx.accounts.signer,
+        &extension_ai,
+    )?;
+
+    Ok(())
+}
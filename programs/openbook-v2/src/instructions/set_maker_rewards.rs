@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+
+pub fn set_maker_rewards(
+    ctx: Context<SetMakerRewards>,
+    rewards_per_million_points: u64,
+) -> Result<()> {
+    let mut maker_rewards = ctx.accounts.maker_rewards.load_mut()?;
+    maker_rewards.rewards_per_million_points = rewards_per_million_points;
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::error::*;
+use crate::state::*;
+
+pub fn set_market_authority(
+    ctx: Context<SetMarketAuthority>,
+    role: MarketAdmin,
+    new_admin: Pubkey,
+) -> Result<()> {
+    let market = ctx.accounts.market.load()?;
+    require!(
+        market.admin(role) == ctx.accounts.admin.key(),
+        OpenBookError::InvalidMarketAdmin
+    );
+
+    let pending_market_admin = &mut ctx.accounts.pending_market_admin;
+    pending_market_admin.bump = ctx.bumps.pending_market_admin;
+    pending_market_admin.market = ctx.accounts.market.key();
+    pending_market_admin.role = role.into();
+    pending_market_admin.new_admin = new_admin;
+    pending_market_admin.payer = ctx.accounts.payer.key();
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::state::MarketMetadata;
+use crate::util::fill_from_str;
+use crate::MarketMetadataArgs;
+
+pub fn set_market_metadata(
+    ctx: Context<SetMarketMetadata>,
+    args: MarketMetadataArgs,
+) -> Result<()> {
+    let mut market_metadata = ctx.accounts.market_metadata.load_mut()?;
+    write_market_metadata(&mut market_metadata, &args)
+}
+
+pub(crate) fn write_market_metadata(
+    market_metadata: &mut MarketMetadata,
+    args: &MarketMetadataArgs,
+) -> Result<()> {
+    market_metadata.name = fill_from_str(&args.name)?;
+    market_metadata.base_symbol = fill_from_str(&args.base_symbol)?;
+    market_metadata.quote_symbol = fill_from_str(&args.quote_symbol)?;
+    market_metadata.uri = fill_from_str(&args.uri)?;
+    Ok(())
+}
@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::error::*;
+use crate::state::MarketState;
+
+pub fn set_market_state(ctx: Context<SetMarketState>, state: MarketState) -> Result<()> {
+    let mut market = ctx.accounts.market.load_mut()?;
+    require!(!market.is_settled(), OpenBookError::MarketIsSettled);
+    market.market_state = state.into();
+    Ok(())
+}
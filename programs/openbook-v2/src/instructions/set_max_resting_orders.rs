@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::error::*;
+use crate::state::MAX_OPEN_ORDERS;
+
+pub fn set_max_resting_orders(
+    ctx: Context<SetMaxRestingOrders>,
+    max_resting_orders: u8,
+) -> Result<()> {
+    require_gte!(
+        MAX_OPEN_ORDERS,
+        max_resting_orders as usize,
+        OpenBookError::InvalidInputMaxRestingOrders
+    );
+
+    let mut market = ctx.accounts.market.load_mut()?;
+    market.max_resting_orders = max_resting_orders;
+    Ok(())
+}
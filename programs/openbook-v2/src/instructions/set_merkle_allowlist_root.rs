@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+
+pub fn set_merkle_allowlist_root(
+    ctx: Context<SetMerkleAllowlistRoot>,
+    root: [u8; 32],
+) -> Result<()> {
+    let mut merkle_allowlist = ctx.accounts.merkle_allowlist.load_mut()?;
+    merkle_allowlist.root = root;
+    Ok(())
+}
@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+
+pub fn set_min_order_notional(
+    ctx: Context<SetMinOrderNotional>,
+    min_order_notional: u64,
+) -> Result<()> {
+    let mut market = ctx.accounts.market.load_mut()?;
+    market.min_order_notional = min_order_notional;
+    Ok(())
+}
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::error::*;
+
+pub fn set_order_rate_limit(
+    ctx: Context<SetOrderRateLimit>,
+    max_orders: u32,
+    window_seconds: u32,
+) -> Result<()> {
+    require!(
+        max_orders == 0 || window_seconds > 0,
+        OpenBookError::InvalidInputOrderRateLimit
+    );
+
+    let mut market = ctx.accounts.market.load_mut()?;
+    market.order_rate_limit = max_orders;
+    market.order_rate_limit_window = window_seconds;
+    Ok(())
+}
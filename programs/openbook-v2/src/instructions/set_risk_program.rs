@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::error::*;
+
+pub fn set_risk_program(ctx: Context<SetRiskProgram>, program: Option<Pubkey>) -> Result<()> {
+    require!(
+        program != Some(crate::id()),
+        OpenBookError::InvalidRiskProgram
+    );
+
+    let mut position_ext = ctx.accounts.position_extension.load_mut()?;
+    position_ext.risk_program = program.into();
+    Ok(())
+}
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::error::*;
+
+pub fn set_settle_receiver(ctx: Context<SetSettleReceiver>, program: Option<Pubkey>) -> Result<()> {
+    require!(
+        program != Some(crate::id()),
+        OpenBookError::InvalidSettleReceiverProgram
+    );
+
+    let mut position_ext = ctx.accounts.position_extension.load_mut()?;
+    position_ext.settle_receiver = program.into();
+    Ok(())
+}
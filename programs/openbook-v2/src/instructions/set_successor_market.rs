@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::error::*;
+
+pub fn set_successor_market(ctx: Context<SetSuccessorMarket>) -> Result<()> {
+    let market_pk = ctx.accounts.market.key();
+    let market = ctx.accounts.market.load()?;
+
+    let successor_market = match &ctx.accounts.successor_market {
+        Some(successor_market) => {
+            require_keys_neq!(
+                successor_market.key(),
+                market_pk,
+                OpenBookError::InvalidSuccessorMarket
+            );
+            let successor = successor_market.load()?;
+            require!(
+                successor.base_mint == market.base_mint
+                    && successor.quote_mint == market.quote_mint,
+                OpenBookError::InvalidSuccessorMarket
+            );
+            Some(successor_market.key())
+        }
+        None => None,
+    };
+
+    ctx.accounts.market_metadata.load_mut()?.successor_market = successor_market.into();
+    Ok(())
+}
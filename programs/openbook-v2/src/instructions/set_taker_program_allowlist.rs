@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+
+pub fn set_taker_program_allowlist(
+    ctx: Context<SetTakerProgramAllowlist>,
+    programs: Vec<Pubkey>,
+) -> Result<()> {
+    let mut taker_program_allowlist = ctx.accounts.taker_program_allowlist.load_mut()?;
+    taker_program_allowlist.set_programs(&programs)
+}
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::error::OpenBookError;
+
+pub fn set_taker_rebate_campaign(
+    ctx: Context<SetTakerRebateCampaign>,
+    start_timestamp: u64,
+    end_timestamp: u64,
+    rebate_bps: u16,
+) -> Result<()> {
+    require!(
+        start_timestamp < end_timestamp && rebate_bps <= 10_000,
+        OpenBookError::InvalidInputTakerRebateCampaign
+    );
+
+    let mut campaign = ctx.accounts.taker_rebate_campaign.load_mut()?;
+    campaign.start_timestamp = start_timestamp;
+    campaign.end_timestamp = end_timestamp;
+    campaign.rebate_bps = rebate_bps;
+    Ok(())
+}
@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::accounts_ix::*;
+use crate::accounts_zerocopy::AccountInfoRef;
+use crate::error::*;
+use crate::logs::{emit_stack, SettleExpiredMarketLog};
+use crate::state::*;
+
+pub fn settle_expired_market<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, SettleExpiredMarket<'info>>,
+    limit: u8,
+    settlement_price_lots: Option<i64>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let market_pk = ctx.accounts.market.key();
+    let mut market = ctx.accounts.market.load_mut()?;
+    require!(
+        market.is_expired(clock.unix_timestamp),
+        OpenBookError::MarketHasNotExpired
+    );
+
+    if !market.is_settled() {
+        require!(
+            market.binary_outcome() == BinaryOutcome::Disabled,
+            OpenBookError::BinaryOutcomeUnresolved
+        );
+        // markets with an oracle settle at its price, the others at the admin's
+        let settlement_price_lots = if market.oracle_a.is_some() {
+            require!(
+                settlement_price_lots.is_none(),
+                OpenBookError::InvalidInputSettlementPrice
+            );
+            market
+                .oracle_price_lots(
+                    AccountInfoRef::borrow_some(ctx.accounts.oracle_a.as_ref())?.as_ref(),
+                    AccountInfoRef::borrow_some(ctx.accounts.oracle_b.as_ref())?.as_ref(),
+                    clock.slot,
+                )?
+                .filter(|price_lots| *price_lots > 0)
+                .ok_or(OpenBookError::SettlementPriceUnavailable)?
+        } else {
+            settlement_price_lots
+                .filter(|price_lots| *price_lots > 0)
+                .ok_or(OpenBookError::InvalidInputSettlementPrice)?
+        };
+
+        market.settlement_price_lots = settlement_price_lots;
+        market.market_state = MarketState::CancelOnly.into();
+        emit_stack(SettleExpiredMarketLog {
+            market: market_pk,
+            settlement_price_lots,
+        });
+    }
+
+    let mut book = Orderbook {
+        bids: ctx.accounts.bids.load_mut()?,
+        asks: ctx.accounts.asks.load_mut()?,
+    };
+
+    // Book side pages may be passed along the open orders accounts
+    for ai in ctx.remaining_accounts.iter() {
+        if ai
+            .try_borrow_data()?
+            .starts_with(&BookSide::discriminator())
+        {
+            continue;
+        }
+        let loader: AccountLoader<OpenOrdersAccount> = AccountLoader::try_from(ai)?;
+        let mut open_orders_account = loader.load_mut()?;
+        require_keys_eq!(
+            open_orders_account.market,
+            market_pk,
+            OpenBookError::InvalidInputRemainingAccounts
+        );
+        book.cancel_all_orders(
+            &mut open_orders_account,
+            &mut market,
+            limit,
+            None,
+            None,
+            clock.unix_timestamp as u64,
+            ctx.remaining_accounts,
+        )?;
+    }
+    book.update_best_levels(clock.unix_timestamp as u64);
+
+    Ok(())
+}
@@ -3,6 +3,7 @@ use anchor_lang::prelude::*;
 use crate::accounts_ix::*;
 use crate::logs::emit_stack;
 use crate::logs::SettleFundsLog;
+use crate::settle_receiver::{invoke_settle_receiver, settle_receiver_accounts, OnSettleArgs};
 use crate::state::*;
 use crate::token_utils::*;
 
@@ -17,6 +18,20 @@ pub fn settle_funds<'info>(ctx: Context<'_, '_, '_, 'info, SettleFunds<'info>>)
         open_orders_account.position.locked_maker_fees = 0;
     }
 
+    let owner = open_orders_account.owner;
+    let settle_receiver_program = load_position_extension(
+        &open_orders_account,
+        &ctx.accounts.open_orders_account.key(),
+        ctx.remaining_accounts,
+    )?
+    .and_then(|position_ext| Option::<Pubkey>::from(position_ext.settle_receiver));
+    let settle_receiver = settle_receiver_program
+        .map(|program| {
+            settle_receiver_accounts(&ctx.accounts.market.key(), program, ctx.remaining_accounts)
+        })
+        .transpose()?;
+
+    let is_dmm = open_orders_account.is_dmm();
     let pa = &mut open_orders_account.position;
     let referrer_rebate = pa.referrer_rebates_available + roundoff_maker_fees;
 
@@ -29,6 +44,8 @@ pub fn settle_funds<'info>(ctx: Context<'_, '_, '_, 'info, SettleFunds<'info>>)
 
     market.base_deposit_total -= pa.base_free_native;
     market.quote_deposit_total -= pa.quote_free_native;
+    market.base_free_native_total -= pa.base_free_native as i64;
+    market.quote_free_native_total -= pa.quote_free_native as i64;
     market.referrer_rebates_accrued -= pa.referrer_rebates_available;
 
     let seeds = market_seeds!(market, ctx.accounts.market.key());
@@ -36,12 +53,15 @@ pub fn settle_funds<'info>(ctx: Context<'_, '_, '_, 'info, SettleFunds<'info>>)
     drop(market);
 
     if pa.penalty_heap_count > 0 {
-        system_program_transfer(
-            pa.penalty_heap_count * PENALTY_EVENT_HEAP,
-            &ctx.accounts.system_program,
-            &ctx.accounts.penalty_payer,
-            &ctx.accounts.market,
-        )?;
+        // designated market makers are exempt from the penalty
+        if !is_dmm {
+            system_program_transfer(
+                pa.penalty_heap_count * PENALTY_EVENT_HEAP,
+                &ctx.accounts.system_program,
+                &ctx.accounts.penalty_payer,
+                &ctx.accounts.market,
+            )?;
+        }
         pa.penalty_heap_count = 0;
     }
 
@@ -74,6 +94,28 @@ pub fn settle_funds<'info>(ctx: Context<'_, '_, '_, 'info, SettleFunds<'info>>)
         seeds,
     )?;
 
+    if let Some(receiver) = settle_receiver {
+        if pa.base_free_native > 0 || pa.quote_free_native > 0 {
+            invoke_settle_receiver(
+                &receiver,
+                &ctx.accounts.user_base_account.to_account_info(),
+                &ctx.accounts.user_quote_account.to_account_info(),
+                &[
+                    ctx.accounts.market_authority.key(),
+                    ctx.accounts.market_base_vault.key(),
+                    ctx.accounts.market_quote_vault.key(),
+                ],
+                OnSettleArgs {
+                    market: ctx.accounts.market.key(),
+                    open_orders_account: ctx.accounts.open_orders_account.key(),
+                    owner,
+                    base_native: pa.base_free_native,
+                    quote_native: pa.quote_free_native,
+                },
+            )?;
+        }
+    }
+
     emit_stack(SettleFundsLog {
         open_orders_account: ctx.accounts.open_orders_account.key(),
         base_native: pa.base_free_native,
@@ -85,6 +127,7 @@ pub fn settle_funds<'info>(ctx: Context<'_, '_, '_, 'info, SettleFunds<'info>>)
     pa.base_free_native = 0;
     pa.quote_free_native = 0;
     pa.referrer_rebates_available = 0;
+    pa.maker_rebates_available = 0;
 
     Ok(())
 }
@@ -0,0 +1,25 @@
+use crate::accounts_ix::*;
+use crate::error::OpenBookError;
+use anchor_lang::prelude::*;
+
+pub fn settle_funds_multiple<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, SettleFundsMultiple<'info>>,
+) -> Result<()> {
+    let remaining_accs = ctx.remaining_accounts;
+    require!(
+        !remaining_accs.is_empty() && remaining_accs.len() % SETTLE_FUNDS_MULTIPLE_ACCOUNTS == 0,
+        OpenBookError::InvalidInputRemainingAccounts
+    );
+
+    for ais in remaining_accs.chunks_exact(SETTLE_FUNDS_MULTIPLE_ACCOUNTS) {
+        let mut accounts = ctx.accounts.to_settle_funds(ais)?;
+        crate::instructions::settle_funds(Context::new(
+            ctx.program_id,
+            &mut accounts,
+            &[],
+            SettleFundsBumps {},
+        ))?;
+    }
+
+    Ok(())
+}
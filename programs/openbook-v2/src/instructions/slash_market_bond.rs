@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+
+pub fn slash_market_bond(ctx: Context<SlashMarketBond>) -> Result<()> {
+    let bond = {
+        let mut market = ctx.accounts.market.load_mut()?;
+        std::mem::take(&mut market.creation_bond)
+    };
+
+    let market_ai = ctx.accounts.market.to_account_info();
+    let destination_ai = ctx.accounts.destination.to_account_info();
+    **market_ai.try_borrow_mut_lamports()? -= bond;
+    **destination_ai.try_borrow_mut_lamports()? += bond;
+
+    msg!("slashed {} lamports", bond);
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::accounts_zerocopy::AccountInfoRef;
+use crate::error::OpenBookError;
+use crate::state::{Side, DEPTH_SNAPSHOT_LEVELS};
+
+pub fn snapshot_depth(ctx: Context<SnapshotDepth>, levels: u8) -> Result<()> {
+    require_gte!(
+        DEPTH_SNAPSHOT_LEVELS,
+        levels as usize,
+        OpenBookError::InvalidInputDepthLevels
+    );
+
+    let clock = Clock::get()?;
+    let now_ts: u64 = clock.unix_timestamp.try_into().unwrap();
+    let market = ctx.accounts.market.load()?;
+
+    let oracle_price = market.oracle_price(
+        AccountInfoRef::borrow_some(ctx.accounts.oracle_a.as_ref())?.as_ref(),
+        AccountInfoRef::borrow_some(ctx.accounts.oracle_b.as_ref())?.as_ref(),
+        clock.slot,
+    )?;
+    let oracle_price_lots = match oracle_price {
+        Some(oracle_price) => Some(market.native_price_to_lot(oracle_price.price)?),
+        None => None,
+    };
+
+    let bids = ctx.accounts.bids.load()?;
+    let asks = ctx.accounts.asks.load()?;
+    let mut depth_snapshot = ctx.accounts.depth_snapshot.load_mut()?;
+    depth_snapshot.set_levels(
+        Side::Bid,
+        &bids.levels(levels.into(), now_ts, oracle_price_lots),
+    );
+    depth_snapshot.set_levels(
+        Side::Ask,
+        &asks.levels(levels.into(), now_ts, oracle_price_lots),
+    );
+    depth_snapshot.slot = clock.slot;
+    depth_snapshot.timestamp = now_ts;
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use crate::accounts_ix::*;
+use crate::error::*;
+use anchor_lang::prelude::*;
+
+pub fn stub_oracle_set_test(
+    ctx: Context<StubOracleSet>,
+    price: f64,
+    exponent: i8,
+    deviation: f64,
+    last_update_ts: i64,
+    last_update_slot: u64,
+) -> Result<()> {
+    require!(
+        (-12..=12).contains(&exponent),
+        OpenBookError::InvalidInputOracleExponent
+    );
+
+    let mut oracle = ctx.accounts.oracle.load_mut()?;
+
+    oracle.price = price;
+    oracle.exponent = exponent;
+    oracle.deviation = deviation;
+    oracle.last_update_ts = last_update_ts;
+    oracle.last_update_slot = last_update_slot;
+
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::logs::{emit_stack, SweepQuoteDustLog};
+
+pub fn sweep_quote_dust(ctx: Context<SweepQuoteDust>) -> Result<()> {
+    let mut market = ctx.accounts.market.load_mut()?;
+
+    // Everything owed to the open orders accounts, referrers and the fee
+    // destination is part of the deposit total, the rest of the vault has no owner
+    let amount = ctx
+        .accounts
+        .market_quote_vault
+        .amount
+        .saturating_sub(market.quote_deposit_total);
+    market.quote_deposit_total += amount;
+    market.fees_available += amount;
+
+    emit_stack(SweepQuoteDustLog {
+        market: ctx.accounts.market.key(),
+        amount,
+    });
+
+    Ok(())
+}
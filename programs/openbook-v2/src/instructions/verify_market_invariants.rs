@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use bytemuck::cast_ref;
+
+use crate::accounts_ix::*;
+use crate::accounts_zerocopy::LoadZeroCopyRef;
+use crate::error::*;
+use crate::logs::{emit_stack, MarketInvariantsLog};
+use crate::math;
+use crate::state::*;
+
+pub fn verify_market_invariants<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, VerifyMarketInvariants<'info>>,
+) -> Result<()> {
+    let market_pk = ctx.accounts.market.key();
+    let market = ctx.accounts.market.load()?;
+    let event_heap = EventHeapRefMut::load_mut(&ctx.accounts.event_heap)?;
+
+    // What the passed accounts can settle or get back by cancelling their orders
+    let mut keys = vec![];
+    let mut base_owed: i128 = 0;
+    let mut quote_owed: i128 = 0;
+    for ai in ctx.remaining_accounts.iter() {
+        require!(
+            !keys.contains(ai.key),
+            OpenBookError::InvalidInputRemainingAccounts
+        );
+        keys.push(*ai.key);
+
+        let open_orders_account = ai.load::<OpenOrdersAccount>()?;
+        require_keys_eq!(
+            open_orders_account.market,
+            market_pk,
+            OpenBookError::InvalidInputRemainingAccounts
+        );
+        let pa = &open_orders_account.position;
+        base_owed += pa.base_free_native as i128
+            + math::lots_to_native(pa.asks_base_lots, market.base_lot_size)? as i128;
+        quote_owed += pa.quote_free_native as i128
+            + math::lots_to_native(pa.bids_quote_lots, market.quote_lot_size)? as i128
+            + pa.locked_maker_fees as i128
+            + pa.referrer_rebates_available as i128;
+    }
+
+    // The taker of a fill got its side of the trade when matching, the maker only
+    // gets its side, along with the rebate, once the event is consumed. Until then
+    // the maker's order still locks what the taker got.
+    let mut pending_fills = 0;
+    for (event, _slot) in event_heap.iter() {
+        if event.event_type != EventType::Fill as u8 {
+            continue;
+        }
+        let fill: &FillEvent = cast_ref(event);
+        let base_native = math::lots_to_native(fill.quantity, market.base_lot_size)? as i128;
+        let quote_native = math::quote_native(fill.quantity, fill.price, market.quote_lot_size)?;
+        let maker_rebate = if fill.maker == fill.taker {
+            0
+        } else {
+            market.maker_rebate_floor(quote_native) as i128
+        };
+        match fill.taker_side() {
+            Side::Ask => {
+                base_owed += base_native;
+                quote_owed += maker_rebate - quote_native as i128;
+            }
+            Side::Bid => {
+                base_owed -= base_native;
+                quote_owed += quote_native as i128 + maker_rebate;
+            }
+        }
+        pending_fills += 1;
+    }
+
+    let base_vault = ctx.accounts.market_base_vault.amount;
+    let quote_vault = ctx.accounts.market_quote_vault.amount;
+    // Zero once all the open orders accounts of the market are passed
+    let base_unaccounted = market.base_deposit_total as i128 - base_owed;
+    let quote_unaccounted =
+        market.quote_deposit_total as i128 - market.fees_available as i128 - quote_owed;
+
+    if base_vault < market.base_deposit_total {
+        msg!(
+            "base vault holds {}, less than the {} deposited",
+            base_vault,
+            market.base_deposit_total
+        );
+    }
+    if quote_vault < market.quote_deposit_total {
+        msg!(
+            "quote vault holds {}, less than the {} deposited",
+            quote_vault,
+            market.quote_deposit_total
+        );
+    }
+    if base_unaccounted < 0 || quote_unaccounted < 0 {
+        msg!(
+            "accounts are owed more than deposited: {} base, {} quote",
+            -base_unaccounted.min(0),
+            -quote_unaccounted.min(0)
+        );
+    }
+
+    emit_stack(MarketInvariantsLog {
+        market: market_pk,
+        open_orders_accounts: keys.len() as u32,
+        pending_fills,
+        base_vault,
+        base_deposit_total: market.base_deposit_total,
+        base_unaccounted,
+        quote_vault,
+        quote_deposit_total: market.quote_deposit_total,
+        fees_available: market.fees_available,
+        quote_unaccounted,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::error::OpenBookError;
+use crate::token_utils::*;
+
+pub fn withdraw_taker_rebate_budget(ctx: Context<WithdrawTakerRebateBudget>) -> Result<()> {
+    let mut campaign = ctx.accounts.taker_rebate_campaign.load_mut()?;
+    let now_ts: u64 = Clock::get()?.unix_timestamp.try_into().unwrap();
+    require!(
+        !campaign.is_active(now_ts),
+        OpenBookError::TakerRebateCampaignActive
+    );
+
+    let amount = campaign.budget_remaining;
+    campaign.budget_remaining = 0;
+
+    let market_key = ctx.accounts.market.key();
+    let seeds: &[&[u8]] = &[
+        b"TakerRebateCampaign".as_ref(),
+        market_key.as_ref(),
+        &[campaign.bump],
+    ];
+    drop(campaign);
+
+    token_transfer_signed(
+        amount,
+        &ctx.accounts.token_program,
+        &ctx.accounts.rebate_vault,
+        &ctx.accounts.token_receiver_account,
+        &ctx.accounts.taker_rebate_campaign,
+        seeds,
+    )
+}
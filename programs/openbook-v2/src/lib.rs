@@ -12,9 +12,18 @@ pub mod util;
 
 pub mod accounts_ix;
 pub mod accounts_zerocopy;
+#[cfg(feature = "cpi")]
+pub mod cpi_builders;
 pub mod error;
+pub mod fill_callback;
+pub mod health_check;
 pub mod logs;
+pub mod maker_settlement;
+pub mod math;
+pub mod pricemath;
 pub mod pubkey_option;
+pub mod settle_receiver;
+pub mod signed_order;
 pub mod state;
 pub mod token_utils;
 pub mod types;
@@ -27,17 +36,30 @@ pub mod instructions;
 use accounts_ix::*;
 use accounts_ix::{StubOracleCreate, StubOracleSet};
 use error::*;
-use state::{OracleConfigParams, Order, OrderParams, PlaceOrderType, SelfTradeBehavior, Side};
+use fixed::types::I80F48;
+use signed_order::SignedOrder;
+use state::{
+    BinaryOutcome, EvictionPolicy, FeeRounding, MarketAdmin, MarketState, OracleConfigParams,
+    Order, OrderParams, PlaceOrderType, SelfTradeBehavior, Side, TriggerCondition,
+};
 use std::cmp;
 
 #[cfg(all(not(feature = "no-entrypoint"), not(feature = "enable-gpl")))]
-compile_error!("compiling the program entrypoint without 'enable-gpl' makes no sense, enable it or use the 'cpi' or 'client' features");
+compile_error!("compiling the program entrypoint without 'enable-gpl' makes no sense, enable it or use the 'cpi', 'client' or 'wasm' features");
 
 #[program]
 pub mod openbook_v2 {
     use super::*;
 
     /// Create a [`Market`](crate::state::Market) for a given token pair.
+    ///
+    /// The payer escrows a refundable [`MARKET_CREATION_BOND`](crate::state::MARKET_CREATION_BOND)
+    /// in the market account.
+    ///
+    /// Orders that would rest on the book with less than `min_order_notional` in quote
+    /// native units are rejected, see `set_min_order_notional`, and open orders accounts
+    /// can't have more than `max_resting_orders` orders on the book, see
+    /// `set_max_resting_orders`.
     #[allow(clippy::too_many_arguments)]
     pub fn create_market(
         ctx: Context<CreateMarket>,
@@ -48,6 +70,8 @@ pub mod openbook_v2 {
         maker_fee: i64,
         taker_fee: i64,
         time_expiry: i64,
+        min_order_notional: u64,
+        max_resting_orders: u8,
     ) -> Result<()> {
         #[cfg(feature = "enable-gpl")]
         instructions::create_market(
@@ -59,12 +83,171 @@ pub mod openbook_v2 {
             maker_fee,
             taker_fee,
             time_expiry,
+            min_order_notional,
+            max_resting_orders,
+        )?;
+        Ok(())
+    }
+
+    /// Create the canonical [`Market`](crate::state::Market) of a token pair and
+    /// lot sizes.
+    ///
+    /// The market address is a PDA of `["CanonicalMarket", base_mint, quote_mint,
+    /// base_lot_size, quote_lot_size]` (lot sizes as little endian bytes), so there
+    /// is at most one per configuration and clients can derive it. Canonical
+    /// markets can't have an open orders admin.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_canonical_market<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateCanonicalMarket<'info>>,
+        name: String,
+        oracle_config: OracleConfigParams,
+        quote_lot_size: i64,
+        base_lot_size: i64,
+        maker_fee: i64,
+        taker_fee: i64,
+        time_expiry: i64,
+        min_order_notional: u64,
+        max_resting_orders: u8,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::create_canonical_market(
+            ctx,
+            name,
+            oracle_config,
+            quote_lot_size,
+            base_lot_size,
+            maker_fee,
+            taker_fee,
+            time_expiry,
+            min_order_notional,
+            max_resting_orders,
         )?;
         Ok(())
     }
 
+    /// Move a [`Market`](crate::state::Market)'s creation bond to `destination`,
+    /// as the program's upgrade authority.
+    ///
+    /// Every market escrows [`MARKET_CREATION_BOND`](crate::state::MARKET_CREATION_BOND)
+    /// lamports when created, returned by `close_market` unless slashed.
+    pub fn slash_market_bond(ctx: Context<SlashMarketBond>) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::slash_market_bond(ctx)?;
+        Ok(())
+    }
+
+    /// Create the [`MerkleAllowlist`](crate::state::MerkleAllowlist) of a
+    /// permissioned market, as its open orders admin.
+    pub fn create_merkle_allowlist(
+        ctx: Context<CreateMerkleAllowlist>,
+        root: [u8; 32],
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::create_merkle_allowlist(ctx, root)?;
+        Ok(())
+    }
+
+    /// Replace the root of a market's [`MerkleAllowlist`](crate::state::MerkleAllowlist),
+    /// as its open orders admin.
+    ///
+    /// Open orders accounts that already joined stay allowed until revoked.
+    pub fn set_merkle_allowlist_root(
+        ctx: Context<SetMerkleAllowlistRoot>,
+        root: [u8; 32],
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::set_merkle_allowlist_root(ctx, root)?;
+        Ok(())
+    }
+
+    /// Prove the owner of an [`OpenOrdersAccount`](crate::state::OpenOrdersAccount)
+    /// is in the market's [`MerkleAllowlist`](crate::state::MerkleAllowlist).
+    ///
+    /// The account can then place orders without the open orders admin signature.
+    pub fn join_merkle_allowlist(
+        ctx: Context<JoinMerkleAllowlist>,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::join_merkle_allowlist(ctx, proof)?;
+        Ok(())
+    }
+
+    /// Require the open orders admin signature again for an
+    /// [`OpenOrdersAccount`](crate::state::OpenOrdersAccount) that joined the
+    /// market's allowlist, as its open orders admin.
+    pub fn revoke_merkle_allowlist_approval(
+        ctx: Context<RevokeMerkleAllowlistApproval>,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::revoke_merkle_allowlist_approval(ctx)?;
+        Ok(())
+    }
+
+    /// Create the [`TokenGate`](crate::state::TokenGate) of a permissioned market,
+    /// as its open orders admin.
+    ///
+    /// Orders can then be placed without the open orders admin signature by
+    /// passing the gate and a token account of the owner holding at least
+    /// `min_amount` native units of `mint`. `min_amount` is at least 1.
+    pub fn create_token_gate(ctx: Context<CreateTokenGate>, min_amount: u64) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::create_token_gate(ctx, min_amount)?;
+        Ok(())
+    }
+
+    /// Create the [`TakerProgramAllowlist`](crate::state::TakerProgramAllowlist)
+    /// of a permissioned market, as its open orders admin.
+    pub fn create_taker_program_allowlist(
+        ctx: Context<CreateTakerProgramAllowlist>,
+        programs: Vec<Pubkey>,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::create_taker_program_allowlist(ctx, programs)?;
+        Ok(())
+    }
+
+    /// Replace the programs of a market's
+    /// [`TakerProgramAllowlist`](crate::state::TakerProgramAllowlist), as its open
+    /// orders admin.
+    pub fn set_taker_program_allowlist(
+        ctx: Context<SetTakerProgramAllowlist>,
+        programs: Vec<Pubkey>,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::set_taker_program_allowlist(ctx, programs)?;
+        Ok(())
+    }
+
+    /// Create a page of the market registry, see
+    /// [`MarketRegistryPage`](crate::state::MarketRegistryPage).
+    ///
+    /// Anyone can pay for it. Every page but the first one requires the previous
+    /// page to be full.
+    pub fn create_market_registry_page(
+        ctx: Context<CreateMarketRegistryPage>,
+        page_index: u32,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::create_market_registry_page(ctx, page_index)?;
+        Ok(())
+    }
+
+    /// Add a [`Market`](crate::state::Market) to the market registry.
+    ///
+    /// Permissionless, each market can only be registered once. Clients usually
+    /// do it in the same transaction as `create_market`.
+    pub fn register_market(ctx: Context<RegisterMarket>) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::register_market(ctx)?;
+        Ok(())
+    }
+
     /// Close a [`Market`](crate::state::Market) (only
     /// [`close_market_admin`](crate::state::Market::close_market_admin)).
+    ///
+    /// Sends the dust left in the vaults to the collect fee admin, and the rent
+    /// of the market, its book, event heap and vaults to `sol_destination`.
     pub fn close_market(ctx: Context<CloseMarket>) -> Result<()> {
         #[cfg(feature = "enable-gpl")]
         instructions::close_market(ctx)?;
@@ -102,6 +285,29 @@ pub mod openbook_v2 {
         Ok(())
     }
 
+    /// Create the [`PositionExtension`](crate::state::PositionExtension) of an
+    /// [`OpenOrdersAccount`](crate::state::OpenOrdersAccount), tracking its net
+    /// position, cost basis and realized PnL from then on.
+    ///
+    /// The extension has to be passed among the remaining accounts of every order
+    /// the account places afterwards and of every instruction executing its fills,
+    /// like its open orders account: orders fail with `MissingPositionExtension` and
+    /// the fills of makers stay on the event heap without it.
+    pub fn create_position_extension(ctx: Context<CreatePositionExtension>) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::create_position_extension(ctx)?;
+        Ok(())
+    }
+
+    /// Close the [`PositionExtension`](crate::state::PositionExtension) of an
+    /// [`OpenOrdersAccount`](crate::state::OpenOrdersAccount). Required before
+    /// closing the account itself.
+    pub fn close_position_extension(ctx: Context<ClosePositionExtension>) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::close_position_extension(ctx)?;
+        Ok(())
+    }
+
     /// Place an order.
     ///
     /// Different types of orders have different effects on the order book,
@@ -118,6 +324,16 @@ pub mod openbook_v2 {
     /// `limit` determines the maximum number of orders from the book to fill,
     /// and can be used to limit CU spent. When the limit is reached, processing
     /// stops and the instruction succeeds.
+    ///
+    /// Makers whose open orders account is among the remaining accounts get their
+    /// fills applied right away. If they enabled `set_direct_settlement` and a token
+    /// account of theirs for the token the taker pays with is passed too, their
+    /// proceeds are transferred to it directly, see
+    /// [`maker_settlement`](crate::maker_settlement).
+    ///
+    /// The signer, the owner or a delegate such as a session key, pays for
+    /// nothing and isn't writable, so a relayer can pay the transaction fee, see
+    /// `place_order_relayed`.
     pub fn place_order<'c: 'info, 'info>(
         ctx: Context<'_, '_, 'c, 'info, PlaceOrder<'info>>,
         args: PlaceOrderArgs,
@@ -159,6 +375,54 @@ pub mod openbook_v2 {
         Ok(None)
     }
 
+    /// Place an order like `place_order` in a transaction whose fee is paid by
+    /// `relayer`, who gets `relayer_fee` native tokens of the signer's token
+    /// account in exchange.
+    ///
+    /// The signer has to be the owner or delegate of the open orders account, the
+    /// relayer signing doesn't give it any say over the account.
+    pub fn place_order_relayed<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, PlaceOrderRelayed<'info>>,
+        args: PlaceOrderArgs,
+        relayer_fee: u64,
+    ) -> Result<Option<u128>> {
+        require_gte!(args.price_lots, 1, OpenBookError::InvalidInputPriceLots);
+
+        let time_in_force = match Order::tif_from_expiry(args.expiry_timestamp) {
+            Some(t) => t,
+            None => {
+                msg!("Order is already expired");
+                return Ok(None);
+            }
+        };
+        let order = Order {
+            side: args.side,
+            max_base_lots: args.max_base_lots,
+            max_quote_lots_including_fees: args.max_quote_lots_including_fees,
+            client_order_id: args.client_order_id,
+            time_in_force,
+            self_trade_behavior: args.self_trade_behavior,
+            params: match args.order_type {
+                PlaceOrderType::Market => OrderParams::Market,
+                PlaceOrderType::ImmediateOrCancel => OrderParams::ImmediateOrCancel {
+                    price_lots: args.price_lots,
+                },
+                PlaceOrderType::FillOrKill => OrderParams::FillOrKill {
+                    price_lots: args.price_lots,
+                },
+                _ => OrderParams::Fixed {
+                    price_lots: args.price_lots,
+                    order_type: args.order_type.to_post_order_type()?,
+                },
+            },
+        };
+        #[cfg(feature = "enable-gpl")]
+        return instructions::place_order_relayed(ctx, order, args.limit, relayer_fee);
+
+        #[cfg(not(feature = "enable-gpl"))]
+        Ok(None)
+    }
+
     /// Edit an order.
     pub fn edit_order<'c: 'info, 'info>(
         ctx: Context<'_, '_, 'c, 'info, PlaceOrder<'info>>,
@@ -411,6 +675,13 @@ pub mod openbook_v2 {
     /// add a new order off the book.
     ///
     /// This type of order allows for instant token settlement for the taker.
+    ///
+    /// On permissioned markets, programs in the market's
+    /// [`TakerProgramAllowlist`](crate::state::TakerProgramAllowlist) can call it
+    /// through CPI without the open orders admin signature.
+    ///
+    /// Makers can be settled directly through the remaining accounts as with
+    /// `place_order`.
     pub fn place_take_order<'c: 'info, 'info>(
         ctx: Context<'_, '_, 'c, 'info, PlaceTakeOrder<'info>>,
         args: PlaceTakeOrderArgs,
@@ -421,7 +692,7 @@ pub mod openbook_v2 {
             side: args.side,
             max_base_lots: args.max_base_lots,
             max_quote_lots_including_fees: args.max_quote_lots_including_fees,
-            client_order_id: 0,
+            client_order_id: args.client_order_id,
             time_in_force: 0,
             self_trade_behavior: SelfTradeBehavior::default(),
             params: match args.order_type {
@@ -441,6 +712,140 @@ pub mod openbook_v2 {
         Ok(())
     }
 
+    /// Place an order of an open orders account that only takes liquidity, and
+    /// transfer what it receives straight to the user's token accounts.
+    ///
+    /// Like with `place_take_order` the taker doesn't wait for `consume_events` nor
+    /// calls `settle_funds`, the event heap only keeps the makers' side of the fills.
+    /// Unlike it, the fills count towards the account's volume and referrer rebates,
+    /// and `self_trade_behavior` applies. Other free funds of the account aren't
+    /// touched.
+    ///
+    /// Only `Market`, `ImmediateOrCancel` and `FillOrKill` orders are accepted,
+    /// `expiry_timestamp` is ignored.
+    pub fn place_order_and_settle<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, PlaceOrderAndSettle<'info>>,
+        args: PlaceOrderArgs,
+    ) -> Result<()> {
+        require_gte!(args.price_lots, 1, OpenBookError::InvalidInputPriceLots);
+
+        let order = Order {
+            side: args.side,
+            max_base_lots: args.max_base_lots,
+            max_quote_lots_including_fees: args.max_quote_lots_including_fees,
+            client_order_id: args.client_order_id,
+            time_in_force: 0,
+            self_trade_behavior: args.self_trade_behavior,
+            params: match args.order_type {
+                PlaceOrderType::Market => OrderParams::Market,
+                PlaceOrderType::ImmediateOrCancel => OrderParams::ImmediateOrCancel {
+                    price_lots: args.price_lots,
+                },
+                PlaceOrderType::FillOrKill => OrderParams::FillOrKill {
+                    price_lots: args.price_lots,
+                },
+                _ => return Err(OpenBookError::InvalidInputOrderType.into()),
+            },
+        };
+
+        #[cfg(feature = "enable-gpl")]
+        instructions::place_order_and_settle(ctx, order, args.limit)?;
+        Ok(())
+    }
+
+    /// Create a [`TriggerOrder`](crate::state::TriggerOrder), placing `order` for an
+    /// open orders account once `condition` is met for `trigger_price_lots`.
+    ///
+    /// `trigger_id` is chosen by the caller and has to be unique per open orders
+    /// account. The order is only funded from the account's free funds when it's
+    /// executed, and `expiry_timestamp` also ends the trigger.
+    pub fn create_trigger_order(
+        ctx: Context<CreateTriggerOrder>,
+        trigger_id: u64,
+        condition: TriggerCondition,
+        trigger_price_lots: i64,
+        order: PlaceOrderArgs,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::create_trigger_order(ctx, trigger_id, condition, trigger_price_lots, order)?;
+        Ok(())
+    }
+
+    /// Close a trigger order before it's executed, as the owner or delegate of its
+    /// open orders account.
+    pub fn cancel_trigger_order(ctx: Context<CancelTriggerOrder>) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::cancel_trigger_order(ctx)?;
+        Ok(())
+    }
+
+    /// Place the order of a trigger order whose condition is met, closing it.
+    ///
+    /// Anyone can call this and gets the trigger order's rent. Fails if the
+    /// condition isn't met or the free funds of the open orders account don't cover
+    /// the order. Returns the id of the order left on the book, if any. An expired
+    /// trigger order is closed without placing anything.
+    pub fn execute_trigger<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ExecuteTrigger<'info>>,
+    ) -> Result<Option<u128>> {
+        #[cfg(feature = "enable-gpl")]
+        return instructions::execute_trigger(ctx);
+
+        #[cfg(not(feature = "enable-gpl"))]
+        Ok(None)
+    }
+
+    /// Place an order signed off-chain by the owner or delegate of the open orders
+    /// account, on their behalf.
+    ///
+    /// The ed25519 signature of `signed_order` has to be verified by the
+    /// instruction right before this one, see
+    /// [`signed_order`](crate::signed_order). Anyone can submit it, each nonce only
+    /// once, kept in the account's position extension which has to be among the
+    /// remaining accounts. Like trigger orders the order is funded from the free
+    /// funds of the open orders account.
+    pub fn place_signed_order<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, PlaceSignedOrder<'info>>,
+        signed_order: SignedOrder,
+    ) -> Result<Option<u128>> {
+        let args = signed_order.order;
+        require_gte!(args.price_lots, 1, OpenBookError::InvalidInputPriceLots);
+
+        let time_in_force = match Order::tif_from_expiry(args.expiry_timestamp) {
+            Some(t) => t,
+            None => {
+                msg!("Order is already expired");
+                return Ok(None);
+            }
+        };
+        let order = Order {
+            side: args.side,
+            max_base_lots: args.max_base_lots,
+            max_quote_lots_including_fees: args.max_quote_lots_including_fees,
+            client_order_id: args.client_order_id,
+            time_in_force,
+            self_trade_behavior: args.self_trade_behavior,
+            params: match args.order_type {
+                PlaceOrderType::Market => OrderParams::Market,
+                PlaceOrderType::ImmediateOrCancel => OrderParams::ImmediateOrCancel {
+                    price_lots: args.price_lots,
+                },
+                PlaceOrderType::FillOrKill => OrderParams::FillOrKill {
+                    price_lots: args.price_lots,
+                },
+                _ => OrderParams::Fixed {
+                    price_lots: args.price_lots,
+                    order_type: args.order_type.to_post_order_type()?,
+                },
+            },
+        };
+        #[cfg(feature = "enable-gpl")]
+        return instructions::place_signed_order(ctx, signed_order, order);
+
+        #[cfg(not(feature = "enable-gpl"))]
+        Ok(None)
+    }
+
     /// Process up to `limit` [events](crate::state::AnyEvent).
     ///
     /// When a user places a 'take' order, they do not know beforehand which
@@ -463,7 +868,16 @@ pub mod openbook_v2 {
     /// An `OutEvent` is emitted when a limit order needs to be removed from
     /// the book during a `place_order` invocation, and it is handled by
     /// crediting whatever the maker would have sold (quote token in a bid,
-    /// base token in an ask) back to the maker.
+    /// base token in an ask) back to the maker. Its `reason` tells whether the
+    /// order expired or was booted to make room for a better one.
+    ///
+    /// Fill events are processed before out events, so the fills queued behind the
+    /// out events of a mass cancel get processed first.
+    ///
+    /// The open orders accounts of the events go in the remaining accounts, in
+    /// any order. Events whose account is missing stay on the heap, don't count
+    /// towards `limit` and are reported in a
+    /// [`ConsumeEventsSkippedLog`](crate::logs::ConsumeEventsSkippedLog).
     pub fn consume_events<'c: 'info, 'info>(
         ctx: Context<'_, '_, 'c, 'info, ConsumeEvents>,
         limit: usize,
@@ -478,59 +892,248 @@ pub mod openbook_v2 {
         ctx: Context<'_, '_, 'c, 'info, ConsumeEvents>,
         slots: Vec<usize>,
     ) -> Result<()> {
-        require!(
-            slots
-                .iter()
-                .all(|slot| *slot < crate::state::MAX_NUM_EVENTS as usize),
-            OpenBookError::InvalidInputHeapSlots
-        );
         #[cfg(feature = "enable-gpl")]
         instructions::consume_events(ctx, slots.len(), Some(slots))?;
         Ok(())
     }
 
-    /// Cancel an order by its `order_id`.
+    /// Process up to `limit` [events](crate::state::AnyEvent) involving the
+    /// [`OpenOrdersAccount`](crate::state::OpenOrdersAccount)s passed as remaining
+    /// accounts, leaving all other events on the heap.
     ///
-    /// Note that this doesn't emit an [`OutEvent`](crate::state::OutEvent) because a
-    /// maker knows that they will be passing in their own [`OpenOrdersAccount`](crate::state::OpenOrdersAccount).
-    pub fn cancel_order(ctx: Context<CancelOrder>, order_id: u128) -> Result<()> {
+    /// Lets a market maker crank their own fills without waiting for, or paying
+    /// for, everybody else's events.
+    pub fn consume_events_for_accounts<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ConsumeEvents>,
+        limit: usize,
+    ) -> Result<()> {
         #[cfg(feature = "enable-gpl")]
-        instructions::cancel_order(ctx, order_id)?;
+        instructions::consume_events_for_accounts(ctx, limit)?;
         Ok(())
     }
 
-    /// Cancel an order by its `client_order_id`.
+    /// Same as `consume_events`, but for several markets at once.
     ///
-    /// Note that this doesn't emit an [`OutEvent`](crate::state::OutEvent) because a
-    /// maker knows that they will be passing in their own [`OpenOrdersAccount`](crate::state::OpenOrdersAccount).
-    pub fn cancel_order_by_client_order_id(
-        ctx: Context<CancelOrder>,
-        client_order_id: u64,
-    ) -> Result<i64> {
+    /// Every market is described by its `market` and `event_heap` remaining
+    /// accounts, followed by the accounts `consume_events` would take as
+    /// remaining accounts: the groups' lengths and limits are in `groups`.
+    /// Markets with a `consume_events_admin` need it as signer.
+    pub fn consume_events_multiple<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ConsumeEventsMultiple<'info>>,
+        groups: Vec<ConsumeEventsGroup>,
+    ) -> Result<()> {
         #[cfg(feature = "enable-gpl")]
-        return instructions::cancel_order_by_client_order_id(ctx, client_order_id);
-
-        #[cfg(not(feature = "enable-gpl"))]
-        Ok(0)
+        instructions::consume_events_multiple(ctx, groups)?;
+        Ok(())
     }
 
-    /// Cancel up to `limit` orders, optionally filtering by side
-    pub fn cancel_all_orders(
-        ctx: Context<CancelOrder>,
-        side_option: Option<Side>,
-        limit: u8,
+    /// Same as `consume_events`, but pays the signing `cranker` the market's
+    /// [`consume_events_reward`](crate::state::Market::consume_events_reward)
+    /// for every processed event.
+    ///
+    /// Rewards are paid in lamports out of the event heap penalties the market
+    /// has collected, and are capped by what is available.
+    pub fn consume_events_with_reward<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ConsumeEventsWithReward<'info>>,
+        limit: usize,
     ) -> Result<()> {
         #[cfg(feature = "enable-gpl")]
-        instructions::cancel_all_orders(ctx, side_option, limit)?;
+        instructions::consume_events_with_reward(ctx, limit)?;
         Ok(())
     }
 
-    /// Deposit a certain amount of `base` and `quote` lamports into one's
-    /// [`Position`](crate::state::Position).
+    /// Same as `consume_events`, but then calls the market's
+    /// [fill callback](crate::fill_callback) program with the processed fills.
     ///
-    /// Makers might wish to `deposit`, rather than have actual tokens moved for
-    /// each trade, in order to reduce CUs.
-    pub fn deposit(ctx: Context<Deposit>, base_amount: u64, quote_amount: u64) -> Result<()> {
+    /// The callback is only made here, so crankers can always fall back to
+    /// `consume_events` if it fails.
+    pub fn consume_events_with_fill_callback<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ConsumeEventsWithFillCallback<'info>>,
+        limit: usize,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::consume_events_with_fill_callback(ctx, limit)?;
+        Ok(())
+    }
+
+    /// Chain a page after the market's bids or asks, for fixed price orders posted once
+    /// they're full. The existing pages of the side go in the remaining accounts, in
+    /// order. Anyone can pay for it, up to [`MAX_BOOK_SIDE_PAGES`](crate::state::MAX_BOOK_SIDE_PAGES) per side.
+    ///
+    /// Once a side has orders on its pages, instructions matching against that side
+    /// need all its pages in their remaining accounts, and cancelling an order on a
+    /// page needs the pages up to it.
+    pub fn create_book_side_page<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, CreateBookSidePage<'info>>,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::create_book_side_page(ctx)?;
+        Ok(())
+    }
+
+    /// Compact the nodes of the market's book sides, moving at most `limit` nodes
+    /// per side, and rebuild their free lists. Anyone can call it.
+    ///
+    /// After heavy order churn the live nodes are spread over the whole book side
+    /// account. Repeat until no more nodes are moved to fully compact a book.
+    pub fn defragment_book(ctx: Context<DefragmentBook>, limit: u8) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::defragment_book(ctx, limit)?;
+        Ok(())
+    }
+
+    /// Remove up to `limit` expired orders from each of the market's book sides.
+    /// Anyone can call it.
+    ///
+    /// Resting orders are grouped by expiry in
+    /// [`ExpiryBucket`](crate::state::ExpiryBucket)s, so only the orders of the due
+    /// buckets are looked for, earliest expiry first. Their out events are applied
+    /// to the open orders accounts passed as remaining accounts and queued for the
+    /// others.
+    pub fn expire_orders<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ExpireOrders<'info>>,
+        limit: u8,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::expire_orders(ctx, limit)?;
+        Ok(())
+    }
+
+    /// Cancel an order by its `order_id`.
+    ///
+    /// Note that this doesn't emit an [`OutEvent`](crate::state::OutEvent) because a
+    /// maker knows that they will be passing in their own [`OpenOrdersAccount`](crate::state::OpenOrdersAccount).
+    pub fn cancel_order(ctx: Context<CancelOrder>, order_id: u128) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::cancel_order(ctx, order_id)?;
+        Ok(())
+    }
+
+    /// Cancel an order by its `client_order_id`.
+    ///
+    /// Note that this doesn't emit an [`OutEvent`](crate::state::OutEvent) because a
+    /// maker knows that they will be passing in their own [`OpenOrdersAccount`](crate::state::OpenOrdersAccount).
+    pub fn cancel_order_by_client_order_id(
+        ctx: Context<CancelOrder>,
+        client_order_id: u64,
+    ) -> Result<i64> {
+        #[cfg(feature = "enable-gpl")]
+        return instructions::cancel_order_by_client_order_id(ctx, client_order_id);
+
+        #[cfg(not(feature = "enable-gpl"))]
+        Ok(0)
+    }
+
+    /// Look up the order with `client_order_id` of an open orders account, returned
+    /// as the instruction's return data. `None` if the account has no such order.
+    ///
+    /// Lets a bot that lost track of its orders recover their ids, e.g. by simulating
+    /// this instruction. Orders on book side pages are only found with the pages passed
+    /// as remaining accounts, their quantity is 0 otherwise.
+    pub fn find_order<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, FindOrder<'info>>,
+        client_order_id: u64,
+    ) -> Result<Option<OrderInfo>> {
+        #[cfg(feature = "enable-gpl")]
+        return instructions::find_order(ctx, client_order_id);
+
+        #[cfg(not(feature = "enable-gpl"))]
+        Ok(None)
+    }
+
+    /// Top of the book of a market along with its oracle price, returned as the
+    /// instruction's return data, see [`MarketQuote`].
+    ///
+    /// Read-only, meant for programs pricing assets against the market's liquidity
+    /// through CPI. Oracle pegged orders are included when the oracle is usable.
+    pub fn get_market_quote(ctx: Context<GetMarketQuote>) -> Result<MarketQuote> {
+        #[cfg(feature = "enable-gpl")]
+        return instructions::get_market_quote(ctx);
+
+        #[cfg(not(feature = "enable-gpl"))]
+        Ok(MarketQuote::default())
+    }
+
+    /// Write the top `levels` aggregated price levels of each side of the book into
+    /// a [`DepthSnapshot`](crate::state::DepthSnapshot), at most
+    /// [`DEPTH_SNAPSHOT_LEVELS`](crate::state::DEPTH_SNAPSHOT_LEVELS).
+    ///
+    /// Anyone can refresh a snapshot. Oracle pegged orders are included when the
+    /// oracle is usable.
+    pub fn snapshot_depth(ctx: Context<SnapshotDepth>, levels: u8) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::snapshot_depth(ctx, levels)?;
+        Ok(())
+    }
+
+    /// Cancel up to `limit` orders, optionally filtering by side
+    pub fn cancel_all_orders(
+        ctx: Context<CancelOrder>,
+        side_option: Option<Side>,
+        limit: u8,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::cancel_all_orders(ctx, side_option, limit)?;
+        Ok(())
+    }
+
+    /// Let anyone cancel all orders of an open orders account once `cancel_after_ts`
+    /// has passed, as its owner or delegate. Pass 0 to unschedule. The schedule is
+    /// kept in the account's position extension.
+    ///
+    /// A dead man's switch for makers: a bot pushes the time out while it's alive,
+    /// a keeper or automation service cancels its orders when it stops doing so.
+    pub fn schedule_cancel_all(
+        ctx: Context<ScheduleCancelAll>,
+        cancel_after_ts: u64,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::schedule_cancel_all(ctx, cancel_after_ts)?;
+        Ok(())
+    }
+
+    /// Cancel up to `limit` orders of an open orders account whose scheduled
+    /// cancel is due, see `schedule_cancel_all`. Permissionless.
+    ///
+    /// The schedule is cleared once no orders are left. The call cancelling the
+    /// last orders gets the account's heartbeat bounty, see `set_heartbeat_interval`.
+    pub fn execute_scheduled_cancel(ctx: Context<ExecuteScheduledCancel>, limit: u8) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::execute_scheduled_cancel(ctx, limit)?;
+        Ok(())
+    }
+
+    /// Make `heartbeat` calls keep the orders of an open orders account alive, as
+    /// its owner or delegate. Pass an `interval` of 0 to disable it.
+    ///
+    /// Once `interval` seconds pass without a heartbeat, anyone can cancel the
+    /// account's orders with `execute_scheduled_cancel` and earn `bounty` lamports,
+    /// escrowed in the account's position extension by the signer. A previous
+    /// bounty is returned to the owner. Starts the first interval right away.
+    pub fn set_heartbeat_interval(
+        ctx: Context<SetHeartbeatInterval>,
+        interval: u32,
+        bounty: u64,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::set_heartbeat_interval(ctx, interval, bounty)?;
+        Ok(())
+    }
+
+    /// Push the scheduled cancel of an open orders account out by its heartbeat
+    /// interval, see `set_heartbeat_interval`.
+    pub fn heartbeat(ctx: Context<Heartbeat>) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::heartbeat(ctx)?;
+        Ok(())
+    }
+
+    /// Deposit a certain amount of `base` and `quote` lamports into one's
+    /// [`Position`](crate::state::Position).
+    ///
+    /// Makers might wish to `deposit`, rather than have actual tokens moved for
+    /// each trade, in order to reduce CUs.
+    pub fn deposit(ctx: Context<Deposit>, base_amount: u64, quote_amount: u64) -> Result<()> {
         #[cfg(feature = "enable-gpl")]
         instructions::deposit(ctx, base_amount, quote_amount)?;
         Ok(())
@@ -556,14 +1159,37 @@ pub mod openbook_v2 {
     }
 
     /// Withdraw any available tokens.
+    ///
+    /// The owner can send them to any token accounts, e.g. a prime broker's or
+    /// another wallet of the same firm, the delegate only to the owner's.
+    ///
+    /// Accounts with a position extension need it among the remaining accounts.
+    /// When it has a settle receiver, the funds are routed into it, see
+    /// [`settle_receiver`](crate::settle_receiver).
+    ///
+    /// Event heap penalties are paid by `penalty_payer`, which can be the relayer
+    /// paying the transaction fee rather than the owner.
     pub fn settle_funds<'info>(ctx: Context<'_, '_, '_, 'info, SettleFunds<'info>>) -> Result<()> {
         #[cfg(feature = "enable-gpl")]
         instructions::settle_funds(ctx)?;
         Ok(())
     }
 
+    /// Withdraw only the maker and referrer rebates of an open orders account.
+    ///
+    /// The owner can send them to any quote token account, the delegate only to the
+    /// owner's. Maker rebates are paid up to the free quote left, `settle_funds` still
+    /// pays out whatever wasn't claimed.
+    pub fn claim_rebates(ctx: Context<ClaimRebates>) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::claim_rebates(ctx)?;
+        Ok(())
+    }
+
     /// Withdraw any available tokens when the market is expired (only
     /// [`close_market_admin`](crate::state::Market::close_market_admin)).
+    ///
+    /// Tokens go to the owner's accounts, or to any accounts if the owner signs too.
     pub fn settle_funds_expired<'info>(
         ctx: Context<'_, '_, '_, 'info, SettleFundsExpired<'info>>,
     ) -> Result<()> {
@@ -572,6 +1198,454 @@ pub mod openbook_v2 {
         Ok(())
     }
 
+    /// Withdraw any available tokens from several markets at once.
+    ///
+    /// Every market is described by seven remaining accounts, in order:
+    /// `open_orders_account`, `market`, `market_authority`, `market_base_vault`,
+    /// `market_quote_vault`, `user_base_account` and `user_quote_account`.
+    /// Referrer rebates are not paid out and go to the market's fees instead.
+    pub fn settle_funds_multiple<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, SettleFundsMultiple<'info>>,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::settle_funds_multiple(ctx)?;
+        Ok(())
+    }
+
+    /// Grow the [`EventHeap`](crate::state::EventHeap) by `additional_nodes`, as a
+    /// [`Market`](crate::state::Market)'s admin.
+    ///
+    /// The new nodes are added to the free list right away. Solana limits how
+    /// much an account can grow per instruction, so large resizes need to be
+    /// split over several instructions.
+    pub fn resize_event_heap(ctx: Context<ResizeEventHeap>, additional_nodes: u16) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::resize_event_heap(ctx, additional_nodes)?;
+        Ok(())
+    }
+
+    /// Upgrade a [`Market`](crate::state::Market) or
+    /// [`OpenOrdersAccount`](crate::state::OpenOrdersAccount) created by an older
+    /// version of the program to the current version.
+    ///
+    /// Layout changes only use reserved space, so accounts keep their size and the
+    /// new fields start zeroed. Version 0 open orders accounts need to cancel their
+    /// bids first, as their `bids_quote_lots` wasn't tracked. Anyone can call it,
+    /// it's a no-op for accounts that are already up to date.
+    pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::migrate_account(ctx)?;
+        Ok(())
+    }
+
+    /// Set the lamports paid per consumed event by `consume_events_with_reward`,
+    /// as a [`Market`](crate::state::Market)'s admin.
+    pub fn set_consume_events_reward(
+        ctx: Context<SetConsumeEventsReward>,
+        reward_lamports: u64,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::set_consume_events_reward(ctx, reward_lamports)?;
+        Ok(())
+    }
+
+    /// Cap the resting orders each open orders account can have on a
+    /// [`Market`](crate::state::Market), as its admin, to share the book space
+    /// fairly. 0 removes the cap.
+    ///
+    /// Accounts above a new cap keep their orders but can't post new ones.
+    pub fn set_max_resting_orders(
+        ctx: Context<SetMaxRestingOrders>,
+        max_resting_orders: u8,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::set_max_resting_orders(ctx, max_resting_orders)?;
+        Ok(())
+    }
+
+    /// Set the smallest quote native value new orders can rest on the book with, as
+    /// a [`Market`](crate::state::Market)'s admin. 0 removes the minimum.
+    ///
+    /// Fills don't count towards it: the remainder of a partially filled order below
+    /// the minimum isn't posted. Resting orders below a new minimum stay on the book.
+    pub fn set_min_order_notional(
+        ctx: Context<SetMinOrderNotional>,
+        min_order_notional: u64,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::set_min_order_notional(ctx, min_order_notional)?;
+        Ok(())
+    }
+
+    /// Limit how many new orders each open orders account can place per window of
+    /// `window_seconds`, as a [`Market`](crate::state::Market)'s admin.
+    ///
+    /// Protects the book and event heap of permissionless markets from spam. Orders
+    /// placed without an open orders account, with `place_take_order`, aren't limited.
+    /// A `max_orders` of 0 removes the limit.
+    ///
+    /// The limit is tracked in the [`PositionExtension`](crate::state::PositionExtension)
+    /// of each account, accounts need one to place orders while the market is limited.
+    pub fn set_order_rate_limit(
+        ctx: Context<SetOrderRateLimit>,
+        max_orders: u32,
+        window_seconds: u32,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::set_order_rate_limit(ctx, max_orders, window_seconds)?;
+        Ok(())
+    }
+
+    /// Choose which order is booted when a new order is posted to a full book side,
+    /// as a [`Market`](crate::state::Market)'s admin, see
+    /// [`EvictionPolicy`](crate::state::EvictionPolicy). `band_bps` only applies to
+    /// `EvictOldestOutOfBand`.
+    pub fn set_eviction_policy(
+        ctx: Context<SetEvictionPolicy>,
+        policy: EvictionPolicy,
+        band_bps: u16,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::set_eviction_policy(ctx, policy, band_bps)?;
+        Ok(())
+    }
+
+    /// Choose whether taker fees round up or down to native quote units, as a
+    /// [`Market`](crate::state::Market)'s admin, see
+    /// [`FeeRounding`](crate::state::FeeRounding).
+    ///
+    /// Rounding up, the default, the taker pays the fractional remainder to the
+    /// market's fees. Rounding down it stays with the taker. Maker fees and rebates
+    /// always round down.
+    pub fn set_fee_rounding(
+        ctx: Context<SetFeeRounding>,
+        taker_fee_rounding: FeeRounding,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::set_fee_rounding(ctx, taker_fee_rounding)?;
+        Ok(())
+    }
+
+    /// Opt a market into maker rewards paid in `reward_mint`, as a
+    /// [`Market`](crate::state::Market)'s admin. See
+    /// [`MakerRewards`](crate::state::MakerRewards) for how they accrue.
+    ///
+    /// The rewards are paid out of the reward vault created along, which anyone
+    /// can fund with a token transfer. Points only accrue from its creation on.
+    pub fn create_maker_rewards(
+        ctx: Context<CreateMakerRewards>,
+        rewards_per_million_points: u64,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::create_maker_rewards(ctx, rewards_per_million_points)?;
+        Ok(())
+    }
+
+    /// Change the reward rate of a market's maker rewards, as its admin.
+    ///
+    /// Unclaimed points are paid at the rate in effect when claimed. Pass a rate of
+    /// 0 to pause the payouts, points keep accruing.
+    pub fn set_maker_rewards(
+        ctx: Context<SetMakerRewards>,
+        rewards_per_million_points: u64,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::set_maker_rewards(ctx, rewards_per_million_points)?;
+        Ok(())
+    }
+
+    /// Transfer the maker rewards of the points accrued by an open orders account,
+    /// as far as the reward vault covers them.
+    pub fn claim_maker_rewards(ctx: Context<ClaimMakerRewards>) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::claim_maker_rewards(ctx)?;
+        Ok(())
+    }
+
+    /// Start tracking the quotes of a designated market maker's open orders account,
+    /// as a [`Market`](crate::state::Market)'s admin. See
+    /// [`QuoteQuality`](crate::state::QuoteQuality).
+    ///
+    /// The account quotes while it has `min_size_lots` on each side within
+    /// `max_oracle_distance_bps` of the oracle. Requires a market with an oracle.
+    pub fn create_quote_quality(
+        ctx: Context<CreateQuoteQuality>,
+        max_oracle_distance_bps: u16,
+        min_size_lots: i64,
+        epoch_duration: u64,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::create_quote_quality(
+            ctx,
+            max_oracle_distance_bps,
+            min_size_lots,
+            epoch_duration,
+        )?;
+        Ok(())
+    }
+
+    /// Record whether a designated market maker is quoting, crediting it the time
+    /// since the previous sample. Anyone can sample.
+    pub fn sample_quote_quality(ctx: Context<SampleQuoteQuality>) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::sample_quote_quality(ctx)?;
+        Ok(())
+    }
+
+    /// Create the [`DmmRegistry`](crate::state::DmmRegistry) of a market's
+    /// designated market makers, as its collect fee admin.
+    pub fn create_dmm_registry(ctx: Context<CreateDmmRegistry>) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::create_dmm_registry(ctx)?;
+        Ok(())
+    }
+
+    /// Make an open orders account a designated market maker of `priority`,
+    /// committed to quote `min_uptime_bps` of the time, or remove it with a zero
+    /// priority. Only the collect fee admin can call this.
+    pub fn set_dmm_slot(ctx: Context<SetDmmSlot>, priority: u8, min_uptime_bps: u16) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::set_dmm_slot(ctx, priority, min_uptime_bps)?;
+        Ok(())
+    }
+
+    /// Remove a designated market maker whose last complete
+    /// [`QuoteQuality`](crate::state::QuoteQuality) epoch fell short of its
+    /// `min_uptime_bps`. Anyone can call this.
+    pub fn enforce_dmm_obligation(ctx: Context<EnforceDmmObligation>) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::enforce_dmm_obligation(ctx)?;
+        Ok(())
+    }
+
+    /// Create a [`TakerRebateCampaign`](crate::state::TakerRebateCampaign) rebating
+    /// `rebate_bps` of the taker fees paid between `start_timestamp` and `end_timestamp`,
+    /// as a [`Market`](crate::state::Market)'s admin.
+    ///
+    /// Takers accrue rebates when they pass the campaign among the remaining accounts
+    /// of their orders, up to the budget funded with `fund_taker_rebate_campaign`.
+    pub fn create_taker_rebate_campaign(
+        ctx: Context<CreateTakerRebateCampaign>,
+        start_timestamp: u64,
+        end_timestamp: u64,
+        rebate_bps: u16,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::create_taker_rebate_campaign(
+            ctx,
+            start_timestamp,
+            end_timestamp,
+            rebate_bps,
+        )?;
+        Ok(())
+    }
+
+    /// Change the window and rebate of a taker rebate campaign, as the market's admin.
+    ///
+    /// Accrued rebates can still be claimed, this can also start a new campaign once
+    /// the previous one ended.
+    pub fn set_taker_rebate_campaign(
+        ctx: Context<SetTakerRebateCampaign>,
+        start_timestamp: u64,
+        end_timestamp: u64,
+        rebate_bps: u16,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::set_taker_rebate_campaign(ctx, start_timestamp, end_timestamp, rebate_bps)?;
+        Ok(())
+    }
+
+    /// Add `amount` quote to the budget of a taker rebate campaign, as the market's admin.
+    pub fn fund_taker_rebate_campaign(
+        ctx: Context<FundTakerRebateCampaign>,
+        amount: u64,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::fund_taker_rebate_campaign(ctx, amount)?;
+        Ok(())
+    }
+
+    /// Withdraw the budget of a taker rebate campaign that wasn't accrued, as the
+    /// market's admin. Not possible while the campaign is running.
+    pub fn withdraw_taker_rebate_budget(ctx: Context<WithdrawTakerRebateBudget>) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::withdraw_taker_rebate_budget(ctx)?;
+        Ok(())
+    }
+
+    /// Transfer the taker rebates accrued by an open orders account.
+    pub fn claim_taker_rebates(ctx: Context<ClaimTakerRebates>) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::claim_taker_rebates(ctx)?;
+        Ok(())
+    }
+
+    /// Create the [`FillHistory`](crate::state::FillHistory) of a market.
+    ///
+    /// Anyone can pay for it. Once created, pass it among the remaining accounts
+    /// of order placing instructions to record their fills.
+    pub fn create_fill_history(ctx: Context<CreateFillHistory>) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::create_fill_history(ctx)?;
+        Ok(())
+    }
+
+    /// Create the [`MarketStats`](crate::state::MarketStats) of a market.
+    ///
+    /// Anyone can pay for it. Once created, pass it among the remaining accounts
+    /// of order placing instructions to record their fills.
+    pub fn create_market_stats(ctx: Context<CreateMarketStats>) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::create_market_stats(ctx)?;
+        Ok(())
+    }
+
+    /// Create a [`DepthSnapshot`](crate::state::DepthSnapshot) of a market for `owner`,
+    /// to be filled by `snapshot_depth`.
+    pub fn create_depth_snapshot(ctx: Context<CreateDepthSnapshot>) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::create_depth_snapshot(ctx)?;
+        Ok(())
+    }
+
+    /// Point a [`Market`](crate::state::Market) to the market of the same token pair
+    /// replacing it, as its admin. Omit the successor market to remove the link.
+    ///
+    /// The link is stored in the market's [`MarketMetadata`](crate::state::MarketMetadata),
+    /// see `create_market_metadata`.
+    ///
+    /// Lot sizes can't change, so a market whose prices moved too far is migrated by
+    /// creating a successor with new lot sizes, linking it here for clients to redirect
+    /// their users, and usually switching this market to cancel only. See the client's
+    /// `migration` module to carry the resting orders over.
+    pub fn set_successor_market(ctx: Context<SetSuccessorMarket>) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::set_successor_market(ctx)?;
+        Ok(())
+    }
+
+    /// Register a program to be called with the fills processed by
+    /// `consume_events_with_fill_callback`, as a [`Market`](crate::state::Market)'s admin.
+    ///
+    /// See [`fill_callback`](crate::fill_callback) for the interface. The program is
+    /// kept in the market's [`FillCallback`](crate::state::FillCallback) account.
+    pub fn create_fill_callback(ctx: Context<CreateFillCallback>, program: Pubkey) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::create_fill_callback(ctx, program)?;
+        Ok(())
+    }
+
+    /// Change the program registered with `create_fill_callback`, as the market's
+    /// admin. Pass `None` to remove it.
+    pub fn set_fill_callback(ctx: Context<SetFillCallback>, program: Option<Pubkey>) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::set_fill_callback(ctx, program)?;
+        Ok(())
+    }
+
+    /// Offer one of a [`Market`](crate::state::Market)'s admin roles to a new key,
+    /// as the current holder of that role.
+    ///
+    /// The new admin takes over by calling `accept_market_authority`. The offer is
+    /// kept in a [`PendingMarketAdmin`](crate::state::PendingMarketAdmin) account,
+    /// so only one transfer can be pending at a time.
+    pub fn set_market_authority(
+        ctx: Context<SetMarketAuthority>,
+        role: MarketAdmin,
+        new_admin: Pubkey,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::set_market_authority(ctx, role, new_admin)?;
+        Ok(())
+    }
+
+    /// Take over the admin role offered by `set_market_authority`.
+    pub fn accept_market_authority(ctx: Context<AcceptMarketAuthority>) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::accept_market_authority(ctx)?;
+        Ok(())
+    }
+
+    /// Withdraw the admin role offered by `set_market_authority`, as the current
+    /// holder of that role.
+    pub fn cancel_market_authority(ctx: Context<CancelMarketAuthority>) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::cancel_market_authority(ctx)?;
+        Ok(())
+    }
+
+    /// Create the [`MarketMetadata`](crate::state::MarketMetadata) of a market,
+    /// as a [`Market`](crate::state::Market)'s admin.
+    pub fn create_market_metadata(
+        ctx: Context<CreateMarketMetadata>,
+        args: MarketMetadataArgs,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::create_market_metadata(ctx, args)?;
+        Ok(())
+    }
+
+    /// Update the [`MarketMetadata`](crate::state::MarketMetadata) of a market,
+    /// as a [`Market`](crate::state::Market)'s admin.
+    pub fn set_market_metadata(
+        ctx: Context<SetMarketMetadata>,
+        args: MarketMetadataArgs,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::set_market_metadata(ctx, args)?;
+        Ok(())
+    }
+
+    /// Turn a market into a binary outcome market, or post its resolution once
+    /// expired, as a [`Market`](crate::state::Market)'s admin.
+    ///
+    /// Resolving settles the market at a price of 0 or 1, see [`BinaryOutcome`].
+    /// Resting orders are then cancelled through `settle_expired_market`.
+    pub fn set_binary_outcome(
+        ctx: Context<SetBinaryOutcome>,
+        outcome: BinaryOutcome,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::set_binary_outcome(ctx, outcome)?;
+        Ok(())
+    }
+
+    /// Restrict what users can do on a market, as a [`Market`](crate::state::Market)'s admin.
+    ///
+    /// See [`MarketState`] for what each state allows.
+    pub fn set_market_state(ctx: Context<SetMarketState>, state: MarketState) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::set_market_state(ctx, state)?;
+        Ok(())
+    }
+
+    /// Record the settlement price of an expired market and switch it to cancel
+    /// only, as its close market admin.
+    ///
+    /// Markets with an oracle settle at the oracle price and take no
+    /// `settlement_price_lots`, markets without one settle at the given price.
+    ///
+    /// Cancels the resting orders of the open orders accounts passed as remaining
+    /// accounts. Can be called again with other accounts until the book is empty.
+    pub fn settle_expired_market<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, SettleExpiredMarket<'info>>,
+        limit: u8,
+        settlement_price_lots: Option<i64>,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::settle_expired_market(ctx, limit, settlement_price_lots)?;
+        Ok(())
+    }
+
+    /// Move the quote tokens of the vault that no account is owed, like rounding
+    /// remainders of fills, to the fees available to `sweep_fees`, so the vault
+    /// balance reconciles exactly with the market's deposits. Anyone can call it.
+    pub fn sweep_quote_dust(ctx: Context<SweepQuoteDust>) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::sweep_quote_dust(ctx)?;
+        Ok(())
+    }
+
     /// Sweep fees, as a [`Market`](crate::state::Market)'s admin.
     pub fn sweep_fees(ctx: Context<SweepFees>) -> Result<()> {
         #[cfg(feature = "enable-gpl")]
@@ -579,6 +1653,21 @@ pub mod openbook_v2 {
         Ok(())
     }
 
+    /// Compare a market's vault balances and deposit totals with what the
+    /// [`OpenOrdersAccount`](crate::state::OpenOrdersAccount)s passed as remaining
+    /// accounts are owed, pending fills included. Anyone can call it.
+    ///
+    /// Doesn't fail on a discrepancy: it's logged, along with the totals in a
+    /// [`MarketInvariantsLog`](crate::logs::MarketInvariantsLog). With all the open
+    /// orders accounts of the market passed, nothing is left unaccounted.
+    pub fn verify_market_invariants<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, VerifyMarketInvariants<'info>>,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::verify_market_invariants(ctx)?;
+        Ok(())
+    }
+
     /// Update the [`delegate`](crate::state::OpenOrdersAccount::delegate) of an open orders account.
     pub fn set_delegate(ctx: Context<SetDelegate>) -> Result<()> {
         #[cfg(feature = "enable-gpl")]
@@ -586,6 +1675,41 @@ pub mod openbook_v2 {
         Ok(())
     }
 
+    /// Let takers pay the fill proceeds of an open orders account straight to its
+    /// owner's token accounts passed along with it, see `place_order`. Off by default.
+    pub fn set_direct_settlement(ctx: Context<SetDirectSettlement>, enabled: bool) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::set_direct_settlement(ctx, enabled)?;
+        Ok(())
+    }
+
+    /// Register a program approving the new orders of an open orders account,
+    /// as its owner. It's kept in the account's position extension, which has to
+    /// be created first.
+    ///
+    /// See [`health_check`](crate::health_check) for the interface. Pass `None`
+    /// to remove it.
+    pub fn set_risk_program(ctx: Context<SetRiskProgram>, program: Option<Pubkey>) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::set_risk_program(ctx, program)?;
+        Ok(())
+    }
+
+    /// Register a program `settle_funds` routes the withdrawn tokens of an open
+    /// orders account into, as its owner. Like the risk program, it's kept in the
+    /// account's position extension.
+    ///
+    /// See [`settle_receiver`](crate::settle_receiver) for the interface. Pass
+    /// `None` to remove it.
+    pub fn set_settle_receiver(
+        ctx: Context<SetSettleReceiver>,
+        program: Option<Pubkey>,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::set_settle_receiver(ctx, program)?;
+        Ok(())
+    }
+
     /// Set market to expired before pruning orders and closing the market (only
     /// [`close_market_admin`](crate::state::Market::close_market_admin)).
     pub fn set_market_expired(ctx: Context<SetMarketExpired>) -> Result<()> {
@@ -594,6 +1718,19 @@ pub mod openbook_v2 {
         Ok(())
     }
 
+    /// Cancel the orders of an open orders account, as the market's
+    /// [`close_market_admin`](crate::state::Market::close_market_admin), whatever
+    /// the market's state.
+    ///
+    /// For compliance actions on permissioned markets and to clear the book of broken
+    /// accounts before `close_market`. Emits an `OutLog` per cancelled order, the
+    /// account keeps the released funds and can settle them as usual.
+    pub fn force_cancel_orders(ctx: Context<ForceCancelOrders>, limit: u8) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::force_cancel_orders(ctx, limit)?;
+        Ok(())
+    }
+
     /// Remove orders from the book when the market is expired (only
     /// [`close_market_admin`](crate::state::Market::close_market_admin)).
     pub fn prune_orders(ctx: Context<PruneOrders>, limit: u8) -> Result<()> {
@@ -619,6 +1756,31 @@ pub mod openbook_v2 {
         instructions::stub_oracle_set(ctx, price)?;
         Ok(())
     }
+
+    /// Set every field of a stub oracle, to test stale oracles, low confidence
+    /// and prices with an exponent.
+    ///
+    /// `price` and `deviation` are multiplied by 10^`exponent`. A
+    /// `last_update_slot` of 0 means the oracle is never stale.
+    pub fn stub_oracle_set_test(
+        ctx: Context<StubOracleSet>,
+        price: f64,
+        exponent: i8,
+        deviation: f64,
+        last_update_ts: i64,
+        last_update_slot: u64,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::stub_oracle_set_test(
+            ctx,
+            price,
+            exponent,
+            deviation,
+            last_update_ts,
+            last_update_slot,
+        )?;
+        Ok(())
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Debug, Copy, Clone)]
@@ -639,6 +1801,42 @@ pub struct PlaceOrderArgs {
     pub limit: u8,
 }
 
+/// Top of the book of a market, see `get_market_quote`.
+///
+/// Prices without a `_lots` suffix are native prices: native quote per native base.
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Default, Copy, Clone, PartialEq)]
+pub struct MarketQuote {
+    pub best_bid_lots: Option<i64>,
+    /// Base lots at the best bid
+    pub best_bid_quantity: i64,
+    pub best_ask_lots: Option<i64>,
+    /// Base lots at the best ask
+    pub best_ask_quantity: i64,
+    /// Average of the best bid and ask, `None` unless both sides have orders
+    pub mid_price: Option<I80F48>,
+    /// Difference between the best ask and bid, relative to the mid price
+    pub spread_bps: Option<i64>,
+    /// `None` without a usable oracle, e.g. when it's stale
+    pub oracle_price: Option<I80F48>,
+    /// Difference between the mid price and the oracle price, relative to the oracle price
+    pub oracle_deviation_bps: Option<i64>,
+}
+
+/// An order of an open orders account, see `find_order`
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Copy, Clone, PartialEq, Eq)]
+pub struct OrderInfo {
+    pub order_id: u128,
+    pub client_order_id: u64,
+    pub side: Side,
+    pub is_oracle_pegged: bool,
+    /// Price the order's funds are locked at, the peg limit for pegged bids
+    pub locked_price: i64,
+    /// Base lots left on the book. 0 when the order was filled or expired and the
+    /// event freeing it wasn't consumed yet.
+    pub quantity: i64,
+    pub timestamp: u64,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Debug, Copy, Clone)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PlaceMultipleOrdersArgs {
@@ -647,6 +1845,15 @@ pub struct PlaceMultipleOrdersArgs {
     pub expiry_timestamp: u64,
 }
 
+/// One market cranked by `consume_events_multiple`
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Copy, Clone)]
+pub struct ConsumeEventsGroup {
+    /// Remaining accounts of the market after its `market` and `event_heap`
+    pub num_accounts: u8,
+    /// Max events to consume in the market
+    pub limit: u8,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Debug, Copy, Clone)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PlaceOrderPeggedArgs {
@@ -688,6 +1895,8 @@ pub struct PlaceTakeOrderArgs {
     pub price_lots: i64,
     pub max_base_lots: i64,
     pub max_quote_lots_including_fees: i64,
+    /// Copied to the fill events and logs as `taker_client_order_id`
+    pub client_order_id: u64,
     pub order_type: PlaceOrderType,
     // Maximum number of orders from the book to fill.
     //
@@ -696,6 +1905,14 @@ pub struct PlaceTakeOrderArgs {
     pub limit: u8,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]
+pub struct MarketMetadataArgs {
+    pub name: String,
+    pub base_symbol: String,
+    pub quote_symbol: String,
+    pub uri: String,
+}
+
 // Add security details to explorer.solana.com
 #[cfg(not(feature = "no-entrypoint"))]
 use {default_env::default_env, solana_security_txt::security_txt};
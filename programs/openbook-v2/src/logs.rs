@@ -1,5 +1,9 @@
 use anchor_lang::prelude::*;
 use borsh::BorshSerialize;
+use num_enum::IntoPrimitive;
+
+use crate::math;
+use crate::state::{FillEvent, LeafNode, Market, Order, OrderWithAmounts, OutEvent, Side};
 
 #[inline(never)] // ensure fresh stack frame
 pub fn emit_stack<T: anchor_lang::Event>(e: T) {
@@ -43,10 +47,169 @@ pub struct FillLog {
 
     pub taker: Pubkey,
     pub taker_client_order_id: u64,
-    pub taker_fee_ceil: u64, // native quote
+    pub taker_fee_ceil: u64, // native quote, rounded as the market's taker_fee_rounding
+
+    pub price: i64,
+    pub quantity: i64, // number of base lots
 
+    pub base_native: u64,
+    pub quote_native: u64,
+    pub event_seq_num: u64,
+}
+
+impl FillLog {
+    pub fn new(market_pk: Pubkey, market: &Market, fill: &FillEvent) -> Result<Self> {
+        let base_native = math::lots_to_native(fill.quantity, market.base_lot_size)?;
+        let quote_native = math::quote_native(fill.quantity, fill.price, market.quote_lot_size)?;
+
+        // Fees are not charged on self trades
+        let (maker_fee, taker_fee_ceil) = if quote_native > 0 && fill.maker != fill.taker {
+            (
+                market.maker_fees_floor(quote_native),
+                market.taker_fees(quote_native),
+            )
+        } else {
+            (0, 0)
+        };
+
+        Ok(Self {
+            market: market_pk,
+            taker_side: fill.taker_side,
+            maker_slot: fill.maker_slot,
+            maker_out: fill.maker_out(),
+            timestamp: fill.timestamp,
+            seq_num: fill.market_seq_num,
+            maker: fill.maker,
+            maker_client_order_id: fill.maker_client_order_id,
+            maker_fee,
+            maker_timestamp: fill.maker_timestamp,
+            taker: fill.taker,
+            taker_client_order_id: fill.taker_client_order_id,
+            taker_fee_ceil,
+            price: fill.price,
+            quantity: fill.quantity,
+            base_native,
+            quote_native,
+            event_seq_num: fill.event_seq_num,
+        })
+    }
+}
+
+#[event]
+pub struct OutLog {
+    pub market: Pubkey,
+    pub side: u8,
+    pub owner_slot: u8,
+    pub timestamp: u64,
+    pub seq_num: u64,
+    pub owner: Pubkey,
+    pub quantity: i64, // number of base lots
+    pub event_seq_num: u64,
+    /// OutReason
+    pub reason: u8,
+    /// See [`LeafNode::price_lots_or_peg_offset`]
     pub price: i64,
+    pub peg_limit: i64,
+    pub client_order_id: u64,
+}
+
+impl OutLog {
+    pub fn new(market_pk: Pubkey, seq_num: u64, out: &OutEvent) -> Self {
+        Self {
+            market: market_pk,
+            side: out.side,
+            owner_slot: out.owner_slot,
+            timestamp: out.timestamp,
+            seq_num,
+            owner: out.owner,
+            quantity: out.quantity,
+            event_seq_num: out.event_seq_num,
+            reason: out.reason,
+            price: out.price,
+            peg_limit: out.peg_limit,
+            client_order_id: out.client_order_id,
+        }
+    }
+}
+
+#[event]
+pub struct PlaceLog {
+    pub market: Pubkey,
+    pub side: u8,
+    pub seq_num: u64,
+    /// Open orders account placing the order, the signer's wallet for take orders
+    pub owner: Pubkey,
+    pub client_order_id: u64,
+    /// Id of the order posted on the book, if any
+    pub order_id: Option<u128>,
+
+    pub base_taken_native: u64,
+    pub quote_taken_native: u64,
+    pub base_posted_native: u64,
+    pub quote_posted_native: u64,
+    pub taker_fees: u64,
+    pub maker_fees: u64, // locked for the posted part
+}
+
+impl PlaceLog {
+    pub fn new(
+        market_pk: Pubkey,
+        market: &Market,
+        owner: Pubkey,
+        order: &Order,
+        amounts: &OrderWithAmounts,
+    ) -> Self {
+        Self {
+            market: market_pk,
+            side: order.side.into(),
+            seq_num: market.seq_num,
+            owner,
+            client_order_id: order.client_order_id,
+            order_id: amounts.order_id,
+            base_taken_native: amounts.total_base_taken_native,
+            quote_taken_native: amounts.total_quote_taken_native,
+            base_posted_native: amounts.posted_base_native,
+            quote_posted_native: amounts.posted_quote_native,
+            taker_fees: amounts.taker_fees,
+            maker_fees: amounts.maker_fees,
+        }
+    }
+}
+
+#[event]
+pub struct CancelLog {
+    pub market: Pubkey,
+    pub side: u8,
+    pub seq_num: u64,
+    pub open_orders_account: Pubkey,
+    pub order_id: u128,
+    pub client_order_id: u64,
     pub quantity: i64, // number of base lots
+    /// See [`LeafNode::price_lots_or_peg_offset`]
+    pub price: i64,
+    pub peg_limit: i64,
+}
+
+impl CancelLog {
+    pub fn new(
+        market_pk: Pubkey,
+        market: &Market,
+        open_orders_account: Pubkey,
+        side: Side,
+        leaf_node: &LeafNode,
+    ) -> Self {
+        Self {
+            market: market_pk,
+            side: side.into(),
+            seq_num: market.seq_num,
+            open_orders_account,
+            order_id: leaf_node.key,
+            client_order_id: leaf_node.client_order_id,
+            quantity: leaf_node.quantity,
+            price: leaf_node.price_lots_or_peg_offset(),
+            peg_limit: leaf_node.peg_limit,
+        }
+    }
 }
 
 #[event]
@@ -76,6 +239,42 @@ pub struct TotalOrderFillEvent {
     pub fees: u64,
 }
 
+/// Why a taker order stopped matching before filling its max base lots
+#[derive(Clone, Copy, Debug, PartialEq, Eq, IntoPrimitive)]
+#[repr(u8)]
+pub enum TakerStopReason {
+    /// No more valid orders on the other side
+    BookExhausted = 0,
+    /// The best opposing order is beyond the order's limit price
+    PriceLimit = 1,
+    /// The order's max quote lots, including fees, are used up
+    QuoteLimit = 2,
+    /// The order reached the limit of matched orders
+    MatchLimit = 3,
+    /// The best opposing order is the taker's own with `SelfTradeBehavior::AbortTransaction`
+    SelfTrade = 4,
+}
+
+/// Outcome of a taker order that didn't fully fill and didn't post the rest,
+/// including orders failing because of it, so their logs explain the failure
+#[event]
+pub struct TakerExecutionLog {
+    pub market: Pubkey,
+    pub side: u8,
+    /// Open orders account placing the order, the signer's wallet for take orders
+    pub owner: Pubkey,
+    pub client_order_id: u64,
+    pub max_base_lots: i64,
+    pub base_lots_filled: i64,
+    pub quote_lots_filled: i64,
+    /// 0 when nothing filled
+    pub average_price_lots: i64,
+    /// See `TakerStopReason`
+    pub reason: u8,
+    /// Whether the transaction fails because of it
+    pub aborted: bool,
+}
+
 #[event]
 pub struct SetDelegateLog {
     pub open_orders_account: Pubkey,
@@ -91,6 +290,37 @@ pub struct SettleFundsLog {
     pub referrer: Option<Pubkey>,
 }
 
+#[event]
+pub struct ClaimRebatesLog {
+    pub open_orders_account: Pubkey,
+    pub maker_rebate: u64,
+    pub referrer_rebate: u64,
+    pub receiver: Pubkey,
+}
+
+#[event]
+pub struct ConsumeEventsRewardLog {
+    pub market: Pubkey,
+    pub cranker: Pubkey,
+    pub events_consumed: u64,
+    pub reward: u64,
+}
+
+/// Events `consume_events` left on the heap because their open orders account
+/// wasn't passed
+#[event]
+pub struct ConsumeEventsSkippedLog {
+    pub market: Pubkey,
+    pub events_skipped: u8,
+    pub missing_open_orders_accounts: Vec<Pubkey>,
+}
+
+#[event]
+pub struct SettleExpiredMarketLog {
+    pub market: Pubkey,
+    pub settlement_price_lots: i64,
+}
+
 #[event]
 pub struct SweepFeesLog {
     pub market: Pubkey,
@@ -98,6 +328,22 @@ pub struct SweepFeesLog {
     pub receiver: Pubkey,
 }
 
+#[event]
+pub struct MakerRewardsClaimLog {
+    pub market: Pubkey,
+    pub open_orders_account: Pubkey,
+    pub amount: u64,
+    pub receiver: Pubkey,
+}
+
+#[event]
+pub struct TakerRebatesClaimLog {
+    pub market: Pubkey,
+    pub open_orders_account: Pubkey,
+    pub amount: u64,
+    pub receiver: Pubkey,
+}
+
 #[event]
 pub struct OpenOrdersPositionLog {
     pub owner: Pubkey,
@@ -118,3 +364,51 @@ pub struct OpenOrdersPositionLog {
     /// Cumulative taker volume in quote native units (display only)
     pub taker_volume: u128,
 }
+
+#[event]
+pub struct MarketInvariantsLog {
+    pub market: Pubkey,
+    pub open_orders_accounts: u32,
+    pub pending_fills: u32,
+    pub base_vault: u64,
+    pub base_deposit_total: u64,
+    /// Deposits not owed to the passed accounts, zero if all the market's accounts
+    /// were passed and negative if they are owed more than deposited
+    pub base_unaccounted: i128,
+    pub quote_vault: u64,
+    pub quote_deposit_total: u64,
+    pub fees_available: u64,
+    /// Like `base_unaccounted`, the available fees being owed to the market
+    pub quote_unaccounted: i128,
+}
+
+#[event]
+pub struct ExecuteTriggerLog {
+    pub market: Pubkey,
+    pub open_orders_account: Pubkey,
+    pub trigger_id: u64,
+    pub keeper: Pubkey,
+    /// Id of the order left on the book, if any
+    pub order_id: Option<u128>,
+}
+
+#[event]
+pub struct HeartbeatBountyLog {
+    pub open_orders_account: Pubkey,
+    pub keeper: Pubkey,
+    pub bounty: u64,
+}
+
+#[event]
+pub struct RelayerFeeLog {
+    pub open_orders_account: Pubkey,
+    pub relayer: Pubkey,
+    pub mint: Pubkey,
+    pub relayer_fee: u64,
+}
+
+#[event]
+pub struct SweepQuoteDustLog {
+    pub market: Pubkey,
+    pub amount: u64,
+}
@@ -0,0 +1,161 @@
+//! Direct settlement of makers while a taker order executes.
+//!
+//! Fills against makers whose open orders account is among the remaining
+//! accounts are applied to the account right away, no fill event is queued for
+//! them. When the maker opted in with `set_direct_settlement` and a token account
+//! of the maker's owner for the token the taker pays with is among the remaining
+//! accounts too, the maker's proceeds go from the taker's token account straight
+//! to it: they never land in the vault and the maker doesn't need `settle_funds`
+//! for them.
+//!
+//! Makers with a risk program, see [`health_check`](crate::health_check), keep
+//! their proceeds on the account where the risk program accounts for them, and
+//! makers with a [`settle_receiver`](crate::settle_receiver) for it to get them
+//! through `settle_funds`.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, TokenAccount};
+
+use crate::accounts_zerocopy::LoadMutZeroCopyRef;
+use crate::logs::{emit_stack, SettleFundsLog};
+use crate::math;
+use crate::state::{find_position_extension, FillEvent, Market, OpenOrdersAccount, Side};
+use crate::token_utils::token_transfer;
+
+/// What the maker of `fill` was credited in the token the taker pays with before
+/// maker fees, see `OpenOrdersAccount::execute_maker`, the maker rebate included in
+/// it and the maker fees, which designated market makers don't pay.
+fn maker_proceeds(market: &Market, fill: &FillEvent) -> Result<(u64, u64, u64)> {
+    Ok(match fill.taker_side() {
+        Side::Bid => {
+            let quote_native =
+                math::quote_native(fill.quantity, fill.price, market.quote_lot_size)?;
+            let rebate = market.maker_rebate_floor(quote_native);
+            (
+                quote_native + rebate,
+                rebate,
+                market.maker_fees_floor(quote_native),
+            )
+        }
+        Side::Ask => (
+            math::lots_to_native(fill.quantity, market.base_lot_size)?,
+            0,
+            0,
+        ),
+    })
+}
+
+/// Pays the makers of `processed_fills` that have a token account among
+/// `remaining_accs` out of the taker's `deposit_amount`, from `taker_token_account`.
+///
+/// Makers are paid in full or not at all, in the order of their first fill, while
+/// the deposit covers them. Returns the total paid, which the caller doesn't
+/// transfer to the vault.
+#[allow(clippy::too_many_arguments)]
+pub fn settle_makers_directly<'info>(
+    market: &mut Market,
+    taker_side: Side,
+    processed_fills: &[FillEvent],
+    deposit_amount: u64,
+    taker_token_account: &Account<'info, TokenAccount>,
+    authority: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    remaining_accs: &[AccountInfo<'info>],
+) -> Result<u64> {
+    let mut proceeds: Vec<(Pubkey, u64, u64, u64)> = vec![];
+    for fill in processed_fills
+        .iter()
+        .filter(|fill| fill.maker != fill.taker)
+    {
+        let (amount, rebate, fees) = maker_proceeds(market, fill)?;
+        match proceeds
+            .iter_mut()
+            .find(|(maker, _, _, _)| *maker == fill.maker)
+        {
+            Some((_, total, total_rebate, total_fees)) => {
+                *total += amount;
+                *total_rebate += rebate;
+                *total_fees += fees;
+            }
+            None => proceeds.push((fill.maker, amount, rebate, fees)),
+        }
+    }
+
+    let mut paid = 0;
+    for (maker, amount, rebate, fees) in proceeds {
+        let maker_ai = match remaining_accs.iter().find(|ai| ai.key == &maker) {
+            Some(ai) => ai,
+            None => continue,
+        };
+        let mut maker_account = maker_ai.load_mut::<OpenOrdersAccount>()?;
+        if !maker_account.accepts_direct_settlement() {
+            continue;
+        }
+        if maker_account.has_position_extension() {
+            match find_position_extension(&maker, remaining_accs)? {
+                Some(position_ext)
+                    if position_ext.risk_program.is_none()
+                        && position_ext.settle_receiver.is_none() => {}
+                _ => continue,
+            }
+        }
+        let amount = if maker_account.is_dmm() {
+            amount
+        } else {
+            amount - fees
+        };
+        if paid + amount > deposit_amount {
+            continue;
+        }
+        let maker_token_ai = remaining_accs.iter().find(|ai| {
+            ai.owner == &token::ID
+                && TokenAccount::try_deserialize(&mut &ai.data.borrow()[..]).map_or(
+                    false,
+                    |token_account| {
+                        token_account.mint == taker_token_account.mint
+                            && token_account.owner == maker_account.owner
+                    },
+                )
+        });
+        let maker_token_ai = match maker_token_ai {
+            Some(ai) => ai,
+            None => continue,
+        };
+
+        let position = &mut maker_account.position;
+        let (base_native, quote_native) = match taker_side {
+            Side::Bid => {
+                position.quote_free_native -= amount;
+                market.quote_free_native_total -= amount as i64;
+                // the rebate left with the proceeds, claim_rebates must not pay it again
+                position.maker_rebates_available =
+                    position.maker_rebates_available.saturating_sub(rebate);
+                (0, amount)
+            }
+            Side::Ask => {
+                position.base_free_native -= amount;
+                market.base_free_native_total -= amount as i64;
+                (amount, 0)
+            }
+        };
+
+        token_transfer(
+            amount,
+            token_program,
+            &taker_token_account.to_account_info(),
+            maker_token_ai,
+            authority,
+        )?;
+        paid += amount;
+
+        emit_stack(SettleFundsLog {
+            open_orders_account: maker,
+            base_native,
+            quote_native,
+            referrer_rebate: 0,
+            referrer: None,
+        });
+    }
+
+    Ok(paid)
+}
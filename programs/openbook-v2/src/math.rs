@@ -0,0 +1,117 @@
+//! Checked arithmetic for lots, native amounts and fees.
+//!
+//! Orders are bounded by `Market::max_base_lots` and `Market::max_quote_lots` when
+//! they are placed, so none of these overflow on a consistent market. They still
+//! check every step: should an amount ever be out of range, the instruction fails
+//! with `MathOverflow` instead of wrapping a balance with an `as` cast.
+//!
+//! Fees are in units of `1 / FEES_SCALE_FACTOR` and bounded by it, so a fee is never
+//! larger than the amount it's charged on and fee computations can't overflow.
+
+use anchor_lang::prelude::*;
+use std::convert::TryFrom;
+
+use crate::error::OpenBookError;
+use crate::state::FEES_SCALE_FACTOR;
+
+fn to_u64(amount: i128) -> Result<u64> {
+    u64::try_from(amount).map_err(|_| error!(OpenBookError::MathOverflow))
+}
+
+fn to_i64(amount: i128) -> Result<i64> {
+    i64::try_from(amount).map_err(|_| error!(OpenBookError::MathOverflow))
+}
+
+/// Quote lots of `base_lots` at `price_lots`
+pub fn quote_lots(base_lots: i64, price_lots: i64) -> Result<i64> {
+    base_lots
+        .checked_mul(price_lots)
+        .ok_or_else(|| error!(OpenBookError::MathOverflow))
+}
+
+/// Native amount of `lots` lots of `lot_size`, fails on negative amounts
+pub fn lots_to_native(lots: i64, lot_size: i64) -> Result<u64> {
+    to_u64(i128::from(lots) * i128::from(lot_size))
+}
+
+/// Native quote amount of `base_lots` at `price_lots`
+pub fn quote_native(base_lots: i64, price_lots: i64, quote_lot_size: i64) -> Result<u64> {
+    let quote_lots = i128::from(base_lots) * i128::from(price_lots);
+    quote_lots
+        .checked_mul(i128::from(quote_lot_size))
+        .ok_or_else(|| error!(OpenBookError::MathOverflow))
+        .and_then(to_u64)
+}
+
+/// Lots needed to cover `native`, rounded up
+pub fn native_to_lots_ceil(native: u64, lot_size: i64) -> Result<i64> {
+    let lot_size = i128::from(lot_size);
+    to_i64((i128::from(native) + lot_size - 1) / lot_size)
+}
+
+pub fn fees_floor(amount: i128, fee: i64) -> i128 {
+    amount * i128::from(fee) / FEES_SCALE_FACTOR
+}
+
+pub fn fees_ceil(amount: i128, fee: i64) -> i128 {
+    (amount * i128::from(fee) + (FEES_SCALE_FACTOR - 1)) / FEES_SCALE_FACTOR
+}
+
+/// Largest amount that, with a fee of `fee` on top, doesn't exceed `amount_including_fees`
+pub fn amount_without_fees(amount_including_fees: i128, fee: i64) -> i128 {
+    amount_including_fees * FEES_SCALE_FACTOR / (FEES_SCALE_FACTOR + i128::from(fee))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::IsAnchorErrorWithCode;
+
+    fn is_overflow<T>(result: Result<T>) -> bool {
+        result.is_anchor_error_with_code(OpenBookError::MathOverflow.into())
+    }
+
+    #[test]
+    fn test_lot_conversions() {
+        assert_eq!(quote_lots(3, 7).unwrap(), 21);
+        assert_eq!(lots_to_native(3, 100).unwrap(), 300);
+        assert_eq!(quote_native(3, 7, 10).unwrap(), 210);
+        assert_eq!(native_to_lots_ceil(0, 10).unwrap(), 0);
+        assert_eq!(native_to_lots_ceil(15, 10).unwrap(), 2);
+        assert_eq!(native_to_lots_ceil(20, 10).unwrap(), 2);
+
+        // the products fit in an u64 even when they don't fit in an i64
+        assert_eq!(lots_to_native(i64::MAX, 2).unwrap(), 2 * i64::MAX as u64);
+        assert_eq!(quote_native(i64::MAX, 1, 2).unwrap(), 2 * i64::MAX as u64);
+    }
+
+    #[test]
+    fn test_lot_conversions_overflow() {
+        assert!(is_overflow(quote_lots(i64::MAX, 2)));
+        assert!(is_overflow(lots_to_native(i64::MAX, 3)));
+        assert!(is_overflow(lots_to_native(-1, 10)));
+        assert!(is_overflow(quote_native(i64::MAX, i64::MAX, i64::MAX)));
+        assert!(is_overflow(quote_native(1, -1, 10)));
+        assert!(is_overflow(native_to_lots_ceil(u64::MAX, 1)));
+    }
+
+    #[test]
+    fn test_fees() {
+        // 10 bps
+        let fee = 1_000;
+        assert_eq!(fees_floor(1_999, fee), 1);
+        assert_eq!(fees_ceil(1_999, fee), 2);
+        assert_eq!(fees_floor(2_000, fee), 2);
+        assert_eq!(fees_ceil(2_000, fee), 2);
+        assert_eq!(fees_floor(1_999, -fee), -1);
+
+        assert_eq!(amount_without_fees(1_001, fee), 1_000);
+        assert_eq!(amount_without_fees(1_000, fee), 999);
+
+        // fees never exceed the amount, even at the largest fee
+        let amount = i128::from(u64::MAX);
+        let max_fee = FEES_SCALE_FACTOR as i64;
+        assert_eq!(fees_floor(amount, max_fee), amount);
+        assert_eq!(fees_ceil(amount, max_fee), amount);
+    }
+}
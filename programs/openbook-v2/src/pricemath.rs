@@ -0,0 +1,136 @@
+//! Conversions between lots, native units and UI amounts.
+//!
+//! - native: integer amount of the token's smallest unit
+//! - lots: integer multiple of the market's `base_lot_size` / `quote_lot_size`
+//! - UI: decimal amount as shown to users, i.e. native / 10^decimals
+//!
+//! A price in lots is the number of quote lots per base lot, a UI price the amount
+//! of quote tokens per base token.
+//!
+//! UI amounts are rounded to the nearest native unit first, so that e.g. 4.35 with
+//! 2 decimals is 435 and not 434. Sizes are then rounded down to whole lots,
+//! prices to the nearest price lot.
+
+fn pow10(decimals: u8) -> f64 {
+    10f64.powi(decimals.into())
+}
+
+pub fn ui_to_native(amount: f64, decimals: u8) -> i64 {
+    (amount * pow10(decimals)).round() as i64
+}
+
+pub fn native_to_ui(native: i64, decimals: u8) -> f64 {
+    native as f64 / pow10(decimals)
+}
+
+/// Rounds down to whole lots
+pub fn native_to_lots(native: i64, lot_size: i64) -> i64 {
+    native / lot_size
+}
+
+pub fn lots_to_native(lots: i64, lot_size: i64) -> i64 {
+    lots * lot_size
+}
+
+/// Rounds down to whole lots
+pub fn size_ui_to_lots(size: f64, decimals: u8, lot_size: i64) -> i64 {
+    native_to_lots(ui_to_native(size, decimals), lot_size)
+}
+
+pub fn size_lots_to_ui(lots: i64, decimals: u8, lot_size: i64) -> f64 {
+    native_to_ui(lots_to_native(lots, lot_size), decimals)
+}
+
+/// Rounds to the nearest price lot
+pub fn price_ui_to_lots(
+    price: f64,
+    base_decimals: u8,
+    quote_decimals: u8,
+    base_lot_size: i64,
+    quote_lot_size: i64,
+) -> i64 {
+    let native_price = price * pow10(quote_decimals) / pow10(base_decimals);
+    (native_price * base_lot_size as f64 / quote_lot_size as f64).round() as i64
+}
+
+pub fn price_lots_to_ui(
+    price_lots: i64,
+    base_decimals: u8,
+    quote_decimals: u8,
+    base_lot_size: i64,
+    quote_lot_size: i64,
+) -> f64 {
+    let native_price = price_lots as f64 * quote_lot_size as f64 / base_lot_size as f64;
+    native_price * pow10(base_decimals) / pow10(quote_decimals)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_native_ui() {
+        assert_eq!(ui_to_native(1.0, 6), 1_000_000);
+        assert_eq!(ui_to_native(4.35, 2), 435);
+        assert_eq!(ui_to_native(0.000_001, 6), 1);
+        assert_eq!(ui_to_native(0.000_000_4, 6), 0);
+        assert_eq!(ui_to_native(-1.5, 9), -1_500_000_000);
+        assert_eq!(ui_to_native(123.0, 0), 123);
+
+        assert_eq!(native_to_ui(435, 2), 4.35);
+        assert_eq!(native_to_ui(1, 9), 0.000_000_001);
+
+        for decimals in 0..=9 {
+            for native in [0, 1, 7, 99, 12_345, 1_000_000_007] {
+                assert_eq!(
+                    ui_to_native(native_to_ui(native, decimals), decimals),
+                    native
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_lots() {
+        assert_eq!(native_to_lots(1_999, 1_000), 1);
+        assert_eq!(native_to_lots(2_000, 1_000), 2);
+        assert_eq!(lots_to_native(3, 1_000), 3_000);
+
+        assert_eq!(size_ui_to_lots(4.35, 2, 1), 435);
+        assert_eq!(size_ui_to_lots(1.234_567, 6, 1_000), 1_234);
+        assert_eq!(size_lots_to_ui(1_234, 6, 1_000), 1.234);
+
+        for decimals in 0..=9 {
+            for lot_size in [1, 10, 100, 1_000, 1_000_000] {
+                for lots in [0, 1, 5, 250, 1_000_001] {
+                    let ui = size_lots_to_ui(lots, decimals, lot_size);
+                    assert_eq!(size_ui_to_lots(ui, decimals, lot_size), lots);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_price() {
+        // SOL (9 decimals) / USDC (6 decimals), 0.001 SOL and 0.0001 USDC lots:
+        // 1 price lot is 0.1 USDC per SOL
+        let (bd, qd, bl, ql) = (9, 6, 1_000_000, 100);
+        assert_eq!(price_ui_to_lots(20.0, bd, qd, bl, ql), 200);
+        assert_eq!(price_ui_to_lots(20.04, bd, qd, bl, ql), 200);
+        assert_eq!(price_ui_to_lots(20.06, bd, qd, bl, ql), 201);
+        assert_eq!(price_lots_to_ui(201, bd, qd, bl, ql), 20.1);
+
+        // base with fewer decimals than quote
+        assert_eq!(price_ui_to_lots(1.23, 2, 6, 1, 1), 12_300);
+        assert_eq!(price_lots_to_ui(12_300, 2, 6, 1, 1), 1.23);
+
+        for (bd, qd) in [(0, 0), (6, 6), (9, 6), (6, 9), (2, 8)] {
+            for (bl, ql) in [(1, 1), (100, 1), (1, 100), (1_000_000, 100)] {
+                for price_lots in [1, 2, 99, 1_234, 1_000_000] {
+                    let ui = price_lots_to_ui(price_lots, bd, qd, bl, ql);
+                    assert_eq!(price_ui_to_lots(ui, bd, qd, bl, ql), price_lots);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,147 @@
+//! Interface of the program an [`OpenOrdersAccount`](crate::state::OpenOrdersAccount)
+//! can register to receive its settled funds, e.g. a lending vault depositing them
+//! for the owner of the account.
+//!
+//! The receiver program is kept in the account's
+//! [`PositionExtension`](crate::state::PositionExtension). Once registered,
+//! `settle_funds` needs the extension, the receiver program and the market's
+//! settle receiver authority among its remaining accounts, the latter two in this
+//! order, followed by any accounts the receiver needs to take the funds in.
+//!
+//! The tokens are withdrawn to the base and quote token accounts given to
+//! `settle_funds`, which have to be controlled by the receiver, and the receiver
+//! is CPI'd with an anchor-style instruction: `sha256("global:on_settle")[..8]`
+//! followed by the borsh-serialized [`OnSettleArgs`]. The accounts passed are:
+//!
+//! 0. the settle receiver authority, a signer PDA of seeds
+//!    `[SETTLE_RECEIVER_AUTHORITY_SEED, market]` that signs nothing else, so the
+//!    receiver can check the call comes from the market it claims
+//! 1. the base token account the funds were settled to, writable
+//! 2. the quote token account the funds were settled to, writable
+//! 3. the remaining accounts after the authority, writable as passed to
+//!    `settle_funds` but never signing
+//!
+//! The receiver then moves the funds into wherever they are deposited, crediting
+//! the owner. Failing the instruction fails the settlement. The market authority
+//! and vaults are never passed on.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::invoke_signed;
+
+use crate::error::OpenBookError;
+
+pub const SETTLE_RECEIVER_AUTHORITY_SEED: &[u8] = b"SettleReceiverAuthority";
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct OnSettleArgs {
+    pub market: Pubkey,
+    pub open_orders_account: Pubkey,
+    /// Owner of the open orders account, who the funds are settled for
+    pub owner: Pubkey,
+    pub base_native: u64,
+    pub quote_native: u64,
+}
+
+pub fn on_settle_discriminator() -> [u8; 8] {
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash(b"global:on_settle").to_bytes()[..8]);
+    discriminator
+}
+
+/// Settle receiver program, authority and accounts `settle_funds` was given, with
+/// the authority's bump.
+pub struct SettleReceiver<'a, 'info> {
+    pub program: &'a AccountInfo<'info>,
+    pub authority: &'a AccountInfo<'info>,
+    pub authority_bump: u8,
+    pub accounts: &'a [AccountInfo<'info>],
+}
+
+/// The accounts for invoking `settle_receiver_program` among `remaining_accs`,
+/// checking the authority passed along with it.
+pub fn settle_receiver_accounts<'a, 'info>(
+    market_pk: &Pubkey,
+    settle_receiver_program: Pubkey,
+    remaining_accs: &'a [AccountInfo<'info>],
+) -> Result<SettleReceiver<'a, 'info>> {
+    let index = remaining_accs
+        .iter()
+        .position(|ai| ai.key == &settle_receiver_program)
+        .ok_or_else(|| error!(OpenBookError::MissingSettleReceiverAccount))?;
+    let authority_ai = remaining_accs
+        .get(index + 1)
+        .ok_or_else(|| error!(OpenBookError::InvalidSettleReceiverAuthority))?;
+
+    let (authority, authority_bump) = Pubkey::find_program_address(
+        &[SETTLE_RECEIVER_AUTHORITY_SEED, market_pk.as_ref()],
+        &crate::id(),
+    );
+    require_keys_eq!(
+        authority_ai.key(),
+        authority,
+        OpenBookError::InvalidSettleReceiverAuthority
+    );
+
+    Ok(SettleReceiver {
+        program: &remaining_accs[index],
+        authority: authority_ai,
+        authority_bump,
+        accounts: &remaining_accs[index + 2..],
+    })
+}
+
+/// Hand the funds just transferred to `base_account` and `quote_account` over to
+/// the settle receiver, signing with the market's settle receiver authority. None
+/// of the accounts passed on can be any of `market_accounts`, the market authority
+/// and vaults.
+pub fn invoke_settle_receiver<'info>(
+    receiver: &SettleReceiver<'_, 'info>,
+    base_account: &AccountInfo<'info>,
+    quote_account: &AccountInfo<'info>,
+    market_accounts: &[Pubkey],
+    args: OnSettleArgs,
+) -> Result<()> {
+    let mut account_infos = vec![
+        receiver.authority.clone(),
+        base_account.clone(),
+        quote_account.clone(),
+    ];
+    account_infos.extend(receiver.accounts.iter().cloned());
+    require!(
+        account_infos
+            .iter()
+            .all(|ai| !market_accounts.contains(ai.key)),
+        OpenBookError::InvalidSettleReceiverAccount
+    );
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(receiver.authority.key(), true),
+        AccountMeta::new(base_account.key(), false),
+        AccountMeta::new(quote_account.key(), false),
+    ];
+    accounts.extend(receiver.accounts.iter().map(|ai| AccountMeta {
+        pubkey: ai.key(),
+        is_signer: false,
+        is_writable: ai.is_writable,
+    }));
+
+    let mut data = on_settle_discriminator().to_vec();
+    args.serialize(&mut data)?;
+
+    let ix = Instruction {
+        program_id: receiver.program.key(),
+        accounts,
+        data,
+    };
+    account_infos.push(receiver.program.clone());
+    let seeds = [
+        SETTLE_RECEIVER_AUTHORITY_SEED,
+        args.market.as_ref(),
+        &[receiver.authority_bump],
+    ];
+    invoke_signed(&ix, &account_infos, &[&seeds])?;
+
+    Ok(())
+}
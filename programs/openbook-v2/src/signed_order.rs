@@ -0,0 +1,80 @@
+//! Orders signed off-chain by the owner or delegate of an open orders account,
+//! placed on their behalf by anyone with `place_signed_order`, e.g. a relayer
+//! or the quoting side of an RFQ.
+//!
+//! The signed message is the borsh-serialized [`SignedOrder`]. Its ed25519
+//! signature is verified by the native ed25519 program, in the instruction right
+//! before `place_signed_order`, which holds a single signature with the public
+//! key and the message in its own data, as `new_ed25519_instruction` builds it.
+//! The order is funded from the free funds of the open orders account only, as
+//! no token account signs for it.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+
+use crate::error::OpenBookError;
+use crate::PlaceOrderArgs;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Copy, Clone)]
+pub struct SignedOrder {
+    pub market: Pubkey,
+    pub open_orders_account: Pubkey,
+    /// Has to exceed the nonce of the last signed order placed for the account,
+    /// so every signed order is placed once at most
+    pub nonce: u64,
+    /// Last moment the order can be placed, 0 for never
+    pub valid_until_ts: u64,
+    pub order: PlaceOrderArgs,
+}
+
+// Layout of the ed25519 program instruction data, see solana_sdk::ed25519_instruction
+const SIGNATURE_OFFSETS_START: usize = 2;
+const SIGNATURE_OFFSETS_SERIALIZED_SIZE: usize = 14;
+const PUBKEY_SERIALIZED_SIZE: usize = 32;
+
+/// The key that signed `message`, as verified by the ed25519 program
+/// instruction preceding the current one.
+pub fn ed25519_signer(instructions_sysvar: &AccountInfo, message: &[u8]) -> Result<Pubkey> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    require_gt!(current_index, 0, OpenBookError::InvalidSignedOrderSignature);
+    let ix = load_instruction_at_checked((current_index - 1).into(), instructions_sysvar)?;
+    require_keys_eq!(
+        ix.program_id,
+        ed25519_program::ID,
+        OpenBookError::InvalidSignedOrderSignature
+    );
+
+    let data = &ix.data;
+    require!(
+        data.len() >= SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_SERIALIZED_SIZE && data[0] == 1,
+        OpenBookError::InvalidSignedOrderSignature
+    );
+    let offsets: Vec<usize> = data
+        [SIGNATURE_OFFSETS_START..SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_SERIALIZED_SIZE]
+        .chunks_exact(2)
+        .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]).into())
+        .collect();
+    let signature_ix_index = offsets[1];
+    let (pubkey_offset, pubkey_ix_index) = (offsets[2], offsets[3]);
+    let (message_offset, message_size, message_ix_index) = (offsets[4], offsets[5], offsets[6]);
+
+    // the signature, key and message verified have to be the ones in this data,
+    // not in some other instruction
+    let this_ix = usize::from(u16::MAX);
+    require!(
+        signature_ix_index == this_ix && pubkey_ix_index == this_ix && message_ix_index == this_ix,
+        OpenBookError::InvalidSignedOrderSignature
+    );
+    require!(
+        data.get(message_offset..message_offset + message_size) == Some(message),
+        OpenBookError::InvalidSignedOrderSignature
+    );
+    let pubkey = data
+        .get(pubkey_offset..pubkey_offset + PUBKEY_SERIALIZED_SIZE)
+        .ok_or_else(|| error!(OpenBookError::InvalidSignedOrderSignature))?;
+
+    Ok(Pubkey::try_from(pubkey).unwrap())
+}
@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+
+use super::{BookLevel, Side};
+
+pub const DEPTH_SNAPSHOT_LEVELS: usize = 32;
+
+/// Aggregated top levels of a market's book, written by `snapshot_depth`, so other
+/// programs can reason about the available liquidity without walking the book.
+///
+/// Each owner has its own snapshot per market, anyone can refresh it. Check `slot`
+/// before trusting it: a snapshot is only as fresh as its last refresh.
+#[account(zero_copy)]
+pub struct DepthSnapshot {
+    pub market: Pubkey,
+    pub owner: Pubkey,
+    /// Slot of the last refresh, 0 if never refreshed
+    pub slot: u64,
+    pub timestamp: u64,
+    /// Number of levels in use in `bids` and `asks`
+    pub bids_len: u8,
+    pub asks_len: u8,
+    pub bump: u8,
+    pub padding: [u8; 5],
+    pub reserved: [u8; 64],
+    pub bids: [DepthLevel; DEPTH_SNAPSHOT_LEVELS],
+    pub asks: [DepthLevel; DEPTH_SNAPSHOT_LEVELS],
+}
+
+#[zero_copy]
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct DepthLevel {
+    pub price_lots: i64,
+    /// Base lots resting at `price_lots`
+    pub quantity: i64,
+    /// Base lots resting at `price_lots` or better
+    pub cumulative_quantity: i64,
+}
+
+impl From<BookLevel> for DepthLevel {
+    fn from(level: BookLevel) -> Self {
+        Self {
+            price_lots: level.price_lots,
+            quantity: level.quantity,
+            cumulative_quantity: level.cumulative_quantity,
+        }
+    }
+}
+
+impl DepthSnapshot {
+    /// Replace the levels of `side`, at most [`DEPTH_SNAPSHOT_LEVELS`] are kept
+    pub fn set_levels(&mut self, side: Side, levels: &[BookLevel]) {
+        let (snapshot_levels, len) = match side {
+            Side::Bid => (&mut self.bids, &mut self.bids_len),
+            Side::Ask => (&mut self.asks, &mut self.asks_len),
+        };
+        let count = levels.len().min(DEPTH_SNAPSHOT_LEVELS);
+        for (i, snapshot_level) in snapshot_levels.iter_mut().enumerate() {
+            *snapshot_level = levels.get(i).copied().map(Into::into).unwrap_or_default();
+        }
+        *len = count as u8;
+    }
+
+    /// Levels of `side` in use, closest to the spread first
+    pub fn levels(&self, side: Side) -> &[DepthLevel] {
+        match side {
+            Side::Bid => &self.bids[..self.bids_len as usize],
+            Side::Ask => &self.asks[..self.asks_len as usize],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytemuck::Zeroable;
+
+    fn level(price_lots: i64, quantity: i64, cumulative_quantity: i64) -> BookLevel {
+        BookLevel {
+            price_lots,
+            quantity,
+            cumulative_quantity,
+        }
+    }
+
+    #[test]
+    fn test_set_levels() {
+        let mut snapshot = DepthSnapshot::zeroed();
+        snapshot.set_levels(Side::Bid, &[level(10, 1, 1), level(9, 2, 3)]);
+        snapshot.set_levels(Side::Ask, &[level(11, 5, 5)]);
+        assert_eq!(snapshot.levels(Side::Bid).len(), 2);
+        assert_eq!(snapshot.levels(Side::Bid)[1], level(9, 2, 3).into());
+        assert_eq!(snapshot.levels(Side::Ask), &[level(11, 5, 5).into()]);
+
+        // stale levels are cleared
+        snapshot.set_levels(Side::Bid, &[level(8, 4, 4)]);
+        assert_eq!(snapshot.levels(Side::Bid), &[level(8, 4, 4).into()]);
+        assert_eq!(snapshot.bids[1], DepthLevel::default());
+
+        let many: Vec<BookLevel> = (0..DEPTH_SNAPSHOT_LEVELS as i64 + 5)
+            .map(|i| level(100 + i, 1, i + 1))
+            .collect();
+        snapshot.set_levels(Side::Ask, &many);
+        assert_eq!(snapshot.levels(Side::Ask).len(), DEPTH_SNAPSHOT_LEVELS);
+    }
+}
@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+
+use super::QuoteQualityEpoch;
+use crate::error::OpenBookError;
+
+pub const MAX_DMM_SLOTS: usize = 8;
+
+/// Per-market PDA of the designated market makers, managed by the collect fee
+/// admin with `set_dmm_slot`.
+///
+/// The slot's priority is copied to the open orders account, see
+/// `OpenOrdersAccount::dmm_priority`, and from there to the account's orders. Their
+/// orders aren't booted from a full book side by orders of a lower priority, and
+/// the accounts are exempt from the order rate limit and the event heap penalty.
+/// They pay no maker fees, and as takers only pay the rebates of the makers they
+/// fill.
+/// In return they commit to quote a `min_uptime_bps` share of the time, measured
+/// by their [`QuoteQuality`](super::QuoteQuality); anyone can remove an account
+/// falling short with `enforce_dmm_obligation`.
+#[account(zero_copy)]
+pub struct DmmRegistry {
+    pub market: Pubkey,
+    pub slots: [DmmSlot; MAX_DMM_SLOTS],
+    pub bump: u8,
+    pub padding: [u8; 7],
+    pub reserved: [u8; 64],
+}
+
+#[zero_copy]
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct DmmSlot {
+    /// Zero for a free slot
+    pub open_orders_account: Pubkey,
+    /// Higher priorities win when booting orders from a full book side
+    pub priority: u8,
+    pub padding: u8,
+    /// Least uptime of a quote quality epoch to keep the slot
+    pub min_uptime_bps: u16,
+    pub padding2: [u8; 4],
+}
+
+impl DmmSlot {
+    pub fn is_free(&self) -> bool {
+        self.open_orders_account == Pubkey::default()
+    }
+
+    /// Whether `epoch` falls short of the slot's uptime commitment. Epochs
+    /// without samples don't count.
+    pub fn is_obligation_missed(&self, epoch: &QuoteQualityEpoch) -> bool {
+        epoch.seconds_sampled > 0 && epoch.uptime_bps() < u64::from(self.min_uptime_bps)
+    }
+}
+
+impl DmmRegistry {
+    pub fn find(&self, open_orders_account: &Pubkey) -> Option<&DmmSlot> {
+        self.slots
+            .iter()
+            .find(|slot| !slot.is_free() && slot.open_orders_account == *open_orders_account)
+    }
+
+    /// Assign `open_orders_account` to a slot, keeping its slot if it has one.
+    /// A zero priority frees the slot.
+    pub fn set(
+        &mut self,
+        open_orders_account: Pubkey,
+        priority: u8,
+        min_uptime_bps: u16,
+    ) -> Result<()> {
+        let existing = self
+            .slots
+            .iter()
+            .position(|slot| !slot.is_free() && slot.open_orders_account == open_orders_account);
+        if priority == 0 {
+            if let Some(index) = existing {
+                self.slots[index] = DmmSlot::default();
+            }
+            return Ok(());
+        }
+
+        let index = existing
+            .or_else(|| self.slots.iter().position(|slot| slot.is_free()))
+            .ok_or_else(|| error!(OpenBookError::DmmRegistryFull))?;
+        self.slots[index] = DmmSlot {
+            open_orders_account,
+            priority,
+            min_uptime_bps,
+            ..DmmSlot::default()
+        };
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytemuck::Zeroable;
+
+    #[test]
+    fn test_set() {
+        let mut registry = DmmRegistry::zeroed();
+        let accounts: Vec<Pubkey> = (0..=MAX_DMM_SLOTS).map(|_| Pubkey::new_unique()).collect();
+
+        for account in accounts.iter().take(MAX_DMM_SLOTS) {
+            registry.set(*account, 1, 5_000).unwrap();
+        }
+        assert!(registry.set(accounts[MAX_DMM_SLOTS], 1, 5_000).is_err());
+
+        // updating keeps the slot
+        registry.set(accounts[3], 2, 9_000).unwrap();
+        assert_eq!(registry.slots[3].priority, 2);
+        assert_eq!(registry.find(&accounts[3]).unwrap().min_uptime_bps, 9_000);
+
+        // removing frees the slot for another account
+        registry.set(accounts[3], 0, 0).unwrap();
+        assert!(registry.find(&accounts[3]).is_none());
+        registry.set(accounts[MAX_DMM_SLOTS], 1, 5_000).unwrap();
+        assert_eq!(
+            registry.slots[3].open_orders_account,
+            accounts[MAX_DMM_SLOTS]
+        );
+    }
+
+    #[test]
+    fn test_is_obligation_missed() {
+        let slot = DmmSlot {
+            min_uptime_bps: 8_000,
+            ..DmmSlot::default()
+        };
+        let epoch = |seconds_sampled, seconds_quoting| QuoteQualityEpoch {
+            seconds_sampled,
+            seconds_quoting,
+            ..QuoteQualityEpoch::default()
+        };
+        assert!(!slot.is_obligation_missed(&epoch(0, 0)));
+        assert!(!slot.is_obligation_missed(&epoch(100, 80)));
+        assert!(slot.is_obligation_missed(&epoch(100, 79)));
+    }
+}
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+/// Program a [`Market`](crate::state::Market)'s admin registered to be called with
+/// the fills processed by `consume_events_with_fill_callback`, see
+/// [`fill_callback`](crate::fill_callback).
+///
+/// PDA of seeds `[b"FillCallback", market]`.
+#[account]
+#[derive(Default)]
+pub struct FillCallback {
+    pub bump: u8,
+    pub market: Pubkey,
+    pub program: Option<Pubkey>,
+}
+
+impl FillCallback {
+    pub const SPACE: usize = 8 + 1 + 32 + (1 + 32);
+}
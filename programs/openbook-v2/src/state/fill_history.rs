@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+
+use super::FillEvent;
+
+pub const FILL_HISTORY_LEN: usize = 64;
+
+/// Optional per-market PDA keeping the last [`FILL_HISTORY_LEN`] fills, so
+/// clients and other programs can read recent trades without an indexer.
+///
+/// Fills are recorded while matching when the account is passed among the
+/// remaining accounts of the order placing instruction.
+#[account(zero_copy)]
+pub struct FillHistory {
+    pub market: Pubkey,
+    /// Total number of fills recorded, the latest one is at `(count - 1) % FILL_HISTORY_LEN`
+    pub count: u64,
+    pub bump: u8,
+    pub padding: [u8; 7],
+    pub reserved: [u8; 64],
+    pub fills: [FillRecord; FILL_HISTORY_LEN],
+}
+
+#[zero_copy]
+#[derive(Debug, Default)]
+pub struct FillRecord {
+    pub price: i64,    // lots
+    pub quantity: i64, // number of base lots
+    pub timestamp: u64,
+    pub event_seq_num: u64,
+    pub maker: Pubkey,
+    pub taker: Pubkey,
+    pub taker_side: u8, // Side
+    pub padding: [u8; 7],
+}
+
+impl FillHistory {
+    pub fn push(&mut self, fill: &FillEvent) {
+        let index = self.count as usize % FILL_HISTORY_LEN;
+        self.fills[index] = FillRecord {
+            price: fill.price,
+            quantity: fill.quantity,
+            timestamp: fill.timestamp,
+            event_seq_num: fill.event_seq_num,
+            maker: fill.maker,
+            taker: fill.taker,
+            taker_side: fill.taker_side,
+            padding: Default::default(),
+        };
+        self.count += 1;
+    }
+
+    pub fn len(&self) -> usize {
+        std::cmp::min(self.count as usize, FILL_HISTORY_LEN)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Recorded fills, most recent first
+    pub fn iter_recent(&self) -> impl Iterator<Item = &FillRecord> {
+        let count = self.count as usize;
+        (1..=self.len()).map(move |i| &self.fills[(count - i) % FILL_HISTORY_LEN])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::Side;
+    use bytemuck::Zeroable;
+
+    fn fill(event_seq_num: u64) -> FillEvent {
+        FillEvent::new(
+            Side::Bid,
+            false,
+            0,
+            event_seq_num,
+            0,
+            Pubkey::new_unique(),
+            0,
+            0,
+            Pubkey::new_unique(),
+            0,
+            100,
+            -1,
+            1,
+            event_seq_num,
+        )
+    }
+
+    #[test]
+    fn test_fill_history_wraps_around() {
+        let mut history = FillHistory::zeroed();
+        assert!(history.is_empty());
+        assert_eq!(history.iter_recent().count(), 0);
+
+        for seq_num in 1..=3 {
+            history.push(&fill(seq_num));
+        }
+        assert_eq!(history.len(), 3);
+        let recent: Vec<u64> = history.iter_recent().map(|f| f.event_seq_num).collect();
+        assert_eq!(recent, vec![3, 2, 1]);
+
+        let total = FILL_HISTORY_LEN as u64 + 10;
+        for seq_num in 4..=total {
+            history.push(&fill(seq_num));
+        }
+        assert_eq!(history.len(), FILL_HISTORY_LEN);
+        assert_eq!(history.count, total);
+        let recent: Vec<u64> = history.iter_recent().map(|f| f.event_seq_num).collect();
+        let expected: Vec<u64> = (11..=total).rev().collect();
+        assert_eq!(recent, expected);
+    }
+}
@@ -0,0 +1,234 @@
+//! Byte layout of the program's accounts.
+//!
+//! Existing accounts are read with whatever layout the deployed program has, so a
+//! field that moves or changes size corrupts them. New fields must take the place
+//! of reserved or padding bytes, and these offsets only change along with a
+//! migration of the existing accounts. Sizes exclude the 8 byte discriminator.
+
+use super::*;
+use memoffset::offset_of;
+use std::mem::size_of;
+
+macro_rules! assert_offsets {
+    ($type:ty { $($field:ident: $offset:expr),* $(,)? }) => {
+        $(
+            assert_eq!(
+                offset_of!($type, $field),
+                $offset,
+                "offset of {}::{}",
+                stringify!($type),
+                stringify!($field)
+            );
+        )*
+    };
+}
+
+#[test]
+fn market_layout() {
+    assert_eq!(size_of::<Market>(), 840);
+    assert_eq!(size_of::<OracleConfig>(), 88);
+    assert_offsets!(Market {
+        bump: 0,
+        base_decimals: 1,
+        quote_decimals: 2,
+        market_state: 3,
+        binary_outcome: 4,
+        registered: 5,
+        padding1: 6,
+        market_authority: 8,
+        time_expiry: 40,
+        collect_fee_admin: 48,
+        open_orders_admin: 80,
+        consume_events_admin: 112,
+        close_market_admin: 144,
+        name: 176,
+        bids: 192,
+        asks: 224,
+        event_heap: 256,
+        oracle_a: 288,
+        oracle_b: 320,
+        oracle_config: 352,
+        quote_lot_size: 440,
+        base_lot_size: 448,
+        seq_num: 456,
+        registration_time: 464,
+        maker_fee: 472,
+        taker_fee: 480,
+        fees_accrued: 488,
+        fees_to_referrers: 504,
+        referrer_rebates_accrued: 520,
+        fees_available: 528,
+        maker_volume: 536,
+        taker_volume_wo_oo: 552,
+        base_mint: 568,
+        quote_mint: 600,
+        market_base_vault: 632,
+        base_deposit_total: 664,
+        market_quote_vault: 672,
+        quote_deposit_total: 704,
+        consume_events_reward: 712,
+        event_heap_oldest_timestamp: 720,
+        event_heap_count: 728,
+        event_heap_capacity: 730,
+        padding2: 732,
+        event_seq_num: 736,
+        settlement_price_lots: 744,
+        creation_bond: 752,
+        maker_rewards_start_ts: 760,
+        asks_base_lots_total: 768,
+        bids_quote_lots_total: 776,
+        base_free_native_total: 784,
+        quote_free_native_total: 792,
+        order_rate_limit: 800,
+        order_rate_limit_window: 804,
+        min_order_notional: 808,
+        max_resting_orders: 816,
+        version: 817,
+        padding3: 818,
+        eviction_policy: 824,
+        taker_fee_rounding: 825,
+        eviction_band_bps: 826,
+        reserved: 828,
+    });
+}
+
+#[test]
+fn open_orders_account_layout() {
+    assert_eq!(size_of::<OpenOrdersAccount>(), 1256);
+    assert_offsets!(OpenOrdersAccount {
+        owner: 0,
+        market: 32,
+        name: 64,
+        delegate: 96,
+        account_num: 128,
+        bump: 132,
+        version: 133,
+        flags: 134,
+        dmm_priority: 135,
+        position: 136,
+        open_orders: 296,
+    });
+
+    assert_eq!(size_of::<Position>(), 160);
+    assert_offsets!(Position {
+        bids_base_lots: 0,
+        asks_base_lots: 8,
+        base_free_native: 16,
+        quote_free_native: 24,
+        locked_maker_fees: 32,
+        referrer_rebates_available: 40,
+        penalty_heap_count: 48,
+        maker_volume: 56,
+        taker_volume: 72,
+        bids_quote_lots: 88,
+        maker_fees_paid: 96,
+        maker_rebates_received: 104,
+        taker_fees_paid: 112,
+        maker_reward_points: 120,
+        maker_rewards_claimed: 128,
+        taker_rebates_accrued: 136,
+        maker_rebates_available: 144,
+        reserved: 152,
+    });
+
+    assert_eq!(size_of::<OpenOrder>(), 40);
+    assert_offsets!(OpenOrder {
+        id: 0,
+        client_id: 16,
+        locked_price: 24,
+        is_free: 32,
+        side_and_tree: 33,
+        padding: 34,
+    });
+}
+
+#[test]
+fn book_side_layout() {
+    assert_eq!(size_of::<BookSide>(), 90944);
+    assert_offsets!(BookSide {
+        roots: 0,
+        reserved_roots: 16,
+        next_page: 48,
+        paged_leaf_count: 80,
+        padding: 84,
+        best_price_lots: 88,
+        best_base_lots: 96,
+        best_price_since: 104,
+        reserved: 112,
+        nodes: 304,
+    });
+
+    assert_eq!(size_of::<OrderTreeRoot>(), 8);
+    assert_eq!(size_of::<ExpiryBucket>(), 16);
+    assert_eq!(size_of::<OrderTreeNodes>(), 90640);
+    assert_offsets!(OrderTreeNodes {
+        order_tree_type: 0,
+        padding: 1,
+        bump_index: 4,
+        free_list_len: 8,
+        free_list_head: 12,
+        expiry_buckets: 16,
+        reserved: 272,
+        nodes: 528,
+    });
+
+    assert_eq!(size_of::<AnyNode>(), 88);
+    assert_eq!(size_of::<InnerNode>(), 88);
+    assert_eq!(size_of::<LeafNode>(), 88);
+    assert_eq!(size_of::<FreeNode>(), 88);
+}
+
+#[test]
+fn event_heap_layout() {
+    assert_eq!(size_of::<EventHeap>(), 91280);
+    assert_offsets!(EventHeap {
+        header: 0,
+        nodes: 16,
+        extension_nodes: 91216,
+        reserved: 91218,
+    });
+
+    assert_eq!(size_of::<EventHeapHeader>(), 16);
+    assert_eq!(size_of::<EventNode>(), 152);
+    assert_eq!(size_of::<AnyEvent>(), 144);
+    assert_eq!(size_of::<FillEvent>(), 144);
+    assert_eq!(size_of::<OutEvent>(), 144);
+}
+
+#[test]
+fn market_stats_layout() {
+    assert_eq!(size_of::<MarketStats>(), 1064);
+    assert_offsets!(MarketStats {
+        market: 0,
+        bump: 32,
+        padding: 33,
+        reserved: 40,
+        hours: 104,
+    });
+
+    assert_eq!(size_of::<HourlyStats>(), 40);
+}
+
+#[test]
+fn position_extension_layout() {
+    assert_eq!(size_of::<PositionExtension>(), 320);
+    assert_offsets!(PositionExtension {
+        open_orders_account: 0,
+        bump: 32,
+        padding: 33,
+        net_base_native: 40,
+        cost_basis_native: 48,
+        realized_pnl_native: 56,
+        order_window_start_ts: 64,
+        orders_in_window: 72,
+        padding2: 76,
+        risk_program: 80,
+        settle_receiver: 112,
+        cancel_all_after_ts: 144,
+        heartbeat_interval: 152,
+        padding3: 156,
+        heartbeat_bounty: 160,
+        signed_order_nonce: 168,
+        reserved: 176,
+    });
+}
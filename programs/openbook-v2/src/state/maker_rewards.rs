@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+/// Points a reward rate is expressed for, see `MakerRewards::rewards_per_million_points`
+pub const MAKER_REWARD_POINTS_UNIT: u64 = 1_000_000;
+
+/// Opt-in liquidity mining for the makers of a market.
+///
+/// Makers earn points, base lots times seconds at the top of the book, when their
+/// orders are filled or cancelled. An order is at the top while its fixed price is
+/// the best of its side, see [`BookSide::seconds_at_top`](super::BookSide::seconds_at_top).
+/// The points are accrued to the maker's own open orders account, so nobody
+/// else decides who earns them.
+///
+/// `claim_maker_rewards` pays out the points at `rewards_per_million_points` from
+/// `reward_vault`, which anyone can fund. Points the vault can't cover yet are
+/// kept for a later claim.
+#[account(zero_copy)]
+pub struct MakerRewards {
+    pub market: Pubkey,
+    pub reward_mint: Pubkey,
+    /// Token account of `reward_mint` owned by this account
+    pub reward_vault: Pubkey,
+
+    /// Reward native paid per million points
+    pub rewards_per_million_points: u64,
+    /// Rewards claimed since creation
+    pub rewards_claimed_total: u64,
+
+    pub bump: u8,
+    pub padding: [u8; 7],
+    pub reserved: [u8; 64],
+}
+
+impl MakerRewards {
+    /// Rewards `points` are worth, capped by the `vault_amount` funding them, and
+    /// the points that pays for
+    pub fn claimable(&self, points: u64, vault_amount: u64) -> (u64, u64) {
+        let rate = u128::from(self.rewards_per_million_points);
+        if rate == 0 {
+            return (0, 0);
+        }
+        let unit = u128::from(MAKER_REWARD_POINTS_UNIT);
+        let worth = u128::from(points) * rate / unit;
+        let amount = worth.min(u128::from(vault_amount));
+        // round up so the points kept are never worth more than they were
+        let points_used = (amount * unit + rate - 1) / rate;
+        (amount as u64, (points_used as u64).min(points))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytemuck::Zeroable;
+
+    #[test]
+    fn test_claimable() {
+        let mut rewards = MakerRewards::zeroed();
+        assert_eq!(rewards.claimable(1_000, 1_000), (0, 0));
+
+        rewards.rewards_per_million_points = 2_000_000;
+        assert_eq!(rewards.claimable(1_000, 10_000), (2_000, 1_000));
+        // the vault covers part of it, the other points are kept
+        assert_eq!(rewards.claimable(1_000, 500), (500, 250));
+
+        // points worth less than a native unit are kept too
+        rewards.rewards_per_million_points = 3;
+        assert_eq!(rewards.claimable(1_000_000, 10), (3, 1_000_000));
+        assert_eq!(rewards.claimable(1_000_001, 10), (3, 1_000_000));
+        assert_eq!(rewards.claimable(333_333, 10), (0, 0));
+    }
+}
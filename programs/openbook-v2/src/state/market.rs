@@ -1,18 +1,180 @@
 use anchor_lang::prelude::*;
 use fixed::types::I80F48;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
 use std::convert::{TryFrom, TryInto};
 
 use crate::error::OpenBookError;
+use crate::math;
 use crate::pubkey_option::NonZeroPubkeyOption;
 use crate::state::oracle;
 use crate::{accounts_zerocopy::KeyedAccountReader, state::orderbook::Side};
 
-use super::{orderbook, OracleConfig};
+use super::{orderbook, EventHeapStorage, OracleConfig, OraclePrice};
 
 // For a 1bps taker fee, set taker_fee to 100, so taker_fee/FEES_SCALE_FACTOR = 10e-4
 pub const FEES_SCALE_FACTOR: i128 = 1_000_000;
 // taker pays 500 lamports penalty for every transaction that adds to the event heap
 pub const PENALTY_EVENT_HEAP: u64 = 500;
+// lamports escrowed in the market account by whoever creates it, see `Market::creation_bond`
+pub const MARKET_CREATION_BOND: u64 = 100_000_000;
+// layout version of new markets, `migrate_account` upgrades older ones
+pub const MARKET_VERSION: u8 = 1;
+
+/// What users can do on a market, set by its admin to handle incidents.
+///
+/// Settling funds and consuming events are always allowed, so users never
+/// lose access to their balances.
+#[derive(
+    Eq,
+    PartialEq,
+    Copy,
+    Clone,
+    Default,
+    TryFromPrimitive,
+    IntoPrimitive,
+    Debug,
+    AnchorSerialize,
+    AnchorDeserialize,
+)]
+#[repr(u8)]
+pub enum MarketState {
+    /// Normal trading.
+    #[default]
+    Active = 0,
+
+    /// Only post only orders are accepted, no matching happens.
+    PostOnly = 1,
+
+    /// No new orders, resting orders can be cancelled.
+    CancelOnly = 2,
+
+    /// The book is frozen: no new orders and no cancellations.
+    Paused = 3,
+}
+
+impl MarketState {
+    pub fn check_new_order(&self, post_only: bool) -> Result<()> {
+        match self {
+            Self::Active => Ok(()),
+            Self::PostOnly if post_only => Ok(()),
+            Self::PostOnly => Err(OpenBookError::MarketIsPostOnly.into()),
+            Self::CancelOnly => Err(OpenBookError::MarketIsCancelOnly.into()),
+            Self::Paused => Err(OpenBookError::MarketIsPaused.into()),
+        }
+    }
+
+    pub fn check_cancel_order(&self) -> Result<()> {
+        match self {
+            Self::Paused => Err(OpenBookError::MarketIsPaused.into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Resolution of a market trading a binary outcome token, set by its admin.
+///
+/// A resolved market is settled at a price of 0 or 1 quote per base (in ui
+/// units), recorded in its `settlement_price_lots`. The market doesn't redeem
+/// outcome tokens itself, it only trades them and publishes the resolution.
+#[derive(
+    Eq,
+    PartialEq,
+    Copy,
+    Clone,
+    Default,
+    TryFromPrimitive,
+    IntoPrimitive,
+    Debug,
+    AnchorSerialize,
+    AnchorDeserialize,
+)]
+#[repr(u8)]
+pub enum BinaryOutcome {
+    /// Not a binary outcome market.
+    #[default]
+    Disabled = 0,
+    /// Binary outcome market waiting for its resolution.
+    Unresolved = 1,
+    /// Resolved to 0.
+    No = 2,
+    /// Resolved to 1.
+    Yes = 3,
+}
+
+impl BinaryOutcome {
+    pub fn is_resolved(&self) -> bool {
+        matches!(self, Self::No | Self::Yes)
+    }
+}
+
+/// Which order makes room when a new order is posted to a full book side, set by
+/// the market's admin.
+#[derive(
+    Eq,
+    PartialEq,
+    Copy,
+    Clone,
+    Default,
+    TryFromPrimitive,
+    IntoPrimitive,
+    Debug,
+    AnchorSerialize,
+    AnchorDeserialize,
+)]
+#[repr(u8)]
+pub enum EvictionPolicy {
+    /// Boot the worst-priced order, if the new order is better.
+    #[default]
+    EvictWorstPrice = 0,
+    /// Keep the book as it is, the new order fails.
+    RejectIncoming = 1,
+    /// Boot the oldest order priced further than `eviction_band_bps` from the
+    /// oracle, or from the best order of the side without an oracle, if the new
+    /// order is better. Fails when there is none.
+    EvictOldestOutOfBand = 2,
+}
+
+/// How a [`Market`]'s taker fees are rounded to native quote units, set by the
+/// market's admin.
+#[derive(
+    Eq,
+    PartialEq,
+    Copy,
+    Clone,
+    Default,
+    TryFromPrimitive,
+    IntoPrimitive,
+    Debug,
+    AnchorSerialize,
+    AnchorDeserialize,
+)]
+#[repr(u8)]
+pub enum FeeRounding {
+    /// The taker pays the fractional remainder, which goes to the market's fees
+    #[default]
+    Up = 0,
+    /// The taker keeps the fractional remainder
+    Down = 1,
+}
+
+/// Admin roles of a market that can be handed over with `set_market_authority`.
+#[derive(
+    Eq,
+    PartialEq,
+    Copy,
+    Clone,
+    TryFromPrimitive,
+    IntoPrimitive,
+    Debug,
+    AnchorSerialize,
+    AnchorDeserialize,
+)]
+#[repr(u8)]
+pub enum MarketAdmin {
+    CollectFee = 0,
+    ConsumeEvents = 1,
+    CloseMarket = 2,
+}
 
 #[account(zero_copy)]
 #[derive(Debug)]
@@ -26,7 +188,14 @@ pub struct Market {
     pub base_decimals: u8,
     pub quote_decimals: u8,
 
-    pub padding1: [u8; 5],
+    /// See [`MarketState`].
+    pub market_state: u8,
+    /// See [`BinaryOutcome`].
+    pub binary_outcome: u8,
+    /// 1 once the market was added to the market registry, see `register_market`.
+    pub registered: u8,
+
+    pub padding1: [u8; 2],
 
     // Pda for signing vault txs
     pub market_authority: Pubkey,
@@ -114,7 +283,80 @@ pub struct Market {
     pub market_quote_vault: Pubkey,
     pub quote_deposit_total: u64,
 
-    pub reserved: [u8; 128],
+    /// Lamports paid per consumed event to whoever calls `consume_events_with_reward`.
+    ///
+    /// Funded out of the event heap penalties collected by the market account.
+    pub consume_events_reward: u64,
+
+    /// Timestamp of the oldest event waiting on the event heap, 0 when it's empty.
+    ///
+    /// Like the other event heap stats, refreshed by every instruction that
+    /// adds or consumes events.
+    pub event_heap_oldest_timestamp: u64,
+    /// Number of events waiting on the event heap.
+    pub event_heap_count: u16,
+    /// Number of events the event heap can hold, including resized nodes.
+    pub event_heap_capacity: u16,
+    pub padding2: [u8; 4],
+
+    /// Total number of fill and out events generated, processed directly or not.
+    ///
+    /// Each event carries its own number so consumers can detect missed events.
+    pub event_seq_num: u64,
+
+    /// Price in lots the market settled at with `settle_expired_market`, 0 until then.
+    pub settlement_price_lots: i64,
+
+    /// Lamports escrowed in the market account at creation, on top of its rent.
+    ///
+    /// Returned together with the rent by `close_market`, unless governance slashed
+    /// it with `slash_market_bond` for spam or malicious markets.
+    pub creation_bond: u64,
+
+    /// Time the market opted into maker rewards, 0 when it hasn't. See
+    /// [`MakerRewards`](crate::state::MakerRewards).
+    pub maker_rewards_start_ts: u64,
+
+    /// Base lots in the resting asks of all open orders accounts.
+    ///
+    /// This and the following totals sum the positions of all open orders accounts,
+    /// they are only exact for markets created after they were introduced.
+    pub asks_base_lots_total: i64,
+    /// Quote lots locked in the resting bids of all open orders accounts
+    pub bids_quote_lots_total: i64,
+    /// Base native unsettled in all open orders accounts
+    pub base_free_native_total: i64,
+    /// Quote native unsettled in all open orders accounts
+    pub quote_free_native_total: i64,
+
+    /// Max new orders an open orders account can place per `order_rate_limit_window`,
+    /// 0 when unlimited.
+    pub order_rate_limit: u32,
+    /// Length of the windows of `order_rate_limit` in seconds
+    pub order_rate_limit_window: u32,
+
+    /// Smallest quote native value of an order resting on the book, at the order's
+    /// price when it's posted. 0 when there's no minimum.
+    ///
+    /// Keeps dust orders that are worth less than the space they take off the book.
+    pub min_order_notional: u64,
+
+    /// Max resting orders of an open orders account on this market, 0 when only
+    /// limited by the size of the account.
+    pub max_resting_orders: u8,
+    /// Layout version, see `MARKET_VERSION`. 0 for markets created before it was
+    /// introduced.
+    pub version: u8,
+    pub padding3: [u8; 6],
+
+    /// See [`EvictionPolicy`].
+    pub eviction_policy: u8,
+    /// See [`FeeRounding`].
+    pub taker_fee_rounding: u8,
+    /// Price band of `EvictionPolicy::EvictOldestOutOfBand`
+    pub eviction_band_bps: u16,
+
+    pub reserved: [u8; 12],
 }
 
 impl Market {
@@ -128,6 +370,49 @@ impl Market {
         self.time_expiry != 0 && self.time_expiry < timestamp
     }
 
+    pub fn is_settled(&self) -> bool {
+        self.settlement_price_lots != 0 || self.binary_outcome().is_resolved()
+    }
+
+    pub fn admin(&self, role: MarketAdmin) -> NonZeroPubkeyOption {
+        match role {
+            MarketAdmin::CollectFee => Some(self.collect_fee_admin).into(),
+            MarketAdmin::ConsumeEvents => self.consume_events_admin,
+            MarketAdmin::CloseMarket => self.close_market_admin,
+        }
+    }
+
+    pub fn set_admin(&mut self, role: MarketAdmin, admin: Pubkey) {
+        match role {
+            MarketAdmin::CollectFee => self.collect_fee_admin = admin,
+            MarketAdmin::ConsumeEvents => self.consume_events_admin = Some(admin).into(),
+            MarketAdmin::CloseMarket => self.close_market_admin = Some(admin).into(),
+        }
+    }
+
+    pub fn binary_outcome(&self) -> BinaryOutcome {
+        BinaryOutcome::try_from(self.binary_outcome).unwrap()
+    }
+
+    /// Price in lots of one ui base unit for one ui quote unit, the settlement
+    /// price of a binary outcome resolved to `Yes`.
+    pub fn binary_outcome_price_lots(&self) -> Result<i64> {
+        let decimals = (self.quote_decimals as i8) - (self.base_decimals as i8);
+        self.native_price_to_lot(I80F48::from_num(oracle::power_of_ten_float(decimals)))
+    }
+
+    pub fn market_state(&self) -> MarketState {
+        MarketState::try_from(self.market_state).unwrap()
+    }
+
+    pub fn eviction_policy(&self) -> EvictionPolicy {
+        EvictionPolicy::try_from(self.eviction_policy).unwrap()
+    }
+
+    pub fn taker_fee_rounding(&self) -> FeeRounding {
+        FeeRounding::try_from(self.taker_fee_rounding).unwrap()
+    }
+
     pub fn is_empty(&self) -> bool {
         self.base_deposit_total == 0
             && self.quote_deposit_total == 0
@@ -151,6 +436,33 @@ impl Market {
         orderbook::new_node_key(side, price_data, self.seq_num)
     }
 
+    pub fn update_event_heap_stats(&mut self, event_heap: &impl EventHeapStorage) {
+        self.event_heap_count = event_heap.len() as u16;
+        self.event_heap_capacity = event_heap.capacity() as u16;
+        self.event_heap_oldest_timestamp = event_heap.front().map_or(0, |e| e.timestamp());
+    }
+
+    /// Event heap fill level in basis points, as of the last update.
+    pub fn event_heap_fill_bps(&self) -> u64 {
+        if self.event_heap_capacity == 0 {
+            return 0;
+        }
+        self.event_heap_count as u64 * 10_000 / self.event_heap_capacity as u64
+    }
+
+    /// Seconds the oldest event has been waiting on the event heap, 0 when it's empty.
+    pub fn oldest_event_age(&self, now_ts: u64) -> u64 {
+        if self.event_heap_count == 0 {
+            return 0;
+        }
+        now_ts.saturating_sub(self.event_heap_oldest_timestamp)
+    }
+
+    pub fn gen_event_seq_num(&mut self) -> u64 {
+        self.event_seq_num += 1;
+        self.event_seq_num
+    }
+
     pub fn max_base_lots(&self) -> i64 {
         i64::MAX / self.base_lot_size
     }
@@ -195,77 +507,63 @@ impl Market {
     ) -> Result<Option<i64>> {
         let oracle_price = self.oracle_price(oracle_a_acc, oracle_b_acc, slot)?;
         match oracle_price {
-            Some(p) => Ok(Some(self.native_price_to_lot(p)?)),
+            Some(p) => Ok(Some(self.native_price_to_lot(p.price)?)),
             None => Ok(None),
         }
     }
 
+    /// The price of the market's oracle, or of `oracle_a` in `oracle_b` units if both
+    /// are set. `None` without oracle, or if one is stale or the price isn't confident
+    /// enough.
     pub fn oracle_price(
         &self,
         oracle_a_acc: Option<&impl KeyedAccountReader>,
         oracle_b_acc: Option<&impl KeyedAccountReader>,
-        slot: u64,
-    ) -> Result<Option<I80F48>> {
-        if self.oracle_a.is_some() && self.oracle_b.is_some() {
-            self.oracle_price_from_a_and_b(oracle_a_acc.unwrap(), oracle_b_acc.unwrap(), slot)
-        } else if self.oracle_a.is_some() {
-            self.oracle_price_from_a(oracle_a_acc.unwrap(), slot)
-        } else {
-            Ok(None)
-        }
-    }
-
-    fn oracle_price_from_a(
-        &self,
-        oracle_acc: &impl KeyedAccountReader,
         now_slot: u64,
-    ) -> Result<Option<I80F48>> {
-        assert_eq!(self.oracle_a, *oracle_acc.key());
-        let oracle = oracle::oracle_state_unchecked(oracle_acc)?;
-
-        if oracle.is_stale(oracle_acc.key(), &self.oracle_config, now_slot)
-            || !oracle.has_valid_confidence(oracle_acc.key(), &self.oracle_config)
-        {
-            Ok(None)
-        } else {
-            let decimals = (self.quote_decimals as i8) - (self.base_decimals as i8);
-            let decimal_adj = oracle::power_of_ten_float(decimals);
-            Ok(I80F48::checked_from_num(oracle.price * decimal_adj))
+    ) -> Result<Option<OraclePrice>> {
+        if self.oracle_a.is_none() {
+            return Ok(None);
         }
-    }
-
-    fn oracle_price_from_a_and_b(
-        &self,
-        oracle_a_acc: &impl KeyedAccountReader,
-        oracle_b_acc: &impl KeyedAccountReader,
-        now_slot: u64,
-    ) -> Result<Option<I80F48>> {
+        let oracle_a_acc = oracle_a_acc.unwrap();
         assert_eq!(self.oracle_a, *oracle_a_acc.key());
-        assert_eq!(self.oracle_b, *oracle_b_acc.key());
-
         let oracle_a = oracle::oracle_state_unchecked(oracle_a_acc)?;
-        let oracle_b = oracle::oracle_state_unchecked(oracle_b_acc)?;
+        let oracle_b = if self.oracle_b.is_some() {
+            let oracle_b_acc = oracle_b_acc.unwrap();
+            assert_eq!(self.oracle_b, *oracle_b_acc.key());
+            Some((
+                oracle_b_acc.key(),
+                oracle::oracle_state_unchecked(oracle_b_acc)?,
+            ))
+        } else {
+            None
+        };
 
         if oracle_a.is_stale(oracle_a_acc.key(), &self.oracle_config, now_slot)
-            || oracle_b.is_stale(oracle_b_acc.key(), &self.oracle_config, now_slot)
-            || !oracle_a.has_valid_combined_confidence(&oracle_b, &self.oracle_config)
+            || oracle_b.as_ref().map_or(false, |(key, oracle_b)| {
+                oracle_b.is_stale(key, &self.oracle_config, now_slot)
+            })
         {
-            Ok(None)
-        } else {
-            let price = oracle_a.price / oracle_b.price;
-            let decimals = (self.quote_decimals as i8) - (self.base_decimals as i8);
-            let decimal_adj = oracle::power_of_ten_float(decimals);
-            Ok(I80F48::checked_from_num(price * decimal_adj))
+            return Ok(None);
+        }
+        let oracle = match oracle_b {
+            Some((_, oracle_b)) => oracle_a.ratio(&oracle_b),
+            None => oracle_a,
+        };
+        if !oracle.has_valid_confidence(&self.oracle_config) {
+            return Ok(None);
         }
+
+        let decimals = (self.quote_decimals as i8) - (self.base_decimals as i8);
+        Ok(oracle.to_oracle_price(oracle::power_of_ten_float(decimals)))
     }
 
     pub fn subtract_taker_fees(&self, quote: i64) -> i64 {
-        ((quote as i128) * FEES_SCALE_FACTOR / (FEES_SCALE_FACTOR + (self.taker_fee as i128)))
+        math::amount_without_fees(quote.into(), self.taker_fee)
             .try_into()
             .unwrap()
     }
 
-    pub fn maker_fees_floor(self, amount: u64) -> u64 {
+    pub fn maker_fees_floor(&self, amount: u64) -> u64 {
         if self.maker_fee.is_positive() {
             self.unsigned_maker_fees_floor(amount)
         } else {
@@ -273,7 +571,7 @@ impl Market {
         }
     }
 
-    pub fn maker_rebate_floor(self, amount: u64) -> u64 {
+    pub fn maker_rebate_floor(&self, amount: u64) -> u64 {
         if self.maker_fee.is_positive() {
             0
         } else {
@@ -281,13 +579,13 @@ impl Market {
         }
     }
 
-    pub fn maker_fees_ceil<T>(self, amount: T) -> T
+    pub fn maker_fees_ceil<T>(&self, amount: T) -> T
     where
         T: Into<i128> + TryFrom<i128> + From<u8>,
         <T as TryFrom<i128>>::Error: std::fmt::Debug,
     {
         if self.maker_fee.is_positive() {
-            self.ceil_fee_division(amount.into() * (self.maker_fee.abs() as i128))
+            math::fees_ceil(amount.into(), self.maker_fee)
                 .try_into()
                 .unwrap()
         } else {
@@ -295,22 +593,21 @@ impl Market {
         }
     }
 
-    pub fn taker_fees_ceil<T>(self, amount: T) -> T
+    /// Taker fees of `amount`, rounded as set by `taker_fee_rounding`
+    pub fn taker_fees<T>(&self, amount: T) -> T
     where
         T: Into<i128> + TryFrom<i128>,
         <T as TryFrom<i128>>::Error: std::fmt::Debug,
     {
-        self.ceil_fee_division(amount.into() * (self.taker_fee as i128))
-            .try_into()
-            .unwrap()
-    }
-
-    fn ceil_fee_division(self, numerator: i128) -> i128 {
-        (numerator + (FEES_SCALE_FACTOR - 1_i128)) / FEES_SCALE_FACTOR
+        let fees = match self.taker_fee_rounding() {
+            FeeRounding::Up => math::fees_ceil(amount.into(), self.taker_fee),
+            FeeRounding::Down => math::fees_floor(amount.into(), self.taker_fee),
+        };
+        fees.try_into().unwrap()
     }
 
-    fn unsigned_maker_fees_floor(self, amount: u64) -> u64 {
-        (i128::from(amount) * i128::from(self.maker_fee.abs()) / FEES_SCALE_FACTOR)
+    fn unsigned_maker_fees_floor(&self, amount: u64) -> u64 {
+        math::fees_floor(amount.into(), self.maker_fee.abs())
             .try_into()
             .unwrap()
     }
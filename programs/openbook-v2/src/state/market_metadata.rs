@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::pubkey_option::NonZeroPubkeyOption;
+
+/// Optional per-market PDA with human readable labels, maintained by the
+/// market's `collect_fee_admin`.
+///
+/// Strings are stored as fixed size arrays; trailing zero bytes are ignored.
+#[account(zero_copy)]
+pub struct MarketMetadata {
+    pub market: Pubkey,
+    pub bump: u8,
+    pub padding: [u8; 7],
+    pub name: [u8; 64],
+    pub base_symbol: [u8; 16],
+    pub quote_symbol: [u8; 16],
+    /// Link to further off-chain information about the market.
+    pub uri: [u8; 200],
+    /// Market of the same token pair replacing this one, e.g. with lot sizes fitting
+    /// the current prices better, so clients can redirect their users to it.
+    /// See `set_successor_market`.
+    pub successor_market: NonZeroPubkeyOption,
+    pub reserved: [u8; 32],
+}
+
+impl MarketMetadata {
+    pub fn name(&self) -> &str {
+        trimmed_str(&self.name)
+    }
+
+    pub fn base_symbol(&self) -> &str {
+        trimmed_str(&self.base_symbol)
+    }
+
+    pub fn quote_symbol(&self) -> &str {
+        trimmed_str(&self.quote_symbol)
+    }
+
+    pub fn uri(&self) -> &str {
+        trimmed_str(&self.uri)
+    }
+}
+
+fn trimmed_str(bytes: &[u8]) -> &str {
+    std::str::from_utf8(bytes)
+        .unwrap()
+        .trim_matches(char::from(0))
+}
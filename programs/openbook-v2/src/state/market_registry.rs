@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use super::Market;
+
+pub const MARKET_REGISTRY_PAGE_LEN: usize = 64;
+
+/// Page of the global market registry, a PDA of `["MarketRegistry", page_index]`
+/// (little endian `u32`).
+///
+/// Pages are created in order and only once the previous one is full, so
+/// clients can list every registered market by reading pages from 0 until
+/// one is missing or not full.
+#[account(zero_copy)]
+pub struct MarketRegistryPage {
+    pub page_index: u32,
+    pub count: u32,
+    pub bump: u8,
+    pub padding: [u8; 7],
+    pub reserved: [u8; 64],
+    pub entries: [MarketRegistryEntry; MARKET_REGISTRY_PAGE_LEN],
+}
+
+#[zero_copy]
+#[derive(Debug, Default)]
+pub struct MarketRegistryEntry {
+    pub market: Pubkey,
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    pub base_lot_size: i64,
+    pub quote_lot_size: i64,
+}
+
+impl MarketRegistryPage {
+    pub fn is_full(&self) -> bool {
+        self.count as usize == MARKET_REGISTRY_PAGE_LEN
+    }
+
+    pub fn entries(&self) -> &[MarketRegistryEntry] {
+        &self.entries[..self.count as usize]
+    }
+
+    pub fn push(&mut self, market_pk: Pubkey, market: &Market) {
+        assert!(!self.is_full());
+        self.entries[self.count as usize] = MarketRegistryEntry {
+            market: market_pk,
+            base_mint: market.base_mint,
+            quote_mint: market.quote_mint,
+            base_lot_size: market.base_lot_size,
+            quote_lot_size: market.quote_lot_size,
+        };
+        self.count += 1;
+    }
+}
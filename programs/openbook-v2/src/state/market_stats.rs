@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+
+pub const MARKET_STATS_HOURS: usize = 24;
+
+const SECONDS_PER_HOUR: u64 = 3600;
+
+/// Optional per-market PDA keeping the fills of the last [`MARKET_STATS_HOURS`] hours
+/// in hourly buckets, so markets can be compared from on-chain data alone.
+///
+/// Fills are recorded while matching when the account is passed among the
+/// remaining accounts of the order placing instruction. Buckets are only rotated
+/// by fills: a bucket of an hour without fills keeps the fills of an older hour,
+/// which [`MarketStats::last_24h`] skips.
+#[account(zero_copy)]
+pub struct MarketStats {
+    pub market: Pubkey,
+    pub bump: u8,
+    pub padding: [u8; 7],
+    pub reserved: [u8; 64],
+    pub hours: [HourlyStats; MARKET_STATS_HOURS],
+}
+
+#[zero_copy]
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct HourlyStats {
+    /// Hours since the unix epoch
+    pub hour: u64,
+    /// Native quote traded
+    pub quote_volume: u64,
+    pub trade_count: u64,
+    pub high_lots: i64,
+    pub low_lots: i64,
+}
+
+/// Totals of the fills of the current and previous 23 hours, see [`MarketStats::last_24h`]
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Stats24h {
+    pub quote_volume: u64,
+    pub trade_count: u64,
+    /// Highest and lowest fill prices, 0 without fills
+    pub high_lots: i64,
+    pub low_lots: i64,
+}
+
+impl MarketStats {
+    pub fn record_fill(&mut self, now_ts: u64, price_lots: i64, quote_native: u64) {
+        let hour = now_ts / SECONDS_PER_HOUR;
+        let bucket = &mut self.hours[hour as usize % MARKET_STATS_HOURS];
+        if bucket.hour != hour || bucket.trade_count == 0 {
+            *bucket = HourlyStats {
+                hour,
+                quote_volume: 0,
+                trade_count: 0,
+                high_lots: price_lots,
+                low_lots: price_lots,
+            };
+        }
+        bucket.quote_volume = bucket.quote_volume.saturating_add(quote_native);
+        bucket.trade_count += 1;
+        bucket.high_lots = bucket.high_lots.max(price_lots);
+        bucket.low_lots = bucket.low_lots.min(price_lots);
+    }
+
+    pub fn last_24h(&self, now_ts: u64) -> Stats24h {
+        let hour = now_ts / SECONDS_PER_HOUR;
+        let mut stats = Stats24h::default();
+        for bucket in self.hours.iter() {
+            let is_recent = bucket.hour <= hour && hour - bucket.hour < MARKET_STATS_HOURS as u64;
+            if bucket.trade_count == 0 || !is_recent {
+                continue;
+            }
+            if stats.trade_count == 0 {
+                stats.high_lots = bucket.high_lots;
+                stats.low_lots = bucket.low_lots;
+            }
+            stats.quote_volume = stats.quote_volume.saturating_add(bucket.quote_volume);
+            stats.trade_count += bucket.trade_count;
+            stats.high_lots = stats.high_lots.max(bucket.high_lots);
+            stats.low_lots = stats.low_lots.min(bucket.low_lots);
+        }
+        stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytemuck::Zeroable;
+
+    const HOUR: u64 = SECONDS_PER_HOUR;
+
+    #[test]
+    fn test_last_24h() {
+        let mut stats = MarketStats::zeroed();
+        let start = 1_700_000_000;
+        assert_eq!(stats.last_24h(start), Stats24h::default());
+
+        stats.record_fill(start, 100, 1_000);
+        stats.record_fill(start + 1, 90, 500);
+        stats.record_fill(start + 5 * HOUR, 120, 2_000);
+        assert_eq!(
+            stats.last_24h(start + 5 * HOUR),
+            Stats24h {
+                quote_volume: 3_500,
+                trade_count: 3,
+                high_lots: 120,
+                low_lots: 90,
+            }
+        );
+
+        // the first hour drops out of the window
+        assert_eq!(
+            stats.last_24h(start + 24 * HOUR),
+            Stats24h {
+                quote_volume: 2_000,
+                trade_count: 1,
+                high_lots: 120,
+                low_lots: 120,
+            }
+        );
+
+        // and its bucket is reused by the next fill landing in it
+        stats.record_fill(start + 24 * HOUR, 110, 700);
+        assert_eq!(
+            stats.last_24h(start + 24 * HOUR),
+            Stats24h {
+                quote_volume: 2_700,
+                trade_count: 2,
+                high_lots: 120,
+                low_lots: 110,
+            }
+        );
+
+        assert_eq!(stats.last_24h(start + 48 * HOUR), Stats24h::default());
+    }
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::{hash, hashv};
+
+/// Per-market PDA letting traders of a permissioned market prove once that they
+/// are allowed to trade, instead of the `open_orders_admin` co-signing every order.
+///
+/// Leaves of the tree are `sha256(owner)`, and each pair of nodes is hashed in
+/// sorted order so proofs don't need to carry left/right flags.
+#[account(zero_copy)]
+pub struct MerkleAllowlist {
+    pub market: Pubkey,
+    pub root: [u8; 32],
+    pub bump: u8,
+    pub padding: [u8; 7],
+    pub reserved: [u8; 64],
+}
+
+impl MerkleAllowlist {
+    pub fn contains(&self, owner: &Pubkey, proof: &[[u8; 32]]) -> bool {
+        let computed = proof.iter().fold(merkle_leaf(owner), |node, sibling| {
+            merkle_parent(&node, sibling)
+        });
+        computed == self.root
+    }
+}
+
+pub fn merkle_leaf(owner: &Pubkey) -> [u8; 32] {
+    hash(owner.as_ref()).to_bytes()
+}
+
+pub fn merkle_parent(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    if a <= b {
+        hashv(&[a, b]).to_bytes()
+    } else {
+        hashv(&[b, a]).to_bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merkle_allowlist_contains() {
+        let owners: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        let leaves: Vec<[u8; 32]> = owners.iter().map(merkle_leaf).collect();
+        let left = merkle_parent(&leaves[0], &leaves[1]);
+        let root = merkle_parent(&left, &leaves[2]);
+
+        let allowlist = MerkleAllowlist {
+            market: Pubkey::default(),
+            root,
+            bump: 0,
+            padding: [0; 7],
+            reserved: [0; 64],
+        };
+
+        assert!(allowlist.contains(&owners[0], &[leaves[1], leaves[2]]));
+        assert!(allowlist.contains(&owners[1], &[leaves[0], leaves[2]]));
+        assert!(allowlist.contains(&owners[2], &[left]));
+
+        assert!(!allowlist.contains(&owners[2], &[leaves[0]]));
+        assert!(!allowlist.contains(&Pubkey::new_unique(), &[left]));
+    }
+}
@@ -1,14 +1,49 @@
+pub use depth_snapshot::*;
+pub use dmm_registry::*;
+pub use fill_callback::*;
+pub use fill_history::*;
+pub use maker_rewards::*;
 pub use market::*;
+pub use market_metadata::*;
+pub use market_registry::*;
+pub use market_stats::*;
+pub use merkle_allowlist::*;
 pub use open_orders_account::*;
 pub use open_orders_indexer::*;
 pub use oracle::*;
 pub use orderbook::*;
+pub use pending_market_admin::*;
+pub use position_extension::*;
+pub use quote_quality::*;
+pub use taker_program_allowlist::*;
+pub use taker_rebate_campaign::*;
+pub use token_gate::*;
+pub use trigger_order::*;
 
+mod depth_snapshot;
+mod dmm_registry;
+mod fill_callback;
+mod fill_history;
+mod maker_rewards;
 mod market;
+mod market_metadata;
+mod market_registry;
+mod market_stats;
+mod merkle_allowlist;
 mod open_orders_account;
 mod open_orders_indexer;
 mod orderbook;
+mod pending_market_admin;
+mod position_extension;
+mod quote_quality;
+mod taker_program_allowlist;
+mod taker_rebate_campaign;
+mod token_gate;
+mod trigger_order;
 
 pub mod oracle;
 mod raydium_internal;
 mod switchboard_solana;
+
+#[cfg(test)]
+mod layout_tests;
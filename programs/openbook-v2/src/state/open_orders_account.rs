@@ -2,14 +2,35 @@ use anchor_lang::prelude::*;
 use derivative::Derivative;
 use std::mem::size_of;
 
-use crate::logs::{emit_stack, FillLog};
+use crate::logs::emit_stack;
+use crate::math;
 use crate::pubkey_option::NonZeroPubkeyOption;
 use crate::{error::*, logs::OpenOrdersPositionLog};
 
-use super::{BookSideOrderTree, FillEvent, LeafNode, Market, Side, SideAndOrderTree};
+use super::{
+    BookSideOrderTree, FillEvent, LeafNode, Market, PositionExtension, Side, SideAndOrderTree,
+};
 
 pub const MAX_OPEN_ORDERS: usize = 24;
 
+/// Layout version of new open orders accounts, `migrate_account` upgrades older ones
+///
+/// 1. bids_quote_lots tracked
+pub const OPEN_ORDERS_ACCOUNT_VERSION: u8 = 1;
+
+/// Bits of [`OpenOrdersAccount::flags`].
+pub mod open_orders_flags {
+    /// The owner proved to be in the market's
+    /// [`MerkleAllowlist`](crate::state::MerkleAllowlist), so it can place orders
+    /// without the open orders admin signature.
+    pub const ALLOWLISTED: u8 = 1 << 0;
+    /// The account has a [`PositionExtension`](crate::state::PositionExtension).
+    pub const HAS_POSITION_EXTENSION: u8 = 1 << 1;
+    /// The owner lets takers pay the account's fill proceeds straight to its token
+    /// accounts, see [`maker_settlement`](crate::maker_settlement).
+    pub const DIRECT_SETTLEMENT: u8 = 1 << 2;
+}
+
 #[account(zero_copy)]
 #[derive(Debug)]
 pub struct OpenOrdersAccount {
@@ -28,7 +49,13 @@ pub struct OpenOrdersAccount {
     // Introducing a version as we are adding a new field bids_quote_lots
     pub version: u8,
 
-    pub padding: [u8; 2],
+    /// See [`open_orders_flags`].
+    pub flags: u8,
+
+    /// Priority of the account's slot in the market's
+    /// [`DmmRegistry`](crate::state::DmmRegistry), 0 when it isn't a designated
+    /// market maker.
+    pub dmm_priority: u8,
 
     pub position: Position,
 
@@ -55,13 +82,60 @@ impl OpenOrdersAccount {
             delegate: NonZeroPubkeyOption::default(),
             account_num: 0,
             bump: 0,
-            version: 1,
-            padding: [0; 2],
+            version: OPEN_ORDERS_ACCOUNT_VERSION,
+            flags: 0,
+            dmm_priority: 0,
             position: Position::default(),
             open_orders: [OpenOrder::default(); MAX_OPEN_ORDERS],
         })
     }
 
+    fn has_flag(&self, flag: u8) -> bool {
+        self.flags & flag != 0
+    }
+
+    fn set_flag(&mut self, flag: u8, value: bool) {
+        if value {
+            self.flags |= flag;
+        } else {
+            self.flags &= !flag;
+        }
+    }
+
+    pub fn is_allowlisted(&self) -> bool {
+        self.has_flag(open_orders_flags::ALLOWLISTED)
+    }
+
+    pub fn set_allowlisted(&mut self, allowlisted: bool) {
+        self.set_flag(open_orders_flags::ALLOWLISTED, allowlisted);
+    }
+
+    pub fn has_position_extension(&self) -> bool {
+        self.has_flag(open_orders_flags::HAS_POSITION_EXTENSION)
+    }
+
+    pub fn set_has_position_extension(&mut self, has_position_extension: bool) {
+        self.set_flag(
+            open_orders_flags::HAS_POSITION_EXTENSION,
+            has_position_extension,
+        );
+    }
+
+    pub fn accepts_direct_settlement(&self) -> bool {
+        self.has_flag(open_orders_flags::DIRECT_SETTLEMENT)
+    }
+
+    pub fn set_accepts_direct_settlement(&mut self, accepts_direct_settlement: bool) {
+        self.set_flag(
+            open_orders_flags::DIRECT_SETTLEMENT,
+            accepts_direct_settlement,
+        );
+    }
+
+    pub fn is_dmm(&self) -> bool {
+        self.dmm_priority > 0
+    }
+
     pub fn is_owner_or_delegate(&self, ix_signer: Pubkey) -> bool {
         let delegate_option: Option<Pubkey> = Option::from(self.delegate);
         if let Some(delegate) = delegate_option {
@@ -99,6 +173,18 @@ impl OpenOrdersAccount {
             .ok_or_else(|| error!(OpenBookError::OpenOrdersFull))
     }
 
+    /// Fails if the account can't have one more resting order on the market
+    pub fn check_max_resting_orders(&self, market: &Market) -> Result<()> {
+        if market.max_resting_orders > 0 {
+            require_gt!(
+                market.max_resting_orders as usize,
+                self.all_orders_in_use().count(),
+                OpenBookError::MaxRestingOrdersReached
+            );
+        }
+        Ok(())
+    }
+
     pub fn find_order_with_client_order_id(&self, client_order_id: u64) -> Option<&OpenOrder> {
         self.all_orders_in_use()
             .find(|&oo| oo.client_id == client_order_id)
@@ -116,13 +202,20 @@ impl OpenOrdersAccount {
         &mut self.open_orders[raw_index]
     }
 
-    pub fn execute_maker(&mut self, market: &mut Market, fill: &FillEvent) {
+    /// Apply a fill to the maker account, `position_ext` is its [`PositionExtension`]
+    /// if it has one
+    pub fn execute_maker(
+        &mut self,
+        market: &mut Market,
+        fill: &FillEvent,
+        position_ext: Option<&mut PositionExtension>,
+    ) -> Result<()> {
         let is_self_trade = fill.maker == fill.taker;
 
         let side = fill.taker_side().invert_side();
-        let quote_native = (fill.quantity * fill.price * market.quote_lot_size) as u64;
+        let quote_native = math::quote_native(fill.quantity, fill.price, market.quote_lot_size)?;
 
-        let (maker_fees, maker_rebate) = if is_self_trade {
+        let (fees_at_fill_price, maker_rebate) = if is_self_trade {
             (0, 0)
         } else {
             (
@@ -130,17 +223,19 @@ impl OpenOrdersAccount {
                 market.maker_rebate_floor(quote_native),
             )
         };
+        // Designated market makers don't pay maker fees, the fees locked for their
+        // bids are freed instead
+        let maker_fees = if self.is_dmm() { 0 } else { fees_at_fill_price };
 
-        let mut locked_maker_fees = maker_fees;
+        let mut locked_maker_fees = fees_at_fill_price;
         let mut locked_amount_above_fill_price = 0;
 
         let locked_price = if fill.peg_limit != -1 && side == Side::Bid {
             let quote_at_lock_price =
-                (fill.quantity * fill.peg_limit * market.quote_lot_size) as u64;
+                math::quote_native(fill.quantity, fill.peg_limit, market.quote_lot_size)?;
             let quote_to_free = quote_at_lock_price - quote_native;
 
             let fees_at_lock_price = market.maker_fees_floor(quote_at_lock_price);
-            let fees_at_fill_price = maker_fees;
             let maker_fees_to_free = fees_at_lock_price - fees_at_fill_price;
 
             locked_maker_fees = fees_at_lock_price;
@@ -150,64 +245,66 @@ impl OpenOrdersAccount {
             fill.price
         };
 
+        self.accrue_maker_reward_points(fill.quantity, fill.maker_seconds_at_top.into());
+
         {
             let pa = &mut self.position;
 
-            match side {
+            let (base_freed, quote_freed) = match side {
                 Side::Bid => {
-                    pa.base_free_native += (fill.quantity * market.base_lot_size) as u64;
-                    pa.quote_free_native += maker_rebate + locked_amount_above_fill_price;
                     pa.locked_maker_fees -= locked_maker_fees;
+                    (
+                        math::lots_to_native(fill.quantity, market.base_lot_size)?,
+                        maker_rebate + locked_amount_above_fill_price + fees_at_fill_price
+                            - maker_fees,
+                    )
                 }
-                Side::Ask => {
-                    pa.quote_free_native += quote_native + maker_rebate - maker_fees;
-                }
+                Side::Ask => (0, quote_native + maker_rebate - maker_fees),
             };
+            pa.base_free_native += base_freed;
+            pa.quote_free_native += quote_freed;
+            market.base_free_native_total += base_freed as i64;
+            market.quote_free_native_total += quote_freed as i64;
 
             pa.maker_volume += quote_native as u128;
+            pa.maker_fees_paid += maker_fees;
+            pa.maker_rebates_received += maker_rebate;
+            pa.maker_rebates_available += maker_rebate;
+            if let Some(position_ext) = position_ext {
+                position_ext.record_fill_pnl(
+                    side,
+                    math::lots_to_native(fill.quantity, market.base_lot_size)? as i64,
+                    quote_native as i64,
+                );
+            }
             pa.referrer_rebates_available += maker_fees;
             market.referrer_rebates_accrued += maker_fees;
             market.maker_volume += quote_native as u128;
             market.fees_accrued += maker_fees as u128;
 
             if fill.maker_out() {
-                self.remove_order(fill.maker_slot as usize, fill.quantity, locked_price);
+                self.remove_order(
+                    market,
+                    fill.maker_slot as usize,
+                    fill.quantity,
+                    locked_price,
+                );
             } else {
                 match side {
                     Side::Bid => {
+                        let quote_lots = math::quote_lots(fill.quantity, locked_price)?;
                         pa.bids_base_lots -= fill.quantity;
-                        pa.bids_quote_lots -= fill.quantity * locked_price;
+                        pa.bids_quote_lots -= quote_lots;
+                        market.bids_quote_lots_total -= quote_lots;
+                    }
+                    Side::Ask => {
+                        pa.asks_base_lots -= fill.quantity;
+                        market.asks_base_lots_total -= fill.quantity;
                     }
-                    Side::Ask => pa.asks_base_lots -= fill.quantity,
                 };
             }
         }
 
-        // Calculate taker fee, ignoring self trades
-        let taker_fee_ceil = if quote_native > 0 && fill.maker != fill.taker {
-            market.taker_fees_ceil(quote_native)
-        } else {
-            0
-        };
-
-        emit_stack(FillLog {
-            market: self.market,
-            taker_side: fill.taker_side,
-            maker_slot: fill.maker_slot,
-            maker_out: fill.maker_out(),
-            timestamp: fill.timestamp,
-            seq_num: fill.market_seq_num,
-            maker: fill.maker,
-            maker_client_order_id: fill.maker_client_order_id,
-            maker_fee: maker_fees,
-            maker_timestamp: fill.maker_timestamp,
-            taker: fill.taker,
-            taker_client_order_id: fill.taker_client_order_id,
-            taker_fee_ceil,
-            price: fill.price,
-            quantity: fill.quantity,
-        });
-
         let pa = &self.position;
         emit_stack(OpenOrdersPositionLog {
             owner: self.owner,
@@ -222,10 +319,12 @@ impl OpenOrdersAccount {
             referrer_rebates_available: pa.referrer_rebates_available,
             maker_volume: pa.maker_volume,
             taker_volume: pa.taker_volume,
-        })
+        });
+        Ok(())
     }
 
     /// Release funds and apply taker fees to the taker account. Account fees for referrer
+    #[allow(clippy::too_many_arguments)]
     pub fn execute_taker(
         &mut self,
         market: &mut Market,
@@ -234,14 +333,25 @@ impl OpenOrdersAccount {
         quote_native: u64,
         taker_fees: u64,
         referrer_amount: u64,
+        position_ext: Option<&mut PositionExtension>,
     ) {
         let pa = &mut self.position;
         match taker_side {
-            Side::Bid => pa.base_free_native += base_native,
-            Side::Ask => pa.quote_free_native += quote_native - taker_fees,
+            Side::Bid => {
+                pa.base_free_native += base_native;
+                market.base_free_native_total += base_native as i64;
+            }
+            Side::Ask => {
+                pa.quote_free_native += quote_native - taker_fees;
+                market.quote_free_native_total += (quote_native - taker_fees) as i64;
+            }
         };
 
         pa.taker_volume += quote_native as u128;
+        pa.taker_fees_paid += taker_fees;
+        if let Some(position_ext) = position_ext {
+            position_ext.record_fill_pnl(taker_side, base_native as i64, quote_native as i64);
+        }
         pa.referrer_rebates_available += referrer_amount;
         market.referrer_rebates_accrued += referrer_amount;
 
@@ -263,6 +373,7 @@ impl OpenOrdersAccount {
 
     pub fn add_order(
         &mut self,
+        market: &mut Market,
         side: Side,
         order_tree: BookSideOrderTree,
         order: &LeafNode,
@@ -274,8 +385,12 @@ impl OpenOrdersAccount {
             Side::Bid => {
                 position.bids_base_lots += order.quantity;
                 position.bids_quote_lots += order.quantity * locked_price;
+                market.bids_quote_lots_total += order.quantity * locked_price;
+            }
+            Side::Ask => {
+                position.asks_base_lots += order.quantity;
+                market.asks_base_lots_total += order.quantity;
             }
-            Side::Ask => position.asks_base_lots += order.quantity,
         };
         let slot = order.owner_slot as usize;
 
@@ -287,7 +402,13 @@ impl OpenOrdersAccount {
         oo.locked_price = locked_price;
     }
 
-    pub fn remove_order(&mut self, slot: usize, base_quantity: i64, locked_price: i64) {
+    pub fn remove_order(
+        &mut self,
+        market: &mut Market,
+        slot: usize,
+        base_quantity: i64,
+        locked_price: i64,
+    ) {
         let oo = self.open_order_by_raw_index(slot);
         assert!(!oo.is_free());
 
@@ -299,21 +420,39 @@ impl OpenOrdersAccount {
             Side::Bid => {
                 position.bids_base_lots -= base_quantity;
                 position.bids_quote_lots -= base_quantity * locked_price;
+                market.bids_quote_lots_total -= base_quantity * locked_price;
+            }
+            Side::Ask => {
+                position.asks_base_lots -= base_quantity;
+                market.asks_base_lots_total -= base_quantity;
             }
-            Side::Ask => position.asks_base_lots -= base_quantity,
         }
 
         // release space
         *self.open_order_mut_by_raw_index(slot) = OpenOrder::default();
     }
 
-    pub fn cancel_order(&mut self, slot: usize, base_quantity: i64, market: Market) {
+    /// Accrue the maker reward points of `base_lots` that spent `seconds_at_top` at
+    /// the top of the book, see [`MakerRewards`](crate::state::MakerRewards)
+    pub fn accrue_maker_reward_points(&mut self, base_lots: i64, seconds_at_top: u64) {
+        let points = (base_lots as u64).saturating_mul(seconds_at_top);
+        self.position.maker_reward_points =
+            self.position.maker_reward_points.saturating_add(points);
+    }
+
+    pub fn cancel_order(
+        &mut self,
+        slot: usize,
+        base_quantity: i64,
+        market: &mut Market,
+    ) -> Result<()> {
         let oo = self.open_order_by_raw_index(slot);
         let price = oo.locked_price;
         let order_side = oo.side_and_tree().side();
 
-        let base_quantity_native = (base_quantity * market.base_lot_size) as u64;
-        let quote_quantity_native = (base_quantity * price * market.quote_lot_size) as u64;
+        let base_quantity_native = math::lots_to_native(base_quantity, market.base_lot_size)?;
+        let quote_quantity_native =
+            math::quote_native(base_quantity, price, market.quote_lot_size)?;
         let fees = market.maker_fees_ceil(quote_quantity_native);
 
         let position = &mut self.position;
@@ -321,11 +460,16 @@ impl OpenOrdersAccount {
             Side::Bid => {
                 position.quote_free_native += quote_quantity_native + fees;
                 position.locked_maker_fees -= fees;
+                market.quote_free_native_total += (quote_quantity_native + fees) as i64;
+            }
+            Side::Ask => {
+                position.base_free_native += base_quantity_native;
+                market.base_free_native_total += base_quantity_native as i64;
             }
-            Side::Ask => position.base_free_native += base_quantity_native,
         }
 
-        self.remove_order(slot, base_quantity, price);
+        self.remove_order(market, slot, base_quantity, price);
+        Ok(())
     }
 }
 
@@ -355,8 +499,26 @@ pub struct Position {
     /// Quote lots in open bids
     pub bids_quote_lots: i64,
 
+    /// Cumulative fees paid on maker fills in quote native units
+    pub maker_fees_paid: u64,
+    /// Cumulative rebates received on maker fills in quote native units
+    pub maker_rebates_received: u64,
+    /// Cumulative fees paid on taker trades in quote native units
+    pub taker_fees_paid: u64,
+
+    /// Base lots times seconds at the top of the book not claimed as maker rewards
+    /// yet, see `MakerRewards`
+    pub maker_reward_points: u64,
+    /// Cumulative maker rewards claimed in reward native units
+    pub maker_rewards_claimed: u64,
+    /// Taker fee rebates accrued and not claimed yet, see `TakerRebateCampaign`
+    pub taker_rebates_accrued: u64,
+    /// Maker rebates credited to `quote_free_native` since it was last settled,
+    /// see `claim_rebates`
+    pub maker_rebates_available: u64,
+
     #[derivative(Debug = "ignore")]
-    pub reserved: [u8; 64],
+    pub reserved: [u8; 8],
 }
 
 impl Default for Position {
@@ -372,7 +534,14 @@ impl Default for Position {
             maker_volume: 0,
             taker_volume: 0,
             bids_quote_lots: 0,
-            reserved: [0; 64],
+            maker_fees_paid: 0,
+            maker_rebates_received: 0,
+            taker_fees_paid: 0,
+            maker_reward_points: 0,
+            maker_rewards_claimed: 0,
+            taker_rebates_accrued: 0,
+            maker_rebates_available: 0,
+            reserved: [0; 8],
         }
     }
 }
@@ -1,5 +1,10 @@
 use anchor_lang::prelude::*;
 
+/// Per-owner PDA listing every live [`OpenOrdersAccount`](crate::state::OpenOrdersAccount),
+/// so clients can discover them without scanning all program accounts.
+///
+/// Accounts are added on creation and removed on close; `created_counter` only
+/// ever grows and is used as the `account_num` seed of new open orders accounts.
 #[account]
 #[derive(Default)]
 pub struct OpenOrdersIndexer {
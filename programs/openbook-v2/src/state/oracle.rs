@@ -1,7 +1,7 @@
 use super::switchboard_solana::AggregatorAccountData;
 use anchor_lang::prelude::*;
 use anchor_lang::Discriminator;
-use fixed::types::U64F64;
+use fixed::types::{I80F48, U64F64};
 use switchboard_program::FastRoundResultAccountData;
 
 use crate::accounts_zerocopy::*;
@@ -92,11 +92,10 @@ impl OracleState {
         }
     }
 
-    pub fn has_valid_confidence(&self, oracle_pk: &Pubkey, config: &OracleConfig) -> bool {
+    pub fn has_valid_confidence(&self, config: &OracleConfig) -> bool {
         if self.deviation > config.conf_filter * self.price {
             msg!(
-                "Oracle confidence not good enough: pubkey {}, price: {}, deviation: {}, conf_filter: {}",
-                oracle_pk,
+                "Oracle confidence not good enough: price: {}, deviation: {}, conf_filter: {}",
                 self.price,
                 self.deviation,
                 config.conf_filter,
@@ -107,27 +106,40 @@ impl OracleState {
         }
     }
 
-    pub fn has_valid_combined_confidence(&self, other: &Self, config: &OracleConfig) -> bool {
-        // target uncertainty reads
+    /// The price of `self` in units of `other`, as used by markets with two oracles.
+    /// It's as recent as the older of both.
+    pub fn ratio(&self, other: &Self) -> Self {
+        // the deviation reads
         //   $ \sigma \approx \frac{A}{B} * \sqrt{(\sigma_A/A)^2 + (\sigma_B/B)^2} $
-        // but alternatively, to avoid costly operations, we compute the square
-        // Also note that the relative scaled var, i.e. without the \frac{A}{B} factor, is computed
+        let price = self.price / other.price;
         let relative_var =
             (self.deviation / self.price).powi(2) + (other.deviation / other.price).powi(2);
-
-        let relative_target_var = config.conf_filter.powi(2);
-
-        if relative_var > relative_target_var {
-            msg!(
-                "Combined confidence too high: computed^2: {}, conf_filter^2: {}",
-                relative_var,
-                relative_target_var
-            );
-            false
-        } else {
-            true
+        Self {
+            price,
+            deviation: price * relative_var.sqrt(),
+            last_update_slot: self.last_update_slot.min(other.last_update_slot),
+            oracle_type: self.oracle_type,
         }
     }
+
+    /// Scales the price and deviation by `decimal_adj`, `None` if they don't fit
+    pub fn to_oracle_price(&self, decimal_adj: f64) -> Option<OraclePrice> {
+        Some(OraclePrice {
+            price: I80F48::checked_from_num(self.price * decimal_adj)?,
+            confidence: I80F48::checked_from_num(self.deviation * decimal_adj)?,
+            last_update_slot: self.last_update_slot,
+        })
+    }
+}
+
+/// An oracle price as the market uses it, in native quote tokens per native base
+/// token. See `Market::oracle_price`, which never returns stale or unconfident ones.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OraclePrice {
+    pub price: I80F48,
+    /// Deviation of `price` reported by the oracles, in the same unit
+    pub confidence: I80F48,
+    pub last_update_slot: u64,
 }
 
 #[account(zero_copy)]
@@ -138,7 +150,10 @@ pub struct StubOracle {
     pub last_update_ts: i64,
     pub last_update_slot: u64,
     pub deviation: f64,
-    pub reserved: [u8; 104],
+    /// `price` and `deviation` are multiplied by 10^exponent
+    pub exponent: i8,
+    pub padding: [u8; 7],
+    pub reserved: [u8; 96],
 }
 
 pub fn determine_oracle_type(acc_info: &impl KeyedAccountReader) -> Result<OracleType> {
@@ -217,10 +232,11 @@ pub fn oracle_state_unchecked(acc_info: &impl KeyedAccountReader) -> Result<Orac
             } else {
                 stub.last_update_slot
             };
+            let decimal_adj = power_of_ten_float(stub.exponent);
             OracleState {
-                price: stub.price,
+                price: stub.price * decimal_adj,
                 last_update_slot,
-                deviation: stub.deviation,
+                deviation: stub.deviation * decimal_adj,
                 oracle_type: OracleType::Stub,
             }
         }
@@ -300,6 +316,16 @@ pub fn oracle_state_unchecked(acc_info: &impl KeyedAccountReader) -> Result<Orac
     })
 }
 
+/// Is `price_lots` at most `max_distance_bps` away from `oracle_price_lots`?
+pub fn is_within_oracle_distance(
+    price_lots: i64,
+    oracle_price_lots: i64,
+    max_distance_bps: u16,
+) -> bool {
+    let distance = i128::from(price_lots) - i128::from(oracle_price_lots);
+    distance.abs() * 10_000 <= i128::from(oracle_price_lots) * i128::from(max_distance_bps)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -388,4 +414,48 @@ mod tests {
             assert_eq!(power_of_ten_float(idx), f64::from_str(&s).unwrap());
         }
     }
+
+    #[test]
+    fn test_ratio() {
+        let config = OracleConfigParams {
+            conf_filter: 0.1,
+            max_staleness_slots: None,
+        }
+        .to_oracle_config();
+        let state = |price: f64, deviation: f64, last_update_slot: u64| OracleState {
+            price,
+            deviation,
+            last_update_slot,
+            oracle_type: OracleType::Stub,
+        };
+
+        // 3% and 4% of relative deviation add up to 5%
+        let ratio = state(300.0, 9.0, 10).ratio(&state(2.0, 0.08, 12));
+        assert_eq!(ratio.price, 150.0);
+        assert!((ratio.deviation - 7.5).abs() < 1e-9);
+        assert_eq!(ratio.last_update_slot, 10);
+        assert!(ratio.has_valid_confidence(&config));
+
+        let ratio = state(300.0, 24.0, 10).ratio(&state(2.0, 0.12, 12));
+        assert!((ratio.deviation - 15.0).abs() < 1e-9);
+        assert!(!ratio.has_valid_confidence(&config));
+
+        let price = ratio.to_oracle_price(1e-3).unwrap();
+        assert!((price.price.to_num::<f64>() - 0.15).abs() < 1e-9);
+        assert!((price.confidence.to_num::<f64>() - 0.015).abs() < 1e-9);
+        assert_eq!(price.last_update_slot, 10);
+        assert!(state(1.0, 0.0, 0)
+            .ratio(&state(0.0, 0.0, 0))
+            .to_oracle_price(1.0)
+            .is_none());
+    }
+
+    #[test]
+    fn test_oracle_distance() {
+        assert!(is_within_oracle_distance(10_100, 10_000, 100));
+        assert!(is_within_oracle_distance(9_900, 10_000, 100));
+        assert!(!is_within_oracle_distance(10_101, 10_000, 100));
+        assert!(!is_within_oracle_distance(9_899, 10_000, 100));
+        assert!(is_within_oracle_distance(10_000, 10_000, 0));
+    }
 }
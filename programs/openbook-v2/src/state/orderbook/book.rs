@@ -1,11 +1,18 @@
+use crate::accounts_zerocopy::LoadMutZeroCopyRef;
 use crate::logs::*;
+use crate::math;
 use crate::state::MAX_OPEN_ORDERS;
 use crate::{
     error::*,
-    state::{orderbook::bookside::*, EventHeap, Market, OpenOrdersAccount},
+    state::{
+        find_position_extension, load_position_extension, orderbook::bookside::*, EventHeapStorage,
+        EvictionPolicy, FillHistory, Market, MarketStats, OpenOrdersAccount, PositionExtension,
+        TakerRebateCampaign,
+    },
 };
 use anchor_lang::prelude::*;
-use bytemuck::cast;
+use anchor_lang::Discriminator;
+use bytemuck::{cast, cast_ref};
 use std::cell::RefMut;
 
 use super::*;
@@ -31,6 +38,13 @@ pub struct OrderWithAmounts {
     pub taker_fees: u64,
     pub maker_fees: u64,
     pub referrer_amount: u64,
+    /// Fills against resting orders, in matching order
+    pub fills: Vec<FillEvent>,
+    /// The fills applied to maker accounts among the remaining accounts, for which
+    /// no event was queued
+    pub processed_fills: Vec<FillEvent>,
+    /// Orders removed from the book while placing this one
+    pub outs: Vec<OutEvent>,
 }
 
 impl<'a> Orderbook<'a> {
@@ -43,6 +57,13 @@ impl<'a> Orderbook<'a> {
         self.bids.is_empty() && self.asks.is_empty()
     }
 
+    /// Refresh the cached best level of both book sides, see
+    /// [`BookSide::best_price_lots`]
+    pub fn update_best_levels(&mut self, now_ts: u64) {
+        self.bids.update_best_level(now_ts);
+        self.asks.update_best_level(now_ts);
+    }
+
     pub fn bookside_mut(&mut self, side: Side) -> &mut BookSide {
         match side {
             Side::Bid => &mut self.bids,
@@ -63,7 +84,7 @@ impl<'a> Orderbook<'a> {
         order: &Order,
         open_book_market: &mut Market,
         market_pk: &Pubkey,
-        event_heap: &mut EventHeap,
+        event_heap: &mut impl EventHeapStorage,
         oracle_price_lots: Option<i64>,
         mut open_orders_account: Option<&mut OpenOrdersAccount>,
         owner: &Pubkey,
@@ -77,6 +98,30 @@ impl<'a> Orderbook<'a> {
 
         let other_side = side.invert_side();
         let post_only = order.is_post_only();
+        market.market_state().check_new_order(post_only)?;
+
+        // The position extension also keeps the rate limit window, so it's required
+        // for every order of accounts having one rather than just the filled ones.
+        // Designated market makers are exempt from the rate limit.
+        let rate_limited = market.order_rate_limit > 0
+            && !open_orders_account
+                .as_deref()
+                .map_or(false, |account| account.is_dmm());
+        let mut position_ext = match open_orders_account.as_deref() {
+            Some(open_orders_account) => {
+                require!(
+                    !rate_limited || open_orders_account.has_position_extension(),
+                    OpenBookError::OrderRateLimitWithoutPositionExtension
+                );
+                load_position_extension(open_orders_account, owner, remaining_accs)?
+            }
+            None => None,
+        };
+        if let Some(position_ext) = position_ext.as_deref_mut() {
+            if rate_limited {
+                position_ext.record_new_order(market, now_ts)?;
+            }
+        }
         let fill_or_kill = order.is_fill_or_kill();
         let mut post_target = order.post_target();
         let (price_lots, price_data) = order.price(now_ts, oracle_price_lots, self)?;
@@ -115,15 +160,32 @@ impl<'a> Orderbook<'a> {
         let mut referrer_amount = 0_u64;
         let mut maker_rebates_acc = 0_u64;
 
-        let mut matched_order_changes: Vec<(BookSideOrderHandle, i64)> = vec![];
-        let mut matched_order_deletes: Vec<(BookSideOrderTree, u128)> = vec![];
+        // Matched orders along with the index of their book side, 0 for the market's
+        // book side itself and then its pages
+        let mut matched_order_changes: Vec<(usize, BookSideOrderHandle, i64)> = vec![];
+        let mut matched_order_deletes: Vec<(usize, BookSideOrderTree, u128)> = vec![];
         let mut number_of_dropped_expired_orders = 0;
         let mut number_of_processed_fill_events = 0;
-
+        let mut fills = vec![];
+        let mut processed_fills = vec![];
+        let mut outs = vec![];
+        let mut remaining_open_orders = RemainingOpenOrders::new(remaining_accs);
+        let mut stop_reason = TakerStopReason::BookExhausted;
+
+        let mut opposing_pages = if self.bookside(other_side).paged_leaf_count > 0 {
+            load_all_book_side_pages(self.bookside(other_side), remaining_accs)?
+        } else {
+            vec![]
+        };
         let opposing_bookside = self.bookside_mut(other_side);
-        for best_opposing in opposing_bookside.iter_all_including_invalid(now_ts, oracle_price_lots)
+        let opposing_book_sides: Vec<&BookSide> = std::iter::once(&*opposing_bookside)
+            .chain(opposing_pages.iter().map(|page| &**page))
+            .collect();
+        for (page, best_opposing) in
+            PagedBookSideIter::new(&opposing_book_sides, now_ts, oracle_price_lots)
         {
             if remaining_base_lots == 0 || remaining_quote_lots == 0 {
+                stop_reason = TakerStopReason::QuoteLimit;
                 break;
             }
 
@@ -133,23 +195,27 @@ impl<'a> Orderbook<'a> {
                     number_of_dropped_expired_orders += 1;
                     let event = OutEvent::new(
                         other_side,
-                        best_opposing.node.owner_slot,
+                        best_opposing.node,
+                        OutReason::Expired,
                         now_ts,
-                        event_heap.header.seq_num,
-                        best_opposing.node.owner,
-                        best_opposing.node.quantity,
+                        event_heap.header().seq_num,
+                        market.gen_event_seq_num(),
                     );
 
+                    outs.push(event);
                     process_out_event(
                         event,
                         market,
                         event_heap,
                         open_orders_account.as_deref_mut(),
                         owner,
-                        remaining_accs,
+                        &mut remaining_open_orders,
                     )?;
-                    matched_order_deletes
-                        .push((best_opposing.handle.order_tree, best_opposing.node.key));
+                    matched_order_deletes.push((
+                        page,
+                        best_opposing.handle.order_tree,
+                        best_opposing.node.key,
+                    ));
                 }
                 continue;
             }
@@ -157,6 +223,7 @@ impl<'a> Orderbook<'a> {
             let best_opposing_price = best_opposing.price_lots;
 
             if !side.is_price_within_limit(best_opposing_price, price_lots) {
+                stop_reason = TakerStopReason::PriceLimit;
                 break;
             }
             if post_only {
@@ -167,6 +234,7 @@ impl<'a> Orderbook<'a> {
             if limit == 0 {
                 msg!("Order matching limit reached");
                 post_target = None;
+                stop_reason = TakerStopReason::MatchLimit;
                 break;
             }
 
@@ -174,13 +242,14 @@ impl<'a> Orderbook<'a> {
             // Do not post orders in the book due to bad pricing and negative spread
             if max_match_by_quote == 0 {
                 post_target = None;
+                stop_reason = TakerStopReason::QuoteLimit;
                 break;
             }
 
             let match_base_lots = remaining_base_lots
                 .min(best_opposing.node.quantity)
                 .min(max_match_by_quote);
-            let match_quote_lots = match_base_lots * best_opposing_price;
+            let match_quote_lots = math::quote_lots(match_base_lots, best_opposing_price)?;
 
             // Self-trade behaviour
             if open_orders_account.is_some() && owner == &best_opposing.node.owner {
@@ -191,41 +260,90 @@ impl<'a> Orderbook<'a> {
                     }
                     SelfTradeBehavior::CancelProvide => {
                         // The open orders acc is always present in this case, no need event_heap
-                        open_orders_account.as_mut().unwrap().cancel_order(
-                            best_opposing.node.owner_slot as usize,
+                        let seconds_at_top = seconds_at_top(
+                            market,
+                            opposing_book_sides[0],
+                            best_opposing.handle.order_tree,
+                            best_opposing.node,
+                            now_ts,
+                        );
+                        let open_orders_account = open_orders_account.as_mut().unwrap();
+                        open_orders_account.accrue_maker_reward_points(
                             best_opposing.node.quantity,
-                            *market,
+                            seconds_at_top,
                         );
-                        matched_order_deletes
-                            .push((best_opposing.handle.order_tree, best_opposing.node.key));
+                        open_orders_account.cancel_order(
+                            best_opposing.node.owner_slot as usize,
+                            best_opposing.node.quantity,
+                            market,
+                        )?;
+                        // Only logged, the account is already released
+                        outs.push(OutEvent::new(
+                            other_side,
+                            best_opposing.node,
+                            OutReason::SelfTradeCancel,
+                            now_ts,
+                            event_heap.header().seq_num,
+                            market.gen_event_seq_num(),
+                        ));
+                        matched_order_deletes.push((
+                            page,
+                            best_opposing.handle.order_tree,
+                            best_opposing.node.key,
+                        ));
 
                         // skip actual matching
                         continue;
                     }
                     SelfTradeBehavior::AbortTransaction => {
-                        return err!(OpenBookError::WouldSelfTrade)
+                        emit_taker_execution_log(
+                            market_pk,
+                            order,
+                            owner,
+                            order_max_base_lots - remaining_base_lots,
+                            order_max_quote_lots - remaining_quote_lots,
+                            TakerStopReason::SelfTrade,
+                            true,
+                        );
+                        return err!(OpenBookError::WouldSelfTrade);
                     }
                 }
                 assert!(order.self_trade_behavior == SelfTradeBehavior::DecrementTake);
             } else {
-                maker_rebates_acc +=
-                    market.maker_rebate_floor((match_quote_lots * market.quote_lot_size) as u64);
+                maker_rebates_acc += market.maker_rebate_floor(math::lots_to_native(
+                    match_quote_lots,
+                    market.quote_lot_size,
+                )?);
             }
 
             remaining_base_lots -= match_base_lots;
             remaining_quote_lots -= match_quote_lots;
             assert!(remaining_quote_lots >= 0);
 
+            let maker_seconds_at_top = seconds_at_top(
+                market,
+                opposing_book_sides[0],
+                best_opposing.handle.order_tree,
+                best_opposing.node,
+                now_ts,
+            );
             let new_best_opposing_quantity = best_opposing.node.quantity - match_base_lots;
             let maker_out = new_best_opposing_quantity == 0;
             if maker_out {
-                matched_order_deletes
-                    .push((best_opposing.handle.order_tree, best_opposing.node.key));
+                matched_order_deletes.push((
+                    page,
+                    best_opposing.handle.order_tree,
+                    best_opposing.node.key,
+                ));
             } else {
-                matched_order_changes.push((best_opposing.handle, new_best_opposing_quantity));
+                matched_order_changes.push((
+                    page,
+                    best_opposing.handle,
+                    new_best_opposing_quantity,
+                ));
             }
 
-            let fill = FillEvent::new(
+            let mut fill = FillEvent::new(
                 side,
                 maker_out,
                 best_opposing.node.owner_slot,
@@ -239,20 +357,26 @@ impl<'a> Orderbook<'a> {
                 best_opposing_price,
                 best_opposing.node.peg_limit,
                 match_base_lots,
+                market.gen_event_seq_num(),
             );
+            fill.maker_seconds_at_top = maker_seconds_at_top.try_into().unwrap_or(u32::MAX);
 
             emit_stack(TakerSignatureLog {
                 market: *market_pk,
                 seq_num: market.seq_num,
             });
 
-            process_fill_event(
+            fills.push(fill);
+            let is_processed = process_fill_event(
                 fill,
                 market,
                 event_heap,
-                remaining_accs,
+                &mut remaining_open_orders,
                 &mut number_of_processed_fill_events,
             )?;
+            if is_processed {
+                processed_fills.push(fill);
+            }
 
             limit -= 1;
         }
@@ -262,18 +386,40 @@ impl<'a> Orderbook<'a> {
         assert!(total_quote_lots_taken >= 0);
         assert!(total_base_lots_taken >= 0);
 
-        let total_base_taken_native = (total_base_lots_taken * market.base_lot_size) as u64;
-        let total_quote_taken_native = (total_quote_lots_taken * market.quote_lot_size) as u64;
+        if !post_only && remaining_base_lots > 0 && (post_target.is_none() || fill_or_kill) {
+            emit_taker_execution_log(
+                market_pk,
+                order,
+                owner,
+                total_base_lots_taken,
+                total_quote_lots_taken,
+                stop_reason,
+                fill_or_kill,
+            );
+        }
+
+        let total_base_taken_native =
+            math::lots_to_native(total_base_lots_taken, market.base_lot_size)?;
+        let total_quote_taken_native =
+            math::lots_to_native(total_quote_lots_taken, market.quote_lot_size)?;
 
         // Record the taker trade in the account already, even though it will only be
         // realized when the fill event gets executed
         let mut taker_fees_native = 0_u64;
         if total_quote_lots_taken > 0 || total_base_lots_taken > 0 {
-            let total_quote_taken_native_wo_self =
-                ((total_quote_lots_taken - decremented_quote_lots) * market.quote_lot_size) as u64;
+            let total_quote_taken_native_wo_self = math::lots_to_native(
+                total_quote_lots_taken - decremented_quote_lots,
+                market.quote_lot_size,
+            )?;
 
             if total_quote_taken_native_wo_self > 0 {
-                taker_fees_native = market.taker_fees_ceil(total_quote_taken_native_wo_self);
+                // Designated market makers only pay for the rebates of the makers they take from
+                taker_fees_native = if open_orders_account.as_ref().map_or(false, |oo| oo.is_dmm())
+                {
+                    maker_rebates_acc
+                } else {
+                    market.taker_fees(total_quote_taken_native_wo_self)
+                };
 
                 // Only account taker fees now. Maker fees accounted once processing the event
                 referrer_amount = taker_fees_native - maker_rebates_acc;
@@ -288,7 +434,10 @@ impl<'a> Orderbook<'a> {
                     total_quote_taken_native,
                     taker_fees_native,
                     referrer_amount,
+                    position_ext.as_deref_mut(),
                 );
+                open_orders_account.position.taker_rebates_accrued +=
+                    accrue_taker_rebate(market_pk, now_ts, taker_fees_native, remaining_accs)?;
             } else {
                 market.taker_volume_wo_oo += total_quote_taken_native as u128;
             }
@@ -318,25 +467,30 @@ impl<'a> Orderbook<'a> {
         // Imagine quote_lot_size = 10. A new bid comes in with max_quote lots = 10. It matches against
         // other orders for 5 quote lots total. The taker_fees_native is 15, taker_fees_lots is 2. That
         // means only up the 3 quote lots may be placed on the book.
-        let taker_fees_lots =
-            (taker_fees_native as i64 + market.quote_lot_size - 1) / market.quote_lot_size;
+        let taker_fees_lots = math::native_to_lots_ceil(taker_fees_native, market.quote_lot_size)?;
 
         // Update remaining based on quote_lots taken. If nothing taken, same as the beginning
         remaining_quote_lots =
             order.max_quote_lots_including_fees - total_quote_lots_taken - taker_fees_lots;
 
         // Apply changes to matched asks (handles invalidate on delete!)
-        for (handle, new_quantity) in matched_order_changes {
-            opposing_bookside
+        for (page, handle, new_quantity) in matched_order_changes {
+            book_side_or_page(opposing_bookside, &mut opposing_pages, page)
                 .node_mut(handle.node)
                 .unwrap()
                 .as_leaf_mut()
                 .unwrap()
                 .quantity = new_quantity;
         }
-        for (component, key) in matched_order_deletes {
-            let _removed_leaf = opposing_bookside.remove_by_key(component, key).unwrap();
+        for (page, component, key) in matched_order_deletes {
+            let _removed_leaf = book_side_or_page(opposing_bookside, &mut opposing_pages, page)
+                .remove_by_key(component, key)
+                .unwrap();
+            if page > 0 {
+                opposing_bookside.paged_leaf_count -= 1;
+            }
         }
+        drop(opposing_pages);
 
         //
         // Place remainder on the book if requested
@@ -361,6 +515,20 @@ impl<'a> Orderbook<'a> {
             post_target = None;
         }
 
+        // Only the part resting on the book has to be worth the minimum notional: fills
+        // never count, and a dust remainder after a fill is dropped instead of failing it
+        if post_target.is_some()
+            && math::quote_native(book_base_quantity_lots, price_lots, market.quote_lot_size)?
+                < market.min_order_notional
+        {
+            require!(
+                total_base_lots_taken > 0,
+                OpenBookError::OrderNotionalTooSmall
+            );
+            msg!("Remainder below the minimum order notional is not posted");
+            post_target = None;
+        }
+
         if is_oracle_peg && side.is_price_better(price_lots, order.peg_limit()) {
             msg!(
                 "Posting on book disallowed due to peg_limit, order price {:?}, limit {:?}",
@@ -382,22 +550,22 @@ impl<'a> Orderbook<'a> {
         if let Some(order_tree_target) = post_target {
             require_gte!(
                 market.max_quote_lots(),
-                book_base_quantity_lots * price,
+                math::quote_lots(book_base_quantity_lots, price)?,
                 OpenBookError::InvalidPostAmount
             );
 
-            posted_base_native = book_base_quantity_lots * market.base_lot_size;
-            posted_quote_native = book_base_quantity_lots * price * market.quote_lot_size;
+            posted_base_native =
+                math::lots_to_native(book_base_quantity_lots, market.base_lot_size)?;
+            posted_quote_native =
+                math::quote_native(book_base_quantity_lots, price, market.quote_lot_size)?;
 
             // Open orders always exists in this case
             let open_orders = open_orders_account.as_mut().unwrap();
+            open_orders.check_max_resting_orders(market)?;
 
             // Subtract maker fees in bid.
             if side == Side::Bid {
-                maker_fees_native = market
-                    .maker_fees_ceil(posted_quote_native)
-                    .try_into()
-                    .unwrap();
+                maker_fees_native = market.maker_fees_ceil(posted_quote_native);
 
                 open_orders.position.locked_maker_fees += maker_fees_native;
             }
@@ -407,51 +575,93 @@ impl<'a> Orderbook<'a> {
             if let Some(expired_order) = bookside.remove_one_expired(order_tree_target, now_ts) {
                 let event = OutEvent::new(
                     side,
-                    expired_order.owner_slot,
+                    &expired_order,
+                    OutReason::Expired,
                     now_ts,
-                    event_heap.header.seq_num,
-                    expired_order.owner,
-                    expired_order.quantity,
+                    event_heap.header().seq_num,
+                    market.gen_event_seq_num(),
                 );
+                outs.push(event);
                 process_out_event(
                     event,
                     market,
                     event_heap,
                     Some(open_orders),
                     owner,
-                    remaining_accs,
+                    &mut remaining_open_orders,
                 )?;
             }
 
-            if bookside.is_full() {
-                // If this bid is higher than lowest bid, boot that bid and insert this one
-                let (worst_order, worst_price) =
-                    bookside.remove_worst(now_ts, oracle_price_lots).unwrap();
-                // OpenBookErrorCode::OutOfSpace
+            // Once the book side is full, fixed orders go to its pages if there's room
+            let mut page = None;
+            if bookside.is_full() && order_tree_target == BookSideOrderTree::Fixed {
+                page = load_book_side_pages(bookside, remaining_accs)?
+                    .into_iter()
+                    .find(|page| !page.is_full());
+            }
+            let mut post_to_page = false;
+            if let Some(page) = page.as_mut() {
+                // Keep the better orders on the book side, moving its worst one to the page
+                let worst_price = bookside
+                    .nodes
+                    .find_worst(bookside.root(BookSideOrderTree::Fixed))
+                    .map(|(_, worst)| fixed_price_lots(worst.price_data()));
+                match worst_price {
+                    Some(worst_price) if side.is_price_better(price_lots, worst_price) => {
+                        let (worst_order, _) = bookside.remove_worst_fixed().unwrap();
+                        page.insert_leaf(BookSideOrderTree::Fixed, &worst_order)?;
+                    }
+                    _ => post_to_page = true,
+                }
+                bookside.paged_leaf_count += 1;
+            } else if bookside.is_full() {
+                // Boot the order picked by the market's eviction policy, as long as this
+                // order is priced better, and insert this one
+                let worst = match market.eviction_policy() {
+                    EvictionPolicy::EvictWorstPrice => {
+                        bookside.find_worst(now_ts, oracle_price_lots)
+                    }
+                    EvictionPolicy::RejectIncoming => None,
+                    EvictionPolicy::EvictOldestOutOfBand => bookside.find_oldest_out_of_band(
+                        now_ts,
+                        oracle_price_lots,
+                        market.eviction_band_bps,
+                    ),
+                }
+                .ok_or_else(|| error!(OpenBookError::BookFull))?;
                 require!(
-                    side.is_price_better(price_lots, worst_price),
-                    OpenBookError::SomeError
+                    side.is_price_better(price_lots, worst.price_lots),
+                    OpenBookError::BookFull
                 );
+                // designated market makers' orders are only booted by higher priorities
+                require!(
+                    worst.node.dmm_priority == 0
+                        || open_orders.dmm_priority > worst.node.dmm_priority,
+                    OpenBookError::DmmOrderProtected
+                );
+                let (order_tree, key) = (worst.handle.order_tree, worst.node.key);
+                let worst_order = bookside.remove_by_key(order_tree, key).unwrap();
                 let event = OutEvent::new(
                     side,
-                    worst_order.owner_slot,
+                    &worst_order,
+                    OutReason::BootedForSpace,
                     now_ts,
-                    event_heap.header.seq_num,
-                    worst_order.owner,
-                    worst_order.quantity,
+                    event_heap.header().seq_num,
+                    market.gen_event_seq_num(),
                 );
+                outs.push(event);
                 process_out_event(
                     event,
                     market,
                     event_heap,
                     Some(open_orders),
                     owner,
-                    remaining_accs,
+                    &mut remaining_open_orders,
                 )?;
             }
 
             let owner_slot = open_orders.next_order_slot()?;
-            let new_order = LeafNode::new(
+            let mut new_order = LeafNode::new(
                 owner_slot as u8,
                 order_id,
                 *owner,
@@ -461,9 +671,14 @@ impl<'a> Orderbook<'a> {
                 order.peg_limit(),
                 order.client_order_id,
             );
-            let _result = bookside.insert_leaf(order_tree_target, &new_order)?;
+            new_order.dmm_priority = open_orders.dmm_priority;
+            let _result = match page.as_mut() {
+                Some(page) if post_to_page => page.insert_leaf(order_tree_target, &new_order)?,
+                _ => bookside.insert_leaf(order_tree_target, &new_order)?,
+            };
 
             open_orders.add_order(
+                market,
                 side,
                 order_tree_target,
                 &new_order,
@@ -472,6 +687,10 @@ impl<'a> Orderbook<'a> {
             );
         }
 
+        market.update_event_heap_stats(event_heap);
+        record_fill_history(market_pk, &fills, remaining_accs)?;
+        record_market_stats(market_pk, market, now_ts, &fills, remaining_accs)?;
+
         let placed_order_id = if post_target.is_some() {
             Some(order_id)
         } else {
@@ -480,28 +699,87 @@ impl<'a> Orderbook<'a> {
 
         Ok(OrderWithAmounts {
             order_id: placed_order_id,
-            posted_base_native: posted_base_native as u64,
-            posted_quote_native: posted_quote_native as u64,
+            posted_base_native,
+            posted_quote_native,
             total_base_taken_native,
             total_quote_taken_native,
             referrer_amount,
             taker_fees: taker_fees_native,
             maker_fees: maker_fees_native,
+            fills,
+            processed_fills,
+            outs,
         })
     }
 
+    /// Removes up to `limit` expired orders from each side, earliest expiry first,
+    /// and applies their out events to the open orders accounts among
+    /// `remaining_accs`, queuing the others. Returns the out events.
+    ///
+    /// Only as many orders as the side's due [`ExpiryBucket`]s hold are looked for,
+    /// each one found through the order tree's earliest expiry. Orders on book side
+    /// pages aren't removed.
+    pub fn expire_orders<'c: 'info, 'info>(
+        &mut self,
+        market: &mut Market,
+        event_heap: &mut impl EventHeapStorage,
+        now_ts: u64,
+        limit: u8,
+        remaining_accs: &'c [AccountInfo<'info>],
+    ) -> Result<Vec<OutEvent>> {
+        let mut remaining_open_orders = RemainingOpenOrders::new(remaining_accs);
+        let mut outs = vec![];
+        for side in [Side::Bid, Side::Ask] {
+            let bookside = self.bookside_mut(side);
+            let due = bookside
+                .nodes
+                .due_expiry_order_count(now_ts)
+                .min(limit.into());
+            for _ in 0..due {
+                let expired_order =
+                    match bookside.remove_one_expired(BookSideOrderTree::Fixed, now_ts) {
+                        Some(expired_order) => expired_order,
+                        None => break,
+                    };
+                let event = OutEvent::new(
+                    side,
+                    &expired_order,
+                    OutReason::Expired,
+                    now_ts,
+                    event_heap.header().seq_num,
+                    market.gen_event_seq_num(),
+                );
+                outs.push(event);
+                process_out_event(
+                    event,
+                    market,
+                    event_heap,
+                    None,
+                    &Pubkey::default(),
+                    &mut remaining_open_orders,
+                )?;
+            }
+        }
+        Ok(outs)
+    }
+
     /// Cancels up to `limit` orders that are listed on the openorders account for the given market.
     /// Optionally filters by `side_to_cancel_option`.
     /// The orders are removed from the book and from the openorders account open order list.
+    /// Returns the removed orders along with their side.
+    ///
+    /// Orders on book side pages are only found if the pages are among `remaining_accs`.
     pub fn cancel_all_orders(
         &mut self,
         open_orders_account: &mut OpenOrdersAccount,
-        market: Market,
+        market: &mut Market,
         mut limit: u8,
         side_to_cancel_option: Option<Side>,
         client_id_option: Option<u64>,
-    ) -> Result<i64> {
-        let mut total_quantity = 0_i64;
+        now_ts: u64,
+        remaining_accs: &[AccountInfo],
+    ) -> Result<Vec<(Side, LeafNode)>> {
+        let mut cancelled = vec![];
         for i in 0..MAX_OPEN_ORDERS {
             let oo = open_orders_account.open_orders[i];
             if oo.is_free() {
@@ -534,6 +812,8 @@ impl<'a> Orderbook<'a> {
                 order_side_and_tree,
                 market,
                 None,
+                now_ts,
+                remaining_accs,
             );
             if cancel_result.is_anchor_error_with_code(OpenBookError::OrderIdNotFound.into()) {
                 // It's possible for the order to be filled or expired already.
@@ -544,86 +824,405 @@ impl<'a> Orderbook<'a> {
                     order_id
                 );
             } else {
-                total_quantity += cancel_result?.quantity;
+                cancelled.push((order_side_and_tree.side(), cancel_result?));
             }
 
             limit -= 1;
         }
-        Ok(total_quantity)
+        Ok(cancelled)
     }
 
     /// Cancels an order on a side, removing it from the book and the openorders account orders list
+    /// and accruing the maker reward points it earned at the top of the book
+    ///
+    /// Orders on book side pages are only found if the pages are among `remaining_accs`.
+    #[allow(clippy::too_many_arguments)]
     pub fn cancel_order(
         &mut self,
         open_orders_account: &mut OpenOrdersAccount,
         order_id: u128,
         side_and_tree: SideAndOrderTree,
-        market: Market,
+        market: &mut Market,
         expected_owner: Option<Pubkey>,
+        now_ts: u64,
+        remaining_accs: &[AccountInfo],
     ) -> Result<LeafNode> {
         let side = side_and_tree.side();
         let book_component = side_and_tree.order_tree();
-        let leaf_node = self.bookside_mut(side).
-        remove_by_key(book_component, order_id).ok_or_else(|| {
+        let bookside = self.bookside_mut(side);
+        let mut leaf_node = bookside.remove_by_key(book_component, order_id);
+        if leaf_node.is_none() && bookside.paged_leaf_count > 0 {
+            for mut page in load_book_side_pages(bookside, remaining_accs)? {
+                leaf_node = page.remove_by_key(book_component, order_id);
+                if leaf_node.is_some() {
+                    bookside.paged_leaf_count -= 1;
+                    break;
+                }
+            }
+        }
+        let leaf_node = leaf_node.ok_or_else(|| {
             // possibly already filled or expired?
             error_msg_typed!(OpenBookError::OrderIdNotFound, "no order with id {order_id}, side {side:?}, component {book_component:?} found on the orderbook")
         })?;
         if let Some(owner) = expected_owner {
             require_keys_eq!(leaf_node.owner, owner);
         }
-        open_orders_account.cancel_order(leaf_node.owner_slot as usize, leaf_node.quantity, market);
+        open_orders_account.accrue_maker_reward_points(
+            leaf_node.quantity,
+            seconds_at_top(&market, bookside, book_component, &leaf_node, now_ts),
+        );
+        open_orders_account.cancel_order(
+            leaf_node.owner_slot as usize,
+            leaf_node.quantity,
+            market,
+        )?;
 
         Ok(leaf_node)
     }
 }
 
-pub fn process_out_event<'c: 'info, 'info>(
+/// Open orders accounts among the remaining accounts, each one loaded at most once
+/// while placing an order: a taker crossing many levels often fills against the same
+/// makers repeatedly.
+///
+/// Accounts with a [`PositionExtension`] are loaded along with it, they count as
+/// missing if the extension wasn't passed too.
+pub struct RemainingOpenOrders<'c, 'info> {
+    remaining_accs: &'c [AccountInfo<'info>],
+    loaded: Vec<(
+        Pubkey,
+        RefMut<'c, OpenOrdersAccount>,
+        Option<RefMut<'c, PositionExtension>>,
+    )>,
+}
+
+impl<'c, 'info> RemainingOpenOrders<'c, 'info> {
+    pub fn new(remaining_accs: &'c [AccountInfo<'info>]) -> Self {
+        Self {
+            remaining_accs,
+            loaded: vec![],
+        }
+    }
+
+    /// The open orders account at `key` and its position extension, None if it
+    /// isn't among the remaining accounts
+    pub fn get_mut(
+        &mut self,
+        key: &Pubkey,
+    ) -> Result<Option<(&mut OpenOrdersAccount, Option<&mut PositionExtension>)>> {
+        let index = match self.loaded.iter().position(|(k, _, _)| k == key) {
+            Some(index) => index,
+            None => {
+                let ai = match self.remaining_accs.iter().find(|ai| ai.key == key) {
+                    Some(ai) => ai,
+                    None => return Ok(None),
+                };
+                let open_orders_account = ai.load_mut::<OpenOrdersAccount>()?;
+                let position_ext = if open_orders_account.has_position_extension() {
+                    match find_position_extension(key, self.remaining_accs)? {
+                        Some(position_ext) => Some(position_ext),
+                        None => return Ok(None),
+                    }
+                } else {
+                    None
+                };
+                self.loaded.push((*key, open_orders_account, position_ext));
+                self.loaded.len() - 1
+            }
+        };
+        let (_, open_orders_account, position_ext) = &mut self.loaded[index];
+        Ok(Some((open_orders_account, position_ext.as_deref_mut())))
+    }
+}
+
+/// The pages chained after `book_side` that are among the remaining accounts, in
+/// order. Stops at the first page that wasn't passed.
+pub fn load_book_side_pages<'c, 'info>(
+    book_side: &BookSide,
+    remaining_accs: &'c [AccountInfo<'info>],
+) -> Result<Vec<RefMut<'c, BookSide>>> {
+    let mut pages: Vec<RefMut<'c, BookSide>> = vec![];
+    let mut next_page = book_side.next_page;
+    while next_page != Pubkey::default() && pages.len() < MAX_BOOK_SIDE_PAGES {
+        let ai = match remaining_accs.iter().find(|ai| ai.key == &next_page) {
+            Some(ai) => ai,
+            None => break,
+        };
+        let page = ai.load_mut::<BookSide>()?;
+        next_page = page.next_page;
+        pages.push(page);
+    }
+    Ok(pages)
+}
+
+/// Like [`load_book_side_pages`], but fails with `MissingBookSidePages` unless all
+/// the pages holding orders were passed.
+pub fn load_all_book_side_pages<'c, 'info>(
+    book_side: &BookSide,
+    remaining_accs: &'c [AccountInfo<'info>],
+) -> Result<Vec<RefMut<'c, BookSide>>> {
+    let pages = load_book_side_pages(book_side, remaining_accs)?;
+    let paged_leaf_count: u32 = pages
+        .iter()
+        .map(|page| page.roots.iter().map(|root| root.leaf_count).sum::<u32>())
+        .sum();
+    require_eq!(
+        paged_leaf_count,
+        book_side.paged_leaf_count,
+        OpenBookError::MissingBookSidePages
+    );
+    Ok(pages)
+}
+
+/// The book side at `index` of a book side followed by its pages
+fn book_side_or_page<'b>(
+    book_side: &'b mut BookSide,
+    pages: &'b mut [RefMut<BookSide>],
+    index: usize,
+) -> &'b mut BookSide {
+    match index {
+        0 => book_side,
+        _ => &mut pages[index - 1],
+    }
+}
+
+pub fn process_out_event(
     event: OutEvent,
-    market: &Market,
-    event_heap: &mut EventHeap,
+    market: &mut Market,
+    event_heap: &mut impl EventHeapStorage,
     open_orders_account: Option<&mut OpenOrdersAccount>,
     owner: &Pubkey,
-    remaining_accs: &'c [AccountInfo<'info>],
+    remaining_open_orders: &mut RemainingOpenOrders,
 ) -> Result<()> {
     if let Some(acc) = open_orders_account {
         if owner == &event.owner {
-            acc.cancel_order(event.owner_slot as usize, event.quantity, *market);
-            return Ok(());
+            return acc.cancel_order(event.owner_slot as usize, event.quantity, market);
         }
     }
 
-    if let Some(acc) = remaining_accs.iter().find(|ai| ai.key == &event.owner) {
-        let ooa: AccountLoader<OpenOrdersAccount> = AccountLoader::try_from(acc)?;
-        let mut acc = ooa.load_mut()?;
-        acc.cancel_order(event.owner_slot as usize, event.quantity, *market);
+    if let Some((acc, _)) = remaining_open_orders.get_mut(&event.owner)? {
+        acc.cancel_order(event.owner_slot as usize, event.quantity, market)?;
     } else {
+        boot_oldest_event(market, event_heap, remaining_open_orders)?;
         event_heap.push_back(cast(event));
     }
 
     Ok(())
 }
 
-pub fn process_fill_event<'c: 'info, 'info>(
+/// Seconds `leaf` of `book_side` has been at the top of the book, see
+/// [`BookSide::seconds_at_top`]. Oracle pegged orders don't earn maker reward points.
+fn seconds_at_top(
+    market: &Market,
+    book_side: &BookSide,
+    order_tree: BookSideOrderTree,
+    leaf: &LeafNode,
+    now_ts: u64,
+) -> u64 {
+    match order_tree {
+        BookSideOrderTree::Fixed => book_side.seconds_at_top(
+            fixed_price_lots(leaf.price_data()),
+            leaf.timestamp,
+            market.maker_rewards_start_ts,
+            now_ts,
+        ),
+        BookSideOrderTree::OraclePegged => 0,
+    }
+}
+
+/// Applies `event` to the maker if it's among the remaining accounts, queues it
+/// otherwise. Returns whether it was applied.
+pub fn process_fill_event(
     event: FillEvent,
     market: &mut Market,
-    event_heap: &mut EventHeap,
-    remaining_accs: &'c [AccountInfo<'info>],
+    event_heap: &mut impl EventHeapStorage,
+    remaining_open_orders: &mut RemainingOpenOrders,
     number_of_processed_fill_events: &mut usize,
-) -> Result<()> {
+) -> Result<bool> {
     let mut is_processed = false;
     if *number_of_processed_fill_events < FILL_EVENT_REMAINING_LIMIT {
-        if let Some(acc) = remaining_accs.iter().find(|ai| ai.key == &event.maker) {
-            let ooa: AccountLoader<OpenOrdersAccount> = AccountLoader::try_from(acc)?;
-            let mut maker = ooa.load_mut()?;
-            maker.execute_maker(market, &event);
+        if let Some((maker, maker_ext)) = remaining_open_orders.get_mut(&event.maker)? {
+            maker.execute_maker(market, &event, maker_ext)?;
             is_processed = true;
             *number_of_processed_fill_events += 1;
         }
     }
 
     if !is_processed {
+        boot_oldest_event(market, event_heap, remaining_open_orders)?;
         event_heap.push_back(cast(event));
     }
 
+    Ok(is_processed)
+}
+
+/// Records `fills` in the market's [`FillHistory`] if it's among the remaining accounts.
+fn record_fill_history<'c: 'info, 'info>(
+    market_pk: &Pubkey,
+    fills: &[FillEvent],
+    remaining_accs: &'c [AccountInfo<'info>],
+) -> Result<()> {
+    if fills.is_empty() {
+        return Ok(());
+    }
+
+    let fill_history_ai = remaining_accs.iter().find(|ai| {
+        ai.owner == &crate::id()
+            && ai.try_borrow_data().map_or(false, |data| {
+                data.starts_with(&FillHistory::discriminator())
+            })
+    });
+    if let Some(ai) = fill_history_ai {
+        let loader: AccountLoader<FillHistory> = AccountLoader::try_from(ai)?;
+        let mut fill_history = loader.load_mut()?;
+        require_keys_eq!(
+            fill_history.market,
+            *market_pk,
+            OpenBookError::InvalidFillHistory
+        );
+        for fill in fills {
+            fill_history.push(fill);
+        }
+    }
+
+    Ok(())
+}
+
+/// Records `fills` in the market's [`MarketStats`] if it's among the remaining accounts.
+fn record_market_stats<'c: 'info, 'info>(
+    market_pk: &Pubkey,
+    market: &Market,
+    now_ts: u64,
+    fills: &[FillEvent],
+    remaining_accs: &'c [AccountInfo<'info>],
+) -> Result<()> {
+    if fills.is_empty() {
+        return Ok(());
+    }
+
+    let market_stats_ai = remaining_accs.iter().find(|ai| {
+        ai.owner == &crate::id()
+            && ai.try_borrow_data().map_or(false, |data| {
+                data.starts_with(&MarketStats::discriminator())
+            })
+    });
+    if let Some(ai) = market_stats_ai {
+        let loader: AccountLoader<MarketStats> = AccountLoader::try_from(ai)?;
+        let mut market_stats = loader.load_mut()?;
+        require_keys_eq!(
+            market_stats.market,
+            *market_pk,
+            OpenBookError::InvalidMarketStats
+        );
+        for fill in fills {
+            market_stats.record_fill(
+                now_ts,
+                fill.price,
+                math::quote_native(fill.quantity, fill.price, market.quote_lot_size)?,
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Accrues the rebate of `taker_fees_native` in the market's [`TakerRebateCampaign`]
+/// if it's among the remaining accounts, returns the amount rebated.
+fn accrue_taker_rebate<'c: 'info, 'info>(
+    market_pk: &Pubkey,
+    now_ts: u64,
+    taker_fees_native: u64,
+    remaining_accs: &'c [AccountInfo<'info>],
+) -> Result<u64> {
+    if taker_fees_native == 0 {
+        return Ok(0);
+    }
+
+    let campaign_ai = remaining_accs.iter().find(|ai| {
+        ai.owner == &crate::id()
+            && ai.try_borrow_data().map_or(false, |data| {
+                data.starts_with(&TakerRebateCampaign::discriminator())
+            })
+    });
+    match campaign_ai {
+        Some(ai) => {
+            let loader: AccountLoader<TakerRebateCampaign> = AccountLoader::try_from(ai)?;
+            let mut campaign = loader.load_mut()?;
+            require_keys_eq!(
+                campaign.market,
+                *market_pk,
+                OpenBookError::InvalidTakerRebateCampaign
+            );
+            Ok(campaign.accrue(now_ts, taker_fees_native))
+        }
+        None => Ok(0),
+    }
+}
+
+fn emit_taker_execution_log(
+    market_pk: &Pubkey,
+    order: &Order,
+    owner: &Pubkey,
+    base_lots_filled: i64,
+    quote_lots_filled: i64,
+    reason: TakerStopReason,
+    aborted: bool,
+) {
+    let average_price_lots = if base_lots_filled > 0 {
+        quote_lots_filled / base_lots_filled
+    } else {
+        0
+    };
+    emit_stack(TakerExecutionLog {
+        market: *market_pk,
+        side: order.side.into(),
+        owner: *owner,
+        client_order_id: order.client_order_id,
+        max_base_lots: order.max_base_lots,
+        base_lots_filled,
+        quote_lots_filled,
+        average_price_lots,
+        reason: reason.into(),
+        aborted,
+    });
+}
+
+/// Makes room on a full event heap by processing its oldest event, so a busy
+/// market doesn't block order placement until the crank catches up.
+///
+/// The open orders account of the oldest event, and its position extension if it
+/// has one, have to be among the remaining accounts, fails with `EventHeapFull`
+/// otherwise.
+fn boot_oldest_event(
+    market: &mut Market,
+    event_heap: &mut impl EventHeapStorage,
+    remaining_open_orders: &mut RemainingOpenOrders,
+) -> Result<()> {
+    if !event_heap.is_full() {
+        return Ok(());
+    }
+
+    let event = *event_heap.front().unwrap();
+    let key = event
+        .open_orders_account()
+        .ok_or(OpenBookError::InvalidEventType)?;
+    let (acc, position_ext) = remaining_open_orders
+        .get_mut(&key)?
+        .ok_or(OpenBookError::EventHeapFull)?;
+
+    match EventType::try_from(event.event_type)
+        .map_err(|_| error!(OpenBookError::InvalidEventType))?
+    {
+        EventType::Fill => {
+            let fill: &FillEvent = cast_ref(&event);
+            acc.execute_maker(market, fill, position_ext)?;
+        }
+        EventType::Out => {
+            let out: &OutEvent = cast_ref(&event);
+            acc.cancel_order(out.owner_slot as usize, out.quantity, market)?;
+        }
+    }
+
+    event_heap.pop_front()?;
     Ok(())
 }
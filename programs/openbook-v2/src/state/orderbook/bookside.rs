@@ -26,11 +26,67 @@ pub struct BookSideOrderHandle {
     pub order_tree: BookSideOrderTree,
 }
 
+/// Orders of a [`BookSide`] aggregated by price, see [`BookSide::levels`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BookLevel {
+    pub price_lots: i64,
+    /// Base lots resting at `price_lots`.
+    pub quantity: i64,
+    /// Base lots resting at `price_lots` or better.
+    pub cumulative_quantity: i64,
+}
+
+/// A resting order of a [`BookSide`] with its owner, see [`BookSide::orders`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BookOrder {
+    pub order_id: u128,
+    /// Address of the owning OpenOrdersAccount
+    pub owner: Pubkey,
+    pub owner_slot: u8,
+    pub client_order_id: u64,
+    pub price_lots: i64,
+    pub quantity: i64,
+    pub timestamp: u64,
+    /// Time at which the order expires, `None` if never
+    pub expiry: Option<u64>,
+    pub is_oracle_pegged: bool,
+    /// False for orders that expired or exceeded their peg limit
+    pub is_valid: bool,
+}
+
+/// Maximum number of pages chained after a market's book side, see [`BookSide::next_page`]
+pub const MAX_BOOK_SIDE_PAGES: usize = 4;
+
 #[account(zero_copy)]
 pub struct BookSide {
     pub roots: [OrderTreeRoot; 2],
     pub reserved_roots: [OrderTreeRoot; 4],
-    pub reserved: [u8; 256],
+    /// Page holding further fixed price orders once this book side is full, created
+    /// with `create_book_side_page`. Default when there is none.
+    ///
+    /// Pages hold the orders posted while the market's book side was full, it keeps
+    /// the better ones. Instructions matching against a side with paged orders need
+    /// all its pages as remaining accounts.
+    pub next_page: Pubkey,
+    /// Orders resting on the pages after this book side, only tracked on the market's
+    /// book side itself.
+    pub paged_leaf_count: u32,
+    pub padding: [u8; 4],
+    /// Price of the best valid fixed price order, 0 when there is none.
+    ///
+    /// Top of the book as of the last instruction that changed it, for programs and
+    /// clients that don't want to load the whole book side. Oracle pegged orders
+    /// aren't included, their price moves with the oracle.
+    pub best_price_lots: i64,
+    /// Base lots of the valid fixed price orders at `best_price_lots`, 0 when there
+    /// are none.
+    pub best_base_lots: i64,
+    /// Time `best_price_lots` last changed, since when the orders at that price are
+    /// at the top of the book. See `seconds_at_top`.
+    pub best_price_since: u64,
+    pub reserved: [u8; 192],
     pub nodes: OrderTreeNodes,
 }
 
@@ -76,10 +132,18 @@ impl BookSide {
         self.nodes.is_full()
     }
 
+    /// Compact the nodes of both order trees, moving at most `limit` nodes, see
+    /// [`OrderTreeNodes::defragment`]. Returns the number of nodes moved.
+    pub fn defragment(&mut self, limit: usize) -> usize {
+        self.nodes.defragment(&mut self.roots, limit)
+    }
+
+    /// True if neither this book side nor its pages hold orders
     pub fn is_empty(&self) -> bool {
-        [BookSideOrderTree::Fixed, BookSideOrderTree::OraclePegged]
-            .into_iter()
-            .all(|component| self.nodes.iter(self.root(component)).count() == 0)
+        self.paged_leaf_count == 0
+            && [BookSideOrderTree::Fixed, BookSideOrderTree::OraclePegged]
+                .into_iter()
+                .all(|component| self.nodes.iter(self.root(component)).count() == 0)
     }
 
     pub fn insert_leaf(
@@ -91,23 +155,49 @@ impl BookSide {
         self.nodes.insert_leaf(root, new_leaf)
     }
 
-    /// Remove the overall worst-price order.
-    pub fn remove_worst(
-        &mut self,
+    /// The overall worst-price order, the one `remove_worst` removes.
+    pub fn find_worst(
+        &self,
         now_ts: u64,
         oracle_price_lots: Option<i64>,
-    ) -> Option<(LeafNode, i64)> {
+    ) -> Option<BookSideIterItem> {
         let worst_fixed = self.nodes.find_worst(&self.roots[0]);
         let worst_pegged = self.nodes.find_worst(&self.roots[1]);
         let side = self.nodes.order_tree_type().side();
-        let worse = rank_orders(
+        rank_orders(
             side,
             worst_fixed,
             worst_pegged,
             true,
             now_ts,
             oracle_price_lots,
-        )?;
+        )
+    }
+
+    /// Find the oldest order priced more than `band_bps` away from the oracle
+    /// price, or from the best price of this side when there's no oracle.
+    pub fn find_oldest_out_of_band(
+        &self,
+        now_ts: u64,
+        oracle_price_lots: Option<i64>,
+        band_bps: u16,
+    ) -> Option<BookSideIterItem> {
+        let reference_lots = oracle_price_lots.or_else(|| self.best_price(now_ts, None))?;
+        let band_lots = i128::from(reference_lots) * i128::from(band_bps) / 10_000;
+        self.iter_all_including_invalid(now_ts, oracle_price_lots)
+            .filter(|item| {
+                (i128::from(item.price_lots) - i128::from(reference_lots)).abs() > band_lots
+            })
+            .min_by_key(|item| item.node.timestamp)
+    }
+
+    /// Remove the overall worst-price order.
+    pub fn remove_worst(
+        &mut self,
+        now_ts: u64,
+        oracle_price_lots: Option<i64>,
+    ) -> Option<(LeafNode, i64)> {
+        let worse = self.find_worst(now_ts, oracle_price_lots)?;
         let price = worse.price_lots;
         let key = worse.node.key;
         let order_tree = worse.handle.order_tree;
@@ -115,6 +205,15 @@ impl BookSide {
         Some((n, price))
     }
 
+    /// Remove the worst-price fixed order, along with its price.
+    pub fn remove_worst_fixed(&mut self) -> Option<(LeafNode, i64)> {
+        let (_, worst) = self.nodes.find_worst(self.root(BookSideOrderTree::Fixed))?;
+        let key = worst.key;
+        let price = fixed_price_lots(worst.price_data());
+        let n = self.remove_by_key(BookSideOrderTree::Fixed, key)?;
+        Some((n, price))
+    }
+
     /// Remove the order with the lowest expiry timestamp in the component, if that's < now_ts.
     /// If there is none, try to remove the lowest expiry one from the other component.
     pub fn remove_one_expired(
@@ -135,6 +234,11 @@ impl BookSide {
         self.nodes.remove_one_expired(other_root, now_ts)
     }
 
+    pub fn find_by_key(&self, component: BookSideOrderTree, search_key: u128) -> Option<&LeafNode> {
+        self.nodes
+            .find_by_key(&self.roots[component as usize], search_key)
+    }
+
     pub fn remove_by_key(
         &mut self,
         component: BookSideOrderTree,
@@ -175,6 +279,46 @@ impl BookSide {
         )
     }
 
+    /// Return the price of the order closest to the spread and the quantity of all
+    /// orders at that price
+    pub fn best_level(&self, now_ts: u64, oracle_price_lots: Option<i64>) -> Option<(i64, i64)> {
+        let mut orders = self.iter_valid(now_ts, oracle_price_lots);
+        let best = orders.next()?;
+        let quantity = orders
+            .take_while(|order| order.price_lots == best.price_lots)
+            .fold(best.node.quantity, |sum, order| sum + order.node.quantity);
+        Some((best.price_lots, quantity))
+    }
+
+    /// Refresh `best_price_lots` and `best_base_lots`, after the book side changed
+    pub fn update_best_level(&mut self, now_ts: u64) {
+        let (best_price_lots, best_base_lots) = self.best_level(now_ts, None).unwrap_or((0, 0));
+        if best_price_lots != self.best_price_lots {
+            self.best_price_since = now_ts;
+        }
+        (self.best_price_lots, self.best_base_lots) = (best_price_lots, best_base_lots);
+    }
+
+    /// Seconds a fixed price order at `price_lots`, placed at `placed_ts`, has been at
+    /// the top of the book by `now_ts`, earning maker reward points since `start_ts`.
+    ///
+    /// That's the time since its price became the best of the side, since it was
+    /// placed or since `start_ts`, whichever is later, and 0 when its price isn't the
+    /// best or `start_ts` is 0. Time at the top before another price overtook it
+    /// isn't counted.
+    pub fn seconds_at_top(
+        &self,
+        price_lots: i64,
+        placed_ts: u64,
+        start_ts: u64,
+        now_ts: u64,
+    ) -> u64 {
+        if start_ts == 0 || self.best_price_lots == 0 || price_lots != self.best_price_lots {
+            return 0;
+        }
+        now_ts.saturating_sub(placed_ts.max(self.best_price_since).max(start_ts))
+    }
+
     /// Walk up the book `quantity` units and return the price at that level. If `quantity` units
     /// not on book, return None
     pub fn impact_price(&self, quantity: i64, now_ts: u64, oracle_price_lots: i64) -> Option<i64> {
@@ -187,6 +331,60 @@ impl BookSide {
         }
         None
     }
+
+    /// Iterate all orders with their owner, including invalid ones, starting
+    /// closest to the spread
+    pub fn orders(
+        &self,
+        now_ts: u64,
+        oracle_price_lots: Option<i64>,
+    ) -> impl Iterator<Item = BookOrder> + '_ {
+        self.iter_all_including_invalid(now_ts, oracle_price_lots)
+            .map(|item| BookOrder {
+                order_id: item.node.key,
+                owner: item.node.owner,
+                owner_slot: item.node.owner_slot,
+                client_order_id: item.node.client_order_id,
+                price_lots: item.price_lots,
+                quantity: item.node.quantity,
+                timestamp: item.node.timestamp,
+                expiry: (item.node.time_in_force > 0).then(|| item.node.expiry()),
+                is_oracle_pegged: item.handle.order_tree == BookSideOrderTree::OraclePegged,
+                is_valid: item.is_valid(),
+            })
+    }
+
+    /// Aggregate the valid orders into at most `depth` price levels, starting
+    /// closest to the spread
+    pub fn levels(
+        &self,
+        depth: usize,
+        now_ts: u64,
+        oracle_price_lots: Option<i64>,
+    ) -> Vec<BookLevel> {
+        let mut levels: Vec<BookLevel> = Vec::with_capacity(depth);
+        let mut cumulative_quantity = 0;
+        for order in self.iter_valid(now_ts, oracle_price_lots) {
+            cumulative_quantity += order.node.quantity;
+            match levels.last_mut() {
+                Some(level) if level.price_lots == order.price_lots => {
+                    level.quantity += order.node.quantity;
+                    level.cumulative_quantity = cumulative_quantity;
+                }
+                _ => {
+                    if levels.len() == depth {
+                        break;
+                    }
+                    levels.push(BookLevel {
+                        price_lots: order.price_lots,
+                        quantity: order.node.quantity,
+                        cumulative_quantity,
+                    });
+                }
+            }
+        }
+        levels
+    }
 }
 
 #[cfg(test)]
@@ -253,7 +451,13 @@ mod tests {
         let bookside = BookSide {
             roots: [root_fixed, root_pegged],
             reserved_roots: [OrderTreeRoot::zeroed(); 4],
-            reserved: [0; 256],
+            next_page: Pubkey::default(),
+            paged_leaf_count: 0,
+            padding: [0; 4],
+            best_price_lots: 0,
+            best_base_lots: 0,
+            best_price_since: 0,
+            reserved: [0; 192],
             nodes: order_tree,
         };
 
@@ -274,6 +478,14 @@ mod tests {
                 total += 1;
             }
             assert!(total >= 101); // some oracle peg orders could be skipped
+            let visible_pegged = bookside
+                .nodes
+                .iter(bookside.root(BookSideOrderTree::OraclePegged))
+                .filter(|(_, node)| {
+                    oracle_pegged_price(oracle_price_lots, node, side).0 != OrderState::Skipped
+                })
+                .count();
+            assert_eq!(total, 100 + visible_pegged);
             if oracle_price_lots > 20 {
                 assert_eq!(total, 200);
             }
@@ -322,7 +534,13 @@ mod tests {
         BookSide {
             roots: [root_fixed, root_pegged],
             reserved_roots: [OrderTreeRoot::zeroed(); 4],
-            reserved: [0; 256],
+            next_page: Pubkey::default(),
+            paged_leaf_count: 0,
+            padding: [0; 4],
+            best_price_lots: 0,
+            best_base_lots: 0,
+            best_price_since: 0,
+            reserved: [0; 192],
             nodes: order_tree.into_inner(),
         }
     }
@@ -391,5 +609,261 @@ mod tests {
         assert_eq!(order_prices(0, 100), Vec::<i64>::new());
     }
 
+    #[test]
+    fn bookside_levels() {
+        let mut order_tree = new_order_tree(OrderTreeType::Asks);
+        let mut root_fixed = OrderTreeRoot::zeroed();
+        for (seq_num, (price, quantity)) in [(100, 1), (100, 2), (101, 3), (103, 4)]
+            .into_iter()
+            .enumerate()
+        {
+            let key = new_node_key(Side::Ask, fixed_price_data(price).unwrap(), seq_num as u64);
+            let leaf = LeafNode::new(0, key, Pubkey::default(), quantity, 0, 0, -1, 0);
+            order_tree.insert_leaf(&mut root_fixed, &leaf).unwrap();
+        }
+
+        let bookside = BookSide {
+            roots: [root_fixed, OrderTreeRoot::zeroed()],
+            reserved_roots: [OrderTreeRoot::zeroed(); 4],
+            next_page: Pubkey::default(),
+            paged_leaf_count: 0,
+            padding: [0; 4],
+            best_price_lots: 0,
+            best_base_lots: 0,
+            best_price_since: 0,
+            reserved: [0; 192],
+            nodes: order_tree,
+        };
+
+        let level = |price_lots: i64, quantity: i64, cumulative_quantity: i64| BookLevel {
+            price_lots,
+            quantity,
+            cumulative_quantity,
+        };
+
+        assert_eq!(
+            bookside.levels(2, 0, None),
+            vec![level(100, 3, 3), level(101, 3, 6)]
+        );
+        assert_eq!(
+            bookside.levels(10, 0, None),
+            vec![level(100, 3, 3), level(101, 3, 6), level(103, 4, 10)]
+        );
+        assert_eq!(bookside.levels(0, 0, None), vec![]);
+    }
+
+    #[test]
+    fn bookside_orders() {
+        let bookside = bookside_setup();
+
+        let orders: Vec<BookOrder> = bookside.orders(1006, Some(100)).collect();
+        let prices: Vec<i64> = orders.iter().map(|o| o.price_lots).collect();
+        assert_eq!(prices, vec![120, 100, 90, 85, 80]);
+
+        assert_eq!(orders[0].expiry, Some(1005));
+        assert!(!orders[0].is_valid);
+        assert!(!orders[0].is_oracle_pegged);
+        assert_eq!(orders[1].expiry, None);
+        assert!(orders[1].is_valid);
+        assert!(orders[2].is_oracle_pegged);
+        assert_eq!(orders[4].expiry, Some(1007));
+        assert!(orders[4].is_valid);
+    }
+
+    #[test]
+    fn bookside_find_by_key() {
+        let bookside = bookside_setup();
+
+        for order in bookside.orders(0, Some(100)) {
+            let tree = if order.is_oracle_pegged {
+                BookSideOrderTree::OraclePegged
+            } else {
+                BookSideOrderTree::Fixed
+            };
+            let leaf = bookside.find_by_key(tree, order.order_id).unwrap();
+            assert_eq!(leaf.key, order.order_id);
+            assert!(bookside
+                .find_by_key(tree, order.order_id.wrapping_add(1))
+                .is_none());
+        }
+        assert!(BookSide::zeroed()
+            .find_by_key(BookSideOrderTree::Fixed, 1)
+            .is_none());
+    }
+
     // add test for oracle expired
+
+    mod properties {
+        use super::*;
+        use proptest::collection::vec;
+        use proptest::prelude::*;
+
+        #[derive(Clone, Debug)]
+        enum TreeOp {
+            Insert(i64),
+            /// Removes the live order at this index modulo the number of live orders
+            Remove(usize),
+        }
+
+        fn side_strategy() -> impl Strategy<Value = Side> {
+            prop_oneof![Just(Side::Bid), Just(Side::Ask)]
+        }
+
+        fn tree_op_strategy() -> impl Strategy<Value = TreeOp> {
+            prop_oneof![
+                (1..100i64).prop_map(TreeOp::Insert),
+                any::<usize>().prop_map(TreeOp::Remove),
+            ]
+        }
+
+        fn new_bookside(side: Side) -> BookSide {
+            let mut bookside = BookSide::zeroed();
+            bookside.nodes.order_tree_type = match side {
+                Side::Bid => OrderTreeType::Bids,
+                Side::Ask => OrderTreeType::Asks,
+            }
+            .into();
+            bookside
+        }
+
+        /// Inserts a fixed price order, tagged with its seq_num in every leaf field
+        /// that isn't part of the key
+        fn insert_fixed(bookside: &mut BookSide, price_lots: i64, seq_num: u64) {
+            let key = new_node_key(
+                bookside.side(),
+                fixed_price_data(price_lots).unwrap(),
+                seq_num,
+            );
+            let leaf = LeafNode::new(
+                seq_num as u8,
+                key,
+                Pubkey::default(),
+                seq_num as i64 + 1,
+                seq_num,
+                0,
+                -1,
+                seq_num,
+            );
+            let (_, replaced) = bookside
+                .insert_leaf(BookSideOrderTree::Fixed, &leaf)
+                .unwrap();
+            assert!(replaced.is_none());
+        }
+
+        /// (price, seq_num) of orders, best first
+        fn by_priority(side: Side, mut orders: Vec<(i64, u64)>) -> Vec<(i64, u64)> {
+            orders.sort_by_key(|&(price_lots, seq_num)| match side {
+                Side::Bid => (-price_lots, seq_num),
+                Side::Ask => (price_lots, seq_num),
+            });
+            orders
+        }
+
+        /// (price, seq_num) of the orders in iteration order, checking the tags of
+        /// `insert_fixed`
+        fn iterated(bookside: &BookSide) -> Vec<(i64, u64)> {
+            bookside
+                .iter_all_including_invalid(0, None)
+                .map(|item| {
+                    let leaf = item.node;
+                    let seq_num = leaf.client_order_id;
+                    assert_eq!(leaf.owner_slot, seq_num as u8);
+                    assert_eq!(leaf.quantity, seq_num as i64 + 1);
+                    assert_eq!(leaf.timestamp, seq_num);
+                    (item.price_lots, seq_num)
+                })
+                .collect()
+        }
+
+        proptest! {
+            #[test]
+            fn bookside_iteration_matches_priority(
+                side in side_strategy(),
+                prices in vec(1..100i64, 0..300),
+            ) {
+                let mut bookside = new_bookside(side);
+                let mut orders = vec![];
+                for (seq_num, price_lots) in prices.into_iter().enumerate() {
+                    insert_fixed(&mut bookside, price_lots, seq_num as u64);
+                    orders.push((price_lots, seq_num as u64));
+                }
+
+                prop_assert_eq!(iterated(&bookside), by_priority(side, orders));
+            }
+
+            #[test]
+            fn bookside_remove_worst_matches_priority(
+                side in side_strategy(),
+                prices in vec(1..100i64, 0..300),
+                fixed_only in any::<bool>(),
+            ) {
+                let mut bookside = new_bookside(side);
+                let mut orders = vec![];
+                for (seq_num, price_lots) in prices.into_iter().enumerate() {
+                    insert_fixed(&mut bookside, price_lots, seq_num as u64);
+                    orders.push((price_lots, seq_num as u64));
+                }
+
+                let mut removed = vec![];
+                loop {
+                    let worst = if fixed_only {
+                        bookside.remove_worst_fixed()
+                    } else {
+                        bookside.remove_worst(0, None)
+                    };
+                    match worst {
+                        Some((leaf, price_lots)) => {
+                            removed.push((price_lots, leaf.client_order_id))
+                        }
+                        None => break,
+                    }
+                }
+
+                let mut expected = by_priority(side, orders);
+                expected.reverse();
+                prop_assert_eq!(removed, expected);
+                prop_assert!(bookside.is_empty());
+            }
+
+            #[test]
+            fn bookside_node_reuse_keeps_live_orders(
+                side in side_strategy(),
+                ops in vec(tree_op_strategy(), 0..400),
+            ) {
+                let mut bookside = new_bookside(side);
+                let mut live = vec![];
+                for (seq_num, op) in ops.into_iter().enumerate() {
+                    let seq_num = seq_num as u64;
+                    match op {
+                        TreeOp::Insert(price_lots) => {
+                            insert_fixed(&mut bookside, price_lots, seq_num);
+                            live.push((price_lots, seq_num));
+                        }
+                        TreeOp::Remove(index) => {
+                            if live.is_empty() {
+                                continue;
+                            }
+                            let (price_lots, order_seq_num) = live.swap_remove(index % live.len());
+                            let key = new_node_key(
+                                side,
+                                fixed_price_data(price_lots).unwrap(),
+                                order_seq_num,
+                            );
+                            let leaf = bookside.remove_by_key(BookSideOrderTree::Fixed, key);
+                            prop_assert_eq!(
+                                leaf.map(|leaf| leaf.client_order_id),
+                                Some(order_seq_num)
+                            );
+                        }
+                    }
+
+                    prop_assert_eq!(
+                        bookside.root(BookSideOrderTree::Fixed).leaf_count as usize,
+                        live.len()
+                    );
+                    prop_assert_eq!(iterated(&bookside), by_priority(side, live.clone()));
+                }
+            }
+        }
+    }
 }
@@ -144,6 +144,17 @@ pub fn rank_orders<'a>(
         None
     };
 
+    rank_priced_orders(side, fixed, oracle_pegged, return_worse, now_ts)
+}
+
+/// Like `rank_orders`, for an oracle pegged order whose price and state are known already
+fn rank_priced_orders<'a>(
+    side: Side,
+    fixed: Option<(NodeHandle, &'a LeafNode)>,
+    oracle_pegged: Option<(NodeHandle, &'a LeafNode, i64, OrderState)>,
+    return_worse: bool,
+    now_ts: u64,
+) -> Option<BookSideIterItem<'a>> {
     match (fixed, oracle_pegged) {
         (Some(f), Some(o)) => {
             let is_better = if side == Side::Bid {
@@ -173,29 +184,25 @@ impl<'a> Iterator for BookSideIter<'a> {
         // Skip all the oracle pegged orders that aren't representable with the current oracle
         // price. Example: iterating asks, but the best ask is at offset -100 with the oracle at 50.
         // We need to skip asks until we find the first that has a price >= 1.
-        let o_peek = if let Some(oracle_price_lots) = self.oracle_price_lots {
-            let mut o_peek = self.oracle_pegged_iter.peek();
-            while let Some((_, o_node)) = o_peek {
-                if oracle_pegged_price(oracle_price_lots, o_node, side).0 != OrderState::Skipped {
+        // The price of the first representable order is kept for ranking, so it's only
+        // computed once per step.
+        let mut o_peek = None;
+        if let Some(oracle_price_lots) = self.oracle_price_lots {
+            let mut next = self.oracle_pegged_iter.peek();
+            while let Some((o_handle, o_node)) = next {
+                let (state, price_lots) = oracle_pegged_price(oracle_price_lots, o_node, side);
+                if state != OrderState::Skipped {
+                    o_peek = Some((o_handle, o_node, price_lots, state));
                     break;
                 }
-                o_peek = self.oracle_pegged_iter.next()
+                self.oracle_pegged_iter.next();
+                next = self.oracle_pegged_iter.peek();
             }
-            o_peek
-        } else {
-            None
-        };
+        }
 
         let f_peek = self.fixed_iter.peek();
 
-        let better = rank_orders(
-            side,
-            f_peek,
-            o_peek,
-            false,
-            self.now_ts,
-            self.oracle_price_lots,
-        )?;
+        let better = rank_priced_orders(side, f_peek, o_peek, false, self.now_ts)?;
         match better.handle.order_tree {
             BookSideOrderTree::Fixed => self.fixed_iter.next(),
             BookSideOrderTree::OraclePegged => self.oracle_pegged_iter.next(),
@@ -204,3 +211,54 @@ impl<'a> Iterator for BookSideIter<'a> {
         Some(better)
     }
 }
+
+/// Iterates a book side and its pages together, in matching order. Items come with
+/// the index of the book side they're on: 0 for the first one, then the pages.
+pub struct PagedBookSideIter<'a> {
+    iters: Vec<std::iter::Peekable<BookSideIter<'a>>>,
+    side: Side,
+}
+
+impl<'a> PagedBookSideIter<'a> {
+    pub fn new(book_sides: &[&'a BookSide], now_ts: u64, oracle_price_lots: Option<i64>) -> Self {
+        Self {
+            iters: book_sides
+                .iter()
+                .map(|book_side| BookSideIter::new(book_side, now_ts, oracle_price_lots).peekable())
+                .collect(),
+            side: book_sides[0].side(),
+        }
+    }
+}
+
+impl<'a> Iterator for PagedBookSideIter<'a> {
+    type Item = (usize, BookSideIterItem<'a>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.iters.len() == 1 {
+            return self.iters[0].next().map(|item| (0, item));
+        }
+
+        // Same ranking as between the fixed and oracle pegged orders of a book side
+        let mut best: Option<(usize, u128)> = None;
+        for (index, iter) in self.iters.iter_mut().enumerate() {
+            let key = match iter.peek() {
+                Some(item) => key_for_fixed_price(item.node.key, item.price_lots),
+                None => continue,
+            };
+            let is_better = match best {
+                None => true,
+                Some((_, best_key)) => match self.side {
+                    Side::Bid => key > best_key,
+                    Side::Ask => key < best_key,
+                },
+            };
+            if is_better {
+                best = Some((index, key));
+            }
+        }
+
+        let (index, _) = best?;
+        self.iters[index].next().map(|item| (index, item))
+    }
+}
@@ -1,8 +1,9 @@
 use crate::error::OpenBookError;
 use anchor_lang::prelude::*;
 use num_enum::{IntoPrimitive, TryFromPrimitive};
+use std::cell::RefMut;
 
-use super::Side;
+use super::{LeafNode, Side};
 
 pub const MAX_NUM_EVENTS: u16 = 600;
 pub const NO_NODE: u16 = u16::MAX;
@@ -12,11 +13,16 @@ pub const NO_NODE: u16 = u16::MAX;
 /// Events are stored in a fixed-array of nodes. Free nodes are connected by a single-linked list
 /// starting at free_head while used nodes form a circular doubly-linked list starting at
 /// used_head.
+///
+/// The account can be grown with `resize_event_heap`, in which case additional nodes are
+/// stored right after this struct; use [`EventHeapRefMut`] to access all of them.
 #[account(zero_copy)]
 pub struct EventHeap {
     pub header: EventHeapHeader,
     pub nodes: [EventNode; MAX_NUM_EVENTS as usize],
-    pub reserved: [u8; 64],
+    /// Number of nodes stored after the struct, added by `resize_event_heap`
+    pub extension_nodes: u16,
+    pub reserved: [u8; 62],
 }
 
 impl EventHeap {
@@ -35,40 +41,207 @@ impl EventHeap {
         }
         self.nodes[MAX_NUM_EVENTS as usize - 1].next = NO_NODE;
     }
+}
+
+impl EventHeapStorage for EventHeap {
+    fn header(&self) -> &EventHeapHeader {
+        &self.header
+    }
+
+    fn header_mut(&mut self) -> &mut EventHeapHeader {
+        &mut self.header
+    }
+
+    fn capacity(&self) -> usize {
+        self.nodes.len()
+    }
+
+    fn node(&self, slot: usize) -> &EventNode {
+        &self.nodes[slot]
+    }
+
+    fn node_mut(&mut self, slot: usize) -> &mut EventNode {
+        &mut self.nodes[slot]
+    }
+}
+
+/// Mutable access to an event heap account, including the nodes added by
+/// `resize_event_heap` after the [`EventHeap`] struct.
+pub struct EventHeapRefMut<'a> {
+    pub heap: RefMut<'a, EventHeap>,
+    extension: RefMut<'a, [EventNode]>,
+}
+
+impl<'a> EventHeapRefMut<'a> {
+    pub fn load_mut<'info>(loader: &'a AccountLoader<'info, EventHeap>) -> Result<Self> {
+        // runs the writable and discriminator checks
+        drop(loader.load_mut()?);
+
+        let data = loader.as_ref().try_borrow_mut_data()?;
+        let (heap, extension) = RefMut::map_split(data, |data| {
+            let (heap, extension) = data.split_at_mut(8 + std::mem::size_of::<EventHeap>());
+            let extension_len = extension.len() / std::mem::size_of::<EventNode>();
+            (
+                bytemuck::from_bytes_mut::<EventHeap>(&mut heap[8..]),
+                bytemuck::cast_slice_mut::<u8, EventNode>(
+                    &mut extension[..extension_len * std::mem::size_of::<EventNode>()],
+                ),
+            )
+        });
+
+        Ok(Self { heap, extension })
+    }
+
+    /// Link all nodes added to the account since the last call into the free list.
+    pub fn init_extension_nodes(&mut self) -> Result<()> {
+        let linked = self.heap.extension_nodes as usize;
+        let total = self.extension.len();
+        require_gt!(
+            NO_NODE as usize,
+            MAX_NUM_EVENTS as usize + total,
+            OpenBookError::InvalidInputHeapSlots
+        );
+        if total == linked {
+            return Ok(());
+        }
+
+        let first_slot = MAX_NUM_EVENTS as usize + linked;
+        let end_slot = MAX_NUM_EVENTS as usize + total;
+        for slot in first_slot..end_slot {
+            let node = &mut self.extension[slot - MAX_NUM_EVENTS as usize];
+            node.next = (slot + 1) as u16;
+            node.prev = NO_NODE;
+        }
+        self.extension[total - 1].next = self.heap.header.free_head;
+        self.heap.header.free_head = first_slot as u16;
+        self.heap.extension_nodes = total as u16;
+
+        Ok(())
+    }
+}
+
+impl EventHeapStorage for EventHeapRefMut<'_> {
+    fn header(&self) -> &EventHeapHeader {
+        &self.heap.header
+    }
+
+    fn header_mut(&mut self) -> &mut EventHeapHeader {
+        &mut self.heap.header
+    }
+
+    fn capacity(&self) -> usize {
+        MAX_NUM_EVENTS as usize + self.heap.extension_nodes as usize
+    }
+
+    fn node(&self, slot: usize) -> &EventNode {
+        match slot.checked_sub(MAX_NUM_EVENTS as usize) {
+            None => &self.heap.nodes[slot],
+            Some(extension_slot) => &self.extension[extension_slot],
+        }
+    }
+
+    fn node_mut(&mut self, slot: usize) -> &mut EventNode {
+        match slot.checked_sub(MAX_NUM_EVENTS as usize) {
+            None => &mut self.heap.nodes[slot],
+            Some(extension_slot) => &mut self.extension[extension_slot],
+        }
+    }
+}
+
+/// Owned copy of an event heap account including its extension nodes, for reading
+/// the events off-chain.
+#[cfg(any(feature = "client", feature = "wasm"))]
+pub struct EventHeapSnapshot {
+    pub heap: Box<EventHeap>,
+    extension: Vec<EventNode>,
+}
+
+#[cfg(any(feature = "client", feature = "wasm"))]
+impl EventHeapSnapshot {
+    pub fn from_bytes(data: &[u8]) -> Result<Self> {
+        use crate::accounts_zerocopy::FromBytes;
+
+        let heap = Box::new(EventHeap::from_bytes(data)?);
+        let extension = data[8 + std::mem::size_of::<EventHeap>()..]
+            .chunks_exact(std::mem::size_of::<EventNode>())
+            .take(heap.extension_nodes as usize)
+            .map(bytemuck::pod_read_unaligned)
+            .collect();
+        Ok(Self { heap, extension })
+    }
+}
+
+#[cfg(any(feature = "client", feature = "wasm"))]
+impl EventHeapStorage for EventHeapSnapshot {
+    fn header(&self) -> &EventHeapHeader {
+        &self.heap.header
+    }
+
+    fn header_mut(&mut self) -> &mut EventHeapHeader {
+        &mut self.heap.header
+    }
+
+    fn capacity(&self) -> usize {
+        MAX_NUM_EVENTS as usize + self.extension.len()
+    }
+
+    fn node(&self, slot: usize) -> &EventNode {
+        match slot.checked_sub(MAX_NUM_EVENTS as usize) {
+            None => &self.heap.nodes[slot],
+            Some(extension_slot) => &self.extension[extension_slot],
+        }
+    }
+
+    fn node_mut(&mut self, slot: usize) -> &mut EventNode {
+        match slot.checked_sub(MAX_NUM_EVENTS as usize) {
+            None => &mut self.heap.nodes[slot],
+            Some(extension_slot) => &mut self.extension[extension_slot],
+        }
+    }
+}
+
+/// Operations on an event heap, independent of where its nodes are stored.
+pub trait EventHeapStorage {
+    fn header(&self) -> &EventHeapHeader;
+    fn header_mut(&mut self) -> &mut EventHeapHeader;
+    /// Total number of nodes
+    fn capacity(&self) -> usize;
+    fn node(&self, slot: usize) -> &EventNode;
+    fn node_mut(&mut self, slot: usize) -> &mut EventNode;
 
-    pub fn len(&self) -> usize {
-        self.header.count()
+    fn len(&self) -> usize {
+        self.header().count()
     }
 
-    pub fn is_empty(&self) -> bool {
+    fn is_empty(&self) -> bool {
         self.len() == 0
     }
 
-    pub fn is_full(&self) -> bool {
-        self.len() == self.nodes.len()
+    fn is_full(&self) -> bool {
+        self.len() == self.capacity()
     }
 
-    pub fn front(&self) -> Option<&AnyEvent> {
+    fn front(&self) -> Option<&AnyEvent> {
         if self.is_empty() {
             None
         } else {
-            Some(&self.nodes[self.header.used_head()].event)
+            Some(&self.node(self.header().used_head()).event)
         }
     }
 
-    pub fn at_slot(&self, slot: usize) -> Option<&AnyEvent> {
-        if slot >= self.nodes.len() || self.nodes[slot].is_free() {
+    fn at_slot(&self, slot: usize) -> Option<&AnyEvent> {
+        if slot >= self.capacity() || self.node(slot).is_free() {
             None
         } else {
-            Some(&self.nodes[slot].event)
+            Some(&self.node(slot).event)
         }
     }
 
-    pub fn push_back(&mut self, value: AnyEvent) {
+    fn push_back(&mut self, value: AnyEvent) {
         assert!(!self.is_full());
 
-        let slot = self.header.free_head;
-        self.header.free_head = self.nodes[slot as usize].next;
+        let slot = self.header().free_head;
+        self.header_mut().free_head = self.node(slot as usize).next;
 
         let new_next: u16;
         let new_prev: u16;
@@ -77,81 +250,116 @@ impl EventHeap {
             new_next = slot;
             new_prev = slot;
 
-            self.header.used_head = slot;
+            self.header_mut().used_head = slot;
         } else {
-            new_next = self.header.used_head;
-            new_prev = self.nodes[new_next as usize].prev;
+            new_next = self.header().used_head;
+            new_prev = self.node(new_next as usize).prev;
 
-            self.nodes[new_prev as usize].next = slot;
-            self.nodes[new_next as usize].prev = slot;
+            self.node_mut(new_prev as usize).next = slot;
+            self.node_mut(new_next as usize).prev = slot;
         }
 
-        self.header.incr_count();
-        self.header.incr_event_id();
-        self.nodes[slot as usize].event = value;
-        self.nodes[slot as usize].next = new_next;
-        self.nodes[slot as usize].prev = new_prev;
+        self.header_mut().incr_count();
+        self.header_mut().incr_event_id();
+        let node = self.node_mut(slot as usize);
+        node.event = value;
+        node.next = new_next;
+        node.prev = new_prev;
     }
 
-    pub fn pop_front(&mut self) -> Result<AnyEvent> {
-        self.delete_slot(self.header.used_head())
+    fn pop_front(&mut self) -> Result<AnyEvent> {
+        self.delete_slot(self.header().used_head())
     }
 
-    pub fn delete_slot(&mut self, slot: usize) -> Result<AnyEvent> {
-        if slot >= self.nodes.len() || self.is_empty() || self.nodes[slot].is_free() {
-            return Err(OpenBookError::SomeError.into());
+    fn delete_slot(&mut self, slot: usize) -> Result<AnyEvent> {
+        if slot >= self.capacity() || self.is_empty() || self.node(slot).is_free() {
+            return Err(OpenBookError::InvalidEventHeapSlot.into());
         }
 
-        let prev_slot = self.nodes[slot].prev;
-        let next_slot = self.nodes[slot].next;
-        let next_free = self.header.free_head;
+        let prev_slot = self.node(slot).prev;
+        let next_slot = self.node(slot).next;
+        let next_free = self.header().free_head;
 
-        self.nodes[prev_slot as usize].next = next_slot;
-        self.nodes[next_slot as usize].prev = prev_slot;
+        self.node_mut(prev_slot as usize).next = next_slot;
+        self.node_mut(next_slot as usize).prev = prev_slot;
 
-        if self.header.count() == 1 {
-            self.header.used_head = NO_NODE;
-        } else if self.header.used_head() == slot {
-            self.header.used_head = next_slot;
+        if self.header().count() == 1 {
+            self.header_mut().used_head = NO_NODE;
+        } else if self.header().used_head() == slot {
+            self.header_mut().used_head = next_slot;
         };
 
-        self.header.decr_count();
-        self.header.free_head = slot.try_into().unwrap();
-        self.nodes[slot].next = next_free;
-        self.nodes[slot].prev = NO_NODE;
+        self.header_mut().decr_count();
+        self.header_mut().free_head = slot.try_into().unwrap();
+        let node = self.node_mut(slot);
+        node.next = next_free;
+        node.prev = NO_NODE;
 
-        Ok(self.nodes[slot].event)
+        Ok(node.event)
     }
 
-    pub fn iter(&self) -> impl Iterator<Item = (&AnyEvent, usize)> {
+    fn iter(&self) -> EventHeapIterator<'_, Self>
+    where
+        Self: Sized,
+    {
         EventHeapIterator {
             heap: self,
             index: 0,
-            slot: self.header.used_head(),
+            slot: self.header().used_head(),
+        }
+    }
+
+    /// Iterate the events in processing order: fill events first, as processing them
+    /// unlocks the funds of the makers, then out events. Each class in heap order.
+    ///
+    /// Fills queued behind many out events, like after a mass cancel, don't wait for
+    /// the outs to be consumed.
+    fn iter_by_priority(&self) -> EventHeapPriorityIterator<'_, Self>
+    where
+        Self: Sized,
+    {
+        EventHeapPriorityIterator {
+            fills: self.iter(),
+            outs: self.iter(),
         }
     }
 }
 
-struct EventHeapIterator<'a> {
-    heap: &'a EventHeap,
+pub struct EventHeapIterator<'a, H> {
+    heap: &'a H,
     index: usize,
     slot: usize,
 }
 
-impl<'a> Iterator for EventHeapIterator<'a> {
+impl<'a, H: EventHeapStorage> Iterator for EventHeapIterator<'a, H> {
     type Item = (&'a AnyEvent, usize);
     fn next(&mut self) -> Option<Self::Item> {
         if self.index == self.heap.len() {
             None
         } else {
             let current_slot = self.slot;
-            self.slot = self.heap.nodes[current_slot].next as usize;
+            self.slot = self.heap.node(current_slot).next as usize;
             self.index += 1;
-            Some((&self.heap.nodes[current_slot].event, current_slot))
+            Some((&self.heap.node(current_slot).event, current_slot))
         }
     }
 }
 
+pub struct EventHeapPriorityIterator<'a, H> {
+    fills: EventHeapIterator<'a, H>,
+    outs: EventHeapIterator<'a, H>,
+}
+
+impl<'a, H: EventHeapStorage> Iterator for EventHeapPriorityIterator<'a, H> {
+    type Item = (&'a AnyEvent, usize);
+    fn next(&mut self) -> Option<Self::Item> {
+        let is_fill = |(event, _): &(&AnyEvent, usize)| event.event_type == EventType::Fill as u8;
+        self.fills
+            .find(is_fill)
+            .or_else(|| self.outs.find(|item| !is_fill(item)))
+    }
+}
+
 #[zero_copy]
 pub struct EventHeapHeader {
     free_head: u16,
@@ -210,6 +418,25 @@ pub struct AnyEvent {
     pub padding: [u8; 143],
 }
 
+impl AnyEvent {
+    /// The open orders account that has to be passed in to process this event:
+    /// the maker of a fill or the owner of an out event.
+    pub fn open_orders_account(&self) -> Option<Pubkey> {
+        match EventType::try_from(self.event_type).ok()? {
+            EventType::Fill => Some(bytemuck::cast_ref::<_, FillEvent>(self).maker),
+            EventType::Out => Some(bytemuck::cast_ref::<_, OutEvent>(self).owner),
+        }
+    }
+
+    /// Unix timestamp at which the event was pushed.
+    pub fn timestamp(&self) -> u64 {
+        match EventType::try_from(self.event_type) {
+            Ok(EventType::Fill) => bytemuck::cast_ref::<_, FillEvent>(self).timestamp,
+            Ok(EventType::Out) => bytemuck::cast_ref::<_, OutEvent>(self).timestamp,
+            Err(_) => 0,
+        }
+    }
+}
 
 #[derive(Copy, Clone, IntoPrimitive, TryFromPrimitive, Eq, PartialEq)]
 #[repr(u8)]
@@ -218,6 +445,24 @@ pub enum EventType {
     Out,
 }
 
+/// Why an order left the book, stored in [`OutEvent::reason`]
+///
+/// Orders cancelled by their owner are reported with `CancelLog` instead of out
+/// events and logs.
+#[derive(Copy, Clone, Debug, IntoPrimitive, TryFromPrimitive, Eq, PartialEq)]
+#[repr(u8)]
+pub enum OutReason {
+    Cancelled,
+    /// The order's `expiry_timestamp` passed
+    Expired,
+    /// The book side was full and a better order took its place
+    BootedForSpace,
+    /// A taker of the same account with `SelfTradeBehavior::CancelProvide` crossed it
+    SelfTradeCancel,
+    /// Cancelled by the market's admin with `force_cancel_orders`
+    AdminCancel,
+}
+
 #[derive(
     Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable, AnchorSerialize, AnchorDeserialize,
 )]
@@ -227,7 +472,9 @@ pub struct FillEvent {
     pub taker_side: u8, // Side, from the taker's POV
     pub maker_out: u8,  // 1 if maker order quantity == 0
     pub maker_slot: u8,
-    pub padding: [u8; 4],
+    /// Seconds the maker order had been at the top of the book, see
+    /// `Market::seconds_at_top`
+    pub maker_seconds_at_top: u32,
     pub timestamp: u64,
     pub market_seq_num: u64,
 
@@ -243,7 +490,8 @@ pub struct FillEvent {
     pub peg_limit: i64,
     pub quantity: i64, // number of base lots
     pub maker_client_order_id: u64,
-    pub reserved: [u8; 8],
+    /// Per-market sequence number shared by fill and out events
+    pub event_seq_num: u64,
 }
 
 impl FillEvent {
@@ -262,12 +510,14 @@ impl FillEvent {
         price: i64,
         peg_limit: i64,
         quantity: i64,
+        event_seq_num: u64,
     ) -> FillEvent {
         Self {
             event_type: EventType::Fill as u8,
             taker_side: taker_side.into(),
             maker_out: maker_out.into(),
             maker_slot,
+            maker_seconds_at_top: 0,
             timestamp,
             market_seq_num,
             maker,
@@ -278,8 +528,7 @@ impl FillEvent {
             price,
             peg_limit,
             quantity,
-            padding: Default::default(),
-            reserved: [0; 8],
+            event_seq_num,
         }
     }
 
@@ -299,39 +548,56 @@ pub struct OutEvent {
     pub event_type: u8,
     pub side: u8, // Side
     pub owner_slot: u8,
-    padding0: [u8; 5],
+    /// OutReason
+    pub reason: u8,
+    padding0: [u8; 4],
     pub timestamp: u64,
     pub seq_num: u64,
     pub owner: Pubkey,
     pub quantity: i64,
-    padding1: [u8; 80],
+    /// Per-market sequence number shared by fill and out events
+    pub event_seq_num: u64,
+    /// See [`LeafNode::price_lots_or_peg_offset`]
+    pub price: i64,
+    pub peg_limit: i64,
+    pub client_order_id: u64,
+    padding1: [u8; 48],
 }
 
 impl OutEvent {
+    /// Out event of `order`, with its remaining quantity
     pub fn new(
         side: Side,
-        owner_slot: u8,
+        order: &LeafNode,
+        reason: OutReason,
         timestamp: u64,
         seq_num: u64,
-        owner: Pubkey,
-        quantity: i64,
+        event_seq_num: u64,
     ) -> Self {
         Self {
             event_type: EventType::Out.into(),
             side: side.into(),
-            owner_slot,
-            padding0: [0; 5],
+            owner_slot: order.owner_slot,
+            reason: reason.into(),
+            padding0: [0; 4],
             timestamp,
             seq_num,
-            owner,
-            quantity,
-            padding1: [0; EVENT_SIZE - 64],
+            owner: order.owner,
+            quantity: order.quantity,
+            event_seq_num,
+            price: order.price_lots_or_peg_offset(),
+            peg_limit: order.peg_limit,
+            client_order_id: order.client_order_id,
+            padding1: [0; 48],
         }
     }
 
     pub fn side(&self) -> Side {
         self.side.try_into().unwrap()
     }
+    pub fn reason(&self) -> OutReason {
+        self.reason.try_into().unwrap()
+    }
 }
 
 #[cfg(test)]
@@ -581,4 +847,23 @@ mod tests {
         assert_eq!(eq.header.free_head(), 2);
         assert_eq!(eq.nodes[2].next, 3);
     }
+
+    #[test]
+    fn fills_before_outs() {
+        let mut eq = EventHeap::zeroed();
+        eq.init();
+        let fill = dummy_event_with_number(EventType::Fill.into());
+        let out = dummy_event_with_number(EventType::Out.into());
+        for event in [out, fill, out, out, fill] {
+            eq.push_back(event);
+        }
+
+        let slots: Vec<usize> = eq.iter_by_priority().map(|(_, slot)| slot).collect();
+        assert_eq!(slots, vec![1, 4, 0, 2, 3]);
+
+        eq.delete_slot(1).unwrap();
+        eq.delete_slot(4).unwrap();
+        let slots: Vec<usize> = eq.iter_by_priority().map(|(_, slot)| slot).collect();
+        assert_eq!(slots, vec![0, 2, 3]);
+    }
 }
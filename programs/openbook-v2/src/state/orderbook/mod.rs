@@ -191,6 +191,13 @@ mod tests {
             1002
         );
         assert_eq!(event_heap.len(), 1);
+        let out_reasons = |event_heap: &EventHeap| -> Vec<OutReason> {
+            event_heap
+                .iter()
+                .map(|(event, _)| bytemuck::cast_ref::<_, OutEvent>(event).reason())
+                .collect()
+        };
+        assert_eq!(out_reasons(&event_heap), vec![OutReason::BootedForSpace]);
 
         // adding another bid after expiry removes the soonest-expiring order (1005)
         new_order(&mut book, &mut event_heap, Side::Bid, 999, 2000000);
@@ -205,6 +212,10 @@ mod tests {
         );
         assert!(!order_tree_contains_key(&book.bids, 1005));
         assert_eq!(event_heap.len(), 2);
+        assert_eq!(
+            out_reasons(&event_heap),
+            vec![OutReason::BootedForSpace, OutReason::Expired]
+        );
 
         // adding an ask will wipe up to three expired bids at the top of the book
         let bids_max = book
@@ -219,6 +230,9 @@ mod tests {
         assert_eq!(book.bids.roots[0].leaf_count, bids_count - 5);
         assert_eq!(book.asks.roots[0].leaf_count, 1);
         assert_eq!(event_heap.len(), 2 + 5);
+        assert!(out_reasons(&event_heap)[2..]
+            .iter()
+            .all(|reason| *reason == OutReason::Expired));
         assert!(!order_tree_contains_price(&book.bids, bids_max));
         assert!(!order_tree_contains_price(&book.bids, bids_max - 1));
         assert!(!order_tree_contains_price(&book.bids, bids_max - 2));
@@ -227,6 +241,322 @@ mod tests {
         assert!(order_tree_contains_price(&book.bids, bids_max - 5));
     }
 
+    // Orders of designated market makers are only booted by higher priorities
+    #[test]
+    fn book_bids_full_dmm_priority() {
+        use crate::error::*;
+
+        let (mut openbook_market, oracle_price_lots, mut event_heap, book_accs) =
+            test_setup(5000.0);
+        let mut book = book_accs.orderbook();
+        let market_pk = Pubkey::new_unique();
+
+        let mut new_order = |book: &mut Orderbook, price_lots, dmm_priority| {
+            let mut account = OpenOrdersAccount::default_for_tests();
+            account.dmm_priority = dmm_priority;
+            book.new_order(
+                &Order {
+                    side: Side::Bid,
+                    max_base_lots: 1,
+                    max_quote_lots_including_fees: i64::MAX / openbook_market.quote_lot_size,
+                    client_order_id: 0,
+                    time_in_force: 0,
+                    params: OrderParams::Fixed {
+                        price_lots,
+                        order_type: PostOrderType::Limit,
+                    },
+                    self_trade_behavior: SelfTradeBehavior::DecrementTake,
+                },
+                &mut openbook_market,
+                &market_pk,
+                &mut event_heap,
+                oracle_price_lots,
+                Some(&mut account),
+                &Pubkey::new_unique(),
+                1000,
+                u8::MAX,
+                &[],
+            )
+        };
+
+        let mut price_lots = 1000;
+        while !book.bids.is_full() {
+            new_order(&mut book, price_lots, 1).unwrap();
+            price_lots += 1;
+        }
+        let worst_leaf =
+            |book: &Orderbook| *book.bids.nodes.min_leaf(&book.bids.roots[0]).unwrap().1;
+        assert_eq!(worst_leaf(&book).dmm_priority, 1);
+
+        for dmm_priority in [0, 1] {
+            let result = new_order(&mut book, price_lots, dmm_priority);
+            assert!(result.is_anchor_error_with_code(OpenBookError::DmmOrderProtected.into()));
+        }
+
+        new_order(&mut book, price_lots, 2).unwrap();
+        assert_eq!(worst_leaf(&book).price_data(), 1001);
+        assert_eq!(
+            book.bids
+                .nodes
+                .max_leaf(&book.bids.roots[0])
+                .unwrap()
+                .1
+                .dmm_priority,
+            2
+        );
+    }
+
+    // The market's eviction policy picks the order booted from a full book side
+    #[test]
+    fn book_bids_full_eviction_policy() {
+        use crate::error::*;
+        use crate::state::EvictionPolicy;
+
+        let (mut openbook_market, oracle_price_lots, mut event_heap, book_accs) =
+            test_setup(5000.0);
+        let mut book = book_accs.orderbook();
+        let market_pk = Pubkey::new_unique();
+        openbook_market.eviction_policy = EvictionPolicy::EvictOldestOutOfBand.into();
+        openbook_market.eviction_band_bps = 1000;
+
+        let mut new_order = |book: &mut Orderbook, market: &mut Market, price_lots, now_ts| {
+            let mut account = OpenOrdersAccount::default_for_tests();
+            book.new_order(
+                &Order {
+                    side: Side::Bid,
+                    max_base_lots: 1,
+                    max_quote_lots_including_fees: i64::MAX / market.quote_lot_size,
+                    client_order_id: 0,
+                    time_in_force: 0,
+                    params: OrderParams::Fixed {
+                        price_lots,
+                        order_type: PostOrderType::Limit,
+                    },
+                    self_trade_behavior: SelfTradeBehavior::DecrementTake,
+                },
+                market,
+                &market_pk,
+                &mut event_heap,
+                oracle_price_lots,
+                Some(&mut account),
+                &Pubkey::new_unique(),
+                now_ts,
+                u8::MAX,
+                &[],
+            )
+        };
+
+        // the oldest order is within 10% of the oracle price, the next one isn't
+        new_order(&mut book, &mut openbook_market, 4900, 999).unwrap();
+        new_order(&mut book, &mut openbook_market, 4000, 1000).unwrap();
+        let mut price_lots = 2000;
+        while !book.bids.is_full() {
+            new_order(&mut book, &mut openbook_market, price_lots, 1001).unwrap();
+            price_lots += 1;
+        }
+        let now_ts = 1002;
+
+        new_order(&mut book, &mut openbook_market, 4950, now_ts).unwrap();
+        assert!(!order_tree_contains_price(&book.bids, 4000));
+        assert!(order_tree_contains_price(&book.bids, 4900));
+        assert!(order_tree_contains_price(&book.bids, 2000));
+
+        // the oldest order out of the band is priced better
+        let result = new_order(&mut book, &mut openbook_market, 1500, now_ts);
+        assert!(result.is_anchor_error_with_code(OpenBookError::BookFull.into()));
+
+        openbook_market.eviction_policy = EvictionPolicy::RejectIncoming.into();
+        let result = new_order(&mut book, &mut openbook_market, 4960, now_ts);
+        assert!(result.is_anchor_error_with_code(OpenBookError::BookFull.into()));
+        assert!(order_tree_contains_price(&book.bids, 2000));
+
+        openbook_market.eviction_policy = EvictionPolicy::EvictWorstPrice.into();
+        new_order(&mut book, &mut openbook_market, 4960, now_ts).unwrap();
+        assert!(!order_tree_contains_price(&book.bids, 2000));
+    }
+
+    // Orders posted while a book side is full go to its pages
+    #[test]
+    fn book_bids_paged() {
+        use crate::accounts_zerocopy::LoadMutZeroCopyRef;
+        use crate::error::*;
+        use anchor_lang::prelude::AccountInfo;
+        use anchor_lang::Discriminator;
+
+        let (mut market, oracle_price_lots, mut event_heap, book_accs) = test_setup(5000.0);
+        let mut book = book_accs.orderbook();
+        let market_pk = Pubkey::new_unique();
+        let now_ts = 1000;
+
+        // account data of a page, the BookSide after the discriminator 16 bytes aligned
+        let page_key = Pubkey::new_unique();
+        let mut page_lamports = 0;
+        let mut page_data = vec![0_u128; std::mem::size_of::<BookSide>() / 16 + 2];
+        let page_bytes = &mut bytemuck::cast_slice_mut::<u128, u8>(&mut page_data)[8..];
+        page_bytes[..8].copy_from_slice(&BookSide::discriminator());
+        bytemuck::from_bytes_mut::<BookSide>(
+            &mut page_bytes[8..8 + std::mem::size_of::<BookSide>()],
+        )
+        .nodes
+        .order_tree_type = OrderTreeType::Bids.into();
+        let program_id = crate::id();
+        let page_ai = AccountInfo::new(
+            &page_key,
+            false,
+            true,
+            &mut page_lamports,
+            page_bytes,
+            &program_id,
+            false,
+            0,
+        );
+        let page_accs = [page_ai];
+        book.bids.next_page = page_key;
+
+        let order = |side, price_lots| Order {
+            side,
+            max_base_lots: 1,
+            max_quote_lots_including_fees: i64::MAX,
+            client_order_id: 0,
+            time_in_force: 0,
+            params: OrderParams::Fixed {
+                price_lots,
+                order_type: PostOrderType::Limit,
+            },
+            self_trade_behavior: SelfTradeBehavior::DecrementTake,
+        };
+
+        for i in 0.. {
+            let mut account = OpenOrdersAccount::default_for_tests();
+            book.new_order(
+                &order(Side::Bid, 1000 + i),
+                &mut market,
+                &market_pk,
+                &mut event_heap,
+                oracle_price_lots,
+                Some(&mut account),
+                &Pubkey::new_unique(),
+                now_ts,
+                u8::MAX,
+                &[],
+            )
+            .unwrap();
+            if book.bids.is_full() {
+                break;
+            }
+        }
+        let leaf_count = book.bids.roots[0].leaf_count;
+
+        // a worse bid is posted to the page
+        let mut paged_account = OpenOrdersAccount::default_for_tests();
+        let paged_owner = Pubkey::new_unique();
+        let paged_order_id = book
+            .new_order(
+                &order(Side::Bid, 900),
+                &mut market,
+                &market_pk,
+                &mut event_heap,
+                oracle_price_lots,
+                Some(&mut paged_account),
+                &paged_owner,
+                now_ts,
+                u8::MAX,
+                &page_accs,
+            )
+            .unwrap()
+            .order_id
+            .unwrap();
+        assert!(!order_tree_contains_key(&book.bids, paged_order_id));
+        assert_eq!(book.bids.paged_leaf_count, 1);
+
+        // a better one takes the place of the worst bid, which moves to the page
+        let mut account = OpenOrdersAccount::default_for_tests();
+        book.new_order(
+            &order(Side::Bid, 5000),
+            &mut market,
+            &market_pk,
+            &mut event_heap,
+            oracle_price_lots,
+            Some(&mut account),
+            &Pubkey::new_unique(),
+            now_ts,
+            u8::MAX,
+            &page_accs,
+        )
+        .unwrap();
+        assert!(order_tree_contains_price(&book.bids, 5000));
+        assert!(!order_tree_contains_price(&book.bids, 1000));
+        assert_eq!(book.bids.roots[0].leaf_count, leaf_count);
+        assert_eq!(book.bids.paged_leaf_count, 2);
+        assert_eq!(event_heap.len(), 0);
+        {
+            let page = page_accs[0].load_mut::<BookSide>().unwrap();
+            assert_eq!(page.roots[0].leaf_count, 2);
+            assert!(order_tree_contains_key(&page, paged_order_id));
+            assert!(order_tree_contains_price(&page, 1000));
+        }
+
+        // matching against the bids needs the page
+        let mut taker = OpenOrdersAccount::default_for_tests();
+        let result = book.new_order(
+            &order(Side::Ask, 1),
+            &mut market,
+            &market_pk,
+            &mut event_heap,
+            oracle_price_lots,
+            Some(&mut taker),
+            &Pubkey::new_unique(),
+            now_ts,
+            u8::MAX,
+            &[],
+        );
+        assert!(result.is_anchor_error_with_code(OpenBookError::MissingBookSidePages.into()));
+        let result = book
+            .new_order(
+                &order(Side::Ask, 1),
+                &mut market,
+                &market_pk,
+                &mut event_heap,
+                oracle_price_lots,
+                Some(&mut taker),
+                &Pubkey::new_unique(),
+                now_ts,
+                u8::MAX,
+                &page_accs,
+            )
+            .unwrap();
+        assert_eq!(result.fills.len(), 1);
+        assert_eq!(result.fills[0].price, 5000);
+
+        // cancelling an order on the page
+        let side_and_tree = paged_account
+            .find_order_with_order_id(paged_order_id)
+            .unwrap()
+            .side_and_tree();
+        let result = book.cancel_order(
+            &mut paged_account,
+            paged_order_id,
+            side_and_tree,
+            &mut market,
+            None,
+            now_ts,
+            &[],
+        );
+        assert!(result.is_anchor_error_with_code(OpenBookError::OrderIdNotFound.into()));
+        book.cancel_order(
+            &mut paged_account,
+            paged_order_id,
+            side_and_tree,
+            &mut market,
+            Some(paged_owner),
+            now_ts,
+            &page_accs,
+        )
+        .unwrap();
+        assert_eq!(book.bids.paged_leaf_count, 1);
+        assert_eq!(paged_account.all_orders_in_use().count(), 0);
+    }
+
     #[test]
     fn book_new_order() {
         let (mut market, oracle_price_lots, mut event_heap, book_accs) = test_setup(1000.0);
@@ -353,8 +683,8 @@ mod tests {
         assert_eq!(fill.taker, taker_pk);
 
         // simulate event heap processing
-        maker.execute_maker(&mut market, fill);
-        taker.execute_taker(&mut market, Side::Ask, 0, 0, 0, 0);
+        maker.execute_maker(&mut market, fill, None).unwrap();
+        taker.execute_taker(&mut market, Side::Ask, 0, 0, 0, 0, None);
 
         assert_eq!(maker.position.bids_base_lots, bid_quantity - match_quantity);
         assert_eq!(maker.position.asks_base_lots, 0);
@@ -149,7 +149,10 @@ pub struct LeafNode {
     /// A value of 0 means no expiry.
     pub time_in_force: u16,
 
-    pub padding: [u8; 4],
+    /// `OpenOrdersAccount::dmm_priority` of the owner when the order was placed
+    pub dmm_priority: u8,
+
+    pub padding: [u8; 3],
 
     /// The binary tree key, see new_node_key()
     pub key: u128,
@@ -189,6 +192,7 @@ impl LeafNode {
             tag: NodeTag::LeafNode.into(),
             owner_slot,
             time_in_force,
+            dmm_priority: 0,
             padding: Default::default(),
             key,
             owner,
@@ -207,6 +211,18 @@ impl LeafNode {
         (self.key >> 64) as u64
     }
 
+    /// The price in lots of a fixed order, the offset from the oracle price in lots
+    /// of an oracle pegged one
+    ///
+    /// Pegged orders are the ones with a `peg_limit` other than -1.
+    pub fn price_lots_or_peg_offset(&self) -> i64 {
+        if self.peg_limit == -1 {
+            fixed_price_lots(self.price_data())
+        } else {
+            oracle_pegged_price_offset(self.price_data())
+        }
+    }
+
     /// Time at which this order will expire, u64::MAX if never
     #[inline(always)]
     pub fn expiry(&self) -> u64 {
@@ -367,6 +383,20 @@ mod tests {
         assert_eq!(oracle_pegged_price_data(0), -(i64::MIN as i128) as u64); // remember -i64::MIN is not a valid i64
     }
 
+    #[test]
+    fn leaf_node_price_lots_or_peg_offset() {
+        let leaf = |price_data, peg_limit| {
+            let key = new_node_key(Side::Bid, price_data, 7);
+            LeafNode::new(0, key, Pubkey::default(), 1, 0, 0, peg_limit, 0)
+        };
+
+        let fixed = leaf(fixed_price_data(42).unwrap(), -1);
+        assert_eq!(fixed.price_lots_or_peg_offset(), 42);
+
+        let pegged = leaf(oracle_pegged_price_data(-5), 100);
+        assert_eq!(pegged.price_lots_or_peg_offset(), -5);
+    }
+
     #[test]
     fn order_tree_key_ordering() {
         let bid_seq: Vec<(i64, u64)> = vec![
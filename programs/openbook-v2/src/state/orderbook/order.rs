@@ -142,7 +142,7 @@ impl Order {
                 let price_lots = oracle_price_lots
                     .ok_or(OpenBookError::OraclePegInvalidOracleState)?
                     .checked_add(price_offset_lots)
-                    .ok_or(OpenBookError::InvalidPriceLots)?;
+                    .ok_or(OpenBookError::PriceOutsideBand)?;
 
                 self.price_for_order_type(
                     now_ts,
@@ -153,7 +153,7 @@ impl Order {
                 )
             }
         };
-        require_gte!(price_lots, 1, OpenBookError::InvalidPriceLots);
+        require_gte!(price_lots, 1, OpenBookError::PriceOutsideBand);
         let price_data = match self.params {
             OrderParams::OraclePegged { .. } => {
                 // unwrap cannot fail (already handled above)
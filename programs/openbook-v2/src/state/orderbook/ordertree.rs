@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use bytemuck::{cast, cast_mut, cast_ref};
+use bytemuck::{cast, cast_mut, cast_ref, Zeroable};
 
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 
@@ -7,6 +7,9 @@ use super::*;
 use crate::error::OpenBookError;
 
 pub const MAX_ORDERTREE_NODES: usize = 1024;
+/// Width of the expiry epochs resting orders are grouped by, see [`ExpiryBucket`]
+pub const EXPIRY_BUCKET_SECONDS: u64 = 60;
+pub const MAX_EXPIRY_BUCKETS: usize = 16;
 
 #[derive(
     Eq,
@@ -50,6 +53,26 @@ impl OrderTreeRoot {
     }
 }
 
+/// Resting orders expiring within the same epoch of `EXPIRY_BUCKET_SECONDS`.
+///
+/// Buckets are reused by epoch modulo `MAX_EXPIRY_BUCKETS`. Orders of epochs sharing
+/// a bucket are counted together under the earliest epoch, so a bucket is due as
+/// soon as its first order may have expired. Orders never expiring aren't counted.
+#[zero_copy]
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ExpiryBucket {
+    pub epoch: u64,
+    pub order_count: u32,
+    pub padding: [u8; 4],
+}
+
+impl ExpiryBucket {
+    /// Whether some of the bucket's orders may have expired at `now_ts`
+    pub fn is_due(&self, now_ts: u64) -> bool {
+        self.order_count > 0 && self.epoch * EXPIRY_BUCKET_SECONDS < now_ts
+    }
+}
+
 /// A binary tree on AnyNode::key()
 ///
 /// The key encodes the price in the top 64 bits.
@@ -60,7 +83,10 @@ pub struct OrderTreeNodes {
     pub bump_index: u32,
     pub free_list_len: u32,
     pub free_list_head: NodeHandle,
-    pub reserved: [u8; 512],
+    /// Leaves of all trees using these nodes grouped by expiry, see
+    /// [`OrderTreeNodes::due_expiry_order_count`]
+    pub expiry_buckets: [ExpiryBucket; MAX_EXPIRY_BUCKETS],
+    pub reserved: [u8; 256],
     pub nodes: [AnyNode; MAX_ORDERTREE_NODES],
 }
 
@@ -119,6 +145,39 @@ impl OrderTreeNodes {
         }
     }
 
+    /// Number of leaves in expiry buckets that are due at `now_ts`: an upper bound
+    /// of the expired leaves, read without walking the trees. Each one is then found
+    /// through the inner nodes' earliest expiry, see `remove_one_expired`.
+    pub fn due_expiry_order_count(&self, now_ts: u64) -> u32 {
+        self.expiry_buckets
+            .iter()
+            .filter(|bucket| bucket.is_due(now_ts))
+            .map(|bucket| bucket.order_count)
+            .sum()
+    }
+
+    fn add_to_expiry_bucket(&mut self, leaf: &LeafNode) {
+        if leaf.time_in_force == 0 {
+            return;
+        }
+        let epoch = leaf.expiry() / EXPIRY_BUCKET_SECONDS;
+        let bucket = &mut self.expiry_buckets[epoch as usize % MAX_EXPIRY_BUCKETS];
+        if bucket.order_count == 0 || epoch < bucket.epoch {
+            bucket.epoch = epoch;
+        }
+        bucket.order_count += 1;
+    }
+
+    fn remove_from_expiry_bucket(&mut self, leaf: &LeafNode) {
+        if leaf.time_in_force == 0 {
+            return;
+        }
+        let epoch = leaf.expiry() / EXPIRY_BUCKET_SECONDS;
+        let bucket = &mut self.expiry_buckets[epoch as usize % MAX_EXPIRY_BUCKETS];
+        // orders resting since before the buckets were tracked aren't counted
+        bucket.order_count = bucket.order_count.saturating_sub(1);
+    }
+
     // only for fixed-price ordertrees
     #[cfg(test)]
     #[allow(dead_code)]
@@ -182,6 +241,16 @@ impl OrderTreeNodes {
         }
     }
 
+    pub fn find_by_key(&self, root: &OrderTreeRoot, search_key: u128) -> Option<&LeafNode> {
+        let mut node_handle = root.node()?;
+        loop {
+            match self.node(node_handle)?.case()? {
+                NodeRef::Inner(inner) => node_handle = inner.walk_down(search_key).0,
+                NodeRef::Leaf(leaf) => return (leaf.key == search_key).then_some(leaf),
+            }
+        }
+    }
+
     pub fn remove_by_key(
         &mut self,
         root: &mut OrderTreeRoot,
@@ -198,6 +267,7 @@ impl OrderTreeNodes {
                 root.maybe_node = 0;
                 root.leaf_count = 0;
                 let _old_root = self.remove(parent_h).unwrap();
+                self.remove_from_expiry_bucket(&leaf);
                 return Some(leaf);
             }
             NodeRef::Leaf(_) => return None,
@@ -237,6 +307,7 @@ impl OrderTreeNodes {
         let outdated_expiry = removed_leaf.expiry();
         stack.pop(); // the final parent has been replaced by the remaining leaf
         self.update_parent_earliest_expiry(&stack, outdated_expiry, new_expiry);
+        self.remove_from_expiry_bucket(&removed_leaf);
 
         Some(removed_leaf)
     }
@@ -272,7 +343,7 @@ impl OrderTreeNodes {
         if self.free_list_len == 0 {
             require!(
                 (self.bump_index as usize) < self.nodes.len() && self.bump_index < u32::MAX,
-                OpenBookError::SomeError
+                OpenBookError::BookFull
             );
 
             self.nodes[self.bump_index as usize] = *val;
@@ -312,6 +383,7 @@ impl OrderTreeNodes {
                 let handle = self.insert(new_leaf.as_ref())?;
                 root.maybe_node = handle;
                 root.leaf_count = 1;
+                self.add_to_expiry_bucket(new_leaf);
                 return Ok((handle, None));
             }
         };
@@ -331,6 +403,8 @@ impl OrderTreeNodes {
                         old_parent_as_leaf.expiry(),
                         new_leaf.expiry(),
                     );
+                    self.remove_from_expiry_bucket(&old_parent_as_leaf);
+                    self.add_to_expiry_bucket(new_leaf);
                     return Ok((parent_handle, Some(old_parent_as_leaf)));
                 }
                 // InnerNodes have a random child's key, so matching can happen and is fine
@@ -383,10 +457,128 @@ impl OrderTreeNodes {
             }
 
             root.leaf_count += 1;
+            self.add_to_expiry_bucket(new_leaf);
             return Ok((new_leaf_handle, None));
         }
     }
 
+    /// Compacts the nodes of the trees at `roots` towards the start of the node array,
+    /// moving at most `limit` nodes, and rebuilds the free list in index order.
+    ///
+    /// After heavy churn live nodes end up spread over the whole array. Moving them
+    /// into the lowest free slots keeps lookups within fewer cache lines. Returns the
+    /// number of nodes moved, 0 once the nodes are compact.
+    pub fn defragment(&mut self, roots: &mut [OrderTreeRoot], limit: usize) -> usize {
+        let is_free = |node: &AnyNode| {
+            matches!(
+                NodeTag::try_from(node.tag),
+                Ok(NodeTag::FreeNode) | Ok(NodeTag::LastFreeNode)
+            )
+        };
+        let free: Vec<NodeHandle> = (0..self.bump_index)
+            .filter(|&h| is_free(&self.nodes[h as usize]))
+            .collect();
+
+        // move the highest live nodes into the lowest free slots
+        let mut moved = 0;
+        let mut free_iter = free.iter();
+        let mut source = self.bump_index;
+        while moved < limit {
+            let target = match free_iter.next() {
+                Some(&target) => target,
+                None => break,
+            };
+            source = match (target + 1..source)
+                .rev()
+                .find(|&h| !is_free(&self.nodes[h as usize]))
+            {
+                Some(source) => source,
+                None => break,
+            };
+            self.move_node(roots, source, target);
+            moved += 1;
+        }
+
+        // drop the free nodes at the end instead of keeping them on the free list
+        while self.bump_index > 0 && is_free(&self.nodes[self.bump_index as usize - 1]) {
+            self.bump_index -= 1;
+            self.nodes[self.bump_index as usize] = AnyNode::zeroed();
+        }
+
+        // rebuild the free list so the lowest free slots get used first
+        self.free_list_len = 0;
+        self.free_list_head = 0;
+        for h in (0..self.bump_index).rev() {
+            if !is_free(&self.nodes[h as usize]) {
+                continue;
+            }
+            self.nodes[h as usize] = cast(FreeNode {
+                tag: if self.free_list_len == 0 {
+                    NodeTag::LastFreeNode.into()
+                } else {
+                    NodeTag::FreeNode.into()
+                },
+                padding: Default::default(),
+                next: self.free_list_head,
+                reserved: [0; 72],
+                force_align: 0,
+            });
+            self.free_list_len += 1;
+            self.free_list_head = h;
+        }
+
+        moved
+    }
+
+    /// Internal: Moves the live node at `source` to the free slot `target` and points its
+    /// parent, or the root, at the new location. `source` is left as a free node, but
+    /// isn't added to the free list, see defragment().
+    fn move_node(&mut self, roots: &mut [OrderTreeRoot], source: NodeHandle, target: NodeHandle) {
+        let node = self.nodes[source as usize];
+        let key = node.key().unwrap();
+
+        let mut parent = None;
+        for (i, root) in roots.iter().enumerate() {
+            let mut current = match root.node() {
+                Some(h) => h,
+                None => continue,
+            };
+            if current == source {
+                parent = Some((i, None));
+                break;
+            }
+            // the prefix of an inner node's key is shared by all its descendants, so
+            // walking down by it passes through the node itself
+            while let Some(NodeRef::Inner(inner)) = self.node(current).unwrap().case() {
+                let (child, crit_bit) = inner.walk_down(key);
+                if child == source {
+                    parent = Some((i, Some((current, crit_bit))));
+                    break;
+                }
+                current = child;
+            }
+            if parent.is_some() {
+                break;
+            }
+        }
+
+        match parent.expect("moved node is part of a tree") {
+            (i, None) => roots[i].maybe_node = target,
+            (_, Some((parent_h, crit_bit))) => {
+                let parent = self.node_mut(parent_h).unwrap().as_inner_mut().unwrap();
+                parent.children[crit_bit as usize] = target;
+            }
+        }
+        self.nodes[target as usize] = node;
+        self.nodes[source as usize] = cast(FreeNode {
+            tag: NodeTag::FreeNode.into(),
+            padding: Default::default(),
+            next: 0,
+            reserved: [0; 72],
+            force_align: 0,
+        });
+    }
+
     pub fn is_full(&self) -> bool {
         self.free_list_len <= 1 && (self.bump_index as usize) >= self.nodes.len() - 1
     }
@@ -641,4 +833,120 @@ mod tests {
             verify_order_tree(&bids, &root);
         }
     }
+
+    #[test]
+    fn order_tree_defragment_random() {
+        use rand::Rng;
+        let mut rng = rand::thread_rng();
+
+        let mut roots = [OrderTreeRoot::zeroed(); 2];
+        let mut bids = new_order_tree(OrderTreeType::Bids);
+        let new_leaf = |key: u128| LeafNode::new(0, key, Pubkey::default(), 0, 1, 0, -1, 0);
+
+        // two trees sharing the nodes, like the fixed and oracle pegged trees of a BookSide
+        let mut keys = vec![vec![], vec![]];
+        for i in 0..400 {
+            let key: u128 = rng.gen_range(0..10000);
+            if keys.iter().any(|k| k.contains(&key)) {
+                continue;
+            }
+            keys[i % 2].push(key);
+            bids.insert_leaf(&mut roots[i % 2], &new_leaf(key)).unwrap();
+        }
+        // churn: removals spread the live nodes over the array
+        for _ in 0..250 {
+            let tree = rng.gen_range(0..2);
+            if keys[tree].is_empty() {
+                continue;
+            }
+            let index = rng.gen_range(0..keys[tree].len());
+            let k = keys[tree].swap_remove(index);
+            bids.remove_by_key(&mut roots[tree], k).unwrap();
+        }
+        assert!(bids.free_list_len > 0);
+
+        let leaf_keys = |bids: &OrderTreeNodes, root: &OrderTreeRoot| -> Vec<u128> {
+            bids.iter(root).map(|(_, leaf)| leaf.key).collect()
+        };
+        let before: Vec<Vec<u128>> = roots.iter().map(|r| leaf_keys(&bids, r)).collect();
+
+        // bounded work per call, repeat until compact
+        let mut calls = 0;
+        while bids.defragment(&mut roots, 10) > 0 {
+            calls += 1;
+            assert!(calls < 100);
+            for (root, keys) in roots.iter().zip(before.iter()) {
+                verify_order_tree(&bids, root);
+                assert_eq!(&leaf_keys(&bids, root), keys);
+            }
+        }
+        for (root, keys) in roots.iter().zip(before.iter()) {
+            verify_order_tree(&bids, root);
+            assert_eq!(&leaf_keys(&bids, root), keys);
+        }
+
+        let live_nodes: u32 = roots
+            .iter()
+            .filter(|r| r.leaf_count > 0)
+            .map(|r| 2 * r.leaf_count - 1)
+            .sum();
+        assert_eq!(bids.bump_index, live_nodes);
+        assert_eq!(bids.free_list_len, 0);
+
+        // the trees keep working, freed nodes are reused lowest first
+        let k = keys[0].pop().unwrap();
+        bids.remove_by_key(&mut roots[0], k).unwrap();
+        assert_eq!(bids.defragment(&mut roots, 0), 0);
+        assert_eq!(bids.bump_index - bids.free_list_len, live_nodes - 2);
+        let lowest_free = (0..bids.bump_index).find(|&h| bids.node(h).is_none());
+        let (handle, _) = bids.insert_leaf(&mut roots[0], &new_leaf(k)).unwrap();
+        if let Some(lowest_free) = lowest_free {
+            assert_eq!(handle, lowest_free);
+        }
+        verify_order_tree(&bids, &roots[0]);
+    }
+
+    #[test]
+    fn order_tree_expiry_buckets() {
+        let mut bids = new_order_tree(OrderTreeType::Bids);
+        let mut root = OrderTreeRoot::zeroed();
+        let new_expiring_leaf = |key: u128, expiry: u64| {
+            LeafNode::new(0, key, Pubkey::default(), 0, expiry - 1, 1, -1, 0)
+        };
+        let s = EXPIRY_BUCKET_SECONDS;
+
+        bids.insert_leaf(&mut root, &new_expiring_leaf(0, 10 * s))
+            .unwrap();
+        bids.insert_leaf(&mut root, &new_expiring_leaf(1, 10 * s + 1))
+            .unwrap();
+        bids.insert_leaf(&mut root, &new_expiring_leaf(2, 12 * s))
+            .unwrap();
+        // never expires
+        bids.insert_leaf(
+            &mut root,
+            &LeafNode::new(0, 3, Pubkey::default(), 0, 0, 0, -1, 0),
+        )
+        .unwrap();
+
+        assert_eq!(bids.due_expiry_order_count(10 * s), 0);
+        assert_eq!(bids.due_expiry_order_count(10 * s + 1), 2);
+        assert_eq!(bids.due_expiry_order_count(12 * s + 1), 3);
+        assert_eq!(bids.due_expiry_order_count(u64::MAX), 3);
+
+        // a later epoch sharing the bucket counts as the earliest one
+        let later_epoch = 10 + MAX_EXPIRY_BUCKETS as u64;
+        bids.insert_leaf(&mut root, &new_expiring_leaf(4, later_epoch * s))
+            .unwrap();
+        assert_eq!(bids.due_expiry_order_count(10 * s + 1), 3);
+        assert_eq!(bids.expiry_buckets[10].epoch, 10);
+
+        for key in 0..5 {
+            bids.remove_by_key(&mut root, key).unwrap();
+        }
+        assert_eq!(bids.due_expiry_order_count(u64::MAX), 0);
+        assert!(bids
+            .expiry_buckets
+            .iter()
+            .all(|bucket| bucket.order_count == 0));
+    }
 }
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use super::MarketAdmin;
+
+/// Admin role of a [`Market`](crate::state::Market) offered with `set_market_authority`,
+/// until the new admin takes it over with `accept_market_authority`.
+///
+/// PDA of seeds `[b"PendingMarketAdmin", market]`, so only one handover is pending at
+/// a time. It's closed when accepted or cancelled, returning the rent to `payer`.
+#[account]
+#[derive(Default)]
+pub struct PendingMarketAdmin {
+    pub bump: u8,
+    pub market: Pubkey,
+    /// See [`MarketAdmin`].
+    pub role: u8,
+    pub new_admin: Pubkey,
+    pub payer: Pubkey,
+}
+
+impl PendingMarketAdmin {
+    pub const SPACE: usize = 8 + 1 + 32 + 1 + 32 + 32;
+
+    pub fn role(&self) -> MarketAdmin {
+        MarketAdmin::try_from(self.role).unwrap()
+    }
+}
@@ -0,0 +1,211 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use fixed::types::I80F48;
+use std::cell::RefMut;
+
+use super::{Market, OpenOrdersAccount, Side};
+use crate::accounts_zerocopy::LoadMutZeroCopyRef;
+use crate::error::OpenBookError;
+use crate::pubkey_option::NonZeroPubkeyOption;
+
+/// Position data of an open orders account that doesn't fit in its [`Position`](super::Position),
+/// created by the owner with `create_position_extension`.
+///
+/// Once created, the extension has to be passed among the remaining accounts of
+/// every instruction executing fills of the account, see [`load_position_extension`].
+#[account(zero_copy)]
+pub struct PositionExtension {
+    pub open_orders_account: Pubkey,
+    pub bump: u8,
+    pub padding: [u8; 7],
+
+    /// Base native bought minus sold in fills, negative when more was sold
+    pub net_base_native: i64,
+    /// Quote native paid for `net_base_native`, negative for a net sold amount
+    pub cost_basis_native: i64,
+    /// Cumulative profit of fills reducing `net_base_native` against its average
+    /// entry price, in quote native units. Fees are tracked separately.
+    pub realized_pnl_native: i64,
+
+    /// Start of the current window of the market's `order_rate_limit`
+    pub order_window_start_ts: u64,
+    /// New orders placed since `order_window_start_ts`
+    pub orders_in_window: u32,
+    pub padding2: [u8; 4],
+
+    /// Program approving new orders of the account through a `check_health` CPI,
+    /// see [`health_check`](crate::health_check)
+    pub risk_program: NonZeroPubkeyOption,
+    /// Program `settle_funds` routes the settled funds into, see
+    /// [`settle_receiver`](crate::settle_receiver)
+    pub settle_receiver: NonZeroPubkeyOption,
+
+    /// Time after which anyone can cancel all orders of the account with
+    /// `execute_scheduled_cancel`, 0 when none is scheduled
+    pub cancel_all_after_ts: u64,
+    /// Seconds each `heartbeat` pushes `cancel_all_after_ts` out by, 0 when disabled
+    pub heartbeat_interval: u32,
+    pub padding3: [u8; 4],
+    /// Lamports escrowed in the extension for whoever executes the scheduled cancel
+    pub heartbeat_bounty: u64,
+
+    /// Nonce of the last order placed with `place_signed_order`, signed orders
+    /// need a higher one
+    pub signed_order_nonce: u64,
+
+    pub reserved: [u8; 144],
+}
+
+impl PositionExtension {
+    /// Average price paid for `net_base_native`, in quote native per base native
+    pub fn average_entry_price(&self) -> Option<I80F48> {
+        if self.net_base_native == 0 {
+            return None;
+        }
+        Some(I80F48::from_num(self.cost_basis_native) / I80F48::from_num(self.net_base_native))
+    }
+
+    /// Update the net position and realized PnL with a fill buying (`Side::Bid`)
+    /// or selling `base_native` for `quote_native`, fees excluded
+    pub fn record_fill_pnl(&mut self, side: Side, base_native: i64, quote_native: i64) {
+        if base_native == 0 {
+            return;
+        }
+        let (base, cost) = match side {
+            Side::Bid => (base_native, quote_native),
+            Side::Ask => (-base_native, -quote_native),
+        };
+
+        let net = self.net_base_native;
+        if net == 0 || net.signum() == base.signum() {
+            self.net_base_native += base;
+            self.cost_basis_native += cost;
+            return;
+        }
+
+        // Close the opposite position at its average entry price first
+        let closed = base.abs().min(net.abs());
+        let closed_cost_basis = (i128::from(self.cost_basis_native) * i128::from(closed)
+            / i128::from(net.abs())) as i64;
+        let closed_cost = (i128::from(cost) * i128::from(closed) / i128::from(base.abs())) as i64;
+        self.realized_pnl_native -= closed_cost_basis + closed_cost;
+        self.net_base_native += closed * base.signum();
+        self.cost_basis_native -= closed_cost_basis;
+
+        // and open a new one with whatever is left
+        if closed < base.abs() {
+            self.net_base_native += base - closed * base.signum();
+            self.cost_basis_native += cost - closed_cost;
+        }
+    }
+
+    /// Count a new order against the market's `order_rate_limit`
+    pub fn record_new_order(&mut self, market: &Market, now_ts: u64) -> Result<()> {
+        if market.order_rate_limit == 0 {
+            return Ok(());
+        }
+
+        if now_ts >= self.order_window_start_ts + u64::from(market.order_rate_limit_window) {
+            self.order_window_start_ts = now_ts;
+            self.orders_in_window = 0;
+        }
+        require_gt!(
+            market.order_rate_limit,
+            self.orders_in_window,
+            OpenBookError::OrderRateLimitExceeded
+        );
+        self.orders_in_window += 1;
+        Ok(())
+    }
+}
+
+/// The [`PositionExtension`] of the open orders account at `key` among `remaining_accs`
+pub fn find_position_extension<'c, 'info>(
+    key: &Pubkey,
+    remaining_accs: &'c [AccountInfo<'info>],
+) -> Result<Option<RefMut<'c, PositionExtension>>> {
+    // open_orders_account is the first field after the discriminator
+    let extension_ai = remaining_accs.iter().find(|ai| {
+        ai.owner == &crate::id()
+            && ai.try_borrow_data().map_or(false, |data| {
+                data.starts_with(&PositionExtension::discriminator())
+                    && data.get(8..40) == Some(key.as_ref())
+            })
+    });
+    match extension_ai {
+        Some(ai) => Ok(Some(ai.load_mut::<PositionExtension>()?)),
+        None => Ok(None),
+    }
+}
+
+/// Like [`find_position_extension`], but fails with `MissingPositionExtension` when
+/// `open_orders_account` has an extension that wasn't passed
+pub fn load_position_extension<'c, 'info>(
+    open_orders_account: &OpenOrdersAccount,
+    key: &Pubkey,
+    remaining_accs: &'c [AccountInfo<'info>],
+) -> Result<Option<RefMut<'c, PositionExtension>>> {
+    if !open_orders_account.has_position_extension() {
+        return Ok(None);
+    }
+    let extension = find_position_extension(key, remaining_accs)?;
+    require!(extension.is_some(), OpenBookError::MissingPositionExtension);
+    Ok(extension)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytemuck::Zeroable;
+
+    #[test]
+    fn test_record_fill_pnl() {
+        let mut ext = PositionExtension::zeroed();
+        assert_eq!(ext.average_entry_price(), None);
+
+        // buy 10 at 100 and 10 at 120
+        ext.record_fill_pnl(Side::Bid, 10, 1_000);
+        ext.record_fill_pnl(Side::Bid, 10, 1_200);
+        assert_eq!(ext.net_base_native, 20);
+        assert_eq!(ext.average_entry_price(), Some(I80F48::from_num(110)));
+
+        // sell 5 at 130
+        ext.record_fill_pnl(Side::Ask, 5, 650);
+        assert_eq!(ext.net_base_native, 15);
+        assert_eq!(ext.cost_basis_native, 1_650);
+        assert_eq!(ext.realized_pnl_native, 100);
+
+        // sell 25 at 100, closing the rest and going short 10
+        ext.record_fill_pnl(Side::Ask, 25, 2_500);
+        assert_eq!(ext.net_base_native, -10);
+        assert_eq!(ext.cost_basis_native, -1_000);
+        assert_eq!(ext.realized_pnl_native, -50);
+        assert_eq!(ext.average_entry_price(), Some(I80F48::from_num(100)));
+
+        // buy back the short at 90
+        ext.record_fill_pnl(Side::Bid, 10, 900);
+        assert_eq!(ext.net_base_native, 0);
+        assert_eq!(ext.cost_basis_native, 0);
+        assert_eq!(ext.realized_pnl_native, 50);
+    }
+
+    #[test]
+    fn test_record_new_order() {
+        let mut market = Market::zeroed();
+        let mut ext = PositionExtension::zeroed();
+        for _ in 0..5 {
+            ext.record_new_order(&market, 1000).unwrap();
+        }
+
+        // 2 orders per 10 seconds
+        market.order_rate_limit = 2;
+        market.order_rate_limit_window = 10;
+        ext.record_new_order(&market, 1000).unwrap();
+        ext.record_new_order(&market, 1005).unwrap();
+        assert!(ext.record_new_order(&market, 1009).is_err());
+
+        ext.record_new_order(&market, 1010).unwrap();
+        assert_eq!(ext.order_window_start_ts, 1010);
+        assert_eq!(ext.orders_in_window, 1);
+    }
+}
@@ -0,0 +1,171 @@
+use anchor_lang::prelude::*;
+
+use super::{is_within_oracle_distance, BookSide};
+
+/// Longest time a sample of the quote quality accounts for. Longer gaps between
+/// samples are only counted up to it.
+pub const MAX_QUOTE_QUALITY_INTERVAL: u64 = 300;
+
+/// Quoting of a designated market maker's open orders account, measured against
+/// the terms of its agreement, see `sample_quote_quality`.
+///
+/// The account quotes while it has at least `min_size_lots` on each side within
+/// `max_oracle_distance_bps` of the oracle price. Each sample credits the time since
+/// the previous one to the state it observes, so the counterparty of the agreement
+/// should sample at unpredictable times. Stats are kept per epoch of
+/// `epoch_duration` seconds, the last complete one stays in `previous_epoch`.
+#[account(zero_copy)]
+pub struct QuoteQuality {
+    pub market: Pubkey,
+    pub open_orders_account: Pubkey,
+
+    pub min_size_lots: i64,
+    pub epoch_duration: u64,
+    pub last_sample_timestamp: u64,
+
+    pub max_oracle_distance_bps: u16,
+    pub bump: u8,
+    pub padding: [u8; 5],
+
+    pub current_epoch: QuoteQualityEpoch,
+    pub previous_epoch: QuoteQualityEpoch,
+
+    pub reserved: [u8; 64],
+}
+
+#[zero_copy]
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct QuoteQualityEpoch {
+    pub start_timestamp: u64,
+    pub samples: u64,
+    /// Seconds accounted for by samples
+    pub seconds_sampled: u64,
+    /// Seconds accounted for by samples where the account was quoting
+    pub seconds_quoting: u64,
+    /// Smallest size within the oracle distance seen by a sample, per side
+    pub min_bid_size_lots: i64,
+    pub min_ask_size_lots: i64,
+}
+
+impl QuoteQualityEpoch {
+    fn new(start_timestamp: u64) -> Self {
+        Self {
+            start_timestamp,
+            samples: 0,
+            seconds_sampled: 0,
+            seconds_quoting: 0,
+            min_bid_size_lots: i64::MAX,
+            min_ask_size_lots: i64::MAX,
+        }
+    }
+
+    /// Share of the sampled time the account was quoting
+    pub fn uptime_bps(&self) -> u64 {
+        if self.seconds_sampled == 0 {
+            return 0;
+        }
+        self.seconds_quoting * 10_000 / self.seconds_sampled
+    }
+}
+
+impl QuoteQuality {
+    pub fn start(&mut self, now_ts: u64) {
+        self.last_sample_timestamp = now_ts;
+        self.current_epoch = QuoteQualityEpoch::new(now_ts);
+        self.previous_epoch = QuoteQualityEpoch::default();
+    }
+
+    /// Base lots of the account's valid orders on `book_side` within the oracle distance
+    pub fn quoted_size_lots(
+        &self,
+        book_side: &BookSide,
+        now_ts: u64,
+        oracle_price_lots: i64,
+    ) -> i64 {
+        let side = book_side.nodes.order_tree_type().side();
+        let mut size = 0;
+        for order in book_side.iter_valid(now_ts, Some(oracle_price_lots)) {
+            if !is_within_oracle_distance(
+                order.price_lots,
+                oracle_price_lots,
+                self.max_oracle_distance_bps,
+            ) {
+                // orders are sorted best first, the rest is further from the oracle
+                if side.is_price_better(oracle_price_lots, order.price_lots) {
+                    break;
+                }
+                continue;
+            }
+            if order.node.owner == self.open_orders_account {
+                size += order.node.quantity;
+            }
+        }
+        size
+    }
+
+    pub fn record_sample(&mut self, now_ts: u64, bid_size_lots: i64, ask_size_lots: i64) {
+        let epoch_end = self.current_epoch.start_timestamp + self.epoch_duration;
+        if now_ts >= epoch_end {
+            self.previous_epoch = self.current_epoch;
+            self.current_epoch = QuoteQualityEpoch::new(now_ts);
+        }
+
+        let since = self
+            .last_sample_timestamp
+            .max(self.current_epoch.start_timestamp);
+        let elapsed = now_ts.saturating_sub(since).min(MAX_QUOTE_QUALITY_INTERVAL);
+        let is_quoting = bid_size_lots >= self.min_size_lots && ask_size_lots >= self.min_size_lots;
+
+        let epoch = &mut self.current_epoch;
+        epoch.samples += 1;
+        epoch.seconds_sampled += elapsed;
+        if is_quoting {
+            epoch.seconds_quoting += elapsed;
+        }
+        epoch.min_bid_size_lots = epoch.min_bid_size_lots.min(bid_size_lots);
+        epoch.min_ask_size_lots = epoch.min_ask_size_lots.min(ask_size_lots);
+        self.last_sample_timestamp = now_ts;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytemuck::Zeroable;
+
+    fn quote_quality() -> QuoteQuality {
+        let mut quote_quality = QuoteQuality::zeroed();
+        quote_quality.min_size_lots = 10;
+        quote_quality.epoch_duration = 1000;
+        quote_quality.start(0);
+        quote_quality
+    }
+
+    #[test]
+    fn test_record_sample() {
+        let mut quote_quality = quote_quality();
+        quote_quality.record_sample(100, 10, 20);
+        quote_quality.record_sample(200, 10, 5);
+        // capped at MAX_QUOTE_QUALITY_INTERVAL
+        quote_quality.record_sample(900, 15, 15);
+
+        let epoch = quote_quality.current_epoch;
+        assert_eq!(epoch.samples, 3);
+        assert_eq!(epoch.seconds_sampled, 500);
+        assert_eq!(epoch.seconds_quoting, 400);
+        assert_eq!(epoch.uptime_bps(), 8_000);
+        assert_eq!(epoch.min_bid_size_lots, 10);
+        assert_eq!(epoch.min_ask_size_lots, 5);
+        assert_eq!(quote_quality.previous_epoch, QuoteQualityEpoch::default());
+
+        // the next epoch starts with the first sample after the end of this one
+        quote_quality.record_sample(1100, 0, 0);
+        assert_eq!(quote_quality.previous_epoch, epoch);
+        let epoch = quote_quality.current_epoch;
+        assert_eq!(epoch.start_timestamp, 1100);
+        assert_eq!(epoch.samples, 1);
+        assert_eq!(epoch.seconds_sampled, 0);
+        assert_eq!(epoch.uptime_bps(), 0);
+        assert_eq!(epoch.min_bid_size_lots, 0);
+    }
+}
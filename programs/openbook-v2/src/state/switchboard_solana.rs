@@ -317,7 +317,7 @@ impl From<SwitchboardDecimal> for bool {
 impl TryInto<u64> for SwitchboardDecimal {
     type Error = anchor_lang::error::Error;
     fn try_into(self) -> anchor_lang::Result<u64> {
-        let dec: Decimal = (&self).try_into().unwrap();
+        let dec: Decimal = (&self).try_into()?;
         dec.to_u64()
             .ok_or(error!(SwitchboardError::IntegerOverflowError))
     }
@@ -326,7 +326,7 @@ impl TryInto<u64> for SwitchboardDecimal {
 impl TryInto<i64> for SwitchboardDecimal {
     type Error = anchor_lang::error::Error;
     fn try_into(self) -> anchor_lang::Result<i64> {
-        let dec: Decimal = (&self).try_into().unwrap();
+        let dec: Decimal = (&self).try_into()?;
         dec.to_i64()
             .ok_or(error!(SwitchboardError::IntegerOverflowError))
     }
@@ -335,7 +335,7 @@ impl TryInto<i64> for SwitchboardDecimal {
 impl TryInto<f64> for SwitchboardDecimal {
     type Error = anchor_lang::error::Error;
     fn try_into(self) -> anchor_lang::Result<f64> {
-        let dec: Decimal = (&self).try_into().unwrap();
+        let dec: Decimal = (&self).try_into()?;
         dec.to_f64()
             .ok_or(error!(SwitchboardError::IntegerOverflowError))
     }
@@ -408,3 +408,109 @@ pub enum SwitchboardError {
     #[msg("Service is already being executed by a worker. Please remove the service before adding to a new service worker")]
     ServiceAlreadyAssignedToWorker,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Largest mantissa a `Decimal` holds, 96 bits
+    const MAX_MANTISSA: i128 = (1 << 96) - 1;
+    const MAX_SCALE: u32 = 28;
+
+    fn representable() -> impl Strategy<Value = SwitchboardDecimal> {
+        (-MAX_MANTISSA..=MAX_MANTISSA, 0..=MAX_SCALE)
+            .prop_map(|(mantissa, scale)| SwitchboardDecimal::new(mantissa, scale))
+    }
+
+    #[test]
+    fn test_conversion_errors_instead_of_panicking() {
+        for (mantissa, scale) in [
+            (1, MAX_SCALE + 1),
+            (1, u32::MAX),
+            (MAX_MANTISSA + 1, 0),
+            (i128::MIN, 0),
+            (i128::MAX, 6),
+        ] {
+            let value = SwitchboardDecimal::new(mantissa, scale);
+            assert!(TryInto::<f64>::try_into(value).is_err());
+            assert!(TryInto::<u64>::try_into(value).is_err());
+            assert!(TryInto::<i64>::try_into(value).is_err());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn decimal_round_trip(value in representable()) {
+            let decimal: Decimal = value.try_into().unwrap();
+            prop_assert_eq!(SwitchboardDecimal::from(decimal), value);
+            let borsh = BorshDecimal::from(value);
+            prop_assert_eq!(SwitchboardDecimal::from(borsh), value);
+            let decimal_from_borsh: Decimal = borsh.try_into().unwrap();
+            prop_assert_eq!(decimal_from_borsh, decimal);
+        }
+
+        #[test]
+        fn decimal_conversion_never_panics(mantissa in any::<i128>(), scale in any::<u32>()) {
+            let value = SwitchboardDecimal::new(mantissa, scale);
+            let in_range = mantissa.unsigned_abs() <= MAX_MANTISSA as u128 && scale <= MAX_SCALE;
+            prop_assert_eq!(TryInto::<Decimal>::try_into(value).is_ok(), in_range);
+            prop_assert_eq!(TryInto::<f64>::try_into(value).is_ok(), in_range);
+            let _: anchor_lang::Result<u64> = value.try_into();
+            let _: anchor_lang::Result<i64> = value.try_into();
+        }
+
+        /// Feed results are prices with up to 18 significant digits, converting them
+        /// only loses what an `f64` can't hold
+        #[test]
+        fn feed_values_convert_to_f64(
+            mantissa in -1_000_000_000_000_000_000i128..=1_000_000_000_000_000_000,
+            scale in 0..=MAX_SCALE,
+        ) {
+            let value = SwitchboardDecimal::new(mantissa, scale);
+            let price: f64 = value.try_into().unwrap();
+            let expected = mantissa as f64 / 10f64.powi(scale as i32);
+            prop_assert!((price - expected).abs() <= expected.abs() * 1e-12);
+        }
+
+        /// Scaling up is exact while the mantissa fits an i128, 10^18 * 10^20 does
+        #[test]
+        fn scale_up_is_exact(
+            mantissa in -1_000_000_000_000_000_000i128..=1_000_000_000_000_000_000,
+            scale in 0..=18u32,
+            extra in 0..=20u32,
+        ) {
+            let value = SwitchboardDecimal::new(mantissa, scale);
+            let scaled = value.new_with_scale(scale + extra);
+            prop_assert_eq!(scaled.mantissa, mantissa * 10i128.pow(extra));
+            prop_assert_eq!(scaled.new_with_scale(scale), value);
+            if scale + extra <= MAX_SCALE {
+                let decimal: Decimal = value.try_into().unwrap();
+                let scaled_decimal: Decimal = scaled.try_into().unwrap();
+                prop_assert_eq!(scaled_decimal, decimal);
+            }
+        }
+
+        /// Scaling down truncates towards zero, by less than one unit of the new scale
+        #[test]
+        fn scale_down_truncates_towards_zero(
+            mantissa in any::<i128>(),
+            scale in 0..=38u32,
+            fewer in 0..=38u32,
+        ) {
+            let new_scale = scale.saturating_sub(fewer);
+            let unit = 10i128.pow(scale - new_scale);
+            let scaled = SwitchboardDecimal::new(mantissa, scale).scale_to(new_scale);
+            let dropped = mantissa - scaled * unit;
+            prop_assert!(dropped.abs() < unit);
+            prop_assert!(dropped == 0 || dropped.signum() == mantissa.signum());
+        }
+
+        #[test]
+        fn ordering_matches_decimal(lhs in representable(), rhs in representable()) {
+            let lhs_decimal: Decimal = lhs.try_into().unwrap();
+            let rhs_decimal: Decimal = rhs.try_into().unwrap();
+            prop_assert_eq!(lhs.cmp(&rhs), lhs_decimal.cmp(&rhs_decimal));
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{get_stack_height, TRANSACTION_LEVEL_STACK_HEIGHT};
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+
+use crate::error::OpenBookError;
+
+pub const MAX_TAKER_PROGRAMS: usize = 8;
+
+/// Per-market PDA listing the programs allowed to `place_take_order` on a
+/// permissioned market through CPI without the `open_orders_admin` signature.
+///
+/// This lets an aggregator be granted access once instead of whitelisting each
+/// of its users.
+#[account(zero_copy)]
+pub struct TakerProgramAllowlist {
+    pub market: Pubkey,
+    /// Unused slots are zero.
+    pub programs: [Pubkey; MAX_TAKER_PROGRAMS],
+    pub bump: u8,
+    pub padding: [u8; 7],
+    pub reserved: [u8; 64],
+}
+
+impl TakerProgramAllowlist {
+    pub fn set_programs(&mut self, programs: &[Pubkey]) -> Result<()> {
+        require_gte!(
+            MAX_TAKER_PROGRAMS,
+            programs.len(),
+            OpenBookError::TooManyTakerPrograms
+        );
+        self.programs = [Pubkey::default(); MAX_TAKER_PROGRAMS];
+        self.programs[..programs.len()].copy_from_slice(programs);
+        Ok(())
+    }
+
+    pub fn contains(&self, program_id: &Pubkey) -> bool {
+        *program_id != Pubkey::default() && self.programs.contains(program_id)
+    }
+
+    /// Whether the current instruction is a CPI from one of the allowed programs.
+    ///
+    /// The caller is taken to be the program of the top level instruction, read
+    /// from the instructions sysvar. That only holds when it invoked this program
+    /// directly, so deeper CPIs are never allowed.
+    pub fn is_cpi_from_allowed_program(&self, instructions_sysvar: &AccountInfo) -> Result<bool> {
+        if get_stack_height() != TRANSACTION_LEVEL_STACK_HEIGHT + 1 {
+            return Ok(false);
+        }
+        let current_index = load_current_index_checked(instructions_sysvar)?;
+        let current_ix = load_instruction_at_checked(current_index.into(), instructions_sysvar)?;
+        Ok(current_ix.program_id != crate::id() && self.contains(&current_ix.program_id))
+    }
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+/// Promotional rebate of a share of the taker fees of a market, funded by its admin.
+///
+/// While the campaign runs, takers with an open orders account accrue `rebate_bps`
+/// of the taker fees they pay, as long as the campaign account is among the remaining
+/// accounts of the order. Rebates are paid in quote out of `rebate_vault` with
+/// `claim_taker_rebates`.
+///
+/// Only the budget funded with `fund_taker_rebate_campaign` is rebated, rebates of
+/// fills past it are dropped.
+#[account(zero_copy)]
+pub struct TakerRebateCampaign {
+    pub market: Pubkey,
+    /// Quote token account owned by this account
+    pub rebate_vault: Pubkey,
+
+    pub start_timestamp: u64,
+    pub end_timestamp: u64,
+
+    /// Funded rebates not accrued yet
+    pub budget_remaining: u64,
+    /// Rebates accrued to open orders accounts and not claimed yet
+    pub rebates_outstanding: u64,
+    /// Rebates accrued since creation
+    pub rebates_accrued_total: u64,
+
+    /// Share of the taker fees rebated
+    pub rebate_bps: u16,
+    pub bump: u8,
+    pub padding: [u8; 5],
+    pub reserved: [u8; 64],
+}
+
+impl TakerRebateCampaign {
+    pub fn is_active(&self, now_ts: u64) -> bool {
+        self.start_timestamp <= now_ts && now_ts < self.end_timestamp
+    }
+
+    /// Accrue the rebate of `taker_fees_native` paid at `now_ts` and return it
+    pub fn accrue(&mut self, now_ts: u64, taker_fees_native: u64) -> u64 {
+        if !self.is_active(now_ts) {
+            return 0;
+        }
+
+        let rebate = u128::from(taker_fees_native) * u128::from(self.rebate_bps) / 10_000;
+        let rebate = (rebate as u64).min(self.budget_remaining);
+        self.budget_remaining -= rebate;
+        self.rebates_outstanding += rebate;
+        self.rebates_accrued_total += rebate;
+        rebate
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytemuck::Zeroable;
+
+    #[test]
+    fn test_accrue() {
+        let mut campaign = TakerRebateCampaign::zeroed();
+        campaign.start_timestamp = 1000;
+        campaign.end_timestamp = 2000;
+        campaign.rebate_bps = 2_500;
+        campaign.budget_remaining = 30;
+
+        assert_eq!(campaign.accrue(999, 100), 0);
+        assert_eq!(campaign.accrue(2000, 100), 0);
+
+        assert_eq!(campaign.accrue(1000, 99), 24);
+        assert_eq!(campaign.accrue(1999, 100), 6);
+        assert_eq!(campaign.accrue(1999, 100), 0);
+
+        assert_eq!(campaign.budget_remaining, 0);
+        assert_eq!(campaign.rebates_outstanding, 30);
+        assert_eq!(campaign.rebates_accrued_total, 30);
+    }
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::error::OpenBookError;
+
+/// Per-market PDA letting holders of a given token (e.g. a KYC NFT) trade on a
+/// permissioned market without the `open_orders_admin` co-signing every order.
+///
+/// The holding is checked on every order placement, so selling or moving the
+/// token revokes access right away.
+#[account(zero_copy)]
+pub struct TokenGate {
+    pub market: Pubkey,
+    pub mint: Pubkey,
+    /// Minimum balance, in native units, a token account must hold to place orders.
+    pub min_amount: u64,
+    pub bump: u8,
+    pub padding: [u8; 7],
+    pub reserved: [u8; 64],
+}
+
+impl TokenGate {
+    pub fn is_satisfied_by(&self, token_account: &TokenAccount, owner: &Pubkey) -> bool {
+        token_account.mint == self.mint
+            && token_account.owner == *owner
+            && token_account.amount >= self.min_amount
+    }
+}
+
+/// Whether `gate_token_account` lets `owner` place orders through the market's
+/// `token_gate`. Both accounts are optional; without them the gate isn't used.
+pub fn holds_gate_token(
+    token_gate: &Option<AccountLoader<TokenGate>>,
+    gate_token_account: &Option<Box<Account<TokenAccount>>>,
+    owner: &Pubkey,
+) -> Result<bool> {
+    match (token_gate, gate_token_account) {
+        (Some(token_gate), Some(gate_token_account)) => {
+            require!(
+                token_gate
+                    .load()?
+                    .is_satisfied_by(gate_token_account, owner),
+                OpenBookError::InvalidTokenGateAccount
+            );
+            Ok(true)
+        }
+        _ => Ok(false),
+    }
+}
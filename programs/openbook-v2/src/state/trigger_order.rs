@@ -0,0 +1,180 @@
+use anchor_lang::prelude::*;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+use super::{Order, OrderParams, PlaceOrderType, SelfTradeBehavior, Side};
+
+/// When a [`TriggerOrder`] can be executed.
+///
+/// Oracle conditions compare the market's oracle price. Book conditions compare
+/// the best opposing price, the one the order would take at: the best ask for
+/// bids, the best bid for asks. Conditions are never met while the price is
+/// unavailable.
+#[derive(
+    Eq,
+    PartialEq,
+    Copy,
+    Clone,
+    TryFromPrimitive,
+    IntoPrimitive,
+    Debug,
+    AnchorSerialize,
+    AnchorDeserialize,
+)]
+#[repr(u8)]
+pub enum TriggerCondition {
+    /// Oracle price at or above the trigger price
+    OraclePriceAbove = 0,
+    /// Oracle price at or below the trigger price
+    OraclePriceBelow = 1,
+    /// Best opposing price at or above the trigger price
+    BookPriceAbove = 2,
+    /// Best opposing price at or below the trigger price
+    BookPriceBelow = 3,
+}
+
+/// An order of an open orders account waiting for a price condition, e.g. a stop
+/// loss, take profit or limit-if-touched order.
+///
+/// Created by the owner or delegate with `create_trigger_order`. Once the
+/// condition is met anyone can place the order with `execute_trigger` and gets the
+/// rent of this account in return. The order is funded from the free balances of
+/// the open orders account only, as no token account signs for it.
+#[account(zero_copy)]
+#[derive(Debug)]
+pub struct TriggerOrder {
+    pub owner: Pubkey,
+    pub market: Pubkey,
+    pub open_orders_account: Pubkey,
+    /// Chosen by the owner, distinguishes the trigger orders of an account
+    pub trigger_id: u64,
+    pub trigger_price_lots: i64,
+
+    pub price_lots: i64,
+    pub max_base_lots: i64,
+    pub max_quote_lots_including_fees: i64,
+    pub client_order_id: u64,
+    /// Expiry of the placed order, see `PlaceOrderArgs`. Also the last moment the
+    /// trigger can be executed, 0 for never.
+    pub expiry_timestamp: u64,
+
+    /// See [`TriggerCondition`]
+    pub condition: u8,
+    /// See [`Side`]
+    pub side: u8,
+    /// See [`PlaceOrderType`]
+    pub order_type: u8,
+    /// See [`SelfTradeBehavior`]
+    pub self_trade_behavior: u8,
+    /// Max orders of the book to fill when executed
+    pub limit: u8,
+    pub bump: u8,
+    pub padding: [u8; 2],
+
+    pub reserved: [u8; 64],
+}
+
+impl TriggerOrder {
+    pub fn condition(&self) -> TriggerCondition {
+        TriggerCondition::try_from(self.condition).unwrap()
+    }
+
+    pub fn side(&self) -> Side {
+        Side::try_from(self.side).unwrap()
+    }
+
+    /// Is the condition met, given the oracle price and the top of the book?
+    pub fn is_triggered(
+        &self,
+        oracle_price_lots: Option<i64>,
+        best_bid_lots: Option<i64>,
+        best_ask_lots: Option<i64>,
+    ) -> bool {
+        let book_price_lots = match self.side() {
+            Side::Bid => best_ask_lots,
+            Side::Ask => best_bid_lots,
+        };
+        match self.condition() {
+            TriggerCondition::OraclePriceAbove => {
+                oracle_price_lots.map_or(false, |price| price >= self.trigger_price_lots)
+            }
+            TriggerCondition::OraclePriceBelow => {
+                oracle_price_lots.map_or(false, |price| price <= self.trigger_price_lots)
+            }
+            TriggerCondition::BookPriceAbove => {
+                book_price_lots.map_or(false, |price| price >= self.trigger_price_lots)
+            }
+            TriggerCondition::BookPriceBelow => {
+                book_price_lots.map_or(false, |price| price <= self.trigger_price_lots)
+            }
+        }
+    }
+
+    /// The order to place, with its time in force counted from now. `None` once it
+    /// expired.
+    pub fn order(&self) -> Result<Option<Order>> {
+        let order_type = PlaceOrderType::try_from(self.order_type).unwrap();
+        let time_in_force = match Order::tif_from_expiry(self.expiry_timestamp) {
+            Some(t) => t,
+            None => return Ok(None),
+        };
+        Ok(Some(Order {
+            side: self.side(),
+            max_base_lots: self.max_base_lots,
+            max_quote_lots_including_fees: self.max_quote_lots_including_fees,
+            client_order_id: self.client_order_id,
+            time_in_force,
+            self_trade_behavior: SelfTradeBehavior::try_from(self.self_trade_behavior).unwrap(),
+            params: match order_type {
+                PlaceOrderType::Market => OrderParams::Market,
+                PlaceOrderType::ImmediateOrCancel => OrderParams::ImmediateOrCancel {
+                    price_lots: self.price_lots,
+                },
+                PlaceOrderType::FillOrKill => OrderParams::FillOrKill {
+                    price_lots: self.price_lots,
+                },
+                _ => OrderParams::Fixed {
+                    price_lots: self.price_lots,
+                    order_type: order_type.to_post_order_type()?,
+                },
+            },
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytemuck::Zeroable;
+
+    fn trigger(side: Side, condition: TriggerCondition) -> TriggerOrder {
+        TriggerOrder {
+            side: side.into(),
+            condition: condition.into(),
+            trigger_price_lots: 100,
+            ..TriggerOrder::zeroed()
+        }
+    }
+
+    #[test]
+    fn test_is_triggered() {
+        let stop = trigger(Side::Ask, TriggerCondition::OraclePriceBelow);
+        assert!(!stop.is_triggered(None, Some(50), Some(50)));
+        assert!(!stop.is_triggered(Some(101), None, None));
+        assert!(stop.is_triggered(Some(100), None, None));
+        assert!(stop.is_triggered(Some(99), None, None));
+
+        let take_profit = trigger(Side::Ask, TriggerCondition::OraclePriceAbove);
+        assert!(!take_profit.is_triggered(Some(99), None, None));
+        assert!(take_profit.is_triggered(Some(100), None, None));
+
+        // book conditions look at the side the order takes from
+        let bid = trigger(Side::Bid, TriggerCondition::BookPriceBelow);
+        assert!(!bid.is_triggered(Some(50), Some(90), Some(110)));
+        assert!(bid.is_triggered(Some(150), Some(120), Some(100)));
+        assert!(!bid.is_triggered(Some(50), Some(90), None));
+
+        let ask = trigger(Side::Ask, TriggerCondition::BookPriceAbove);
+        assert!(!ask.is_triggered(Some(150), Some(99), Some(110)));
+        assert!(ask.is_triggered(None, Some(100), None));
+    }
+}
@@ -88,3 +88,27 @@ pub fn system_program_transfer<
         Ok(())
     }
 }
+
+pub fn token_close_account_signed<
+    'info,
+    P: ToAccountInfo<'info>,
+    A: ToAccountInfo<'info>,
+    D: ToAccountInfo<'info>,
+    L: ToAccountInfo<'info>,
+>(
+    token_program: &P,
+    account: &A,
+    destination: &D,
+    authority: &L,
+    seeds: &[&[u8]],
+) -> Result<()> {
+    token::close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        token::CloseAccount {
+            account: account.to_account_info(),
+            destination: destination.to_account_info(),
+            authority: authority.to_account_info(),
+        },
+        &[seeds],
+    ))
+}
@@ -15,6 +15,7 @@ pub use utils::assert_equal_fixed_f64 as assert_equal;
 mod test;
 mod test_crank;
 mod test_create_market;
+mod test_differential;
 mod test_edit_order;
 mod test_fees;
 mod test_fill_or_kill_order;
@@ -25,4 +26,6 @@ mod test_order_types;
 mod test_permissioned;
 mod test_place_order_remaining;
 mod test_self_trade;
+mod test_signed_order;
 mod test_take_order;
+mod test_trigger_order;
@@ -152,6 +152,7 @@ async fn test_simple_settle() -> Result<(), TransportError> {
             user_base_account: owner_token_0,
             user_quote_account: owner_token_1,
             referrer_account: None,
+            remainings: vec![],
         },
     )
     .await
@@ -178,6 +179,7 @@ async fn test_simple_settle() -> Result<(), TransportError> {
             user_base_account: owner_token_0,
             user_quote_account: owner_token_1,
             referrer_account: None,
+            remainings: vec![],
         },
     )
     .await
@@ -297,6 +299,7 @@ async fn test_delegate_settle() -> Result<(), TransportError> {
             user_base_account: payer_token_0,
             user_quote_account: payer_token_1,
             referrer_account: None,
+            remainings: vec![],
         },
     )
     .await
@@ -314,6 +317,7 @@ async fn test_delegate_settle() -> Result<(), TransportError> {
             user_base_account: owner_token_0,
             user_quote_account: owner_token_1,
             referrer_account: None,
+            remainings: vec![],
         },
     )
     .await
@@ -341,6 +345,132 @@ async fn test_delegate_settle() -> Result<(), TransportError> {
             user_base_account: payer_token_0,
             user_quote_account: payer_token_1,
             referrer_account: None,
+            remainings: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    {
+        let open_orders_account_1 = solana.get_account::<OpenOrdersAccount>(account_1).await;
+        let open_orders_account_2 = solana.get_account::<OpenOrdersAccount>(account_2).await;
+
+        assert_eq!(open_orders_account_1.position.base_free_native, 0);
+        assert_eq!(open_orders_account_2.position.base_free_native, 0);
+        assert_eq!(open_orders_account_1.position.quote_free_native, 0);
+        assert_eq!(open_orders_account_2.position.quote_free_native, 0);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_settle_funds_multiple() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        collect_fee_admin,
+        owner,
+        payer,
+        owner_token_0,
+        owner_token_1,
+        market,
+        market_base_vault,
+        market_quote_vault,
+        price_lots,
+        tokens,
+        account_1,
+        account_2,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize::default()).await?;
+    let solana = &context.solana.clone();
+
+    // Set the initial oracle price
+    set_stub_oracle_price(solana, &tokens[1], collect_fee_admin, 1000.0).await;
+
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            open_orders_account: account_1,
+            open_orders_admin: None,
+            market,
+            signer: owner,
+            user_token_account: owner_token_1,
+            market_vault: market_quote_vault,
+            side: Side::Bid,
+            price_lots,
+            max_base_lots: 1,
+            max_quote_lots_including_fees: 10000,
+
+            client_order_id: 0,
+            expiry_timestamp: 0,
+            order_type: PlaceOrderType::Limit,
+            self_trade_behavior: SelfTradeBehavior::default(),
+            remainings: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            open_orders_account: account_2,
+            open_orders_admin: None,
+            market,
+            signer: owner,
+            user_token_account: owner_token_0,
+            market_vault: market_base_vault,
+            side: Side::Ask,
+            price_lots,
+            max_base_lots: 1,
+            max_quote_lots_including_fees: 10000,
+
+            client_order_id: 0,
+            expiry_timestamp: 0,
+            order_type: PlaceOrderType::Limit,
+            self_trade_behavior: SelfTradeBehavior::default(),
+            remainings: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    send_tx(
+        solana,
+        ConsumeEventsInstruction {
+            consume_events_admin: None,
+            market,
+            open_orders_accounts: vec![account_1, account_2],
+        },
+    )
+    .await
+    .unwrap();
+
+    let entry = |open_orders_account| SettleFundsMultipleEntry {
+        open_orders_account,
+        market,
+        market_base_vault,
+        market_quote_vault,
+        user_base_account: owner_token_0,
+        user_quote_account: owner_token_1,
+    };
+
+    // someone else can't settle the owner's accounts
+    assert!(send_tx(
+        solana,
+        SettleFundsMultipleInstruction {
+            owner: payer,
+            entries: vec![entry(account_1), entry(account_2)],
+        },
+    )
+    .await
+    .is_err());
+
+    send_tx(
+        solana,
+        SettleFundsMultipleInstruction {
+            owner,
+            entries: vec![entry(account_1), entry(account_2)],
         },
     )
     .await
@@ -359,6 +489,265 @@ async fn test_delegate_settle() -> Result<(), TransportError> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_settle_funds_multiple_markets() -> Result<(), TransportError> {
+    let TestMultiMarketInitialize {
+        context,
+        collect_fee_admin,
+        owner,
+        owner_token_0,
+        owner_token_1,
+        tokens,
+        markets,
+        ..
+    } = TestContext::new_with_markets(2, vec![]).await?;
+    let solana = &context.solana.clone();
+
+    set_stub_oracle_price(solana, &tokens[1], collect_fee_admin, 1000.0).await;
+
+    let mut entries = vec![];
+    for market in markets.iter() {
+        for (open_orders_account, side) in
+            [(market.account_1, Side::Bid), (market.account_2, Side::Ask)]
+        {
+            let (user_token_account, market_vault) = match side {
+                Side::Bid => (owner_token_1, market.market_quote_vault),
+                Side::Ask => (owner_token_0, market.market_base_vault),
+            };
+            send_tx(
+                solana,
+                PlaceOrderInstruction {
+                    open_orders_account,
+                    open_orders_admin: None,
+                    market: market.market,
+                    signer: owner,
+                    user_token_account,
+                    market_vault,
+                    side,
+                    price_lots: market.price_lots,
+                    max_base_lots: 1,
+                    max_quote_lots_including_fees: 10000,
+
+                    client_order_id: 0,
+                    expiry_timestamp: 0,
+                    order_type: PlaceOrderType::Limit,
+                    self_trade_behavior: SelfTradeBehavior::default(),
+                    remainings: vec![],
+                },
+            )
+            .await
+            .unwrap();
+
+            entries.push(SettleFundsMultipleEntry {
+                open_orders_account,
+                market: market.market,
+                market_base_vault: market.market_base_vault,
+                market_quote_vault: market.market_quote_vault,
+                user_base_account: owner_token_0,
+                user_quote_account: owner_token_1,
+            });
+        }
+
+        send_tx(
+            solana,
+            ConsumeEventsInstruction {
+                consume_events_admin: None,
+                market: market.market,
+                open_orders_accounts: vec![market.account_1, market.account_2],
+            },
+        )
+        .await
+        .unwrap();
+    }
+
+    send_tx(solana, SettleFundsMultipleInstruction { owner, entries })
+        .await
+        .unwrap();
+
+    for market in markets.iter() {
+        for account in [market.account_1, market.account_2] {
+            let open_orders_account = solana.get_account::<OpenOrdersAccount>(account).await;
+            assert_eq!(open_orders_account.market, market.market);
+            assert_eq!(open_orders_account.position.base_free_native, 0);
+            assert_eq!(open_orders_account.position.quote_free_native, 0);
+        }
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_settle_receiver() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        collect_fee_admin,
+        owner,
+        mints,
+        owner_token_0,
+        owner_token_1,
+        market,
+        market_base_vault,
+        market_quote_vault,
+        price_lots,
+        tokens,
+        account_1,
+        account_2,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize::default()).await?;
+    let solana = &context.solana.clone();
+    let receiver_program = settle_receiver_program_id();
+    let position_extension = get_position_extension_address(account_2);
+
+    set_stub_oracle_price(solana, &tokens[1], collect_fee_admin, 1000.0).await;
+
+    send_tx(
+        solana,
+        CreatePositionExtensionInstruction {
+            owner,
+            payer: owner,
+            open_orders_account: account_2,
+        },
+    )
+    .await
+    .unwrap();
+
+    // only the owner can register a receiver
+    assert!(send_tx(
+        solana,
+        SetSettleReceiverInstruction {
+            owner: collect_fee_admin,
+            open_orders_account: account_2,
+            program: Some(receiver_program),
+        },
+    )
+    .await
+    .is_err());
+
+    send_tx(
+        solana,
+        SetSettleReceiverInstruction {
+            owner,
+            open_orders_account: account_2,
+            program: Some(receiver_program),
+        },
+    )
+    .await
+    .unwrap();
+
+    for (account, side, user_token_account, market_vault) in [
+        (account_1, Side::Bid, owner_token_1, market_quote_vault),
+        (account_2, Side::Ask, owner_token_0, market_base_vault),
+    ] {
+        send_tx(
+            solana,
+            PlaceOrderInstruction {
+                open_orders_account: account,
+                open_orders_admin: None,
+                market,
+                signer: owner,
+                user_token_account,
+                market_vault,
+                side,
+                price_lots,
+                max_base_lots: 1,
+                max_quote_lots_including_fees: 10000,
+                client_order_id: 0,
+                expiry_timestamp: 0,
+                order_type: PlaceOrderType::Limit,
+                self_trade_behavior: SelfTradeBehavior::default(),
+                remainings: vec![position_extension],
+            },
+        )
+        .await
+        .unwrap();
+    }
+
+    // the receiver takes the funds in from token accounts of its own
+    let (depositor, _) = settle_receiver_depositor();
+    let receiver_base = solana
+        .create_token_account(&depositor, mints[0].pubkey)
+        .await;
+    let receiver_quote = solana
+        .create_token_account(&depositor, mints[1].pubkey)
+        .await;
+    let deposit_base = solana
+        .create_token_account(&depositor, mints[0].pubkey)
+        .await;
+    let deposit_quote = solana
+        .create_token_account(&depositor, mints[1].pubkey)
+        .await;
+
+    let receiver_authority = get_settle_receiver_authority_address(market);
+    let settle = |remainings: Vec<Pubkey>| SettleFundsInstruction {
+        owner,
+        market,
+        open_orders_account: account_2,
+        market_base_vault,
+        market_quote_vault,
+        user_base_account: receiver_base,
+        user_quote_account: receiver_quote,
+        referrer_account: None,
+        remainings,
+    };
+    let receiver_accounts = |authority| {
+        vec![
+            position_extension,
+            receiver_program,
+            authority,
+            deposit_base,
+            deposit_quote,
+            depositor,
+            spl_token::id(),
+        ]
+    };
+
+    // the registered receiver can't be skipped
+    assert_openbook_error(
+        &send_tx(solana, settle(vec![position_extension])).await,
+        OpenBookError::MissingSettleReceiverAccount.error_code(),
+        "Settle receiver required".into(),
+    );
+
+    // it must come with its own authority, never the market's
+    let market_authority = solana.get_account::<Market>(market).await.market_authority;
+    assert_openbook_error(
+        &send_tx(solana, settle(receiver_accounts(market_authority))).await,
+        OpenBookError::InvalidSettleReceiverAuthority.error_code(),
+        "Market authority can't stand in".into(),
+    );
+
+    // and can't get the market's vaults
+    assert_openbook_error(
+        &send_tx(
+            solana,
+            settle(vec![
+                position_extension,
+                receiver_program,
+                receiver_authority,
+                market_quote_vault,
+            ]),
+        )
+        .await,
+        OpenBookError::InvalidSettleReceiverAccount.error_code(),
+        "Vaults can't be passed on".into(),
+    );
+
+    send_tx(solana, settle(receiver_accounts(receiver_authority)))
+        .await
+        .unwrap();
+
+    // the receiver moved what was settled to it into its deposit
+    assert_eq!(solana.token_account_balance(receiver_quote).await, 0);
+    assert_eq!(solana.token_account_balance(deposit_quote).await, 99960);
+    let expected_log = format!("on_settle {} 0 99960", account_2);
+    assert!(solana
+        .program_log()
+        .iter()
+        .any(|line| line.contains(&expected_log)));
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_cancel_orders() -> Result<(), TransportError> {
     let TestInitialize {
@@ -544,6 +933,7 @@ async fn test_cancel_orders() -> Result<(), TransportError> {
             user_base_account: owner_token_0,
             user_quote_account: owner_token_1,
             referrer_account: None,
+            remainings: vec![],
         },
     )
     .await
@@ -781,3 +1171,1276 @@ async fn test_expired_orders() -> Result<(), TransportError> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_orders_match_until_expiry() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        owner,
+        owner_token_0,
+        owner_token_1,
+        market,
+        market_base_vault,
+        market_quote_vault,
+        price_lots,
+        account_1,
+        account_2,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize::default()).await?;
+    let solana = &context.solana.clone();
+
+    let now_ts = solana.get_clock().await.unix_timestamp;
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            open_orders_account: account_1,
+            open_orders_admin: None,
+            market,
+            signer: owner,
+            user_token_account: owner_token_1,
+            market_vault: market_quote_vault,
+            side: Side::Bid,
+            price_lots,
+            max_base_lots: 2,
+            max_quote_lots_including_fees: 20000,
+
+            client_order_id: 0,
+            expiry_timestamp: now_ts as u64 + 2,
+            order_type: PlaceOrderType::Limit,
+            self_trade_behavior: SelfTradeBehavior::default(),
+            remainings: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let take_one_lot = PlaceOrderInstruction {
+        open_orders_account: account_2,
+        open_orders_admin: None,
+        market,
+        signer: owner,
+        user_token_account: owner_token_0,
+        market_vault: market_base_vault,
+        side: Side::Ask,
+        price_lots,
+        max_base_lots: 1,
+        max_quote_lots_including_fees: 10000,
+
+        client_order_id: 0,
+        expiry_timestamp: 0,
+        order_type: PlaceOrderType::ImmediateOrCancel,
+        self_trade_behavior: SelfTradeBehavior::default(),
+        remainings: vec![],
+    };
+
+    // the last second the bid is valid
+    solana.advance_clock_to(now_ts + 1).await;
+    send_tx(solana, take_one_lot.clone()).await.unwrap();
+    let quote_free_native = {
+        let open_orders_account_2 = solana.get_account::<OpenOrdersAccount>(account_2).await;
+        assert!(open_orders_account_2.position.quote_free_native > 0);
+        open_orders_account_2.position.quote_free_native
+    };
+
+    // expired right at its expiry timestamp
+    solana.advance_clock_to(now_ts + 2).await;
+    send_tx(solana, take_one_lot).await.unwrap();
+    {
+        let open_orders_account_2 = solana.get_account::<OpenOrdersAccount>(account_2).await;
+        assert_eq!(
+            open_orders_account_2.position.quote_free_native,
+            quote_free_native
+        );
+
+        let market_acc = solana.get_account_boxed::<Market>(market).await;
+        let bids = solana.get_account_boxed::<BookSide>(market_acc.bids).await;
+        assert_eq!(bids.roots[0].leaf_count, 0);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_find_order() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        owner,
+        owner_token_0,
+        owner_token_1,
+        market,
+        market_base_vault,
+        market_quote_vault,
+        price_lots,
+        account_1,
+        account_2,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize::default()).await?;
+    let solana = &context.solana.clone();
+
+    let find_order = |client_order_id: u64| {
+        simulate_tx_and_get_return::<_, Option<openbook_v2::OrderInfo>>(
+            solana,
+            FindOrderInstruction {
+                open_orders_account: account_1,
+                market,
+                client_order_id,
+            },
+        )
+    };
+
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            open_orders_account: account_1,
+            open_orders_admin: None,
+            market,
+            signer: owner,
+            user_token_account: owner_token_1,
+            market_vault: market_quote_vault,
+            side: Side::Bid,
+            price_lots,
+            max_base_lots: 2,
+            max_quote_lots_including_fees: 20000,
+
+            client_order_id: 7,
+            expiry_timestamp: 0,
+            order_type: PlaceOrderType::Limit,
+            self_trade_behavior: SelfTradeBehavior::default(),
+            remainings: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let order = find_order(7).await.unwrap().unwrap();
+    {
+        let open_orders_account_1 = solana.get_account::<OpenOrdersAccount>(account_1).await;
+        assert_eq!(order.order_id, open_orders_account_1.open_orders[0].id);
+    }
+    assert_eq!(order.client_order_id, 7);
+    assert_eq!(order.side, Side::Bid);
+    assert!(!order.is_oracle_pegged);
+    assert_eq!(order.locked_price, price_lots);
+    assert_eq!(order.quantity, 2);
+    assert_eq!(find_order(8).await.unwrap(), None);
+
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            open_orders_account: account_2,
+            open_orders_admin: None,
+            market,
+            signer: owner,
+            user_token_account: owner_token_0,
+            market_vault: market_base_vault,
+            side: Side::Ask,
+            price_lots,
+            max_base_lots: 2,
+            max_quote_lots_including_fees: 20000,
+
+            client_order_id: 0,
+            expiry_timestamp: 0,
+            order_type: PlaceOrderType::ImmediateOrCancel,
+            self_trade_behavior: SelfTradeBehavior::default(),
+            remainings: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    // filled, but the slot is only freed with the fill event
+    let filled = find_order(7).await.unwrap().unwrap();
+    assert_eq!(filled.order_id, order.order_id);
+    assert_eq!(filled.quantity, 0);
+
+    send_tx(
+        solana,
+        ConsumeEventsInstruction {
+            consume_events_admin: None,
+            market,
+            open_orders_accounts: vec![account_1],
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(find_order(7).await.unwrap(), None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_market_best_prices() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        owner,
+        owner_token_0,
+        owner_token_1,
+        market,
+        market_base_vault,
+        market_quote_vault,
+        price_lots,
+        account_1,
+        account_2,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize::default()).await?;
+    let solana = &context.solana.clone();
+
+    // (price, size) of the best bid and ask, cached in the book side headers
+    let top_of_book = move || async move {
+        let market = solana.get_account_boxed::<Market>(market).await;
+        let bids = solana.get_account_boxed::<BookSide>(market.bids).await;
+        let asks = solana.get_account_boxed::<BookSide>(market.asks).await;
+        (
+            (bids.best_price_lots, bids.best_base_lots),
+            (asks.best_price_lots, asks.best_base_lots),
+        )
+    };
+    assert_eq!(top_of_book().await, ((0, 0), (0, 0)));
+
+    let place_bid = PlaceOrderInstruction {
+        open_orders_account: account_1,
+        open_orders_admin: None,
+        market,
+        signer: owner,
+        user_token_account: owner_token_1,
+        market_vault: market_quote_vault,
+        side: Side::Bid,
+        price_lots: price_lots - 2,
+        max_base_lots: 1,
+        max_quote_lots_including_fees: 10000,
+
+        client_order_id: 2,
+        expiry_timestamp: 0,
+        order_type: PlaceOrderType::Limit,
+        self_trade_behavior: SelfTradeBehavior::default(),
+        remainings: vec![],
+    };
+    send_tx(solana, place_bid.clone()).await.unwrap();
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            client_order_id: 4,
+            ..place_bid.clone()
+        },
+    )
+    .await
+    .unwrap();
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            price_lots: price_lots - 1,
+            client_order_id: 1,
+            ..place_bid.clone()
+        },
+    )
+    .await
+    .unwrap();
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            open_orders_account: account_2,
+            open_orders_admin: None,
+            market,
+            signer: owner,
+            user_token_account: owner_token_0,
+            market_vault: market_base_vault,
+            side: Side::Ask,
+            price_lots: price_lots + 1,
+            max_base_lots: 1,
+            max_quote_lots_including_fees: 10000,
+
+            client_order_id: 0,
+            expiry_timestamp: 0,
+            order_type: PlaceOrderType::Limit,
+            self_trade_behavior: SelfTradeBehavior::default(),
+            remainings: vec![],
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        top_of_book().await,
+        ((price_lots - 1, 1), (price_lots + 1, 1))
+    );
+
+    send_tx(
+        solana,
+        CancelOrderByClientOrderIdInstruction {
+            open_orders_account: account_1,
+            market,
+            signer: owner,
+            client_order_id: 1,
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        top_of_book().await,
+        ((price_lots - 2, 2), (price_lots + 1, 1))
+    );
+
+    // taking the ask empties its side
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            price_lots: price_lots + 1,
+            client_order_id: 3,
+            order_type: PlaceOrderType::ImmediateOrCancel,
+            ..place_bid
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(top_of_book().await, ((price_lots - 2, 2), (0, 0)));
+
+    send_tx(
+        solana,
+        CancelAllOrdersInstruction {
+            open_orders_account: account_1,
+            market,
+            signer: owner,
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(top_of_book().await, ((0, 0), (0, 0)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_market_stats() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        owner,
+        payer,
+        owner_token_0,
+        owner_token_1,
+        market,
+        market_base_vault,
+        market_quote_vault,
+        price_lots,
+        account_1,
+        account_2,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize::default()).await?;
+    let solana = &context.solana.clone();
+    let market_stats = get_market_stats_address(market);
+
+    send_tx(solana, CreateMarketStatsInstruction { payer, market })
+        .await
+        .unwrap();
+
+    let stats_24h = move || async move {
+        let now_ts = solana.get_clock().await.unix_timestamp as u64;
+        let stats = solana.get_account::<MarketStats>(market_stats).await;
+        assert_eq!(stats.market, market);
+        stats.last_24h(now_ts)
+    };
+    assert_eq!(stats_24h().await, Stats24h::default());
+
+    let place_ask = PlaceOrderInstruction {
+        open_orders_account: account_2,
+        open_orders_admin: None,
+        market,
+        signer: owner,
+        user_token_account: owner_token_0,
+        market_vault: market_base_vault,
+        side: Side::Ask,
+        price_lots: price_lots + 1,
+        max_base_lots: 2,
+        max_quote_lots_including_fees: 100000,
+
+        client_order_id: 0,
+        expiry_timestamp: 0,
+        order_type: PlaceOrderType::Limit,
+        self_trade_behavior: SelfTradeBehavior::default(),
+        remainings: vec![],
+    };
+    send_tx(solana, place_ask.clone()).await.unwrap();
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            price_lots: price_lots + 3,
+            max_base_lots: 1,
+            ..place_ask
+        },
+    )
+    .await
+    .unwrap();
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            open_orders_account: account_1,
+            open_orders_admin: None,
+            market,
+            signer: owner,
+            user_token_account: owner_token_1,
+            market_vault: market_quote_vault,
+            side: Side::Bid,
+            price_lots: price_lots + 3,
+            max_base_lots: 3,
+            max_quote_lots_including_fees: 100000,
+
+            client_order_id: 0,
+            expiry_timestamp: 0,
+            order_type: PlaceOrderType::ImmediateOrCancel,
+            self_trade_behavior: SelfTradeBehavior::default(),
+            remainings: vec![market_stats],
+        },
+    )
+    .await
+    .unwrap();
+
+    // one fill per maker order, quote_lot_size is 10
+    assert_eq!(
+        stats_24h().await,
+        Stats24h {
+            quote_volume: (2 * (price_lots + 1) + price_lots + 3) as u64 * 10,
+            trade_count: 2,
+            high_lots: price_lots + 3,
+            low_lots: price_lots + 1,
+        }
+    );
+
+    let now_ts = solana.get_clock().await.unix_timestamp;
+    solana.advance_clock_to(now_ts + 24 * 3600).await;
+    assert_eq!(stats_24h().await, Stats24h::default());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_market_quote() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        collect_fee_admin,
+        owner,
+        owner_token_0,
+        owner_token_1,
+        market,
+        market_base_vault,
+        market_quote_vault,
+        price_lots,
+        tokens,
+        account_1,
+        account_2,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize::default()).await?;
+    let solana = &context.solana.clone();
+
+    set_stub_oracle_price(solana, &tokens[0], collect_fee_admin, 1000.0).await;
+    let get_market_quote = move || {
+        simulate_tx_and_get_return::<_, openbook_v2::MarketQuote>(
+            solana,
+            GetMarketQuoteInstruction { market },
+        )
+    };
+
+    let quote = get_market_quote().await.unwrap();
+    assert_eq!(quote.best_bid_lots, None);
+    assert_eq!(quote.best_ask_lots, None);
+    assert_eq!(quote.mid_price, None);
+    assert_eq!(quote.oracle_price, Some(I80F48::from(1000)));
+
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            open_orders_account: account_1,
+            open_orders_admin: None,
+            market,
+            signer: owner,
+            user_token_account: owner_token_1,
+            market_vault: market_quote_vault,
+            side: Side::Bid,
+            price_lots: price_lots - 10,
+            max_base_lots: 1,
+            max_quote_lots_including_fees: 100000,
+
+            client_order_id: 0,
+            expiry_timestamp: 0,
+            order_type: PlaceOrderType::Limit,
+            self_trade_behavior: SelfTradeBehavior::default(),
+            remainings: vec![],
+        },
+    )
+    .await
+    .unwrap();
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            open_orders_account: account_2,
+            open_orders_admin: None,
+            market,
+            signer: owner,
+            user_token_account: owner_token_0,
+            market_vault: market_base_vault,
+            side: Side::Ask,
+            price_lots: price_lots + 30,
+            max_base_lots: 2,
+            max_quote_lots_including_fees: 100000,
+
+            client_order_id: 0,
+            expiry_timestamp: 0,
+            order_type: PlaceOrderType::Limit,
+            self_trade_behavior: SelfTradeBehavior::default(),
+            remainings: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    // with quote_lot_size 10 and base_lot_size 100, price lots are 10x native prices
+    let quote = get_market_quote().await.unwrap();
+    assert_eq!(quote.best_bid_lots, Some(price_lots - 10));
+    assert_eq!(quote.best_bid_quantity, 1);
+    assert_eq!(quote.best_ask_lots, Some(price_lots + 30));
+    assert_eq!(quote.best_ask_quantity, 2);
+    assert_eq!(quote.mid_price, Some(I80F48::from(1001)));
+    assert_eq!(quote.spread_bps, Some(39));
+    assert_eq!(quote.oracle_deviation_bps, Some(10));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_stub_oracle_set_test() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        collect_fee_admin,
+        market,
+        tokens,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize::default()).await?;
+    let solana = &context.solana.clone();
+
+    let get_oracle_price = move || async move {
+        simulate_tx_and_get_return::<_, openbook_v2::MarketQuote>(
+            solana,
+            GetMarketQuoteInstruction { market },
+        )
+        .await
+        .unwrap()
+        .oracle_price
+    };
+    let set_stub_oracle = StubOracleSetTestInstruction {
+        mint: tokens[0].mint.pubkey,
+        owner: collect_fee_admin,
+        price: 10.0,
+        exponent: 2,
+        deviation: 0.0,
+        last_update_ts: 0,
+        last_update_slot: 0,
+    };
+
+    send_tx(solana, set_stub_oracle.clone()).await.unwrap();
+    assert_eq!(get_oracle_price().await, Some(I80F48::from(1000)));
+
+    // the market's conf_filter is 0.1
+    send_tx(
+        solana,
+        StubOracleSetTestInstruction {
+            deviation: 1.01,
+            ..set_stub_oracle.clone()
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(get_oracle_price().await, None);
+
+    // and its max_staleness_slots 100
+    let clock = solana.get_clock().await;
+    send_tx(
+        solana,
+        StubOracleSetTestInstruction {
+            last_update_ts: clock.unix_timestamp,
+            last_update_slot: clock.slot,
+            ..set_stub_oracle.clone()
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(get_oracle_price().await, Some(I80F48::from(1000)));
+    solana.advance_by_slots(101).await;
+    assert_eq!(get_oracle_price().await, None);
+
+    assert_openbook_error(
+        &send_tx(
+            solana,
+            StubOracleSetTestInstruction {
+                exponent: 13,
+                ..set_stub_oracle
+            },
+        )
+        .await,
+        OpenBookError::InvalidInputOracleExponent.error_code(),
+        "exponent out of range".into(),
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_snapshot_depth() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        owner,
+        payer,
+        owner_token_0,
+        owner_token_1,
+        market,
+        market_base_vault,
+        market_quote_vault,
+        price_lots,
+        account_1,
+        account_2,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize::default()).await?;
+    let solana = &context.solana.clone();
+
+    let place_bid = PlaceOrderInstruction {
+        open_orders_account: account_1,
+        open_orders_admin: None,
+        market,
+        signer: owner,
+        user_token_account: owner_token_1,
+        market_vault: market_quote_vault,
+        side: Side::Bid,
+        price_lots: price_lots - 10,
+        max_base_lots: 1,
+        max_quote_lots_including_fees: 100000,
+
+        client_order_id: 0,
+        expiry_timestamp: 0,
+        order_type: PlaceOrderType::Limit,
+        self_trade_behavior: SelfTradeBehavior::default(),
+        remainings: vec![],
+    };
+    send_tx(solana, place_bid.clone()).await.unwrap();
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            max_base_lots: 2,
+            ..place_bid.clone()
+        },
+    )
+    .await
+    .unwrap();
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            price_lots: price_lots - 20,
+            ..place_bid.clone()
+        },
+    )
+    .await
+    .unwrap();
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            price_lots: price_lots - 30,
+            ..place_bid
+        },
+    )
+    .await
+    .unwrap();
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            open_orders_account: account_2,
+            open_orders_admin: None,
+            market,
+            signer: owner,
+            user_token_account: owner_token_0,
+            market_vault: market_base_vault,
+            side: Side::Ask,
+            price_lots: price_lots + 10,
+            max_base_lots: 4,
+            max_quote_lots_including_fees: 100000,
+
+            client_order_id: 0,
+            expiry_timestamp: 0,
+            order_type: PlaceOrderType::Limit,
+            self_trade_behavior: SelfTradeBehavior::default(),
+            remainings: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    send_tx(
+        solana,
+        CreateDepthSnapshotInstruction {
+            payer,
+            owner,
+            market,
+        },
+    )
+    .await
+    .unwrap();
+    let depth_snapshot = get_depth_snapshot_address(market, owner.pubkey());
+
+    assert!(send_tx(
+        solana,
+        SnapshotDepthInstruction {
+            depth_snapshot,
+            market,
+            levels: DEPTH_SNAPSHOT_LEVELS as u8 + 1,
+        },
+    )
+    .await
+    .is_err());
+
+    send_tx(
+        solana,
+        SnapshotDepthInstruction {
+            depth_snapshot,
+            market,
+            levels: 2,
+        },
+    )
+    .await
+    .unwrap();
+
+    let snapshot = solana.get_account::<DepthSnapshot>(depth_snapshot).await;
+    assert_eq!(snapshot.owner, owner.pubkey());
+    assert!(snapshot.slot > 0);
+    assert_eq!(
+        snapshot.levels(Side::Bid),
+        &[
+            DepthLevel {
+                price_lots: price_lots - 10,
+                quantity: 3,
+                cumulative_quantity: 3,
+            },
+            DepthLevel {
+                price_lots: price_lots - 20,
+                quantity: 1,
+                cumulative_quantity: 4,
+            },
+        ]
+    );
+    assert_eq!(
+        snapshot.levels(Side::Ask),
+        &[DepthLevel {
+            price_lots: price_lots + 10,
+            quantity: 4,
+            cumulative_quantity: 4,
+        }]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_migrate_account() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        collect_fee_admin,
+        owner,
+        owner_token_1,
+        market,
+        market_quote_vault,
+        price_lots,
+        tokens,
+        account_1,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize::default()).await?;
+    let solana = &context.solana.clone();
+
+    set_stub_oracle_price(solana, &tokens[1], collect_fee_admin, 1000.0).await;
+
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            open_orders_account: account_1,
+            open_orders_admin: None,
+            market,
+            signer: owner,
+            user_token_account: owner_token_1,
+            market_vault: market_quote_vault,
+            side: Side::Bid,
+            price_lots,
+            max_base_lots: 1,
+            max_quote_lots_including_fees: 10000,
+            client_order_id: 7,
+            expiry_timestamp: 0,
+            order_type: PlaceOrderType::Limit,
+            self_trade_behavior: SelfTradeBehavior::default(),
+            remainings: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    // Recreate a version 0 account, which didn't track bids_quote_lots
+    let bids_quote_lots_offset = 8 + 136 + 88;
+    let set_version_0 = |mut data: Vec<u8>| {
+        data[8 + 133] = 0;
+        data[bids_quote_lots_offset..bids_quote_lots_offset + 8]
+            .copy_from_slice(&12345i64.to_le_bytes());
+        data
+    };
+    let data = solana.get_account_data(account_1).await.unwrap();
+    solana
+        .set_account_data(account_1, set_version_0(data))
+        .await;
+
+    // and a market from before markets had a version
+    let mut market_data = solana.get_account_data(market).await.unwrap();
+    market_data[8 + 817] = 0;
+    solana.set_account_data(market, market_data).await;
+
+    assert_eq!(
+        send_tx_and_get_ix_custom_error(solana, MigrateAccountInstruction { account: account_1 })
+            .await,
+        Some(openbook_v2::error::OpenBookError::MigrateAccountWithBids.into())
+    );
+
+    send_tx(
+        solana,
+        CancelOrderByClientOrderIdInstruction {
+            open_orders_account: account_1,
+            market,
+            signer: owner,
+            client_order_id: 7,
+        },
+    )
+    .await
+    .unwrap();
+    let data = solana.get_account_data(account_1).await.unwrap();
+    solana
+        .set_account_data(account_1, set_version_0(data))
+        .await;
+
+    for account in [account_1, market] {
+        send_tx(solana, MigrateAccountInstruction { account })
+            .await
+            .unwrap();
+    }
+
+    let open_orders_account = solana.get_account::<OpenOrdersAccount>(account_1).await;
+    assert_eq!(open_orders_account.version, OPEN_ORDERS_ACCOUNT_VERSION);
+    assert_eq!(open_orders_account.position.bids_quote_lots, 0);
+
+    let market = solana.get_account::<Market>(market).await;
+    assert_eq!(market.version, MARKET_VERSION);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_scheduled_cancel() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        collect_fee_admin,
+        owner,
+        payer,
+        owner_token_1,
+        market,
+        market_quote_vault,
+        price_lots,
+        tokens,
+        account_1,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize::default()).await?;
+    let solana = &context.solana.clone();
+    let position_extension = get_position_extension_address(account_1);
+
+    set_stub_oracle_price(solana, &tokens[1], collect_fee_admin, 1000.0).await;
+
+    // the schedule is kept in the position extension
+    send_tx(
+        solana,
+        CreatePositionExtensionInstruction {
+            owner,
+            payer: owner,
+            open_orders_account: account_1,
+        },
+    )
+    .await
+    .unwrap();
+
+    for price_offset in [10, 20] {
+        send_tx(
+            solana,
+            PlaceOrderInstruction {
+                open_orders_account: account_1,
+                open_orders_admin: None,
+                market,
+                signer: owner,
+                user_token_account: owner_token_1,
+                market_vault: market_quote_vault,
+                side: Side::Bid,
+                price_lots: price_lots - price_offset,
+                max_base_lots: 1,
+                max_quote_lots_including_fees: 10000,
+                client_order_id: 0,
+                expiry_timestamp: 0,
+                order_type: PlaceOrderType::Limit,
+                self_trade_behavior: SelfTradeBehavior::default(),
+                remainings: vec![position_extension],
+            },
+        )
+        .await
+        .unwrap();
+    }
+
+    let execute = |limit| ExecuteScheduledCancelInstruction {
+        keeper: payer,
+        open_orders_account: account_1,
+        market,
+        limit,
+    };
+
+    // nothing scheduled
+    assert_openbook_error(
+        &send_tx(solana, execute(5)).await,
+        OpenBookError::ScheduledCancelNotDue.error_code(),
+        "No cancel scheduled".into(),
+    );
+
+    let now_ts = solana.get_clock().await.unix_timestamp as u64;
+    send_tx(
+        solana,
+        ScheduleCancelAllInstruction {
+            open_orders_account: account_1,
+            signer: owner,
+            cancel_after_ts: now_ts + 60,
+        },
+    )
+    .await
+    .unwrap();
+
+    assert_openbook_error(
+        &send_tx(solana, execute(5)).await,
+        OpenBookError::ScheduledCancelNotDue.error_code(),
+        "Scheduled cancel not due yet".into(),
+    );
+
+    solana.advance_clock(61).await;
+
+    // the schedule stays until all orders are cancelled
+    send_tx(solana, execute(1)).await.unwrap();
+    let open_orders_account = solana.get_account::<OpenOrdersAccount>(account_1).await;
+    assert_eq!(open_orders_account.all_orders_in_use().count(), 1);
+    assert_eq!(
+        solana
+            .get_account::<PositionExtension>(position_extension)
+            .await
+            .cancel_all_after_ts,
+        now_ts + 60
+    );
+
+    send_tx(solana, execute(1)).await.unwrap();
+    let open_orders_account = solana.get_account::<OpenOrdersAccount>(account_1).await;
+    assert_eq!(open_orders_account.all_orders_in_use().count(), 0);
+    assert_eq!(open_orders_account.position.bids_base_lots, 0);
+    assert_eq!(
+        solana
+            .get_account::<PositionExtension>(position_extension)
+            .await
+            .cancel_all_after_ts,
+        0
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_heartbeat() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        collect_fee_admin,
+        owner,
+        payer,
+        owner_token_1,
+        market,
+        market_quote_vault,
+        price_lots,
+        tokens,
+        account_1,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize::default()).await?;
+    let solana = &context.solana.clone();
+    let position_extension = get_position_extension_address(account_1);
+    let bounty = 1_000_000;
+
+    set_stub_oracle_price(solana, &tokens[1], collect_fee_admin, 1000.0).await;
+
+    send_tx(
+        solana,
+        CreatePositionExtensionInstruction {
+            owner,
+            payer: owner,
+            open_orders_account: account_1,
+        },
+    )
+    .await
+    .unwrap();
+
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            open_orders_account: account_1,
+            open_orders_admin: None,
+            market,
+            signer: owner,
+            user_token_account: owner_token_1,
+            market_vault: market_quote_vault,
+            side: Side::Bid,
+            price_lots,
+            max_base_lots: 1,
+            max_quote_lots_including_fees: 10000,
+            client_order_id: 0,
+            expiry_timestamp: 0,
+            order_type: PlaceOrderType::Limit,
+            self_trade_behavior: SelfTradeBehavior::default(),
+            remainings: vec![position_extension],
+        },
+    )
+    .await
+    .unwrap();
+
+    let heartbeat = HeartbeatInstruction {
+        open_orders_account: account_1,
+        signer: owner,
+    };
+    let execute = ExecuteScheduledCancelInstruction {
+        keeper: payer,
+        open_orders_account: account_1,
+        market,
+        limit: 5,
+    };
+
+    assert_openbook_error(
+        &send_tx(solana, heartbeat.clone()).await,
+        OpenBookError::HeartbeatNotEnabled.error_code(),
+        "Heartbeat not enabled yet".into(),
+    );
+
+    let extension_lamports = solana.get_account_lamports(position_extension).await;
+    send_tx(
+        solana,
+        SetHeartbeatIntervalInstruction {
+            open_orders_account: account_1,
+            signer: owner,
+            interval: 60,
+            bounty,
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        solana.get_account_lamports(position_extension).await,
+        extension_lamports + bounty
+    );
+
+    solana.advance_clock(30).await;
+    send_tx(solana, heartbeat.clone()).await.unwrap();
+    solana.advance_clock(40).await;
+
+    // still alive thanks to the heartbeat
+    assert_openbook_error(
+        &send_tx(solana, execute.clone()).await,
+        OpenBookError::ScheduledCancelNotDue.error_code(),
+        "Heartbeat extended the deadline".into(),
+    );
+
+    solana.advance_clock(30).await;
+
+    let keeper_lamports = solana.get_account_lamports(payer.pubkey()).await;
+    send_tx(solana, execute).await.unwrap();
+    assert_eq!(
+        solana.get_account_lamports(payer.pubkey()).await,
+        keeper_lamports + bounty
+    );
+    assert_eq!(
+        solana.get_account_lamports(position_extension).await,
+        extension_lamports
+    );
+
+    let open_orders_account = solana.get_account::<OpenOrdersAccount>(account_1).await;
+    assert_eq!(open_orders_account.all_orders_in_use().count(), 0);
+    assert_eq!(
+        solana
+            .get_account::<PositionExtension>(position_extension)
+            .await
+            .heartbeat_bounty,
+        0
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_place_order_relayed() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        collect_fee_admin,
+        owner,
+        payer,
+        owner_token_0,
+        owner_token_1,
+        market,
+        market_base_vault,
+        market_quote_vault,
+        price_lots,
+        tokens,
+        account_1,
+        account_2,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize::default()).await?;
+    let solana = &context.solana.clone();
+    let relayer = payer;
+    let relayer_token_1 = context.users[1].token_accounts[1];
+    let session_key = TestKeypair::new();
+    let relayer_fee = 500;
+
+    set_stub_oracle_price(solana, &tokens[1], collect_fee_admin, 1000.0).await;
+
+    let place_order = |signer, open_orders_account, side, user_token_account, market_vault| {
+        PlaceOrderInstruction {
+            open_orders_account,
+            open_orders_admin: None,
+            market,
+            signer,
+            user_token_account,
+            market_vault,
+            side,
+            price_lots,
+            max_base_lots: 1,
+            max_quote_lots_including_fees: 10000,
+            client_order_id: 0,
+            expiry_timestamp: 0,
+            order_type: PlaceOrderType::Limit,
+            self_trade_behavior: SelfTradeBehavior::default(),
+            remainings: vec![],
+        }
+    };
+
+    let owner_lamports = solana.get_account_lamports(owner.pubkey()).await;
+
+    // paying the fee doesn't allow the relayer to trade for the owner
+    assert_openbook_error(
+        &send_tx_relayed(
+            solana,
+            place_order(
+                relayer,
+                account_1,
+                Side::Bid,
+                owner_token_1,
+                market_quote_vault,
+            ),
+            relayer,
+        )
+        .await,
+        OpenBookError::NoOwnerOrDelegate.error_code(),
+        "Relayer is neither owner nor delegate".into(),
+    );
+
+    // a session key without any lamports places orders once it is the delegate
+    // of the open orders account and of the token account
+    send_tx_relayed(
+        solana,
+        SetDelegateInstruction {
+            delegate_account: Some(session_key.pubkey()),
+            owner,
+            open_orders_account: account_1,
+        },
+        relayer,
+    )
+    .await
+    .unwrap();
+    solana
+        .process_transaction_with_fee_payer(
+            &[spl_token::instruction::approve(
+                &spl_token::id(),
+                &owner_token_1,
+                &session_key.pubkey(),
+                &owner.pubkey(),
+                &[],
+                1_000_000,
+            )
+            .unwrap()],
+            Some(&[owner]),
+            Some(relayer),
+        )
+        .await
+        .unwrap();
+
+    let relayer_quote_before = solana.token_account_balance(relayer_token_1).await;
+    send_tx_relayed(
+        solana,
+        PlaceOrderRelayedInstruction {
+            place_order: place_order(
+                session_key,
+                account_1,
+                Side::Bid,
+                owner_token_1,
+                market_quote_vault,
+            ),
+            relayer,
+            relayer_token_account: relayer_token_1,
+            relayer_fee,
+        },
+        relayer,
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        solana.token_account_balance(relayer_token_1).await,
+        relayer_quote_before + relayer_fee
+    );
+
+    send_tx_relayed(
+        solana,
+        place_order(
+            owner,
+            account_2,
+            Side::Ask,
+            owner_token_0,
+            market_base_vault,
+        ),
+        relayer,
+    )
+    .await
+    .unwrap();
+
+    let quote_balance_before = solana.token_account_balance(owner_token_1).await;
+    send_tx_relayed(
+        solana,
+        SettleFundsRelayedInstruction {
+            settle: SettleFundsInstruction {
+                owner,
+                market,
+                open_orders_account: account_2,
+                market_base_vault,
+                market_quote_vault,
+                user_base_account: owner_token_0,
+                user_quote_account: owner_token_1,
+                referrer_account: None,
+                remainings: vec![],
+            },
+            penalty_payer: relayer,
+        },
+        relayer,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        solana.token_account_balance(owner_token_1).await,
+        quote_balance_before + 99960
+    );
+
+    // the relayer paid for the transactions and the taker's event heap penalty
+    assert_eq!(
+        solana.get_account_lamports(owner.pubkey()).await,
+        owner_lamports
+    );
+    let open_orders_account_2 = solana.get_account::<OpenOrdersAccount>(account_2).await;
+    assert_eq!(open_orders_account_2.position.penalty_heap_count, 0);
+
+    Ok(())
+}
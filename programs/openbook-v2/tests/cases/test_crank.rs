@@ -166,12 +166,17 @@ async fn test_skip_missing_accounts() -> Result<(), TransportError> {
         ConsumeEventsInstruction {
             consume_events_admin: None,
             market,
-            open_orders_accounts: vec![maker_2, maker_3],
+            open_orders_accounts: vec![maker_3, maker_2],
         },
     )
     .await
     .unwrap();
 
+    let skipped_logs = solana.program_log_events::<openbook_v2::logs::ConsumeEventsSkippedLog>();
+    assert_eq!(skipped_logs.len(), 1);
+    assert_eq!(skipped_logs[0].events_skipped, 1);
+    assert_eq!(skipped_logs[0].missing_open_orders_accounts, vec![maker_1]);
+
     {
         let event_heap = solana.get_account_boxed::<EventHeap>(event_heap).await;
         assert_eq!(event_heap.header.count(), 1);
@@ -181,6 +186,89 @@ async fn test_skip_missing_accounts() -> Result<(), TransportError> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_consume_events_multiple_markets() -> Result<(), TransportError> {
+    let TestMultiMarketInitialize {
+        context,
+        collect_fee_admin,
+        owner,
+        owner_token_0,
+        owner_token_1,
+        tokens,
+        markets,
+        ..
+    } = TestContext::new_with_markets(2, vec![]).await?;
+    let solana = &context.solana.clone();
+
+    set_stub_oracle_price(solana, &tokens[1], collect_fee_admin, 1000.0).await;
+
+    let mut entries = vec![];
+    for market in markets.iter() {
+        for (open_orders_account, side) in
+            [(market.account_1, Side::Bid), (market.account_2, Side::Ask)]
+        {
+            let (user_token_account, market_vault) = match side {
+                Side::Bid => (owner_token_1, market.market_quote_vault),
+                Side::Ask => (owner_token_0, market.market_base_vault),
+            };
+            send_tx(
+                solana,
+                PlaceOrderInstruction {
+                    open_orders_account,
+                    open_orders_admin: None,
+                    market: market.market,
+                    signer: owner,
+                    user_token_account,
+                    market_vault,
+                    side,
+                    price_lots: market.price_lots,
+                    max_base_lots: 1,
+                    max_quote_lots_including_fees: 10000,
+                    client_order_id: 0,
+                    expiry_timestamp: 0,
+                    order_type: PlaceOrderType::Limit,
+                    self_trade_behavior: SelfTradeBehavior::default(),
+                    remainings: vec![],
+                },
+            )
+            .await
+            .unwrap();
+        }
+
+        entries.push(ConsumeEventsMultipleEntry {
+            market: market.market,
+            open_orders_accounts: vec![market.account_1],
+            limit: 10,
+        });
+    }
+
+    send_tx(
+        solana,
+        ConsumeEventsMultipleInstruction {
+            consume_events_admin: None,
+            entries,
+        },
+    )
+    .await
+    .unwrap();
+
+    for market in markets.iter() {
+        let market_account = solana.get_account::<Market>(market.market).await;
+        let event_heap = solana
+            .get_account_boxed::<EventHeap>(market_account.event_heap)
+            .await;
+        assert_eq!(event_heap.header.count(), 0);
+
+        let open_orders_account = solana
+            .get_account::<OpenOrdersAccount>(market.account_1)
+            .await;
+        assert_eq!(open_orders_account.position.bids_base_lots, 0);
+        assert_eq!(open_orders_account.position.base_free_native, 100);
+    }
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_crank_given_events() -> Result<(), TransportError> {
     let context = TestContext::new().await;
@@ -393,7 +481,1603 @@ async fn test_crank_given_events() -> Result<(), TransportError> {
     Ok(())
 }
 
-fn fill_maker(anyevent: &AnyEvent) -> Pubkey {
-    let event: &FillEvent = cast_ref(anyevent);
-    event.maker
+#[tokio::test]
+async fn test_crank_events_for_accounts() -> Result<(), TransportError> {
+    let context = TestContext::new().await;
+    let solana = &context.solana.clone();
+
+    let collect_fee_admin = TestKeypair::new();
+    let close_market_admin = TestKeypair::new();
+    let owner = context.users[0].key;
+    let payer = context.users[1].key;
+    let mints = &context.mints[0..=2];
+
+    let owner_token_0 = context.users[0].token_accounts[0];
+    let owner_token_1 = context.users[0].token_accounts[1];
+
+    let tokens = Token::create(mints.to_vec(), solana, collect_fee_admin, payer).await;
+
+    let market = TestKeypair::new();
+
+    let openbook_v2::accounts::CreateMarket {
+        market,
+        market_base_vault,
+        market_quote_vault,
+        event_heap,
+        ..
+    } = send_tx(
+        solana,
+        CreateMarketInstruction {
+            collect_fee_admin: collect_fee_admin.pubkey(),
+            open_orders_admin: None,
+            close_market_admin: Some(close_market_admin.pubkey()),
+            payer,
+            market,
+            quote_lot_size: 10,
+            base_lot_size: 100,
+            maker_fee: -200,
+            taker_fee: 400,
+            base_mint: mints[0].pubkey,
+            quote_mint: mints[1].pubkey,
+            ..CreateMarketInstruction::with_new_book_and_heap(solana, Some(tokens[0].oracle), None)
+                .await
+        },
+    )
+    .await
+    .unwrap();
+
+    let price_lots = {
+        let market = solana.get_account::<Market>(market).await;
+        market.native_price_to_lot(I80F48::ONE).unwrap()
+    };
+
+    let _indexer = create_open_orders_indexer(solana, &context.users[1], owner, market).await;
+    let (maker_1, maker_2, maker_3) = {
+        (
+            create_open_orders_account(solana, owner, market, 1, &context.users[1], None).await,
+            create_open_orders_account(solana, owner, market, 2, &context.users[1], None).await,
+            create_open_orders_account(solana, owner, market, 3, &context.users[1], None).await,
+        )
+    };
+    let taker = create_open_orders_account(solana, owner, market, 4, &context.users[1], None).await;
+
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            open_orders_account: maker_1,
+            open_orders_admin: None,
+            market,
+            signer: owner,
+            user_token_account: owner_token_1,
+            market_vault: market_quote_vault,
+            side: Side::Bid,
+            price_lots,
+            max_base_lots: 1,
+            max_quote_lots_including_fees: 10000,
+            client_order_id: 1,
+            expiry_timestamp: 0,
+            order_type: PlaceOrderType::Limit,
+            self_trade_behavior: SelfTradeBehavior::default(),
+            remainings: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            open_orders_account: maker_2,
+            open_orders_admin: None,
+            market,
+            signer: owner,
+            user_token_account: owner_token_1,
+            market_vault: market_quote_vault,
+            side: Side::Bid,
+            price_lots,
+            max_base_lots: 1,
+            max_quote_lots_including_fees: 10000,
+            client_order_id: 2,
+            expiry_timestamp: 0,
+            order_type: PlaceOrderType::Limit,
+            self_trade_behavior: SelfTradeBehavior::default(),
+            remainings: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            open_orders_account: maker_3,
+            open_orders_admin: None,
+            market,
+            signer: owner,
+            user_token_account: owner_token_1,
+            market_vault: market_quote_vault,
+            side: Side::Bid,
+            price_lots,
+            max_base_lots: 1,
+            max_quote_lots_including_fees: 10000,
+            client_order_id: 3,
+            expiry_timestamp: 0,
+            order_type: PlaceOrderType::Limit,
+            self_trade_behavior: SelfTradeBehavior::default(),
+            remainings: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            open_orders_account: taker,
+            open_orders_admin: None,
+            market,
+            signer: owner,
+            user_token_account: owner_token_0,
+            market_vault: market_base_vault,
+            side: Side::Ask,
+            price_lots,
+            max_base_lots: 3,
+            max_quote_lots_including_fees: 10000,
+            client_order_id: 0,
+            expiry_timestamp: 0,
+            order_type: PlaceOrderType::Limit,
+            self_trade_behavior: SelfTradeBehavior::default(),
+            remainings: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    {
+        let event_heap = solana.get_account_boxed::<EventHeap>(event_heap).await;
+        assert_eq!(event_heap.header.count(), 3);
+        assert_eq!(fill_maker(event_heap.at_slot(0).unwrap()), maker_1);
+        assert_eq!(fill_maker(event_heap.at_slot(1).unwrap()), maker_2);
+        assert_eq!(fill_maker(event_heap.at_slot(2).unwrap()), maker_3);
+    }
+
+    // only the events of the passed accounts are processed
+    send_tx(
+        solana,
+        ConsumeEventsForAccountsInstruction {
+            consume_events_admin: None,
+            market,
+            open_orders_accounts: vec![maker_2],
+        },
+    )
+    .await
+    .unwrap();
+
+    {
+        let event_heap = solana.get_account_boxed::<EventHeap>(event_heap).await;
+        assert_eq!(event_heap.header.count(), 2);
+        assert_eq!(fill_maker(event_heap.at_slot(0).unwrap()), maker_1);
+        assert!(event_heap.at_slot(1).is_none());
+        assert_eq!(fill_maker(event_heap.at_slot(2).unwrap()), maker_3);
+    }
+
+    // accounts without pending events leave the heap untouched
+    send_tx(
+        solana,
+        ConsumeEventsForAccountsInstruction {
+            consume_events_admin: None,
+            market,
+            open_orders_accounts: vec![taker],
+        },
+    )
+    .await
+    .unwrap();
+
+    {
+        let event_heap = solana.get_account_boxed::<EventHeap>(event_heap).await;
+        assert_eq!(event_heap.header.count(), 2);
+    }
+
+    send_tx(
+        solana,
+        ConsumeEventsForAccountsInstruction {
+            consume_events_admin: None,
+            market,
+            open_orders_accounts: vec![maker_1, maker_3],
+        },
+    )
+    .await
+    .unwrap();
+
+    {
+        let event_heap = solana.get_account_boxed::<EventHeap>(event_heap).await;
+        assert_eq!(event_heap.header.count(), 0);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_consume_events_reward() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        collect_fee_admin,
+        owner,
+        payer,
+        owner_token_0,
+        owner_token_1,
+        market,
+        market_base_vault,
+        market_quote_vault,
+        price_lots,
+        tokens,
+        account_1,
+        account_2,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize::default()).await?;
+    let solana = &context.solana.clone();
+    let cranker = payer;
+
+    set_stub_oracle_price(solana, &tokens[1], collect_fee_admin, 1000.0).await;
+
+    // only the collect fee admin can configure the reward
+    assert!(send_tx(
+        solana,
+        SetConsumeEventsRewardInstruction {
+            collect_fee_admin: owner,
+            market,
+            reward_lamports: 100,
+        },
+    )
+    .await
+    .is_err());
+
+    send_tx(
+        solana,
+        SetConsumeEventsRewardInstruction {
+            collect_fee_admin,
+            market,
+            reward_lamports: 100,
+        },
+    )
+    .await
+    .unwrap();
+
+    for (account, side, user_token_account, market_vault) in [
+        (account_1, Side::Bid, owner_token_1, market_quote_vault),
+        (account_2, Side::Ask, owner_token_0, market_base_vault),
+    ] {
+        send_tx(
+            solana,
+            PlaceOrderInstruction {
+                open_orders_account: account,
+                open_orders_admin: None,
+                market,
+                signer: owner,
+                user_token_account,
+                market_vault,
+                side,
+                price_lots,
+                max_base_lots: 1,
+                max_quote_lots_including_fees: 10000,
+                client_order_id: 0,
+                expiry_timestamp: 0,
+                order_type: PlaceOrderType::Limit,
+                self_trade_behavior: SelfTradeBehavior::default(),
+                remainings: vec![],
+            },
+        )
+        .await
+        .unwrap();
+    }
+
+    // the taker pays the event heap penalty to the market when settling
+    send_tx(
+        solana,
+        SettleFundsInstruction {
+            owner,
+            market,
+            open_orders_account: account_2,
+            market_base_vault,
+            market_quote_vault,
+            user_base_account: owner_token_0,
+            user_quote_account: owner_token_1,
+            referrer_account: None,
+            remainings: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let market_lamports = solana.get_account_lamports(market).await;
+    let cranker_lamports = solana.get_account_lamports(cranker.pubkey()).await;
+
+    send_tx(
+        solana,
+        ConsumeEventsWithRewardInstruction {
+            cranker,
+            consume_events_admin: None,
+            market,
+            open_orders_accounts: vec![account_1],
+        },
+    )
+    .await
+    .unwrap();
+
+    {
+        let market_acc = solana.get_account::<Market>(market).await;
+        let event_heap = solana
+            .get_account_boxed::<EventHeap>(market_acc.event_heap)
+            .await;
+        assert_eq!(event_heap.header.count(), 0);
+    }
+    assert_eq!(
+        solana.get_account_lamports(market).await,
+        market_lamports - 100
+    );
+    assert_eq!(
+        solana.get_account_lamports(cranker.pubkey()).await,
+        cranker_lamports + 100
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_resize_event_heap() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        collect_fee_admin,
+        owner,
+        payer,
+        owner_token_0,
+        owner_token_1,
+        market,
+        market_base_vault,
+        market_quote_vault,
+        price_lots,
+        tokens,
+        account_1,
+        account_2,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize::default()).await?;
+    let solana = &context.solana.clone();
+
+    set_stub_oracle_price(solana, &tokens[1], collect_fee_admin, 1000.0).await;
+
+    let event_heap_pk = solana.get_account::<Market>(market).await.event_heap;
+    let initial_len = solana.get_account_data(event_heap_pk).await.unwrap().len();
+
+    // only the collect fee admin can grow the heap
+    assert!(send_tx(
+        solana,
+        ResizeEventHeapInstruction {
+            collect_fee_admin: owner,
+            payer,
+            market,
+            additional_nodes: 10,
+        },
+    )
+    .await
+    .is_err());
+
+    send_tx(
+        solana,
+        ResizeEventHeapInstruction {
+            collect_fee_admin,
+            payer,
+            market,
+            additional_nodes: 10,
+        },
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        solana.get_account_data(event_heap_pk).await.unwrap().len(),
+        initial_len + 10 * std::mem::size_of::<EventNode>()
+    );
+    {
+        let event_heap = solana.get_account_boxed::<EventHeap>(event_heap_pk).await;
+        assert_eq!(event_heap.extension_nodes, 10);
+        assert_eq!(event_heap.header.free_head(), MAX_NUM_EVENTS as usize);
+    }
+    assert_eq!(
+        solana
+            .get_account::<Market>(market)
+            .await
+            .event_heap_capacity,
+        MAX_NUM_EVENTS + 10
+    );
+
+    for (account, side, user_token_account, market_vault) in [
+        (account_1, Side::Bid, owner_token_1, market_quote_vault),
+        (account_2, Side::Ask, owner_token_0, market_base_vault),
+    ] {
+        send_tx(
+            solana,
+            PlaceOrderInstruction {
+                open_orders_account: account,
+                open_orders_admin: None,
+                market,
+                signer: owner,
+                user_token_account,
+                market_vault,
+                side,
+                price_lots,
+                max_base_lots: 1,
+                max_quote_lots_including_fees: 10000,
+                client_order_id: 0,
+                expiry_timestamp: 0,
+                order_type: PlaceOrderType::Limit,
+                self_trade_behavior: SelfTradeBehavior::default(),
+                remainings: vec![],
+            },
+        )
+        .await
+        .unwrap();
+    }
+
+    // the fill lands in the first extension node
+    {
+        let event_heap = solana.get_account_boxed::<EventHeap>(event_heap_pk).await;
+        assert_eq!(event_heap.header.count(), 1);
+        assert_eq!(event_heap.header.used_head(), MAX_NUM_EVENTS as usize);
+    }
+
+    send_tx(
+        solana,
+        ConsumeGivenEventsInstruction {
+            consume_events_admin: None,
+            market,
+            slots: vec![MAX_NUM_EVENTS.into()],
+            open_orders_accounts: vec![account_1],
+        },
+    )
+    .await
+    .unwrap();
+
+    {
+        let event_heap = solana.get_account_boxed::<EventHeap>(event_heap_pk).await;
+        assert_eq!(event_heap.header.count(), 0);
+        assert_eq!(event_heap.header.free_head(), MAX_NUM_EVENTS as usize);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_event_heap_stats() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        collect_fee_admin,
+        owner,
+        owner_token_0,
+        owner_token_1,
+        market,
+        market_base_vault,
+        market_quote_vault,
+        price_lots,
+        tokens,
+        account_1,
+        account_2,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize::default()).await?;
+    let solana = &context.solana.clone();
+
+    set_stub_oracle_price(solana, &tokens[1], collect_fee_admin, 1000.0).await;
+
+    {
+        let market_acc = solana.get_account::<Market>(market).await;
+        assert_eq!(market_acc.event_heap_count, 0);
+        assert_eq!(market_acc.event_heap_capacity, MAX_NUM_EVENTS);
+        assert_eq!(market_acc.event_heap_oldest_timestamp, 0);
+    }
+
+    for (account, side, user_token_account, market_vault) in [
+        (account_1, Side::Bid, owner_token_1, market_quote_vault),
+        (account_2, Side::Ask, owner_token_0, market_base_vault),
+    ] {
+        send_tx(
+            solana,
+            PlaceOrderInstruction {
+                open_orders_account: account,
+                open_orders_admin: None,
+                market,
+                signer: owner,
+                user_token_account,
+                market_vault,
+                side,
+                price_lots,
+                max_base_lots: 1,
+                max_quote_lots_including_fees: 10000,
+                client_order_id: 0,
+                expiry_timestamp: 0,
+                order_type: PlaceOrderType::Limit,
+                self_trade_behavior: SelfTradeBehavior::default(),
+                remainings: vec![],
+            },
+        )
+        .await
+        .unwrap();
+    }
+
+    let fill_ts = solana.get_clock().await.unix_timestamp as u64;
+    {
+        let market_acc = solana.get_account::<Market>(market).await;
+        assert_eq!(market_acc.event_heap_count, 1);
+        assert_eq!(market_acc.event_heap_oldest_timestamp, fill_ts);
+        assert_eq!(
+            market_acc.event_heap_fill_bps(),
+            10_000 / MAX_NUM_EVENTS as u64
+        );
+        assert_eq!(market_acc.oldest_event_age(fill_ts + 30), 30);
+    }
+
+    send_tx(
+        solana,
+        ConsumeEventsInstruction {
+            consume_events_admin: None,
+            market,
+            open_orders_accounts: vec![account_1],
+        },
+    )
+    .await
+    .unwrap();
+
+    {
+        let market_acc = solana.get_account::<Market>(market).await;
+        assert_eq!(market_acc.event_heap_count, 0);
+        assert_eq!(market_acc.event_heap_oldest_timestamp, 0);
+        assert_eq!(market_acc.oldest_event_age(fill_ts + 30), 0);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_event_seq_num() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        collect_fee_admin,
+        owner,
+        owner_token_0,
+        owner_token_1,
+        market,
+        market_base_vault,
+        market_quote_vault,
+        price_lots,
+        tokens,
+        account_1,
+        account_2,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize::default()).await?;
+    let solana = &context.solana.clone();
+
+    set_stub_oracle_price(solana, &tokens[1], collect_fee_admin, 1000.0).await;
+
+    for (account, side, user_token_account, market_vault, max_base_lots) in [
+        (account_1, Side::Bid, owner_token_1, market_quote_vault, 1),
+        (account_1, Side::Bid, owner_token_1, market_quote_vault, 1),
+        (account_2, Side::Ask, owner_token_0, market_base_vault, 2),
+    ] {
+        send_tx(
+            solana,
+            PlaceOrderInstruction {
+                open_orders_account: account,
+                open_orders_admin: None,
+                market,
+                signer: owner,
+                user_token_account,
+                market_vault,
+                side,
+                price_lots,
+                max_base_lots,
+                max_quote_lots_including_fees: 10000,
+                client_order_id: 0,
+                expiry_timestamp: 0,
+                order_type: PlaceOrderType::Limit,
+                self_trade_behavior: SelfTradeBehavior::default(),
+                remainings: vec![],
+            },
+        )
+        .await
+        .unwrap();
+    }
+
+    let market_acc = solana.get_account::<Market>(market).await;
+    assert_eq!(market_acc.event_seq_num, 2);
+
+    let event_heap = solana
+        .get_account_boxed::<EventHeap>(market_acc.event_heap)
+        .await;
+    let seq_nums: Vec<u64> = event_heap
+        .iter()
+        .map(|(event, _slot)| {
+            let fill: &FillEvent = cast_ref(event);
+            fill.event_seq_num
+        })
+        .collect();
+    assert_eq!(seq_nums, vec![1, 2]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fill_callback() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        collect_fee_admin,
+        owner,
+        owner_token_0,
+        owner_token_1,
+        market,
+        market_base_vault,
+        market_quote_vault,
+        price_lots,
+        tokens,
+        account_1,
+        account_2,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize::default()).await?;
+    let solana = &context.solana.clone();
+    let callback_program = fill_callback_program_id();
+
+    set_stub_oracle_price(solana, &tokens[1], collect_fee_admin, 1000.0).await;
+
+    // only the collect fee admin can register a callback
+    assert!(send_tx(
+        solana,
+        CreateFillCallbackInstruction {
+            payer: owner,
+            collect_fee_admin: owner,
+            market,
+            program: callback_program,
+        },
+    )
+    .await
+    .is_err());
+
+    // calling back into openbook itself is not allowed
+    assert!(send_tx(
+        solana,
+        CreateFillCallbackInstruction {
+            payer: owner,
+            collect_fee_admin,
+            market,
+            program: openbook_v2::id(),
+        },
+    )
+    .await
+    .is_err());
+
+    send_tx(
+        solana,
+        CreateFillCallbackInstruction {
+            payer: owner,
+            collect_fee_admin,
+            market,
+            program: callback_program,
+        },
+    )
+    .await
+    .unwrap();
+
+    for (account, side, user_token_account, market_vault) in [
+        (account_1, Side::Bid, owner_token_1, market_quote_vault),
+        (account_2, Side::Ask, owner_token_0, market_base_vault),
+    ] {
+        send_tx(
+            solana,
+            PlaceOrderInstruction {
+                open_orders_account: account,
+                open_orders_admin: None,
+                market,
+                signer: owner,
+                user_token_account,
+                market_vault,
+                side,
+                price_lots,
+                max_base_lots: 1,
+                max_quote_lots_including_fees: 10000,
+                client_order_id: 0,
+                expiry_timestamp: 0,
+                order_type: PlaceOrderType::Limit,
+                self_trade_behavior: SelfTradeBehavior::default(),
+                remainings: vec![],
+            },
+        )
+        .await
+        .unwrap();
+    }
+
+    // consume_events doesn't call back, so a failing callback can't block it
+    send_tx(
+        solana,
+        ConsumeEventsInstruction {
+            consume_events_admin: None,
+            market,
+            open_orders_accounts: vec![account_1],
+        },
+    )
+    .await
+    .unwrap();
+    assert!(!solana
+        .program_log()
+        .iter()
+        .any(|line| line.contains("on_fill")));
+
+    for (account, side, user_token_account, market_vault) in [
+        (account_1, Side::Bid, owner_token_1, market_quote_vault),
+        (account_2, Side::Ask, owner_token_0, market_base_vault),
+    ] {
+        send_tx(
+            solana,
+            PlaceOrderInstruction {
+                open_orders_account: account,
+                open_orders_admin: None,
+                market,
+                signer: owner,
+                user_token_account,
+                market_vault,
+                side,
+                price_lots,
+                max_base_lots: 1,
+                max_quote_lots_including_fees: 10000,
+                client_order_id: 0,
+                expiry_timestamp: 0,
+                order_type: PlaceOrderType::Limit,
+                self_trade_behavior: SelfTradeBehavior::default(),
+                remainings: vec![],
+            },
+        )
+        .await
+        .unwrap();
+    }
+
+    // another program than the registered one is rejected
+    assert!(send_tx(
+        solana,
+        ConsumeEventsWithFillCallbackInstruction {
+            consume_events_admin: None,
+            market,
+            fill_callback_program: openbook_v2::id(),
+            open_orders_accounts: vec![account_1],
+        },
+    )
+    .await
+    .is_err());
+
+    send_tx(
+        solana,
+        ConsumeEventsWithFillCallbackInstruction {
+            consume_events_admin: None,
+            market,
+            fill_callback_program: callback_program,
+            open_orders_accounts: vec![account_1],
+        },
+    )
+    .await
+    .unwrap();
+
+    let expected_log = format!("on_fill {} 1", market);
+    assert!(solana
+        .program_log()
+        .iter()
+        .any(|line| line.contains(&expected_log)));
+
+    // once removed, there's no program to call back
+    send_tx(
+        solana,
+        SetFillCallbackInstruction {
+            collect_fee_admin,
+            market,
+            program: None,
+        },
+    )
+    .await
+    .unwrap();
+    assert!(send_tx(
+        solana,
+        ConsumeEventsWithFillCallbackInstruction {
+            consume_events_admin: None,
+            market,
+            fill_callback_program: callback_program,
+            open_orders_accounts: vec![account_1],
+        },
+    )
+    .await
+    .is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fill_history() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        collect_fee_admin,
+        owner,
+        payer,
+        owner_token_0,
+        owner_token_1,
+        market,
+        market_base_vault,
+        market_quote_vault,
+        price_lots,
+        tokens,
+        account_1,
+        account_2,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize::default()).await?;
+    let solana = &context.solana.clone();
+    let fill_history = get_fill_history_address(market);
+
+    set_stub_oracle_price(solana, &tokens[1], collect_fee_admin, 1000.0).await;
+
+    send_tx(solana, CreateFillHistoryInstruction { payer, market })
+        .await
+        .unwrap();
+
+    for (account, side, user_token_account, market_vault) in [
+        (account_1, Side::Bid, owner_token_1, market_quote_vault),
+        (account_2, Side::Ask, owner_token_0, market_base_vault),
+    ] {
+        send_tx(
+            solana,
+            PlaceOrderInstruction {
+                open_orders_account: account,
+                open_orders_admin: None,
+                market,
+                signer: owner,
+                user_token_account,
+                market_vault,
+                side,
+                price_lots,
+                max_base_lots: 1,
+                max_quote_lots_including_fees: 10000,
+                client_order_id: 0,
+                expiry_timestamp: 0,
+                order_type: PlaceOrderType::Limit,
+                self_trade_behavior: SelfTradeBehavior::default(),
+                remainings: vec![fill_history],
+            },
+        )
+        .await
+        .unwrap();
+    }
+
+    let history = solana.get_account::<FillHistory>(fill_history).await;
+    assert_eq!(history.market, market);
+    assert_eq!(history.len(), 1);
+
+    let fill = history.iter_recent().next().unwrap();
+    assert_eq!(fill.price, price_lots);
+    assert_eq!(fill.quantity, 1);
+    assert_eq!(fill.maker, account_1);
+    assert_eq!(fill.taker, account_2);
+    assert_eq!(fill.taker_side, Side::Ask as u8);
+    assert_eq!(fill.event_seq_num, 1);
+
+    Ok(())
+}
+
+fn fill_maker(anyevent: &AnyEvent) -> Pubkey {
+    let event: &FillEvent = cast_ref(anyevent);
+    event.maker
+}
+
+#[tokio::test]
+async fn test_defragment_book() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        owner,
+        owner_token_1,
+        market,
+        market_quote_vault,
+        price_lots,
+        account_1,
+        bids,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize {
+        with_oracle: false,
+        ..TestNewMarketInitialize::default()
+    })
+    .await?;
+    let solana = &context.solana.clone();
+
+    for i in 0..20 {
+        send_tx(
+            solana,
+            PlaceOrderInstruction {
+                open_orders_account: account_1,
+                open_orders_admin: None,
+                market,
+                signer: owner,
+                user_token_account: owner_token_1,
+                market_vault: market_quote_vault,
+                side: Side::Bid,
+                price_lots: price_lots - i,
+                max_base_lots: 1,
+                max_quote_lots_including_fees: 10000,
+                client_order_id: i as u64,
+                expiry_timestamp: 0,
+                order_type: PlaceOrderType::Limit,
+                self_trade_behavior: SelfTradeBehavior::default(),
+                remainings: vec![],
+            },
+        )
+        .await
+        .unwrap();
+    }
+    // cancelling the best orders frees nodes all over the node array
+    for client_order_id in (0..20).step_by(2) {
+        send_tx(
+            solana,
+            CancelOrderByClientOrderIdInstruction {
+                open_orders_account: account_1,
+                market,
+                signer: owner,
+                client_order_id,
+            },
+        )
+        .await
+        .unwrap();
+    }
+
+    let book_orders = |bookside: &BookSide| -> Vec<(u128, i64)> {
+        bookside
+            .iter_all_including_invalid(0, None)
+            .map(|order| (order.node.key, order.price_lots))
+            .collect()
+    };
+    let before = solana.get_account_boxed::<BookSide>(bids).await;
+    assert!(before.nodes.free_list_len > 0);
+
+    // anyone can defragment, a few nodes at a time
+    for _ in 0..10 {
+        send_tx(solana, DefragmentBookInstruction { market, limit: 4 })
+            .await
+            .unwrap();
+    }
+
+    let after = solana.get_account_boxed::<BookSide>(bids).await;
+    assert_eq!(book_orders(&after), book_orders(&before));
+    assert_eq!(after.nodes.free_list_len, 0);
+    assert_eq!(after.nodes.bump_index, 2 * 10 - 1);
+
+    // the book keeps working
+    send_tx(
+        solana,
+        CancelAllOrdersInstruction {
+            open_orders_account: account_1,
+            market,
+            signer: owner,
+        },
+    )
+    .await
+    .unwrap();
+    send_tx(
+        solana,
+        CancelAllOrdersInstruction {
+            open_orders_account: account_1,
+            market,
+            signer: owner,
+        },
+    )
+    .await
+    .unwrap();
+    let open_orders_account_1 = solana.get_account::<OpenOrdersAccount>(account_1).await;
+    assert_eq!(open_orders_account_1.all_orders_in_use().count(), 0);
+    assert!(solana.get_account_boxed::<BookSide>(bids).await.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_book_side_page() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        market,
+        bids,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize {
+        with_oracle: false,
+        ..TestNewMarketInitialize::default()
+    })
+    .await?;
+    let solana = &context.solana.clone();
+
+    let mut pages = vec![];
+    for i in 0..MAX_BOOK_SIDE_PAGES {
+        let page = solana
+            .create_account_for_type::<BookSide>(&openbook_v2::id())
+            .await;
+        // the page goes after the last one
+        if i > 0 {
+            assert!(send_tx(
+                solana,
+                CreateBookSidePageInstruction {
+                    market,
+                    side: Side::Bid,
+                    page,
+                    pages: vec![],
+                },
+            )
+            .await
+            .is_err());
+        }
+        send_tx(
+            solana,
+            CreateBookSidePageInstruction {
+                market,
+                side: Side::Bid,
+                page,
+                pages: pages.clone(),
+            },
+        )
+        .await
+        .unwrap();
+        pages.push(page);
+    }
+
+    let bids_account = solana.get_account::<BookSide>(bids).await;
+    assert_eq!(bids_account.next_page, pages[0]);
+    for (page, next_page) in pages.iter().zip(pages.iter().skip(1)) {
+        let page_account = solana.get_account::<BookSide>(*page).await;
+        assert_eq!(page_account.next_page, *next_page);
+        assert_eq!(page_account.side(), Side::Bid);
+    }
+    let asks = solana.get_account::<Market>(market).await.asks;
+    let asks_account = solana.get_account::<BookSide>(asks).await;
+    assert_eq!(asks_account.next_page, Pubkey::default());
+
+    // at most MAX_BOOK_SIDE_PAGES per side
+    let page = solana
+        .create_account_for_type::<BookSide>(&openbook_v2::id())
+        .await;
+    assert!(send_tx(
+        solana,
+        CreateBookSidePageInstruction {
+            market,
+            side: Side::Bid,
+            page,
+            pages,
+        },
+    )
+    .await
+    .is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_maker_rewards() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        collect_fee_admin,
+        owner,
+        payer,
+        mints,
+        owner_token_0,
+        owner_token_1,
+        market,
+        market_base_vault,
+        market_quote_vault,
+        price_lots,
+        tokens,
+        account_1,
+        account_2,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize::default()).await?;
+    let solana = &context.solana.clone();
+
+    set_stub_oracle_price(solana, &tokens[0], collect_fee_admin, 1000.0).await;
+
+    send_tx(
+        solana,
+        CreateMakerRewardsInstruction {
+            collect_fee_admin,
+            payer,
+            market,
+            reward_mint: mints[0].pubkey,
+            rewards_per_million_points: 2_000_000,
+        },
+    )
+    .await
+    .unwrap();
+    let maker_rewards = solana
+        .get_account::<MakerRewards>(get_maker_rewards_address(market))
+        .await;
+    solana
+        .set_account_balance(maker_rewards.reward_vault, 1_000_000)
+        .await;
+
+    let place_bid = PlaceOrderInstruction {
+        open_orders_account: account_1,
+        open_orders_admin: None,
+        market,
+        signer: owner,
+        user_token_account: owner_token_1,
+        market_vault: market_quote_vault,
+        side: Side::Bid,
+        price_lots: price_lots - 10,
+        max_base_lots: 1,
+        max_quote_lots_including_fees: 100000,
+
+        client_order_id: 0,
+        expiry_timestamp: 0,
+        order_type: PlaceOrderType::Limit,
+        self_trade_behavior: SelfTradeBehavior::default(),
+        remainings: vec![],
+    };
+    send_tx(solana, place_bid.clone()).await.unwrap();
+    // never at the top of the book
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            price_lots: price_lots - 20,
+            ..place_bid.clone()
+        },
+    )
+    .await
+    .unwrap();
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            open_orders_account: account_2,
+            open_orders_admin: None,
+            market,
+            signer: owner,
+            user_token_account: owner_token_0,
+            market_vault: market_base_vault,
+            side: Side::Ask,
+            price_lots: price_lots + 10,
+            max_base_lots: 3,
+            max_quote_lots_including_fees: 100000,
+
+            client_order_id: 0,
+            expiry_timestamp: 0,
+            order_type: PlaceOrderType::Limit,
+            self_trade_behavior: SelfTradeBehavior::default(),
+            remainings: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    solana.advance_clock(100).await;
+    set_stub_oracle_price(solana, &tokens[0], collect_fee_admin, 1000.0).await;
+
+    // cancelling accrues the time the best bid was at the top
+    send_tx(
+        solana,
+        CancelAllOrdersInstruction {
+            open_orders_account: account_1,
+            market,
+            signer: owner,
+        },
+    )
+    .await
+    .unwrap();
+    let position_1 = solana
+        .get_account::<OpenOrdersAccount>(account_1)
+        .await
+        .position;
+    assert_eq!(position_1.maker_reward_points, 100);
+
+    // so does filling, for the filled base lots, once the maker's fill is consumed
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            price_lots: price_lots + 10,
+            ..place_bid
+        },
+    )
+    .await
+    .unwrap();
+    send_tx(
+        solana,
+        ConsumeEventsInstruction {
+            consume_events_admin: None,
+            market,
+            open_orders_accounts: vec![account_2],
+        },
+    )
+    .await
+    .unwrap();
+    let position_2 = solana
+        .get_account::<OpenOrdersAccount>(account_2)
+        .await
+        .position;
+    assert_eq!(position_2.maker_reward_points, 100);
+
+    let balance_before = solana.token_account_balance(owner_token_0).await;
+    send_tx(
+        solana,
+        ClaimMakerRewardsInstruction {
+            owner,
+            open_orders_account: account_1,
+            market,
+            user_reward_account: owner_token_0,
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        solana.token_account_balance(owner_token_0).await,
+        balance_before + 200
+    );
+    let position_1 = solana
+        .get_account::<OpenOrdersAccount>(account_1)
+        .await
+        .position;
+    assert_eq!(position_1.maker_reward_points, 0);
+    assert_eq!(position_1.maker_rewards_claimed, 200);
+    let maker_rewards = solana
+        .get_account::<MakerRewards>(get_maker_rewards_address(market))
+        .await;
+    assert_eq!(maker_rewards.rewards_claimed_total, 200);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_quote_quality() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        collect_fee_admin,
+        owner,
+        payer,
+        owner_token_0,
+        owner_token_1,
+        market,
+        market_base_vault,
+        market_quote_vault,
+        price_lots,
+        tokens,
+        account_1,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize::default()).await?;
+    let solana = &context.solana.clone();
+
+    set_stub_oracle_price(solana, &tokens[0], collect_fee_admin, 1000.0).await;
+    send_tx(
+        solana,
+        CreateQuoteQualityInstruction {
+            collect_fee_admin,
+            payer,
+            market,
+            open_orders_account: account_1,
+            max_oracle_distance_bps: 50,
+            min_size_lots: 2,
+            epoch_duration: 86_400,
+        },
+    )
+    .await
+    .unwrap();
+
+    let place_bid = PlaceOrderInstruction {
+        open_orders_account: account_1,
+        open_orders_admin: None,
+        market,
+        signer: owner,
+        user_token_account: owner_token_1,
+        market_vault: market_quote_vault,
+        side: Side::Bid,
+        price_lots: price_lots - 10,
+        max_base_lots: 2,
+        max_quote_lots_including_fees: 100000,
+
+        client_order_id: 1,
+        expiry_timestamp: 0,
+        order_type: PlaceOrderType::Limit,
+        self_trade_behavior: SelfTradeBehavior::default(),
+        remainings: vec![],
+    };
+    send_tx(solana, place_bid.clone()).await.unwrap();
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            user_token_account: owner_token_0,
+            market_vault: market_base_vault,
+            side: Side::Ask,
+            price_lots: price_lots + 10,
+            client_order_id: 2,
+            ..place_bid.clone()
+        },
+    )
+    .await
+    .unwrap();
+    // too far from the oracle to count
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            price_lots: price_lots - 100,
+            max_base_lots: 5,
+            client_order_id: 3,
+            ..place_bid
+        },
+    )
+    .await
+    .unwrap();
+
+    let oracle_token = &tokens[0];
+    let sample = move || async move {
+        set_stub_oracle_price(solana, oracle_token, collect_fee_admin, 1000.0).await;
+        send_tx(
+            solana,
+            SampleQuoteQualityInstruction {
+                market,
+                open_orders_account: account_1,
+            },
+        )
+        .await
+        .unwrap();
+        solana
+            .get_account::<QuoteQuality>(get_quote_quality_address(account_1))
+            .await
+            .current_epoch
+    };
+
+    solana.advance_clock(100).await;
+    let epoch = sample().await;
+    assert_eq!(epoch.samples, 1);
+    assert_eq!(epoch.seconds_sampled, 100);
+    assert_eq!(epoch.seconds_quoting, 100);
+    assert_eq!(epoch.min_bid_size_lots, 2);
+    assert_eq!(epoch.min_ask_size_lots, 2);
+
+    send_tx(
+        solana,
+        CancelOrderByClientOrderIdInstruction {
+            open_orders_account: account_1,
+            market,
+            signer: owner,
+            client_order_id: 2,
+        },
+    )
+    .await
+    .unwrap();
+
+    solana.advance_clock(100).await;
+    let epoch = sample().await;
+    assert_eq!(epoch.samples, 2);
+    assert_eq!(epoch.seconds_sampled, 200);
+    assert_eq!(epoch.seconds_quoting, 100);
+    assert_eq!(epoch.uptime_bps(), 5_000);
+    assert_eq!(epoch.min_ask_size_lots, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_dmm_registry() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        collect_fee_admin,
+        owner,
+        payer,
+        market,
+        tokens,
+        account_1,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize::default()).await?;
+    let solana = &context.solana.clone();
+
+    set_stub_oracle_price(solana, &tokens[0], collect_fee_admin, 1000.0).await;
+    send_tx(
+        solana,
+        CreateQuoteQualityInstruction {
+            collect_fee_admin,
+            payer,
+            market,
+            open_orders_account: account_1,
+            max_oracle_distance_bps: 50,
+            min_size_lots: 1,
+            epoch_duration: 100,
+        },
+    )
+    .await
+    .unwrap();
+    send_tx(
+        solana,
+        CreateDmmRegistryInstruction {
+            collect_fee_admin,
+            payer,
+            market,
+        },
+    )
+    .await
+    .unwrap();
+
+    // only the collect fee admin assigns slots
+    assert!(send_tx(
+        solana,
+        SetDmmSlotInstruction {
+            collect_fee_admin: owner,
+            market,
+            open_orders_account: account_1,
+            priority: 1,
+            min_uptime_bps: 8_000,
+        },
+    )
+    .await
+    .is_err());
+
+    send_tx(
+        solana,
+        SetDmmSlotInstruction {
+            collect_fee_admin,
+            market,
+            open_orders_account: account_1,
+            priority: 1,
+            min_uptime_bps: 8_000,
+        },
+    )
+    .await
+    .unwrap();
+
+    let open_orders_account_1 = solana.get_account::<OpenOrdersAccount>(account_1).await;
+    assert_eq!(open_orders_account_1.dmm_priority, 1);
+    let dmm_registry = solana
+        .get_account::<DmmRegistry>(get_dmm_registry_address(market))
+        .await;
+    assert_eq!(dmm_registry.slots[0].open_orders_account, account_1);
+    assert_eq!(dmm_registry.slots[0].min_uptime_bps, 8_000);
+
+    let enforce = || EnforceDmmObligationInstruction {
+        market,
+        open_orders_account: account_1,
+    };
+    let sample = || SampleQuoteQualityInstruction {
+        market,
+        open_orders_account: account_1,
+    };
+
+    // no complete epoch yet
+    assert_openbook_error(
+        &send_tx(solana, enforce()).await,
+        OpenBookError::DmmObligationMet.error_code(),
+        "Nothing to hold the market maker to yet".into(),
+    );
+
+    // an epoch without any quotes
+    solana.advance_clock(50).await;
+    set_stub_oracle_price(solana, &tokens[0], collect_fee_admin, 1000.0).await;
+    send_tx(solana, sample()).await.unwrap();
+    solana.advance_clock(60).await;
+    set_stub_oracle_price(solana, &tokens[0], collect_fee_admin, 1000.0).await;
+    send_tx(solana, sample()).await.unwrap();
+
+    let quote_quality = solana
+        .get_account::<QuoteQuality>(get_quote_quality_address(account_1))
+        .await;
+    assert_eq!(quote_quality.previous_epoch.seconds_sampled, 50);
+    assert_eq!(quote_quality.previous_epoch.seconds_quoting, 0);
+
+    send_tx(solana, enforce()).await.unwrap();
+
+    let open_orders_account_1 = solana.get_account::<OpenOrdersAccount>(account_1).await;
+    assert_eq!(open_orders_account_1.dmm_priority, 0);
+    let dmm_registry = solana
+        .get_account::<DmmRegistry>(get_dmm_registry_address(market))
+        .await;
+    assert!(dmm_registry.slots[0].is_free());
+
+    assert_openbook_error(
+        &send_tx(solana, enforce()).await,
+        OpenBookError::NotADmm.error_code(),
+        "Already removed".into(),
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_expire_orders() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        owner,
+        owner_token_1,
+        market,
+        market_quote_vault,
+        price_lots,
+        account_1,
+        account_2,
+        bids,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize {
+        with_oracle: false,
+        ..TestNewMarketInitialize::default()
+    })
+    .await?;
+    let solana = &context.solana.clone();
+
+    let now_ts: u64 = solana.get_clock().await.unix_timestamp as u64;
+    for (i, (open_orders_account, expiry_timestamp)) in [
+        (account_1, now_ts + 2),
+        (account_1, now_ts + 2),
+        (account_2, now_ts + 3),
+        (account_2, 0),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        send_tx(
+            solana,
+            PlaceOrderInstruction {
+                open_orders_account,
+                open_orders_admin: None,
+                market,
+                signer: owner,
+                user_token_account: owner_token_1,
+                market_vault: market_quote_vault,
+                side: Side::Bid,
+                price_lots: price_lots - i as i64,
+                max_base_lots: 1,
+                max_quote_lots_including_fees: 10000,
+                client_order_id: i as u64,
+                expiry_timestamp,
+                order_type: PlaceOrderType::Limit,
+                self_trade_behavior: SelfTradeBehavior::default(),
+                remainings: vec![],
+            },
+        )
+        .await
+        .unwrap();
+    }
+
+    {
+        let bids = solana.get_account_boxed::<BookSide>(bids).await;
+        assert_eq!(bids.nodes.due_expiry_order_count(now_ts + 10), 3);
+    }
+
+    solana.advance_clock(4).await;
+
+    // the out events of the accounts passed along are applied right away
+    send_tx(
+        solana,
+        ExpireOrdersInstruction {
+            market,
+            limit: 2,
+            open_orders_accounts: vec![account_1],
+        },
+    )
+    .await
+    .unwrap();
+    {
+        let bids = solana.get_account_boxed::<BookSide>(bids).await;
+        assert_eq!(bids.roots[0].leaf_count, 2);
+        let open_orders_account_1 = solana.get_account::<OpenOrdersAccount>(account_1).await;
+        assert_eq!(open_orders_account_1.position.bids_base_lots, 0);
+        assert_eq!(open_orders_account_1.all_orders_in_use().count(), 0);
+    }
+
+    // the others are queued, orders that don't expire stay
+    send_tx(
+        solana,
+        ExpireOrdersInstruction {
+            market,
+            limit: 10,
+            open_orders_accounts: vec![],
+        },
+    )
+    .await
+    .unwrap();
+    {
+        let bids = solana.get_account_boxed::<BookSide>(bids).await;
+        assert_eq!(bids.roots[0].leaf_count, 1);
+        assert_eq!(bids.nodes.due_expiry_order_count(u64::MAX), 0);
+
+        let market_acc = solana.get_account_boxed::<Market>(market).await;
+        let event_heap = solana
+            .get_account_boxed::<EventHeap>(market_acc.event_heap)
+            .await;
+        assert_eq!(event_heap.header.count(), 1);
+        let open_orders_account_2 = solana.get_account::<OpenOrdersAccount>(account_2).await;
+        assert_eq!(open_orders_account_2.position.bids_base_lots, 2);
+    }
+
+    Ok(())
 }
@@ -145,3 +145,363 @@ async fn test_with_wrong_oracle_types() -> Result<(), TransportError> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_market_metadata() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        collect_fee_admin,
+        owner,
+        payer,
+        market,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize::default()).await?;
+    let solana = &context.solana.clone();
+    let args = |name: &str, uri: &str| openbook_v2::MarketMetadataArgs {
+        name: name.to_string(),
+        base_symbol: "SOL".to_string(),
+        quote_symbol: "USDC".to_string(),
+        uri: uri.to_string(),
+    };
+
+    // only the market admin can label a market
+    assert!(send_tx(
+        solana,
+        CreateMarketMetadataInstruction {
+            collect_fee_admin: owner,
+            payer,
+            market,
+            args: args("SOL/USDC", ""),
+        },
+    )
+    .await
+    .is_err());
+
+    send_tx(
+        solana,
+        CreateMarketMetadataInstruction {
+            collect_fee_admin,
+            payer,
+            market,
+            args: args("SOL/USDC", ""),
+        },
+    )
+    .await
+    .unwrap();
+
+    send_tx(
+        solana,
+        SetMarketMetadataInstruction {
+            collect_fee_admin,
+            market,
+            args: args("SOL/USDC spot", "https://example.com/sol-usdc"),
+        },
+    )
+    .await
+    .unwrap();
+
+    let metadata = solana
+        .get_account::<MarketMetadata>(get_market_metadata_address(market))
+        .await;
+    assert_eq!(metadata.market, market);
+    assert_eq!(metadata.name(), "SOL/USDC spot");
+    assert_eq!(metadata.base_symbol(), "SOL");
+    assert_eq!(metadata.quote_symbol(), "USDC");
+    assert_eq!(metadata.uri(), "https://example.com/sol-usdc");
+
+    // fields have a fixed maximum length
+    assert_eq!(
+        send_tx_and_get_ix_custom_error(
+            solana,
+            SetMarketMetadataInstruction {
+                collect_fee_admin,
+                market,
+                args: args(&"x".repeat(65), ""),
+            },
+        )
+        .await,
+        Some(OpenBookError::InvalidInputNameLength.into())
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_canonical_market() -> Result<(), TransportError> {
+    let context = TestContextBuilder::new().start_default().await;
+    let solana = &context.solana.clone();
+
+    let payer = context.users[0].key;
+    let mints = &context.mints[0..=2];
+    let tokens = Token::create(mints.to_vec(), solana, payer, payer).await;
+
+    // canonical markets are open to everyone
+    assert_eq!(
+        send_tx_and_get_ix_custom_error(
+            solana,
+            CreateCanonicalMarketInstruction {
+                payer,
+                open_orders_admin: Some(payer.pubkey()),
+                quote_lot_size: 100,
+                base_lot_size: 100,
+                base_mint: mints[0].pubkey,
+                quote_mint: mints[1].pubkey,
+                ..CreateCanonicalMarketInstruction::with_new_book_and_heap(
+                    solana,
+                    Some(tokens[0].oracle)
+                )
+                .await
+            },
+        )
+        .await,
+        Some(openbook_v2::error::OpenBookError::InvalidOpenOrdersAdmin.into())
+    );
+
+    send_tx(
+        solana,
+        CreateCanonicalMarketInstruction {
+            payer,
+            quote_lot_size: 100,
+            base_lot_size: 100,
+            base_mint: mints[0].pubkey,
+            quote_mint: mints[1].pubkey,
+            ..CreateCanonicalMarketInstruction::with_new_book_and_heap(
+                solana,
+                Some(tokens[0].oracle),
+            )
+            .await
+        },
+    )
+    .await
+    .unwrap();
+
+    let market_pk = get_canonical_market_address(mints[0].pubkey, mints[1].pubkey, 100, 100);
+    let market = solana.get_account::<Market>(market_pk).await;
+    assert_eq!(market.base_mint, mints[0].pubkey);
+    assert_eq!(market.quote_mint, mints[1].pubkey);
+    assert_eq!(market.base_lot_size, 100);
+    assert_eq!(market.quote_lot_size, 100);
+
+    // only one canonical market per configuration
+    assert!(send_tx(
+        solana,
+        CreateCanonicalMarketInstruction {
+            payer,
+            quote_lot_size: 100,
+            base_lot_size: 100,
+            base_mint: mints[0].pubkey,
+            quote_mint: mints[1].pubkey,
+            ..CreateCanonicalMarketInstruction::with_new_book_and_heap(
+                solana,
+                Some(tokens[0].oracle),
+            )
+            .await
+        },
+    )
+    .await
+    .is_err());
+
+    // other lot sizes make another market
+    send_tx(
+        solana,
+        CreateCanonicalMarketInstruction {
+            payer,
+            quote_lot_size: 10,
+            base_lot_size: 100,
+            base_mint: mints[0].pubkey,
+            quote_mint: mints[1].pubkey,
+            ..CreateCanonicalMarketInstruction::with_new_book_and_heap(
+                solana,
+                Some(tokens[0].oracle),
+            )
+            .await
+        },
+    )
+    .await
+    .unwrap();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_market_registry() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        payer,
+        market,
+        mints,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize::default()).await?;
+    let solana = &context.solana.clone();
+
+    send_tx(
+        solana,
+        CreateMarketRegistryPageInstruction {
+            payer,
+            page_index: 0,
+        },
+    )
+    .await
+    .unwrap();
+
+    // pages are only added once the previous one is full
+    assert!(send_tx(
+        solana,
+        CreateMarketRegistryPageInstruction {
+            payer,
+            page_index: 1,
+        },
+    )
+    .await
+    .is_err());
+
+    send_tx(
+        solana,
+        RegisterMarketInstruction {
+            market,
+            page_index: 0,
+        },
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        send_tx_and_get_ix_custom_error(
+            solana,
+            RegisterMarketInstruction {
+                market,
+                page_index: 0,
+            },
+        )
+        .await,
+        Some(openbook_v2::error::OpenBookError::MarketAlreadyRegistered.into())
+    );
+
+    let page = solana
+        .get_account::<MarketRegistryPage>(get_market_registry_page_address(0))
+        .await;
+    assert_eq!(page.page_index, 0);
+    assert_eq!(page.entries().len(), 1);
+    assert_eq!(page.entries()[0].market, market);
+    assert_eq!(page.entries()[0].base_mint, mints[0].pubkey);
+    assert_eq!(page.entries()[0].quote_mint, mints[1].pubkey);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_market_creation_bond() -> Result<(), TransportError> {
+    let TestInitialize {
+        context, market, ..
+    } = TestContext::new_with_market(TestNewMarketInitialize::default()).await?;
+    let solana = &context.solana.clone();
+
+    let rent = solana
+        .rent
+        .minimum_balance(8 + std::mem::size_of::<Market>());
+    assert_eq!(
+        solana.get_account_lamports(market).await,
+        rent + MARKET_CREATION_BOND
+    );
+    assert_eq!(
+        solana.get_account::<Market>(market).await.creation_bond,
+        MARKET_CREATION_BOND
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_successor_market() -> Result<(), TransportError> {
+    let TestMultiMarketInitialize {
+        context,
+        collect_fee_admin,
+        owner,
+        payer,
+        markets,
+        ..
+    } = TestContext::new_with_markets(
+        2,
+        vec![
+            TestNewMarketInitialize::default(),
+            TestNewMarketInitialize {
+                base_lot_size: 1000,
+                quote_lot_size: 100,
+                ..TestNewMarketInitialize::default()
+            },
+        ],
+    )
+    .await?;
+    let solana = &context.solana.clone();
+    let market = markets[0].market;
+    let successor_market = markets[1].market;
+
+    send_tx(
+        solana,
+        CreateMarketMetadataInstruction {
+            collect_fee_admin,
+            payer,
+            market,
+            args: openbook_v2::MarketMetadataArgs {
+                name: "SOL/USDC".to_string(),
+                base_symbol: "SOL".to_string(),
+                quote_symbol: "USDC".to_string(),
+                uri: "".to_string(),
+            },
+        },
+    )
+    .await
+    .unwrap();
+    let market_metadata = get_market_metadata_address(market);
+
+    for (admin, successor) in [(owner, successor_market), (collect_fee_admin, market)] {
+        assert!(send_tx(
+            solana,
+            SetSuccessorMarketInstruction {
+                collect_fee_admin: admin,
+                market,
+                successor_market: Some(successor),
+            },
+        )
+        .await
+        .is_err());
+    }
+
+    send_tx(
+        solana,
+        SetSuccessorMarketInstruction {
+            collect_fee_admin,
+            market,
+            successor_market: Some(successor_market),
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        Option::<Pubkey>::from(
+            solana
+                .get_account::<MarketMetadata>(market_metadata)
+                .await
+                .successor_market
+        ),
+        Some(successor_market)
+    );
+
+    send_tx(
+        solana,
+        SetSuccessorMarketInstruction {
+            collect_fee_admin,
+            market,
+            successor_market: None,
+        },
+    )
+    .await
+    .unwrap();
+    assert!(solana
+        .get_account::<MarketMetadata>(market_metadata)
+        .await
+        .successor_market
+        .is_none());
+
+    Ok(())
+}
@@ -0,0 +1,146 @@
+use super::*;
+use bytemuck::cast_ref;
+use program_test::reference_book::{ReferenceBook, ReferenceFill, ReferenceOrder};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+const ORDERS: usize = 100;
+const ACCOUNTS: u32 = 6;
+// The match limit PlaceOrderInstruction passes
+const MATCH_LIMIT: usize = 10;
+// Keeps the accounts below MAX_OPEN_ORDERS
+const MAX_RESTING_ORDERS: usize = 16;
+
+/// Fill events on the heap, oldest first
+async fn heap_fills(solana: &SolanaCookie, event_heap: Pubkey) -> Vec<ReferenceFill> {
+    let event_heap = solana.get_account_boxed::<EventHeap>(event_heap).await;
+    let mut fills: Vec<FillEvent> = event_heap
+        .iter()
+        .filter(|(event, _)| event.event_type == u8::from(EventType::Fill))
+        .map(|(event, _)| *cast_ref::<_, FillEvent>(event))
+        .collect();
+    fills.sort_by_key(|fill| fill.event_seq_num);
+    fills
+        .iter()
+        .map(|fill| ReferenceFill {
+            maker: fill.maker,
+            taker: fill.taker,
+            price_lots: fill.price,
+            quantity: fill.quantity,
+            maker_out: fill.maker_out(),
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_differential_matching() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        owner,
+        owner_token_0,
+        owner_token_1,
+        market,
+        market_base_vault,
+        market_quote_vault,
+        price_lots,
+        account_1,
+        account_2,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize::default()).await?;
+    let solana = &context.solana.clone();
+
+    let mut accounts = vec![account_1, account_2];
+    for account_num in 3..=ACCOUNTS {
+        accounts.push(
+            create_open_orders_account(solana, owner, market, account_num, &context.users[1], None)
+                .await,
+        );
+    }
+    let market_acc = solana.get_account::<Market>(market).await;
+
+    let mut reference = ReferenceBook::default();
+    let mut rng = StdRng::seed_from_u64(0);
+    for i in 0..ORDERS {
+        let account = accounts[rng.gen_range(0..accounts.len())];
+        let side = if rng.gen_bool(0.5) {
+            Side::Bid
+        } else {
+            Side::Ask
+        };
+        let order_price_lots = price_lots + rng.gen_range(-10..=10);
+        let base_lots = rng.gen_range(1..=10);
+        let order_type =
+            if reference.resting_orders(account) < MAX_RESTING_ORDERS && rng.gen_bool(0.8) {
+                PlaceOrderType::Limit
+            } else {
+                PlaceOrderType::ImmediateOrCancel
+            };
+        let (user_token_account, market_vault) = match side {
+            Side::Bid => (owner_token_1, market_quote_vault),
+            Side::Ask => (owner_token_0, market_base_vault),
+        };
+
+        send_tx(
+            solana,
+            PlaceOrderInstruction {
+                open_orders_account: account,
+                open_orders_admin: None,
+                market,
+                signer: owner,
+                user_token_account,
+                market_vault,
+                side,
+                price_lots: order_price_lots,
+                max_base_lots: base_lots,
+                max_quote_lots_including_fees: 1_000_000_000,
+                client_order_id: i as u64,
+                expiry_timestamp: 0,
+                order_type,
+                self_trade_behavior: SelfTradeBehavior::DecrementTake,
+                remainings: vec![],
+            },
+        )
+        .await
+        .unwrap();
+
+        let expected = reference.place(
+            account,
+            side,
+            order_price_lots,
+            base_lots,
+            order_type == PlaceOrderType::Limit,
+            MATCH_LIMIT,
+        );
+        let fills = heap_fills(solana, market_acc.event_heap).await;
+        assert_eq!(fills, expected, "fills of order {}", i);
+
+        if !fills.is_empty() {
+            send_tx(
+                solana,
+                ConsumeEventsInstruction {
+                    consume_events_admin: None,
+                    market,
+                    open_orders_accounts: accounts.clone(),
+                },
+            )
+            .await
+            .unwrap();
+        }
+    }
+
+    let bids = solana.get_account_boxed::<BookSide>(market_acc.bids).await;
+    let asks = solana.get_account_boxed::<BookSide>(market_acc.asks).await;
+    for (bookside, expected) in [(&bids, &reference.bids), (&asks, &reference.asks)] {
+        let orders: Vec<ReferenceOrder> = bookside
+            .orders(0, None)
+            .map(|order| ReferenceOrder {
+                owner: order.owner,
+                price_lots: order.price_lots,
+                quantity: order.quantity,
+            })
+            .collect();
+        assert_eq!(&orders, expected);
+    }
+
+    Ok(())
+}
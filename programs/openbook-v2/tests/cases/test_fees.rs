@@ -6,6 +6,7 @@ async fn test_fees_accrued() -> Result<(), TransportError> {
         context,
         collect_fee_admin,
         owner,
+        payer,
         mints,
         owner_token_0,
         owner_token_1,
@@ -29,6 +30,21 @@ async fn test_fees_accrued() -> Result<(), TransportError> {
     // Set the initial oracle price
     set_stub_oracle_price(solana, &tokens[1], collect_fee_admin, 1000.0).await;
 
+    for open_orders_account in [account_1, account_2] {
+        send_tx(
+            solana,
+            CreatePositionExtensionInstruction {
+                owner,
+                payer,
+                open_orders_account,
+            },
+        )
+        .await
+        .unwrap();
+    }
+    let position_extension_1 = get_position_extension_address(account_1);
+    let position_extension_2 = get_position_extension_address(account_2);
+
     send_tx(
         solana,
         PlaceOrderInstruction {
@@ -46,7 +62,7 @@ async fn test_fees_accrued() -> Result<(), TransportError> {
             expiry_timestamp: 0,
             order_type: PlaceOrderType::Limit,
             self_trade_behavior: SelfTradeBehavior::default(),
-            remainings: vec![],
+            remainings: vec![position_extension_1],
         },
     )
     .await
@@ -69,7 +85,7 @@ async fn test_fees_accrued() -> Result<(), TransportError> {
             expiry_timestamp: 0,
             order_type: PlaceOrderType::Limit,
             self_trade_behavior: SelfTradeBehavior::default(),
-            remainings: vec![],
+            remainings: vec![position_extension_2],
         },
     )
     .await
@@ -87,6 +103,14 @@ async fn test_fees_accrued() -> Result<(), TransportError> {
         assert_eq!(open_orders_account_2.position.base_free_native, 0);
         assert_eq!(open_orders_account_1.position.quote_free_native, 0);
         assert_eq!(open_orders_account_2.position.quote_free_native, 99980);
+        assert_eq!(open_orders_account_2.position.taker_volume, 100000);
+        assert_eq!(open_orders_account_2.position.taker_fees_paid, 20);
+
+        let position_extension_2 = solana
+            .get_account::<PositionExtension>(position_extension_2)
+            .await;
+        assert_eq!(position_extension_2.net_base_native, -100);
+        assert_eq!(position_extension_2.cost_basis_native, -100000);
     }
 
     send_tx(
@@ -94,7 +118,7 @@ async fn test_fees_accrued() -> Result<(), TransportError> {
         ConsumeEventsInstruction {
             consume_events_admin: None,
             market,
-            open_orders_accounts: vec![account_1, account_2],
+            open_orders_accounts: vec![account_1, account_2, position_extension_1],
         },
     )
     .await
@@ -112,6 +136,18 @@ async fn test_fees_accrued() -> Result<(), TransportError> {
         assert_eq!(open_orders_account_2.position.base_free_native, 0);
         assert_eq!(open_orders_account_1.position.quote_free_native, 10);
         assert_eq!(open_orders_account_2.position.quote_free_native, 99980);
+        assert_eq!(open_orders_account_1.position.maker_volume, 100000);
+        assert_eq!(open_orders_account_1.position.maker_fees_paid, 0);
+        assert_eq!(open_orders_account_1.position.maker_rebates_received, 10);
+
+        let position_extension_1 = solana
+            .get_account::<PositionExtension>(position_extension_1)
+            .await;
+        assert_eq!(position_extension_1.net_base_native, 100);
+        assert_eq!(
+            position_extension_1.average_entry_price(),
+            Some(I80F48::from_num(1000))
+        );
     }
 
     let admin_token_1 = solana
@@ -129,6 +165,7 @@ async fn test_fees_accrued() -> Result<(), TransportError> {
             user_base_account: owner_token_0,
             user_quote_account: owner_token_1,
             referrer_account: None,
+            remainings: vec![],
         },
     )
     .await
@@ -345,6 +382,7 @@ async fn test_maker_fees() -> Result<(), TransportError> {
             user_base_account: owner_token_0,
             user_quote_account: owner_token_1,
             referrer_account: None,
+            remainings: vec![],
         },
     )
     .await
@@ -368,6 +406,7 @@ async fn test_maker_fees() -> Result<(), TransportError> {
             user_base_account: owner_token_0,
             user_quote_account: owner_token_1,
             referrer_account: Some(owner_token_1),
+            remainings: vec![],
         },
     )
     .await
@@ -400,6 +439,193 @@ async fn test_maker_fees() -> Result<(), TransportError> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_dmm_fees() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        collect_fee_admin,
+        owner,
+        payer,
+        owner_token_0,
+        owner_token_1,
+        market,
+
+        market_base_vault,
+        market_quote_vault,
+        price_lots,
+        tokens,
+        account_1,
+        account_2,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize {
+        maker_fee: 200,
+        taker_fee: 400,
+        ..TestNewMarketInitialize::default()
+    })
+    .await?;
+    let solana = &context.solana.clone();
+
+    // Set the initial oracle price
+    set_stub_oracle_price(solana, &tokens[1], collect_fee_admin, 1000.0).await;
+
+    send_tx(
+        solana,
+        CreateDmmRegistryInstruction {
+            collect_fee_admin,
+            payer,
+            market,
+        },
+    )
+    .await
+    .unwrap();
+    send_tx(
+        solana,
+        SetDmmSlotInstruction {
+            collect_fee_admin,
+            market,
+            open_orders_account: account_1,
+            priority: 1,
+            min_uptime_bps: 0,
+        },
+    )
+    .await
+    .unwrap();
+
+    // The designated market maker's bid locks the maker fees like any other
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            open_orders_account: account_1,
+            open_orders_admin: None,
+            market,
+            signer: owner,
+            user_token_account: owner_token_1,
+            market_vault: market_quote_vault,
+            side: Side::Bid,
+            price_lots,
+            max_base_lots: 1,
+            max_quote_lots_including_fees: 10020,
+            client_order_id: 0,
+            expiry_timestamp: 0,
+            order_type: PlaceOrderType::Limit,
+            self_trade_behavior: SelfTradeBehavior::default(),
+            remainings: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            open_orders_account: account_2,
+            open_orders_admin: None,
+            market,
+            signer: owner,
+            user_token_account: owner_token_0,
+            market_vault: market_base_vault,
+            side: Side::Ask,
+            price_lots,
+            max_base_lots: 1,
+            max_quote_lots_including_fees: 10000,
+            client_order_id: 0,
+            expiry_timestamp: 0,
+            order_type: PlaceOrderType::Limit,
+            self_trade_behavior: SelfTradeBehavior::default(),
+            remainings: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    send_tx(
+        solana,
+        ConsumeEventsInstruction {
+            consume_events_admin: None,
+            market,
+            open_orders_accounts: vec![account_1, account_2],
+        },
+    )
+    .await
+    .unwrap();
+
+    // ... and gets them back once filled
+    {
+        let open_orders_account_1 = solana.get_account::<OpenOrdersAccount>(account_1).await;
+        let open_orders_account_2 = solana.get_account::<OpenOrdersAccount>(account_2).await;
+
+        assert_eq!(open_orders_account_1.position.base_free_native, 100);
+        assert_eq!(open_orders_account_1.position.quote_free_native, 20);
+        assert_eq!(open_orders_account_1.position.locked_maker_fees, 0);
+        assert_eq!(open_orders_account_1.position.maker_fees_paid, 0);
+        assert_eq!(open_orders_account_2.position.quote_free_native, 99960);
+        assert_eq!(open_orders_account_2.position.taker_fees_paid, 40);
+
+        let market = solana.get_account::<Market>(market).await;
+        assert_eq!(market.fees_accrued, 40);
+    }
+
+    // Taking doesn't cost the designated market maker anything either
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            open_orders_account: account_2,
+            open_orders_admin: None,
+            market,
+            signer: owner,
+            user_token_account: owner_token_1,
+            market_vault: market_quote_vault,
+            side: Side::Bid,
+            price_lots,
+            max_base_lots: 1,
+            max_quote_lots_including_fees: 10020,
+            client_order_id: 0,
+            expiry_timestamp: 0,
+            order_type: PlaceOrderType::Limit,
+            self_trade_behavior: SelfTradeBehavior::default(),
+            remainings: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            open_orders_account: account_1,
+            open_orders_admin: None,
+            market,
+            signer: owner,
+            user_token_account: owner_token_0,
+            market_vault: market_base_vault,
+            side: Side::Ask,
+            price_lots,
+            max_base_lots: 1,
+            max_quote_lots_including_fees: 10000,
+            client_order_id: 0,
+            expiry_timestamp: 0,
+            order_type: PlaceOrderType::Limit,
+            self_trade_behavior: SelfTradeBehavior::default(),
+            remainings: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    {
+        let open_orders_account_1 = solana.get_account::<OpenOrdersAccount>(account_1).await;
+
+        assert_eq!(open_orders_account_1.position.base_free_native, 0);
+        assert_eq!(open_orders_account_1.position.quote_free_native, 100020);
+        assert_eq!(open_orders_account_1.position.taker_fees_paid, 0);
+
+        let market = solana.get_account::<Market>(market).await;
+        assert_eq!(market.fees_accrued, 40);
+    }
+
+    Ok(())
+}
+
 // Real simulation. Market maker pays fees but get them back on referral. Jupiter users don't pay fees.
 // Only users paying fees are users using a UI limit orders.
 #[tokio::test]
@@ -485,6 +711,7 @@ async fn test_market_maker_fees() -> Result<(), TransportError> {
             price_lots,
             max_base_lots: 1,
             max_quote_lots_including_fees: 10040,
+            client_order_id: 0,
             open_orders_admin: None,
         },
     )
@@ -525,6 +752,7 @@ async fn test_market_maker_fees() -> Result<(), TransportError> {
             user_base_account: owner_token_0,
             user_quote_account: owner_token_1,
             referrer_account: Some(owner_token_1),
+            remainings: vec![],
         },
     )
     .await
@@ -685,6 +913,7 @@ async fn test_no_maker_fees_ask() -> Result<(), TransportError> {
             user_base_account: owner_token_0,
             user_quote_account: owner_token_1,
             referrer_account: None,
+            remainings: vec![],
         },
     )
     .await
@@ -708,6 +937,7 @@ async fn test_no_maker_fees_ask() -> Result<(), TransportError> {
             user_base_account: owner_token_0,
             user_quote_account: owner_token_1,
             referrer_account: Some(owner_token_1),
+            remainings: vec![],
         },
     )
     .await
@@ -878,6 +1108,7 @@ async fn test_maker_fees_ask() -> Result<(), TransportError> {
             user_base_account: owner_token_0,
             user_quote_account: owner_token_1,
             referrer_account: None,
+            remainings: vec![],
         },
     )
     .await
@@ -901,6 +1132,7 @@ async fn test_maker_fees_ask() -> Result<(), TransportError> {
             user_base_account: owner_token_0,
             user_quote_account: owner_token_1,
             referrer_account: Some(owner_token_1),
+            remainings: vec![],
         },
     )
     .await
@@ -1052,6 +1284,7 @@ async fn test_fees_half() -> Result<(), TransportError> {
             user_base_account: owner_token_0,
             user_quote_account: owner_token_1,
             referrer_account: None,
+            remainings: vec![],
         },
     )
     .await
@@ -1068,6 +1301,7 @@ async fn test_fees_half() -> Result<(), TransportError> {
             user_base_account: owner_token_0,
             user_quote_account: owner_token_1,
             referrer_account: None,
+            remainings: vec![],
         },
     )
     .await
@@ -1214,6 +1448,7 @@ async fn test_fees_half() -> Result<(), TransportError> {
             user_base_account: owner_token_0,
             user_quote_account: owner_token_1,
             referrer_account: None,
+            remainings: vec![],
         },
     )
     .await
@@ -1230,6 +1465,7 @@ async fn test_fees_half() -> Result<(), TransportError> {
             user_base_account: owner_token_0,
             user_quote_account: owner_token_1,
             referrer_account: None,
+            remainings: vec![],
         },
     )
     .await
@@ -1327,6 +1563,7 @@ async fn test_locked_maker_fees() -> Result<(), TransportError> {
         user_base_account: owner_base_ata,
         user_quote_account: owner_quote_ata,
         referrer_account: None,
+        remainings: vec![],
     };
 
     send_tx(solana, place_maker_bid.clone()).await.unwrap();
@@ -1394,3 +1631,598 @@ async fn test_locked_maker_fees() -> Result<(), TransportError> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_verify_market_invariants() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        owner,
+        owner_token_0,
+        owner_token_1,
+        market,
+        market_base_vault,
+        market_quote_vault,
+        price_lots,
+        account_1,
+        account_2,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize {
+        maker_fee: -100,
+        taker_fee: 200,
+        with_oracle: false,
+        ..TestNewMarketInitialize::default()
+    })
+    .await?;
+    let solana = &context.solana.clone();
+
+    for (open_orders_account, side, user_token_account, market_vault) in [
+        (account_1, Side::Bid, owner_token_1, market_quote_vault),
+        (account_2, Side::Ask, owner_token_0, market_base_vault),
+    ] {
+        send_tx(
+            solana,
+            PlaceOrderInstruction {
+                open_orders_account,
+                open_orders_admin: None,
+                market,
+                signer: owner,
+                user_token_account,
+                market_vault,
+                side,
+                price_lots,
+                max_base_lots: 1,
+                max_quote_lots_including_fees: 10000,
+                client_order_id: 0,
+                expiry_timestamp: 0,
+                order_type: PlaceOrderType::Limit,
+                self_trade_behavior: SelfTradeBehavior::default(),
+                remainings: vec![],
+            },
+        )
+        .await
+        .unwrap();
+    }
+
+    let verify = |open_orders_accounts: Vec<Pubkey>| async move {
+        send_tx(
+            solana,
+            VerifyMarketInvariantsInstruction {
+                market,
+                open_orders_accounts,
+            },
+        )
+        .await
+        .unwrap();
+        solana
+            .program_log_events::<openbook_v2::logs::MarketInvariantsLog>()
+            .pop()
+            .unwrap()
+    };
+
+    // the fill is still on the event heap
+    let log = verify(vec![account_1, account_2]).await;
+    assert_eq!(log.open_orders_accounts, 2);
+    assert_eq!(log.pending_fills, 1);
+    assert_eq!(log.base_unaccounted, 0);
+    assert_eq!(log.quote_unaccounted, 0);
+    assert!(log.base_vault >= log.base_deposit_total);
+    assert!(log.quote_vault >= log.quote_deposit_total);
+
+    // what the taker got is left unaccounted without it
+    let log = verify(vec![account_1]).await;
+    assert_eq!(log.base_unaccounted, 0);
+    assert!(log.quote_unaccounted > 0);
+
+    // the maker's order stays in the open interest until the fill is consumed
+    {
+        let market_acc = solana.get_account::<Market>(market).await;
+        let oo = solana.get_account::<OpenOrdersAccount>(account_1).await;
+        assert!(market_acc.bids_quote_lots_total > 0);
+        assert_eq!(
+            market_acc.bids_quote_lots_total,
+            oo.position.bids_quote_lots
+        );
+        assert_eq!(market_acc.asks_base_lots_total, 0);
+    }
+
+    // the same account can't be counted twice
+    assert!(send_tx(
+        solana,
+        VerifyMarketInvariantsInstruction {
+            market,
+            open_orders_accounts: vec![account_1, account_1],
+        },
+    )
+    .await
+    .is_err());
+
+    send_tx(
+        solana,
+        ConsumeEventsInstruction {
+            consume_events_admin: None,
+            market,
+            open_orders_accounts: vec![account_1, account_2],
+        },
+    )
+    .await
+    .unwrap();
+
+    let log = verify(vec![account_1, account_2]).await;
+    assert_eq!(log.pending_fills, 0);
+    assert_eq!(log.base_unaccounted, 0);
+    assert_eq!(log.quote_unaccounted, 0);
+
+    {
+        let market_acc = solana.get_account::<Market>(market).await;
+        assert_eq!(market_acc.bids_quote_lots_total, 0);
+        assert_eq!(market_acc.asks_base_lots_total, 0);
+        assert_eq!(
+            market_acc.base_free_native_total as u64,
+            market_acc.base_deposit_total
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_taker_rebate_campaign() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        collect_fee_admin,
+        owner,
+        payer,
+        mints,
+        owner_token_0,
+        owner_token_1,
+        market,
+        market_base_vault,
+        market_quote_vault,
+        price_lots,
+        tokens,
+        account_1,
+        account_2,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize {
+        maker_fee: -100,
+        taker_fee: 200,
+        ..TestNewMarketInitialize::default()
+    })
+    .await?;
+    let solana = &context.solana.clone();
+    let campaign_address = get_taker_rebate_campaign_address(market);
+
+    set_stub_oracle_price(solana, &tokens[1], collect_fee_admin, 1000.0).await;
+
+    let now_ts = solana.get_clock().await.unix_timestamp as u64;
+    send_tx(
+        solana,
+        CreateTakerRebateCampaignInstruction {
+            collect_fee_admin,
+            payer,
+            market,
+            start_timestamp: now_ts,
+            end_timestamp: now_ts + 1000,
+            rebate_bps: 5_000,
+        },
+    )
+    .await
+    .unwrap();
+
+    let admin_token_1 = solana
+        .create_associated_token_account(&collect_fee_admin.pubkey(), mints[1].pubkey)
+        .await;
+    solana.set_account_balance(admin_token_1, 15).await;
+    send_tx(
+        solana,
+        FundTakerRebateCampaignInstruction {
+            collect_fee_admin,
+            market,
+            admin_quote_account: admin_token_1,
+            amount: 15,
+        },
+    )
+    .await
+    .unwrap();
+
+    // each trade pays 20 in taker fees, the second one exhausts the budget
+    for _ in 0..2 {
+        send_tx(
+            solana,
+            PlaceOrderInstruction {
+                open_orders_account: account_1,
+                open_orders_admin: None,
+                market,
+                signer: owner,
+                user_token_account: owner_token_1,
+                market_vault: market_quote_vault,
+                side: Side::Bid,
+                price_lots,
+                max_base_lots: 1,
+                max_quote_lots_including_fees: 10000,
+                client_order_id: 0,
+                expiry_timestamp: 0,
+                order_type: PlaceOrderType::Limit,
+                self_trade_behavior: SelfTradeBehavior::default(),
+                remainings: vec![],
+            },
+        )
+        .await
+        .unwrap();
+
+        send_tx(
+            solana,
+            PlaceOrderInstruction {
+                open_orders_account: account_2,
+                open_orders_admin: None,
+                market,
+                signer: owner,
+                user_token_account: owner_token_0,
+                market_vault: market_base_vault,
+                side: Side::Ask,
+                price_lots,
+                max_base_lots: 1,
+                max_quote_lots_including_fees: 10000,
+                client_order_id: 0,
+                expiry_timestamp: 0,
+                order_type: PlaceOrderType::Limit,
+                self_trade_behavior: SelfTradeBehavior::default(),
+                remainings: vec![campaign_address],
+            },
+        )
+        .await
+        .unwrap();
+    }
+
+    let position_2 = solana
+        .get_account::<OpenOrdersAccount>(account_2)
+        .await
+        .position;
+    assert_eq!(position_2.taker_fees_paid, 40);
+    assert_eq!(position_2.taker_rebates_accrued, 15);
+    let campaign = solana
+        .get_account::<TakerRebateCampaign>(campaign_address)
+        .await;
+    assert_eq!(campaign.budget_remaining, 0);
+    assert_eq!(campaign.rebates_outstanding, 15);
+    assert_eq!(campaign.rebates_accrued_total, 15);
+
+    let withdraw_budget = WithdrawTakerRebateBudgetInstruction {
+        collect_fee_admin,
+        market,
+        token_receiver_account: admin_token_1,
+    };
+    assert!(send_tx(solana, withdraw_budget.clone()).await.is_err());
+
+    let balance_before = solana.token_account_balance(owner_token_1).await;
+    send_tx(
+        solana,
+        ClaimTakerRebatesInstruction {
+            owner,
+            open_orders_account: account_2,
+            market,
+            user_quote_account: owner_token_1,
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        solana.token_account_balance(owner_token_1).await,
+        balance_before + 15
+    );
+    let campaign = solana
+        .get_account::<TakerRebateCampaign>(campaign_address)
+        .await;
+    assert_eq!(campaign.rebates_outstanding, 0);
+
+    // the budget left once the campaign ended goes back to the admin
+    solana.set_account_balance(admin_token_1, 7).await;
+    send_tx(
+        solana,
+        FundTakerRebateCampaignInstruction {
+            collect_fee_admin,
+            market,
+            admin_quote_account: admin_token_1,
+            amount: 7,
+        },
+    )
+    .await
+    .unwrap();
+    solana.advance_clock(1000).await;
+    send_tx(solana, withdraw_budget).await.unwrap();
+    assert_eq!(solana.token_account_balance(admin_token_1).await, 7);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_claim_rebates() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        collect_fee_admin,
+        owner,
+        mints,
+        owner_token_0,
+        owner_token_1,
+        market,
+        market_base_vault,
+        market_quote_vault,
+        price_lots,
+        tokens,
+        account_1,
+        account_2,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize {
+        maker_fee: -100,
+        taker_fee: 200,
+        ..TestNewMarketInitialize::default()
+    })
+    .await?;
+    let solana = &context.solana.clone();
+
+    set_stub_oracle_price(solana, &tokens[1], collect_fee_admin, 1000.0).await;
+
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            open_orders_account: account_1,
+            open_orders_admin: None,
+            market,
+            signer: owner,
+            user_token_account: owner_token_1,
+            market_vault: market_quote_vault,
+            side: Side::Bid,
+            price_lots,
+            max_base_lots: 1,
+            max_quote_lots_including_fees: 10000,
+            client_order_id: 0,
+            expiry_timestamp: 0,
+            order_type: PlaceOrderType::Limit,
+            self_trade_behavior: SelfTradeBehavior::default(),
+            remainings: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            open_orders_account: account_2,
+            open_orders_admin: None,
+            market,
+            signer: owner,
+            user_token_account: owner_token_0,
+            market_vault: market_base_vault,
+            side: Side::Ask,
+            price_lots,
+            max_base_lots: 1,
+            max_quote_lots_including_fees: 10000,
+            client_order_id: 0,
+            expiry_timestamp: 0,
+            order_type: PlaceOrderType::Limit,
+            self_trade_behavior: SelfTradeBehavior::default(),
+            remainings: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    send_tx(
+        solana,
+        ConsumeEventsInstruction {
+            consume_events_admin: None,
+            market,
+            open_orders_accounts: vec![account_1, account_2],
+        },
+    )
+    .await
+    .unwrap();
+
+    // a treasury wallet that isn't the owner of the open orders accounts
+    let treasury = TestKeypair::new();
+    let treasury_token_1 = solana
+        .create_token_account(&treasury.pubkey(), mints[1].pubkey)
+        .await;
+
+    // the maker gets its rebate out of its free quote
+    send_tx(
+        solana,
+        ClaimRebatesInstruction {
+            owner,
+            open_orders_account: account_1,
+            market,
+            rebate_receiver_account: treasury_token_1,
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(solana.token_account_balance(treasury_token_1).await, 10);
+    let position_1 = solana
+        .get_account::<OpenOrdersAccount>(account_1)
+        .await
+        .position;
+    assert_eq!(position_1.quote_free_native, 0);
+    assert_eq!(position_1.maker_rebates_available, 0);
+    assert_eq!(position_1.base_free_native, 100);
+
+    // the taker gets the share of its fees left for referrers
+    send_tx(
+        solana,
+        ClaimRebatesInstruction {
+            owner,
+            open_orders_account: account_2,
+            market,
+            rebate_receiver_account: treasury_token_1,
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(solana.token_account_balance(treasury_token_1).await, 20);
+    let position_2 = solana
+        .get_account::<OpenOrdersAccount>(account_2)
+        .await
+        .position;
+    assert_eq!(position_2.referrer_rebates_available, 0);
+    assert_eq!(position_2.quote_free_native, 99980);
+
+    let market_acc = solana.get_account::<Market>(market).await;
+    assert_eq!(market_acc.fees_to_referrers, 10);
+    assert_eq!(market_acc.referrer_rebates_accrued, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sweep_quote_dust() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        collect_fee_admin,
+        owner,
+        mints,
+        owner_token_1,
+        market,
+        market_quote_vault,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize::default()).await?;
+    let solana = &context.solana.clone();
+
+    // quote tokens nobody is owed
+    solana
+        .process_transaction(
+            &[spl_token::instruction::transfer(
+                &spl_token::id(),
+                &owner_token_1,
+                &market_quote_vault,
+                &owner.pubkey(),
+                &[],
+                7,
+            )
+            .unwrap()],
+            Some(&[owner]),
+        )
+        .await
+        .unwrap();
+
+    // anyone can sweep, repeating it finds nothing new
+    for _ in 0..2 {
+        send_tx(solana, SweepQuoteDustInstruction { market })
+            .await
+            .unwrap();
+        let market = solana.get_account::<Market>(market).await;
+        assert_eq!(market.fees_available, 7);
+        assert_eq!(
+            market.quote_deposit_total,
+            solana.token_account_balance(market_quote_vault).await
+        );
+    }
+
+    let admin_token_1 = solana
+        .create_associated_token_account(&collect_fee_admin.pubkey(), mints[1].pubkey)
+        .await;
+    send_tx(
+        solana,
+        SweepFeesInstruction {
+            collect_fee_admin,
+            market,
+            market_quote_vault,
+            token_receiver_account: admin_token_1,
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(solana.token_account_balance(admin_token_1).await, 7);
+    let market = solana.get_account::<Market>(market).await;
+    assert_eq!(
+        market.quote_deposit_total,
+        solana.token_account_balance(market_quote_vault).await
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_taker_fee_rounding() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        collect_fee_admin,
+        owner,
+        owner_token_0,
+        owner_token_1,
+        market,
+        market_base_vault,
+        market_quote_vault,
+        price_lots,
+        account_1,
+        account_2,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize {
+        maker_fee: 0,
+        taker_fee: 205,
+        with_oracle: false,
+        ..TestNewMarketInitialize::default()
+    })
+    .await?;
+    let solana = &context.solana.clone();
+
+    // account_2 takes 1 lot, 100000 native quote, paying 20.5 in fees
+    let fill = || async {
+        for (open_orders_account, side, user_token_account, market_vault) in [
+            (account_1, Side::Bid, owner_token_1, market_quote_vault),
+            (account_2, Side::Ask, owner_token_0, market_base_vault),
+        ] {
+            send_tx(
+                solana,
+                PlaceOrderInstruction {
+                    open_orders_account,
+                    open_orders_admin: None,
+                    market,
+                    signer: owner,
+                    user_token_account,
+                    market_vault,
+                    side,
+                    price_lots,
+                    max_base_lots: 1,
+                    max_quote_lots_including_fees: 10000,
+                    client_order_id: 0,
+                    expiry_timestamp: 0,
+                    order_type: PlaceOrderType::Limit,
+                    self_trade_behavior: SelfTradeBehavior::default(),
+                    remainings: vec![],
+                },
+            )
+            .await
+            .unwrap();
+        }
+    };
+
+    fill().await;
+    {
+        let open_orders_account_2 = solana.get_account::<OpenOrdersAccount>(account_2).await;
+        assert_eq!(open_orders_account_2.position.taker_fees_paid, 21);
+        assert_eq!(open_orders_account_2.position.quote_free_native, 99979);
+    }
+
+    send_tx(
+        solana,
+        SetFeeRoundingInstruction {
+            collect_fee_admin,
+            market,
+            taker_fee_rounding: FeeRounding::Down,
+        },
+    )
+    .await
+    .unwrap();
+
+    fill().await;
+    {
+        let open_orders_account_2 = solana.get_account::<OpenOrdersAccount>(account_2).await;
+        assert_eq!(open_orders_account_2.position.taker_fees_paid, 21 + 20);
+        assert_eq!(
+            open_orders_account_2.position.quote_free_native,
+            99979 + 99980
+        );
+    }
+
+    Ok(())
+}
@@ -125,6 +125,17 @@ async fn test_fill_or_kill_order() -> Result<(), TransportError> {
         OpenBookError::WouldExecutePartially.error_code(),
         "Should kill order".into(),
     );
+
+    // the killed order still logs how far it got
+    let logs = solana.program_log_events::<openbook_v2::logs::TakerExecutionLog>();
+    assert_eq!(logs.len(), 1);
+    assert_eq!(logs[0].max_base_lots, 6);
+    assert_eq!(logs[0].base_lots_filled, 4);
+    assert_eq!(
+        logs[0].reason,
+        u8::from(openbook_v2::logs::TakerStopReason::BookExhausted)
+    );
+    assert!(logs[0].aborted);
     Ok(())
 }
 
@@ -184,6 +195,7 @@ async fn consume_and_settle(
             user_base_account: owner_token_0,
             user_quote_account: owner_token_1,
             referrer_account: None,
+            remainings: vec![],
         },
     )
     .await
@@ -200,6 +212,7 @@ async fn consume_and_settle(
             user_base_account: owner_token_0,
             user_quote_account: owner_token_1,
             referrer_account: None,
+            remainings: vec![],
         },
     )
     .await
@@ -669,6 +669,7 @@ async fn test_locked_amounts() -> Result<(), TransportError> {
         user_base_account: owner_base_ata,
         user_quote_account: owner_quote_ata,
         referrer_account: None,
+        remainings: vec![],
     };
 
     let settle_funds_1_ix = SettleFundsInstruction {
@@ -132,6 +132,7 @@ async fn test_immediate_order() -> Result<(), TransportError> {
             user_base_account: owner_token_0,
             user_quote_account: owner_token_1,
             referrer_account: None,
+            remainings: vec![],
         },
     )
     .await
@@ -312,3 +313,427 @@ async fn test_immediate_order() -> Result<(), TransportError> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_order_rate_limit() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        collect_fee_admin,
+        owner,
+        payer,
+        owner_token_1,
+        market,
+        market_quote_vault,
+        price_lots,
+        tokens,
+        account_1,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize::default()).await?;
+    let solana = &context.solana.clone();
+
+    set_stub_oracle_price(solana, &tokens[1], collect_fee_admin, 1000.0).await;
+
+    // a limit needs a window
+    assert!(send_tx(
+        solana,
+        SetOrderRateLimitInstruction {
+            collect_fee_admin,
+            market,
+            max_orders: 2,
+            window_seconds: 0,
+        },
+    )
+    .await
+    .is_err());
+
+    send_tx(
+        solana,
+        SetOrderRateLimitInstruction {
+            collect_fee_admin,
+            market,
+            max_orders: 2,
+            window_seconds: 60,
+        },
+    )
+    .await
+    .unwrap();
+
+    let position_extension = get_position_extension_address(account_1);
+    let place_bid = |client_order_id: u64| {
+        send_tx(
+            solana,
+            PlaceOrderInstruction {
+                open_orders_account: account_1,
+                open_orders_admin: None,
+                market,
+                signer: owner,
+                user_token_account: owner_token_1,
+                market_vault: market_quote_vault,
+                side: Side::Bid,
+                price_lots,
+                max_base_lots: 1,
+                max_quote_lots_including_fees: 10000,
+                client_order_id,
+                expiry_timestamp: 0,
+                order_type: PlaceOrderType::Limit,
+                self_trade_behavior: SelfTradeBehavior::default(),
+                remainings: vec![position_extension],
+            },
+        )
+    };
+
+    // the window is kept in the position extension
+    assert!(place_bid(1).await.is_err());
+    send_tx(
+        solana,
+        CreatePositionExtensionInstruction {
+            owner,
+            payer,
+            open_orders_account: account_1,
+        },
+    )
+    .await
+    .unwrap();
+
+    place_bid(1).await.unwrap();
+    place_bid(2).await.unwrap();
+    assert!(place_bid(3).await.is_err());
+
+    // the limit resets with the next window
+    solana.advance_clock(60).await;
+    place_bid(3).await.unwrap();
+
+    {
+        let position_extension = solana
+            .get_account::<PositionExtension>(position_extension)
+            .await;
+        assert_eq!(position_extension.orders_in_window, 1);
+        let open_orders_account = solana.get_account::<OpenOrdersAccount>(account_1).await;
+        assert_eq!(open_orders_account.all_orders_in_use().count(), 3);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_set_eviction_policy() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        collect_fee_admin,
+        owner,
+        market,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize::default()).await?;
+    let solana = &context.solana.clone();
+
+    {
+        let market = solana.get_account::<Market>(market).await;
+        assert_eq!(market.eviction_policy(), EvictionPolicy::EvictWorstPrice);
+    }
+
+    // only the market's admin chooses the policy
+    assert!(send_tx(
+        solana,
+        SetEvictionPolicyInstruction {
+            collect_fee_admin: owner,
+            market,
+            policy: EvictionPolicy::RejectIncoming,
+            band_bps: 0,
+        },
+    )
+    .await
+    .is_err());
+
+    // evicting orders out of the band needs a band
+    let result = send_tx(
+        solana,
+        SetEvictionPolicyInstruction {
+            collect_fee_admin,
+            market,
+            policy: EvictionPolicy::EvictOldestOutOfBand,
+            band_bps: 0,
+        },
+    )
+    .await;
+    assert_openbook_error(
+        &result,
+        OpenBookError::InvalidInputEvictionBand.error_code(),
+        "band of zero".into(),
+    );
+
+    send_tx(
+        solana,
+        SetEvictionPolicyInstruction {
+            collect_fee_admin,
+            market,
+            policy: EvictionPolicy::EvictOldestOutOfBand,
+            band_bps: 500,
+        },
+    )
+    .await
+    .unwrap();
+
+    {
+        let market = solana.get_account::<Market>(market).await;
+        assert_eq!(
+            market.eviction_policy(),
+            EvictionPolicy::EvictOldestOutOfBand
+        );
+        assert_eq!(market.eviction_band_bps, 500);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_min_order_notional() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        collect_fee_admin,
+        owner,
+        owner_token_0,
+        owner_token_1,
+        market,
+        market_base_vault,
+        market_quote_vault,
+        price_lots,
+        tokens,
+        account_1,
+        account_2,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize {
+        min_order_notional: 200_000,
+        ..TestNewMarketInitialize::default()
+    })
+    .await?;
+    let solana = &context.solana.clone();
+
+    set_stub_oracle_price(solana, &tokens[1], collect_fee_admin, 1000.0).await;
+
+    // a base lot is worth 100_000 quote native
+    let place_bid = |max_base_lots: i64, client_order_id: u64| {
+        send_tx(
+            solana,
+            PlaceOrderInstruction {
+                open_orders_account: account_1,
+                open_orders_admin: None,
+                market,
+                signer: owner,
+                user_token_account: owner_token_1,
+                market_vault: market_quote_vault,
+                side: Side::Bid,
+                price_lots,
+                max_base_lots,
+                max_quote_lots_including_fees: 30000,
+                client_order_id,
+                expiry_timestamp: 0,
+                order_type: PlaceOrderType::Limit,
+                self_trade_behavior: SelfTradeBehavior::default(),
+                remainings: vec![],
+            },
+        )
+    };
+
+    assert!(place_bid(1, 1).await.is_err());
+    place_bid(2, 2).await.unwrap();
+
+    // fills don't count, an order taking a single lot at any price goes through
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            open_orders_account: account_2,
+            open_orders_admin: None,
+            market,
+            signer: owner,
+            user_token_account: owner_token_0,
+            market_vault: market_base_vault,
+            side: Side::Ask,
+            price_lots: 1,
+            max_base_lots: 1,
+            max_quote_lots_including_fees: 10000,
+            client_order_id: 0,
+            expiry_timestamp: 0,
+            order_type: PlaceOrderType::ImmediateOrCancel,
+            self_trade_behavior: SelfTradeBehavior::default(),
+            remainings: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    // only the collect fee admin can change the minimum
+    assert!(send_tx(
+        solana,
+        SetMinOrderNotionalInstruction {
+            collect_fee_admin: owner,
+            market,
+            min_order_notional: 0,
+        },
+    )
+    .await
+    .is_err());
+
+    send_tx(
+        solana,
+        SetMinOrderNotionalInstruction {
+            collect_fee_admin,
+            market,
+            min_order_notional: 0,
+        },
+    )
+    .await
+    .unwrap();
+
+    place_bid(1, 3).await.unwrap();
+
+    {
+        let market = solana.get_account::<Market>(market).await;
+        assert_eq!(market.min_order_notional, 0);
+        assert_eq!(market.bids_quote_lots_total, 30000);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_order_rejection_errors() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        collect_fee_admin,
+        owner,
+        owner_token_1,
+        market,
+        market_quote_vault,
+        price_lots,
+        tokens,
+        account_1,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize::default()).await?;
+    let solana = &context.solana.clone();
+
+    set_stub_oracle_price(solana, &tokens[1], collect_fee_admin, 1000.0).await;
+
+    let place_bid = PlaceOrderInstruction {
+        open_orders_account: account_1,
+        open_orders_admin: None,
+        market,
+        signer: owner,
+        user_token_account: owner_token_1,
+        market_vault: market_quote_vault,
+        side: Side::Bid,
+        price_lots,
+        max_base_lots: 1,
+        max_quote_lots_including_fees: 10100,
+        client_order_id: 0,
+        expiry_timestamp: 0,
+        order_type: PlaceOrderType::Limit,
+        self_trade_behavior: SelfTradeBehavior::default(),
+        remainings: vec![],
+    };
+
+    // a base lot is worth 100_000 quote native, more than the wallet holds
+    let balance = solana.token_account_balance(owner_token_1).await;
+    let max_base_lots = (balance / 100_000 + 1) as i64;
+    assert_openbook_error(
+        &send_tx(
+            solana,
+            PlaceOrderInstruction {
+                max_base_lots,
+                max_quote_lots_including_fees: max_base_lots * price_lots * 2,
+                ..place_bid.clone()
+            },
+        )
+        .await,
+        OpenBookError::InsufficientFunds.error_code(),
+        "not enough tokens".into(),
+    );
+
+    send_tx(solana, place_bid).await.unwrap();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_max_resting_orders() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        collect_fee_admin,
+        owner,
+        owner_token_1,
+        market,
+        market_quote_vault,
+        price_lots,
+        tokens,
+        account_1,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize {
+        max_resting_orders: 2,
+        ..TestNewMarketInitialize::default()
+    })
+    .await?;
+    let solana = &context.solana.clone();
+
+    set_stub_oracle_price(solana, &tokens[1], collect_fee_admin, 1000.0).await;
+
+    let place_bid = |client_order_id: u64, order_type: PlaceOrderType| {
+        send_tx(
+            solana,
+            PlaceOrderInstruction {
+                open_orders_account: account_1,
+                open_orders_admin: None,
+                market,
+                signer: owner,
+                user_token_account: owner_token_1,
+                market_vault: market_quote_vault,
+                side: Side::Bid,
+                price_lots: price_lots - client_order_id as i64,
+                max_base_lots: 1,
+                max_quote_lots_including_fees: 10000,
+                client_order_id,
+                expiry_timestamp: 0,
+                order_type,
+                self_trade_behavior: SelfTradeBehavior::default(),
+                remainings: vec![],
+            },
+        )
+    };
+
+    place_bid(1, PlaceOrderType::Limit).await.unwrap();
+    place_bid(2, PlaceOrderType::Limit).await.unwrap();
+    assert!(place_bid(3, PlaceOrderType::Limit).await.is_err());
+    // orders that don't rest on the book aren't capped
+    place_bid(4, PlaceOrderType::ImmediateOrCancel)
+        .await
+        .unwrap();
+
+    assert!(send_tx(
+        solana,
+        SetMaxRestingOrdersInstruction {
+            collect_fee_admin,
+            market,
+            max_resting_orders: MAX_OPEN_ORDERS as u8 + 1,
+        },
+    )
+    .await
+    .is_err());
+
+    send_tx(
+        solana,
+        SetMaxRestingOrdersInstruction {
+            collect_fee_admin,
+            market,
+            max_resting_orders: 3,
+        },
+    )
+    .await
+    .unwrap();
+
+    place_bid(3, PlaceOrderType::Limit).await.unwrap();
+
+    {
+        let open_orders_account = solana.get_account::<OpenOrdersAccount>(account_1).await;
+        assert_eq!(open_orders_account.all_orders_in_use().count(), 3);
+    }
+
+    Ok(())
+}
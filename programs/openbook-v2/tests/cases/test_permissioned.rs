@@ -357,9 +357,15 @@ async fn test_close_market_admin() -> Result<(), TransportError> {
     .await
     .unwrap();
 
+    let fee_admin_base_ata = solana
+        .create_associated_token_account(&collect_fee_admin.pubkey(), mints[0].pubkey)
+        .await;
+
     let close_ix = CloseMarketInstruction {
         close_market_admin,
         market,
+        base_dust_receiver: fee_admin_base_ata,
+        quote_dust_receiver: fee_admin_ata,
         sol_destination: owner.pubkey(),
     };
 
@@ -467,14 +473,49 @@ async fn test_close_market_admin() -> Result<(), TransportError> {
             user_base_account: owner_token_0,
             user_quote_account: owner_token_1,
             referrer_account: None,
+            remainings: vec![],
         },
     )
     .await
     .unwrap();
 
+    // the close_market_admin can only send the funds to the owner's accounts
+    let third_party = TestKeypair::new();
+    let third_party_token_0 = solana
+        .create_token_account(&third_party.pubkey(), mints[0].pubkey)
+        .await;
+    let third_party_token_1 = solana
+        .create_token_account(&third_party.pubkey(), mints[1].pubkey)
+        .await;
+    assert!(send_tx(
+        solana,
+        SettleFundsExpiredInstruction {
+            user_base_account: third_party_token_0,
+            user_quote_account: third_party_token_1,
+            ..settle_funds_expired_ix.clone()
+        },
+    )
+    .await
+    .is_err());
+
     // which can be even be called by the close_market_admin once the market is expired so it
     // doesn't have to wait for the users!
-    send_tx(solana, settle_funds_expired_ix).await.unwrap();
+    send_tx(solana, settle_funds_expired_ix.clone())
+        .await
+        .unwrap();
+
+    // the owner can also sign to send them anywhere, nothing is left to settle by now
+    send_tx(
+        solana,
+        SettleFundsExpiredInstruction {
+            owner,
+            user_base_account: third_party_token_0,
+            user_quote_account: third_party_token_1,
+            ..settle_funds_expired_ix
+        },
+    )
+    .await
+    .unwrap();
 
     // but wait! the're still pending fees
     {
@@ -496,8 +537,16 @@ async fn test_close_market_admin() -> Result<(), TransportError> {
     .unwrap();
 
     // Boom
+    let vault_rent = solana.get_account_lamports(market_base_vault).await
+        + solana.get_account_lamports(market_quote_vault).await;
+    let owner_lamports = solana.get_account_lamports(owner.pubkey()).await;
     send_tx(solana, close_ix.clone()).await.unwrap();
 
+    // the vaults are closed too
+    assert!(solana.get_account_data(market_base_vault).await.is_none());
+    assert!(solana.get_account_data(market_quote_vault).await.is_none());
+    assert!(solana.get_account_lamports(owner.pubkey()).await > owner_lamports + vault_rent);
+
     Ok(())
 }
 
@@ -607,3 +656,1096 @@ async fn test_delegate() -> Result<(), TransportError> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_market_state() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        collect_fee_admin,
+        owner,
+        owner_token_1,
+        market,
+        market_quote_vault,
+        price_lots,
+        tokens,
+        account_1,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize::default()).await?;
+    let solana = &context.solana.clone();
+
+    set_stub_oracle_price(solana, &tokens[1], collect_fee_admin, 1000.0).await;
+
+    let place_bid = |order_type| PlaceOrderInstruction {
+        open_orders_account: account_1,
+        open_orders_admin: None,
+        market,
+        signer: owner,
+        user_token_account: owner_token_1,
+        market_vault: market_quote_vault,
+        side: Side::Bid,
+        price_lots,
+        max_base_lots: 1,
+        max_quote_lots_including_fees: 10000,
+        client_order_id: 0,
+        expiry_timestamp: 0,
+        order_type,
+        self_trade_behavior: SelfTradeBehavior::default(),
+        remainings: vec![],
+    };
+    let set_state = |state| SetMarketStateInstruction {
+        collect_fee_admin,
+        market,
+        state,
+    };
+    let cancel_all = CancelAllOrdersInstruction {
+        signer: owner,
+        market,
+        open_orders_account: account_1,
+    };
+
+    // only the collect fee admin can change the state
+    assert!(send_tx(
+        solana,
+        SetMarketStateInstruction {
+            collect_fee_admin: owner,
+            market,
+            state: MarketState::Paused,
+        },
+    )
+    .await
+    .is_err());
+
+    send_tx(solana, place_bid(PlaceOrderType::Limit))
+        .await
+        .unwrap();
+
+    send_tx(solana, set_state(MarketState::Paused))
+        .await
+        .unwrap();
+    assert_openbook_error(
+        &send_tx(solana, place_bid(PlaceOrderType::Limit)).await,
+        OpenBookError::MarketIsPaused.error_code(),
+        "no orders while paused".into(),
+    );
+    assert_openbook_error(
+        &send_tx(solana, cancel_all.clone()).await,
+        OpenBookError::MarketIsPaused.error_code(),
+        "no cancels while paused".into(),
+    );
+
+    send_tx(solana, set_state(MarketState::CancelOnly))
+        .await
+        .unwrap();
+    assert_openbook_error(
+        &send_tx(solana, place_bid(PlaceOrderType::PostOnly)).await,
+        OpenBookError::MarketIsCancelOnly.error_code(),
+        "no orders while cancel only".into(),
+    );
+    send_tx(solana, cancel_all).await.unwrap();
+
+    send_tx(solana, set_state(MarketState::PostOnly))
+        .await
+        .unwrap();
+    assert_openbook_error(
+        &send_tx(solana, place_bid(PlaceOrderType::Limit)).await,
+        OpenBookError::MarketIsPostOnly.error_code(),
+        "only post only orders".into(),
+    );
+    send_tx(solana, place_bid(PlaceOrderType::PostOnly))
+        .await
+        .unwrap();
+
+    send_tx(solana, set_state(MarketState::Active))
+        .await
+        .unwrap();
+    send_tx(solana, place_bid(PlaceOrderType::Limit))
+        .await
+        .unwrap();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_settle_expired_market() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        close_market_admin,
+        collect_fee_admin,
+        owner,
+        owner_token_1,
+        market,
+        market_quote_vault,
+        price_lots,
+        tokens,
+        account_1,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize {
+        close_market_admin_bool: true,
+        ..TestNewMarketInitialize::default()
+    })
+    .await?;
+    let solana = &context.solana.clone();
+
+    set_stub_oracle_price(solana, &tokens[1], collect_fee_admin, 1000.0).await;
+
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            open_orders_account: account_1,
+            open_orders_admin: None,
+            market,
+            signer: owner,
+            user_token_account: owner_token_1,
+            market_vault: market_quote_vault,
+            side: Side::Bid,
+            price_lots,
+            max_base_lots: 1,
+            max_quote_lots_including_fees: 10000,
+            client_order_id: 0,
+            expiry_timestamp: 0,
+            order_type: PlaceOrderType::Limit,
+            self_trade_behavior: SelfTradeBehavior::default(),
+            remainings: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let settle_ix = SettleExpiredMarketInstruction {
+        close_market_admin,
+        market,
+        settlement_price_lots: None,
+        open_orders_accounts: vec![account_1],
+    };
+
+    assert_openbook_error(
+        &send_tx(solana, settle_ix.clone()).await,
+        OpenBookError::MarketHasNotExpired.error_code(),
+        "market has not expired".into(),
+    );
+
+    send_tx(
+        solana,
+        SetMarketExpiredInstruction {
+            close_market_admin,
+            market,
+        },
+    )
+    .await
+    .unwrap();
+
+    // only the close market admin can settle
+    assert!(send_tx(
+        solana,
+        SettleExpiredMarketInstruction {
+            close_market_admin: owner,
+            ..settle_ix.clone()
+        },
+    )
+    .await
+    .is_err());
+
+    // markets with an oracle settle at the oracle price
+    assert_openbook_error(
+        &send_tx(
+            solana,
+            SettleExpiredMarketInstruction {
+                settlement_price_lots: Some(1),
+                ..settle_ix.clone()
+            },
+        )
+        .await,
+        OpenBookError::InvalidInputSettlementPrice.error_code(),
+        "price given for a market with an oracle".into(),
+    );
+
+    send_tx(solana, settle_ix).await.unwrap();
+
+    {
+        let market = solana.get_account::<Market>(market).await;
+        assert!(market.settlement_price_lots > 0);
+        assert_eq!(market.market_state(), MarketState::CancelOnly);
+
+        let open_orders_account_1 = solana.get_account::<OpenOrdersAccount>(account_1).await;
+        assert_eq!(open_orders_account_1.position.bids_base_lots, 0);
+    }
+
+    // the settled state is final
+    assert_openbook_error(
+        &send_tx(
+            solana,
+            SetMarketStateInstruction {
+                collect_fee_admin,
+                market,
+                state: MarketState::Active,
+            },
+        )
+        .await,
+        OpenBookError::MarketIsSettled.error_code(),
+        "market is settled".into(),
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_settle_expired_market_without_oracle() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        close_market_admin,
+        market,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize {
+        close_market_admin_bool: true,
+        with_oracle: false,
+        ..TestNewMarketInitialize::default()
+    })
+    .await?;
+    let solana = &context.solana.clone();
+
+    send_tx(
+        solana,
+        SetMarketExpiredInstruction {
+            close_market_admin,
+            market,
+        },
+    )
+    .await
+    .unwrap();
+
+    let settle_ix = SettleExpiredMarketInstruction {
+        close_market_admin,
+        market,
+        settlement_price_lots: None,
+        open_orders_accounts: vec![],
+    };
+
+    // the admin gives the price of markets without an oracle
+    assert_openbook_error(
+        &send_tx(solana, settle_ix.clone()).await,
+        OpenBookError::InvalidInputSettlementPrice.error_code(),
+        "no price for a market without an oracle".into(),
+    );
+
+    send_tx(
+        solana,
+        SettleExpiredMarketInstruction {
+            settlement_price_lots: Some(1500),
+            ..settle_ix
+        },
+    )
+    .await
+    .unwrap();
+
+    let market = solana.get_account::<Market>(market).await;
+    assert_eq!(market.settlement_price_lots, 1500);
+    assert_eq!(market.market_state(), MarketState::CancelOnly);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_binary_outcome() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        close_market_admin,
+        collect_fee_admin,
+        owner,
+        owner_token_1,
+        market,
+        market_quote_vault,
+        price_lots,
+        tokens,
+        account_1,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize {
+        close_market_admin_bool: true,
+        ..TestNewMarketInitialize::default()
+    })
+    .await?;
+    let solana = &context.solana.clone();
+    let set_outcome = |outcome| SetBinaryOutcomeInstruction {
+        collect_fee_admin,
+        market,
+        outcome,
+    };
+
+    set_stub_oracle_price(solana, &tokens[1], collect_fee_admin, 1000.0).await;
+
+    // can't resolve a market that isn't binary, nor before expiry
+    assert!(send_tx(solana, set_outcome(BinaryOutcome::Yes))
+        .await
+        .is_err());
+
+    send_tx(solana, set_outcome(BinaryOutcome::Unresolved))
+        .await
+        .unwrap();
+
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            open_orders_account: account_1,
+            open_orders_admin: None,
+            market,
+            signer: owner,
+            user_token_account: owner_token_1,
+            market_vault: market_quote_vault,
+            side: Side::Bid,
+            price_lots,
+            max_base_lots: 1,
+            max_quote_lots_including_fees: 10000,
+            client_order_id: 0,
+            expiry_timestamp: 0,
+            order_type: PlaceOrderType::Limit,
+            self_trade_behavior: SelfTradeBehavior::default(),
+            remainings: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    send_tx(
+        solana,
+        SetMarketExpiredInstruction {
+            close_market_admin,
+            market,
+        },
+    )
+    .await
+    .unwrap();
+
+    let settle_ix = SettleExpiredMarketInstruction {
+        close_market_admin,
+        market,
+        settlement_price_lots: None,
+        open_orders_accounts: vec![account_1],
+    };
+
+    // the oracle price doesn't settle binary outcome markets
+    assert_openbook_error(
+        &send_tx(solana, settle_ix.clone()).await,
+        OpenBookError::BinaryOutcomeUnresolved.error_code(),
+        "binary outcome is unresolved".into(),
+    );
+
+    send_tx(solana, set_outcome(BinaryOutcome::Yes))
+        .await
+        .unwrap();
+    send_tx(solana, settle_ix).await.unwrap();
+
+    {
+        let market = solana.get_account::<Market>(market).await;
+        assert_eq!(market.binary_outcome(), BinaryOutcome::Yes);
+        assert_eq!(
+            market.settlement_price_lots,
+            market.binary_outcome_price_lots().unwrap()
+        );
+        assert_eq!(market.market_state(), MarketState::CancelOnly);
+
+        let open_orders_account_1 = solana.get_account::<OpenOrdersAccount>(account_1).await;
+        assert_eq!(open_orders_account_1.position.bids_base_lots, 0);
+    }
+
+    assert_openbook_error(
+        &send_tx(solana, set_outcome(BinaryOutcome::No)).await,
+        OpenBookError::MarketIsSettled.error_code(),
+        "resolution is final".into(),
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_market_authority_transfer() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        close_market_admin,
+        collect_fee_admin,
+        owner,
+        payer,
+        market,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize {
+        close_market_admin_bool: true,
+        ..TestNewMarketInitialize::default()
+    })
+    .await?;
+    let solana = &context.solana.clone();
+    let new_admin = TestKeypair::new();
+    let pending_market_admin = get_pending_market_admin_address(market);
+
+    // only the current holder of the role can offer it
+    assert_openbook_error(
+        &send_tx(
+            solana,
+            SetMarketAuthorityInstruction {
+                payer,
+                admin: collect_fee_admin,
+                market,
+                role: MarketAdmin::CloseMarket,
+                new_admin: new_admin.pubkey(),
+            },
+        )
+        .await,
+        OpenBookError::InvalidMarketAdmin.error_code(),
+        "not the close market admin".into(),
+    );
+
+    send_tx(
+        solana,
+        SetMarketAuthorityInstruction {
+            payer,
+            admin: close_market_admin,
+            market,
+            role: MarketAdmin::CloseMarket,
+            new_admin: new_admin.pubkey(),
+        },
+    )
+    .await
+    .unwrap();
+
+    assert_openbook_error(
+        &send_tx(
+            solana,
+            AcceptMarketAuthorityInstruction {
+                pending_admin: owner,
+                market,
+            },
+        )
+        .await,
+        OpenBookError::InvalidPendingMarketAdmin.error_code(),
+        "not the pending admin".into(),
+    );
+
+    {
+        // nothing changes until the transfer is accepted
+        let market = solana.get_account::<Market>(market).await;
+        assert_eq!(market.close_market_admin, close_market_admin.pubkey());
+        let pending = solana
+            .get_account::<PendingMarketAdmin>(pending_market_admin)
+            .await;
+        assert_eq!(pending.role(), MarketAdmin::CloseMarket);
+        assert_eq!(pending.new_admin, new_admin.pubkey());
+    }
+
+    // the offer can be withdrawn by the current admin only
+    assert_openbook_error(
+        &send_tx(
+            solana,
+            CancelMarketAuthorityInstruction {
+                admin: collect_fee_admin,
+                market,
+            },
+        )
+        .await,
+        OpenBookError::InvalidMarketAdmin.error_code(),
+        "not the close market admin".into(),
+    );
+
+    send_tx(
+        solana,
+        CancelMarketAuthorityInstruction {
+            admin: close_market_admin,
+            market,
+        },
+    )
+    .await
+    .unwrap();
+    assert!(solana
+        .get_account_data(pending_market_admin)
+        .await
+        .is_none());
+
+    send_tx(
+        solana,
+        SetMarketAuthorityInstruction {
+            payer,
+            admin: close_market_admin,
+            market,
+            role: MarketAdmin::CloseMarket,
+            new_admin: new_admin.pubkey(),
+        },
+    )
+    .await
+    .unwrap();
+
+    send_tx(
+        solana,
+        AcceptMarketAuthorityInstruction {
+            pending_admin: new_admin,
+            market,
+        },
+    )
+    .await
+    .unwrap();
+    assert!(solana
+        .get_account_data(pending_market_admin)
+        .await
+        .is_none());
+
+    {
+        let market = solana.get_account::<Market>(market).await;
+        assert_eq!(market.close_market_admin, new_admin.pubkey());
+        assert_eq!(market.collect_fee_admin, collect_fee_admin.pubkey());
+    }
+
+    // the previous admin lost the role
+    assert!(send_tx(
+        solana,
+        SetMarketAuthorityInstruction {
+            payer,
+            admin: close_market_admin,
+            market,
+            role: MarketAdmin::CloseMarket,
+            new_admin: close_market_admin.pubkey(),
+        },
+    )
+    .await
+    .is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_merkle_allowlist() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        collect_fee_admin,
+        owner,
+        payer,
+        owner_token_1,
+        market,
+        market_quote_vault,
+        price_lots,
+        tokens,
+        account_1,
+        open_orders_admin,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize {
+        open_orders_admin_bool: true,
+        ..TestNewMarketInitialize::default()
+    })
+    .await?;
+    let solana = &context.solana.clone();
+
+    set_stub_oracle_price(solana, &tokens[1], collect_fee_admin, 1000.0).await;
+
+    let place_bid = PlaceOrderInstruction {
+        open_orders_account: account_1,
+        open_orders_admin: None,
+        market,
+        signer: owner,
+        user_token_account: owner_token_1,
+        market_vault: market_quote_vault,
+        side: Side::Bid,
+        price_lots,
+        max_base_lots: 1,
+        max_quote_lots_including_fees: 10000,
+        client_order_id: 0,
+        expiry_timestamp: 0,
+        order_type: PlaceOrderType::Limit,
+        self_trade_behavior: SelfTradeBehavior::default(),
+        remainings: vec![],
+    };
+
+    // allow owner and someone else
+    let other_leaf = merkle_leaf(&Pubkey::new_unique());
+    let root = merkle_parent(&merkle_leaf(&owner.pubkey()), &other_leaf);
+    send_tx(
+        solana,
+        CreateMerkleAllowlistInstruction {
+            open_orders_admin,
+            payer,
+            market,
+            root,
+        },
+    )
+    .await
+    .unwrap();
+
+    assert!(send_tx(solana, place_bid.clone()).await.is_err());
+
+    assert_openbook_error(
+        &send_tx(
+            solana,
+            JoinMerkleAllowlistInstruction {
+                owner,
+                open_orders_account: account_1,
+                market,
+                proof: vec![merkle_leaf(&Pubkey::new_unique())],
+            },
+        )
+        .await,
+        OpenBookError::InvalidMerkleProof.error_code(),
+        "wrong proof".into(),
+    );
+
+    send_tx(
+        solana,
+        JoinMerkleAllowlistInstruction {
+            owner,
+            open_orders_account: account_1,
+            market,
+            proof: vec![other_leaf],
+        },
+    )
+    .await
+    .unwrap();
+
+    // no open orders admin signature needed anymore
+    send_tx(solana, place_bid.clone()).await.unwrap();
+
+    send_tx(
+        solana,
+        RevokeMerkleAllowlistApprovalInstruction {
+            open_orders_admin,
+            market,
+            open_orders_account: account_1,
+        },
+    )
+    .await
+    .unwrap();
+
+    assert!(send_tx(solana, place_bid).await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_token_gate() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        collect_fee_admin,
+        owner,
+        payer,
+        mints,
+        owner_token_1,
+        market,
+        market_quote_vault,
+        price_lots,
+        tokens,
+        account_1,
+        open_orders_admin,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize {
+        open_orders_admin_bool: true,
+        ..TestNewMarketInitialize::default()
+    })
+    .await?;
+    let solana = &context.solana.clone();
+
+    set_stub_oracle_price(solana, &tokens[1], collect_fee_admin, 1000.0).await;
+
+    let place_bid = PlaceOrderInstruction {
+        open_orders_account: account_1,
+        open_orders_admin: None,
+        market,
+        signer: owner,
+        user_token_account: owner_token_1,
+        market_vault: market_quote_vault,
+        side: Side::Bid,
+        price_lots,
+        max_base_lots: 1,
+        max_quote_lots_including_fees: 10000,
+        client_order_id: 0,
+        expiry_timestamp: 0,
+        order_type: PlaceOrderType::Limit,
+        self_trade_behavior: SelfTradeBehavior::default(),
+        remainings: vec![],
+    };
+
+    // holders of the base token may trade
+    let gate_token_account = solana
+        .create_associated_token_account(&owner.pubkey(), mints[0].pubkey)
+        .await;
+
+    assert_openbook_error(
+        &send_tx(solana, place_bid.clone()).await,
+        OpenBookError::InvalidOpenOrdersAdmin.error_code(),
+        "no token gate yet".into(),
+    );
+
+    send_tx(
+        solana,
+        CreateTokenGateInstruction {
+            open_orders_admin,
+            payer,
+            market,
+            mint: mints[0].pubkey,
+            min_amount: 1,
+        },
+    )
+    .await
+    .unwrap();
+
+    assert_openbook_error(
+        &send_tx(solana, place_bid.clone()).await,
+        OpenBookError::InvalidTokenGateAccount.error_code(),
+        "empty gate token account".into(),
+    );
+
+    // no open orders admin signature needed while holding the token
+    solana.set_account_balance(gate_token_account, 1).await;
+    send_tx(solana, place_bid.clone()).await.unwrap();
+
+    // the holding is checked on every order
+    solana.set_account_balance(gate_token_account, 0).await;
+    assert_openbook_error(
+        &send_tx(solana, place_bid).await,
+        OpenBookError::InvalidTokenGateAccount.error_code(),
+        "token sold".into(),
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_taker_program_allowlist() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        collect_fee_admin,
+        owner,
+        payer,
+        owner_token_0,
+        owner_token_1,
+        market,
+        market_base_vault,
+        market_quote_vault,
+        price_lots,
+        tokens,
+        open_orders_admin,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize {
+        open_orders_admin_bool: true,
+        ..TestNewMarketInitialize::default()
+    })
+    .await?;
+    let solana = &context.solana.clone();
+
+    set_stub_oracle_price(solana, &tokens[1], collect_fee_admin, 1000.0).await;
+
+    let aggregator = Pubkey::new_unique();
+    let taker_program_allowlist = get_taker_program_allowlist_address(market);
+
+    assert_openbook_error(
+        &send_tx(
+            solana,
+            CreateTakerProgramAllowlistInstruction {
+                open_orders_admin,
+                payer,
+                market,
+                programs: (0..9).map(|_| Pubkey::new_unique()).collect(),
+            },
+        )
+        .await,
+        OpenBookError::TooManyTakerPrograms.error_code(),
+        "too many programs".into(),
+    );
+
+    send_tx(
+        solana,
+        CreateTakerProgramAllowlistInstruction {
+            open_orders_admin,
+            payer,
+            market,
+            programs: vec![aggregator],
+        },
+    )
+    .await
+    .unwrap();
+
+    let allowlist = solana
+        .get_account::<TakerProgramAllowlist>(taker_program_allowlist)
+        .await;
+    assert_eq!(allowlist.market, market);
+    assert!(allowlist.contains(&aggregator));
+    assert!(!allowlist.contains(&Pubkey::default()));
+
+    assert_openbook_error(
+        &send_tx(
+            solana,
+            SetTakerProgramAllowlistInstruction {
+                open_orders_admin: owner,
+                market,
+                programs: vec![],
+            },
+        )
+        .await,
+        OpenBookError::InvalidOpenOrdersAdmin.error_code(),
+        "only the open orders admin".into(),
+    );
+
+    send_tx(
+        solana,
+        SetTakerProgramAllowlistInstruction {
+            open_orders_admin,
+            market,
+            programs: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let allowlist = solana
+        .get_account::<TakerProgramAllowlist>(taker_program_allowlist)
+        .await;
+    assert!(!allowlist.contains(&aggregator));
+
+    // direct callers still need the open orders admin signature
+    assert!(send_tx(
+        solana,
+        PlaceTakeOrderInstruction {
+            open_orders_admin: None,
+            market,
+            signer: owner,
+            market_base_vault,
+            market_quote_vault,
+            user_base_account: owner_token_0,
+            user_quote_account: owner_token_1,
+            side: Side::Bid,
+            price_lots,
+            max_base_lots: 1,
+            max_quote_lots_including_fees: 10000,
+            client_order_id: 0,
+        },
+    )
+    .await
+    .is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_force_cancel_orders() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        close_market_admin,
+        collect_fee_admin,
+        owner,
+        owner_token_1,
+        market,
+        market_quote_vault,
+        price_lots,
+        tokens,
+        account_1,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize {
+        close_market_admin_bool: true,
+        ..TestNewMarketInitialize::default()
+    })
+    .await?;
+    let solana = &context.solana.clone();
+
+    set_stub_oracle_price(solana, &tokens[1], collect_fee_admin, 1000.0).await;
+
+    for client_order_id in [1, 2] {
+        send_tx(
+            solana,
+            PlaceOrderInstruction {
+                open_orders_account: account_1,
+                open_orders_admin: None,
+                market,
+                signer: owner,
+                user_token_account: owner_token_1,
+                market_vault: market_quote_vault,
+                side: Side::Bid,
+                price_lots,
+                max_base_lots: 1,
+                max_quote_lots_including_fees: 10000,
+                client_order_id,
+                expiry_timestamp: 0,
+                order_type: PlaceOrderType::Limit,
+                self_trade_behavior: SelfTradeBehavior::default(),
+                remainings: vec![],
+            },
+        )
+        .await
+        .unwrap();
+    }
+
+    // only the close market admin can cancel someone else's orders
+    assert!(send_tx(
+        solana,
+        ForceCancelOrdersInstruction {
+            close_market_admin: owner,
+            market,
+            open_orders_account: account_1,
+        },
+    )
+    .await
+    .is_err());
+
+    // even when the market only allows cancels
+    send_tx(
+        solana,
+        SetMarketStateInstruction {
+            collect_fee_admin,
+            market,
+            state: MarketState::CancelOnly,
+        },
+    )
+    .await
+    .unwrap();
+
+    send_tx(
+        solana,
+        ForceCancelOrdersInstruction {
+            close_market_admin,
+            market,
+            open_orders_account: account_1,
+        },
+    )
+    .await
+    .unwrap();
+
+    {
+        let open_orders_account = solana.get_account::<OpenOrdersAccount>(account_1).await;
+        assert_eq!(open_orders_account.all_orders_in_use().count(), 0);
+        assert_eq!(open_orders_account.position.bids_base_lots, 0);
+        assert_eq!(open_orders_account.position.quote_free_native, 200_000);
+
+        let market = solana.get_account::<Market>(market).await;
+        assert_eq!(market.best_bid_lots, 0);
+        assert_eq!(market.bids_quote_lots_total, 0);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_risk_program() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        collect_fee_admin,
+        owner,
+        owner_token_1,
+        market,
+        market_quote_vault,
+        price_lots,
+        tokens,
+        account_1,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize::default()).await?;
+    let solana = &context.solana.clone();
+    let risk_program = risk_program_id();
+    let position_extension = get_position_extension_address(account_1);
+
+    set_stub_oracle_price(solana, &tokens[1], collect_fee_admin, 1000.0).await;
+
+    // the risk program is kept in the position extension
+    assert!(send_tx(
+        solana,
+        SetRiskProgramInstruction {
+            owner,
+            open_orders_account: account_1,
+            program: Some(risk_program),
+        },
+    )
+    .await
+    .is_err());
+
+    send_tx(
+        solana,
+        CreatePositionExtensionInstruction {
+            owner,
+            payer: owner,
+            open_orders_account: account_1,
+        },
+    )
+    .await
+    .unwrap();
+
+    // only the owner can register a risk program
+    assert!(send_tx(
+        solana,
+        SetRiskProgramInstruction {
+            owner: collect_fee_admin,
+            open_orders_account: account_1,
+            program: Some(risk_program),
+        },
+    )
+    .await
+    .is_err());
+
+    // calling back into openbook itself is not allowed
+    assert!(send_tx(
+        solana,
+        SetRiskProgramInstruction {
+            owner,
+            open_orders_account: account_1,
+            program: Some(openbook_v2::id()),
+        },
+    )
+    .await
+    .is_err());
+
+    send_tx(
+        solana,
+        SetRiskProgramInstruction {
+            owner,
+            open_orders_account: account_1,
+            program: Some(risk_program),
+        },
+    )
+    .await
+    .unwrap();
+
+    let place_bid = |max_base_lots, remainings| PlaceOrderInstruction {
+        open_orders_account: account_1,
+        open_orders_admin: None,
+        market,
+        signer: owner,
+        user_token_account: owner_token_1,
+        market_vault: market_quote_vault,
+        side: Side::Bid,
+        price_lots,
+        max_base_lots,
+        max_quote_lots_including_fees: 10000,
+        client_order_id: 0,
+        expiry_timestamp: 0,
+        order_type: PlaceOrderType::Limit,
+        self_trade_behavior: SelfTradeBehavior::default(),
+        remainings,
+    };
+
+    // the risk program is required to place orders
+    assert_openbook_error(
+        &send_tx(solana, place_bid(1, vec![position_extension])).await,
+        OpenBookError::MissingRiskProgramAccount.error_code(),
+        "Risk program required".into(),
+    );
+
+    send_tx(solana, place_bid(1, vec![position_extension, risk_program]))
+        .await
+        .unwrap();
+    let expected_log = format!("check_health {} 1", account_1);
+    assert!(solana
+        .program_log()
+        .iter()
+        .any(|line| line.contains(&expected_log)));
+
+    // the risk program rejects orders above 10 lots
+    assert!(send_tx(
+        solana,
+        place_bid(11, vec![position_extension, risk_program])
+    )
+    .await
+    .is_err());
+
+    send_tx(
+        solana,
+        SetRiskProgramInstruction {
+            owner,
+            open_orders_account: account_1,
+            program: None,
+        },
+    )
+    .await
+    .unwrap();
+
+    send_tx(solana, place_bid(11, vec![position_extension]))
+        .await
+        .unwrap();
+
+    Ok(())
+}
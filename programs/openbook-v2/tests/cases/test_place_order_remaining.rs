@@ -377,3 +377,309 @@ async fn test_place_order_taker_fees() -> Result<(), TransportError> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_place_order_settles_makers_directly() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        collect_fee_admin,
+        owner,
+        owner_token_0,
+        market,
+        market_base_vault,
+        market_quote_vault,
+        price_lots,
+        tokens,
+        account_2,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize::default()).await?;
+    let solana = &context.solana.clone();
+
+    set_stub_oracle_price(solana, &tokens[1], collect_fee_admin, 1000.0).await;
+
+    let maker = &context.users[1];
+    let maker_account = create_open_orders_account(solana, maker.key, market, 1, maker, None).await;
+
+    for _ in 0..2 {
+        send_tx(
+            solana,
+            PlaceOrderInstruction {
+                open_orders_account: maker_account,
+                open_orders_admin: None,
+                market,
+                signer: maker.key,
+                user_token_account: maker.token_accounts[1],
+                market_vault: market_quote_vault,
+                side: Side::Bid,
+                price_lots,
+                max_base_lots: 1,
+                max_quote_lots_including_fees: 10000,
+                client_order_id: 0,
+                expiry_timestamp: 0,
+                order_type: PlaceOrderType::Limit,
+                self_trade_behavior: SelfTradeBehavior::default(),
+                remainings: vec![],
+            },
+        )
+        .await
+        .unwrap();
+    }
+
+    let maker_base_balance = solana.token_account_balance(maker.token_accounts[0]).await;
+    let vault_base_balance = solana.token_account_balance(market_base_vault).await;
+
+    let sell_to_maker = PlaceOrderInstruction {
+        open_orders_account: account_2,
+        open_orders_admin: None,
+        market,
+        signer: owner,
+        user_token_account: owner_token_0,
+        market_vault: market_base_vault,
+        side: Side::Ask,
+        price_lots,
+        max_base_lots: 1,
+        max_quote_lots_including_fees: 10004,
+        client_order_id: 0,
+        expiry_timestamp: 0,
+        order_type: PlaceOrderType::ImmediateOrCancel,
+        self_trade_behavior: SelfTradeBehavior::default(),
+        remainings: vec![maker_account, maker.token_accounts[0]],
+    };
+
+    // Without the maker's consent, its base token account is ignored and the
+    // bought base stays in the vault for settle_funds
+    send_tx(solana, sell_to_maker.clone()).await.unwrap();
+    assert_eq!(
+        solana.token_account_balance(maker.token_accounts[0]).await,
+        maker_base_balance
+    );
+    assert_eq!(
+        solana.token_account_balance(market_base_vault).await,
+        vault_base_balance + 100
+    );
+    let position = solana
+        .get_account::<OpenOrdersAccount>(maker_account)
+        .await
+        .position;
+    assert_eq!(position.base_free_native, 100);
+
+    send_tx(
+        solana,
+        SetDirectSettlementInstruction {
+            enabled: true,
+            owner: maker.key,
+            open_orders_account: maker_account,
+        },
+    )
+    .await
+    .unwrap();
+    let vault_base_balance = vault_base_balance + 100;
+
+    send_tx(solana, sell_to_maker).await.unwrap();
+
+    // The bought base went from the taker to the maker without touching the vault
+    assert_eq!(
+        solana.token_account_balance(maker.token_accounts[0]).await,
+        maker_base_balance + 100
+    );
+    assert_eq!(
+        solana.token_account_balance(market_base_vault).await,
+        vault_base_balance
+    );
+
+    let position = solana
+        .get_account::<OpenOrdersAccount>(maker_account)
+        .await
+        .position;
+    assert_eq!(position.bids_base_lots, 0);
+    assert_eq!(position.base_free_native, 100);
+    // the maker rebates are in quote, they stay on the account
+    assert_eq!(position.quote_free_native, 40);
+
+    let market_acc = solana.get_account::<Market>(market).await;
+    let event_heap = solana.get_account::<EventHeap>(market_acc.event_heap).await;
+    assert_eq!(event_heap.header.count(), 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_place_order_settles_maker_rebates_directly() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        collect_fee_admin,
+        owner,
+        owner_token_1,
+        market,
+        market_base_vault,
+        market_quote_vault,
+        price_lots,
+        tokens,
+        account_1,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize::default()).await?;
+    let solana = &context.solana.clone();
+
+    set_stub_oracle_price(solana, &tokens[1], collect_fee_admin, 1000.0).await;
+
+    let maker = &context.users[1];
+    let maker_account = create_open_orders_account(solana, maker.key, market, 1, maker, None).await;
+
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            open_orders_account: maker_account,
+            open_orders_admin: None,
+            market,
+            signer: maker.key,
+            user_token_account: maker.token_accounts[0],
+            market_vault: market_base_vault,
+            side: Side::Ask,
+            price_lots,
+            max_base_lots: 1,
+            max_quote_lots_including_fees: 10000,
+            client_order_id: 0,
+            expiry_timestamp: 0,
+            order_type: PlaceOrderType::Limit,
+            self_trade_behavior: SelfTradeBehavior::default(),
+            remainings: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    send_tx(
+        solana,
+        SetDirectSettlementInstruction {
+            enabled: true,
+            owner: maker.key,
+            open_orders_account: maker_account,
+        },
+    )
+    .await
+    .unwrap();
+
+    let maker_quote_balance = solana.token_account_balance(maker.token_accounts[1]).await;
+
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            open_orders_account: account_1,
+            open_orders_admin: None,
+            market,
+            signer: owner,
+            user_token_account: owner_token_1,
+            market_vault: market_quote_vault,
+            side: Side::Bid,
+            price_lots,
+            max_base_lots: 1,
+            max_quote_lots_including_fees: 10004,
+            client_order_id: 0,
+            expiry_timestamp: 0,
+            order_type: PlaceOrderType::ImmediateOrCancel,
+            self_trade_behavior: SelfTradeBehavior::default(),
+            remainings: vec![maker_account, maker.token_accounts[1]],
+        },
+    )
+    .await
+    .unwrap();
+
+    // The maker got the quote with its rebate, which can't be claimed again
+    assert_eq!(
+        solana.token_account_balance(maker.token_accounts[1]).await,
+        maker_quote_balance + 100_000 + 20
+    );
+    let position = solana
+        .get_account::<OpenOrdersAccount>(maker_account)
+        .await
+        .position;
+    assert_eq!(position.quote_free_native, 0);
+    assert_eq!(position.maker_rebates_available, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_place_order_settles_maker_rebates_directly() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        collect_fee_admin,
+        owner,
+        owner_token_1,
+        market,
+        market_base_vault,
+        market_quote_vault,
+        price_lots,
+        tokens,
+        account_1,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize::default()).await?;
+    let solana = &context.solana.clone();
+
+    set_stub_oracle_price(solana, &tokens[1], collect_fee_admin, 1000.0).await;
+
+    let maker = &context.users[1];
+    let maker_account = create_open_orders_account(solana, maker.key, market, 1, maker, None).await;
+
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            open_orders_account: maker_account,
+            open_orders_admin: None,
+            market,
+            signer: maker.key,
+            user_token_account: maker.token_accounts[0],
+            market_vault: market_base_vault,
+            side: Side::Ask,
+            price_lots,
+            max_base_lots: 1,
+            max_quote_lots_including_fees: 10000,
+            client_order_id: 0,
+            expiry_timestamp: 0,
+            order_type: PlaceOrderType::Limit,
+            self_trade_behavior: SelfTradeBehavior::default(),
+            remainings: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let maker_quote_balance = solana.token_account_balance(maker.token_accounts[1]).await;
+
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            open_orders_account: account_1,
+            open_orders_admin: None,
+            market,
+            signer: owner,
+            user_token_account: owner_token_1,
+            market_vault: market_quote_vault,
+            side: Side::Bid,
+            price_lots,
+            max_base_lots: 1,
+            max_quote_lots_including_fees: 10004,
+            client_order_id: 0,
+            expiry_timestamp: 0,
+            order_type: PlaceOrderType::ImmediateOrCancel,
+            self_trade_behavior: SelfTradeBehavior::default(),
+            remainings: vec![maker_account, maker.token_accounts[1]],
+        },
+    )
+    .await
+    .unwrap();
+
+    // The maker got the quote with its rebate, which can't be claimed again
+    assert_eq!(
+        solana.token_account_balance(maker.token_accounts[1]).await,
+        maker_quote_balance + 100_000 + 20
+    );
+    let position = solana
+        .get_account::<OpenOrdersAccount>(maker_account)
+        .await
+        .position;
+    assert_eq!(position.quote_free_native, 0);
+    assert_eq!(position.maker_rebates_available, 0);
+
+    Ok(())
+}
@@ -117,6 +117,7 @@ async fn test_self_trade_decrement_take() -> Result<(), TransportError> {
             user_base_account: owner_token_0,
             user_quote_account: owner_token_1,
             referrer_account: None,
+            remainings: vec![],
         },
     )
     .await
@@ -507,6 +508,7 @@ async fn test_self_trade_no_fees() -> Result<(), TransportError> {
         user_base_account: owner_base_ata,
         user_quote_account: owner_quote_ata,
         referrer_account: None,
+        remainings: vec![],
     };
 
     let balances_before = (
@@ -0,0 +1,187 @@
+use super::*;
+use anchor_lang::AnchorSerialize;
+use openbook_v2::{signed_order::SignedOrder, PlaceOrderArgs};
+
+#[tokio::test]
+async fn test_signed_order() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        collect_fee_admin,
+        owner,
+        payer,
+        owner_token_0,
+        owner_token_1,
+        market,
+        market_base_vault,
+        market_quote_vault,
+        price_lots,
+        tokens,
+        account_1,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize::default()).await?;
+    let solana = &context.solana.clone();
+    let relayer = payer;
+
+    set_stub_oracle_price(solana, &tokens[1], collect_fee_admin, 1000.0).await;
+
+    send_tx(
+        solana,
+        DepositInstruction {
+            owner,
+            market,
+            open_orders_account: account_1,
+            market_base_vault,
+            market_quote_vault,
+            user_base_account: owner_token_0,
+            user_quote_account: owner_token_1,
+            base_amount: 100,
+            quote_amount: 0,
+        },
+    )
+    .await
+    .unwrap();
+
+    let signed_order = |nonce, valid_until_ts| SignedOrder {
+        market,
+        open_orders_account: account_1,
+        nonce,
+        valid_until_ts,
+        order: PlaceOrderArgs {
+            side: Side::Ask,
+            price_lots,
+            max_base_lots: 1,
+            max_quote_lots_including_fees: 10000,
+            client_order_id: 7,
+            order_type: PlaceOrderType::Limit,
+            expiry_timestamp: 0,
+            self_trade_behavior: SelfTradeBehavior::default(),
+            limit: 10,
+        },
+    };
+    let place = |signed_order| PlaceSignedOrderInstruction {
+        relayer,
+        signed_order,
+    };
+    let message = |signed_order: SignedOrder| signed_order.try_to_vec().unwrap();
+
+    assert_openbook_error(
+        &send_tx(solana, place(signed_order(1, 0))).await,
+        OpenBookError::InvalidSignedOrderSignature.error_code(),
+        "No signature verified before".into(),
+    );
+
+    assert_openbook_error(
+        &send_tx_with_ed25519_signature(
+            solana,
+            place(signed_order(1, 0)),
+            owner,
+            &message(signed_order(2, 0)),
+        )
+        .await,
+        OpenBookError::InvalidSignedOrderSignature.error_code(),
+        "Signature of another order".into(),
+    );
+
+    assert_openbook_error(
+        &send_tx_with_ed25519_signature(
+            solana,
+            place(signed_order(1, 0)),
+            relayer,
+            &message(signed_order(1, 0)),
+        )
+        .await,
+        OpenBookError::InvalidSignedOrderSigner.error_code(),
+        "Relayer can't sign orders for the owner".into(),
+    );
+
+    assert_openbook_error(
+        &send_tx_with_ed25519_signature(
+            solana,
+            place(signed_order(1, 0)),
+            owner,
+            &message(signed_order(1, 0)),
+        )
+        .await,
+        OpenBookError::MissingPositionExtension.error_code(),
+        "The nonce needs a position extension".into(),
+    );
+
+    send_tx(
+        solana,
+        CreatePositionExtensionInstruction {
+            owner,
+            payer: owner,
+            open_orders_account: account_1,
+        },
+    )
+    .await
+    .unwrap();
+
+    send_tx_with_ed25519_signature(
+        solana,
+        place(signed_order(1, 0)),
+        owner,
+        &message(signed_order(1, 0)),
+    )
+    .await
+    .unwrap();
+
+    let open_orders_account_1 = solana.get_account::<OpenOrdersAccount>(account_1).await;
+    assert_eq!(open_orders_account_1.position.asks_base_lots, 1);
+    assert_eq!(open_orders_account_1.position.base_free_native, 0);
+    assert_eq!(
+        solana
+            .get_account::<PositionExtension>(get_position_extension_address(account_1))
+            .await
+            .signed_order_nonce,
+        1
+    );
+    assert_eq!(
+        open_orders_account_1
+            .all_orders_in_use()
+            .next()
+            .unwrap()
+            .client_id,
+        7
+    );
+
+    // each signed order is placed once at most
+    assert_openbook_error(
+        &send_tx_with_ed25519_signature(
+            solana,
+            place(signed_order(1, 0)),
+            owner,
+            &message(signed_order(1, 0)),
+        )
+        .await,
+        OpenBookError::SignedOrderNonceUsed.error_code(),
+        "Replayed signed order".into(),
+    );
+
+    let now_ts = solana.get_clock().await.unix_timestamp as u64;
+    assert_openbook_error(
+        &send_tx_with_ed25519_signature(
+            solana,
+            place(signed_order(2, now_ts - 1)),
+            owner,
+            &message(signed_order(2, now_ts - 1)),
+        )
+        .await,
+        OpenBookError::SignedOrderExpired.error_code(),
+        "Signed order no longer valid".into(),
+    );
+
+    assert_openbook_error(
+        &send_tx_with_ed25519_signature(
+            solana,
+            place(signed_order(2, 0)),
+            owner,
+            &message(signed_order(2, 0)),
+        )
+        .await,
+        OpenBookError::InsufficientFreeFunds.error_code(),
+        "The deposited base is already on the book".into(),
+    );
+
+    Ok(())
+}
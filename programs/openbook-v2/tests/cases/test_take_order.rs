@@ -1,4 +1,5 @@
 use super::*;
+use bytemuck::cast_ref;
 
 #[tokio::test]
 async fn test_take_ask_order() -> Result<(), TransportError> {
@@ -63,6 +64,7 @@ async fn test_take_ask_order() -> Result<(), TransportError> {
             price_lots,
             max_base_lots: 1,
             max_quote_lots_including_fees: 10000,
+            client_order_id: 42,
             open_orders_admin: None,
         },
     )
@@ -89,6 +91,11 @@ async fn test_take_ask_order() -> Result<(), TransportError> {
             balance_quote + 99980,
             solana.token_account_balance(owner_token_1).await
         );
+
+        let event_heap = solana.get_account::<Market>(market).await.event_heap;
+        let event_heap = solana.get_account_boxed::<EventHeap>(event_heap).await;
+        let fill: &FillEvent = cast_ref(event_heap.front().unwrap());
+        assert_eq!(fill.taker_client_order_id, 42);
     }
 
     send_tx(
@@ -127,6 +134,7 @@ async fn test_take_ask_order() -> Result<(), TransportError> {
             user_base_account: owner_token_0,
             user_quote_account: owner_token_1,
             referrer_account: None,
+            remainings: vec![],
         },
     )
     .await
@@ -207,6 +215,7 @@ async fn test_take_bid_order() -> Result<(), TransportError> {
             price_lots,
             max_base_lots: 1,
             max_quote_lots_including_fees: 10040,
+            client_order_id: 0,
             open_orders_admin: None,
         },
     )
@@ -271,6 +280,7 @@ async fn test_take_bid_order() -> Result<(), TransportError> {
             user_base_account: owner_token_0,
             user_quote_account: owner_token_1,
             referrer_account: None,
+            remainings: vec![],
         },
     )
     .await
@@ -367,3 +377,118 @@ async fn test_negative_spread_ask() -> Result<(), TransportError> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_place_order_and_settle() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        collect_fee_admin,
+        owner,
+        owner_token_0,
+        owner_token_1,
+        market,
+        market_quote_vault,
+        price_lots,
+        tokens,
+        account_1,
+        account_2,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize::default()).await?;
+    let solana = &context.solana.clone();
+
+    set_stub_oracle_price(solana, &tokens[1], collect_fee_admin, 1000.0).await;
+
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            open_orders_account: account_1,
+            open_orders_admin: None,
+            market,
+            signer: owner,
+            user_token_account: owner_token_1,
+            market_vault: market_quote_vault,
+            side: Side::Bid,
+            price_lots,
+            max_base_lots: 1,
+            max_quote_lots_including_fees: 10000,
+            client_order_id: 0,
+            expiry_timestamp: 0,
+            order_type: PlaceOrderType::Limit,
+            self_trade_behavior: SelfTradeBehavior::default(),
+            remainings: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let take = |order_type| PlaceOrderAndSettleInstruction {
+        open_orders_account: account_2,
+        market,
+        signer: owner,
+        user_base_account: owner_token_0,
+        user_quote_account: owner_token_1,
+        side: Side::Ask,
+        price_lots,
+        max_base_lots: 1,
+        max_quote_lots_including_fees: 10000,
+        order_type,
+        remainings: vec![],
+    };
+
+    // orders that could post aren't accepted
+    let result = send_tx(solana, take(PlaceOrderType::Limit)).await;
+    assert_openbook_error(
+        &result,
+        OpenBookError::InvalidInputOrderType.error_code(),
+        "orders have to be take-only".into(),
+    );
+
+    let balance_base = solana.token_account_balance(owner_token_0).await;
+    let balance_quote = solana.token_account_balance(owner_token_1).await;
+
+    send_tx(solana, take(PlaceOrderType::ImmediateOrCancel))
+        .await
+        .unwrap();
+
+    // the taker gets its tokens right away, the referrer share of the fees
+    // stays on the account
+    assert_eq!(
+        balance_base - 100,
+        solana.token_account_balance(owner_token_0).await
+    );
+    assert_eq!(
+        balance_quote + 99960,
+        solana.token_account_balance(owner_token_1).await
+    );
+    {
+        let position = solana
+            .get_account::<OpenOrdersAccount>(account_2)
+            .await
+            .position;
+        assert_eq!(position.base_free_native, 0);
+        assert_eq!(position.quote_free_native, 0);
+        assert_eq!(position.referrer_rebates_available, 20);
+        assert_eq!(position.taker_volume, 100000);
+    }
+
+    // the maker side still goes through the event heap
+    send_tx(
+        solana,
+        ConsumeEventsInstruction {
+            consume_events_admin: None,
+            market,
+            open_orders_accounts: vec![account_1],
+        },
+    )
+    .await
+    .unwrap();
+
+    let position = solana
+        .get_account::<OpenOrdersAccount>(account_1)
+        .await
+        .position;
+    assert_eq!(position.bids_base_lots, 0);
+    assert_eq!(position.base_free_native, 100);
+
+    Ok(())
+}
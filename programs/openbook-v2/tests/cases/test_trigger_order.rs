@@ -0,0 +1,172 @@
+use super::*;
+
+#[tokio::test]
+async fn test_trigger_order() -> Result<(), TransportError> {
+    let TestInitialize {
+        context,
+        collect_fee_admin,
+        owner,
+        payer,
+        owner_token_0,
+        owner_token_1,
+        market,
+        market_base_vault,
+        market_quote_vault,
+        price_lots,
+        tokens,
+        account_1,
+        account_2,
+        ..
+    } = TestContext::new_with_market(TestNewMarketInitialize::default()).await?;
+    let solana = &context.solana.clone();
+
+    set_stub_oracle_price(solana, &tokens[1], collect_fee_admin, 1000.0).await;
+
+    send_tx(
+        solana,
+        DepositInstruction {
+            owner,
+            market,
+            open_orders_account: account_1,
+            market_base_vault,
+            market_quote_vault,
+            user_base_account: owner_token_0,
+            user_quote_account: owner_token_1,
+            base_amount: 100,
+            quote_amount: 0,
+        },
+    )
+    .await
+    .unwrap();
+
+    send_tx(
+        solana,
+        PlaceOrderInstruction {
+            open_orders_account: account_2,
+            open_orders_admin: None,
+            market,
+            signer: owner,
+            user_token_account: owner_token_1,
+            market_vault: market_quote_vault,
+            side: Side::Bid,
+            price_lots,
+            max_base_lots: 1,
+            max_quote_lots_including_fees: 10000,
+            client_order_id: 0,
+            expiry_timestamp: 0,
+            order_type: PlaceOrderType::Limit,
+            self_trade_behavior: SelfTradeBehavior::default(),
+            remainings: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let trigger_order =
+        |trigger_id, side, order_type, trigger_price_lots| CreateTriggerOrderInstruction {
+            signer: owner,
+            payer,
+            open_orders_account: account_1,
+            market,
+            trigger_id,
+            condition: TriggerCondition::OraclePriceBelow,
+            trigger_price_lots,
+            side,
+            price_lots: price_lots / 2,
+            max_base_lots: 1,
+            max_quote_lots_including_fees: 10000,
+            order_type,
+        };
+    let execute = |trigger_id| ExecuteTriggerInstruction {
+        keeper: payer,
+        open_orders_account: account_1,
+        market,
+        trigger_id,
+    };
+
+    assert_openbook_error(
+        &send_tx(
+            solana,
+            trigger_order(1, Side::Ask, PlaceOrderType::ImmediateOrCancel, 0),
+        )
+        .await,
+        OpenBookError::InvalidInputPriceLots.error_code(),
+        "Trigger price must be positive".into(),
+    );
+
+    // stop loss, selling the deposited lot once the oracle halves
+    send_tx(
+        solana,
+        trigger_order(
+            1,
+            Side::Ask,
+            PlaceOrderType::ImmediateOrCancel,
+            price_lots / 2,
+        ),
+    )
+    .await
+    .unwrap();
+    // a bid the account can't fund
+    send_tx(
+        solana,
+        trigger_order(2, Side::Bid, PlaceOrderType::Limit, price_lots / 2),
+    )
+    .await
+    .unwrap();
+    send_tx(
+        solana,
+        trigger_order(3, Side::Ask, PlaceOrderType::Limit, price_lots / 2),
+    )
+    .await
+    .unwrap();
+
+    let trigger_1 = solana
+        .get_account::<TriggerOrder>(get_trigger_order_address(account_1, 1))
+        .await;
+    assert_eq!(trigger_1.owner, owner.pubkey());
+    assert_eq!(trigger_1.condition(), TriggerCondition::OraclePriceBelow);
+
+    assert_openbook_error(
+        &send_tx(solana, execute(1)).await,
+        OpenBookError::TriggerConditionNotMet.error_code(),
+        "Oracle is still above the trigger price".into(),
+    );
+
+    set_stub_oracle_price(solana, &tokens[1], collect_fee_admin, 400.0).await;
+
+    assert_openbook_error(
+        &send_tx(solana, execute(2)).await,
+        OpenBookError::InsufficientFreeFunds.error_code(),
+        "No free quote to fund the bid".into(),
+    );
+
+    send_tx(solana, execute(1)).await.unwrap();
+    assert!(solana
+        .get_account_data(get_trigger_order_address(account_1, 1))
+        .await
+        .is_none());
+    {
+        let position = solana
+            .get_account::<OpenOrdersAccount>(account_1)
+            .await
+            .position;
+        assert_eq!(position.base_free_native, 0);
+        assert_eq!(position.quote_free_native, 99960);
+    }
+
+    // cancelled triggers can't be executed anymore
+    send_tx(
+        solana,
+        CancelTriggerOrderInstruction {
+            signer: owner,
+            open_orders_account: account_1,
+            trigger_id: 3,
+            sol_destination: owner.pubkey(),
+        },
+    )
+    .await
+    .unwrap();
+    assert!(send_tx(solana, execute(3)).await.is_err());
+
+    Ok(())
+}
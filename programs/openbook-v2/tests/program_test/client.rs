@@ -11,7 +11,9 @@ use std::sync::Arc;
 use super::solana::SolanaCookie;
 use super::utils::TestKeypair;
 use openbook_v2::{
-    state::*, PlaceMultipleOrdersArgs, PlaceOrderArgs, PlaceOrderPeggedArgs, PlaceTakeOrderArgs,
+    fill_callback::FILL_CALLBACK_AUTHORITY_SEED, signed_order::SignedOrder, state::*,
+    MarketMetadataArgs, PlaceMultipleOrdersArgs, PlaceOrderArgs, PlaceOrderPeggedArgs,
+    PlaceTakeOrderArgs,
 };
 
 #[async_trait::async_trait(?Send)]
@@ -44,6 +46,101 @@ pub async fn send_tx<CI: ClientInstruction>(
     Ok(accounts)
 }
 
+/// Like `send_tx`, with `relayer` paying the transaction fee instead of the
+/// instruction's signers, as wallets sponsoring their users' transactions do
+pub async fn send_tx_relayed<CI: ClientInstruction>(
+    solana: &SolanaCookie,
+    ix: CI,
+    relayer: TestKeypair,
+) -> std::result::Result<CI::Accounts, TransportError> {
+    let (accounts, instruction) = ix.to_instruction(solana).await;
+    let signers = ix.signers();
+    let instructions = vec![instruction];
+    solana
+        .process_transaction_with_fee_payer(&instructions, Some(&signers[..]), Some(relayer))
+        .await?;
+    Ok(accounts)
+}
+
+/// Like `send_tx`, preceded by the ed25519 program verifying `signer`'s signature
+/// of `message`
+pub async fn send_tx_with_ed25519_signature<CI: ClientInstruction>(
+    solana: &SolanaCookie,
+    ix: CI,
+    signer: TestKeypair,
+    message: &[u8],
+) -> std::result::Result<CI::Accounts, TransportError> {
+    let (accounts, instruction) = ix.to_instruction(solana).await;
+    let signers = ix.signers();
+    let instructions = vec![ed25519_signature_instruction(signer, message), instruction];
+    solana
+        .process_transaction(&instructions, Some(&signers[..]))
+        .await?;
+    Ok(accounts)
+}
+
+/// An ed25519 program instruction holding a single signature, with the key and
+/// message in its own data, like `solana_sdk::ed25519_instruction` builds it
+pub fn ed25519_signature_instruction(signer: TestKeypair, message: &[u8]) -> Instruction {
+    let signature = solana_sdk::signer::Signer::sign_message(&signer.to_keypair(), message);
+
+    let data_start: u16 = 2 + 14;
+    let public_key_offset = data_start;
+    let signature_offset = public_key_offset + 32;
+    let message_data_offset = signature_offset + 64;
+    let mut data = vec![1, 0];
+    for value in [
+        signature_offset,
+        u16::MAX,
+        public_key_offset,
+        u16::MAX,
+        message_data_offset,
+        message.len() as u16,
+        u16::MAX,
+    ] {
+        data.extend_from_slice(&value.to_le_bytes());
+    }
+    data.extend_from_slice(signer.pubkey().as_ref());
+    data.extend_from_slice(signature.as_ref());
+    data.extend_from_slice(message);
+
+    Instruction {
+        program_id: solana_sdk::ed25519_program::id(),
+        accounts: vec![],
+        data,
+    }
+}
+
+/// Like `send_tx`, also returning the compute units the transaction consumed
+pub async fn send_tx_and_get_compute_units<CI: ClientInstruction>(
+    solana: &SolanaCookie,
+    ix: CI,
+) -> std::result::Result<(CI::Accounts, u64), TransportError> {
+    let (accounts, instruction) = ix.to_instruction(solana).await;
+    let signers = ix.signers();
+    let instructions = vec![instruction];
+    let units = solana
+        .simulate_compute_units(&instructions, Some(&signers[..]))
+        .await?;
+    solana
+        .process_transaction(&instructions, Some(&signers[..]))
+        .await?;
+    Ok((accounts, units))
+}
+
+/// Simulates the instruction and deserializes the value it returns
+pub async fn simulate_tx_and_get_return<CI: ClientInstruction, T: AnchorDeserialize>(
+    solana: &SolanaCookie,
+    ix: CI,
+) -> std::result::Result<T, TransportError> {
+    let (_, instruction) = ix.to_instruction(solana).await;
+    let signers = ix.signers();
+    let data = solana
+        .simulate_return_data(&[instruction], Some(&signers[..]))
+        .await?;
+    Ok(T::deserialize(&mut &data[..]).unwrap())
+}
+
 pub async fn send_tx_and_get_ix_custom_error<CI: ClientInstruction>(
     solana: &SolanaCookie,
     ix: CI,
@@ -133,6 +230,114 @@ pub fn get_market_address(market: TestKeypair) -> Pubkey {
     )
     .0
 }
+
+pub fn get_fill_callback_address(market: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"FillCallback".as_ref(), market.as_ref()],
+        &openbook_v2::id(),
+    )
+    .0
+}
+
+pub fn get_pending_market_admin_address(market: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"PendingMarketAdmin".as_ref(), market.as_ref()],
+        &openbook_v2::id(),
+    )
+    .0
+}
+
+pub fn get_event_authority_address() -> Pubkey {
+    Pubkey::find_program_address(&[b"__event_authority".as_ref()], &openbook_v2::id()).0
+}
+
+pub fn get_fill_history_address(market: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"FillHistory".as_ref(), market.as_ref()],
+        &openbook_v2::id(),
+    )
+    .0
+}
+
+pub fn get_market_stats_address(market: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"MarketStats".as_ref(), market.as_ref()],
+        &openbook_v2::id(),
+    )
+    .0
+}
+
+pub fn get_depth_snapshot_address(market: Pubkey, owner: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"DepthSnapshot".as_ref(), market.as_ref(), owner.as_ref()],
+        &openbook_v2::id(),
+    )
+    .0
+}
+
+pub fn get_trigger_order_address(open_orders_account: Pubkey, trigger_id: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"TriggerOrder".as_ref(),
+            open_orders_account.as_ref(),
+            &trigger_id.to_le_bytes(),
+        ],
+        &openbook_v2::id(),
+    )
+    .0
+}
+
+pub fn get_maker_rewards_address(market: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"MakerRewards".as_ref(), market.as_ref()],
+        &openbook_v2::id(),
+    )
+    .0
+}
+
+pub fn get_quote_quality_address(open_orders_account: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"QuoteQuality".as_ref(), open_orders_account.as_ref()],
+        &openbook_v2::id(),
+    )
+    .0
+}
+
+pub fn get_position_extension_address(open_orders_account: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"PositionExtension".as_ref(), open_orders_account.as_ref()],
+        &openbook_v2::id(),
+    )
+    .0
+}
+
+pub fn get_settle_receiver_authority_address(market: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            openbook_v2::settle_receiver::SETTLE_RECEIVER_AUTHORITY_SEED,
+            market.as_ref(),
+        ],
+        &openbook_v2::id(),
+    )
+    .0
+}
+
+pub fn get_dmm_registry_address(market: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"DmmRegistry".as_ref(), market.as_ref()],
+        &openbook_v2::id(),
+    )
+    .0
+}
+
+pub fn get_taker_rebate_campaign_address(market: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"TakerRebateCampaign".as_ref(), market.as_ref()],
+        &openbook_v2::id(),
+    )
+    .0
+}
+
 pub async fn set_stub_oracle_price(
     solana: &SolanaCookie,
     token: &super::setup::Token,
@@ -318,6 +523,8 @@ pub struct CreateMarketInstruction {
     pub settle_fee_flat: f32,
     pub settle_fee_amount_threshold: f32,
     pub time_expiry: i64,
+    pub min_order_notional: u64,
+    pub max_resting_orders: u8,
 }
 impl CreateMarketInstruction {
     pub async fn with_new_book_and_heap(
@@ -362,11 +569,10 @@ impl ClientInstruction for CreateMarketInstruction {
             maker_fee: self.maker_fee,
             taker_fee: self.taker_fee,
             time_expiry: self.time_expiry,
+            min_order_notional: self.min_order_notional,
+            max_resting_orders: self.max_resting_orders,
         };
 
-        let event_authority =
-            Pubkey::find_program_address(&[b"__event_authority".as_ref()], &openbook_v2::id()).0;
-
         let market_authority = Pubkey::find_program_address(
             &[b"Market".as_ref(), self.market.pubkey().to_bytes().as_ref()],
             &openbook_v2::id(),
@@ -402,7 +608,7 @@ impl ClientInstruction for CreateMarketInstruction {
             close_market_admin: self.close_market_admin,
             oracle_a: self.oracle_a,
             oracle_b: self.oracle_b,
-            event_authority,
+            event_authority: get_event_authority_address(),
             program: openbook_v2::id(),
         };
 
@@ -415,6 +621,135 @@ impl ClientInstruction for CreateMarketInstruction {
     }
 }
 
+pub fn get_canonical_market_address(
+    base_mint: Pubkey,
+    quote_mint: Pubkey,
+    base_lot_size: i64,
+    quote_lot_size: i64,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"CanonicalMarket".as_ref(),
+            base_mint.as_ref(),
+            quote_mint.as_ref(),
+            &base_lot_size.to_le_bytes(),
+            &quote_lot_size.to_le_bytes(),
+        ],
+        &openbook_v2::id(),
+    )
+    .0
+}
+
+#[derive(Default)]
+pub struct CreateCanonicalMarketInstruction {
+    pub collect_fee_admin: Pubkey,
+    pub open_orders_admin: Option<Pubkey>,
+    pub oracle_a: Option<Pubkey>,
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    pub bids: Pubkey,
+    pub asks: Pubkey,
+    pub event_heap: Pubkey,
+    pub payer: TestKeypair,
+    pub quote_lot_size: i64,
+    pub base_lot_size: i64,
+}
+impl CreateCanonicalMarketInstruction {
+    pub async fn with_new_book_and_heap(solana: &SolanaCookie, oracle_a: Option<Pubkey>) -> Self {
+        CreateCanonicalMarketInstruction {
+            bids: solana
+                .create_account_for_type::<BookSide>(&openbook_v2::id())
+                .await,
+            asks: solana
+                .create_account_for_type::<BookSide>(&openbook_v2::id())
+                .await,
+            event_heap: solana
+                .create_account_for_type::<EventHeap>(&openbook_v2::id())
+                .await,
+            oracle_a,
+            ..CreateCanonicalMarketInstruction::default()
+        }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for CreateCanonicalMarketInstruction {
+    type Accounts = openbook_v2::accounts::CreateCanonicalMarket;
+    type Instruction = openbook_v2::instruction::CreateCanonicalMarket;
+    async fn to_instruction(
+        &self,
+        _loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {
+            name: "ONE-TWO".to_string(),
+            oracle_config: OracleConfigParams {
+                conf_filter: 0.1,
+                max_staleness_slots: Some(100),
+            },
+            quote_lot_size: self.quote_lot_size,
+            base_lot_size: self.base_lot_size,
+            maker_fee: 0,
+            taker_fee: 0,
+            time_expiry: 0,
+            min_order_notional: 0,
+            max_resting_orders: 0,
+        };
+
+        let market = get_canonical_market_address(
+            self.base_mint,
+            self.quote_mint,
+            self.base_lot_size,
+            self.quote_lot_size,
+        );
+        let market_authority = Pubkey::find_program_address(
+            &[b"Market".as_ref(), market.to_bytes().as_ref()],
+            &openbook_v2::id(),
+        )
+        .0;
+
+        let market_base_vault = spl_associated_token_account::get_associated_token_address(
+            &market_authority,
+            &self.base_mint,
+        );
+        let market_quote_vault = spl_associated_token_account::get_associated_token_address(
+            &market_authority,
+            &self.quote_mint,
+        );
+
+        let accounts = Self::Accounts {
+            market,
+            market_authority,
+            bids: self.bids,
+            asks: self.asks,
+            event_heap: self.event_heap,
+            payer: self.payer.pubkey(),
+            market_base_vault,
+            market_quote_vault,
+            quote_mint: self.quote_mint,
+            base_mint: self.base_mint,
+            system_program: System::id(),
+            token_program: Token::id(),
+            associated_token_program: AssociatedToken::id(),
+            collect_fee_admin: self.collect_fee_admin,
+            open_orders_admin: self.open_orders_admin,
+            consume_events_admin: None,
+            close_market_admin: None,
+            oracle_a: self.oracle_a,
+            oracle_b: None,
+            event_authority: get_event_authority_address(),
+            program: openbook_v2::id(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.payer]
+    }
+}
+
 #[derive(Clone)]
 pub struct PlaceOrderInstruction {
     pub open_orders_account: Pubkey,
@@ -459,6 +794,8 @@ impl ClientInstruction for PlaceOrderInstruction {
 
         let market: Market = account_loader.load(&self.market).await.unwrap();
 
+        let (token_gate, gate_token_account) =
+            token_gate_accounts(&account_loader, self.market, self.open_orders_account).await;
         let accounts = Self::Accounts {
             open_orders_account: self.open_orders_account,
             open_orders_admin: self.open_orders_admin.map(|kp| kp.pubkey()),
@@ -472,6 +809,10 @@ impl ClientInstruction for PlaceOrderInstruction {
             user_token_account: self.user_token_account,
             market_vault: self.market_vault,
             token_program: Token::id(),
+            token_gate,
+            gate_token_account,
+            event_authority: get_event_authority_address(),
+            program: openbook_v2::id(),
         };
         let mut instruction = make_instruction(program_id, &accounts, instruction);
         let mut vec_remainings: Vec<AccountMeta> = Vec::new();
@@ -496,6 +837,78 @@ impl ClientInstruction for PlaceOrderInstruction {
     }
 }
 
+/// Places `place_order` with `relayer` receiving `relayer_fee` from the signer's
+/// token account
+pub struct PlaceOrderRelayedInstruction {
+    pub place_order: PlaceOrderInstruction,
+    pub relayer: TestKeypair,
+    pub relayer_token_account: Pubkey,
+    pub relayer_fee: u64,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for PlaceOrderRelayedInstruction {
+    type Accounts = openbook_v2::accounts::PlaceOrderRelayed;
+    type Instruction = openbook_v2::instruction::PlaceOrderRelayed;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let place_order = &self.place_order;
+        let instruction = Self::Instruction {
+            args: PlaceOrderArgs {
+                side: place_order.side,
+                price_lots: place_order.price_lots,
+                max_base_lots: place_order.max_base_lots,
+                max_quote_lots_including_fees: place_order.max_quote_lots_including_fees,
+                client_order_id: place_order.client_order_id,
+                order_type: place_order.order_type,
+                expiry_timestamp: place_order.expiry_timestamp,
+                self_trade_behavior: place_order.self_trade_behavior,
+                limit: 10,
+            },
+            relayer_fee: self.relayer_fee,
+        };
+
+        let (place_accounts, _) = place_order.to_instruction(account_loader).await;
+        let accounts = Self::Accounts {
+            signer: place_accounts.signer,
+            relayer: self.relayer.pubkey(),
+            relayer_token_account: self.relayer_token_account,
+            open_orders_account: place_accounts.open_orders_account,
+            open_orders_admin: place_accounts.open_orders_admin,
+            user_token_account: place_accounts.user_token_account,
+            market: place_accounts.market,
+            bids: place_accounts.bids,
+            asks: place_accounts.asks,
+            event_heap: place_accounts.event_heap,
+            market_vault: place_accounts.market_vault,
+            oracle_a: place_accounts.oracle_a,
+            oracle_b: place_accounts.oracle_b,
+            token_program: place_accounts.token_program,
+            token_gate: place_accounts.token_gate,
+            gate_token_account: place_accounts.gate_token_account,
+            event_authority: place_accounts.event_authority,
+            program: place_accounts.program,
+        };
+        let mut instruction = make_instruction(program_id, &accounts, instruction);
+        for remaining in &place_order.remainings {
+            instruction.accounts.push(AccountMeta {
+                pubkey: *remaining,
+                is_signer: false,
+                is_writable: true,
+            })
+        }
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        let mut signers = self.place_order.signers();
+        signers.push(self.relayer);
+        signers
+    }
+}
+
 #[derive(Clone)]
 pub struct PlaceOrderPeggedInstruction {
     pub open_orders_account: Pubkey,
@@ -536,6 +949,8 @@ impl ClientInstruction for PlaceOrderPeggedInstruction {
 
         let market: Market = account_loader.load(&self.market).await.unwrap();
 
+        let (token_gate, gate_token_account) =
+            token_gate_accounts(&account_loader, self.market, self.open_orders_account).await;
         let accounts = Self::Accounts {
             open_orders_account: self.open_orders_account,
             open_orders_admin: None,
@@ -549,6 +964,10 @@ impl ClientInstruction for PlaceOrderPeggedInstruction {
             user_token_account: self.user_token_account,
             market_vault: self.market_vault,
             token_program: Token::id(),
+            token_gate,
+            gate_token_account,
+            event_authority: get_event_authority_address(),
+            program: openbook_v2::id(),
         };
         let instruction = make_instruction(program_id, &accounts, instruction);
 
@@ -560,37 +979,117 @@ impl ClientInstruction for PlaceOrderPeggedInstruction {
     }
 }
 
-pub struct PlaceTakeOrderInstruction {
-    pub open_orders_admin: Option<TestKeypair>,
+pub struct PlaceOrderAndSettleInstruction {
+    pub open_orders_account: Pubkey,
     pub market: Pubkey,
     pub signer: TestKeypair,
-    pub market_base_vault: Pubkey,
-    pub market_quote_vault: Pubkey,
     pub user_base_account: Pubkey,
     pub user_quote_account: Pubkey,
     pub side: Side,
     pub price_lots: i64,
     pub max_base_lots: i64,
     pub max_quote_lots_including_fees: i64,
+    pub order_type: PlaceOrderType,
+    pub remainings: Vec<Pubkey>,
 }
 #[async_trait::async_trait(?Send)]
-impl ClientInstruction for PlaceTakeOrderInstruction {
-    type Accounts = openbook_v2::accounts::PlaceTakeOrder;
-    type Instruction = openbook_v2::instruction::PlaceTakeOrder;
+impl ClientInstruction for PlaceOrderAndSettleInstruction {
+    type Accounts = openbook_v2::accounts::PlaceOrderAndSettle;
+    type Instruction = openbook_v2::instruction::PlaceOrderAndSettle;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
         let program_id = openbook_v2::id();
         let instruction = Self::Instruction {
-            args: PlaceTakeOrderArgs {
+            args: PlaceOrderArgs {
                 side: self.side,
                 price_lots: self.price_lots,
                 max_base_lots: self.max_base_lots,
                 max_quote_lots_including_fees: self.max_quote_lots_including_fees,
-                order_type: PlaceOrderType::ImmediateOrCancel,
-                limit: 10,
-            },
+                client_order_id: 0,
+                order_type: self.order_type,
+                expiry_timestamp: 0,
+                self_trade_behavior: SelfTradeBehavior::default(),
+                limit: 10,
+            },
+        };
+
+        let market: Market = account_loader.load(&self.market).await.unwrap();
+
+        let (token_gate, gate_token_account) =
+            token_gate_accounts(&account_loader, self.market, self.open_orders_account).await;
+
+        let accounts = Self::Accounts {
+            signer: self.signer.pubkey(),
+            open_orders_account: self.open_orders_account,
+            open_orders_admin: None,
+            market: self.market,
+            market_authority: market.market_authority,
+            bids: market.bids,
+            asks: market.asks,
+            event_heap: market.event_heap,
+            market_base_vault: market.market_base_vault,
+            market_quote_vault: market.market_quote_vault,
+            user_base_account: self.user_base_account,
+            user_quote_account: self.user_quote_account,
+            oracle_a: market.oracle_a.into(),
+            oracle_b: market.oracle_b.into(),
+            token_program: Token::id(),
+            token_gate,
+            gate_token_account,
+            event_authority: get_event_authority_address(),
+            program: openbook_v2::id(),
+        };
+        let mut instruction = make_instruction(program_id, &accounts, instruction);
+        instruction
+            .accounts
+            .extend(self.remainings.iter().map(|remaining| AccountMeta {
+                pubkey: *remaining,
+                is_signer: false,
+                is_writable: true,
+            }));
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.signer]
+    }
+}
+
+pub struct PlaceTakeOrderInstruction {
+    pub open_orders_admin: Option<TestKeypair>,
+    pub market: Pubkey,
+    pub signer: TestKeypair,
+    pub market_base_vault: Pubkey,
+    pub market_quote_vault: Pubkey,
+    pub user_base_account: Pubkey,
+    pub user_quote_account: Pubkey,
+    pub side: Side,
+    pub price_lots: i64,
+    pub max_base_lots: i64,
+    pub max_quote_lots_including_fees: i64,
+    pub client_order_id: u64,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for PlaceTakeOrderInstruction {
+    type Accounts = openbook_v2::accounts::PlaceTakeOrder;
+    type Instruction = openbook_v2::instruction::PlaceTakeOrder;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {
+            args: PlaceTakeOrderArgs {
+                side: self.side,
+                price_lots: self.price_lots,
+                max_base_lots: self.max_base_lots,
+                max_quote_lots_including_fees: self.max_quote_lots_including_fees,
+                client_order_id: self.client_order_id,
+                order_type: PlaceOrderType::ImmediateOrCancel,
+                limit: 10,
+            },
         };
 
         let market: Market = account_loader.load(&self.market).await.unwrap();
@@ -612,6 +1111,10 @@ impl ClientInstruction for PlaceTakeOrderInstruction {
             market_quote_vault: self.market_quote_vault,
             token_program: Token::id(),
             system_program: System::id(),
+            taker_program_allowlist: None,
+            instructions_sysvar: None,
+            event_authority: get_event_authority_address(),
+            program: openbook_v2::id(),
         };
 
         let instruction = make_instruction(program_id, &accounts, instruction);
@@ -700,24 +1203,22 @@ impl ClientInstruction for CancelOrderByClientOrderIdInstruction {
     }
 }
 
-#[derive(Clone)]
-pub struct CancelAllOrdersInstruction {
+pub struct FindOrderInstruction {
     pub open_orders_account: Pubkey,
     pub market: Pubkey,
-    pub signer: TestKeypair,
+    pub client_order_id: u64,
 }
 #[async_trait::async_trait(?Send)]
-impl ClientInstruction for CancelAllOrdersInstruction {
-    type Accounts = openbook_v2::accounts::CancelOrder;
-    type Instruction = openbook_v2::instruction::CancelAllOrders;
+impl ClientInstruction for FindOrderInstruction {
+    type Accounts = openbook_v2::accounts::FindOrder;
+    type Instruction = openbook_v2::instruction::FindOrder;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
         let program_id = openbook_v2::id();
         let instruction = Self::Instruction {
-            side_option: None,
-            limit: 5,
+            client_order_id: self.client_order_id,
         };
         let market: Market = account_loader.load(&self.market).await.unwrap();
         let accounts = Self::Accounts {
@@ -725,7 +1226,6 @@ impl ClientInstruction for CancelAllOrdersInstruction {
             market: self.market,
             bids: market.bids,
             asks: market.asks,
-            signer: self.signer.pubkey(),
         };
 
         let instruction = make_instruction(program_id, &accounts, instruction);
@@ -733,133 +1233,98 @@ impl ClientInstruction for CancelAllOrdersInstruction {
     }
 
     fn signers(&self) -> Vec<TestKeypair> {
-        vec![self.signer]
+        vec![]
     }
 }
 
-#[derive(Clone)]
-pub struct ConsumeEventsInstruction {
-    pub consume_events_admin: Option<TestKeypair>,
+pub struct GetMarketQuoteInstruction {
     pub market: Pubkey,
-    pub open_orders_accounts: Vec<Pubkey>,
 }
 #[async_trait::async_trait(?Send)]
-impl ClientInstruction for ConsumeEventsInstruction {
-    type Accounts = openbook_v2::accounts::ConsumeEvents;
-    type Instruction = openbook_v2::instruction::ConsumeEvents;
+impl ClientInstruction for GetMarketQuoteInstruction {
+    type Accounts = openbook_v2::accounts::GetMarketQuote;
+    type Instruction = openbook_v2::instruction::GetMarketQuote;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
         let program_id = openbook_v2::id();
-        let instruction = Self::Instruction { limit: 10 };
-
+        let instruction = Self::Instruction {};
         let market: Market = account_loader.load(&self.market).await.unwrap();
         let accounts = Self::Accounts {
-            consume_events_admin: self.consume_events_admin.map(|kp| kp.pubkey()),
             market: self.market,
-            event_heap: market.event_heap,
+            bids: market.bids,
+            asks: market.asks,
+            oracle_a: market.oracle_a.into(),
+            oracle_b: market.oracle_b.into(),
         };
 
-        let mut instruction = make_instruction(program_id, &accounts, instruction);
-        instruction
-            .accounts
-            .extend(self.open_orders_accounts.iter().map(|ma| AccountMeta {
-                pubkey: *ma,
-                is_signer: false,
-                is_writable: true,
-            }));
+        let instruction = make_instruction(program_id, &accounts, instruction);
         (accounts, instruction)
     }
 
     fn signers(&self) -> Vec<TestKeypair> {
-        match self.consume_events_admin {
-            Some(consume_events_admin) => vec![consume_events_admin],
-            None => vec![],
-        }
+        vec![]
     }
 }
 
-pub struct ConsumeGivenEventsInstruction {
-    pub consume_events_admin: Option<TestKeypair>,
+pub struct CreateDepthSnapshotInstruction {
+    pub payer: TestKeypair,
+    pub owner: TestKeypair,
     pub market: Pubkey,
-    pub open_orders_accounts: Vec<Pubkey>,
-    pub slots: Vec<usize>,
 }
 #[async_trait::async_trait(?Send)]
-impl ClientInstruction for ConsumeGivenEventsInstruction {
-    type Accounts = openbook_v2::accounts::ConsumeEvents;
-    type Instruction = openbook_v2::instruction::ConsumeGivenEvents;
+impl ClientInstruction for CreateDepthSnapshotInstruction {
+    type Accounts = openbook_v2::accounts::CreateDepthSnapshot;
+    type Instruction = openbook_v2::instruction::CreateDepthSnapshot;
     async fn to_instruction(
         &self,
-        account_loader: impl ClientAccountLoader + 'async_trait,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
         let program_id = openbook_v2::id();
-        let instruction = Self::Instruction {
-            slots: self.slots.clone(),
-        };
-
-        let market: Market = account_loader.load(&self.market).await.unwrap();
+        let instruction = Self::Instruction {};
         let accounts = Self::Accounts {
-            consume_events_admin: self.consume_events_admin.map(|kp| kp.pubkey()),
+            payer: self.payer.pubkey(),
+            owner: self.owner.pubkey(),
             market: self.market,
-            event_heap: market.event_heap,
+            depth_snapshot: get_depth_snapshot_address(self.market, self.owner.pubkey()),
+            system_program: System::id(),
         };
 
-        let mut instruction = make_instruction(program_id, &accounts, instruction);
-        instruction
-            .accounts
-            .extend(self.open_orders_accounts.iter().map(|ma| AccountMeta {
-                pubkey: *ma,
-                is_signer: false,
-                is_writable: true,
-            }));
+        let instruction = make_instruction(program_id, &accounts, instruction);
         (accounts, instruction)
     }
 
     fn signers(&self) -> Vec<TestKeypair> {
-        match self.consume_events_admin {
-            Some(consume_events_admin) => vec![consume_events_admin],
-            None => vec![],
-        }
+        vec![self.payer, self.owner]
     }
 }
 
-#[derive(Clone)]
-pub struct SettleFundsInstruction {
-    pub owner: TestKeypair,
-    pub open_orders_account: Pubkey,
+pub struct SnapshotDepthInstruction {
+    pub depth_snapshot: Pubkey,
     pub market: Pubkey,
-    pub market_base_vault: Pubkey,
-    pub market_quote_vault: Pubkey,
-    pub user_base_account: Pubkey,
-    pub user_quote_account: Pubkey,
-    pub referrer_account: Option<Pubkey>,
+    pub levels: u8,
 }
 #[async_trait::async_trait(?Send)]
-impl ClientInstruction for SettleFundsInstruction {
-    type Accounts = openbook_v2::accounts::SettleFunds;
-    type Instruction = openbook_v2::instruction::SettleFunds;
+impl ClientInstruction for SnapshotDepthInstruction {
+    type Accounts = openbook_v2::accounts::SnapshotDepth;
+    type Instruction = openbook_v2::instruction::SnapshotDepth;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
         let program_id = openbook_v2::id();
-        let instruction = Self::Instruction {};
+        let instruction = Self::Instruction {
+            levels: self.levels,
+        };
         let market: Market = account_loader.load(&self.market).await.unwrap();
         let accounts = Self::Accounts {
-            owner: self.owner.pubkey(),
-            penalty_payer: self.owner.pubkey(),
-            open_orders_account: self.open_orders_account,
+            depth_snapshot: self.depth_snapshot,
             market: self.market,
-            market_authority: market.market_authority,
-            market_base_vault: self.market_base_vault,
-            market_quote_vault: self.market_quote_vault,
-            user_base_account: self.user_base_account,
-            user_quote_account: self.user_quote_account,
-            referrer_account: self.referrer_account,
-            token_program: Token::id(),
-            system_program: System::id(),
+            bids: market.bids,
+            asks: market.asks,
+            oracle_a: market.oracle_a.into(),
+            oracle_b: market.oracle_b.into(),
         };
 
         let instruction = make_instruction(program_id, &accounts, instruction);
@@ -867,47 +1332,36 @@ impl ClientInstruction for SettleFundsInstruction {
     }
 
     fn signers(&self) -> Vec<TestKeypair> {
-        vec![self.owner]
+        vec![]
     }
 }
 
 #[derive(Clone)]
-pub struct SettleFundsExpiredInstruction {
-    pub close_market_admin: TestKeypair,
-    pub owner: TestKeypair,
+pub struct CancelAllOrdersInstruction {
     pub open_orders_account: Pubkey,
     pub market: Pubkey,
-    pub market_base_vault: Pubkey,
-    pub market_quote_vault: Pubkey,
-    pub user_base_account: Pubkey,
-    pub user_quote_account: Pubkey,
-    pub referrer_account: Option<Pubkey>,
+    pub signer: TestKeypair,
 }
 #[async_trait::async_trait(?Send)]
-impl ClientInstruction for SettleFundsExpiredInstruction {
-    type Accounts = openbook_v2::accounts::SettleFundsExpired;
-    type Instruction = openbook_v2::instruction::SettleFundsExpired;
+impl ClientInstruction for CancelAllOrdersInstruction {
+    type Accounts = openbook_v2::accounts::CancelOrder;
+    type Instruction = openbook_v2::instruction::CancelAllOrders;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
         let program_id = openbook_v2::id();
-        let instruction = Self::Instruction {};
+        let instruction = Self::Instruction {
+            side_option: None,
+            limit: 5,
+        };
         let market: Market = account_loader.load(&self.market).await.unwrap();
         let accounts = Self::Accounts {
-            close_market_admin: self.close_market_admin.pubkey(),
-            owner: self.owner.pubkey(),
-            penalty_payer: self.owner.pubkey(),
             open_orders_account: self.open_orders_account,
             market: self.market,
-            market_authority: market.market_authority,
-            market_base_vault: self.market_base_vault,
-            market_quote_vault: self.market_quote_vault,
-            user_base_account: self.user_base_account,
-            user_quote_account: self.user_quote_account,
-            referrer_account: self.referrer_account,
-            token_program: Token::id(),
-            system_program: System::id(),
+            bids: market.bids,
+            asks: market.asks,
+            signer: self.signer.pubkey(),
         };
 
         let instruction = make_instruction(program_id, &accounts, instruction);
@@ -915,52 +1369,2206 @@ impl ClientInstruction for SettleFundsExpiredInstruction {
     }
 
     fn signers(&self) -> Vec<TestKeypair> {
-        vec![self.close_market_admin, self.owner]
+        vec![self.signer]
     }
 }
 
-pub struct SweepFeesInstruction {
-    pub collect_fee_admin: TestKeypair,
-    pub market: Pubkey,
-    pub market_quote_vault: Pubkey,
-    pub token_receiver_account: Pubkey,
+pub struct ScheduleCancelAllInstruction {
+    pub open_orders_account: Pubkey,
+    pub signer: TestKeypair,
+    pub cancel_after_ts: u64,
 }
 #[async_trait::async_trait(?Send)]
-impl ClientInstruction for SweepFeesInstruction {
-    type Accounts = openbook_v2::accounts::SweepFees;
-    type Instruction = openbook_v2::instruction::SweepFees;
+impl ClientInstruction for ScheduleCancelAllInstruction {
+    type Accounts = openbook_v2::accounts::ScheduleCancelAll;
+    type Instruction = openbook_v2::instruction::ScheduleCancelAll;
     async fn to_instruction(
         &self,
-        account_loader: impl ClientAccountLoader + 'async_trait,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
         let program_id = openbook_v2::id();
-        let instruction = Self::Instruction {};
-        let market: Market = account_loader.load(&self.market).await.unwrap();
-
+        let instruction = Self::Instruction {
+            cancel_after_ts: self.cancel_after_ts,
+        };
         let accounts = Self::Accounts {
-            collect_fee_admin: self.collect_fee_admin.pubkey(),
-            market: self.market,
-            market_authority: market.market_authority,
-            market_quote_vault: self.market_quote_vault,
-            token_receiver_account: self.token_receiver_account,
-            token_program: Token::id(),
+            signer: self.signer.pubkey(),
+            open_orders_account: self.open_orders_account,
+            position_extension: get_position_extension_address(self.open_orders_account),
         };
-        let instruction = make_instruction(program_id, &accounts, instruction);
 
+        let instruction = make_instruction(program_id, &accounts, instruction);
         (accounts, instruction)
     }
 
     fn signers(&self) -> Vec<TestKeypair> {
-        vec![self.collect_fee_admin]
+        vec![self.signer]
     }
 }
 
-pub struct DepositInstruction {
+#[derive(Clone)]
+pub struct ExecuteScheduledCancelInstruction {
+    pub keeper: TestKeypair,
     pub open_orders_account: Pubkey,
     pub market: Pubkey,
-    pub market_base_vault: Pubkey,
-    pub market_quote_vault: Pubkey,
-    pub user_base_account: Pubkey,
+    pub limit: u8,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for ExecuteScheduledCancelInstruction {
+    type Accounts = openbook_v2::accounts::ExecuteScheduledCancel;
+    type Instruction = openbook_v2::instruction::ExecuteScheduledCancel;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction { limit: self.limit };
+        let market: Market = account_loader.load(&self.market).await.unwrap();
+        let accounts = Self::Accounts {
+            keeper: self.keeper.pubkey(),
+            open_orders_account: self.open_orders_account,
+            position_extension: get_position_extension_address(self.open_orders_account),
+            market: self.market,
+            bids: market.bids,
+            asks: market.asks,
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.keeper]
+    }
+}
+
+pub struct SetHeartbeatIntervalInstruction {
+    pub open_orders_account: Pubkey,
+    pub signer: TestKeypair,
+    pub interval: u32,
+    pub bounty: u64,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for SetHeartbeatIntervalInstruction {
+    type Accounts = openbook_v2::accounts::SetHeartbeatInterval;
+    type Instruction = openbook_v2::instruction::SetHeartbeatInterval;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {
+            interval: self.interval,
+            bounty: self.bounty,
+        };
+        let open_orders_account: OpenOrdersAccount = account_loader
+            .load(&self.open_orders_account)
+            .await
+            .unwrap();
+        let accounts = Self::Accounts {
+            signer: self.signer.pubkey(),
+            owner: open_orders_account.owner,
+            open_orders_account: self.open_orders_account,
+            position_extension: get_position_extension_address(self.open_orders_account),
+            system_program: System::id(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.signer]
+    }
+}
+
+#[derive(Clone)]
+pub struct HeartbeatInstruction {
+    pub open_orders_account: Pubkey,
+    pub signer: TestKeypair,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for HeartbeatInstruction {
+    type Accounts = openbook_v2::accounts::Heartbeat;
+    type Instruction = openbook_v2::instruction::Heartbeat;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {};
+        let accounts = Self::Accounts {
+            signer: self.signer.pubkey(),
+            open_orders_account: self.open_orders_account,
+            position_extension: get_position_extension_address(self.open_orders_account),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.signer]
+    }
+}
+
+#[derive(Clone)]
+pub struct ConsumeEventsMultipleEntry {
+    pub market: Pubkey,
+    pub open_orders_accounts: Vec<Pubkey>,
+    pub limit: u8,
+}
+
+#[derive(Clone)]
+pub struct ConsumeEventsMultipleInstruction {
+    pub consume_events_admin: Option<TestKeypair>,
+    pub entries: Vec<ConsumeEventsMultipleEntry>,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for ConsumeEventsMultipleInstruction {
+    type Accounts = openbook_v2::accounts::ConsumeEventsMultiple;
+    type Instruction = openbook_v2::instruction::ConsumeEventsMultiple;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {
+            groups: self
+                .entries
+                .iter()
+                .map(|entry| openbook_v2::ConsumeEventsGroup {
+                    num_accounts: entry.open_orders_accounts.len() as u8,
+                    limit: entry.limit,
+                })
+                .collect(),
+        };
+        let accounts = Self::Accounts {
+            consume_events_admin: self.consume_events_admin.map(|kp| kp.pubkey()),
+        };
+
+        let mut instruction = make_instruction(program_id, &accounts, instruction);
+        for entry in &self.entries {
+            let market: Market = account_loader.load(&entry.market).await.unwrap();
+            instruction.accounts.extend(
+                [entry.market, market.event_heap]
+                    .iter()
+                    .chain(entry.open_orders_accounts.iter())
+                    .map(|pubkey| AccountMeta {
+                        pubkey: *pubkey,
+                        is_signer: false,
+                        is_writable: true,
+                    }),
+            );
+        }
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        match self.consume_events_admin {
+            Some(consume_events_admin) => vec![consume_events_admin],
+            None => vec![],
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct ConsumeEventsInstruction {
+    pub consume_events_admin: Option<TestKeypair>,
+    pub market: Pubkey,
+    pub open_orders_accounts: Vec<Pubkey>,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for ConsumeEventsInstruction {
+    type Accounts = openbook_v2::accounts::ConsumeEvents;
+    type Instruction = openbook_v2::instruction::ConsumeEvents;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction { limit: 10 };
+
+        let market: Market = account_loader.load(&self.market).await.unwrap();
+        let accounts = Self::Accounts {
+            consume_events_admin: self.consume_events_admin.map(|kp| kp.pubkey()),
+            market: self.market,
+            event_heap: market.event_heap,
+        };
+
+        let mut instruction = make_instruction(program_id, &accounts, instruction);
+        instruction
+            .accounts
+            .extend(self.open_orders_accounts.iter().map(|ma| AccountMeta {
+                pubkey: *ma,
+                is_signer: false,
+                is_writable: true,
+            }));
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        match self.consume_events_admin {
+            Some(consume_events_admin) => vec![consume_events_admin],
+            None => vec![],
+        }
+    }
+}
+
+pub struct ConsumeEventsWithRewardInstruction {
+    pub cranker: TestKeypair,
+    pub consume_events_admin: Option<TestKeypair>,
+    pub market: Pubkey,
+    pub open_orders_accounts: Vec<Pubkey>,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for ConsumeEventsWithRewardInstruction {
+    type Accounts = openbook_v2::accounts::ConsumeEventsWithReward;
+    type Instruction = openbook_v2::instruction::ConsumeEventsWithReward;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction { limit: 10 };
+
+        let market: Market = account_loader.load(&self.market).await.unwrap();
+        let accounts = Self::Accounts {
+            cranker: self.cranker.pubkey(),
+            consume_events_admin: self.consume_events_admin.map(|kp| kp.pubkey()),
+            market: self.market,
+            event_heap: market.event_heap,
+        };
+
+        let mut instruction = make_instruction(program_id, &accounts, instruction);
+        instruction
+            .accounts
+            .extend(self.open_orders_accounts.iter().map(|ma| AccountMeta {
+                pubkey: *ma,
+                is_signer: false,
+                is_writable: true,
+            }));
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        let mut signers = vec![self.cranker];
+        signers.extend(self.consume_events_admin);
+        signers
+    }
+}
+
+pub struct ConsumeEventsWithFillCallbackInstruction {
+    pub consume_events_admin: Option<TestKeypair>,
+    pub market: Pubkey,
+    pub fill_callback_program: Pubkey,
+    pub open_orders_accounts: Vec<Pubkey>,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for ConsumeEventsWithFillCallbackInstruction {
+    type Accounts = openbook_v2::accounts::ConsumeEventsWithFillCallback;
+    type Instruction = openbook_v2::instruction::ConsumeEventsWithFillCallback;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction { limit: 10 };
+
+        let market: Market = account_loader.load(&self.market).await.unwrap();
+        let fill_callback_authority = Pubkey::find_program_address(
+            &[FILL_CALLBACK_AUTHORITY_SEED, self.market.as_ref()],
+            &program_id,
+        )
+        .0;
+        let accounts = Self::Accounts {
+            consume_events_admin: self.consume_events_admin.map(|kp| kp.pubkey()),
+            market: self.market,
+            event_heap: market.event_heap,
+            fill_callback: get_fill_callback_address(self.market),
+            fill_callback_authority,
+            fill_callback_program: self.fill_callback_program,
+        };
+
+        let mut instruction = make_instruction(program_id, &accounts, instruction);
+        instruction
+            .accounts
+            .extend(self.open_orders_accounts.iter().map(|ma| AccountMeta {
+                pubkey: *ma,
+                is_signer: false,
+                is_writable: true,
+            }));
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        match self.consume_events_admin {
+            Some(consume_events_admin) => vec![consume_events_admin],
+            None => vec![],
+        }
+    }
+}
+
+pub struct SetConsumeEventsRewardInstruction {
+    pub collect_fee_admin: TestKeypair,
+    pub market: Pubkey,
+    pub reward_lamports: u64,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for SetConsumeEventsRewardInstruction {
+    type Accounts = openbook_v2::accounts::SetConsumeEventsReward;
+    type Instruction = openbook_v2::instruction::SetConsumeEventsReward;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {
+            reward_lamports: self.reward_lamports,
+        };
+        let accounts = Self::Accounts {
+            collect_fee_admin: self.collect_fee_admin.pubkey(),
+            market: self.market,
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.collect_fee_admin]
+    }
+}
+
+pub struct SetSuccessorMarketInstruction {
+    pub collect_fee_admin: TestKeypair,
+    pub market: Pubkey,
+    pub successor_market: Option<Pubkey>,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for SetSuccessorMarketInstruction {
+    type Accounts = openbook_v2::accounts::SetSuccessorMarket;
+    type Instruction = openbook_v2::instruction::SetSuccessorMarket;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {};
+        let accounts = Self::Accounts {
+            collect_fee_admin: self.collect_fee_admin.pubkey(),
+            market: self.market,
+            market_metadata: get_market_metadata_address(self.market),
+            successor_market: self.successor_market,
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.collect_fee_admin]
+    }
+}
+
+pub struct SetMaxRestingOrdersInstruction {
+    pub collect_fee_admin: TestKeypair,
+    pub market: Pubkey,
+    pub max_resting_orders: u8,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for SetMaxRestingOrdersInstruction {
+    type Accounts = openbook_v2::accounts::SetMaxRestingOrders;
+    type Instruction = openbook_v2::instruction::SetMaxRestingOrders;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {
+            max_resting_orders: self.max_resting_orders,
+        };
+        let accounts = Self::Accounts {
+            collect_fee_admin: self.collect_fee_admin.pubkey(),
+            market: self.market,
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.collect_fee_admin]
+    }
+}
+
+pub struct SetMinOrderNotionalInstruction {
+    pub collect_fee_admin: TestKeypair,
+    pub market: Pubkey,
+    pub min_order_notional: u64,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for SetMinOrderNotionalInstruction {
+    type Accounts = openbook_v2::accounts::SetMinOrderNotional;
+    type Instruction = openbook_v2::instruction::SetMinOrderNotional;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {
+            min_order_notional: self.min_order_notional,
+        };
+        let accounts = Self::Accounts {
+            collect_fee_admin: self.collect_fee_admin.pubkey(),
+            market: self.market,
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.collect_fee_admin]
+    }
+}
+
+pub struct SetOrderRateLimitInstruction {
+    pub collect_fee_admin: TestKeypair,
+    pub market: Pubkey,
+    pub max_orders: u32,
+    pub window_seconds: u32,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for SetOrderRateLimitInstruction {
+    type Accounts = openbook_v2::accounts::SetOrderRateLimit;
+    type Instruction = openbook_v2::instruction::SetOrderRateLimit;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {
+            max_orders: self.max_orders,
+            window_seconds: self.window_seconds,
+        };
+        let accounts = Self::Accounts {
+            collect_fee_admin: self.collect_fee_admin.pubkey(),
+            market: self.market,
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.collect_fee_admin]
+    }
+}
+
+pub struct SetEvictionPolicyInstruction {
+    pub collect_fee_admin: TestKeypair,
+    pub market: Pubkey,
+    pub policy: EvictionPolicy,
+    pub band_bps: u16,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for SetEvictionPolicyInstruction {
+    type Accounts = openbook_v2::accounts::SetEvictionPolicy;
+    type Instruction = openbook_v2::instruction::SetEvictionPolicy;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {
+            policy: self.policy,
+            band_bps: self.band_bps,
+        };
+        let accounts = Self::Accounts {
+            collect_fee_admin: self.collect_fee_admin.pubkey(),
+            market: self.market,
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.collect_fee_admin]
+    }
+}
+
+pub struct SetFeeRoundingInstruction {
+    pub collect_fee_admin: TestKeypair,
+    pub market: Pubkey,
+    pub taker_fee_rounding: FeeRounding,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for SetFeeRoundingInstruction {
+    type Accounts = openbook_v2::accounts::SetFeeRounding;
+    type Instruction = openbook_v2::instruction::SetFeeRounding;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {
+            taker_fee_rounding: self.taker_fee_rounding,
+        };
+        let accounts = Self::Accounts {
+            collect_fee_admin: self.collect_fee_admin.pubkey(),
+            market: self.market,
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.collect_fee_admin]
+    }
+}
+
+pub struct MigrateAccountInstruction {
+    pub account: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for MigrateAccountInstruction {
+    type Accounts = openbook_v2::accounts::MigrateAccount;
+    type Instruction = openbook_v2::instruction::MigrateAccount;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {};
+        let accounts = Self::Accounts {
+            account: self.account,
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![]
+    }
+}
+
+pub struct ResizeEventHeapInstruction {
+    pub collect_fee_admin: TestKeypair,
+    pub payer: TestKeypair,
+    pub market: Pubkey,
+    pub additional_nodes: u16,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for ResizeEventHeapInstruction {
+    type Accounts = openbook_v2::accounts::ResizeEventHeap;
+    type Instruction = openbook_v2::instruction::ResizeEventHeap;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {
+            additional_nodes: self.additional_nodes,
+        };
+
+        let market: Market = account_loader.load(&self.market).await.unwrap();
+        let accounts = Self::Accounts {
+            collect_fee_admin: self.collect_fee_admin.pubkey(),
+            payer: self.payer.pubkey(),
+            market: self.market,
+            event_heap: market.event_heap,
+            system_program: System::id(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.collect_fee_admin, self.payer]
+    }
+}
+
+pub struct CreateFillCallbackInstruction {
+    pub payer: TestKeypair,
+    pub collect_fee_admin: TestKeypair,
+    pub market: Pubkey,
+    pub program: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for CreateFillCallbackInstruction {
+    type Accounts = openbook_v2::accounts::CreateFillCallback;
+    type Instruction = openbook_v2::instruction::CreateFillCallback;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {
+            program: self.program,
+        };
+        let accounts = Self::Accounts {
+            payer: self.payer.pubkey(),
+            collect_fee_admin: self.collect_fee_admin.pubkey(),
+            market: self.market,
+            fill_callback: get_fill_callback_address(self.market),
+            system_program: System::id(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.payer, self.collect_fee_admin]
+    }
+}
+
+pub struct SetFillCallbackInstruction {
+    pub collect_fee_admin: TestKeypair,
+    pub market: Pubkey,
+    pub program: Option<Pubkey>,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for SetFillCallbackInstruction {
+    type Accounts = openbook_v2::accounts::SetFillCallback;
+    type Instruction = openbook_v2::instruction::SetFillCallback;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {
+            program: self.program,
+        };
+        let accounts = Self::Accounts {
+            collect_fee_admin: self.collect_fee_admin.pubkey(),
+            market: self.market,
+            fill_callback: get_fill_callback_address(self.market),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.collect_fee_admin]
+    }
+}
+
+pub struct SetRiskProgramInstruction {
+    pub owner: TestKeypair,
+    pub open_orders_account: Pubkey,
+    pub program: Option<Pubkey>,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for SetRiskProgramInstruction {
+    type Accounts = openbook_v2::accounts::SetRiskProgram;
+    type Instruction = openbook_v2::instruction::SetRiskProgram;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {
+            program: self.program,
+        };
+        let accounts = Self::Accounts {
+            owner: self.owner.pubkey(),
+            open_orders_account: self.open_orders_account,
+            position_extension: get_position_extension_address(self.open_orders_account),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.owner]
+    }
+}
+
+pub struct SetSettleReceiverInstruction {
+    pub owner: TestKeypair,
+    pub open_orders_account: Pubkey,
+    pub program: Option<Pubkey>,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for SetSettleReceiverInstruction {
+    type Accounts = openbook_v2::accounts::SetSettleReceiver;
+    type Instruction = openbook_v2::instruction::SetSettleReceiver;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {
+            program: self.program,
+        };
+        let accounts = Self::Accounts {
+            owner: self.owner.pubkey(),
+            open_orders_account: self.open_orders_account,
+            position_extension: get_position_extension_address(self.open_orders_account),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.owner]
+    }
+}
+
+pub struct CreateFillHistoryInstruction {
+    pub payer: TestKeypair,
+    pub market: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for CreateFillHistoryInstruction {
+    type Accounts = openbook_v2::accounts::CreateFillHistory;
+    type Instruction = openbook_v2::instruction::CreateFillHistory;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {};
+        let accounts = Self::Accounts {
+            payer: self.payer.pubkey(),
+            market: self.market,
+            fill_history: get_fill_history_address(self.market),
+            system_program: System::id(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.payer]
+    }
+}
+
+pub struct CreateMarketStatsInstruction {
+    pub payer: TestKeypair,
+    pub market: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for CreateMarketStatsInstruction {
+    type Accounts = openbook_v2::accounts::CreateMarketStats;
+    type Instruction = openbook_v2::instruction::CreateMarketStats;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {};
+        let accounts = Self::Accounts {
+            payer: self.payer.pubkey(),
+            market: self.market,
+            market_stats: get_market_stats_address(self.market),
+            system_program: System::id(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.payer]
+    }
+}
+
+pub struct SetMarketStateInstruction {
+    pub collect_fee_admin: TestKeypair,
+    pub market: Pubkey,
+    pub state: MarketState,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for SetMarketStateInstruction {
+    type Accounts = openbook_v2::accounts::SetMarketState;
+    type Instruction = openbook_v2::instruction::SetMarketState;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction { state: self.state };
+        let accounts = Self::Accounts {
+            collect_fee_admin: self.collect_fee_admin.pubkey(),
+            market: self.market,
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.collect_fee_admin]
+    }
+}
+
+pub struct SetBinaryOutcomeInstruction {
+    pub collect_fee_admin: TestKeypair,
+    pub market: Pubkey,
+    pub outcome: BinaryOutcome,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for SetBinaryOutcomeInstruction {
+    type Accounts = openbook_v2::accounts::SetBinaryOutcome;
+    type Instruction = openbook_v2::instruction::SetBinaryOutcome;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {
+            outcome: self.outcome,
+        };
+        let accounts = Self::Accounts {
+            collect_fee_admin: self.collect_fee_admin.pubkey(),
+            market: self.market,
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.collect_fee_admin]
+    }
+}
+
+pub struct SetMarketAuthorityInstruction {
+    pub payer: TestKeypair,
+    pub admin: TestKeypair,
+    pub market: Pubkey,
+    pub role: MarketAdmin,
+    pub new_admin: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for SetMarketAuthorityInstruction {
+    type Accounts = openbook_v2::accounts::SetMarketAuthority;
+    type Instruction = openbook_v2::instruction::SetMarketAuthority;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {
+            role: self.role,
+            new_admin: self.new_admin,
+        };
+        let accounts = Self::Accounts {
+            payer: self.payer.pubkey(),
+            admin: self.admin.pubkey(),
+            market: self.market,
+            pending_market_admin: get_pending_market_admin_address(self.market),
+            system_program: system_program::id(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.payer, self.admin]
+    }
+}
+
+pub struct AcceptMarketAuthorityInstruction {
+    pub pending_admin: TestKeypair,
+    pub market: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for AcceptMarketAuthorityInstruction {
+    type Accounts = openbook_v2::accounts::AcceptMarketAuthority;
+    type Instruction = openbook_v2::instruction::AcceptMarketAuthority;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {};
+
+        let pending_market_admin = get_pending_market_admin_address(self.market);
+        let pending: PendingMarketAdmin = account_loader.load(&pending_market_admin).await.unwrap();
+        let accounts = Self::Accounts {
+            pending_admin: self.pending_admin.pubkey(),
+            market: self.market,
+            pending_market_admin,
+            payer: pending.payer,
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.pending_admin]
+    }
+}
+
+pub struct CancelMarketAuthorityInstruction {
+    pub admin: TestKeypair,
+    pub market: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for CancelMarketAuthorityInstruction {
+    type Accounts = openbook_v2::accounts::CancelMarketAuthority;
+    type Instruction = openbook_v2::instruction::CancelMarketAuthority;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {};
+
+        let pending_market_admin = get_pending_market_admin_address(self.market);
+        let pending: PendingMarketAdmin = account_loader.load(&pending_market_admin).await.unwrap();
+        let accounts = Self::Accounts {
+            admin: self.admin.pubkey(),
+            market: self.market,
+            pending_market_admin,
+            payer: pending.payer,
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.admin]
+    }
+}
+
+pub fn get_market_metadata_address(market: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"MarketMetadata".as_ref(), market.as_ref()],
+        &openbook_v2::id(),
+    )
+    .0
+}
+
+pub struct CreateMarketMetadataInstruction {
+    pub collect_fee_admin: TestKeypair,
+    pub payer: TestKeypair,
+    pub market: Pubkey,
+    pub args: MarketMetadataArgs,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for CreateMarketMetadataInstruction {
+    type Accounts = openbook_v2::accounts::CreateMarketMetadata;
+    type Instruction = openbook_v2::instruction::CreateMarketMetadata;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {
+            args: self.args.clone(),
+        };
+        let accounts = Self::Accounts {
+            collect_fee_admin: self.collect_fee_admin.pubkey(),
+            payer: self.payer.pubkey(),
+            market: self.market,
+            market_metadata: get_market_metadata_address(self.market),
+            system_program: System::id(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.collect_fee_admin, self.payer]
+    }
+}
+
+pub struct SetMarketMetadataInstruction {
+    pub collect_fee_admin: TestKeypair,
+    pub market: Pubkey,
+    pub args: MarketMetadataArgs,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for SetMarketMetadataInstruction {
+    type Accounts = openbook_v2::accounts::SetMarketMetadata;
+    type Instruction = openbook_v2::instruction::SetMarketMetadata;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {
+            args: self.args.clone(),
+        };
+        let accounts = Self::Accounts {
+            collect_fee_admin: self.collect_fee_admin.pubkey(),
+            market: self.market,
+            market_metadata: get_market_metadata_address(self.market),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.collect_fee_admin]
+    }
+}
+
+pub fn get_market_registry_page_address(page_index: u32) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"MarketRegistry".as_ref(), &page_index.to_le_bytes()],
+        &openbook_v2::id(),
+    )
+    .0
+}
+
+pub struct CreateMarketRegistryPageInstruction {
+    pub payer: TestKeypair,
+    pub page_index: u32,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for CreateMarketRegistryPageInstruction {
+    type Accounts = openbook_v2::accounts::CreateMarketRegistryPage;
+    type Instruction = openbook_v2::instruction::CreateMarketRegistryPage;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {
+            page_index: self.page_index,
+        };
+        let accounts = Self::Accounts {
+            payer: self.payer.pubkey(),
+            previous_page: self
+                .page_index
+                .checked_sub(1)
+                .map(get_market_registry_page_address),
+            registry_page: get_market_registry_page_address(self.page_index),
+            system_program: System::id(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.payer]
+    }
+}
+
+pub struct RegisterMarketInstruction {
+    pub market: Pubkey,
+    pub page_index: u32,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for RegisterMarketInstruction {
+    type Accounts = openbook_v2::accounts::RegisterMarket;
+    type Instruction = openbook_v2::instruction::RegisterMarket;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {};
+        let accounts = Self::Accounts {
+            market: self.market,
+            registry_page: get_market_registry_page_address(self.page_index),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![]
+    }
+}
+
+pub fn get_merkle_allowlist_address(market: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"MerkleAllowlist".as_ref(), market.as_ref()],
+        &openbook_v2::id(),
+    )
+    .0
+}
+
+pub struct CreateMerkleAllowlistInstruction {
+    pub open_orders_admin: TestKeypair,
+    pub payer: TestKeypair,
+    pub market: Pubkey,
+    pub root: [u8; 32],
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for CreateMerkleAllowlistInstruction {
+    type Accounts = openbook_v2::accounts::CreateMerkleAllowlist;
+    type Instruction = openbook_v2::instruction::CreateMerkleAllowlist;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction { root: self.root };
+        let accounts = Self::Accounts {
+            open_orders_admin: self.open_orders_admin.pubkey(),
+            payer: self.payer.pubkey(),
+            market: self.market,
+            merkle_allowlist: get_merkle_allowlist_address(self.market),
+            system_program: System::id(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.open_orders_admin, self.payer]
+    }
+}
+
+pub struct JoinMerkleAllowlistInstruction {
+    pub owner: TestKeypair,
+    pub open_orders_account: Pubkey,
+    pub market: Pubkey,
+    pub proof: Vec<[u8; 32]>,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for JoinMerkleAllowlistInstruction {
+    type Accounts = openbook_v2::accounts::JoinMerkleAllowlist;
+    type Instruction = openbook_v2::instruction::JoinMerkleAllowlist;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {
+            proof: self.proof.clone(),
+        };
+        let accounts = Self::Accounts {
+            owner: self.owner.pubkey(),
+            open_orders_account: self.open_orders_account,
+            market: self.market,
+            merkle_allowlist: get_merkle_allowlist_address(self.market),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.owner]
+    }
+}
+
+pub struct RevokeMerkleAllowlistApprovalInstruction {
+    pub open_orders_admin: TestKeypair,
+    pub market: Pubkey,
+    pub open_orders_account: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for RevokeMerkleAllowlistApprovalInstruction {
+    type Accounts = openbook_v2::accounts::RevokeMerkleAllowlistApproval;
+    type Instruction = openbook_v2::instruction::RevokeMerkleAllowlistApproval;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {};
+        let accounts = Self::Accounts {
+            open_orders_admin: self.open_orders_admin.pubkey(),
+            market: self.market,
+            open_orders_account: self.open_orders_account,
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.open_orders_admin]
+    }
+}
+
+pub fn get_token_gate_address(market: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"TokenGate".as_ref(), market.as_ref()],
+        &openbook_v2::id(),
+    )
+    .0
+}
+
+/// Accounts letting the owner of `open_orders_account` place orders through the
+/// market's token gate with their associated token account of the gate mint.
+async fn token_gate_accounts(
+    account_loader: &impl ClientAccountLoader,
+    market: Pubkey,
+    open_orders_account: Pubkey,
+) -> (Option<Pubkey>, Option<Pubkey>) {
+    let token_gate_address = get_token_gate_address(market);
+    let token_gate: TokenGate = match account_loader.load(&token_gate_address).await {
+        Some(token_gate) => token_gate,
+        None => return (None, None),
+    };
+    let open_orders_account: OpenOrdersAccount =
+        account_loader.load(&open_orders_account).await.unwrap();
+    let gate_token_account = spl_associated_token_account::get_associated_token_address(
+        &open_orders_account.owner,
+        &token_gate.mint,
+    );
+    match account_loader.load_bytes(&gate_token_account).await {
+        Some(_) => (Some(token_gate_address), Some(gate_token_account)),
+        None => (None, None),
+    }
+}
+
+pub struct CreateTokenGateInstruction {
+    pub open_orders_admin: TestKeypair,
+    pub payer: TestKeypair,
+    pub market: Pubkey,
+    pub mint: Pubkey,
+    pub min_amount: u64,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for CreateTokenGateInstruction {
+    type Accounts = openbook_v2::accounts::CreateTokenGate;
+    type Instruction = openbook_v2::instruction::CreateTokenGate;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {
+            min_amount: self.min_amount,
+        };
+        let accounts = Self::Accounts {
+            open_orders_admin: self.open_orders_admin.pubkey(),
+            payer: self.payer.pubkey(),
+            market: self.market,
+            mint: self.mint,
+            token_gate: get_token_gate_address(self.market),
+            system_program: System::id(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.open_orders_admin, self.payer]
+    }
+}
+
+pub fn get_taker_program_allowlist_address(market: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"TakerProgramAllowlist".as_ref(), market.as_ref()],
+        &openbook_v2::id(),
+    )
+    .0
+}
+
+pub struct CreateTakerProgramAllowlistInstruction {
+    pub open_orders_admin: TestKeypair,
+    pub payer: TestKeypair,
+    pub market: Pubkey,
+    pub programs: Vec<Pubkey>,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for CreateTakerProgramAllowlistInstruction {
+    type Accounts = openbook_v2::accounts::CreateTakerProgramAllowlist;
+    type Instruction = openbook_v2::instruction::CreateTakerProgramAllowlist;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {
+            programs: self.programs.clone(),
+        };
+        let accounts = Self::Accounts {
+            open_orders_admin: self.open_orders_admin.pubkey(),
+            payer: self.payer.pubkey(),
+            market: self.market,
+            taker_program_allowlist: get_taker_program_allowlist_address(self.market),
+            system_program: System::id(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.open_orders_admin, self.payer]
+    }
+}
+
+pub struct SetTakerProgramAllowlistInstruction {
+    pub open_orders_admin: TestKeypair,
+    pub market: Pubkey,
+    pub programs: Vec<Pubkey>,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for SetTakerProgramAllowlistInstruction {
+    type Accounts = openbook_v2::accounts::SetTakerProgramAllowlist;
+    type Instruction = openbook_v2::instruction::SetTakerProgramAllowlist;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {
+            programs: self.programs.clone(),
+        };
+        let accounts = Self::Accounts {
+            open_orders_admin: self.open_orders_admin.pubkey(),
+            market: self.market,
+            taker_program_allowlist: get_taker_program_allowlist_address(self.market),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.open_orders_admin]
+    }
+}
+
+pub struct ConsumeEventsForAccountsInstruction {
+    pub consume_events_admin: Option<TestKeypair>,
+    pub market: Pubkey,
+    pub open_orders_accounts: Vec<Pubkey>,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for ConsumeEventsForAccountsInstruction {
+    type Accounts = openbook_v2::accounts::ConsumeEvents;
+    type Instruction = openbook_v2::instruction::ConsumeEventsForAccounts;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction { limit: 10 };
+
+        let market: Market = account_loader.load(&self.market).await.unwrap();
+        let accounts = Self::Accounts {
+            consume_events_admin: self.consume_events_admin.map(|kp| kp.pubkey()),
+            market: self.market,
+            event_heap: market.event_heap,
+        };
+
+        let mut instruction = make_instruction(program_id, &accounts, instruction);
+        instruction
+            .accounts
+            .extend(self.open_orders_accounts.iter().map(|ma| AccountMeta {
+                pubkey: *ma,
+                is_signer: false,
+                is_writable: true,
+            }));
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        match self.consume_events_admin {
+            Some(consume_events_admin) => vec![consume_events_admin],
+            None => vec![],
+        }
+    }
+}
+
+pub struct ConsumeGivenEventsInstruction {
+    pub consume_events_admin: Option<TestKeypair>,
+    pub market: Pubkey,
+    pub open_orders_accounts: Vec<Pubkey>,
+    pub slots: Vec<usize>,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for ConsumeGivenEventsInstruction {
+    type Accounts = openbook_v2::accounts::ConsumeEvents;
+    type Instruction = openbook_v2::instruction::ConsumeGivenEvents;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {
+            slots: self.slots.clone(),
+        };
+
+        let market: Market = account_loader.load(&self.market).await.unwrap();
+        let accounts = Self::Accounts {
+            consume_events_admin: self.consume_events_admin.map(|kp| kp.pubkey()),
+            market: self.market,
+            event_heap: market.event_heap,
+        };
+
+        let mut instruction = make_instruction(program_id, &accounts, instruction);
+        instruction
+            .accounts
+            .extend(self.open_orders_accounts.iter().map(|ma| AccountMeta {
+                pubkey: *ma,
+                is_signer: false,
+                is_writable: true,
+            }));
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        match self.consume_events_admin {
+            Some(consume_events_admin) => vec![consume_events_admin],
+            None => vec![],
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct SettleFundsInstruction {
+    pub owner: TestKeypair,
+    pub open_orders_account: Pubkey,
+    pub market: Pubkey,
+    pub market_base_vault: Pubkey,
+    pub market_quote_vault: Pubkey,
+    pub user_base_account: Pubkey,
+    pub user_quote_account: Pubkey,
+    pub referrer_account: Option<Pubkey>,
+    pub remainings: Vec<Pubkey>,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for SettleFundsInstruction {
+    type Accounts = openbook_v2::accounts::SettleFunds;
+    type Instruction = openbook_v2::instruction::SettleFunds;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {};
+        let market: Market = account_loader.load(&self.market).await.unwrap();
+        let accounts = Self::Accounts {
+            owner: self.owner.pubkey(),
+            penalty_payer: self.owner.pubkey(),
+            open_orders_account: self.open_orders_account,
+            market: self.market,
+            market_authority: market.market_authority,
+            market_base_vault: self.market_base_vault,
+            market_quote_vault: self.market_quote_vault,
+            user_base_account: self.user_base_account,
+            user_quote_account: self.user_quote_account,
+            referrer_account: self.referrer_account,
+            token_program: Token::id(),
+            system_program: System::id(),
+        };
+
+        let mut instruction = make_instruction(program_id, &accounts, instruction);
+        instruction
+            .accounts
+            .extend(self.remainings.iter().map(|remaining| AccountMeta {
+                pubkey: *remaining,
+                is_signer: false,
+                is_writable: true,
+            }));
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.owner]
+    }
+}
+
+/// Settles with `penalty_payer` instead of the owner paying the event heap
+/// penalties
+pub struct SettleFundsRelayedInstruction {
+    pub settle: SettleFundsInstruction,
+    pub penalty_payer: TestKeypair,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for SettleFundsRelayedInstruction {
+    type Accounts = openbook_v2::accounts::SettleFunds;
+    type Instruction = openbook_v2::instruction::SettleFunds;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let (mut accounts, mut instruction) = self.settle.to_instruction(account_loader).await;
+        accounts.penalty_payer = self.penalty_payer.pubkey();
+        // the penalty payer is the second account of SettleFunds
+        instruction.accounts[1] = AccountMeta::new(self.penalty_payer.pubkey(), true);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.settle.owner, self.penalty_payer]
+    }
+}
+
+pub struct ClaimRebatesInstruction {
+    pub owner: TestKeypair,
+    pub open_orders_account: Pubkey,
+    pub market: Pubkey,
+    pub rebate_receiver_account: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for ClaimRebatesInstruction {
+    type Accounts = openbook_v2::accounts::ClaimRebates;
+    type Instruction = openbook_v2::instruction::ClaimRebates;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {};
+        let market: Market = account_loader.load(&self.market).await.unwrap();
+        let accounts = Self::Accounts {
+            owner: self.owner.pubkey(),
+            open_orders_account: self.open_orders_account,
+            market: self.market,
+            market_authority: market.market_authority,
+            market_quote_vault: market.market_quote_vault,
+            rebate_receiver_account: self.rebate_receiver_account,
+            token_program: Token::id(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.owner]
+    }
+}
+
+#[derive(Clone)]
+pub struct SettleFundsExpiredInstruction {
+    pub close_market_admin: TestKeypair,
+    pub owner: TestKeypair,
+    pub open_orders_account: Pubkey,
+    pub market: Pubkey,
+    pub market_base_vault: Pubkey,
+    pub market_quote_vault: Pubkey,
+    pub user_base_account: Pubkey,
+    pub user_quote_account: Pubkey,
+    pub referrer_account: Option<Pubkey>,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for SettleFundsExpiredInstruction {
+    type Accounts = openbook_v2::accounts::SettleFundsExpired;
+    type Instruction = openbook_v2::instruction::SettleFundsExpired;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {};
+        let market: Market = account_loader.load(&self.market).await.unwrap();
+        let accounts = Self::Accounts {
+            close_market_admin: self.close_market_admin.pubkey(),
+            owner: self.owner.pubkey(),
+            penalty_payer: self.owner.pubkey(),
+            open_orders_account: self.open_orders_account,
+            market: self.market,
+            market_authority: market.market_authority,
+            market_base_vault: self.market_base_vault,
+            market_quote_vault: self.market_quote_vault,
+            user_base_account: self.user_base_account,
+            user_quote_account: self.user_quote_account,
+            referrer_account: self.referrer_account,
+            token_program: Token::id(),
+            system_program: System::id(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.close_market_admin, self.owner]
+    }
+}
+
+#[derive(Clone)]
+pub struct SettleFundsMultipleEntry {
+    pub open_orders_account: Pubkey,
+    pub market: Pubkey,
+    pub market_base_vault: Pubkey,
+    pub market_quote_vault: Pubkey,
+    pub user_base_account: Pubkey,
+    pub user_quote_account: Pubkey,
+}
+
+#[derive(Clone)]
+pub struct SettleFundsMultipleInstruction {
+    pub owner: TestKeypair,
+    pub entries: Vec<SettleFundsMultipleEntry>,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for SettleFundsMultipleInstruction {
+    type Accounts = openbook_v2::accounts::SettleFundsMultiple;
+    type Instruction = openbook_v2::instruction::SettleFundsMultiple;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {};
+        let accounts = Self::Accounts {
+            owner: self.owner.pubkey(),
+            penalty_payer: self.owner.pubkey(),
+            token_program: Token::id(),
+            system_program: System::id(),
+        };
+
+        let mut instruction = make_instruction(program_id, &accounts, instruction);
+        for entry in &self.entries {
+            let market: Market = account_loader.load(&entry.market).await.unwrap();
+            instruction.accounts.extend(
+                [
+                    (entry.open_orders_account, true),
+                    (entry.market, true),
+                    (market.market_authority, false),
+                    (entry.market_base_vault, true),
+                    (entry.market_quote_vault, true),
+                    (entry.user_base_account, true),
+                    (entry.user_quote_account, true),
+                ]
+                .into_iter()
+                .map(|(pubkey, is_writable)| AccountMeta {
+                    pubkey,
+                    is_signer: false,
+                    is_writable,
+                }),
+            );
+        }
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.owner]
+    }
+}
+
+pub struct SweepQuoteDustInstruction {
+    pub market: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for SweepQuoteDustInstruction {
+    type Accounts = openbook_v2::accounts::SweepQuoteDust;
+    type Instruction = openbook_v2::instruction::SweepQuoteDust;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {};
+        let market: Market = account_loader.load(&self.market).await.unwrap();
+
+        let accounts = Self::Accounts {
+            market: self.market,
+            market_quote_vault: market.market_quote_vault,
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![]
+    }
+}
+
+pub struct SweepFeesInstruction {
+    pub collect_fee_admin: TestKeypair,
+    pub market: Pubkey,
+    pub market_quote_vault: Pubkey,
+    pub token_receiver_account: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for SweepFeesInstruction {
+    type Accounts = openbook_v2::accounts::SweepFees;
+    type Instruction = openbook_v2::instruction::SweepFees;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {};
+        let market: Market = account_loader.load(&self.market).await.unwrap();
+
+        let accounts = Self::Accounts {
+            collect_fee_admin: self.collect_fee_admin.pubkey(),
+            market: self.market,
+            market_authority: market.market_authority,
+            market_quote_vault: self.market_quote_vault,
+            token_receiver_account: self.token_receiver_account,
+            token_program: Token::id(),
+        };
+        let instruction = make_instruction(program_id, &accounts, instruction);
+
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.collect_fee_admin]
+    }
+}
+
+pub struct CreateMakerRewardsInstruction {
+    pub collect_fee_admin: TestKeypair,
+    pub payer: TestKeypair,
+    pub market: Pubkey,
+    pub reward_mint: Pubkey,
+    pub rewards_per_million_points: u64,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for CreateMakerRewardsInstruction {
+    type Accounts = openbook_v2::accounts::CreateMakerRewards;
+    type Instruction = openbook_v2::instruction::CreateMakerRewards;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {
+            rewards_per_million_points: self.rewards_per_million_points,
+        };
+        let maker_rewards = get_maker_rewards_address(self.market);
+        let accounts = Self::Accounts {
+            collect_fee_admin: self.collect_fee_admin.pubkey(),
+            payer: self.payer.pubkey(),
+            market: self.market,
+            maker_rewards,
+            reward_mint: self.reward_mint,
+            reward_vault: spl_associated_token_account::get_associated_token_address(
+                &maker_rewards,
+                &self.reward_mint,
+            ),
+            system_program: System::id(),
+            token_program: Token::id(),
+            associated_token_program: AssociatedToken::id(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.collect_fee_admin, self.payer]
+    }
+}
+
+pub struct ClaimMakerRewardsInstruction {
+    pub owner: TestKeypair,
+    pub open_orders_account: Pubkey,
+    pub market: Pubkey,
+    pub user_reward_account: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for ClaimMakerRewardsInstruction {
+    type Accounts = openbook_v2::accounts::ClaimMakerRewards;
+    type Instruction = openbook_v2::instruction::ClaimMakerRewards;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {};
+        let maker_rewards_address = get_maker_rewards_address(self.market);
+        let maker_rewards: MakerRewards =
+            account_loader.load(&maker_rewards_address).await.unwrap();
+        let accounts = Self::Accounts {
+            owner: self.owner.pubkey(),
+            open_orders_account: self.open_orders_account,
+            market: self.market,
+            maker_rewards: maker_rewards_address,
+            reward_vault: maker_rewards.reward_vault,
+            user_reward_account: self.user_reward_account,
+            token_program: Token::id(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.owner]
+    }
+}
+
+pub struct CreateQuoteQualityInstruction {
+    pub collect_fee_admin: TestKeypair,
+    pub payer: TestKeypair,
+    pub market: Pubkey,
+    pub open_orders_account: Pubkey,
+    pub max_oracle_distance_bps: u16,
+    pub min_size_lots: i64,
+    pub epoch_duration: u64,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for CreateQuoteQualityInstruction {
+    type Accounts = openbook_v2::accounts::CreateQuoteQuality;
+    type Instruction = openbook_v2::instruction::CreateQuoteQuality;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {
+            max_oracle_distance_bps: self.max_oracle_distance_bps,
+            min_size_lots: self.min_size_lots,
+            epoch_duration: self.epoch_duration,
+        };
+        let accounts = Self::Accounts {
+            collect_fee_admin: self.collect_fee_admin.pubkey(),
+            payer: self.payer.pubkey(),
+            market: self.market,
+            open_orders_account: self.open_orders_account,
+            quote_quality: get_quote_quality_address(self.open_orders_account),
+            system_program: System::id(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.collect_fee_admin, self.payer]
+    }
+}
+
+pub struct SampleQuoteQualityInstruction {
+    pub market: Pubkey,
+    pub open_orders_account: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for SampleQuoteQualityInstruction {
+    type Accounts = openbook_v2::accounts::SampleQuoteQuality;
+    type Instruction = openbook_v2::instruction::SampleQuoteQuality;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {};
+        let market: Market = account_loader.load(&self.market).await.unwrap();
+        let accounts = Self::Accounts {
+            quote_quality: get_quote_quality_address(self.open_orders_account),
+            market: self.market,
+            bids: market.bids,
+            asks: market.asks,
+            oracle_a: market.oracle_a.into(),
+            oracle_b: market.oracle_b.into(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![]
+    }
+}
+
+pub struct CreatePositionExtensionInstruction {
+    pub owner: TestKeypair,
+    pub payer: TestKeypair,
+    pub open_orders_account: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for CreatePositionExtensionInstruction {
+    type Accounts = openbook_v2::accounts::CreatePositionExtension;
+    type Instruction = openbook_v2::instruction::CreatePositionExtension;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {};
+        let accounts = Self::Accounts {
+            owner: self.owner.pubkey(),
+            payer: self.payer.pubkey(),
+            open_orders_account: self.open_orders_account,
+            position_extension: get_position_extension_address(self.open_orders_account),
+            system_program: System::id(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.owner, self.payer]
+    }
+}
+
+pub struct CreateDmmRegistryInstruction {
+    pub collect_fee_admin: TestKeypair,
+    pub payer: TestKeypair,
+    pub market: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for CreateDmmRegistryInstruction {
+    type Accounts = openbook_v2::accounts::CreateDmmRegistry;
+    type Instruction = openbook_v2::instruction::CreateDmmRegistry;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {};
+        let accounts = Self::Accounts {
+            collect_fee_admin: self.collect_fee_admin.pubkey(),
+            payer: self.payer.pubkey(),
+            market: self.market,
+            dmm_registry: get_dmm_registry_address(self.market),
+            system_program: System::id(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.collect_fee_admin, self.payer]
+    }
+}
+
+pub struct SetDmmSlotInstruction {
+    pub collect_fee_admin: TestKeypair,
+    pub market: Pubkey,
+    pub open_orders_account: Pubkey,
+    pub priority: u8,
+    pub min_uptime_bps: u16,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for SetDmmSlotInstruction {
+    type Accounts = openbook_v2::accounts::SetDmmSlot;
+    type Instruction = openbook_v2::instruction::SetDmmSlot;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {
+            priority: self.priority,
+            min_uptime_bps: self.min_uptime_bps,
+        };
+        let accounts = Self::Accounts {
+            collect_fee_admin: self.collect_fee_admin.pubkey(),
+            market: self.market,
+            dmm_registry: get_dmm_registry_address(self.market),
+            open_orders_account: self.open_orders_account,
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.collect_fee_admin]
+    }
+}
+
+pub struct EnforceDmmObligationInstruction {
+    pub market: Pubkey,
+    pub open_orders_account: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for EnforceDmmObligationInstruction {
+    type Accounts = openbook_v2::accounts::EnforceDmmObligation;
+    type Instruction = openbook_v2::instruction::EnforceDmmObligation;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {};
+        let accounts = Self::Accounts {
+            market: self.market,
+            dmm_registry: get_dmm_registry_address(self.market),
+            open_orders_account: self.open_orders_account,
+            quote_quality: get_quote_quality_address(self.open_orders_account),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![]
+    }
+}
+
+pub struct CreateTakerRebateCampaignInstruction {
+    pub collect_fee_admin: TestKeypair,
+    pub payer: TestKeypair,
+    pub market: Pubkey,
+    pub start_timestamp: u64,
+    pub end_timestamp: u64,
+    pub rebate_bps: u16,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for CreateTakerRebateCampaignInstruction {
+    type Accounts = openbook_v2::accounts::CreateTakerRebateCampaign;
+    type Instruction = openbook_v2::instruction::CreateTakerRebateCampaign;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {
+            start_timestamp: self.start_timestamp,
+            end_timestamp: self.end_timestamp,
+            rebate_bps: self.rebate_bps,
+        };
+        let market: Market = account_loader.load(&self.market).await.unwrap();
+        let taker_rebate_campaign = get_taker_rebate_campaign_address(self.market);
+        let accounts = Self::Accounts {
+            collect_fee_admin: self.collect_fee_admin.pubkey(),
+            payer: self.payer.pubkey(),
+            market: self.market,
+            taker_rebate_campaign,
+            quote_mint: market.quote_mint,
+            rebate_vault: spl_associated_token_account::get_associated_token_address(
+                &taker_rebate_campaign,
+                &market.quote_mint,
+            ),
+            system_program: System::id(),
+            token_program: Token::id(),
+            associated_token_program: AssociatedToken::id(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.collect_fee_admin, self.payer]
+    }
+}
+
+pub struct FundTakerRebateCampaignInstruction {
+    pub collect_fee_admin: TestKeypair,
+    pub market: Pubkey,
+    pub admin_quote_account: Pubkey,
+    pub amount: u64,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for FundTakerRebateCampaignInstruction {
+    type Accounts = openbook_v2::accounts::FundTakerRebateCampaign;
+    type Instruction = openbook_v2::instruction::FundTakerRebateCampaign;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {
+            amount: self.amount,
+        };
+        let campaign_address = get_taker_rebate_campaign_address(self.market);
+        let campaign: TakerRebateCampaign = account_loader.load(&campaign_address).await.unwrap();
+        let accounts = Self::Accounts {
+            collect_fee_admin: self.collect_fee_admin.pubkey(),
+            market: self.market,
+            taker_rebate_campaign: campaign_address,
+            rebate_vault: campaign.rebate_vault,
+            admin_quote_account: self.admin_quote_account,
+            token_program: Token::id(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.collect_fee_admin]
+    }
+}
+
+#[derive(Clone)]
+pub struct WithdrawTakerRebateBudgetInstruction {
+    pub collect_fee_admin: TestKeypair,
+    pub market: Pubkey,
+    pub token_receiver_account: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for WithdrawTakerRebateBudgetInstruction {
+    type Accounts = openbook_v2::accounts::WithdrawTakerRebateBudget;
+    type Instruction = openbook_v2::instruction::WithdrawTakerRebateBudget;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {};
+        let campaign_address = get_taker_rebate_campaign_address(self.market);
+        let campaign: TakerRebateCampaign = account_loader.load(&campaign_address).await.unwrap();
+        let accounts = Self::Accounts {
+            collect_fee_admin: self.collect_fee_admin.pubkey(),
+            market: self.market,
+            taker_rebate_campaign: campaign_address,
+            rebate_vault: campaign.rebate_vault,
+            token_receiver_account: self.token_receiver_account,
+            token_program: Token::id(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.collect_fee_admin]
+    }
+}
+
+pub struct ClaimTakerRebatesInstruction {
+    pub owner: TestKeypair,
+    pub open_orders_account: Pubkey,
+    pub market: Pubkey,
+    pub user_quote_account: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for ClaimTakerRebatesInstruction {
+    type Accounts = openbook_v2::accounts::ClaimTakerRebates;
+    type Instruction = openbook_v2::instruction::ClaimTakerRebates;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {};
+        let campaign_address = get_taker_rebate_campaign_address(self.market);
+        let campaign: TakerRebateCampaign = account_loader.load(&campaign_address).await.unwrap();
+        let accounts = Self::Accounts {
+            owner: self.owner.pubkey(),
+            open_orders_account: self.open_orders_account,
+            market: self.market,
+            taker_rebate_campaign: campaign_address,
+            rebate_vault: campaign.rebate_vault,
+            user_quote_account: self.user_quote_account,
+            token_program: Token::id(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.owner]
+    }
+}
+
+pub struct DepositInstruction {
+    pub open_orders_account: Pubkey,
+    pub market: Pubkey,
+    pub market_base_vault: Pubkey,
+    pub market_quote_vault: Pubkey,
+    pub user_base_account: Pubkey,
     pub user_quote_account: Pubkey,
     pub owner: TestKeypair,
     pub base_amount: u64,
@@ -982,16 +3590,57 @@ impl ClientInstruction for DepositInstruction {
 
         let accounts = Self::Accounts {
             owner: self.owner.pubkey(),
-            open_orders_account: self.open_orders_account,
-            market: self.market,
-            market_base_vault: self.market_base_vault,
-            market_quote_vault: self.market_quote_vault,
-            user_base_account: self.user_base_account,
-            user_quote_account: self.user_quote_account,
-            token_program: Token::id(),
+            open_orders_account: self.open_orders_account,
+            market: self.market,
+            market_base_vault: self.market_base_vault,
+            market_quote_vault: self.market_quote_vault,
+            user_base_account: self.user_base_account,
+            user_quote_account: self.user_quote_account,
+            token_program: Token::id(),
+        };
+        let instruction = make_instruction(program_id, &accounts, instruction);
+
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.owner]
+    }
+}
+
+pub struct StubOracleSetInstruction {
+    pub mint: Pubkey,
+    pub owner: TestKeypair,
+    pub price: f64,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for StubOracleSetInstruction {
+    type Accounts = openbook_v2::accounts::StubOracleSet;
+    type Instruction = openbook_v2::instruction::StubOracleSet;
+
+    async fn to_instruction(
+        &self,
+        _loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction { price: self.price };
+
+        let oracle = Pubkey::find_program_address(
+            &[
+                b"StubOracle".as_ref(),
+                self.owner.pubkey().as_ref(),
+                self.mint.as_ref(),
+            ],
+            &program_id,
+        )
+        .0;
+
+        let accounts = Self::Accounts {
+            oracle,
+            owner: self.owner.pubkey(),
         };
-        let instruction = make_instruction(program_id, &accounts, instruction);
 
+        let instruction = make_instruction(program_id, &accounts, instruction);
         (accounts, instruction)
     }
 
@@ -1000,22 +3649,33 @@ impl ClientInstruction for DepositInstruction {
     }
 }
 
-pub struct StubOracleSetInstruction {
+#[derive(Clone)]
+pub struct StubOracleSetTestInstruction {
     pub mint: Pubkey,
     pub owner: TestKeypair,
     pub price: f64,
+    pub exponent: i8,
+    pub deviation: f64,
+    pub last_update_ts: i64,
+    pub last_update_slot: u64,
 }
 #[async_trait::async_trait(?Send)]
-impl ClientInstruction for StubOracleSetInstruction {
+impl ClientInstruction for StubOracleSetTestInstruction {
     type Accounts = openbook_v2::accounts::StubOracleSet;
-    type Instruction = openbook_v2::instruction::StubOracleSet;
+    type Instruction = openbook_v2::instruction::StubOracleSetTest;
 
     async fn to_instruction(
         &self,
         _loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, Instruction) {
         let program_id = openbook_v2::id();
-        let instruction = Self::Instruction { price: self.price };
+        let instruction = Self::Instruction {
+            price: self.price,
+            exponent: self.exponent,
+            deviation: self.deviation,
+            last_update_ts: self.last_update_ts,
+            last_update_slot: self.last_update_slot,
+        };
 
         let oracle = Pubkey::find_program_address(
             &[
@@ -1081,63 +3741,317 @@ impl ClientInstruction for StubOracleCreate {
     }
 
     fn signers(&self) -> Vec<TestKeypair> {
-        vec![self.payer, self.owner]
+        vec![self.payer, self.owner]
+    }
+}
+
+pub struct StubOracleCloseInstruction {
+    pub mint: Pubkey,
+    pub owner: TestKeypair,
+    pub sol_destination: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for StubOracleCloseInstruction {
+    type Accounts = openbook_v2::accounts::StubOracleClose;
+    type Instruction = openbook_v2::instruction::StubOracleClose;
+
+    async fn to_instruction(
+        &self,
+        _loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {};
+
+        let oracle = Pubkey::find_program_address(
+            &[
+                b"StubOracle".as_ref(),
+                self.owner.pubkey().as_ref(),
+                self.mint.as_ref(),
+            ],
+            &program_id,
+        )
+        .0;
+
+        let accounts = Self::Accounts {
+            owner: self.owner.pubkey(),
+            oracle,
+            sol_destination: self.sol_destination,
+            token_program: Token::id(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.owner]
+    }
+}
+
+#[derive(Clone)]
+pub struct CloseMarketInstruction {
+    pub close_market_admin: TestKeypair,
+    pub market: Pubkey,
+    pub base_dust_receiver: Pubkey,
+    pub quote_dust_receiver: Pubkey,
+    pub sol_destination: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for CloseMarketInstruction {
+    type Accounts = openbook_v2::accounts::CloseMarket;
+    type Instruction = openbook_v2::instruction::CloseMarket;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {};
+        let market: Market = account_loader.load(&self.market).await.unwrap();
+
+        let accounts = Self::Accounts {
+            close_market_admin: self.close_market_admin.pubkey(),
+            market: self.market,
+            market_authority: market.market_authority,
+            bids: market.bids,
+            asks: market.asks,
+            event_heap: market.event_heap,
+            market_base_vault: market.market_base_vault,
+            market_quote_vault: market.market_quote_vault,
+            base_dust_receiver: self.base_dust_receiver,
+            quote_dust_receiver: self.quote_dust_receiver,
+            token_program: Token::id(),
+            sol_destination: self.sol_destination,
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.close_market_admin]
+    }
+}
+
+pub struct SetMarketExpiredInstruction {
+    pub close_market_admin: TestKeypair,
+    pub market: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for SetMarketExpiredInstruction {
+    type Accounts = openbook_v2::accounts::SetMarketExpired;
+    type Instruction = openbook_v2::instruction::SetMarketExpired;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {};
+
+        let accounts = Self::Accounts {
+            close_market_admin: self.close_market_admin.pubkey(),
+            market: self.market,
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.close_market_admin]
+    }
+}
+
+pub struct PruneOrdersInstruction {
+    pub close_market_admin: TestKeypair,
+    pub market: Pubkey,
+    pub open_orders_account: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for PruneOrdersInstruction {
+    type Accounts = openbook_v2::accounts::PruneOrders;
+    type Instruction = openbook_v2::instruction::PruneOrders;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction { limit: 255 };
+        let market: Market = account_loader.load(&self.market).await.unwrap();
+
+        let accounts = Self::Accounts {
+            close_market_admin: self.close_market_admin.pubkey(),
+            market: self.market,
+            open_orders_account: self.open_orders_account,
+            bids: market.bids,
+            asks: market.asks,
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.close_market_admin]
+    }
+}
+
+pub struct ForceCancelOrdersInstruction {
+    pub close_market_admin: TestKeypair,
+    pub market: Pubkey,
+    pub open_orders_account: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for ForceCancelOrdersInstruction {
+    type Accounts = openbook_v2::accounts::ForceCancelOrders;
+    type Instruction = openbook_v2::instruction::ForceCancelOrders;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction { limit: 255 };
+        let market: Market = account_loader.load(&self.market).await.unwrap();
+
+        let accounts = Self::Accounts {
+            close_market_admin: self.close_market_admin.pubkey(),
+            market: self.market,
+            open_orders_account: self.open_orders_account,
+            bids: market.bids,
+            asks: market.asks,
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.close_market_admin]
+    }
+}
+
+pub struct DefragmentBookInstruction {
+    pub market: Pubkey,
+    pub limit: u8,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for DefragmentBookInstruction {
+    type Accounts = openbook_v2::accounts::DefragmentBook;
+    type Instruction = openbook_v2::instruction::DefragmentBook;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction { limit: self.limit };
+        let market: Market = account_loader.load(&self.market).await.unwrap();
+
+        let accounts = Self::Accounts {
+            market: self.market,
+            bids: market.bids,
+            asks: market.asks,
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![]
+    }
+}
+
+pub struct ExpireOrdersInstruction {
+    pub market: Pubkey,
+    pub limit: u8,
+    pub open_orders_accounts: Vec<Pubkey>,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for ExpireOrdersInstruction {
+    type Accounts = openbook_v2::accounts::ExpireOrders;
+    type Instruction = openbook_v2::instruction::ExpireOrders;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction { limit: self.limit };
+        let market: Market = account_loader.load(&self.market).await.unwrap();
+
+        let accounts = Self::Accounts {
+            market: self.market,
+            bids: market.bids,
+            asks: market.asks,
+            event_heap: market.event_heap,
+        };
+
+        let mut instruction = make_instruction(program_id, &accounts, instruction);
+        instruction
+            .accounts
+            .extend(self.open_orders_accounts.iter().map(|ma| AccountMeta {
+                pubkey: *ma,
+                is_signer: false,
+                is_writable: true,
+            }));
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![]
     }
 }
 
-pub struct StubOracleCloseInstruction {
-    pub mint: Pubkey,
-    pub owner: TestKeypair,
-    pub sol_destination: Pubkey,
+pub struct CreateBookSidePageInstruction {
+    pub market: Pubkey,
+    pub side: Side,
+    pub page: Pubkey,
+    /// Existing pages of the book side, in order
+    pub pages: Vec<Pubkey>,
 }
 #[async_trait::async_trait(?Send)]
-impl ClientInstruction for StubOracleCloseInstruction {
-    type Accounts = openbook_v2::accounts::StubOracleClose;
-    type Instruction = openbook_v2::instruction::StubOracleClose;
-
+impl ClientInstruction for CreateBookSidePageInstruction {
+    type Accounts = openbook_v2::accounts::CreateBookSidePage;
+    type Instruction = openbook_v2::instruction::CreateBookSidePage;
     async fn to_instruction(
         &self,
-        _loader: impl ClientAccountLoader + 'async_trait,
-    ) -> (Self::Accounts, Instruction) {
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
         let program_id = openbook_v2::id();
         let instruction = Self::Instruction {};
-
-        let oracle = Pubkey::find_program_address(
-            &[
-                b"StubOracle".as_ref(),
-                self.owner.pubkey().as_ref(),
-                self.mint.as_ref(),
-            ],
-            &program_id,
-        )
-        .0;
+        let market: Market = account_loader.load(&self.market).await.unwrap();
 
         let accounts = Self::Accounts {
-            owner: self.owner.pubkey(),
-            oracle,
-            sol_destination: self.sol_destination,
-            token_program: Token::id(),
+            market: self.market,
+            book_side: match self.side {
+                Side::Bid => market.bids,
+                Side::Ask => market.asks,
+            },
+            page: self.page,
         };
 
-        let instruction = make_instruction(program_id, &accounts, instruction);
+        let mut instruction = make_instruction(program_id, &accounts, instruction);
+        instruction
+            .accounts
+            .extend(self.pages.iter().map(|page| AccountMeta {
+                pubkey: *page,
+                is_signer: false,
+                is_writable: true,
+            }));
         (accounts, instruction)
     }
 
     fn signers(&self) -> Vec<TestKeypair> {
-        vec![self.owner]
+        vec![]
     }
 }
 
-#[derive(Clone)]
-pub struct CloseMarketInstruction {
-    pub close_market_admin: TestKeypair,
+pub struct VerifyMarketInvariantsInstruction {
     pub market: Pubkey,
-    pub sol_destination: Pubkey,
+    pub open_orders_accounts: Vec<Pubkey>,
 }
 #[async_trait::async_trait(?Send)]
-impl ClientInstruction for CloseMarketInstruction {
-    type Accounts = openbook_v2::accounts::CloseMarket;
-    type Instruction = openbook_v2::instruction::CloseMarket;
+impl ClientInstruction for VerifyMarketInvariantsInstruction {
+    type Accounts = openbook_v2::accounts::VerifyMarketInvariants;
+    type Instruction = openbook_v2::instruction::VerifyMarketInvariants;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
@@ -1147,45 +4061,67 @@ impl ClientInstruction for CloseMarketInstruction {
         let market: Market = account_loader.load(&self.market).await.unwrap();
 
         let accounts = Self::Accounts {
-            close_market_admin: self.close_market_admin.pubkey(),
             market: self.market,
-            bids: market.bids,
-            asks: market.asks,
             event_heap: market.event_heap,
-            token_program: Token::id(),
-            sol_destination: self.sol_destination,
+            market_base_vault: market.market_base_vault,
+            market_quote_vault: market.market_quote_vault,
         };
 
-        let instruction = make_instruction(program_id, &accounts, instruction);
+        let mut instruction = make_instruction(program_id, &accounts, instruction);
+        instruction
+            .accounts
+            .extend(self.open_orders_accounts.iter().map(|ma| AccountMeta {
+                pubkey: *ma,
+                is_signer: false,
+                is_writable: false,
+            }));
         (accounts, instruction)
     }
 
     fn signers(&self) -> Vec<TestKeypair> {
-        vec![self.close_market_admin]
+        vec![]
     }
 }
 
-pub struct SetMarketExpiredInstruction {
+#[derive(Clone)]
+pub struct SettleExpiredMarketInstruction {
     pub close_market_admin: TestKeypair,
     pub market: Pubkey,
+    pub settlement_price_lots: Option<i64>,
+    pub open_orders_accounts: Vec<Pubkey>,
 }
 #[async_trait::async_trait(?Send)]
-impl ClientInstruction for SetMarketExpiredInstruction {
-    type Accounts = openbook_v2::accounts::SetMarketExpired;
-    type Instruction = openbook_v2::instruction::SetMarketExpired;
+impl ClientInstruction for SettleExpiredMarketInstruction {
+    type Accounts = openbook_v2::accounts::SettleExpiredMarket;
+    type Instruction = openbook_v2::instruction::SettleExpiredMarket;
     async fn to_instruction(
         &self,
-        _account_loader: impl ClientAccountLoader + 'async_trait,
+        account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
         let program_id = openbook_v2::id();
-        let instruction = Self::Instruction {};
+        let instruction = Self::Instruction {
+            limit: 255,
+            settlement_price_lots: self.settlement_price_lots,
+        };
+        let market: Market = account_loader.load(&self.market).await.unwrap();
 
         let accounts = Self::Accounts {
             close_market_admin: self.close_market_admin.pubkey(),
             market: self.market,
+            bids: market.bids,
+            asks: market.asks,
+            oracle_a: market.oracle_a.into(),
+            oracle_b: market.oracle_b.into(),
         };
 
-        let instruction = make_instruction(program_id, &accounts, instruction);
+        let mut instruction = make_instruction(program_id, &accounts, instruction);
+        instruction
+            .accounts
+            .extend(self.open_orders_accounts.iter().map(|ma| AccountMeta {
+                pubkey: *ma,
+                is_signer: false,
+                is_writable: true,
+            }));
         (accounts, instruction)
     }
 
@@ -1194,29 +4130,26 @@ impl ClientInstruction for SetMarketExpiredInstruction {
     }
 }
 
-pub struct PruneOrdersInstruction {
-    pub close_market_admin: TestKeypair,
-    pub market: Pubkey,
+pub struct SetDelegateInstruction {
+    pub delegate_account: Option<Pubkey>,
+    pub owner: TestKeypair,
     pub open_orders_account: Pubkey,
 }
 #[async_trait::async_trait(?Send)]
-impl ClientInstruction for PruneOrdersInstruction {
-    type Accounts = openbook_v2::accounts::PruneOrders;
-    type Instruction = openbook_v2::instruction::PruneOrders;
+impl ClientInstruction for SetDelegateInstruction {
+    type Accounts = openbook_v2::accounts::SetDelegate;
+    type Instruction = openbook_v2::instruction::SetDelegate;
     async fn to_instruction(
         &self,
-        account_loader: impl ClientAccountLoader + 'async_trait,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
         let program_id = openbook_v2::id();
-        let instruction = Self::Instruction { limit: 255 };
-        let market: Market = account_loader.load(&self.market).await.unwrap();
+        let instruction = Self::Instruction {};
 
         let accounts = Self::Accounts {
-            close_market_admin: self.close_market_admin.pubkey(),
-            market: self.market,
+            owner: self.owner.pubkey(),
             open_orders_account: self.open_orders_account,
-            bids: market.bids,
-            asks: market.asks,
+            delegate_account: self.delegate_account,
         };
 
         let instruction = make_instruction(program_id, &accounts, instruction);
@@ -1224,30 +4157,31 @@ impl ClientInstruction for PruneOrdersInstruction {
     }
 
     fn signers(&self) -> Vec<TestKeypair> {
-        vec![self.close_market_admin]
+        vec![self.owner]
     }
 }
 
-pub struct SetDelegateInstruction {
-    pub delegate_account: Option<Pubkey>,
+pub struct SetDirectSettlementInstruction {
+    pub enabled: bool,
     pub owner: TestKeypair,
     pub open_orders_account: Pubkey,
 }
 #[async_trait::async_trait(?Send)]
-impl ClientInstruction for SetDelegateInstruction {
-    type Accounts = openbook_v2::accounts::SetDelegate;
-    type Instruction = openbook_v2::instruction::SetDelegate;
+impl ClientInstruction for SetDirectSettlementInstruction {
+    type Accounts = openbook_v2::accounts::SetDirectSettlement;
+    type Instruction = openbook_v2::instruction::SetDirectSettlement;
     async fn to_instruction(
         &self,
         _account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
         let program_id = openbook_v2::id();
-        let instruction = Self::Instruction {};
+        let instruction = Self::Instruction {
+            enabled: self.enabled,
+        };
 
         let accounts = Self::Accounts {
             owner: self.owner.pubkey(),
             open_orders_account: self.open_orders_account,
-            delegate_account: self.delegate_account,
         };
 
         let instruction = make_instruction(program_id, &accounts, instruction);
@@ -1306,6 +4240,8 @@ impl ClientInstruction for EditOrderInstruction {
 
         let market: Market = account_loader.load(&self.market).await.unwrap();
 
+        let (token_gate, gate_token_account) =
+            token_gate_accounts(&account_loader, self.market, self.open_orders_account).await;
         let accounts = Self::Accounts {
             open_orders_account: self.open_orders_account,
             open_orders_admin: self.open_orders_admin.map(|kp| kp.pubkey()),
@@ -1319,6 +4255,10 @@ impl ClientInstruction for EditOrderInstruction {
             user_token_account: self.user_token_account,
             market_vault: self.market_vault,
             token_program: Token::id(),
+            token_gate,
+            gate_token_account,
+            event_authority: get_event_authority_address(),
+            program: openbook_v2::id(),
         };
         let mut instruction = make_instruction(program_id, &accounts, instruction);
         let mut vec_remainings: Vec<AccountMeta> = Vec::new();
@@ -1374,6 +4314,8 @@ impl ClientInstruction for CancelAllAndPlaceOrdersInstruction {
 
         let market: Market = account_loader.load(&self.market).await.unwrap();
 
+        let (token_gate, gate_token_account) =
+            token_gate_accounts(&account_loader, self.market, self.open_orders_account).await;
         let accounts = Self::Accounts {
             open_orders_account: self.open_orders_account,
             open_orders_admin: self.open_orders_admin.map(|kp| kp.pubkey()),
@@ -1389,6 +4331,10 @@ impl ClientInstruction for CancelAllAndPlaceOrdersInstruction {
             market_base_vault: market.market_base_vault,
             market_quote_vault: market.market_quote_vault,
             token_program: Token::id(),
+            token_gate,
+            gate_token_account,
+            event_authority: get_event_authority_address(),
+            program: openbook_v2::id(),
         };
         let instruction = make_instruction(program_id, &accounts, instruction);
         (accounts, instruction)
@@ -1403,3 +4349,190 @@ impl ClientInstruction for CancelAllAndPlaceOrdersInstruction {
         signers
     }
 }
+
+pub struct CreateTriggerOrderInstruction {
+    pub signer: TestKeypair,
+    pub payer: TestKeypair,
+    pub open_orders_account: Pubkey,
+    pub market: Pubkey,
+    pub trigger_id: u64,
+    pub condition: TriggerCondition,
+    pub trigger_price_lots: i64,
+    pub side: Side,
+    pub price_lots: i64,
+    pub max_base_lots: i64,
+    pub max_quote_lots_including_fees: i64,
+    pub order_type: PlaceOrderType,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for CreateTriggerOrderInstruction {
+    type Accounts = openbook_v2::accounts::CreateTriggerOrder;
+    type Instruction = openbook_v2::instruction::CreateTriggerOrder;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {
+            trigger_id: self.trigger_id,
+            condition: self.condition,
+            trigger_price_lots: self.trigger_price_lots,
+            order: PlaceOrderArgs {
+                side: self.side,
+                price_lots: self.price_lots,
+                max_base_lots: self.max_base_lots,
+                max_quote_lots_including_fees: self.max_quote_lots_including_fees,
+                client_order_id: self.trigger_id,
+                order_type: self.order_type,
+                expiry_timestamp: 0,
+                self_trade_behavior: SelfTradeBehavior::default(),
+                limit: 10,
+            },
+        };
+        let accounts = Self::Accounts {
+            payer: self.payer.pubkey(),
+            signer: self.signer.pubkey(),
+            open_orders_account: self.open_orders_account,
+            market: self.market,
+            trigger_order: get_trigger_order_address(self.open_orders_account, self.trigger_id),
+            system_program: System::id(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.signer, self.payer]
+    }
+}
+
+pub struct CancelTriggerOrderInstruction {
+    pub signer: TestKeypair,
+    pub open_orders_account: Pubkey,
+    pub trigger_id: u64,
+    pub sol_destination: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for CancelTriggerOrderInstruction {
+    type Accounts = openbook_v2::accounts::CancelTriggerOrder;
+    type Instruction = openbook_v2::instruction::CancelTriggerOrder;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {};
+        let accounts = Self::Accounts {
+            signer: self.signer.pubkey(),
+            open_orders_account: self.open_orders_account,
+            trigger_order: get_trigger_order_address(self.open_orders_account, self.trigger_id),
+            sol_destination: self.sol_destination,
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.signer]
+    }
+}
+
+pub struct ExecuteTriggerInstruction {
+    pub keeper: TestKeypair,
+    pub open_orders_account: Pubkey,
+    pub market: Pubkey,
+    pub trigger_id: u64,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for ExecuteTriggerInstruction {
+    type Accounts = openbook_v2::accounts::ExecuteTrigger;
+    type Instruction = openbook_v2::instruction::ExecuteTrigger;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {};
+        let market: Market = account_loader.load(&self.market).await.unwrap();
+        let accounts = Self::Accounts {
+            keeper: self.keeper.pubkey(),
+            trigger_order: get_trigger_order_address(self.open_orders_account, self.trigger_id),
+            open_orders_account: self.open_orders_account,
+            open_orders_admin: None,
+            market: self.market,
+            bids: market.bids,
+            asks: market.asks,
+            event_heap: market.event_heap,
+            oracle_a: market.oracle_a.into(),
+            oracle_b: market.oracle_b.into(),
+            event_authority: get_event_authority_address(),
+            program: openbook_v2::id(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.keeper]
+    }
+}
+
+pub struct PlaceSignedOrderInstruction {
+    pub relayer: TestKeypair,
+    pub signed_order: SignedOrder,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for PlaceSignedOrderInstruction {
+    type Accounts = openbook_v2::accounts::PlaceSignedOrder;
+    type Instruction = openbook_v2::instruction::PlaceSignedOrder;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = openbook_v2::id();
+        let instruction = Self::Instruction {
+            signed_order: self.signed_order,
+        };
+        let market: Market = account_loader
+            .load(&self.signed_order.market)
+            .await
+            .unwrap();
+        let (token_gate, gate_token_account) = token_gate_accounts(
+            &account_loader,
+            self.signed_order.market,
+            self.signed_order.open_orders_account,
+        )
+        .await;
+        let accounts = Self::Accounts {
+            relayer: self.relayer.pubkey(),
+            open_orders_account: self.signed_order.open_orders_account,
+            open_orders_admin: None,
+            market: self.signed_order.market,
+            bids: market.bids,
+            asks: market.asks,
+            event_heap: market.event_heap,
+            oracle_a: market.oracle_a.into(),
+            oracle_b: market.oracle_b.into(),
+            instructions_sysvar: solana_program::sysvar::instructions::id(),
+            token_gate,
+            gate_token_account,
+            event_authority: get_event_authority_address(),
+            program: openbook_v2::id(),
+        };
+
+        // signed orders keep their nonce in the position extension
+        let mut instruction = make_instruction(program_id, &accounts, instruction);
+        instruction.accounts.push(AccountMeta::new(
+            get_position_extension_address(self.signed_order.open_orders_account),
+            false,
+        ));
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.relayer]
+    }
+}
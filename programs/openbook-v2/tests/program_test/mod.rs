@@ -21,6 +21,7 @@ pub use utils::*;
 
 pub mod client;
 pub mod cookies;
+pub mod reference_book;
 pub mod setup;
 pub mod solana;
 pub mod utils;
@@ -46,6 +47,34 @@ pub struct TestInitialize {
     pub bids: Pubkey,
 }
 
+/// Markets sharing mints, admins and users, see [`TestContext::new_with_markets`]
+pub struct TestMultiMarketInitialize {
+    pub context: TestContext,
+    pub collect_fee_admin: TestKeypair,
+    pub open_orders_admin: TestKeypair,
+    pub close_market_admin: TestKeypair,
+    pub consume_events_admin: TestKeypair,
+    pub owner: TestKeypair,
+    pub payer: TestKeypair,
+    pub mints: Vec<MintCookie>,
+    pub owner_token_0: Pubkey,
+    pub owner_token_1: Pubkey,
+    pub tokens: Vec<Token>,
+    pub markets: Vec<TestMarket>,
+}
+
+/// One of the markets of [`TestMultiMarketInitialize`], with two open orders
+/// accounts of the shared owner
+pub struct TestMarket {
+    pub market: Pubkey,
+    pub market_base_vault: Pubkey,
+    pub market_quote_vault: Pubkey,
+    pub bids: Pubkey,
+    pub price_lots: i64,
+    pub account_1: Pubkey,
+    pub account_2: Pubkey,
+}
+
 trait AddPacked {
     fn add_packable_account<T: Pack>(
         &mut self,
@@ -98,6 +127,149 @@ impl Log for LoggerWrapper {
     fn flush(&self) {}
 }
 
+/// Program id of the stand-in fill callback program, see `fill_callback_entry`
+pub fn fill_callback_program_id() -> Pubkey {
+    Pubkey::new_from_array([0xfc; 32])
+}
+
+/// Stand-in for a program registered with `set_fill_callback`: checks the call is
+/// signed by the market's fill callback authority and logs what it received.
+fn fill_callback_entry(
+    _program_id: &Pubkey,
+    accounts: &[anchor_lang::prelude::AccountInfo],
+    data: &[u8],
+) -> anchor_lang::solana_program::entrypoint::ProgramResult {
+    use anchor_lang::AnchorDeserialize;
+    use openbook_v2::fill_callback::{
+        on_fill_discriminator, OnFillArgs, FILL_CALLBACK_AUTHORITY_SEED,
+    };
+
+    let (discriminator, args) = data.split_at(8);
+    assert_eq!(discriminator, on_fill_discriminator());
+    let args = OnFillArgs::try_from_slice(args)?;
+
+    let callback_authority = Pubkey::find_program_address(
+        &[FILL_CALLBACK_AUTHORITY_SEED, args.market.as_ref()],
+        &openbook_v2::id(),
+    )
+    .0;
+    assert!(accounts[0].is_signer);
+    assert_eq!(accounts[0].key, &callback_authority);
+
+    anchor_lang::prelude::msg!("on_fill {} {}", args.market, args.fills.len());
+    Ok(())
+}
+
+/// Program id of the stand-in settle receiver program, see `settle_receiver_entry`
+pub fn settle_receiver_program_id() -> Pubkey {
+    Pubkey::new_from_array([0x5e; 32])
+}
+
+/// Authority of the token accounts the stand-in settle receiver takes funds in
+pub fn settle_receiver_depositor() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"depositor"], &settle_receiver_program_id())
+}
+
+/// Stand-in for a program registered with `set_settle_receiver`: checks the call
+/// is signed by the market's settle receiver authority, moves the settled funds
+/// from its token accounts to the deposit accounts passed after them and logs
+/// what it received.
+fn settle_receiver_entry(
+    _program_id: &Pubkey,
+    accounts: &[anchor_lang::prelude::AccountInfo],
+    data: &[u8],
+) -> anchor_lang::solana_program::entrypoint::ProgramResult {
+    use anchor_lang::AnchorDeserialize;
+    use openbook_v2::settle_receiver::{
+        on_settle_discriminator, OnSettleArgs, SETTLE_RECEIVER_AUTHORITY_SEED,
+    };
+
+    let (discriminator, args) = data.split_at(8);
+    assert_eq!(discriminator, on_settle_discriminator());
+    let args = OnSettleArgs::try_from_slice(args)?;
+
+    let receiver_authority = Pubkey::find_program_address(
+        &[SETTLE_RECEIVER_AUTHORITY_SEED, args.market.as_ref()],
+        &openbook_v2::id(),
+    )
+    .0;
+    assert!(accounts[0].is_signer);
+    assert_eq!(accounts[0].key, &receiver_authority);
+    assert!(accounts[1..].iter().all(|ai| !ai.is_signer));
+
+    // after the authority: base, quote, base deposit, quote deposit, depositor and
+    // token program
+    let (depositor, bump) = settle_receiver_depositor();
+    assert_eq!(accounts[5].key, &depositor);
+    for (from, to, amount) in [
+        (&accounts[1], &accounts[3], args.base_native),
+        (&accounts[2], &accounts[4], args.quote_native),
+    ] {
+        if amount == 0 {
+            continue;
+        }
+        let ix = spl_token::instruction::transfer(
+            &spl_token::id(),
+            from.key,
+            to.key,
+            &depositor,
+            &[],
+            amount,
+        )?;
+        solana_program::program::invoke_signed(
+            &ix,
+            &[
+                from.clone(),
+                to.clone(),
+                accounts[5].clone(),
+                accounts[6].clone(),
+            ],
+            &[&[b"depositor", &[bump]]],
+        )?;
+    }
+
+    anchor_lang::prelude::msg!(
+        "on_settle {} {} {}",
+        args.open_orders_account,
+        args.base_native,
+        args.quote_native
+    );
+    Ok(())
+}
+
+/// Program id of the stand-in risk program, see `risk_program_entry`
+pub fn risk_program_id() -> Pubkey {
+    Pubkey::new_from_array([0x4c; 32])
+}
+
+/// Stand-in for a program registered with `set_risk_program`: approves orders
+/// of at most 10 base lots and logs what it received.
+fn risk_program_entry(
+    _program_id: &Pubkey,
+    accounts: &[anchor_lang::prelude::AccountInfo],
+    data: &[u8],
+) -> anchor_lang::solana_program::entrypoint::ProgramResult {
+    use anchor_lang::AnchorDeserialize;
+    use openbook_v2::health_check::{check_health_discriminator, CheckHealthArgs};
+
+    let (discriminator, args) = data.split_at(8);
+    assert_eq!(discriminator, check_health_discriminator());
+    let args = CheckHealthArgs::try_from_slice(args)?;
+
+    assert_eq!(accounts[0].key, &args.open_orders_account);
+    assert_eq!(accounts[1].key, &args.market);
+    if args.orders.iter().any(|order| order.max_base_lots > 10) {
+        return Err(anchor_lang::solana_program::program_error::ProgramError::Custom(0));
+    }
+
+    anchor_lang::prelude::msg!(
+        "check_health {} {}",
+        args.open_orders_account,
+        args.orders.len()
+    );
+    Ok(())
+}
+
 #[derive(Default)]
 pub struct TestContextBuilder {
     test: ProgramTest,
@@ -139,6 +311,21 @@ impl TestContextBuilder {
         }
 
         let mut test = ProgramTest::new("openbook_v2", openbook_v2::id(), processor!(fixed_entry));
+        test.add_program(
+            "fill_callback",
+            fill_callback_program_id(),
+            processor!(fill_callback_entry),
+        );
+        test.add_program(
+            "risk_program",
+            risk_program_id(),
+            processor!(risk_program_entry),
+        );
+        test.add_program(
+            "settle_receiver",
+            settle_receiver_program_id(),
+            processor!(settle_receiver_entry),
+        );
 
         // intentionally set to as tight as possible, to catch potential problems early
         test.set_compute_max_units(130000);
@@ -288,6 +475,8 @@ pub struct TestNewMarketInitialize {
     pub close_market_admin_bool: bool,
     pub consume_events_admin_bool: bool,
     pub time_expiry: i64,
+    pub min_order_notional: u64,
+    pub max_resting_orders: u8,
     pub with_oracle: bool,
     pub payer_as_delegate: bool,
 }
@@ -304,6 +493,8 @@ impl Default for TestNewMarketInitialize {
             close_market_admin_bool: false,
             consume_events_admin_bool: false,
             time_expiry: 0,
+            min_order_notional: 0,
+            max_resting_orders: 0,
             with_oracle: true,
             payer_as_delegate: false,
         }
@@ -317,28 +508,67 @@ impl TestContext {
     pub async fn new_with_market(
         args: TestNewMarketInitialize,
     ) -> Result<TestInitialize, TransportError> {
+        let TestMultiMarketInitialize {
+            context,
+            collect_fee_admin,
+            open_orders_admin,
+            close_market_admin,
+            consume_events_admin,
+            owner,
+            payer,
+            mints,
+            owner_token_0,
+            owner_token_1,
+            tokens,
+            mut markets,
+        } = Self::new_with_markets(1, vec![args]).await?;
+        let TestMarket {
+            market,
+            market_base_vault,
+            market_quote_vault,
+            bids,
+            price_lots,
+            account_1,
+            account_2,
+        } = markets.remove(0);
+
+        Ok(TestInitialize {
+            context,
+            collect_fee_admin,
+            open_orders_admin,
+            close_market_admin,
+            consume_events_admin,
+            owner,
+            payer,
+            mints,
+            owner_token_0,
+            owner_token_1,
+            market,
+
+            market_base_vault,
+            market_quote_vault,
+            price_lots,
+            tokens,
+            account_1,
+            account_2,
+            bids,
+        })
+    }
+
+    /// Creates `n` markets trading the same base and quote mints, the i-th one set up
+    /// with `configs[i]` or the default config if there is none. The admins, owner
+    /// and payer are shared, the owner gets two open orders accounts on each market.
+    pub async fn new_with_markets(
+        n: usize,
+        mut configs: Vec<TestNewMarketInitialize>,
+    ) -> Result<TestMultiMarketInitialize, TransportError> {
         let context = TestContextBuilder::new().start_default().await;
         let solana = &context.solana.clone();
 
         let collect_fee_admin_acc = TestKeypair::new();
         let open_orders_admin_acc = TestKeypair::new();
-        let open_orders_admin = if args.open_orders_admin_bool {
-            Some(open_orders_admin_acc.pubkey())
-        } else {
-            None
-        };
         let close_market_admin_acc = TestKeypair::new();
-        let close_market_admin = if args.close_market_admin_bool {
-            Some(close_market_admin_acc.pubkey())
-        } else {
-            None
-        };
         let consume_events_admin_acc = TestKeypair::new();
-        let consume_events_admin = if args.consume_events_admin_bool {
-            Some(consume_events_admin_acc.pubkey())
-        } else {
-            None
-        };
 
         let owner = context.users[0].key;
         let payer = context.users[1].key;
@@ -349,68 +579,101 @@ impl TestContext {
 
         let tokens = Token::create(mints.to_vec(), solana, collect_fee_admin_acc, payer).await;
 
-        // Create a market
+        configs.resize_with(n, TestNewMarketInitialize::default);
+        let mut markets = vec![];
+        for (i, args) in configs.into_iter().enumerate() {
+            let admin = |enabled: bool, admin: TestKeypair| enabled.then(|| admin.pubkey());
+            let oracle = if args.with_oracle {
+                Some(tokens[0].oracle)
+            } else {
+                None
+            };
+
+            let openbook_v2::accounts::CreateMarket {
+                market,
+                market_base_vault,
+                market_quote_vault,
+                bids,
+                ..
+            } = send_tx(
+                solana,
+                CreateMarketInstruction {
+                    collect_fee_admin: collect_fee_admin_acc.pubkey(),
+                    open_orders_admin: admin(args.open_orders_admin_bool, open_orders_admin_acc),
+                    close_market_admin: admin(args.close_market_admin_bool, close_market_admin_acc),
+                    consume_events_admin: admin(
+                        args.consume_events_admin_bool,
+                        consume_events_admin_acc,
+                    ),
+                    payer,
+                    market: TestKeypair::new(),
+                    quote_lot_size: args.quote_lot_size,
+                    base_lot_size: args.base_lot_size,
+                    maker_fee: args.maker_fee,
+                    taker_fee: args.taker_fee,
+                    base_mint: mints[0].pubkey,
+                    quote_mint: mints[1].pubkey,
+                    fee_penalty: args.fee_penalty,
+                    time_expiry: args.time_expiry,
+                    min_order_notional: args.min_order_notional,
+                    max_resting_orders: args.max_resting_orders,
+                    ..CreateMarketInstruction::with_new_book_and_heap(solana, oracle, None).await
+                },
+            )
+            .await
+            .unwrap();
 
-        let market = TestKeypair::new();
+            // the indexer belongs to the owner, whatever the market
+            if i == 0 {
+                create_open_orders_indexer(solana, &context.users[1], owner, market).await;
+            }
 
-        let oracle = if args.with_oracle {
-            Some(tokens[0].oracle)
-        } else {
-            None
-        };
+            let delegate_opt = if args.payer_as_delegate {
+                Some(payer.pubkey())
+            } else {
+                None
+            };
 
-        let openbook_v2::accounts::CreateMarket {
-            market,
-            market_base_vault,
-            market_quote_vault,
-            bids,
-            ..
-        } = send_tx(
-            solana,
-            CreateMarketInstruction {
-                collect_fee_admin: collect_fee_admin_acc.pubkey(),
-                open_orders_admin,
-                close_market_admin,
-                consume_events_admin,
-                payer,
+            // account numbers count the accounts of the owner across markets
+            let account_num = 2 * i as u32 + 1;
+            let account_1 = create_open_orders_account(
+                solana,
+                owner,
+                market,
+                account_num,
+                &context.users[1],
+                delegate_opt,
+            )
+            .await;
+            let account_2 = create_open_orders_account(
+                solana,
+                owner,
+                market,
+                account_num + 1,
+                &context.users[1],
+                delegate_opt,
+            )
+            .await;
+
+            let price_lots = {
+                let market = solana.get_account::<Market>(market).await;
+                market.native_price_to_lot(I80F48::from(1000)).unwrap()
+            };
+
+            markets.push(TestMarket {
                 market,
-                quote_lot_size: args.quote_lot_size,
-                base_lot_size: args.base_lot_size,
-                maker_fee: args.maker_fee,
-                taker_fee: args.taker_fee,
-                base_mint: mints[0].pubkey,
-                quote_mint: mints[1].pubkey,
-                fee_penalty: args.fee_penalty,
-                time_expiry: args.time_expiry,
-                ..CreateMarketInstruction::with_new_book_and_heap(solana, oracle, None).await
-            },
-        )
-        .await
-        .unwrap();
-
-        let _indexer = create_open_orders_indexer(solana, &context.users[1], owner, market).await;
-
-        let delegate_opt = if args.payer_as_delegate {
-            Some(payer.pubkey())
-        } else {
-            None
-        };
-
-        let account_1 =
-            create_open_orders_account(solana, owner, market, 1, &context.users[1], delegate_opt)
-                .await;
-        let account_2 =
-            create_open_orders_account(solana, owner, market, 2, &context.users[1], delegate_opt)
-                .await;
-
-        let price_lots = {
-            let market = solana.get_account::<Market>(market).await;
-            market.native_price_to_lot(I80F48::from(1000)).unwrap()
-        };
+                market_base_vault,
+                market_quote_vault,
+                bids,
+                price_lots,
+                account_1,
+                account_2,
+            });
+        }
 
         let mints = mints.to_vec();
 
-        Ok(TestInitialize {
+        Ok(TestMultiMarketInitialize {
             context,
             collect_fee_admin: collect_fee_admin_acc,
             open_orders_admin: open_orders_admin_acc,
@@ -421,15 +684,8 @@ impl TestContext {
             mints,
             owner_token_0,
             owner_token_1,
-            market,
-
-            market_base_vault,
-            market_quote_vault,
-            price_lots,
             tokens,
-            account_1,
-            account_2,
-            bids,
+            markets,
         })
     }
 }
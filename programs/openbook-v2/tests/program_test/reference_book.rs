@@ -0,0 +1,110 @@
+//! A plain price-time priority order book, the reference the program's matching is
+//! checked against by `test_differential`.
+//!
+//! Only fixed price orders are modelled and fees are left out, they don't change
+//! what gets matched as long as the quote limit of an order doesn't bind. Takers
+//! match the best priced resting order first, the oldest one at the same price, at
+//! the maker's price. A taker crossing more than `limit` orders stops matching and
+//! doesn't post its remainder, like the program does.
+
+use anchor_lang::prelude::Pubkey;
+use openbook_v2::state::Side;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReferenceOrder {
+    pub owner: Pubkey,
+    pub price_lots: i64,
+    pub quantity: i64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReferenceFill {
+    pub maker: Pubkey,
+    pub taker: Pubkey,
+    pub price_lots: i64,
+    pub quantity: i64,
+    pub maker_out: bool,
+}
+
+/// Resting orders of each side, the first one matches first
+#[derive(Clone, Debug, Default)]
+pub struct ReferenceBook {
+    pub bids: Vec<ReferenceOrder>,
+    pub asks: Vec<ReferenceOrder>,
+}
+
+impl ReferenceBook {
+    fn side_mut(&mut self, side: Side) -> &mut Vec<ReferenceOrder> {
+        match side {
+            Side::Bid => &mut self.bids,
+            Side::Ask => &mut self.asks,
+        }
+    }
+
+    /// Resting orders of `owner` on both sides
+    pub fn resting_orders(&self, owner: Pubkey) -> usize {
+        self.bids
+            .iter()
+            .chain(self.asks.iter())
+            .filter(|order| order.owner == owner)
+            .count()
+    }
+
+    /// Match an order of `owner` and post what's left if `post` is set
+    pub fn place(
+        &mut self,
+        owner: Pubkey,
+        side: Side,
+        price_lots: i64,
+        base_lots: i64,
+        post: bool,
+        limit: usize,
+    ) -> Vec<ReferenceFill> {
+        let mut fills = vec![];
+        let mut remaining = base_lots;
+        let mut limit_reached = false;
+        let opposing = self.side_mut(side.invert_side());
+        while remaining > 0 {
+            let best = match opposing.first_mut() {
+                Some(best) if !side.is_price_better(best.price_lots, price_lots) => best,
+                _ => break,
+            };
+            if fills.len() == limit {
+                limit_reached = true;
+                break;
+            }
+
+            let quantity = remaining.min(best.quantity);
+            best.quantity -= quantity;
+            remaining -= quantity;
+            fills.push(ReferenceFill {
+                maker: best.owner,
+                taker: owner,
+                price_lots: best.price_lots,
+                quantity,
+                maker_out: best.quantity == 0,
+            });
+            if best.quantity == 0 {
+                opposing.remove(0);
+            }
+        }
+
+        if post && remaining > 0 && !limit_reached {
+            let own = self.side_mut(side);
+            // behind the orders at the same price
+            let index = own
+                .iter()
+                .position(|order| side.is_price_better(price_lots, order.price_lots))
+                .unwrap_or(own.len());
+            own.insert(
+                index,
+                ReferenceOrder {
+                    owner,
+                    price_lots,
+                    quantity: remaining,
+                },
+            );
+        }
+        fills
+    }
+}
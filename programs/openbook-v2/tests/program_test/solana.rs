@@ -31,6 +31,18 @@ impl SolanaCookie {
         &self,
         instructions: &[Instruction],
         signers: Option<&[TestKeypair]>,
+    ) -> Result<(), BanksClientError> {
+        self.process_transaction_with_fee_payer(instructions, signers, None)
+            .await
+    }
+
+    /// Like `process_transaction`, with `fee_payer` instead of the context payer
+    /// paying the transaction fee, as a relayer would
+    pub async fn process_transaction_with_fee_payer(
+        &self,
+        instructions: &[Instruction],
+        signers: Option<&[TestKeypair]>,
+        fee_payer: Option<TestKeypair>,
     ) -> Result<(), BanksClientError> {
         // The locking in this function is convoluted:
         // We capture the program log output by overriding the global logger and capturing
@@ -43,25 +55,8 @@ impl SolanaCookie {
         self.logger_capture.write().unwrap().clear();
 
         let mut context = self.context.borrow_mut();
-
-        let mut transaction =
-            Transaction::new_with_payer(instructions, Some(&context.payer.pubkey()));
-
-        let mut all_signers = vec![&context.payer];
-        let signer_keypairs =
-            signers.map(|signers| signers.iter().map(|s| s.into()).collect::<Vec<Keypair>>());
-        let signer_keypair_refs = signer_keypairs
-            .as_ref()
-            .map(|kps| kps.iter().collect::<Vec<&Keypair>>());
-
-        if let Some(signer_keypair_refs) = signer_keypair_refs {
-            all_signers.extend(signer_keypair_refs.iter());
-        }
-
-        // This fails when warping is involved - https://gitmemory.com/issue/solana-labs/solana/18201/868325078
-        // let recent_blockhash = self.context.banks_client.get_recent_blockhash().await.unwrap();
-
-        transaction.sign(&all_signers, context.last_blockhash);
+        let transaction =
+            Self::signed_transaction_with_fee_payer(&context, instructions, signers, fee_payer);
 
         let result = context
             .banks_client
@@ -83,6 +78,85 @@ impl SolanaCookie {
         result
     }
 
+    fn signed_transaction(
+        context: &ProgramTestContext,
+        instructions: &[Instruction],
+        signers: Option<&[TestKeypair]>,
+    ) -> Transaction {
+        Self::signed_transaction_with_fee_payer(context, instructions, signers, None)
+    }
+
+    fn signed_transaction_with_fee_payer(
+        context: &ProgramTestContext,
+        instructions: &[Instruction],
+        signers: Option<&[TestKeypair]>,
+        fee_payer: Option<TestKeypair>,
+    ) -> Transaction {
+        let fee_payer_keypair = fee_payer.map(|kp| kp.to_keypair());
+        let fee_payer = fee_payer_keypair.as_ref().unwrap_or(&context.payer);
+        let mut transaction = Transaction::new_with_payer(instructions, Some(&fee_payer.pubkey()));
+
+        let mut all_signers = vec![fee_payer];
+        let signer_keypairs =
+            signers.map(|signers| signers.iter().map(|s| s.into()).collect::<Vec<Keypair>>());
+        let signer_keypair_refs = signer_keypairs
+            .as_ref()
+            .map(|kps| kps.iter().collect::<Vec<&Keypair>>());
+
+        if let Some(signer_keypair_refs) = signer_keypair_refs {
+            all_signers.extend(signer_keypair_refs.iter());
+        }
+
+        // This fails when warping is involved - https://gitmemory.com/issue/solana-labs/solana/18201/868325078
+        // let recent_blockhash = self.context.banks_client.get_recent_blockhash().await.unwrap();
+
+        transaction.sign(&all_signers, context.last_blockhash);
+        transaction
+    }
+
+    /// Compute units the transaction consumes, simulated against the current state
+    /// without processing it
+    pub async fn simulate_compute_units(
+        &self,
+        instructions: &[Instruction],
+        signers: Option<&[TestKeypair]>,
+    ) -> Result<u64, BanksClientError> {
+        let mut context = self.context.borrow_mut();
+        let transaction = Self::signed_transaction(&context, instructions, signers);
+        let simulation = context
+            .banks_client
+            .simulate_transaction(transaction)
+            .await?;
+        if let Some(Err(err)) = simulation.result {
+            return Err(BanksClientError::TransactionError(err));
+        }
+        Ok(simulation
+            .simulation_details
+            .map_or(0, |details| details.units_consumed))
+    }
+
+    /// Data the last instruction of the transaction returns, simulated against the
+    /// current state without processing it
+    pub async fn simulate_return_data(
+        &self,
+        instructions: &[Instruction],
+        signers: Option<&[TestKeypair]>,
+    ) -> Result<Vec<u8>, BanksClientError> {
+        let mut context = self.context.borrow_mut();
+        let transaction = Self::signed_transaction(&context, instructions, signers);
+        let simulation = context
+            .banks_client
+            .simulate_transaction(transaction)
+            .await?;
+        if let Some(Err(err)) = simulation.result {
+            return Err(BanksClientError::TransactionError(err));
+        }
+        Ok(simulation
+            .simulation_details
+            .and_then(|details| details.return_data)
+            .map_or(vec![], |return_data| return_data.data))
+    }
+
     pub async fn get_clock(&self) -> solana_program::clock::Clock {
         self.context
             .borrow_mut()
@@ -92,27 +166,44 @@ impl SolanaCookie {
             .unwrap()
     }
 
+    /// Moves to `slot`. The clock's timestamp never goes back, even when the bank
+    /// estimates an earlier one for the new slot.
+    pub async fn warp_to_slot(&self, slot: u64) {
+        let unix_timestamp = self.get_clock().await.unix_timestamp;
+        self.context.borrow_mut().warp_to_slot(slot).unwrap();
+        if self.get_clock().await.unix_timestamp < unix_timestamp {
+            self.set_unix_timestamp(unix_timestamp).await;
+        }
+    }
+
     pub async fn advance_by_slots(&self, slots: u64) {
         let clock = self.get_clock().await;
-        self.context
-            .borrow_mut()
-            .warp_to_slot(clock.slot + slots + 1)
-            .unwrap();
+        self.warp_to_slot(clock.slot + slots + 1).await;
     }
 
-    pub async fn advance_clock_to(&self, target: i64) {
+    /// Sets the clock's timestamp without changing the slot
+    pub async fn set_unix_timestamp(&self, unix_timestamp: i64) {
         let mut clock = self.get_clock().await;
+        clock.unix_timestamp = unix_timestamp;
+        self.context.borrow_mut().set_sysvar(&clock);
+    }
 
-        // just advance enough to ensure we get changes over last_updated in various ix
-        // if this gets too slow for our tests, remove and replace with manual time offset
-        // which is configurable
-        while clock.unix_timestamp <= target {
-            self.context
-                .borrow_mut()
-                .warp_to_slot(clock.slot + 50)
-                .unwrap();
-            clock = self.get_clock().await;
-        }
+    /// Sets the clock to exactly `target`, after moving ahead by the slots that
+    /// usually pass in the meantime so slot based checks like oracle staleness see
+    /// the time pass too. At least one slot passes, so repeated transactions aren't
+    /// rejected as duplicates.
+    pub async fn advance_clock_to(&self, target: i64) {
+        let clock = self.get_clock().await;
+        assert!(
+            target >= clock.unix_timestamp,
+            "can't move the clock back from {} to {}",
+            clock.unix_timestamp,
+            target
+        );
+        let seconds = (target - clock.unix_timestamp) as u64;
+        let slots = (seconds * 1000 / solana_sdk::clock::DEFAULT_MS_PER_SLOT).max(1);
+        self.warp_to_slot(clock.slot + slots).await;
+        self.set_unix_timestamp(target).await;
     }
 
     pub async fn advance_clock_to_next_multiple(&self, window: i64) {
@@ -259,6 +350,15 @@ impl SolanaCookie {
         self.get_account_opt(address).await.unwrap()
     }
 
+    pub async fn get_account_lamports(&self, address: Pubkey) -> u64 {
+        self.context
+            .borrow_mut()
+            .banks_client
+            .get_balance(address)
+            .await
+            .unwrap()
+    }
+
     pub async fn token_account_balance(&self, address: Pubkey) -> u64 {
         self.get_account::<TokenAccount>(address).await.amount
     }
@@ -282,6 +382,23 @@ impl SolanaCookie {
             .set_account(&address, &account.into());
     }
 
+    /// Replace the data of an account, e.g. to recreate an older layout
+    pub async fn set_account_data(&self, address: Pubkey, data: Vec<u8>) {
+        let mut account = self
+            .context
+            .borrow_mut()
+            .banks_client
+            .get_account(address)
+            .await
+            .unwrap()
+            .unwrap();
+        account.data = data;
+
+        self.context
+            .borrow_mut()
+            .set_account(&address, &account.into());
+    }
+
     pub fn program_log(&self) -> Vec<String> {
         self.last_transaction_log.borrow().clone()
     }
@@ -29,7 +29,7 @@ import {
 } from '@solana/web3.js';
 import { IDL, type OpenbookV2 } from './openbook_v2';
 import { sendTransaction } from './utils/rpc';
-import { SideUtils } from './utils/utils';
+import { SideUtils, nonZeroPubkey } from './utils/utils';
 
 export type IdsSource = 'api' | 'static' | 'get-program-accounts';
 export type PlaceOrderArgs = IdlTypes<OpenbookV2>['PlaceOrderArgs'];
@@ -229,6 +229,8 @@ export class OpenBookV2Client {
     },
     market = Keypair.generate(),
     collectFeeAdmin?: PublicKey,
+    minOrderNotional = new BN(0),
+    maxRestingOrders = 0,
   ): Promise<[TransactionInstruction[], Signer[]]> {
     const [bidIx, bidsKeypair] = await this.createProgramAccountIx(
       payer,
@@ -274,6 +276,8 @@ export class OpenBookV2Client {
         makerFee,
         takerFee,
         timeExpiry,
+        minOrderNotional,
+        maxRestingOrders,
       )
       .accounts({
         market: market.publicKey,
@@ -612,8 +616,8 @@ export class OpenBookV2Client {
         eventHeap: market.eventHeap,
         market: marketPublicKey,
         openOrdersAccount: openOrdersPublicKey,
-        oracleA: market.oracleA.key,
-        oracleB: market.oracleB.key,
+        oracleA: nonZeroPubkey(market.oracleA),
+        oracleB: nonZeroPubkey(market.oracleB),
         userTokenAccount,
         tokenProgram: TOKEN_PROGRAM_ID,
         openOrdersAdmin,
@@ -659,8 +663,8 @@ export class OpenBookV2Client {
         eventHeap: market.eventHeap,
         market: marketPublicKey,
         openOrdersAccount: openOrdersPublicKey,
-        oracleA: market.oracleA.key,
-        oracleB: market.oracleB.key,
+        oracleA: nonZeroPubkey(market.oracleA),
+        oracleB: nonZeroPubkey(market.oracleB),
         userTokenAccount,
         tokenProgram: TOKEN_PROGRAM_ID,
         openOrdersAdmin,
@@ -701,8 +705,8 @@ export class OpenBookV2Client {
         bids: market.bids,
         eventHeap: market.eventHeap,
         market: marketPublicKey,
-        oracleA: market.oracleA.key,
-        oracleB: market.oracleB.key,
+        oracleA: nonZeroPubkey(market.oracleA),
+        oracleB: nonZeroPubkey(market.oracleB),
         userBaseAccount,
         userQuoteAccount,
         marketBaseVault: market.marketBaseVault,
@@ -749,8 +753,8 @@ export class OpenBookV2Client {
         eventHeap: market.eventHeap,
         market: marketPublicKey,
         openOrdersAccount: openOrdersPublicKey,
-        oracleA: market.oracleA.key,
-        oracleB: market.oracleB.key,
+        oracleA: nonZeroPubkey(market.oracleA),
+        oracleB: nonZeroPubkey(market.oracleB),
         userBaseAccount,
         userQuoteAccount,
         tokenProgram: TOKEN_PROGRAM_ID,
@@ -792,8 +796,8 @@ export class OpenBookV2Client {
         eventHeap: market.eventHeap,
         market: marketPublicKey,
         openOrdersAccount: openOrdersPublicKey,
-        oracleA: market.oracleA.key,
-        oracleB: market.oracleB.key,
+        oracleA: nonZeroPubkey(market.oracleA),
+        oracleB: nonZeroPubkey(market.oracleB),
         userBaseAccount,
         userQuoteAccount,
         tokenProgram: TOKEN_PROGRAM_ID,
@@ -6,6 +6,14 @@ export type OpenbookV2 = {
       name: 'createMarket';
       docs: [
         'Create a [`Market`](crate::state::Market) for a given token pair.',
+        '',
+        'The payer escrows a refundable [`MARKET_CREATION_BOND`](crate::state::MARKET_CREATION_BOND)',
+        'in the market account.',
+        '',
+        'Orders that would rest on the book with less than `min_order_notional` in quote',
+        'native units are rejected, see `set_min_order_notional`, and open orders accounts',
+        "can't have more than `max_resting_orders` orders on the book, see",
+        '`set_max_resting_orders`.',
       ];
       accounts: [
         {
@@ -154,29 +162,46 @@ export type OpenbookV2 = {
           name: 'timeExpiry';
           type: 'i64';
         },
+        {
+          name: 'minOrderNotional';
+          type: 'u64';
+        },
+        {
+          name: 'maxRestingOrders';
+          type: 'u8';
+        },
       ];
     },
     {
-      name: 'closeMarket';
+      name: 'createCanonicalMarket';
       docs: [
-        'Close a [`Market`](crate::state::Market) (only',
-        '[`close_market_admin`](crate::state::Market::close_market_admin)).',
+        'Create the canonical [`Market`](crate::state::Market) of a token pair and',
+        'lot sizes.',
+        '',
+        'The market address is a PDA of `["CanonicalMarket", base_mint, quote_mint,',
+        'base_lot_size, quote_lot_size]` (lot sizes as little endian bytes), so there',
+        'is at most one per configuration and clients can derive it. Canonical',
+        "markets can't have an open orders admin.",
       ];
       accounts: [
-        {
-          name: 'closeMarketAdmin';
-          isMut: false;
-          isSigner: true;
-        },
         {
           name: 'market';
           isMut: true;
           isSigner: false;
         },
+        {
+          name: 'marketAuthority';
+          isMut: false;
+          isSigner: false;
+        },
         {
           name: 'bids';
           isMut: true;
           isSigner: false;
+          docs: [
+            'Accounts are initialized by client,',
+            'anchor discriminator is set first when ix exits,',
+          ];
         },
         {
           name: 'asks';
@@ -189,37 +214,28 @@ export type OpenbookV2 = {
           isSigner: false;
         },
         {
-          name: 'solDestination';
+          name: 'payer';
           isMut: true;
-          isSigner: false;
+          isSigner: true;
         },
         {
-          name: 'tokenProgram';
-          isMut: false;
+          name: 'marketBaseVault';
+          isMut: true;
           isSigner: false;
         },
-      ];
-      args: [];
-    },
-    {
-      name: 'createOpenOrdersIndexer';
-      docs: [
-        'Create an [`OpenOrdersIndexer`](crate::state::OpenOrdersIndexer) account.',
-      ];
-      accounts: [
         {
-          name: 'payer';
+          name: 'marketQuoteVault';
           isMut: true;
-          isSigner: true;
+          isSigner: false;
         },
         {
-          name: 'owner';
+          name: 'baseMint';
           isMut: false;
-          isSigner: true;
+          isSigner: false;
         },
         {
-          name: 'openOrdersIndexer';
-          isMut: true;
+          name: 'quoteMint';
+          isMut: false;
           isSigner: false;
         },
         {
@@ -227,77 +243,58 @@ export type OpenbookV2 = {
           isMut: false;
           isSigner: false;
         },
-      ];
-      args: [];
-    },
-    {
-      name: 'closeOpenOrdersIndexer';
-      docs: [
-        'Close an [`OpenOrdersIndexer`](crate::state::OpenOrdersIndexer) account.',
-      ];
-      accounts: [
         {
-          name: 'owner';
+          name: 'tokenProgram';
           isMut: false;
-          isSigner: true;
-        },
-        {
-          name: 'openOrdersIndexer';
-          isMut: true;
           isSigner: false;
         },
         {
-          name: 'solDestination';
-          isMut: true;
+          name: 'associatedTokenProgram';
+          isMut: false;
           isSigner: false;
         },
         {
-          name: 'tokenProgram';
+          name: 'oracleA';
           isMut: false;
           isSigner: false;
+          isOptional: true;
         },
-      ];
-      args: [];
-    },
-    {
-      name: 'createOpenOrdersAccount';
-      docs: [
-        'Create an [`OpenOrdersAccount`](crate::state::OpenOrdersAccount).',
-      ];
-      accounts: [
         {
-          name: 'payer';
-          isMut: true;
-          isSigner: true;
+          name: 'oracleB';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
         },
         {
-          name: 'owner';
+          name: 'collectFeeAdmin';
           isMut: false;
-          isSigner: true;
+          isSigner: false;
         },
         {
-          name: 'delegateAccount';
+          name: 'openOrdersAdmin';
           isMut: false;
           isSigner: false;
           isOptional: true;
         },
         {
-          name: 'openOrdersIndexer';
-          isMut: true;
+          name: 'consumeEventsAdmin';
+          isMut: false;
           isSigner: false;
+          isOptional: true;
         },
         {
-          name: 'openOrdersAccount';
-          isMut: true;
+          name: 'closeMarketAdmin';
+          isMut: false;
           isSigner: false;
+          isOptional: true;
         },
         {
-          name: 'market';
+          name: 'eventAuthority';
           isMut: false;
           isSigner: false;
         },
         {
-          name: 'systemProgram';
+          name: 'program';
           isMut: false;
           isSigner: false;
         },
@@ -307,145 +304,168 @@ export type OpenbookV2 = {
           name: 'name';
           type: 'string';
         },
+        {
+          name: 'oracleConfig';
+          type: {
+            defined: 'OracleConfigParams';
+          };
+        },
+        {
+          name: 'quoteLotSize';
+          type: 'i64';
+        },
+        {
+          name: 'baseLotSize';
+          type: 'i64';
+        },
+        {
+          name: 'makerFee';
+          type: 'i64';
+        },
+        {
+          name: 'takerFee';
+          type: 'i64';
+        },
+        {
+          name: 'timeExpiry';
+          type: 'i64';
+        },
+        {
+          name: 'minOrderNotional';
+          type: 'u64';
+        },
+        {
+          name: 'maxRestingOrders';
+          type: 'u8';
+        },
       ];
     },
     {
-      name: 'closeOpenOrdersAccount';
+      name: 'slashMarketBond';
       docs: [
-        'Close an [`OpenOrdersAccount`](crate::state::OpenOrdersAccount).',
+        "Move a [`Market`](crate::state::Market)'s creation bond to `destination`,",
+        "as the program's upgrade authority.",
+        '',
+        'Every market escrows [`MARKET_CREATION_BOND`](crate::state::MARKET_CREATION_BOND)',
+        'lamports when created, returned by `close_market` unless slashed.',
       ];
       accounts: [
         {
-          name: 'owner';
+          name: 'governance';
           isMut: false;
           isSigner: true;
+          docs: ["The program's upgrade authority"];
         },
         {
-          name: 'openOrdersIndexer';
-          isMut: true;
+          name: 'program';
+          isMut: false;
           isSigner: false;
         },
         {
-          name: 'openOrdersAccount';
-          isMut: true;
+          name: 'programData';
+          isMut: false;
           isSigner: false;
         },
         {
-          name: 'solDestination';
+          name: 'market';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'systemProgram';
-          isMut: false;
+          name: 'destination';
+          isMut: true;
           isSigner: false;
         },
       ];
       args: [];
     },
     {
-      name: 'placeOrder';
+      name: 'createMerkleAllowlist';
       docs: [
-        'Place an order.',
-        '',
-        'Different types of orders have different effects on the order book,',
-        'as described in [`PlaceOrderType`](crate::state::PlaceOrderType).',
-        '',
-        '`price_lots` refers to the price in lots: the number of quote lots',
-        'per base lot. It is ignored for `PlaceOrderType::Market` orders.',
-        '',
-        '`expiry_timestamp` is a unix timestamp for when this order should',
-        'expire. If 0 is passed in, the order will never expire. If the time',
-        'is in the past, the instruction is skipped. Timestamps in the future',
-        'are reduced to now + 65,535s.',
-        '',
-        '`limit` determines the maximum number of orders from the book to fill,',
-        'and can be used to limit CU spent. When the limit is reached, processing',
-        'stops and the instruction succeeds.',
+        'Create the [`MerkleAllowlist`](crate::state::MerkleAllowlist) of a',
+        'permissioned market, as its open orders admin.',
       ];
       accounts: [
-        {
-          name: 'signer';
-          isMut: false;
-          isSigner: true;
-        },
-        {
-          name: 'openOrdersAccount';
-          isMut: true;
-          isSigner: false;
-        },
         {
           name: 'openOrdersAdmin';
           isMut: false;
           isSigner: true;
-          isOptional: true;
         },
         {
-          name: 'userTokenAccount';
+          name: 'payer';
           isMut: true;
-          isSigner: false;
+          isSigner: true;
         },
         {
           name: 'market';
-          isMut: true;
-          isSigner: false;
-        },
-        {
-          name: 'bids';
-          isMut: true;
+          isMut: false;
           isSigner: false;
         },
         {
-          name: 'asks';
+          name: 'merkleAllowlist';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'eventHeap';
-          isMut: true;
+          name: 'systemProgram';
+          isMut: false;
           isSigner: false;
         },
+      ];
+      args: [
         {
-          name: 'marketVault';
-          isMut: true;
-          isSigner: false;
+          name: 'root';
+          type: {
+            array: ['u8', 32];
+          };
         },
+      ];
+    },
+    {
+      name: 'setMerkleAllowlistRoot';
+      docs: [
+        "Replace the root of a market's [`MerkleAllowlist`](crate::state::MerkleAllowlist),",
+        'as its open orders admin.',
+        '',
+        'Open orders accounts that already joined stay allowed until revoked.',
+      ];
+      accounts: [
         {
-          name: 'oracleA';
+          name: 'openOrdersAdmin';
           isMut: false;
-          isSigner: false;
-          isOptional: true;
+          isSigner: true;
         },
         {
-          name: 'oracleB';
+          name: 'market';
           isMut: false;
           isSigner: false;
-          isOptional: true;
         },
         {
-          name: 'tokenProgram';
-          isMut: false;
+          name: 'merkleAllowlist';
+          isMut: true;
           isSigner: false;
         },
       ];
       args: [
         {
-          name: 'args';
+          name: 'root';
           type: {
-            defined: 'PlaceOrderArgs';
+            array: ['u8', 32];
           };
         },
       ];
-      returns: {
-        option: 'u128';
-      };
     },
     {
-      name: 'editOrder';
-      docs: ['Edit an order.'];
+      name: 'joinMerkleAllowlist';
+      docs: [
+        'Prove the owner of an [`OpenOrdersAccount`](crate::state::OpenOrdersAccount)',
+        "is in the market's [`MerkleAllowlist`](crate::state::MerkleAllowlist).",
+        '',
+        'The account can then place orders without the open orders admin signature.',
+      ];
       accounts: [
         {
-          name: 'signer';
+          name: 'owner';
           isMut: false;
           isSigner: true;
         },
@@ -455,214 +475,281 @@ export type OpenbookV2 = {
           isSigner: false;
         },
         {
-          name: 'openOrdersAdmin';
+          name: 'market';
           isMut: false;
-          isSigner: true;
-          isOptional: true;
-        },
-        {
-          name: 'userTokenAccount';
-          isMut: true;
           isSigner: false;
         },
         {
-          name: 'market';
-          isMut: true;
+          name: 'merkleAllowlist';
+          isMut: false;
           isSigner: false;
         },
+      ];
+      args: [
         {
-          name: 'bids';
-          isMut: true;
-          isSigner: false;
+          name: 'proof';
+          type: {
+            vec: {
+              array: ['u8', 32];
+            };
+          };
         },
+      ];
+    },
+    {
+      name: 'revokeMerkleAllowlistApproval';
+      docs: [
+        'Require the open orders admin signature again for an',
+        '[`OpenOrdersAccount`](crate::state::OpenOrdersAccount) that joined the',
+        "market's allowlist, as its open orders admin.",
+      ];
+      accounts: [
         {
-          name: 'asks';
-          isMut: true;
+          name: 'openOrdersAdmin';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'market';
+          isMut: false;
           isSigner: false;
         },
         {
-          name: 'eventHeap';
+          name: 'openOrdersAccount';
           isMut: true;
           isSigner: false;
         },
+      ];
+      args: [];
+    },
+    {
+      name: 'createTokenGate';
+      docs: [
+        'Create the [`TokenGate`](crate::state::TokenGate) of a permissioned market,',
+        'as its open orders admin.',
+        '',
+        'Orders can then be placed without the open orders admin signature by',
+        'passing the gate and a token account of the owner holding at least',
+        '`min_amount` native units of `mint`. `min_amount` is at least 1.',
+      ];
+      accounts: [
+        {
+          name: 'openOrdersAdmin';
+          isMut: false;
+          isSigner: true;
+        },
         {
-          name: 'marketVault';
+          name: 'payer';
           isMut: true;
-          isSigner: false;
+          isSigner: true;
         },
         {
-          name: 'oracleA';
+          name: 'market';
           isMut: false;
           isSigner: false;
-          isOptional: true;
         },
         {
-          name: 'oracleB';
+          name: 'mint';
           isMut: false;
           isSigner: false;
-          isOptional: true;
         },
         {
-          name: 'tokenProgram';
+          name: 'tokenGate';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
           isMut: false;
           isSigner: false;
         },
       ];
       args: [
         {
-          name: 'clientOrderId';
+          name: 'minAmount';
           type: 'u64';
         },
-        {
-          name: 'expectedCancelSize';
-          type: 'i64';
-        },
-        {
-          name: 'placeOrder';
-          type: {
-            defined: 'PlaceOrderArgs';
-          };
-        },
       ];
-      returns: {
-        option: 'u128';
-      };
     },
     {
-      name: 'editOrderPegged';
-      docs: ['Edit an order pegged.'];
+      name: 'createTakerProgramAllowlist';
+      docs: [
+        'Create the [`TakerProgramAllowlist`](crate::state::TakerProgramAllowlist)',
+        'of a permissioned market, as its open orders admin.',
+      ];
       accounts: [
         {
-          name: 'signer';
+          name: 'openOrdersAdmin';
           isMut: false;
           isSigner: true;
         },
         {
-          name: 'openOrdersAccount';
+          name: 'payer';
           isMut: true;
-          isSigner: false;
+          isSigner: true;
         },
         {
-          name: 'openOrdersAdmin';
+          name: 'market';
           isMut: false;
-          isSigner: true;
-          isOptional: true;
+          isSigner: false;
         },
         {
-          name: 'userTokenAccount';
+          name: 'takerProgramAllowlist';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'market';
-          isMut: true;
+          name: 'systemProgram';
+          isMut: false;
           isSigner: false;
         },
+      ];
+      args: [
         {
-          name: 'bids';
-          isMut: true;
-          isSigner: false;
+          name: 'programs';
+          type: {
+            vec: 'publicKey';
+          };
+        },
+      ];
+    },
+    {
+      name: 'setTakerProgramAllowlist';
+      docs: [
+        "Replace the programs of a market's",
+        '[`TakerProgramAllowlist`](crate::state::TakerProgramAllowlist), as its open',
+        'orders admin.',
+      ];
+      accounts: [
+        {
+          name: 'openOrdersAdmin';
+          isMut: false;
+          isSigner: true;
         },
         {
-          name: 'asks';
-          isMut: true;
+          name: 'market';
+          isMut: false;
           isSigner: false;
         },
         {
-          name: 'eventHeap';
+          name: 'takerProgramAllowlist';
           isMut: true;
           isSigner: false;
         },
+      ];
+      args: [
+        {
+          name: 'programs';
+          type: {
+            vec: 'publicKey';
+          };
+        },
+      ];
+    },
+    {
+      name: 'createMarketRegistryPage';
+      docs: [
+        'Create a page of the market registry, see',
+        '[`MarketRegistryPage`](crate::state::MarketRegistryPage).',
+        '',
+        'Anyone can pay for it. Every page but the first one requires the previous',
+        'page to be full.',
+      ];
+      accounts: [
         {
-          name: 'marketVault';
+          name: 'payer';
           isMut: true;
-          isSigner: false;
+          isSigner: true;
         },
         {
-          name: 'oracleA';
+          name: 'previousPage';
           isMut: false;
           isSigner: false;
           isOptional: true;
+          docs: ['Required for every page but the first one.'];
         },
         {
-          name: 'oracleB';
-          isMut: false;
+          name: 'registryPage';
+          isMut: true;
           isSigner: false;
-          isOptional: true;
         },
         {
-          name: 'tokenProgram';
+          name: 'systemProgram';
           isMut: false;
           isSigner: false;
         },
       ];
       args: [
         {
-          name: 'clientOrderId';
-          type: 'u64';
-        },
-        {
-          name: 'expectedCancelSize';
-          type: 'i64';
-        },
-        {
-          name: 'placeOrder';
-          type: {
-            defined: 'PlaceOrderPeggedArgs';
-          };
+          name: 'pageIndex';
+          type: 'u32';
         },
       ];
-      returns: {
-        option: 'u128';
-      };
     },
     {
-      name: 'placeOrders';
-      docs: ['Place multiple orders'];
+      name: 'registerMarket';
+      docs: [
+        'Add a [`Market`](crate::state::Market) to the market registry.',
+        '',
+        'Permissionless, each market can only be registered once. Clients usually',
+        'do it in the same transaction as `create_market`.',
+      ];
       accounts: [
         {
-          name: 'signer';
-          isMut: false;
-          isSigner: true;
+          name: 'market';
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: 'openOrdersAccount';
+          name: 'registryPage';
           isMut: true;
           isSigner: false;
         },
+      ];
+      args: [];
+    },
+    {
+      name: 'closeMarket';
+      docs: [
+        'Close a [`Market`](crate::state::Market) (only',
+        '[`close_market_admin`](crate::state::Market::close_market_admin)).',
+        '',
+        'Sends the dust left in the vaults to the collect fee admin, and the rent',
+        'of the market, its book, event heap and vaults to `sol_destination`.',
+      ];
+      accounts: [
         {
-          name: 'openOrdersAdmin';
+          name: 'closeMarketAdmin';
           isMut: false;
           isSigner: true;
-          isOptional: true;
         },
         {
-          name: 'userQuoteAccount';
+          name: 'market';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'userBaseAccount';
-          isMut: true;
+          name: 'marketAuthority';
+          isMut: false;
           isSigner: false;
         },
         {
-          name: 'market';
+          name: 'bids';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'bids';
+          name: 'asks';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'asks';
+          name: 'eventHeap';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'eventHeap';
+          name: 'marketBaseVault';
           isMut: true;
           isSigner: false;
         },
@@ -672,21 +759,25 @@ export type OpenbookV2 = {
           isSigner: false;
         },
         {
-          name: 'marketBaseVault';
+          name: 'baseDustReceiver';
           isMut: true;
           isSigner: false;
+          docs: [
+            "Collect fee admin's account receiving what's left in the base vault",
+          ];
         },
         {
-          name: 'oracleA';
-          isMut: false;
+          name: 'quoteDustReceiver';
+          isMut: true;
           isSigner: false;
-          isOptional: true;
+          docs: [
+            "Collect fee admin's account receiving what's left in the quote vault",
+          ];
         },
         {
-          name: 'oracleB';
-          isMut: false;
+          name: 'solDestination';
+          isMut: true;
           isSigner: false;
-          isOptional: true;
         },
         {
           name: 'tokenProgram';
@@ -694,155 +785,252 @@ export type OpenbookV2 = {
           isSigner: false;
         },
       ];
-      args: [
-        {
-          name: 'ordersType';
-          type: {
-            defined: 'PlaceOrderType';
-          };
-        },
-        {
-          name: 'bids';
-          type: {
-            vec: {
-              defined: 'PlaceMultipleOrdersArgs';
-            };
-          };
-        },
-        {
-          name: 'asks';
-          type: {
-            vec: {
-              defined: 'PlaceMultipleOrdersArgs';
-            };
-          };
-        },
-        {
-          name: 'limit';
-          type: 'u8';
-        },
-      ];
-      returns: {
-        vec: {
-          option: 'u128';
-        };
-      };
+      args: [];
     },
     {
-      name: 'cancelAllAndPlaceOrders';
-      docs: ['Cancel orders and place multiple orders.'];
+      name: 'createOpenOrdersIndexer';
+      docs: [
+        'Create an [`OpenOrdersIndexer`](crate::state::OpenOrdersIndexer) account.',
+      ];
       accounts: [
         {
-          name: 'signer';
+          name: 'payer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'owner';
           isMut: false;
           isSigner: true;
         },
         {
-          name: 'openOrdersAccount';
+          name: 'openOrdersIndexer';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'openOrdersAdmin';
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'closeOpenOrdersIndexer';
+      docs: [
+        'Close an [`OpenOrdersIndexer`](crate::state::OpenOrdersIndexer) account.',
+      ];
+      accounts: [
+        {
+          name: 'owner';
           isMut: false;
           isSigner: true;
-          isOptional: true;
         },
         {
-          name: 'userQuoteAccount';
+          name: 'openOrdersIndexer';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'userBaseAccount';
+          name: 'solDestination';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'market';
+          name: 'tokenProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'createOpenOrdersAccount';
+      docs: [
+        'Create an [`OpenOrdersAccount`](crate::state::OpenOrdersAccount).',
+      ];
+      accounts: [
+        {
+          name: 'payer';
           isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'owner';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'delegateAccount';
+          isMut: false;
           isSigner: false;
+          isOptional: true;
         },
         {
-          name: 'bids';
+          name: 'openOrdersIndexer';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'asks';
+          name: 'openOrdersAccount';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'eventHeap';
+          name: 'market';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'name';
+          type: 'string';
+        },
+      ];
+    },
+    {
+      name: 'closeOpenOrdersAccount';
+      docs: [
+        'Close an [`OpenOrdersAccount`](crate::state::OpenOrdersAccount).',
+      ];
+      accounts: [
+        {
+          name: 'owner';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'openOrdersIndexer';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'marketQuoteVault';
+          name: 'openOrdersAccount';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'marketBaseVault';
+          name: 'solDestination';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'oracleA';
+          name: 'systemProgram';
           isMut: false;
           isSigner: false;
-          isOptional: true;
         },
+      ];
+      args: [];
+    },
+    {
+      name: 'createPositionExtension';
+      docs: [
+        'Create the [`PositionExtension`](crate::state::PositionExtension) of an',
+        '[`OpenOrdersAccount`](crate::state::OpenOrdersAccount), tracking its net',
+        'position, cost basis and realized PnL from then on.',
+        '',
+        'The extension has to be passed among the remaining accounts of every order',
+        'the account places afterwards and of every instruction executing its fills,',
+        'like its open orders account: orders fail with `MissingPositionExtension` and',
+        'the fills of makers stay on the event heap without it.',
+      ];
+      accounts: [
         {
-          name: 'oracleB';
+          name: 'owner';
           isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'payer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'openOrdersAccount';
+          isMut: true;
           isSigner: false;
-          isOptional: true;
         },
         {
-          name: 'tokenProgram';
+          name: 'positionExtension';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
           isMut: false;
           isSigner: false;
         },
       ];
-      args: [
+      args: [];
+    },
+    {
+      name: 'closePositionExtension';
+      docs: [
+        'Close the [`PositionExtension`](crate::state::PositionExtension) of an',
+        '[`OpenOrdersAccount`](crate::state::OpenOrdersAccount). Required before',
+        'closing the account itself.',
+      ];
+      accounts: [
         {
-          name: 'ordersType';
-          type: {
-            defined: 'PlaceOrderType';
-          };
+          name: 'owner';
+          isMut: false;
+          isSigner: true;
         },
         {
-          name: 'bids';
-          type: {
-            vec: {
-              defined: 'PlaceMultipleOrdersArgs';
-            };
-          };
+          name: 'openOrdersAccount';
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: 'asks';
-          type: {
-            vec: {
-              defined: 'PlaceMultipleOrdersArgs';
-            };
-          };
+          name: 'positionExtension';
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: 'limit';
-          type: 'u8';
+          name: 'solDestination';
+          isMut: true;
+          isSigner: false;
         },
       ];
-      returns: {
-        vec: {
-          option: 'u128';
-        };
-      };
+      args: [];
     },
     {
-      name: 'placeOrderPegged';
-      docs: ['Place an oracle-peg order.'];
+      name: 'placeOrder';
+      docs: [
+        'Place an order.',
+        '',
+        'Different types of orders have different effects on the order book,',
+        'as described in [`PlaceOrderType`](crate::state::PlaceOrderType).',
+        '',
+        '`price_lots` refers to the price in lots: the number of quote lots',
+        'per base lot. It is ignored for `PlaceOrderType::Market` orders.',
+        '',
+        '`expiry_timestamp` is a unix timestamp for when this order should',
+        'expire. If 0 is passed in, the order will never expire. If the time',
+        'is in the past, the instruction is skipped. Timestamps in the future',
+        'are reduced to now + 65,535s.',
+        '',
+        '`limit` determines the maximum number of orders from the book to fill,',
+        'and can be used to limit CU spent. When the limit is reached, processing',
+        'stops and the instruction succeeds.',
+        '',
+        'Makers whose open orders account is among the remaining accounts get their',
+        'fills applied right away. If they enabled `set_direct_settlement` and a token',
+        'account of theirs for the token the taker pays with is passed too, their',
+        'proceeds are transferred to it directly, see',
+        '[`maker_settlement`](crate::maker_settlement).',
+        '',
+        'The signer, the owner or a delegate such as a session key, pays for',
+        "nothing and isn't writable, so a relayer can pay the transaction fee, see",
+        '`place_order_relayed`.',
+      ];
       accounts: [
         {
           name: 'signer';
@@ -907,12 +1095,34 @@ export type OpenbookV2 = {
           isMut: false;
           isSigner: false;
         },
+        {
+          name: 'tokenGate';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'gateTokenAccount';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'eventAuthority';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'program';
+          isMut: false;
+          isSigner: false;
+        },
       ];
       args: [
         {
           name: 'args';
           type: {
-            defined: 'PlaceOrderPeggedArgs';
+            defined: 'PlaceOrderArgs';
           };
         },
       ];
@@ -921,66 +1131,75 @@ export type OpenbookV2 = {
       };
     },
     {
-      name: 'placeTakeOrder';
+      name: 'placeOrderRelayed';
       docs: [
-        'Place an order that shall take existing liquidity off of the book, not',
-        'add a new order off the book.',
+        'Place an order like `place_order` in a transaction whose fee is paid by',
+        "`relayer`, who gets `relayer_fee` native tokens of the signer's token",
+        'account in exchange.',
         '',
-        'This type of order allows for instant token settlement for the taker.',
+        'The signer has to be the owner or delegate of the open orders account, the',
+        "relayer signing doesn't give it any say over the account.",
       ];
       accounts: [
         {
           name: 'signer';
-          isMut: true;
+          isMut: false;
           isSigner: true;
+          docs: [
+            'Owner or delegate of the open orders account, e.g. a session key',
+          ];
         },
         {
-          name: 'penaltyPayer';
-          isMut: true;
+          name: 'relayer';
+          isMut: false;
           isSigner: true;
+          docs: [
+            'Pays the transaction fee, receiving `relayer_fee` in exchange',
+          ];
         },
         {
-          name: 'market';
+          name: 'relayerTokenAccount';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'marketAuthority';
-          isMut: false;
+          name: 'openOrdersAccount';
+          isMut: true;
           isSigner: false;
         },
         {
-          name: 'bids';
-          isMut: true;
-          isSigner: false;
+          name: 'openOrdersAdmin';
+          isMut: false;
+          isSigner: true;
+          isOptional: true;
         },
         {
-          name: 'asks';
+          name: 'userTokenAccount';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'marketBaseVault';
+          name: 'market';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'marketQuoteVault';
+          name: 'bids';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'eventHeap';
+          name: 'asks';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'userBaseAccount';
+          name: 'eventHeap';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'userQuoteAccount';
+          name: 'marketVault';
           isMut: true;
           isSigner: false;
         },
@@ -1002,92 +1221,81 @@ export type OpenbookV2 = {
           isSigner: false;
         },
         {
-          name: 'systemProgram';
+          name: 'tokenGate';
           isMut: false;
           isSigner: false;
+          isOptional: true;
         },
         {
-          name: 'openOrdersAdmin';
+          name: 'gateTokenAccount';
           isMut: false;
-          isSigner: true;
+          isSigner: false;
           isOptional: true;
         },
+        {
+          name: 'eventAuthority';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'program';
+          isMut: false;
+          isSigner: false;
+        },
       ];
       args: [
         {
           name: 'args';
           type: {
-            defined: 'PlaceTakeOrderArgs';
+            defined: 'PlaceOrderArgs';
           };
         },
+        {
+          name: 'relayerFee';
+          type: 'u64';
+        },
       ];
+      returns: {
+        option: 'u128';
+      };
     },
     {
-      name: 'consumeEvents';
-      docs: [
-        'Process up to `limit` [events](crate::state::AnyEvent).',
-        '',
-        "When a user places a 'take' order, they do not know beforehand which",
-        "market maker will have placed the 'make' order that they get executed",
-        "against. This prevents them from passing in a market maker's",
-        '[`OpenOrdersAccount`](crate::state::OpenOrdersAccount), which is needed',
-        'to credit/debit the relevant tokens to/from the maker. As such, Openbook',
-        "uses a 'crank' system, where `place_order` only emits events, and",
-        '`consume_events` handles token settlement.',
-        '',
-        'Currently, there are two types of events: [`FillEvent`](crate::state::FillEvent)s',
-        'and [`OutEvent`](crate::state::OutEvent)s.',
-        '',
-        'A `FillEvent` is emitted when an order is filled, and it is handled by',
-        'debiting whatever the taker is selling from the taker and crediting',
-        'it to the maker, and debiting whatever the taker is buying from the',
-        'maker and crediting it to the taker. Note that *no tokens are moved*,',
-        "these are just debits and credits to each party's [`Position`](crate::state::Position).",
-        '',
-        'An `OutEvent` is emitted when a limit order needs to be removed from',
-        'the book during a `place_order` invocation, and it is handled by',
-        'crediting whatever the maker would have sold (quote token in a bid,',
-        'base token in an ask) back to the maker.',
-      ];
+      name: 'editOrder';
+      docs: ['Edit an order.'];
       accounts: [
         {
-          name: 'consumeEventsAdmin';
+          name: 'signer';
           isMut: false;
           isSigner: true;
-          isOptional: true;
         },
         {
-          name: 'market';
+          name: 'openOrdersAccount';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'eventHeap';
+          name: 'openOrdersAdmin';
+          isMut: false;
+          isSigner: true;
+          isOptional: true;
+        },
+        {
+          name: 'userTokenAccount';
           isMut: true;
           isSigner: false;
         },
-      ];
-      args: [
         {
-          name: 'limit';
-          type: 'u64';
+          name: 'market';
+          isMut: true;
+          isSigner: false;
         },
-      ];
-    },
-    {
-      name: 'consumeGivenEvents';
-      docs: [
-        'Process the [events](crate::state::AnyEvent) at the given positions.',
-      ];
-      accounts: [
         {
-          name: 'consumeEventsAdmin';
-          isMut: false;
-          isSigner: true;
-          isOptional: true;
+          name: 'bids';
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: 'market';
+          name: 'asks';
           isMut: true;
           isSigner: false;
         },
@@ -1096,66 +1304,74 @@ export type OpenbookV2 = {
           isMut: true;
           isSigner: false;
         },
-      ];
-      args: [
         {
-          name: 'slots';
-          type: {
-            vec: 'u64';
-          };
+          name: 'marketVault';
+          isMut: true;
+          isSigner: false;
         },
-      ];
-    },
-    {
-      name: 'cancelOrder';
-      docs: [
-        'Cancel an order by its `order_id`.',
-        '',
-        "Note that this doesn't emit an [`OutEvent`](crate::state::OutEvent) because a",
-        'maker knows that they will be passing in their own [`OpenOrdersAccount`](crate::state::OpenOrdersAccount).',
-      ];
-      accounts: [
         {
-          name: 'signer';
+          name: 'oracleA';
           isMut: false;
-          isSigner: true;
+          isSigner: false;
+          isOptional: true;
         },
         {
-          name: 'openOrdersAccount';
-          isMut: true;
+          name: 'oracleB';
+          isMut: false;
           isSigner: false;
+          isOptional: true;
         },
         {
-          name: 'market';
+          name: 'tokenProgram';
           isMut: false;
           isSigner: false;
         },
         {
-          name: 'bids';
-          isMut: true;
+          name: 'tokenGate';
+          isMut: false;
           isSigner: false;
+          isOptional: true;
         },
         {
-          name: 'asks';
-          isMut: true;
+          name: 'gateTokenAccount';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'eventAuthority';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'program';
+          isMut: false;
           isSigner: false;
         },
       ];
       args: [
         {
-          name: 'orderId';
-          type: 'u128';
+          name: 'clientOrderId';
+          type: 'u64';
+        },
+        {
+          name: 'expectedCancelSize';
+          type: 'i64';
+        },
+        {
+          name: 'placeOrder';
+          type: {
+            defined: 'PlaceOrderArgs';
+          };
         },
       ];
+      returns: {
+        option: 'u128';
+      };
     },
     {
-      name: 'cancelOrderByClientOrderId';
-      docs: [
-        'Cancel an order by its `client_order_id`.',
-        '',
-        "Note that this doesn't emit an [`OutEvent`](crate::state::OutEvent) because a",
-        'maker knows that they will be passing in their own [`OpenOrdersAccount`](crate::state::OpenOrdersAccount).',
-      ];
+      name: 'editOrderPegged';
+      docs: ['Edit an order pegged.'];
       accounts: [
         {
           name: 'signer';
@@ -1168,8 +1384,19 @@ export type OpenbookV2 = {
           isSigner: false;
         },
         {
-          name: 'market';
+          name: 'openOrdersAdmin';
           isMut: false;
+          isSigner: true;
+          isOptional: true;
+        },
+        {
+          name: 'userTokenAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'market';
+          isMut: true;
           isSigner: false;
         },
         {
@@ -1182,87 +1409,103 @@ export type OpenbookV2 = {
           isMut: true;
           isSigner: false;
         },
-      ];
-      args: [
         {
-          name: 'clientOrderId';
-          type: 'u64';
+          name: 'eventHeap';
+          isMut: true;
+          isSigner: false;
         },
-      ];
-      returns: 'i64';
-    },
-    {
-      name: 'cancelAllOrders';
-      docs: ['Cancel up to `limit` orders, optionally filtering by side'];
-      accounts: [
         {
-          name: 'signer';
+          name: 'marketVault';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'oracleA';
           isMut: false;
-          isSigner: true;
+          isSigner: false;
+          isOptional: true;
         },
         {
-          name: 'openOrdersAccount';
-          isMut: true;
+          name: 'oracleB';
+          isMut: false;
           isSigner: false;
+          isOptional: true;
         },
         {
-          name: 'market';
+          name: 'tokenProgram';
           isMut: false;
           isSigner: false;
         },
         {
-          name: 'bids';
-          isMut: true;
+          name: 'tokenGate';
+          isMut: false;
           isSigner: false;
+          isOptional: true;
         },
         {
-          name: 'asks';
-          isMut: true;
+          name: 'gateTokenAccount';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'eventAuthority';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'program';
+          isMut: false;
           isSigner: false;
         },
       ];
       args: [
         {
-          name: 'sideOption';
-          type: {
-            option: {
-              defined: 'Side';
-            };
-          };
+          name: 'clientOrderId';
+          type: 'u64';
         },
         {
-          name: 'limit';
-          type: 'u8';
+          name: 'expectedCancelSize';
+          type: 'i64';
+        },
+        {
+          name: 'placeOrder';
+          type: {
+            defined: 'PlaceOrderPeggedArgs';
+          };
         },
       ];
+      returns: {
+        option: 'u128';
+      };
     },
     {
-      name: 'deposit';
-      docs: [
-        "Deposit a certain amount of `base` and `quote` lamports into one's",
-        '[`Position`](crate::state::Position).',
-        '',
-        'Makers might wish to `deposit`, rather than have actual tokens moved for',
-        'each trade, in order to reduce CUs.',
-      ];
+      name: 'placeOrders';
+      docs: ['Place multiple orders'];
       accounts: [
         {
-          name: 'owner';
+          name: 'signer';
           isMut: false;
           isSigner: true;
         },
         {
-          name: 'userBaseAccount';
+          name: 'openOrdersAccount';
           isMut: true;
           isSigner: false;
         },
+        {
+          name: 'openOrdersAdmin';
+          isMut: false;
+          isSigner: true;
+          isOptional: true;
+        },
         {
           name: 'userQuoteAccount';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'openOrdersAccount';
+          name: 'userBaseAccount';
           isMut: true;
           isSigner: false;
         },
@@ -1272,147 +1515,174 @@ export type OpenbookV2 = {
           isSigner: false;
         },
         {
-          name: 'marketBaseVault';
+          name: 'bids';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'marketQuoteVault';
+          name: 'asks';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'tokenProgram';
-          isMut: false;
+          name: 'eventHeap';
+          isMut: true;
           isSigner: false;
         },
-      ];
-      args: [
         {
-          name: 'baseAmount';
-          type: 'u64';
+          name: 'marketQuoteVault';
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: 'quoteAmount';
-          type: 'u64';
+          name: 'marketBaseVault';
+          isMut: true;
+          isSigner: false;
         },
-      ];
-    },
-    {
-      name: 'refill';
-      docs: [
-        'Refill a certain amount of `base` and `quote` lamports. The amount being passed is the',
-        'total lamports that the [`Position`](crate::state::Position) will have.',
-        '',
-        'Makers might wish to `refill`, rather than have actual tokens moved for',
-        'each trade, in order to reduce CUs.',
-      ];
-      accounts: [
         {
-          name: 'owner';
+          name: 'oracleA';
           isMut: false;
-          isSigner: true;
-        },
-        {
-          name: 'userBaseAccount';
-          isMut: true;
           isSigner: false;
+          isOptional: true;
         },
         {
-          name: 'userQuoteAccount';
-          isMut: true;
+          name: 'oracleB';
+          isMut: false;
           isSigner: false;
+          isOptional: true;
         },
         {
-          name: 'openOrdersAccount';
-          isMut: true;
+          name: 'tokenProgram';
+          isMut: false;
           isSigner: false;
         },
         {
-          name: 'market';
-          isMut: true;
+          name: 'tokenGate';
+          isMut: false;
           isSigner: false;
+          isOptional: true;
         },
         {
-          name: 'marketBaseVault';
-          isMut: true;
+          name: 'gateTokenAccount';
+          isMut: false;
           isSigner: false;
+          isOptional: true;
         },
         {
-          name: 'marketQuoteVault';
-          isMut: true;
+          name: 'eventAuthority';
+          isMut: false;
           isSigner: false;
         },
         {
-          name: 'tokenProgram';
+          name: 'program';
           isMut: false;
           isSigner: false;
         },
       ];
       args: [
         {
-          name: 'baseAmount';
-          type: 'u64';
+          name: 'ordersType';
+          type: {
+            defined: 'PlaceOrderType';
+          };
         },
         {
-          name: 'quoteAmount';
-          type: 'u64';
+          name: 'bids';
+          type: {
+            vec: {
+              defined: 'PlaceMultipleOrdersArgs';
+            };
+          };
+        },
+        {
+          name: 'asks';
+          type: {
+            vec: {
+              defined: 'PlaceMultipleOrdersArgs';
+            };
+          };
+        },
+        {
+          name: 'limit';
+          type: 'u8';
         },
       ];
+      returns: {
+        vec: {
+          option: 'u128';
+        };
+      };
     },
     {
-      name: 'settleFunds';
-      docs: ['Withdraw any available tokens.'];
+      name: 'cancelAllAndPlaceOrders';
+      docs: ['Cancel orders and place multiple orders.'];
       accounts: [
         {
-          name: 'owner';
-          isMut: true;
+          name: 'signer';
+          isMut: false;
           isSigner: true;
         },
         {
-          name: 'penaltyPayer';
+          name: 'openOrdersAccount';
           isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'openOrdersAdmin';
+          isMut: false;
           isSigner: true;
+          isOptional: true;
         },
         {
-          name: 'openOrdersAccount';
+          name: 'userQuoteAccount';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'market';
+          name: 'userBaseAccount';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'marketAuthority';
-          isMut: false;
+          name: 'market';
+          isMut: true;
           isSigner: false;
         },
         {
-          name: 'marketBaseVault';
+          name: 'bids';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'marketQuoteVault';
+          name: 'asks';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'userBaseAccount';
+          name: 'eventHeap';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'userQuoteAccount';
+          name: 'marketQuoteVault';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'referrerAccount';
+          name: 'marketBaseVault';
           isMut: true;
           isSigner: false;
+        },
+        {
+          name: 'oracleA';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'oracleB';
+          isMut: false;
+          isSigner: false;
           isOptional: true;
         },
         {
@@ -1421,37 +1691,84 @@ export type OpenbookV2 = {
           isSigner: false;
         },
         {
-          name: 'systemProgram';
+          name: 'tokenGate';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'gateTokenAccount';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'eventAuthority';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'program';
           isMut: false;
           isSigner: false;
         },
       ];
-      args: [];
+      args: [
+        {
+          name: 'ordersType';
+          type: {
+            defined: 'PlaceOrderType';
+          };
+        },
+        {
+          name: 'bids';
+          type: {
+            vec: {
+              defined: 'PlaceMultipleOrdersArgs';
+            };
+          };
+        },
+        {
+          name: 'asks';
+          type: {
+            vec: {
+              defined: 'PlaceMultipleOrdersArgs';
+            };
+          };
+        },
+        {
+          name: 'limit';
+          type: 'u8';
+        },
+      ];
+      returns: {
+        vec: {
+          option: 'u128';
+        };
+      };
     },
     {
-      name: 'settleFundsExpired';
-      docs: [
-        'Withdraw any available tokens when the market is expired (only',
-        '[`close_market_admin`](crate::state::Market::close_market_admin)).',
-      ];
+      name: 'placeOrderPegged';
+      docs: ['Place an oracle-peg order.'];
       accounts: [
         {
-          name: 'closeMarketAdmin';
+          name: 'signer';
           isMut: false;
           isSigner: true;
         },
         {
-          name: 'owner';
+          name: 'openOrdersAccount';
           isMut: true;
-          isSigner: true;
+          isSigner: false;
         },
         {
-          name: 'penaltyPayer';
-          isMut: true;
+          name: 'openOrdersAdmin';
+          isMut: false;
           isSigner: true;
+          isOptional: true;
         },
         {
-          name: 'openOrdersAccount';
+          name: 'userTokenAccount';
           isMut: true;
           isSigner: false;
         },
@@ -1461,140 +1778,226 @@ export type OpenbookV2 = {
           isSigner: false;
         },
         {
-          name: 'marketAuthority';
-          isMut: false;
-          isSigner: false;
-        },
-        {
-          name: 'marketBaseVault';
+          name: 'bids';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'marketQuoteVault';
+          name: 'asks';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'userBaseAccount';
+          name: 'eventHeap';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'userQuoteAccount';
+          name: 'marketVault';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'referrerAccount';
-          isMut: true;
+          name: 'oracleA';
+          isMut: false;
           isSigner: false;
           isOptional: true;
         },
         {
-          name: 'tokenProgram';
+          name: 'oracleB';
           isMut: false;
           isSigner: false;
+          isOptional: true;
         },
         {
-          name: 'systemProgram';
+          name: 'tokenProgram';
           isMut: false;
           isSigner: false;
         },
-      ];
-      args: [];
-    },
-    {
-      name: 'sweepFees';
-      docs: ["Sweep fees, as a [`Market`](crate::state::Market)'s admin."];
-      accounts: [
         {
-          name: 'collectFeeAdmin';
+          name: 'tokenGate';
           isMut: false;
-          isSigner: true;
-        },
-        {
-          name: 'market';
-          isMut: true;
           isSigner: false;
+          isOptional: true;
         },
         {
-          name: 'marketAuthority';
+          name: 'gateTokenAccount';
           isMut: false;
           isSigner: false;
+          isOptional: true;
         },
         {
-          name: 'marketQuoteVault';
-          isMut: true;
-          isSigner: false;
-        },
-        {
-          name: 'tokenReceiverAccount';
-          isMut: true;
+          name: 'eventAuthority';
+          isMut: false;
           isSigner: false;
         },
         {
-          name: 'tokenProgram';
+          name: 'program';
           isMut: false;
           isSigner: false;
         },
       ];
-      args: [];
-    },
-    {
-      name: 'setDelegate';
+      args: [
+        {
+          name: 'args';
+          type: {
+            defined: 'PlaceOrderPeggedArgs';
+          };
+        },
+      ];
+      returns: {
+        option: 'u128';
+      };
+    },
+    {
+      name: 'placeTakeOrder';
       docs: [
-        'Update the [`delegate`](crate::state::OpenOrdersAccount::delegate) of an open orders account.',
+        'Place an order that shall take existing liquidity off of the book, not',
+        'add a new order off the book.',
+        '',
+        'This type of order allows for instant token settlement for the taker.',
+        '',
+        "On permissioned markets, programs in the market's",
+        '[`TakerProgramAllowlist`](crate::state::TakerProgramAllowlist) can call it',
+        'through CPI without the open orders admin signature.',
+        '',
+        'Makers can be settled directly through the remaining accounts as with',
+        '`place_order`.',
       ];
       accounts: [
         {
-          name: 'owner';
+          name: 'signer';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'penaltyPayer';
           isMut: true;
           isSigner: true;
         },
         {
-          name: 'openOrdersAccount';
+          name: 'market';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'delegateAccount';
+          name: 'marketAuthority';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'bids';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'asks';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'marketBaseVault';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'marketQuoteVault';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'eventHeap';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'userBaseAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'userQuoteAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'oracleA';
           isMut: false;
           isSigner: false;
           isOptional: true;
         },
-      ];
-      args: [];
-    },
-    {
-      name: 'setMarketExpired';
-      docs: [
-        'Set market to expired before pruning orders and closing the market (only',
-        '[`close_market_admin`](crate::state::Market::close_market_admin)).',
-      ];
-      accounts: [
         {
-          name: 'closeMarketAdmin';
+          name: 'oracleB';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'tokenProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'openOrdersAdmin';
           isMut: false;
           isSigner: true;
+          isOptional: true;
         },
         {
-          name: 'market';
-          isMut: true;
+          name: 'takerProgramAllowlist';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'instructionsSysvar';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'eventAuthority';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'program';
+          isMut: false;
           isSigner: false;
         },
       ];
-      args: [];
+      args: [
+        {
+          name: 'args';
+          type: {
+            defined: 'PlaceTakeOrderArgs';
+          };
+        },
+      ];
     },
     {
-      name: 'pruneOrders';
+      name: 'placeOrderAndSettle';
       docs: [
-        'Remove orders from the book when the market is expired (only',
-        '[`close_market_admin`](crate::state::Market::close_market_admin)).',
+        'Place an order of an open orders account that only takes liquidity, and',
+        "transfer what it receives straight to the user's token accounts.",
+        '',
+        "Like with `place_take_order` the taker doesn't wait for `consume_events` nor",
+        "calls `settle_funds`, the event heap only keeps the makers' side of the fills.",
+        "Unlike it, the fills count towards the account's volume and referrer rebates,",
+        "and `self_trade_behavior` applies. Other free funds of the account aren't",
+        'touched.',
+        '',
+        'Only `Market`, `ImmediateOrCancel` and `FillOrKill` orders are accepted,',
+        '`expiry_timestamp` is ignored.',
       ];
       accounts: [
         {
-          name: 'closeMarketAdmin';
+          name: 'signer';
           isMut: false;
           isSigner: true;
         },
@@ -1603,8 +2006,19 @@ export type OpenbookV2 = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: 'openOrdersAdmin';
+          isMut: false;
+          isSigner: true;
+          isOptional: true;
+        },
         {
           name: 'market';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'marketAuthority';
           isMut: false;
           isSigner: false;
         },
@@ -1618,4083 +2032,17596 @@ export type OpenbookV2 = {
           isMut: true;
           isSigner: false;
         },
-      ];
-      args: [
         {
-          name: 'limit';
-          type: 'u8';
+          name: 'eventHeap';
+          isMut: true;
+          isSigner: false;
         },
-      ];
-    },
-    {
-      name: 'stubOracleCreate';
-      accounts: [
         {
-          name: 'payer';
+          name: 'marketBaseVault';
           isMut: true;
-          isSigner: true;
+          isSigner: false;
         },
         {
-          name: 'owner';
-          isMut: false;
-          isSigner: true;
+          name: 'marketQuoteVault';
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: 'oracle';
+          name: 'userBaseAccount';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'mint';
+          name: 'userQuoteAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'oracleA';
           isMut: false;
           isSigner: false;
+          isOptional: true;
         },
         {
-          name: 'systemProgram';
+          name: 'oracleB';
           isMut: false;
           isSigner: false;
+          isOptional: true;
         },
-      ];
-      args: [
         {
-          name: 'price';
-          type: 'f64';
+          name: 'tokenProgram';
+          isMut: false;
+          isSigner: false;
         },
-      ];
-    },
-    {
-      name: 'stubOracleClose';
-      accounts: [
         {
-          name: 'owner';
+          name: 'tokenGate';
           isMut: false;
-          isSigner: true;
+          isSigner: false;
+          isOptional: true;
         },
         {
-          name: 'oracle';
-          isMut: true;
+          name: 'gateTokenAccount';
+          isMut: false;
           isSigner: false;
+          isOptional: true;
         },
         {
-          name: 'solDestination';
-          isMut: true;
+          name: 'eventAuthority';
+          isMut: false;
           isSigner: false;
         },
         {
-          name: 'tokenProgram';
+          name: 'program';
           isMut: false;
           isSigner: false;
         },
       ];
-      args: [];
+      args: [
+        {
+          name: 'args';
+          type: {
+            defined: 'PlaceOrderArgs';
+          };
+        },
+      ];
     },
     {
-      name: 'stubOracleSet';
+      name: 'createTriggerOrder';
+      docs: [
+        'Create a [`TriggerOrder`](crate::state::TriggerOrder), placing `order` for an',
+        'open orders account once `condition` is met for `trigger_price_lots`.',
+        '',
+        '`trigger_id` is chosen by the caller and has to be unique per open orders',
+        "account. The order is only funded from the account's free funds when it's",
+        'executed, and `expiry_timestamp` also ends the trigger.',
+      ];
       accounts: [
         {
-          name: 'owner';
+          name: 'payer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'signer';
           isMut: false;
           isSigner: true;
         },
         {
-          name: 'oracle';
+          name: 'openOrdersAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'market';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'triggerOrder';
           isMut: true;
           isSigner: false;
         },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
       ];
       args: [
         {
-          name: 'price';
-          type: 'f64';
+          name: 'triggerId';
+          type: 'u64';
         },
-      ];
-    },
-  ];
-  accounts: [
-    {
-      name: 'market';
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'bump';
-            docs: ['PDA bump'];
-            type: 'u8';
-          },
-          {
-            name: 'baseDecimals';
-            docs: [
-              'Number of decimals used for the base token.',
-              '',
-              "Used to convert the oracle's price into a native/native price.",
-            ];
-            type: 'u8';
-          },
-          {
-            name: 'quoteDecimals';
-            type: 'u8';
-          },
-          {
-            name: 'padding1';
-            type: {
-              array: ['u8', 5];
-            };
-          },
-          {
-            name: 'marketAuthority';
-            type: 'publicKey';
-          },
-          {
-            name: 'timeExpiry';
-            docs: [
-              'No expiry = 0. Market will expire and no trading allowed after time_expiry',
-            ];
-            type: 'i64';
-          },
-          {
-            name: 'collectFeeAdmin';
-            docs: ['Admin who can collect fees from the market'];
-            type: 'publicKey';
-          },
-          {
-            name: 'openOrdersAdmin';
-            docs: ['Admin who must sign off on all order creations'];
-            type: {
-              defined: 'NonZeroPubkeyOption';
-            };
-          },
-          {
-            name: 'consumeEventsAdmin';
-            docs: ['Admin who must sign off on all event consumptions'];
-            type: {
-              defined: 'NonZeroPubkeyOption';
-            };
-          },
-          {
-            name: 'closeMarketAdmin';
-            docs: [
-              'Admin who can set market expired, prune orders and close the market',
-            ];
-            type: {
-              defined: 'NonZeroPubkeyOption';
-            };
-          },
-          {
-            name: 'name';
-            docs: ['Name. Trailing zero bytes are ignored.'];
-            type: {
-              array: ['u8', 16];
-            };
-          },
-          {
-            name: 'bids';
-            docs: ['Address of the BookSide account for bids'];
-            type: 'publicKey';
-          },
-          {
-            name: 'asks';
-            docs: ['Address of the BookSide account for asks'];
-            type: 'publicKey';
-          },
-          {
-            name: 'eventHeap';
-            docs: ['Address of the EventHeap account'];
-            type: 'publicKey';
-          },
-          {
-            name: 'oracleA';
-            docs: ['Oracles account address'];
-            type: {
-              defined: 'NonZeroPubkeyOption';
-            };
-          },
-          {
-            name: 'oracleB';
-            type: {
-              defined: 'NonZeroPubkeyOption';
-            };
-          },
-          {
-            name: 'oracleConfig';
-            docs: ['Oracle configuration'];
-            type: {
-              defined: 'OracleConfig';
-            };
-          },
-          {
-            name: 'quoteLotSize';
-            docs: [
-              'Number of quote native in a quote lot. Must be a power of 10.',
-              '',
-              'Primarily useful for increasing the tick size on the market: A lot price',
-              'of 1 becomes a native price of quote_lot_size/base_lot_size becomes a',
-              'ui price of quote_lot_size*base_decimals/base_lot_size/quote_decimals.',
-            ];
-            type: 'i64';
-          },
-          {
-            name: 'baseLotSize';
-            docs: [
-              'Number of base native in a base lot. Must be a power of 10.',
-              '',
-              'Example: If base decimals for the underlying asset is 6, base lot size',
-              'is 100 and and base position lots is 10_000 then base position native is',
-              '1_000_000 and base position ui is 1.',
-            ];
-            type: 'i64';
-          },
-          {
-            name: 'seqNum';
-            docs: ['Total number of orders seen'];
-            type: 'u64';
-          },
-          {
-            name: 'registrationTime';
-            docs: ['Timestamp in seconds that the market was registered at.'];
-            type: 'i64';
-          },
-          {
-            name: 'makerFee';
-            docs: [
-              'Fees',
-              '',
-              'Fee (in 10^-6) when matching maker orders.',
-              'maker_fee < 0 it means some of the taker_fees goes to the maker',
-              'maker_fee > 0, it means no taker_fee to the maker, and maker fee goes to the referral',
-            ];
-            type: 'i64';
-          },
-          {
-            name: 'takerFee';
-            docs: ['Fee (in 10^-6) for taker orders, always >= 0.'];
-            type: 'i64';
-          },
-          {
-            name: 'feesAccrued';
-            docs: ['Total fees accrued in native quote'];
-            type: 'u128';
-          },
-          {
-            name: 'feesToReferrers';
-            docs: ['Total fees settled in native quote'];
-            type: 'u128';
-          },
-          {
-            name: 'referrerRebatesAccrued';
-            docs: ['Referrer rebates to be distributed'];
-            type: 'u64';
-          },
-          {
-            name: 'feesAvailable';
-            docs: ['Fees generated and available to withdraw via sweep_fees'];
-            type: 'u64';
-          },
-          {
-            name: 'makerVolume';
-            docs: [
-              'Cumulative maker volume (same as taker volume) in quote native units',
-            ];
-            type: 'u128';
-          },
-          {
-            name: 'takerVolumeWoOo';
-            docs: [
-              'Cumulative taker volume in quote native units due to place take orders',
-            ];
-            type: 'u128';
-          },
-          {
-            name: 'baseMint';
-            type: 'publicKey';
-          },
-          {
-            name: 'quoteMint';
-            type: 'publicKey';
-          },
-          {
-            name: 'marketBaseVault';
-            type: 'publicKey';
-          },
-          {
-            name: 'baseDepositTotal';
-            type: 'u64';
-          },
-          {
-            name: 'marketQuoteVault';
-            type: 'publicKey';
-          },
-          {
-            name: 'quoteDepositTotal';
-            type: 'u64';
-          },
-          {
-            name: 'reserved';
-            type: {
-              array: ['u8', 128];
-            };
-          },
-        ];
-      };
+        {
+          name: 'condition';
+          type: {
+            defined: 'TriggerCondition';
+          };
+        },
+        {
+          name: 'triggerPriceLots';
+          type: 'i64';
+        },
+        {
+          name: 'order';
+          type: {
+            defined: 'PlaceOrderArgs';
+          };
+        },
+      ];
     },
     {
-      name: 'openOrdersAccount';
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'owner';
-            type: 'publicKey';
-          },
-          {
-            name: 'market';
-            type: 'publicKey';
-          },
-          {
-            name: 'name';
-            type: {
-              array: ['u8', 32];
-            };
-          },
-          {
-            name: 'delegate';
-            type: {
-              defined: 'NonZeroPubkeyOption';
-            };
-          },
-          {
-            name: 'accountNum';
-            type: 'u32';
-          },
-          {
-            name: 'bump';
-            type: 'u8';
-          },
-          {
-            name: 'version';
-            type: 'u8';
-          },
-          {
-            name: 'padding';
-            type: {
-              array: ['u8', 2];
-            };
-          },
-          {
-            name: 'position';
-            type: {
-              defined: 'Position';
-            };
-          },
-          {
-            name: 'openOrders';
-            type: {
-              array: [
-                {
-                  defined: 'OpenOrder';
-                },
-                24,
-              ];
-            };
-          },
-        ];
-      };
-    },
-    {
-      name: 'openOrdersIndexer';
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'bump';
-            type: 'u8';
-          },
-          {
-            name: 'createdCounter';
-            type: 'u32';
-          },
-          {
-            name: 'addresses';
-            type: {
-              vec: 'publicKey';
-            };
-          },
-        ];
-      };
-    },
-    {
-      name: 'stubOracle';
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'owner';
-            type: 'publicKey';
-          },
-          {
-            name: 'mint';
-            type: 'publicKey';
-          },
-          {
-            name: 'price';
-            type: 'f64';
-          },
-          {
-            name: 'lastUpdateTs';
-            type: 'i64';
-          },
-          {
-            name: 'lastUpdateSlot';
-            type: 'u64';
-          },
-          {
-            name: 'deviation';
-            type: 'f64';
-          },
-          {
-            name: 'reserved';
-            type: {
-              array: ['u8', 104];
-            };
-          },
-        ];
-      };
+      name: 'cancelTriggerOrder';
+      docs: [
+        "Close a trigger order before it's executed, as the owner or delegate of its",
+        'open orders account.',
+      ];
+      accounts: [
+        {
+          name: 'signer';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'openOrdersAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'triggerOrder';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'solDestination';
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [];
     },
     {
-      name: 'bookSide';
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'roots';
-            type: {
-              array: [
-                {
-                  defined: 'OrderTreeRoot';
-                },
-                2,
-              ];
-            };
-          },
-          {
-            name: 'reservedRoots';
-            type: {
-              array: [
-                {
-                  defined: 'OrderTreeRoot';
-                },
-                4,
-              ];
-            };
-          },
-          {
-            name: 'reserved';
-            type: {
-              array: ['u8', 256];
-            };
-          },
-          {
-            name: 'nodes';
-            type: {
-              defined: 'OrderTreeNodes';
-            };
-          },
-        ];
+      name: 'executeTrigger';
+      docs: [
+        'Place the order of a trigger order whose condition is met, closing it.',
+        '',
+        "Anyone can call this and gets the trigger order's rent. Fails if the",
+        "condition isn't met or the free funds of the open orders account don't cover",
+        'the order. Returns the id of the order left on the book, if any. An expired',
+        'trigger order is closed without placing anything.',
+      ];
+      accounts: [
+        {
+          name: 'keeper';
+          isMut: true;
+          isSigner: true;
+          docs: ['Receives the rent of the trigger order'];
+        },
+        {
+          name: 'triggerOrder';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'openOrdersAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'openOrdersAdmin';
+          isMut: false;
+          isSigner: true;
+          isOptional: true;
+        },
+        {
+          name: 'market';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'bids';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'asks';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'eventHeap';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'oracleA';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'oracleB';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'eventAuthority';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'program';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+      returns: {
+        option: 'u128';
       };
     },
     {
-      name: 'eventHeap';
+      name: 'placeSignedOrder';
       docs: [
-        'Container for the different EventTypes.',
+        'Place an order signed off-chain by the owner or delegate of the open orders',
+        'account, on their behalf.',
         '',
-        'Events are stored in a fixed-array of nodes. Free nodes are connected by a single-linked list',
-        'starting at free_head while used nodes form a circular doubly-linked list starting at',
-        'used_head.',
+        'The ed25519 signature of `signed_order` has to be verified by the',
+        'instruction right before this one, see',
+        '[`signed_order`](crate::signed_order). Anyone can submit it, each nonce only',
+        "once, kept in the account's position extension which has to be among the",
+        'remaining accounts. Like trigger orders the order is funded from the free',
+        'funds of the open orders account.',
       ];
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'header';
-            type: {
-              defined: 'EventHeapHeader';
-            };
-          },
-          {
-            name: 'nodes';
-            type: {
-              array: [
-                {
-                  defined: 'EventNode';
-                },
-                600,
-              ];
-            };
-          },
-          {
-            name: 'reserved';
-            type: {
-              array: ['u8', 64];
-            };
-          },
-        ];
+      accounts: [
+        {
+          name: 'relayer';
+          isMut: false;
+          isSigner: true;
+          docs: [
+            "Submits the order signed by the owner or delegate, doesn't need to be",
+            'either',
+          ];
+        },
+        {
+          name: 'openOrdersAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'openOrdersAdmin';
+          isMut: false;
+          isSigner: true;
+          isOptional: true;
+        },
+        {
+          name: 'market';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'bids';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'asks';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'eventHeap';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'oracleA';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'oracleB';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'instructionsSysvar';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'tokenGate';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'gateTokenAccount';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'eventAuthority';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'program';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'signedOrder';
+          type: {
+            defined: 'SignedOrder';
+          };
+        },
+      ];
+      returns: {
+        option: 'u128';
       };
     },
-  ];
-  types: [
     {
-      name: 'NonZeroPubkeyOption';
+      name: 'consumeEvents';
       docs: [
-        'Like `Option`, but implemented for `Pubkey` to be used with `zero_copy`',
+        'Process up to `limit` [events](crate::state::AnyEvent).',
+        '',
+        "When a user places a 'take' order, they do not know beforehand which",
+        "market maker will have placed the 'make' order that they get executed",
+        "against. This prevents them from passing in a market maker's",
+        '[`OpenOrdersAccount`](crate::state::OpenOrdersAccount), which is needed',
+        'to credit/debit the relevant tokens to/from the maker. As such, Openbook',
+        "uses a 'crank' system, where `place_order` only emits events, and",
+        '`consume_events` handles token settlement.',
+        '',
+        'Currently, there are two types of events: [`FillEvent`](crate::state::FillEvent)s',
+        'and [`OutEvent`](crate::state::OutEvent)s.',
+        '',
+        'A `FillEvent` is emitted when an order is filled, and it is handled by',
+        'debiting whatever the taker is selling from the taker and crediting',
+        'it to the maker, and debiting whatever the taker is buying from the',
+        'maker and crediting it to the taker. Note that *no tokens are moved*,',
+        "these are just debits and credits to each party's [`Position`](crate::state::Position).",
+        '',
+        'An `OutEvent` is emitted when a limit order needs to be removed from',
+        'the book during a `place_order` invocation, and it is handled by',
+        'crediting whatever the maker would have sold (quote token in a bid,',
+        'base token in an ask) back to the maker. Its `reason` tells whether the',
+        'order expired or was booted to make room for a better one.',
+        '',
+        'Fill events are processed before out events, so the fills queued behind the',
+        'out events of a mass cancel get processed first.',
+        '',
+        'The open orders accounts of the events go in the remaining accounts, in',
+        "any order. Events whose account is missing stay on the heap, don't count",
+        'towards `limit` and are reported in a',
+        '[`ConsumeEventsSkippedLog`](crate::logs::ConsumeEventsSkippedLog).',
+      ];
+      accounts: [
+        {
+          name: 'consumeEventsAdmin';
+          isMut: false;
+          isSigner: true;
+          isOptional: true;
+        },
+        {
+          name: 'market';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'eventHeap';
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'limit';
+          type: 'u64';
+        },
       ];
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'key';
-            type: 'publicKey';
-          },
-        ];
-      };
     },
     {
-      name: 'Position';
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'bidsBaseLots';
-            docs: ['Base lots in open bids'];
-            type: 'i64';
-          },
-          {
-            name: 'asksBaseLots';
-            docs: ['Base lots in open asks'];
-            type: 'i64';
-          },
-          {
-            name: 'baseFreeNative';
-            type: 'u64';
-          },
-          {
-            name: 'quoteFreeNative';
-            type: 'u64';
-          },
-          {
-            name: 'lockedMakerFees';
-            type: 'u64';
-          },
-          {
-            name: 'referrerRebatesAvailable';
-            type: 'u64';
-          },
-          {
-            name: 'penaltyHeapCount';
-            docs: [
-              'Count of ixs when events are added to the heap',
-              'To avoid this, send remaining accounts in order to process the events',
-            ];
-            type: 'u64';
-          },
-          {
-            name: 'makerVolume';
-            docs: [
-              'Cumulative maker volume in quote native units (display only)',
-            ];
-            type: 'u128';
-          },
-          {
-            name: 'takerVolume';
-            docs: [
-              'Cumulative taker volume in quote native units (display only)',
-            ];
-            type: 'u128';
-          },
-          {
-            name: 'bidsQuoteLots';
-            docs: ['Quote lots in open bids'];
-            type: 'i64';
-          },
-          {
-            name: 'reserved';
-            type: {
-              array: ['u8', 64];
-            };
-          },
-        ];
-      };
+      name: 'consumeGivenEvents';
+      docs: [
+        'Process the [events](crate::state::AnyEvent) at the given positions.',
+      ];
+      accounts: [
+        {
+          name: 'consumeEventsAdmin';
+          isMut: false;
+          isSigner: true;
+          isOptional: true;
+        },
+        {
+          name: 'market';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'eventHeap';
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'slots';
+          type: {
+            vec: 'u64';
+          };
+        },
+      ];
     },
     {
-      name: 'OpenOrder';
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'id';
-            type: 'u128';
-          },
-          {
-            name: 'clientId';
-            type: 'u64';
-          },
-          {
-            name: 'lockedPrice';
-            docs: ["Price at which user's assets were locked"];
-            type: 'i64';
-          },
-          {
-            name: 'isFree';
-            type: 'u8';
-          },
-          {
-            name: 'sideAndTree';
-            type: 'u8';
-          },
-          {
-            name: 'padding';
-            type: {
-              array: ['u8', 6];
-            };
-          },
-        ];
-      };
+      name: 'consumeEventsForAccounts';
+      docs: [
+        'Process up to `limit` [events](crate::state::AnyEvent) involving the',
+        '[`OpenOrdersAccount`](crate::state::OpenOrdersAccount)s passed as remaining',
+        'accounts, leaving all other events on the heap.',
+        '',
+        'Lets a market maker crank their own fills without waiting for, or paying',
+        "for, everybody else's events.",
+      ];
+      accounts: [
+        {
+          name: 'consumeEventsAdmin';
+          isMut: false;
+          isSigner: true;
+          isOptional: true;
+        },
+        {
+          name: 'market';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'eventHeap';
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'limit';
+          type: 'u64';
+        },
+      ];
     },
     {
-      name: 'OracleConfig';
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'confFilter';
-            type: 'f64';
-          },
-          {
-            name: 'maxStalenessSlots';
-            type: 'i64';
-          },
-          {
-            name: 'reserved';
-            type: {
-              array: ['u8', 72];
+      name: 'consumeEventsMultiple';
+      docs: [
+        'Same as `consume_events`, but for several markets at once.',
+        '',
+        'Every market is described by its `market` and `event_heap` remaining',
+        'accounts, followed by the accounts `consume_events` would take as',
+        "remaining accounts: the groups' lengths and limits are in `groups`.",
+        'Markets with a `consume_events_admin` need it as signer.',
+      ];
+      accounts: [
+        {
+          name: 'consumeEventsAdmin';
+          isMut: false;
+          isSigner: true;
+          isOptional: true;
+          docs: [
+            'Needed for the markets with a `consume_events_admin`, which has to be this signer',
+          ];
+        },
+      ];
+      args: [
+        {
+          name: 'groups';
+          type: {
+            vec: {
+              defined: 'ConsumeEventsGroup';
             };
-          },
-        ];
-      };
+          };
+        },
+      ];
     },
     {
-      name: 'OracleConfigParams';
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'confFilter';
-            type: 'f32';
-          },
-          {
-            name: 'maxStalenessSlots';
-            type: {
-              option: 'u32';
-            };
-          },
-        ];
-      };
+      name: 'consumeEventsWithReward';
+      docs: [
+        "Same as `consume_events`, but pays the signing `cranker` the market's",
+        '[`consume_events_reward`](crate::state::Market::consume_events_reward)',
+        'for every processed event.',
+        '',
+        'Rewards are paid in lamports out of the event heap penalties the market',
+        'has collected, and are capped by what is available.',
+      ];
+      accounts: [
+        {
+          name: 'cranker';
+          isMut: true;
+          isSigner: true;
+          docs: ['Receives the reward for the consumed events'];
+        },
+        {
+          name: 'consumeEventsAdmin';
+          isMut: false;
+          isSigner: true;
+          isOptional: true;
+        },
+        {
+          name: 'market';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'eventHeap';
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'limit';
+          type: 'u64';
+        },
+      ];
     },
     {
-      name: 'EventHeapHeader';
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'freeHead';
-            type: 'u16';
-          },
-          {
-            name: 'usedHead';
-            type: 'u16';
-          },
-          {
-            name: 'count';
-            type: 'u16';
-          },
-          {
-            name: 'padd';
-            type: 'u16';
-          },
-          {
-            name: 'seqNum';
-            type: 'u64';
-          },
-        ];
-      };
+      name: 'consumeEventsWithFillCallback';
+      docs: [
+        "Same as `consume_events`, but then calls the market's",
+        '[fill callback](crate::fill_callback) program with the processed fills.',
+        '',
+        'The callback is only made here, so crankers can always fall back to',
+        '`consume_events` if it fails.',
+      ];
+      accounts: [
+        {
+          name: 'consumeEventsAdmin';
+          isMut: false;
+          isSigner: true;
+          isOptional: true;
+        },
+        {
+          name: 'market';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'eventHeap';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'fillCallback';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'fillCallbackAuthority';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'fillCallbackProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'limit';
+          type: 'u64';
+        },
+      ];
     },
     {
-      name: 'EventNode';
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'next';
-            type: 'u16';
-          },
-          {
-            name: 'prev';
-            type: 'u16';
-          },
-          {
-            name: 'pad';
-            type: {
-              array: ['u8', 4];
-            };
-          },
-          {
-            name: 'event';
-            type: {
-              defined: 'AnyEvent';
-            };
-          },
-        ];
-      };
+      name: 'createBookSidePage';
+      docs: [
+        "Chain a page after the market's bids or asks, for fixed price orders posted once",
+        "they're full. The existing pages of the side go in the remaining accounts, in",
+        'order. Anyone can pay for it, up to [`MAX_BOOK_SIDE_PAGES`](crate::state::MAX_BOOK_SIDE_PAGES) per side.',
+        '',
+        'Once a side has orders on its pages, instructions matching against that side',
+        'need all its pages in their remaining accounts, and cancelling an order on a',
+        'page needs the pages up to it.',
+      ];
+      accounts: [
+        {
+          name: 'market';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'bookSide';
+          isMut: true;
+          isSigner: false;
+          docs: ["The market's bids or asks"];
+        },
+        {
+          name: 'page';
+          isMut: true;
+          isSigner: false;
+          docs: [
+            'Account is initialized by client,',
+            'anchor discriminator is set first when ix exits,',
+          ];
+        },
+      ];
+      args: [];
     },
     {
-      name: 'AnyEvent';
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'eventType';
-            type: 'u8';
-          },
-          {
-            name: 'padding';
-            type: {
-              array: ['u8', 143];
-            };
-          },
-        ];
-      };
+      name: 'defragmentBook';
+      docs: [
+        "Compact the nodes of the market's book sides, moving at most `limit` nodes",
+        'per side, and rebuild their free lists. Anyone can call it.',
+        '',
+        'After heavy order churn the live nodes are spread over the whole book side',
+        'account. Repeat until no more nodes are moved to fully compact a book.',
+      ];
+      accounts: [
+        {
+          name: 'market';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'bids';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'asks';
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'limit';
+          type: 'u8';
+        },
+      ];
     },
     {
-      name: 'FillEvent';
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'eventType';
-            type: 'u8';
-          },
-          {
-            name: 'takerSide';
-            type: 'u8';
-          },
-          {
-            name: 'makerOut';
-            type: 'u8';
-          },
-          {
-            name: 'makerSlot';
-            type: 'u8';
-          },
-          {
-            name: 'padding';
-            type: {
-              array: ['u8', 4];
-            };
-          },
-          {
-            name: 'timestamp';
-            type: 'u64';
-          },
-          {
-            name: 'seqNum';
-            type: 'u64';
-          },
-          {
-            name: 'maker';
-            type: 'publicKey';
-          },
-          {
-            name: 'makerTimestamp';
-            type: 'u64';
-          },
-          {
-            name: 'taker';
-            type: 'publicKey';
-          },
-          {
-            name: 'takerClientOrderId';
-            type: 'u64';
-          },
-          {
-            name: 'price';
-            type: 'i64';
-          },
-          {
-            name: 'pegLimit';
-            type: 'i64';
-          },
-          {
-            name: 'quantity';
-            type: 'i64';
-          },
-          {
-            name: 'makerClientOrderId';
-            type: 'u64';
-          },
-          {
-            name: 'reserved';
-            type: {
-              array: ['u8', 8];
-            };
-          },
-        ];
-      };
+      name: 'expireOrders';
+      docs: [
+        "Remove up to `limit` expired orders from each of the market's book sides.",
+        'Anyone can call it.',
+        '',
+        'Resting orders are grouped by expiry in',
+        '[`ExpiryBucket`](crate::state::ExpiryBucket)s, so only the orders of the due',
+        'buckets are looked for, earliest expiry first. Their out events are applied',
+        'to the open orders accounts passed as remaining accounts and queued for the',
+        'others.',
+      ];
+      accounts: [
+        {
+          name: 'market';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'bids';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'asks';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'eventHeap';
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'limit';
+          type: 'u8';
+        },
+      ];
     },
     {
-      name: 'OutEvent';
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'eventType';
-            type: 'u8';
-          },
-          {
-            name: 'side';
-            type: 'u8';
-          },
-          {
-            name: 'ownerSlot';
-            type: 'u8';
-          },
-          {
-            name: 'padding0';
-            type: {
-              array: ['u8', 5];
-            };
-          },
-          {
-            name: 'timestamp';
-            type: 'u64';
-          },
-          {
-            name: 'seqNum';
-            type: 'u64';
-          },
-          {
-            name: 'owner';
-            type: 'publicKey';
-          },
-          {
-            name: 'quantity';
-            type: 'i64';
-          },
-          {
-            name: 'padding1';
-            type: {
-              array: ['u8', 80];
-            };
-          },
-        ];
-      };
+      name: 'cancelOrder';
+      docs: [
+        'Cancel an order by its `order_id`.',
+        '',
+        "Note that this doesn't emit an [`OutEvent`](crate::state::OutEvent) because a",
+        'maker knows that they will be passing in their own [`OpenOrdersAccount`](crate::state::OpenOrdersAccount).',
+      ];
+      accounts: [
+        {
+          name: 'signer';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'openOrdersAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'market';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'bids';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'asks';
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'orderId';
+          type: 'u128';
+        },
+      ];
     },
     {
-      name: 'InnerNode';
+      name: 'cancelOrderByClientOrderId';
       docs: [
-        'InnerNodes and LeafNodes compose the binary tree of orders.',
+        'Cancel an order by its `client_order_id`.',
         '',
-        'Each InnerNode has exactly two children, which are either InnerNodes themselves,',
-        'or LeafNodes. The children share the top `prefix_len` bits of `key`. The left',
-        'child has a 0 in the next bit, and the right a 1.',
+        "Note that this doesn't emit an [`OutEvent`](crate::state::OutEvent) because a",
+        'maker knows that they will be passing in their own [`OpenOrdersAccount`](crate::state::OpenOrdersAccount).',
       ];
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'tag';
-            type: 'u8';
-          },
-          {
-            name: 'padding';
-            type: {
-              array: ['u8', 3];
-            };
-          },
-          {
-            name: 'prefixLen';
-            docs: [
-              'number of highest `key` bits that all children share',
-              "e.g. if it's 2, the two highest bits of `key` will be the same on all children",
-            ];
-            type: 'u32';
-          },
-          {
-            name: 'key';
-            docs: ['only the top `prefix_len` bits of `key` are relevant'];
-            type: 'u128';
-          },
-          {
-            name: 'children';
-            docs: ['indexes into `BookSide::nodes`'];
-            type: {
-              array: ['u32', 2];
-            };
-          },
-          {
-            name: 'childEarliestExpiry';
-            docs: [
-              'The earliest expiry timestamp for the left and right subtrees.',
-              '',
-              'Needed to be able to find and remove expired orders without having to',
-              'iterate through the whole bookside.',
-            ];
-            type: {
-              array: ['u64', 2];
-            };
-          },
-          {
-            name: 'reserved';
-            type: {
-              array: ['u8', 40];
-            };
-          },
-        ];
-      };
-    },
-    {
-      name: 'LeafNode';
-      docs: ['LeafNodes represent an order in the binary tree'];
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'tag';
-            docs: ['NodeTag'];
-            type: 'u8';
-          },
-          {
-            name: 'ownerSlot';
-            docs: ["Index into the owning OpenOrdersAccount's OpenOrders"];
-            type: 'u8';
-          },
-          {
-            name: 'timeInForce';
-            docs: [
-              'Time in seconds after `timestamp` at which the order expires.',
-              'A value of 0 means no expiry.',
-            ];
-            type: 'u16';
-          },
-          {
-            name: 'padding';
-            type: {
-              array: ['u8', 4];
-            };
-          },
-          {
-            name: 'key';
-            docs: ['The binary tree key, see new_node_key()'];
-            type: 'u128';
-          },
-          {
-            name: 'owner';
-            docs: ['Address of the owning OpenOrdersAccount'];
-            type: 'publicKey';
-          },
-          {
-            name: 'quantity';
-            docs: ['Number of base lots to buy or sell, always >=1'];
-            type: 'i64';
-          },
-          {
-            name: 'timestamp';
-            docs: ['The time the order was placed'];
-            type: 'u64';
-          },
-          {
-            name: 'pegLimit';
-            docs: [
-              'If the effective price of an oracle pegged order exceeds this limit,',
-              'it will be considered invalid and may be removed.',
-              '',
-              'Only applicable in the oracle_pegged OrderTree',
-            ];
-            type: 'i64';
-          },
-          {
-            name: 'clientOrderId';
-            docs: ['User defined id for this order, used in FillEvents'];
-            type: 'u64';
-          },
-        ];
-      };
+      accounts: [
+        {
+          name: 'signer';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'openOrdersAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'market';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'bids';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'asks';
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'clientOrderId';
+          type: 'u64';
+        },
+      ];
+      returns: 'i64';
     },
     {
-      name: 'AnyNode';
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'tag';
-            type: 'u8';
-          },
-          {
-            name: 'data';
-            type: {
-              array: ['u8', 87];
-            };
-          },
-        ];
+      name: 'findOrder';
+      docs: [
+        'Look up the order with `client_order_id` of an open orders account, returned',
+        "as the instruction's return data. `None` if the account has no such order.",
+        '',
+        'Lets a bot that lost track of its orders recover their ids, e.g. by simulating',
+        'this instruction. Orders on book side pages are only found with the pages passed',
+        'as remaining accounts, their quantity is 0 otherwise.',
+      ];
+      accounts: [
+        {
+          name: 'openOrdersAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'market';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'bids';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'asks';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'clientOrderId';
+          type: 'u64';
+        },
+      ];
+      returns: {
+        option: {
+          defined: 'OrderInfo';
+        };
       };
     },
     {
-      name: 'OrderTreeRoot';
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'maybeNode';
-            type: 'u32';
-          },
-          {
-            name: 'leafCount';
-            type: 'u32';
-          },
-        ];
+      name: 'getMarketQuote';
+      docs: [
+        'Top of the book of a market along with its oracle price, returned as the',
+        "instruction's return data, see [`MarketQuote`].",
+        '',
+        "Read-only, meant for programs pricing assets against the market's liquidity",
+        'through CPI. Oracle pegged orders are included when the oracle is usable.',
+      ];
+      accounts: [
+        {
+          name: 'market';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'bids';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'asks';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'oracleA';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'oracleB';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+      ];
+      args: [];
+      returns: {
+        defined: 'MarketQuote';
       };
     },
     {
-      name: 'OrderTreeNodes';
+      name: 'snapshotDepth';
       docs: [
-        'A binary tree on AnyNode::key()',
+        'Write the top `levels` aggregated price levels of each side of the book into',
+        'a [`DepthSnapshot`](crate::state::DepthSnapshot), at most',
+        '[`DEPTH_SNAPSHOT_LEVELS`](crate::state::DEPTH_SNAPSHOT_LEVELS).',
         '',
-        'The key encodes the price in the top 64 bits.',
+        'Anyone can refresh a snapshot. Oracle pegged orders are included when the',
+        'oracle is usable.',
       ];
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'orderTreeType';
-            type: 'u8';
-          },
-          {
-            name: 'padding';
-            type: {
-              array: ['u8', 3];
-            };
-          },
-          {
-            name: 'bumpIndex';
-            type: 'u32';
-          },
-          {
-            name: 'freeListLen';
-            type: 'u32';
-          },
-          {
-            name: 'freeListHead';
-            type: 'u32';
-          },
-          {
-            name: 'reserved';
-            type: {
-              array: ['u8', 512];
-            };
-          },
-          {
-            name: 'nodes';
-            type: {
-              array: [
-                {
-                  defined: 'AnyNode';
-                },
-                1024,
-              ];
-            };
-          },
-        ];
-      };
-    },
-    {
-      name: 'I80F48';
-      docs: [
-        'Nothing in Rust shall use these types. They only exist so that the Anchor IDL',
-        'knows about them and typescript can deserialize it.',
+      accounts: [
+        {
+          name: 'depthSnapshot';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'market';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'bids';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'asks';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'oracleA';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'oracleB';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+      ];
+      args: [
+        {
+          name: 'levels';
+          type: 'u8';
+        },
       ];
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'val';
-            type: 'i128';
-          },
-        ];
-      };
     },
     {
-      name: 'PlaceOrderArgs';
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'side';
-            type: {
+      name: 'cancelAllOrders';
+      docs: ['Cancel up to `limit` orders, optionally filtering by side'];
+      accounts: [
+        {
+          name: 'signer';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'openOrdersAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'market';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'bids';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'asks';
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'sideOption';
+          type: {
+            option: {
               defined: 'Side';
             };
-          },
-          {
-            name: 'priceLots';
-            type: 'i64';
-          },
-          {
-            name: 'maxBaseLots';
-            type: 'i64';
-          },
-          {
-            name: 'maxQuoteLotsIncludingFees';
-            type: 'i64';
-          },
-          {
-            name: 'clientOrderId';
-            type: 'u64';
-          },
-          {
-            name: 'orderType';
-            type: {
-              defined: 'PlaceOrderType';
-            };
-          },
-          {
-            name: 'expiryTimestamp';
-            type: 'u64';
-          },
-          {
-            name: 'selfTradeBehavior';
-            type: {
-              defined: 'SelfTradeBehavior';
-            };
-          },
-          {
-            name: 'limit';
-            type: 'u8';
-          },
-        ];
-      };
-    },
-    {
-      name: 'PlaceMultipleOrdersArgs';
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'priceLots';
-            type: 'i64';
-          },
-          {
-            name: 'maxQuoteLotsIncludingFees';
-            type: 'i64';
-          },
-          {
-            name: 'expiryTimestamp';
-            type: 'u64';
-          },
-        ];
-      };
+          };
+        },
+        {
+          name: 'limit';
+          type: 'u8';
+        },
+      ];
     },
     {
-      name: 'PlaceOrderPeggedArgs';
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'side';
-            type: {
-              defined: 'Side';
-            };
-          },
-          {
-            name: 'priceOffsetLots';
-            type: 'i64';
-          },
-          {
-            name: 'pegLimit';
-            type: 'i64';
-          },
-          {
-            name: 'maxBaseLots';
-            type: 'i64';
-          },
-          {
-            name: 'maxQuoteLotsIncludingFees';
-            type: 'i64';
-          },
-          {
-            name: 'clientOrderId';
-            type: 'u64';
-          },
-          {
-            name: 'orderType';
-            type: {
-              defined: 'PlaceOrderType';
-            };
-          },
-          {
-            name: 'expiryTimestamp';
-            type: 'u64';
-          },
-          {
-            name: 'selfTradeBehavior';
-            type: {
-              defined: 'SelfTradeBehavior';
-            };
-          },
-          {
-            name: 'limit';
-            type: 'u8';
-          },
-        ];
-      };
+      name: 'scheduleCancelAll';
+      docs: [
+        'Let anyone cancel all orders of an open orders account once `cancel_after_ts`',
+        'has passed, as its owner or delegate. Pass 0 to unschedule. The schedule is',
+        "kept in the account's position extension.",
+        '',
+        "A dead man's switch for makers: a bot pushes the time out while it's alive,",
+        'a keeper or automation service cancels its orders when it stops doing so.',
+      ];
+      accounts: [
+        {
+          name: 'signer';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'openOrdersAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'positionExtension';
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'cancelAfterTs';
+          type: 'u64';
+        },
+      ];
     },
     {
-      name: 'PlaceTakeOrderArgs';
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'side';
-            type: {
-              defined: 'Side';
-            };
-          },
-          {
-            name: 'priceLots';
-            type: 'i64';
-          },
-          {
-            name: 'maxBaseLots';
-            type: 'i64';
-          },
-          {
-            name: 'maxQuoteLotsIncludingFees';
-            type: 'i64';
-          },
-          {
-            name: 'orderType';
-            type: {
-              defined: 'PlaceOrderType';
-            };
-          },
-          {
-            name: 'limit';
-            type: 'u8';
-          },
-        ];
-      };
-    },
-    {
-      name: 'OracleType';
-      type: {
-        kind: 'enum';
-        variants: [
-          {
-            name: 'Pyth';
-          },
-          {
-            name: 'Stub';
-          },
-          {
-            name: 'SwitchboardV1';
-          },
-          {
-            name: 'SwitchboardV2';
-          },
-          {
-            name: 'RaydiumCLMM';
-          },
-        ];
-      };
+      name: 'executeScheduledCancel';
+      docs: [
+        'Cancel up to `limit` orders of an open orders account whose scheduled',
+        'cancel is due, see `schedule_cancel_all`. Permissionless.',
+        '',
+        'The schedule is cleared once no orders are left. The call cancelling the',
+        "last orders gets the account's heartbeat bounty, see `set_heartbeat_interval`.",
+      ];
+      accounts: [
+        {
+          name: 'keeper';
+          isMut: true;
+          isSigner: true;
+          docs: ['Receives the heartbeat bounty, if any'];
+        },
+        {
+          name: 'openOrdersAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'positionExtension';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'market';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'bids';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'asks';
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'limit';
+          type: 'u8';
+        },
+      ];
     },
     {
-      name: 'OrderState';
-      type: {
-        kind: 'enum';
-        variants: [
-          {
-            name: 'Valid';
-          },
-          {
-            name: 'Invalid';
-          },
-          {
-            name: 'Skipped';
-          },
-        ];
-      };
+      name: 'setHeartbeatInterval';
+      docs: [
+        'Make `heartbeat` calls keep the orders of an open orders account alive, as',
+        'its owner or delegate. Pass an `interval` of 0 to disable it.',
+        '',
+        'Once `interval` seconds pass without a heartbeat, anyone can cancel the',
+        "account's orders with `execute_scheduled_cancel` and earn `bounty` lamports,",
+        "escrowed in the account's position extension by the signer. A previous",
+        'bounty is returned to the owner. Starts the first interval right away.',
+      ];
+      accounts: [
+        {
+          name: 'signer';
+          isMut: true;
+          isSigner: true;
+          docs: ['Pays the bounty'];
+        },
+        {
+          name: 'owner';
+          isMut: true;
+          isSigner: false;
+          docs: ['Gets the previous bounty back'];
+        },
+        {
+          name: 'openOrdersAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'positionExtension';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'interval';
+          type: 'u32';
+        },
+        {
+          name: 'bounty';
+          type: 'u64';
+        },
+      ];
     },
     {
-      name: 'BookSideOrderTree';
-      type: {
-        kind: 'enum';
-        variants: [
-          {
-            name: 'Fixed';
-          },
-          {
-            name: 'OraclePegged';
-          },
-        ];
-      };
+      name: 'heartbeat';
+      docs: [
+        'Push the scheduled cancel of an open orders account out by its heartbeat',
+        'interval, see `set_heartbeat_interval`.',
+      ];
+      accounts: [
+        {
+          name: 'signer';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'openOrdersAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'positionExtension';
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [];
     },
     {
-      name: 'EventType';
-      type: {
-        kind: 'enum';
-        variants: [
-          {
-            name: 'Fill';
-          },
-          {
-            name: 'Out';
-          },
-        ];
-      };
+      name: 'deposit';
+      docs: [
+        "Deposit a certain amount of `base` and `quote` lamports into one's",
+        '[`Position`](crate::state::Position).',
+        '',
+        'Makers might wish to `deposit`, rather than have actual tokens moved for',
+        'each trade, in order to reduce CUs.',
+      ];
+      accounts: [
+        {
+          name: 'owner';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'userBaseAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'userQuoteAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'openOrdersAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'market';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'marketBaseVault';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'marketQuoteVault';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'tokenProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'baseAmount';
+          type: 'u64';
+        },
+        {
+          name: 'quoteAmount';
+          type: 'u64';
+        },
+      ];
     },
     {
-      name: 'NodeTag';
-      type: {
-        kind: 'enum';
-        variants: [
-          {
-            name: 'Uninitialized';
-          },
-          {
-            name: 'InnerNode';
-          },
-          {
-            name: 'LeafNode';
-          },
-          {
-            name: 'FreeNode';
-          },
-          {
-            name: 'LastFreeNode';
-          },
-        ];
-      };
-    },
-    {
-      name: 'PlaceOrderType';
-      type: {
-        kind: 'enum';
-        variants: [
-          {
-            name: 'Limit';
-          },
-          {
-            name: 'ImmediateOrCancel';
-          },
-          {
-            name: 'PostOnly';
-          },
-          {
-            name: 'Market';
-          },
-          {
-            name: 'PostOnlySlide';
-          },
-          {
-            name: 'FillOrKill';
-          },
-        ];
-      };
-    },
-    {
-      name: 'PostOrderType';
-      type: {
-        kind: 'enum';
-        variants: [
-          {
-            name: 'Limit';
-          },
-          {
-            name: 'PostOnly';
-          },
-          {
-            name: 'PostOnlySlide';
-          },
-        ];
-      };
-    },
-    {
-      name: 'SelfTradeBehavior';
-      docs: [
-        'Self trade behavior controls how taker orders interact with resting limit orders of the same account.',
-        'This setting has no influence on placing a resting or oracle pegged limit order that does not match',
-        "immediately, instead it's the responsibility of the user to correctly configure his taker orders.",
-      ];
-      type: {
-        kind: 'enum';
-        variants: [
-          {
-            name: 'DecrementTake';
-          },
-          {
-            name: 'CancelProvide';
-          },
-          {
-            name: 'AbortTransaction';
-          },
-        ];
-      };
-    },
-    {
-      name: 'Side';
-      type: {
-        kind: 'enum';
-        variants: [
-          {
-            name: 'Bid';
-          },
-          {
-            name: 'Ask';
-          },
-        ];
-      };
-    },
-    {
-      name: 'SideAndOrderTree';
+      name: 'refill';
       docs: [
-        "SideAndOrderTree is a storage optimization, so we don't need two bytes for the data",
+        'Refill a certain amount of `base` and `quote` lamports. The amount being passed is the',
+        'total lamports that the [`Position`](crate::state::Position) will have.',
+        '',
+        'Makers might wish to `refill`, rather than have actual tokens moved for',
+        'each trade, in order to reduce CUs.',
       ];
-      type: {
-        kind: 'enum';
-        variants: [
-          {
-            name: 'BidFixed';
-          },
-          {
-            name: 'AskFixed';
-          },
-          {
-            name: 'BidOraclePegged';
-          },
-          {
-            name: 'AskOraclePegged';
-          },
-        ];
-      };
-    },
-    {
-      name: 'OrderParams';
-      type: {
-        kind: 'enum';
-        variants: [
-          {
-            name: 'Market';
-          },
-          {
-            name: 'ImmediateOrCancel';
-            fields: [
-              {
-                name: 'price_lots';
-                type: 'i64';
-              },
-            ];
-          },
-          {
-            name: 'Fixed';
-            fields: [
-              {
-                name: 'price_lots';
-                type: 'i64';
-              },
-              {
-                name: 'order_type';
-                type: {
-                  defined: 'PostOrderType';
-                };
-              },
-            ];
-          },
-          {
-            name: 'OraclePegged';
-            fields: [
-              {
-                name: 'price_offset_lots';
-                type: 'i64';
-              },
-              {
-                name: 'order_type';
-                type: {
-                  defined: 'PostOrderType';
-                };
-              },
-              {
-                name: 'peg_limit';
-                type: 'i64';
-              },
-            ];
-          },
-          {
-            name: 'FillOrKill';
-            fields: [
-              {
-                name: 'price_lots';
-                type: 'i64';
-              },
-            ];
-          },
-        ];
-      };
-    },
-    {
-      name: 'OrderTreeType';
-      type: {
-        kind: 'enum';
-        variants: [
-          {
-            name: 'Bids';
-          },
-          {
-            name: 'Asks';
-          },
-        ];
-      };
-    },
-  ];
-  events: [
-    {
-      name: 'DepositLog';
-      fields: [
+      accounts: [
         {
-          name: 'openOrdersAccount';
-          type: 'publicKey';
-          index: false;
+          name: 'owner';
+          isMut: false;
+          isSigner: true;
         },
         {
-          name: 'signer';
-          type: 'publicKey';
-          index: false;
+          name: 'userBaseAccount';
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: 'baseAmount';
-          type: 'u64';
-          index: false;
+          name: 'userQuoteAccount';
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: 'quoteAmount';
-          type: 'u64';
-          index: false;
+          name: 'openOrdersAccount';
+          isMut: true;
+          isSigner: false;
         },
-      ];
-    },
-    {
-      name: 'FillLog';
-      fields: [
         {
           name: 'market';
-          type: 'publicKey';
-          index: false;
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: 'takerSide';
-          type: 'u8';
-          index: false;
+          name: 'marketBaseVault';
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: 'makerSlot';
-          type: 'u8';
-          index: false;
+          name: 'marketQuoteVault';
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: 'makerOut';
-          type: 'bool';
-          index: false;
+          name: 'tokenProgram';
+          isMut: false;
+          isSigner: false;
         },
+      ];
+      args: [
         {
-          name: 'timestamp';
+          name: 'baseAmount';
           type: 'u64';
-          index: false;
         },
         {
-          name: 'seqNum';
+          name: 'quoteAmount';
           type: 'u64';
-          index: false;
         },
-        {
-          name: 'maker';
-          type: 'publicKey';
-          index: false;
+      ];
+    },
+    {
+      name: 'settleFunds';
+      docs: [
+        'Withdraw any available tokens.',
+        '',
+        "The owner can send them to any token accounts, e.g. a prime broker's or",
+        "another wallet of the same firm, the delegate only to the owner's.",
+        '',
+        'Accounts with a position extension need it among the remaining accounts.',
+        'When it has a settle receiver, the funds are routed into it, see',
+        '[`settle_receiver`](crate::settle_receiver).',
+        '',
+        'Event heap penalties are paid by `penalty_payer`, which can be the relayer',
+        'paying the transaction fee rather than the owner.',
+      ];
+      accounts: [
+        {
+          name: 'owner';
+          isMut: false;
+          isSigner: true;
         },
         {
-          name: 'makerClientOrderId';
-          type: 'u64';
-          index: false;
+          name: 'penaltyPayer';
+          isMut: true;
+          isSigner: true;
         },
         {
-          name: 'makerFee';
-          type: 'u64';
-          index: false;
+          name: 'openOrdersAccount';
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: 'makerTimestamp';
-          type: 'u64';
-          index: false;
+          name: 'market';
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: 'taker';
-          type: 'publicKey';
-          index: false;
+          name: 'marketAuthority';
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: 'takerClientOrderId';
-          type: 'u64';
-          index: false;
+          name: 'marketBaseVault';
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: 'takerFeeCeil';
-          type: 'u64';
-          index: false;
+          name: 'marketQuoteVault';
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: 'price';
-          type: 'i64';
-          index: false;
+          name: 'userBaseAccount';
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: 'quantity';
-          type: 'i64';
-          index: false;
+          name: 'userQuoteAccount';
+          isMut: true;
+          isSigner: false;
         },
-      ];
-    },
-    {
-      name: 'MarketMetaDataLog';
-      fields: [
         {
-          name: 'market';
-          type: 'publicKey';
-          index: false;
+          name: 'referrerAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
         },
         {
-          name: 'name';
-          type: 'string';
-          index: false;
+          name: 'tokenProgram';
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: 'baseMint';
-          type: 'publicKey';
-          index: false;
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
         },
+      ];
+      args: [];
+    },
+    {
+      name: 'claimRebates';
+      docs: [
+        'Withdraw only the maker and referrer rebates of an open orders account.',
+        '',
+        'The owner can send them to any quote token account, the delegate only to the',
+        "owner's. Maker rebates are paid up to the free quote left, `settle_funds` still",
+        "pays out whatever wasn't claimed.",
+      ];
+      accounts: [
         {
-          name: 'quoteMint';
-          type: 'publicKey';
-          index: false;
+          name: 'owner';
+          isMut: false;
+          isSigner: true;
         },
         {
-          name: 'baseDecimals';
-          type: 'u8';
-          index: false;
+          name: 'openOrdersAccount';
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: 'quoteDecimals';
-          type: 'u8';
-          index: false;
+          name: 'market';
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: 'baseLotSize';
-          type: 'i64';
-          index: false;
+          name: 'marketAuthority';
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: 'quoteLotSize';
-          type: 'i64';
-          index: false;
+          name: 'marketQuoteVault';
+          isMut: true;
+          isSigner: false;
         },
-      ];
-    },
-    {
-      name: 'TotalOrderFillEvent';
-      fields: [
         {
-          name: 'side';
-          type: 'u8';
-          index: false;
+          name: 'rebateReceiverAccount';
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: 'taker';
-          type: 'publicKey';
-          index: false;
+          name: 'tokenProgram';
+          isMut: false;
+          isSigner: false;
         },
+      ];
+      args: [];
+    },
+    {
+      name: 'settleFundsExpired';
+      docs: [
+        'Withdraw any available tokens when the market is expired (only',
+        '[`close_market_admin`](crate::state::Market::close_market_admin)).',
+        '',
+        "Tokens go to the owner's accounts, or to any accounts if the owner signs too.",
+      ];
+      accounts: [
         {
-          name: 'totalQuantityPaid';
-          type: 'u64';
-          index: false;
+          name: 'closeMarketAdmin';
+          isMut: false;
+          isSigner: true;
         },
         {
-          name: 'totalQuantityReceived';
-          type: 'u64';
-          index: false;
+          name: 'owner';
+          isMut: false;
+          isSigner: true;
         },
         {
-          name: 'fees';
-          type: 'u64';
-          index: false;
+          name: 'penaltyPayer';
+          isMut: true;
+          isSigner: true;
         },
-      ];
-    },
-    {
-      name: 'SetDelegateLog';
-      fields: [
         {
           name: 'openOrdersAccount';
-          type: 'publicKey';
-          index: false;
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: 'delegate';
-          type: {
-            option: 'publicKey';
-          };
-          index: false;
+          name: 'market';
+          isMut: true;
+          isSigner: false;
         },
-      ];
-    },
-    {
-      name: 'SettleFundsLog';
-      fields: [
         {
-          name: 'openOrdersAccount';
-          type: 'publicKey';
-          index: false;
+          name: 'marketAuthority';
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: 'baseNative';
-          type: 'u64';
-          index: false;
+          name: 'marketBaseVault';
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: 'quoteNative';
-          type: 'u64';
-          index: false;
+          name: 'marketQuoteVault';
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: 'referrerRebate';
-          type: 'u64';
-          index: false;
+          name: 'userBaseAccount';
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: 'referrer';
-          type: {
-            option: 'publicKey';
-          };
-          index: false;
+          name: 'userQuoteAccount';
+          isMut: true;
+          isSigner: false;
         },
-      ];
-    },
-    {
-      name: 'SweepFeesLog';
-      fields: [
         {
-          name: 'market';
-          type: 'publicKey';
-          index: false;
+          name: 'referrerAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
         },
         {
-          name: 'amount';
-          type: 'u64';
-          index: false;
+          name: 'tokenProgram';
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: 'receiver';
-          type: 'publicKey';
-          index: false;
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
         },
       ];
+      args: [];
     },
     {
-      name: 'OpenOrdersPositionLog';
-      fields: [
+      name: 'settleFundsMultiple';
+      docs: [
+        'Withdraw any available tokens from several markets at once.',
+        '',
+        'Every market is described by seven remaining accounts, in order:',
+        '`open_orders_account`, `market`, `market_authority`, `market_base_vault`,',
+        '`market_quote_vault`, `user_base_account` and `user_quote_account`.',
+        "Referrer rebates are not paid out and go to the market's fees instead.",
+      ];
+      accounts: [
         {
           name: 'owner';
-          type: 'publicKey';
-          index: false;
-        },
-        {
-          name: 'openOrdersAccountNum';
-          type: 'u32';
-          index: false;
-        },
-        {
-          name: 'market';
-          type: 'publicKey';
-          index: false;
+          isMut: false;
+          isSigner: true;
         },
         {
-          name: 'bidsBaseLots';
-          type: 'i64';
-          index: false;
+          name: 'penaltyPayer';
+          isMut: true;
+          isSigner: true;
         },
         {
-          name: 'bidsQuoteLots';
-          type: 'i64';
-          index: false;
+          name: 'tokenProgram';
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: 'asksBaseLots';
-          type: 'i64';
-          index: false;
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
         },
+      ];
+      args: [];
+    },
+    {
+      name: 'resizeEventHeap';
+      docs: [
+        'Grow the [`EventHeap`](crate::state::EventHeap) by `additional_nodes`, as a',
+        "[`Market`](crate::state::Market)'s admin.",
+        '',
+        'The new nodes are added to the free list right away. Solana limits how',
+        'much an account can grow per instruction, so large resizes need to be',
+        'split over several instructions.',
+      ];
+      accounts: [
         {
-          name: 'baseFreeNative';
-          type: 'u64';
-          index: false;
+          name: 'collectFeeAdmin';
+          isMut: false;
+          isSigner: true;
         },
         {
-          name: 'quoteFreeNative';
-          type: 'u64';
-          index: false;
+          name: 'payer';
+          isMut: true;
+          isSigner: true;
         },
         {
-          name: 'lockedMakerFees';
-          type: 'u64';
-          index: false;
+          name: 'market';
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: 'referrerRebatesAvailable';
-          type: 'u64';
-          index: false;
+          name: 'eventHeap';
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: 'makerVolume';
-          type: 'u128';
-          index: false;
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
         },
+      ];
+      args: [
         {
-          name: 'takerVolume';
-          type: 'u128';
-          index: false;
+          name: 'additionalNodes';
+          type: 'u16';
         },
       ];
     },
-  ];
-  errors: [
-    {
-      code: 6000;
-      name: 'SomeError';
-      msg: '';
-    },
-    {
-      code: 6001;
-      name: 'InvalidInputNameLength';
-      msg: 'Name lenght above limit';
-    },
-    {
-      code: 6002;
-      name: 'InvalidInputMarketExpired';
-      msg: 'Market cannot be created as expired';
-    },
-    {
-      code: 6003;
-      name: 'InvalidInputMarketFees';
-      msg: 'Taker fees should be positive and if maker fees are negative, greater or equal to their abs value';
-    },
     {
-      code: 6004;
-      name: 'InvalidInputLots';
-      msg: 'Lots cannot be negative';
+      name: 'migrateAccount';
+      docs: [
+        'Upgrade a [`Market`](crate::state::Market) or',
+        '[`OpenOrdersAccount`](crate::state::OpenOrdersAccount) created by an older',
+        'version of the program to the current version.',
+        '',
+        'Layout changes only use reserved space, so accounts keep their size and the',
+        'new fields start zeroed. Version 0 open orders accounts need to cancel their',
+        "bids first, as their `bids_quote_lots` wasn't tracked. Anyone can call it,",
+        "it's a no-op for accounts that are already up to date.",
+      ];
+      accounts: [
+        {
+          name: 'account';
+          isMut: true;
+          isSigner: false;
+          docs: ['instruction.'];
+        },
+      ];
+      args: [];
     },
     {
-      code: 6005;
-      name: 'InvalidInputLotsSize';
-      msg: 'Lots size above market limits';
+      name: 'setConsumeEventsReward';
+      docs: [
+        'Set the lamports paid per consumed event by `consume_events_with_reward`,',
+        "as a [`Market`](crate::state::Market)'s admin.",
+      ];
+      accounts: [
+        {
+          name: 'collectFeeAdmin';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'market';
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'rewardLamports';
+          type: 'u64';
+        },
+      ];
     },
     {
-      code: 6006;
-      name: 'InvalidInputOrdersAmounts';
-      msg: 'Input amounts above limits';
+      name: 'setMaxRestingOrders';
+      docs: [
+        'Cap the resting orders each open orders account can have on a',
+        '[`Market`](crate::state::Market), as its admin, to share the book space',
+        'fairly. 0 removes the cap.',
+        '',
+        "Accounts above a new cap keep their orders but can't post new ones.",
+      ];
+      accounts: [
+        {
+          name: 'collectFeeAdmin';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'market';
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'maxRestingOrders';
+          type: 'u8';
+        },
+      ];
     },
     {
-      code: 6007;
-      name: 'InvalidInputCancelSize';
-      msg: 'Price lots should be greater than zero';
+      name: 'setMinOrderNotional';
+      docs: [
+        'Set the smallest quote native value new orders can rest on the book with, as',
+        "a [`Market`](crate::state::Market)'s admin. 0 removes the minimum.",
+        '',
+        "Fills don't count towards it: the remainder of a partially filled order below",
+        "the minimum isn't posted. Resting orders below a new minimum stay on the book.",
+      ];
+      accounts: [
+        {
+          name: 'collectFeeAdmin';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'market';
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'minOrderNotional';
+          type: 'u64';
+        },
+      ];
     },
     {
-      code: 6008;
-      name: 'InvalidInputPriceLots';
-      msg: 'Expected cancel size should be greater than zero';
+      name: 'setOrderRateLimit';
+      docs: [
+        'Limit how many new orders each open orders account can place per window of',
+        "`window_seconds`, as a [`Market`](crate::state::Market)'s admin.",
+        '',
+        'Protects the book and event heap of permissionless markets from spam. Orders',
+        "placed without an open orders account, with `place_take_order`, aren't limited.",
+        'A `max_orders` of 0 removes the limit.',
+        '',
+        'The limit is tracked in the [`PositionExtension`](crate::state::PositionExtension)',
+        'of each account, accounts need one to place orders while the market is limited.',
+      ];
+      accounts: [
+        {
+          name: 'collectFeeAdmin';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'market';
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'maxOrders';
+          type: 'u32';
+        },
+        {
+          name: 'windowSeconds';
+          type: 'u32';
+        },
+      ];
     },
     {
-      code: 6009;
-      name: 'InvalidInputPegLimit';
-      msg: 'Peg limit should be greater than zero';
+      name: 'setEvictionPolicy';
+      docs: [
+        'Choose which order is booted when a new order is posted to a full book side,',
+        "as a [`Market`](crate::state::Market)'s admin, see",
+        '[`EvictionPolicy`](crate::state::EvictionPolicy). `band_bps` only applies to',
+        '`EvictOldestOutOfBand`.',
+      ];
+      accounts: [
+        {
+          name: 'collectFeeAdmin';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'market';
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'policy';
+          type: {
+            defined: 'EvictionPolicy';
+          };
+        },
+        {
+          name: 'bandBps';
+          type: 'u16';
+        },
+      ];
     },
     {
-      code: 6010;
-      name: 'InvalidInputOrderType';
-      msg: 'The order type is invalid. A taker order must be Market or ImmediateOrCancel';
+      name: 'setFeeRounding';
+      docs: [
+        'Choose whether taker fees round up or down to native quote units, as a',
+        "[`Market`](crate::state::Market)'s admin, see",
+        '[`FeeRounding`](crate::state::FeeRounding).',
+        '',
+        'Rounding up, the default, the taker pays the fractional remainder to the',
+        "market's fees. Rounding down it stays with the taker. Maker fees and rebates",
+        'always round down.',
+      ];
+      accounts: [
+        {
+          name: 'collectFeeAdmin';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'market';
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'takerFeeRounding';
+          type: {
+            defined: 'FeeRounding';
+          };
+        },
+      ];
     },
     {
-      code: 6011;
-      name: 'InvalidInputOrderId';
-      msg: 'Order id cannot be zero';
-    },
-    {
-      code: 6012;
-      name: 'InvalidInputHeapSlots';
-      msg: 'Slot above heap limit';
-    },
-    {
-      code: 6013;
-      name: 'InvalidOracleTypes';
-      msg: 'Cannot combine two oracles of different providers';
-    },
-    {
-      code: 6014;
-      name: 'InvalidSecondOracle';
-      msg: 'Cannot configure secondary oracle without primary';
-    },
-    {
-      code: 6015;
-      name: 'NoCloseMarketAdmin';
-      msg: 'This market does not have a `close_market_admin` and thus cannot be closed.';
-    },
-    {
-      code: 6016;
-      name: 'InvalidCloseMarketAdmin';
-      msg: "The signer of this transaction is not this market's `close_market_admin`.";
-    },
-    {
-      code: 6017;
-      name: 'InvalidOpenOrdersAdmin';
-      msg: 'The `open_orders_admin` required by this market to sign all instructions that creates orders is missing or is not valid';
-    },
-    {
-      code: 6018;
-      name: 'InvalidConsumeEventsAdmin';
-      msg: 'The `consume_events_admin` required by this market to sign all instructions that consume events is missing or is not valid';
-    },
-    {
-      code: 6019;
-      name: 'InvalidMarketVault';
-      msg: 'Provided `market_vault` is invalid';
-    },
-    {
-      code: 6020;
-      name: 'IndexerActiveOO';
-      msg: 'Cannot be closed due to the existence of open orders accounts';
-    },
-    {
-      code: 6021;
-      name: 'OraclePegInvalidOracleState';
-      msg: 'Cannot place a peg order due to invalid oracle state';
-    },
-    {
-      code: 6022;
-      name: 'UnknownOracleType';
-      msg: 'oracle type cannot be determined';
-    },
-    {
-      code: 6023;
-      name: 'OracleConfidence';
-      msg: 'an oracle does not reach the confidence threshold';
-    },
-    {
-      code: 6024;
-      name: 'OracleStale';
-      msg: 'an oracle is stale';
-    },
-    {
-      code: 6025;
-      name: 'OrderIdNotFound';
-      msg: 'Order id not found on the orderbook';
-    },
-    {
-      code: 6026;
-      name: 'EventHeapContainsElements';
-      msg: "Event heap contains elements and market can't be closed";
-    },
-    {
-      code: 6027;
-      name: 'InvalidOrderPostIOC';
-      msg: 'ImmediateOrCancel is not a PostOrderType';
-    },
-    {
-      code: 6028;
-      name: 'InvalidOrderPostMarket';
-      msg: 'Market is not a PostOrderType';
-    },
-    {
-      code: 6029;
-      name: 'WouldSelfTrade';
-      msg: 'would self trade';
-    },
-    {
-      code: 6030;
-      name: 'MarketHasExpired';
-      msg: 'The Market has already expired.';
-    },
-    {
-      code: 6031;
-      name: 'InvalidPriceLots';
-      msg: 'Price lots should be greater than zero';
-    },
-    {
-      code: 6032;
-      name: 'InvalidOraclePrice';
-      msg: 'Oracle price above market limits';
-    },
-    {
-      code: 6033;
-      name: 'MarketHasNotExpired';
-      msg: 'The Market has not expired yet.';
-    },
-    {
-      code: 6034;
-      name: 'NoOwnerOrDelegate';
-      msg: 'No correct owner or delegate.';
-    },
-    {
-      code: 6035;
-      name: 'NoOwner';
-      msg: 'No correct owner';
-    },
-    {
-      code: 6036;
-      name: 'OpenOrdersFull';
-      msg: 'No free order index in open orders account';
-    },
-    {
-      code: 6037;
-      name: 'BookContainsElements';
-      msg: 'Book contains elements';
-    },
-    {
-      code: 6038;
-      name: 'OpenOrdersOrderNotFound';
-      msg: 'Could not find order in user account';
-    },
-    {
-      code: 6039;
-      name: 'InvalidPostAmount';
-      msg: 'Amount to post above book limits';
-    },
-    {
-      code: 6040;
-      name: 'DisabledOraclePeg';
-      msg: 'Oracle peg orders are not enabled for this market';
-    },
-    {
-      code: 6041;
-      name: 'NonEmptyMarket';
-      msg: 'Cannot close a non-empty market';
-    },
-    {
-      code: 6042;
-      name: 'NonEmptyOpenOrdersPosition';
-      msg: 'Cannot close a non-empty open orders account';
-    },
-    {
-      code: 6043;
-      name: 'WouldExecutePartially';
-      msg: 'Fill-Or-Kill order would generate a partial execution';
-    },
-  ];
-};
-
-export const IDL: OpenbookV2 = {
-  version: '0.1.0',
-  name: 'openbook_v2',
-  instructions: [
-    {
-      name: 'createMarket',
+      name: 'createMakerRewards';
       docs: [
-        'Create a [`Market`](crate::state::Market) for a given token pair.',
-      ],
+        'Opt a market into maker rewards paid in `reward_mint`, as a',
+        "[`Market`](crate::state::Market)'s admin. See",
+        '[`MakerRewards`](crate::state::MakerRewards) for how they accrue.',
+        '',
+        'The rewards are paid out of the reward vault created along, which anyone',
+        'can fund with a token transfer. Points only accrue from its creation on.',
+      ];
       accounts: [
         {
-          name: 'market',
-          isMut: true,
-          isSigner: true,
+          name: 'collectFeeAdmin';
+          isMut: false;
+          isSigner: true;
         },
         {
-          name: 'marketAuthority',
-          isMut: false,
-          isSigner: false,
+          name: 'payer';
+          isMut: true;
+          isSigner: true;
         },
         {
-          name: 'bids',
-          isMut: true,
-          isSigner: false,
-          docs: [
-            'Accounts are initialized by client,',
-            'anchor discriminator is set first when ix exits,',
-          ],
+          name: 'market';
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: 'asks',
-          isMut: true,
-          isSigner: false,
+          name: 'makerRewards';
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: 'eventHeap',
-          isMut: true,
-          isSigner: false,
+          name: 'rewardMint';
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: 'payer',
-          isMut: true,
-          isSigner: true,
+          name: 'rewardVault';
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: 'marketBaseVault',
-          isMut: true,
-          isSigner: false,
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: 'marketQuoteVault',
-          isMut: true,
-          isSigner: false,
+          name: 'tokenProgram';
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: 'baseMint',
-          isMut: false,
-          isSigner: false,
+          name: 'associatedTokenProgram';
+          isMut: false;
+          isSigner: false;
         },
+      ];
+      args: [
         {
-          name: 'quoteMint',
-          isMut: false,
-          isSigner: false,
+          name: 'rewardsPerMillionPoints';
+          type: 'u64';
         },
+      ];
+    },
+    {
+      name: 'setMakerRewards';
+      docs: [
+        "Change the reward rate of a market's maker rewards, as its admin.",
+        '',
+        'Unclaimed points are paid at the rate in effect when claimed. Pass a rate of',
+        '0 to pause the payouts, points keep accruing.',
+      ];
+      accounts: [
         {
-          name: 'systemProgram',
-          isMut: false,
-          isSigner: false,
+          name: 'collectFeeAdmin';
+          isMut: false;
+          isSigner: true;
         },
         {
-          name: 'tokenProgram',
-          isMut: false,
-          isSigner: false,
+          name: 'market';
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: 'associatedTokenProgram',
-          isMut: false,
-          isSigner: false,
+          name: 'makerRewards';
+          isMut: true;
+          isSigner: false;
         },
+      ];
+      args: [
         {
-          name: 'oracleA',
-          isMut: false,
-          isSigner: false,
-          isOptional: true,
+          name: 'rewardsPerMillionPoints';
+          type: 'u64';
         },
+      ];
+    },
+    {
+      name: 'claimMakerRewards';
+      docs: [
+        'Transfer the maker rewards of the points accrued by an open orders account,',
+        'as far as the reward vault covers them.',
+      ];
+      accounts: [
         {
-          name: 'oracleB',
-          isMut: false,
-          isSigner: false,
-          isOptional: true,
+          name: 'owner';
+          isMut: false;
+          isSigner: true;
         },
         {
-          name: 'collectFeeAdmin',
-          isMut: false,
-          isSigner: false,
+          name: 'openOrdersAccount';
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: 'openOrdersAdmin',
-          isMut: false,
-          isSigner: false,
-          isOptional: true,
+          name: 'market';
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: 'consumeEventsAdmin',
-          isMut: false,
-          isSigner: false,
-          isOptional: true,
+          name: 'makerRewards';
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: 'closeMarketAdmin',
-          isMut: false,
-          isSigner: false,
-          isOptional: true,
+          name: 'rewardVault';
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: 'eventAuthority',
-          isMut: false,
-          isSigner: false,
+          name: 'userRewardAccount';
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: 'program',
-          isMut: false,
-          isSigner: false,
+          name: 'tokenProgram';
+          isMut: false;
+          isSigner: false;
         },
-      ],
+      ];
+      args: [];
+    },
+    {
+      name: 'createQuoteQuality';
+      docs: [
+        "Start tracking the quotes of a designated market maker's open orders account,",
+        "as a [`Market`](crate::state::Market)'s admin. See",
+        '[`QuoteQuality`](crate::state::QuoteQuality).',
+        '',
+        'The account quotes while it has `min_size_lots` on each side within',
+        '`max_oracle_distance_bps` of the oracle. Requires a market with an oracle.',
+      ];
+      accounts: [
+        {
+          name: 'collectFeeAdmin';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'payer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'market';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'openOrdersAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'quoteQuality';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
       args: [
         {
-          name: 'name',
-          type: 'string',
+          name: 'maxOracleDistanceBps';
+          type: 'u16';
         },
         {
-          name: 'oracleConfig',
-          type: {
-            defined: 'OracleConfigParams',
+          name: 'minSizeLots';
+          type: 'i64';
+        },
+        {
+          name: 'epochDuration';
+          type: 'u64';
+        },
+      ];
+    },
+    {
+      name: 'sampleQuoteQuality';
+      docs: [
+        'Record whether a designated market maker is quoting, crediting it the time',
+        'since the previous sample. Anyone can sample.',
+      ];
+      accounts: [
+        {
+          name: 'quoteQuality';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'market';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'bids';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'asks';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'oracleA';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'oracleB';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'createDmmRegistry';
+      docs: [
+        "Create the [`DmmRegistry`](crate::state::DmmRegistry) of a market's",
+        'designated market makers, as its collect fee admin.',
+      ];
+      accounts: [
+        {
+          name: 'collectFeeAdmin';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'payer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'market';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'dmmRegistry';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'setDmmSlot';
+      docs: [
+        'Make an open orders account a designated market maker of `priority`,',
+        'committed to quote `min_uptime_bps` of the time, or remove it with a zero',
+        'priority. Only the collect fee admin can call this.',
+      ];
+      accounts: [
+        {
+          name: 'collectFeeAdmin';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'market';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'dmmRegistry';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'openOrdersAccount';
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'priority';
+          type: 'u8';
+        },
+        {
+          name: 'minUptimeBps';
+          type: 'u16';
+        },
+      ];
+    },
+    {
+      name: 'enforceDmmObligation';
+      docs: [
+        'Remove a designated market maker whose last complete',
+        '[`QuoteQuality`](crate::state::QuoteQuality) epoch fell short of its',
+        '`min_uptime_bps`. Anyone can call this.',
+      ];
+      accounts: [
+        {
+          name: 'market';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'dmmRegistry';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'openOrdersAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'quoteQuality';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'createTakerRebateCampaign';
+      docs: [
+        'Create a [`TakerRebateCampaign`](crate::state::TakerRebateCampaign) rebating',
+        '`rebate_bps` of the taker fees paid between `start_timestamp` and `end_timestamp`,',
+        "as a [`Market`](crate::state::Market)'s admin.",
+        '',
+        'Takers accrue rebates when they pass the campaign among the remaining accounts',
+        'of their orders, up to the budget funded with `fund_taker_rebate_campaign`.',
+      ];
+      accounts: [
+        {
+          name: 'collectFeeAdmin';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'payer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'market';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'takerRebateCampaign';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'quoteMint';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'rebateVault';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'tokenProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'associatedTokenProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'startTimestamp';
+          type: 'u64';
+        },
+        {
+          name: 'endTimestamp';
+          type: 'u64';
+        },
+        {
+          name: 'rebateBps';
+          type: 'u16';
+        },
+      ];
+    },
+    {
+      name: 'setTakerRebateCampaign';
+      docs: [
+        "Change the window and rebate of a taker rebate campaign, as the market's admin.",
+        '',
+        'Accrued rebates can still be claimed, this can also start a new campaign once',
+        'the previous one ended.',
+      ];
+      accounts: [
+        {
+          name: 'collectFeeAdmin';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'market';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'takerRebateCampaign';
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'startTimestamp';
+          type: 'u64';
+        },
+        {
+          name: 'endTimestamp';
+          type: 'u64';
+        },
+        {
+          name: 'rebateBps';
+          type: 'u16';
+        },
+      ];
+    },
+    {
+      name: 'fundTakerRebateCampaign';
+      docs: [
+        "Add `amount` quote to the budget of a taker rebate campaign, as the market's admin.",
+      ];
+      accounts: [
+        {
+          name: 'collectFeeAdmin';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'market';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'takerRebateCampaign';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'rebateVault';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'adminQuoteAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'tokenProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'amount';
+          type: 'u64';
+        },
+      ];
+    },
+    {
+      name: 'withdrawTakerRebateBudget';
+      docs: [
+        "Withdraw the budget of a taker rebate campaign that wasn't accrued, as the",
+        "market's admin. Not possible while the campaign is running.",
+      ];
+      accounts: [
+        {
+          name: 'collectFeeAdmin';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'market';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'takerRebateCampaign';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'rebateVault';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'tokenReceiverAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'tokenProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'claimTakerRebates';
+      docs: ['Transfer the taker rebates accrued by an open orders account.'];
+      accounts: [
+        {
+          name: 'owner';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'openOrdersAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'market';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'takerRebateCampaign';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'rebateVault';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'userQuoteAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'tokenProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'createFillHistory';
+      docs: [
+        'Create the [`FillHistory`](crate::state::FillHistory) of a market.',
+        '',
+        'Anyone can pay for it. Once created, pass it among the remaining accounts',
+        'of order placing instructions to record their fills.',
+      ];
+      accounts: [
+        {
+          name: 'payer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'market';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'fillHistory';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'createMarketStats';
+      docs: [
+        'Create the [`MarketStats`](crate::state::MarketStats) of a market.',
+        '',
+        'Anyone can pay for it. Once created, pass it among the remaining accounts',
+        'of order placing instructions to record their fills.',
+      ];
+      accounts: [
+        {
+          name: 'payer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'market';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'marketStats';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'createDepthSnapshot';
+      docs: [
+        'Create a [`DepthSnapshot`](crate::state::DepthSnapshot) of a market for `owner`,',
+        'to be filled by `snapshot_depth`.',
+      ];
+      accounts: [
+        {
+          name: 'payer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'owner';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'market';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'depthSnapshot';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'setSuccessorMarket';
+      docs: [
+        'Point a [`Market`](crate::state::Market) to the market of the same token pair',
+        'replacing it, as its admin. Omit the successor market to remove the link.',
+        '',
+        "The link is stored in the market's [`MarketMetadata`](crate::state::MarketMetadata),",
+        'see `create_market_metadata`.',
+        '',
+        "Lot sizes can't change, so a market whose prices moved too far is migrated by",
+        'creating a successor with new lot sizes, linking it here for clients to redirect',
+        "their users, and usually switching this market to cancel only. See the client's",
+        '`migration` module to carry the resting orders over.',
+      ];
+      accounts: [
+        {
+          name: 'collectFeeAdmin';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'market';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'marketMetadata';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'successorMarket';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+          docs: ['None to unlink the market from its successor'];
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'createFillCallback';
+      docs: [
+        'Register a program to be called with the fills processed by',
+        "`consume_events_with_fill_callback`, as a [`Market`](crate::state::Market)'s admin.",
+        '',
+        'See [`fill_callback`](crate::fill_callback) for the interface. The program is',
+        "kept in the market's [`FillCallback`](crate::state::FillCallback) account.",
+      ];
+      accounts: [
+        {
+          name: 'payer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'collectFeeAdmin';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'market';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'fillCallback';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'program';
+          type: 'publicKey';
+        },
+      ];
+    },
+    {
+      name: 'setFillCallback';
+      docs: [
+        "Change the program registered with `create_fill_callback`, as the market's",
+        'admin. Pass `None` to remove it.',
+      ];
+      accounts: [
+        {
+          name: 'collectFeeAdmin';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'market';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'fillCallback';
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'program';
+          type: {
+            option: 'publicKey';
+          };
+        },
+      ];
+    },
+    {
+      name: 'setMarketAuthority';
+      docs: [
+        "Offer one of a [`Market`](crate::state::Market)'s admin roles to a new key,",
+        'as the current holder of that role.',
+        '',
+        'The new admin takes over by calling `accept_market_authority`. The offer is',
+        'kept in a [`PendingMarketAdmin`](crate::state::PendingMarketAdmin) account,',
+        'so only one transfer can be pending at a time.',
+      ];
+      accounts: [
+        {
+          name: 'payer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'admin';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'market';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'pendingMarketAdmin';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'role';
+          type: {
+            defined: 'MarketAdmin';
+          };
+        },
+        {
+          name: 'newAdmin';
+          type: 'publicKey';
+        },
+      ];
+    },
+    {
+      name: 'acceptMarketAuthority';
+      docs: ['Take over the admin role offered by `set_market_authority`.'];
+      accounts: [
+        {
+          name: 'pendingAdmin';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'market';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'pendingMarketAdmin';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'payer';
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'cancelMarketAuthority';
+      docs: [
+        'Withdraw the admin role offered by `set_market_authority`, as the current',
+        'holder of that role.',
+      ];
+      accounts: [
+        {
+          name: 'admin';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'market';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'pendingMarketAdmin';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'payer';
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'createMarketMetadata';
+      docs: [
+        'Create the [`MarketMetadata`](crate::state::MarketMetadata) of a market,',
+        "as a [`Market`](crate::state::Market)'s admin.",
+      ];
+      accounts: [
+        {
+          name: 'collectFeeAdmin';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'payer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'market';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'marketMetadata';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'args';
+          type: {
+            defined: 'MarketMetadataArgs';
+          };
+        },
+      ];
+    },
+    {
+      name: 'setMarketMetadata';
+      docs: [
+        'Update the [`MarketMetadata`](crate::state::MarketMetadata) of a market,',
+        "as a [`Market`](crate::state::Market)'s admin.",
+      ];
+      accounts: [
+        {
+          name: 'collectFeeAdmin';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'market';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'marketMetadata';
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'args';
+          type: {
+            defined: 'MarketMetadataArgs';
+          };
+        },
+      ];
+    },
+    {
+      name: 'setBinaryOutcome';
+      docs: [
+        'Turn a market into a binary outcome market, or post its resolution once',
+        "expired, as a [`Market`](crate::state::Market)'s admin.",
+        '',
+        'Resolving settles the market at a price of 0 or 1, see [`BinaryOutcome`].',
+        'Resting orders are then cancelled through `settle_expired_market`.',
+      ];
+      accounts: [
+        {
+          name: 'collectFeeAdmin';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'market';
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'outcome';
+          type: {
+            defined: 'BinaryOutcome';
+          };
+        },
+      ];
+    },
+    {
+      name: 'setMarketState';
+      docs: [
+        "Restrict what users can do on a market, as a [`Market`](crate::state::Market)'s admin.",
+        '',
+        'See [`MarketState`] for what each state allows.',
+      ];
+      accounts: [
+        {
+          name: 'collectFeeAdmin';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'market';
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'state';
+          type: {
+            defined: 'MarketState';
+          };
+        },
+      ];
+    },
+    {
+      name: 'settleExpiredMarket';
+      docs: [
+        'Record the settlement price of an expired market and switch it to cancel',
+        'only, as its close market admin.',
+        '',
+        'Markets with an oracle settle at the oracle price and take no',
+        '`settlement_price_lots`, markets without one settle at the given price.',
+        '',
+        'Cancels the resting orders of the open orders accounts passed as remaining',
+        'accounts. Can be called again with other accounts until the book is empty.',
+      ];
+      accounts: [
+        {
+          name: 'closeMarketAdmin';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'market';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'bids';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'asks';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'oracleA';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'oracleB';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+      ];
+      args: [
+        {
+          name: 'limit';
+          type: 'u8';
+        },
+        {
+          name: 'settlementPriceLots';
+          type: {
+            option: 'i64';
+          };
+        },
+      ];
+    },
+    {
+      name: 'sweepQuoteDust';
+      docs: [
+        'Move the quote tokens of the vault that no account is owed, like rounding',
+        'remainders of fills, to the fees available to `sweep_fees`, so the vault',
+        "balance reconciles exactly with the market's deposits. Anyone can call it.",
+      ];
+      accounts: [
+        {
+          name: 'market';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'marketQuoteVault';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'sweepFees';
+      docs: ["Sweep fees, as a [`Market`](crate::state::Market)'s admin."];
+      accounts: [
+        {
+          name: 'collectFeeAdmin';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'market';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'marketAuthority';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'marketQuoteVault';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'tokenReceiverAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'tokenProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'verifyMarketInvariants';
+      docs: [
+        "Compare a market's vault balances and deposit totals with what the",
+        '[`OpenOrdersAccount`](crate::state::OpenOrdersAccount)s passed as remaining',
+        'accounts are owed, pending fills included. Anyone can call it.',
+        '',
+        "Doesn't fail on a discrepancy: it's logged, along with the totals in a",
+        '[`MarketInvariantsLog`](crate::logs::MarketInvariantsLog). With all the open',
+        'orders accounts of the market passed, nothing is left unaccounted.',
+      ];
+      accounts: [
+        {
+          name: 'market';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'eventHeap';
+          isMut: true;
+          isSigner: false;
+          docs: [
+            'Only read, writable for loading the nodes added by `resize_event_heap`',
+          ];
+        },
+        {
+          name: 'marketBaseVault';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'marketQuoteVault';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'setDelegate';
+      docs: [
+        'Update the [`delegate`](crate::state::OpenOrdersAccount::delegate) of an open orders account.',
+      ];
+      accounts: [
+        {
+          name: 'owner';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'openOrdersAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'delegateAccount';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'setDirectSettlement';
+      docs: [
+        'Let takers pay the fill proceeds of an open orders account straight to its',
+        "owner's token accounts passed along with it, see `place_order`. Off by default.",
+      ];
+      accounts: [
+        {
+          name: 'owner';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'openOrdersAccount';
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'enabled';
+          type: 'bool';
+        },
+      ];
+    },
+    {
+      name: 'setRiskProgram';
+      docs: [
+        'Register a program approving the new orders of an open orders account,',
+        "as its owner. It's kept in the account's position extension, which has to",
+        'be created first.',
+        '',
+        'See [`health_check`](crate::health_check) for the interface. Pass `None`',
+        'to remove it.',
+      ];
+      accounts: [
+        {
+          name: 'owner';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'openOrdersAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'positionExtension';
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'program';
+          type: {
+            option: 'publicKey';
+          };
+        },
+      ];
+    },
+    {
+      name: 'setSettleReceiver';
+      docs: [
+        'Register a program `settle_funds` routes the withdrawn tokens of an open',
+        "orders account into, as its owner. Like the risk program, it's kept in the",
+        "account's position extension.",
+        '',
+        'See [`settle_receiver`](crate::settle_receiver) for the interface. Pass',
+        '`None` to remove it.',
+      ];
+      accounts: [
+        {
+          name: 'owner';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'openOrdersAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'positionExtension';
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'program';
+          type: {
+            option: 'publicKey';
+          };
+        },
+      ];
+    },
+    {
+      name: 'setMarketExpired';
+      docs: [
+        'Set market to expired before pruning orders and closing the market (only',
+        '[`close_market_admin`](crate::state::Market::close_market_admin)).',
+      ];
+      accounts: [
+        {
+          name: 'closeMarketAdmin';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'market';
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'forceCancelOrders';
+      docs: [
+        "Cancel the orders of an open orders account, as the market's",
+        '[`close_market_admin`](crate::state::Market::close_market_admin), whatever',
+        "the market's state.",
+        '',
+        'For compliance actions on permissioned markets and to clear the book of broken',
+        'accounts before `close_market`. Emits an `OutLog` per cancelled order, the',
+        'account keeps the released funds and can settle them as usual.',
+      ];
+      accounts: [
+        {
+          name: 'closeMarketAdmin';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'openOrdersAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'market';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'bids';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'asks';
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'limit';
+          type: 'u8';
+        },
+      ];
+    },
+    {
+      name: 'pruneOrders';
+      docs: [
+        'Remove orders from the book when the market is expired (only',
+        '[`close_market_admin`](crate::state::Market::close_market_admin)).',
+      ];
+      accounts: [
+        {
+          name: 'closeMarketAdmin';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'openOrdersAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'market';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'bids';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'asks';
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'limit';
+          type: 'u8';
+        },
+      ];
+    },
+    {
+      name: 'stubOracleCreate';
+      accounts: [
+        {
+          name: 'payer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'owner';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'oracle';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'mint';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'price';
+          type: 'f64';
+        },
+      ];
+    },
+    {
+      name: 'stubOracleClose';
+      accounts: [
+        {
+          name: 'owner';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'oracle';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'solDestination';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'tokenProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'stubOracleSet';
+      accounts: [
+        {
+          name: 'owner';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'oracle';
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'price';
+          type: 'f64';
+        },
+      ];
+    },
+    {
+      name: 'stubOracleSetTest';
+      docs: [
+        'Set every field of a stub oracle, to test stale oracles, low confidence',
+        'and prices with an exponent.',
+        '',
+        '`price` and `deviation` are multiplied by 10^`exponent`. A',
+        '`last_update_slot` of 0 means the oracle is never stale.',
+      ];
+      accounts: [
+        {
+          name: 'owner';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'oracle';
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'price';
+          type: 'f64';
+        },
+        {
+          name: 'exponent';
+          type: 'i8';
+        },
+        {
+          name: 'deviation';
+          type: 'f64';
+        },
+        {
+          name: 'lastUpdateTs';
+          type: 'i64';
+        },
+        {
+          name: 'lastUpdateSlot';
+          type: 'u64';
+        },
+      ];
+    },
+  ];
+  accounts: [
+    {
+      name: 'depthSnapshot';
+      docs: [
+        "Aggregated top levels of a market's book, written by `snapshot_depth`, so other",
+        'programs can reason about the available liquidity without walking the book.',
+        '',
+        'Each owner has its own snapshot per market, anyone can refresh it. Check `slot`',
+        'before trusting it: a snapshot is only as fresh as its last refresh.',
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'market';
+            type: 'publicKey';
+          },
+          {
+            name: 'owner';
+            type: 'publicKey';
+          },
+          {
+            name: 'slot';
+            docs: ['Slot of the last refresh, 0 if never refreshed'];
+            type: 'u64';
+          },
+          {
+            name: 'timestamp';
+            type: 'u64';
+          },
+          {
+            name: 'bidsLen';
+            docs: ['Number of levels in use in `bids` and `asks`'];
+            type: 'u8';
+          },
+          {
+            name: 'asksLen';
+            type: 'u8';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          },
+          {
+            name: 'padding';
+            type: {
+              array: ['u8', 5];
+            };
+          },
+          {
+            name: 'reserved';
+            type: {
+              array: ['u8', 64];
+            };
+          },
+          {
+            name: 'bids';
+            type: {
+              array: [
+                {
+                  defined: 'DepthLevel';
+                },
+                32,
+              ];
+            };
+          },
+          {
+            name: 'asks';
+            type: {
+              array: [
+                {
+                  defined: 'DepthLevel';
+                },
+                32,
+              ];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'dmmRegistry';
+      docs: [
+        'Per-market PDA of the designated market makers, managed by the collect fee',
+        'admin with `set_dmm_slot`.',
+        '',
+        "The slot's priority is copied to the open orders account, see",
+        "`OpenOrdersAccount::dmm_priority`, and from there to the account's orders. Their",
+        "orders aren't booted from a full book side by orders of a lower priority, and",
+        'the accounts are exempt from the order rate limit and the event heap penalty.',
+        'They pay no maker fees, and as takers only pay the rebates of the makers they',
+        'fill.',
+        'In return they commit to quote a `min_uptime_bps` share of the time, measured',
+        'by their [`QuoteQuality`](super::QuoteQuality); anyone can remove an account',
+        'falling short with `enforce_dmm_obligation`.',
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'market';
+            type: 'publicKey';
+          },
+          {
+            name: 'slots';
+            type: {
+              array: [
+                {
+                  defined: 'DmmSlot';
+                },
+                8,
+              ];
+            };
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          },
+          {
+            name: 'padding';
+            type: {
+              array: ['u8', 7];
+            };
+          },
+          {
+            name: 'reserved';
+            type: {
+              array: ['u8', 64];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'fillCallback';
+      docs: [
+        "Program a [`Market`](crate::state::Market)'s admin registered to be called with",
+        'the fills processed by `consume_events_with_fill_callback`, see',
+        '[`fill_callback`](crate::fill_callback).',
+        '',
+        'PDA of seeds `[b"FillCallback", market]`.',
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'bump';
+            type: 'u8';
+          },
+          {
+            name: 'market';
+            type: 'publicKey';
+          },
+          {
+            name: 'program';
+            type: {
+              option: 'publicKey';
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'fillHistory';
+      docs: [
+        'Optional per-market PDA keeping the last [`FILL_HISTORY_LEN`] fills, so',
+        'clients and other programs can read recent trades without an indexer.',
+        '',
+        'Fills are recorded while matching when the account is passed among the',
+        'remaining accounts of the order placing instruction.',
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'market';
+            type: 'publicKey';
+          },
+          {
+            name: 'count';
+            docs: [
+              'Total number of fills recorded, the latest one is at `(count - 1) % FILL_HISTORY_LEN`',
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          },
+          {
+            name: 'padding';
+            type: {
+              array: ['u8', 7];
+            };
+          },
+          {
+            name: 'reserved';
+            type: {
+              array: ['u8', 64];
+            };
+          },
+          {
+            name: 'fills';
+            type: {
+              array: [
+                {
+                  defined: 'FillRecord';
+                },
+                64,
+              ];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'makerRewards';
+      docs: [
+        'Opt-in liquidity mining for the makers of a market.',
+        '',
+        'Makers earn points, base lots times seconds at the top of the book, when their',
+        'orders are filled or cancelled. An order is at the top while its fixed price is',
+        'the best of its side, see [`BookSide::seconds_at_top`](super::BookSide::seconds_at_top).',
+        "The points are accrued to the maker's own open orders account, so nobody",
+        'else decides who earns them.',
+        '',
+        '`claim_maker_rewards` pays out the points at `rewards_per_million_points` from',
+        "`reward_vault`, which anyone can fund. Points the vault can't cover yet are",
+        'kept for a later claim.',
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'market';
+            type: 'publicKey';
+          },
+          {
+            name: 'rewardMint';
+            type: 'publicKey';
+          },
+          {
+            name: 'rewardVault';
+            docs: ['Token account of `reward_mint` owned by this account'];
+            type: 'publicKey';
+          },
+          {
+            name: 'rewardsPerMillionPoints';
+            docs: ['Reward native paid per million points'];
+            type: 'u64';
+          },
+          {
+            name: 'rewardsClaimedTotal';
+            docs: ['Rewards claimed since creation'];
+            type: 'u64';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          },
+          {
+            name: 'padding';
+            type: {
+              array: ['u8', 7];
+            };
+          },
+          {
+            name: 'reserved';
+            type: {
+              array: ['u8', 64];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'marketMetadata';
+      docs: [
+        'Optional per-market PDA with human readable labels, maintained by the',
+        "market's `collect_fee_admin`.",
+        '',
+        'Strings are stored as fixed size arrays; trailing zero bytes are ignored.',
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'market';
+            type: 'publicKey';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          },
+          {
+            name: 'padding';
+            type: {
+              array: ['u8', 7];
+            };
+          },
+          {
+            name: 'name';
+            type: {
+              array: ['u8', 64];
+            };
+          },
+          {
+            name: 'baseSymbol';
+            type: {
+              array: ['u8', 16];
+            };
+          },
+          {
+            name: 'quoteSymbol';
+            type: {
+              array: ['u8', 16];
+            };
+          },
+          {
+            name: 'uri';
+            docs: ['Link to further off-chain information about the market.'];
+            type: {
+              array: ['u8', 200];
+            };
+          },
+          {
+            name: 'successorMarket';
+            docs: [
+              'Market of the same token pair replacing this one, e.g. with lot sizes fitting',
+              'the current prices better, so clients can redirect their users to it.',
+              'See `set_successor_market`.',
+            ];
+            type: {
+              defined: 'NonZeroPubkeyOption';
+            };
+          },
+          {
+            name: 'reserved';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'marketRegistryPage';
+      docs: [
+        'Page of the global market registry, a PDA of `["MarketRegistry", page_index]`',
+        '(little endian `u32`).',
+        '',
+        'Pages are created in order and only once the previous one is full, so',
+        'clients can list every registered market by reading pages from 0 until',
+        'one is missing or not full.',
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'pageIndex';
+            type: 'u32';
+          },
+          {
+            name: 'count';
+            type: 'u32';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          },
+          {
+            name: 'padding';
+            type: {
+              array: ['u8', 7];
+            };
+          },
+          {
+            name: 'reserved';
+            type: {
+              array: ['u8', 64];
+            };
+          },
+          {
+            name: 'entries';
+            type: {
+              array: [
+                {
+                  defined: 'MarketRegistryEntry';
+                },
+                64,
+              ];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'marketStats';
+      docs: [
+        'Optional per-market PDA keeping the fills of the last [`MARKET_STATS_HOURS`] hours',
+        'in hourly buckets, so markets can be compared from on-chain data alone.',
+        '',
+        'Fills are recorded while matching when the account is passed among the',
+        'remaining accounts of the order placing instruction. Buckets are only rotated',
+        'by fills: a bucket of an hour without fills keeps the fills of an older hour,',
+        'which [`MarketStats::last_24h`] skips.',
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'market';
+            type: 'publicKey';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          },
+          {
+            name: 'padding';
+            type: {
+              array: ['u8', 7];
+            };
+          },
+          {
+            name: 'reserved';
+            type: {
+              array: ['u8', 64];
+            };
+          },
+          {
+            name: 'hours';
+            type: {
+              array: [
+                {
+                  defined: 'HourlyStats';
+                },
+                24,
+              ];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'market';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'bump';
+            docs: ['PDA bump'];
+            type: 'u8';
+          },
+          {
+            name: 'baseDecimals';
+            docs: [
+              'Number of decimals used for the base token.',
+              '',
+              "Used to convert the oracle's price into a native/native price.",
+            ];
+            type: 'u8';
+          },
+          {
+            name: 'quoteDecimals';
+            type: 'u8';
+          },
+          {
+            name: 'marketState';
+            docs: ['See [`MarketState`].'];
+            type: 'u8';
+          },
+          {
+            name: 'binaryOutcome';
+            docs: ['See [`BinaryOutcome`].'];
+            type: 'u8';
+          },
+          {
+            name: 'registered';
+            docs: [
+              '1 once the market was added to the market registry, see `register_market`.',
+            ];
+            type: 'u8';
+          },
+          {
+            name: 'padding1';
+            type: {
+              array: ['u8', 2];
+            };
+          },
+          {
+            name: 'marketAuthority';
+            type: 'publicKey';
+          },
+          {
+            name: 'timeExpiry';
+            docs: [
+              'No expiry = 0. Market will expire and no trading allowed after time_expiry',
+            ];
+            type: 'i64';
+          },
+          {
+            name: 'collectFeeAdmin';
+            docs: ['Admin who can collect fees from the market'];
+            type: 'publicKey';
+          },
+          {
+            name: 'openOrdersAdmin';
+            docs: ['Admin who must sign off on all order creations'];
+            type: {
+              defined: 'NonZeroPubkeyOption';
+            };
+          },
+          {
+            name: 'consumeEventsAdmin';
+            docs: ['Admin who must sign off on all event consumptions'];
+            type: {
+              defined: 'NonZeroPubkeyOption';
+            };
+          },
+          {
+            name: 'closeMarketAdmin';
+            docs: [
+              'Admin who can set market expired, prune orders and close the market',
+            ];
+            type: {
+              defined: 'NonZeroPubkeyOption';
+            };
+          },
+          {
+            name: 'name';
+            docs: ['Name. Trailing zero bytes are ignored.'];
+            type: {
+              array: ['u8', 16];
+            };
+          },
+          {
+            name: 'bids';
+            docs: ['Address of the BookSide account for bids'];
+            type: 'publicKey';
+          },
+          {
+            name: 'asks';
+            docs: ['Address of the BookSide account for asks'];
+            type: 'publicKey';
+          },
+          {
+            name: 'eventHeap';
+            docs: ['Address of the EventHeap account'];
+            type: 'publicKey';
+          },
+          {
+            name: 'oracleA';
+            docs: ['Oracles account address'];
+            type: {
+              defined: 'NonZeroPubkeyOption';
+            };
+          },
+          {
+            name: 'oracleB';
+            type: {
+              defined: 'NonZeroPubkeyOption';
+            };
+          },
+          {
+            name: 'oracleConfig';
+            docs: ['Oracle configuration'];
+            type: {
+              defined: 'OracleConfig';
+            };
+          },
+          {
+            name: 'quoteLotSize';
+            docs: [
+              'Number of quote native in a quote lot. Must be a power of 10.',
+              '',
+              'Primarily useful for increasing the tick size on the market: A lot price',
+              'of 1 becomes a native price of quote_lot_size/base_lot_size becomes a',
+              'ui price of quote_lot_size*base_decimals/base_lot_size/quote_decimals.',
+            ];
+            type: 'i64';
+          },
+          {
+            name: 'baseLotSize';
+            docs: [
+              'Number of base native in a base lot. Must be a power of 10.',
+              '',
+              'Example: If base decimals for the underlying asset is 6, base lot size',
+              'is 100 and and base position lots is 10_000 then base position native is',
+              '1_000_000 and base position ui is 1.',
+            ];
+            type: 'i64';
+          },
+          {
+            name: 'seqNum';
+            docs: ['Total number of orders seen'];
+            type: 'u64';
+          },
+          {
+            name: 'registrationTime';
+            docs: ['Timestamp in seconds that the market was registered at.'];
+            type: 'i64';
+          },
+          {
+            name: 'makerFee';
+            docs: [
+              'Fees',
+              '',
+              'Fee (in 10^-6) when matching maker orders.',
+              'maker_fee < 0 it means some of the taker_fees goes to the maker',
+              'maker_fee > 0, it means no taker_fee to the maker, and maker fee goes to the referral',
+            ];
+            type: 'i64';
+          },
+          {
+            name: 'takerFee';
+            docs: ['Fee (in 10^-6) for taker orders, always >= 0.'];
+            type: 'i64';
+          },
+          {
+            name: 'feesAccrued';
+            docs: ['Total fees accrued in native quote'];
+            type: 'u128';
+          },
+          {
+            name: 'feesToReferrers';
+            docs: ['Total fees settled in native quote'];
+            type: 'u128';
+          },
+          {
+            name: 'referrerRebatesAccrued';
+            docs: ['Referrer rebates to be distributed'];
+            type: 'u64';
+          },
+          {
+            name: 'feesAvailable';
+            docs: ['Fees generated and available to withdraw via sweep_fees'];
+            type: 'u64';
+          },
+          {
+            name: 'makerVolume';
+            docs: [
+              'Cumulative maker volume (same as taker volume) in quote native units',
+            ];
+            type: 'u128';
+          },
+          {
+            name: 'takerVolumeWoOo';
+            docs: [
+              'Cumulative taker volume in quote native units due to place take orders',
+            ];
+            type: 'u128';
+          },
+          {
+            name: 'baseMint';
+            type: 'publicKey';
+          },
+          {
+            name: 'quoteMint';
+            type: 'publicKey';
+          },
+          {
+            name: 'marketBaseVault';
+            type: 'publicKey';
+          },
+          {
+            name: 'baseDepositTotal';
+            type: 'u64';
+          },
+          {
+            name: 'marketQuoteVault';
+            type: 'publicKey';
+          },
+          {
+            name: 'quoteDepositTotal';
+            type: 'u64';
+          },
+          {
+            name: 'consumeEventsReward';
+            docs: [
+              'Lamports paid per consumed event to whoever calls `consume_events_with_reward`.',
+              '',
+              'Funded out of the event heap penalties collected by the market account.',
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'eventHeapOldestTimestamp';
+            docs: [
+              "Timestamp of the oldest event waiting on the event heap, 0 when it's empty.",
+              '',
+              'Like the other event heap stats, refreshed by every instruction that',
+              'adds or consumes events.',
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'eventHeapCount';
+            docs: ['Number of events waiting on the event heap.'];
+            type: 'u16';
+          },
+          {
+            name: 'eventHeapCapacity';
+            docs: [
+              'Number of events the event heap can hold, including resized nodes.',
+            ];
+            type: 'u16';
+          },
+          {
+            name: 'padding2';
+            type: {
+              array: ['u8', 4];
+            };
+          },
+          {
+            name: 'eventSeqNum';
+            docs: [
+              'Total number of fill and out events generated, processed directly or not.',
+              '',
+              'Each event carries its own number so consumers can detect missed events.',
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'settlementPriceLots';
+            docs: [
+              'Price in lots the market settled at with `settle_expired_market`, 0 until then.',
+            ];
+            type: 'i64';
+          },
+          {
+            name: 'creationBond';
+            docs: [
+              'Lamports escrowed in the market account at creation, on top of its rent.',
+              '',
+              'Returned together with the rent by `close_market`, unless governance slashed',
+              'it with `slash_market_bond` for spam or malicious markets.',
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'makerRewardsStartTs';
+            docs: [
+              "Time the market opted into maker rewards, 0 when it hasn't. See",
+              '[`MakerRewards`](crate::state::MakerRewards).',
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'asksBaseLotsTotal';
+            docs: [
+              'Base lots in the resting asks of all open orders accounts.',
+              '',
+              'This and the following totals sum the positions of all open orders accounts,',
+              'they are only exact for markets created after they were introduced.',
+            ];
+            type: 'i64';
+          },
+          {
+            name: 'bidsQuoteLotsTotal';
+            docs: [
+              'Quote lots locked in the resting bids of all open orders accounts',
+            ];
+            type: 'i64';
+          },
+          {
+            name: 'baseFreeNativeTotal';
+            docs: ['Base native unsettled in all open orders accounts'];
+            type: 'i64';
+          },
+          {
+            name: 'quoteFreeNativeTotal';
+            docs: ['Quote native unsettled in all open orders accounts'];
+            type: 'i64';
+          },
+          {
+            name: 'orderRateLimit';
+            docs: [
+              'Max new orders an open orders account can place per `order_rate_limit_window`,',
+              '0 when unlimited.',
+            ];
+            type: 'u32';
+          },
+          {
+            name: 'orderRateLimitWindow';
+            docs: ['Length of the windows of `order_rate_limit` in seconds'];
+            type: 'u32';
+          },
+          {
+            name: 'minOrderNotional';
+            docs: [
+              "Smallest quote native value of an order resting on the book, at the order's",
+              "price when it's posted. 0 when there's no minimum.",
+              '',
+              'Keeps dust orders that are worth less than the space they take off the book.',
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'maxRestingOrders';
+            docs: [
+              'Max resting orders of an open orders account on this market, 0 when only',
+              'limited by the size of the account.',
+            ];
+            type: 'u8';
+          },
+          {
+            name: 'version';
+            docs: [
+              'Layout version, see `MARKET_VERSION`. 0 for markets created before it was',
+              'introduced.',
+            ];
+            type: 'u8';
+          },
+          {
+            name: 'padding3';
+            type: {
+              array: ['u8', 6];
+            };
+          },
+          {
+            name: 'evictionPolicy';
+            docs: ['See [`EvictionPolicy`].'];
+            type: 'u8';
+          },
+          {
+            name: 'takerFeeRounding';
+            docs: ['See [`FeeRounding`].'];
+            type: 'u8';
+          },
+          {
+            name: 'evictionBandBps';
+            docs: ['Price band of `EvictionPolicy::EvictOldestOutOfBand`'];
+            type: 'u16';
+          },
+          {
+            name: 'reserved';
+            type: {
+              array: ['u8', 12];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'merkleAllowlist';
+      docs: [
+        'Per-market PDA letting traders of a permissioned market prove once that they',
+        'are allowed to trade, instead of the `open_orders_admin` co-signing every order.',
+        '',
+        'Leaves of the tree are `sha256(owner)`, and each pair of nodes is hashed in',
+        "sorted order so proofs don't need to carry left/right flags.",
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'market';
+            type: 'publicKey';
+          },
+          {
+            name: 'root';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          },
+          {
+            name: 'padding';
+            type: {
+              array: ['u8', 7];
+            };
+          },
+          {
+            name: 'reserved';
+            type: {
+              array: ['u8', 64];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'openOrdersAccount';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'owner';
+            type: 'publicKey';
+          },
+          {
+            name: 'market';
+            type: 'publicKey';
+          },
+          {
+            name: 'name';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'delegate';
+            type: {
+              defined: 'NonZeroPubkeyOption';
+            };
+          },
+          {
+            name: 'accountNum';
+            type: 'u32';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          },
+          {
+            name: 'version';
+            type: 'u8';
+          },
+          {
+            name: 'flags';
+            docs: ['See [`open_orders_flags`].'];
+            type: 'u8';
+          },
+          {
+            name: 'dmmPriority';
+            docs: [
+              "Priority of the account's slot in the market's",
+              "[`DmmRegistry`](crate::state::DmmRegistry), 0 when it isn't a designated",
+              'market maker.',
+            ];
+            type: 'u8';
+          },
+          {
+            name: 'position';
+            type: {
+              defined: 'Position';
+            };
+          },
+          {
+            name: 'openOrders';
+            type: {
+              array: [
+                {
+                  defined: 'OpenOrder';
+                },
+                24,
+              ];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'openOrdersIndexer';
+      docs: [
+        'Per-owner PDA listing every live [`OpenOrdersAccount`](crate::state::OpenOrdersAccount),',
+        'so clients can discover them without scanning all program accounts.',
+        '',
+        'Accounts are added on creation and removed on close; `created_counter` only',
+        'ever grows and is used as the `account_num` seed of new open orders accounts.',
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'bump';
+            type: 'u8';
+          },
+          {
+            name: 'createdCounter';
+            type: 'u32';
+          },
+          {
+            name: 'addresses';
+            type: {
+              vec: 'publicKey';
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'stubOracle';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'owner';
+            type: 'publicKey';
+          },
+          {
+            name: 'mint';
+            type: 'publicKey';
+          },
+          {
+            name: 'price';
+            type: 'f64';
+          },
+          {
+            name: 'lastUpdateTs';
+            type: 'i64';
+          },
+          {
+            name: 'lastUpdateSlot';
+            type: 'u64';
+          },
+          {
+            name: 'deviation';
+            type: 'f64';
+          },
+          {
+            name: 'exponent';
+            docs: ['`price` and `deviation` are multiplied by 10^exponent'];
+            type: 'i8';
+          },
+          {
+            name: 'padding';
+            type: {
+              array: ['u8', 7];
+            };
+          },
+          {
+            name: 'reserved';
+            type: {
+              array: ['u8', 96];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'bookSide';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'roots';
+            type: {
+              array: [
+                {
+                  defined: 'OrderTreeRoot';
+                },
+                2,
+              ];
+            };
+          },
+          {
+            name: 'reservedRoots';
+            type: {
+              array: [
+                {
+                  defined: 'OrderTreeRoot';
+                },
+                4,
+              ];
+            };
+          },
+          {
+            name: 'nextPage';
+            docs: [
+              'Page holding further fixed price orders once this book side is full, created',
+              'with `create_book_side_page`. Default when there is none.',
+              '',
+              "Pages hold the orders posted while the market's book side was full, it keeps",
+              'the better ones. Instructions matching against a side with paged orders need',
+              'all its pages as remaining accounts.',
+            ];
+            type: 'publicKey';
+          },
+          {
+            name: 'pagedLeafCount';
+            docs: [
+              "Orders resting on the pages after this book side, only tracked on the market's",
+              'book side itself.',
+            ];
+            type: 'u32';
+          },
+          {
+            name: 'padding';
+            type: {
+              array: ['u8', 4];
+            };
+          },
+          {
+            name: 'bestPriceLots';
+            docs: [
+              'Price of the best valid fixed price order, 0 when there is none.',
+              '',
+              'Top of the book as of the last instruction that changed it, for programs and',
+              "clients that don't want to load the whole book side. Oracle pegged orders",
+              "aren't included, their price moves with the oracle.",
+            ];
+            type: 'i64';
+          },
+          {
+            name: 'bestBaseLots';
+            docs: [
+              'Base lots of the valid fixed price orders at `best_price_lots`, 0 when there',
+              'are none.',
+            ];
+            type: 'i64';
+          },
+          {
+            name: 'bestPriceSince';
+            docs: [
+              'Time `best_price_lots` last changed, since when the orders at that price are',
+              'at the top of the book. See `seconds_at_top`.',
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'reserved';
+            type: {
+              array: ['u8', 192];
+            };
+          },
+          {
+            name: 'nodes';
+            type: {
+              defined: 'OrderTreeNodes';
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'eventHeap';
+      docs: [
+        'Container for the different EventTypes.',
+        '',
+        'Events are stored in a fixed-array of nodes. Free nodes are connected by a single-linked list',
+        'starting at free_head while used nodes form a circular doubly-linked list starting at',
+        'used_head.',
+        '',
+        'The account can be grown with `resize_event_heap`, in which case additional nodes are',
+        'stored right after this struct; use [`EventHeapRefMut`] to access all of them.',
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'header';
+            type: {
+              defined: 'EventHeapHeader';
+            };
+          },
+          {
+            name: 'nodes';
+            type: {
+              array: [
+                {
+                  defined: 'EventNode';
+                },
+                600,
+              ];
+            };
+          },
+          {
+            name: 'extensionNodes';
+            docs: [
+              'Number of nodes stored after the struct, added by `resize_event_heap`',
+            ];
+            type: 'u16';
+          },
+          {
+            name: 'reserved';
+            type: {
+              array: ['u8', 62];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'pendingMarketAdmin';
+      docs: [
+        'Admin role of a [`Market`](crate::state::Market) offered with `set_market_authority`,',
+        'until the new admin takes it over with `accept_market_authority`.',
+        '',
+        'PDA of seeds `[b"PendingMarketAdmin", market]`, so only one handover is pending at',
+        "a time. It's closed when accepted or cancelled, returning the rent to `payer`.",
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'bump';
+            type: 'u8';
+          },
+          {
+            name: 'market';
+            type: 'publicKey';
+          },
+          {
+            name: 'role';
+            docs: ['See [`MarketAdmin`].'];
+            type: 'u8';
+          },
+          {
+            name: 'newAdmin';
+            type: 'publicKey';
+          },
+          {
+            name: 'payer';
+            type: 'publicKey';
+          },
+        ];
+      };
+    },
+    {
+      name: 'positionExtension';
+      docs: [
+        "Position data of an open orders account that doesn't fit in its [`Position`](super::Position),",
+        'created by the owner with `create_position_extension`.',
+        '',
+        'Once created, the extension has to be passed among the remaining accounts of',
+        'every instruction executing fills of the account, see [`load_position_extension`].',
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'openOrdersAccount';
+            type: 'publicKey';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          },
+          {
+            name: 'padding';
+            type: {
+              array: ['u8', 7];
+            };
+          },
+          {
+            name: 'netBaseNative';
+            docs: [
+              'Base native bought minus sold in fills, negative when more was sold',
+            ];
+            type: 'i64';
+          },
+          {
+            name: 'costBasisNative';
+            docs: [
+              'Quote native paid for `net_base_native`, negative for a net sold amount',
+            ];
+            type: 'i64';
+          },
+          {
+            name: 'realizedPnlNative';
+            docs: [
+              'Cumulative profit of fills reducing `net_base_native` against its average',
+              'entry price, in quote native units. Fees are tracked separately.',
+            ];
+            type: 'i64';
+          },
+          {
+            name: 'orderWindowStartTs';
+            docs: [
+              "Start of the current window of the market's `order_rate_limit`",
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'ordersInWindow';
+            docs: ['New orders placed since `order_window_start_ts`'];
+            type: 'u32';
+          },
+          {
+            name: 'padding2';
+            type: {
+              array: ['u8', 4];
+            };
+          },
+          {
+            name: 'riskProgram';
+            docs: [
+              'Program approving new orders of the account through a `check_health` CPI,',
+              'see [`health_check`](crate::health_check)',
+            ];
+            type: {
+              defined: 'NonZeroPubkeyOption';
+            };
+          },
+          {
+            name: 'settleReceiver';
+            docs: [
+              'Program `settle_funds` routes the settled funds into, see',
+              '[`settle_receiver`](crate::settle_receiver)',
+            ];
+            type: {
+              defined: 'NonZeroPubkeyOption';
+            };
+          },
+          {
+            name: 'cancelAllAfterTs';
+            docs: [
+              'Time after which anyone can cancel all orders of the account with',
+              '`execute_scheduled_cancel`, 0 when none is scheduled',
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'heartbeatInterval';
+            docs: [
+              'Seconds each `heartbeat` pushes `cancel_all_after_ts` out by, 0 when disabled',
+            ];
+            type: 'u32';
+          },
+          {
+            name: 'padding3';
+            type: {
+              array: ['u8', 4];
+            };
+          },
+          {
+            name: 'heartbeatBounty';
+            docs: [
+              'Lamports escrowed in the extension for whoever executes the scheduled cancel',
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'signedOrderNonce';
+            docs: [
+              'Nonce of the last order placed with `place_signed_order`, signed orders',
+              'need a higher one',
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'reserved';
+            type: {
+              array: ['u8', 144];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'quoteQuality';
+      docs: [
+        "Quoting of a designated market maker's open orders account, measured against",
+        'the terms of its agreement, see `sample_quote_quality`.',
+        '',
+        'The account quotes while it has at least `min_size_lots` on each side within',
+        '`max_oracle_distance_bps` of the oracle price. Each sample credits the time since',
+        'the previous one to the state it observes, so the counterparty of the agreement',
+        'should sample at unpredictable times. Stats are kept per epoch of',
+        '`epoch_duration` seconds, the last complete one stays in `previous_epoch`.',
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'market';
+            type: 'publicKey';
+          },
+          {
+            name: 'openOrdersAccount';
+            type: 'publicKey';
+          },
+          {
+            name: 'minSizeLots';
+            type: 'i64';
+          },
+          {
+            name: 'epochDuration';
+            type: 'u64';
+          },
+          {
+            name: 'lastSampleTimestamp';
+            type: 'u64';
+          },
+          {
+            name: 'maxOracleDistanceBps';
+            type: 'u16';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          },
+          {
+            name: 'padding';
+            type: {
+              array: ['u8', 5];
+            };
+          },
+          {
+            name: 'currentEpoch';
+            type: {
+              defined: 'QuoteQualityEpoch';
+            };
+          },
+          {
+            name: 'previousEpoch';
+            type: {
+              defined: 'QuoteQualityEpoch';
+            };
+          },
+          {
+            name: 'reserved';
+            type: {
+              array: ['u8', 64];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'poolState';
+      docs: [
+        'The pool state',
+        '',
+        'PDA of `[POOL_SEED, config, token_mint_0, token_mint_1]`',
+        '',
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'bump';
+            docs: ['Bump to identify PDA'];
+            type: {
+              array: ['u8', 1];
+            };
+          },
+          {
+            name: 'ammConfig';
+            type: 'publicKey';
+          },
+          {
+            name: 'owner';
+            type: 'publicKey';
+          },
+          {
+            name: 'tokenMint0';
+            docs: [
+              'Token pair of the pool, where token_mint_0 address < token_mint_1 address',
+            ];
+            type: 'publicKey';
+          },
+          {
+            name: 'tokenMint1';
+            type: 'publicKey';
+          },
+          {
+            name: 'tokenVault0';
+            docs: ['Token pair vault'];
+            type: 'publicKey';
+          },
+          {
+            name: 'tokenVault1';
+            type: 'publicKey';
+          },
+          {
+            name: 'observationKey';
+            docs: ['observation account key'];
+            type: 'publicKey';
+          },
+          {
+            name: 'mintDecimals0';
+            docs: ['mint0 and mint1 decimals'];
+            type: 'u8';
+          },
+          {
+            name: 'mintDecimals1';
+            type: 'u8';
+          },
+          {
+            name: 'tickSpacing';
+            docs: ['The minimum number of ticks between initialized ticks'];
+            type: 'u16';
+          },
+          {
+            name: 'liquidity';
+            docs: ['The currently in range liquidity available to the pool.'];
+            type: 'u128';
+          },
+          {
+            name: 'sqrtPriceX64';
+            docs: [
+              'The current price of the pool as a sqrt(token_1/token_0) Q64.64 value',
+            ];
+            type: 'u128';
+          },
+          {
+            name: 'tickCurrent';
+            docs: [
+              'The current tick of the pool, i.e. according to the last tick transition that was run.',
+            ];
+            type: 'i32';
+          },
+          {
+            name: 'observationIndex';
+            docs: ['the most-recently updated index of the observations array'];
+            type: 'u16';
+          },
+          {
+            name: 'observationUpdateDuration';
+            type: 'u16';
+          },
+          {
+            name: 'feeGrowthGlobal0X64';
+            docs: [
+              'The fee growth as a Q64.64 number, i.e. fees of token_0 and token_1 collected per',
+              'unit of liquidity for the entire life of the pool.',
+            ];
+            type: 'u128';
+          },
+          {
+            name: 'feeGrowthGlobal1X64';
+            type: 'u128';
+          },
+          {
+            name: 'protocolFeesToken0';
+            docs: [
+              'The amounts of token_0 and token_1 that are owed to the protocol.',
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'protocolFeesToken1';
+            type: 'u64';
+          },
+          {
+            name: 'swapInAmountToken0';
+            docs: ['The amounts in and out of swap token_0 and token_1'];
+            type: 'u128';
+          },
+          {
+            name: 'swapOutAmountToken1';
+            type: 'u128';
+          },
+          {
+            name: 'swapInAmountToken1';
+            type: 'u128';
+          },
+          {
+            name: 'swapOutAmountToken0';
+            type: 'u128';
+          },
+          {
+            name: 'status';
+            docs: [
+              'Bitwise representation of the state of the pool',
+              'bit0, 1: disable open position and increase liquidity, 0: normal',
+              'bit1, 1: disable decrease liquidity, 0: normal',
+              'bit2, 1: disable collect fee, 0: normal',
+              'bit3, 1: disable collect reward, 0: normal',
+              'bit4, 1: disable swap, 0: normal',
+            ];
+            type: 'u8';
+          },
+          {
+            name: 'padding';
+            docs: ['Leave blank for future use'];
+            type: {
+              array: ['u8', 7];
+            };
+          },
+          {
+            name: 'rewardInfos';
+            type: {
+              array: [
+                {
+                  defined: 'RewardInfo';
+                },
+                3,
+              ];
+            };
+          },
+          {
+            name: 'tickArrayBitmap';
+            docs: ['Packed initialized tick array state'];
+            type: {
+              array: ['u64', 16];
+            };
+          },
+          {
+            name: 'totalFeesToken0';
+            docs: ['except protocol_fee and fund_fee'];
+            type: 'u64';
+          },
+          {
+            name: 'totalFeesClaimedToken0';
+            docs: ['except protocol_fee and fund_fee'];
+            type: 'u64';
+          },
+          {
+            name: 'totalFeesToken1';
+            type: 'u64';
+          },
+          {
+            name: 'totalFeesClaimedToken1';
+            type: 'u64';
+          },
+          {
+            name: 'fundFeesToken0';
+            type: 'u64';
+          },
+          {
+            name: 'fundFeesToken1';
+            type: 'u64';
+          },
+          {
+            name: 'openTime';
+            type: 'u64';
+          },
+          {
+            name: 'padding1';
+            type: {
+              array: ['u64', 25];
+            };
+          },
+          {
+            name: 'padding2';
+            type: {
+              array: ['u64', 32];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'aggregatorAccountData';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'name';
+            docs: ['Name of the aggregator to store on-chain.'];
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'metadata';
+            docs: ['Metadata of the aggregator to store on-chain.'];
+            type: {
+              array: ['u8', 128];
+            };
+          },
+          {
+            name: 'reserved1';
+            docs: ['Reserved.'];
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'queuePubkey';
+            docs: ['Pubkey of the queue the aggregator belongs to.'];
+            type: 'publicKey';
+          },
+          {
+            name: 'oracleRequestBatchSize';
+            docs: [
+              'CONFIGS',
+              'Number of oracles assigned to an update request.',
+            ];
+            type: 'u32';
+          },
+          {
+            name: 'minOracleResults';
+            docs: [
+              'Minimum number of oracle responses required before a round is validated.',
+            ];
+            type: 'u32';
+          },
+          {
+            name: 'minJobResults';
+            docs: [
+              'Minimum number of job results before an oracle accepts a result.',
+            ];
+            type: 'u32';
+          },
+          {
+            name: 'minUpdateDelaySeconds';
+            docs: [
+              'Minimum number of seconds required between aggregator rounds.',
+            ];
+            type: 'u32';
+          },
+          {
+            name: 'startAfter';
+            docs: [
+              'Unix timestamp for which no feed update will occur before.',
+            ];
+            type: 'i64';
+          },
+          {
+            name: 'varianceThreshold';
+            docs: [
+              'Change percentage required between a previous round and the current round. If variance percentage is not met, reject new oracle responses.',
+            ];
+            type: {
+              defined: 'SwitchboardDecimal';
+            };
+          },
+          {
+            name: 'forceReportPeriod';
+            docs: [
+              'Number of seconds for which, even if the variance threshold is not passed, accept new responses from oracles.',
+            ];
+            type: 'i64';
+          },
+          {
+            name: 'expiration';
+            docs: ['Timestamp when the feed is no longer needed.'];
+            type: 'i64';
+          },
+          {
+            name: 'consecutiveFailureCount';
+            docs: [
+              'Counter for the number of consecutive failures before a feed is removed from a queue. If set to 0, failed feeds will remain on the queue.',
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'nextAllowedUpdateTime';
+            docs: ['Timestamp when the next update request will be available.'];
+            type: 'i64';
+          },
+          {
+            name: 'isLocked';
+            docs: [
+              'Flag for whether an aggregators configuration is locked for editing.',
+            ];
+            type: 'bool';
+          },
+          {
+            name: 'crankPubkey';
+            docs: [
+              'Optional, public key of the crank the aggregator is currently using. Event based feeds do not need a crank.',
+            ];
+            type: 'publicKey';
+          },
+          {
+            name: 'latestConfirmedRound';
+            docs: [
+              'Latest confirmed update request result that has been accepted as valid.',
+            ];
+            type: {
+              defined: 'AggregatorRound';
+            };
+          },
+          {
+            name: 'currentRound';
+            docs: [
+              'Oracle results from the current round of update request that has not been accepted as valid yet.',
+            ];
+            type: {
+              defined: 'AggregatorRound';
+            };
+          },
+          {
+            name: 'jobPubkeysData';
+            docs: [
+              'List of public keys containing the job definitions for how data is sourced off-chain by oracles.',
+            ];
+            type: {
+              array: ['publicKey', 16];
+            };
+          },
+          {
+            name: 'jobHashes';
+            docs: [
+              'Used to protect against malicious RPC nodes providing incorrect task definitions to oracles before fulfillment.',
+            ];
+            type: {
+              array: [
+                {
+                  defined: 'Hash';
+                },
+                16,
+              ];
+            };
+          },
+          {
+            name: 'jobPubkeysSize';
+            docs: ['Number of jobs assigned to an oracle.'];
+            type: 'u32';
+          },
+          {
+            name: 'jobsChecksum';
+            docs: [
+              'Used to protect against malicious RPC nodes providing incorrect task definitions to oracles before fulfillment.',
+            ];
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'authority';
+            docs: [
+              'The account delegated as the authority for making account changes.',
+            ];
+            type: 'publicKey';
+          },
+          {
+            name: 'historyBuffer';
+            docs: [
+              'Optional, public key of a history buffer account storing the last N accepted results and their timestamps.',
+            ];
+            type: 'publicKey';
+          },
+          {
+            name: 'previousConfirmedRoundResult';
+            docs: ['The previous confirmed round result.'];
+            type: {
+              defined: 'SwitchboardDecimal';
+            };
+          },
+          {
+            name: 'previousConfirmedRoundSlot';
+            docs: ['The slot when the previous confirmed round was opened.'];
+            type: 'u64';
+          },
+          {
+            name: 'disableCrank';
+            docs: ['Whether an aggregator is permitted to join a crank.'];
+            type: 'bool';
+          },
+          {
+            name: 'jobWeights';
+            docs: [
+              "Job weights used for the weighted median of the aggregator's assigned job accounts.",
+            ];
+            type: {
+              array: ['u8', 16];
+            };
+          },
+          {
+            name: 'creationTimestamp';
+            docs: ['Unix timestamp when the feed was created.'];
+            type: 'i64';
+          },
+          {
+            name: 'resolutionMode';
+            docs: [
+              'Use sliding windoe or round based resolution',
+              'NOTE: This changes result propogation in latest_round_result',
+            ];
+            type: {
+              defined: 'AggregatorResolutionMode';
+            };
+          },
+          {
+            name: 'ebuf';
+            docs: ['Reserved for future info.'];
+            type: {
+              array: ['u8', 138];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'takerProgramAllowlist';
+      docs: [
+        'Per-market PDA listing the programs allowed to `place_take_order` on a',
+        'permissioned market through CPI without the `open_orders_admin` signature.',
+        '',
+        'This lets an aggregator be granted access once instead of whitelisting each',
+        'of its users.',
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'market';
+            type: 'publicKey';
+          },
+          {
+            name: 'programs';
+            docs: ['Unused slots are zero.'];
+            type: {
+              array: ['publicKey', 8];
+            };
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          },
+          {
+            name: 'padding';
+            type: {
+              array: ['u8', 7];
+            };
+          },
+          {
+            name: 'reserved';
+            type: {
+              array: ['u8', 64];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'takerRebateCampaign';
+      docs: [
+        'Promotional rebate of a share of the taker fees of a market, funded by its admin.',
+        '',
+        'While the campaign runs, takers with an open orders account accrue `rebate_bps`',
+        'of the taker fees they pay, as long as the campaign account is among the remaining',
+        'accounts of the order. Rebates are paid in quote out of `rebate_vault` with',
+        '`claim_taker_rebates`.',
+        '',
+        'Only the budget funded with `fund_taker_rebate_campaign` is rebated, rebates of',
+        'fills past it are dropped.',
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'market';
+            type: 'publicKey';
+          },
+          {
+            name: 'rebateVault';
+            docs: ['Quote token account owned by this account'];
+            type: 'publicKey';
+          },
+          {
+            name: 'startTimestamp';
+            type: 'u64';
+          },
+          {
+            name: 'endTimestamp';
+            type: 'u64';
+          },
+          {
+            name: 'budgetRemaining';
+            docs: ['Funded rebates not accrued yet'];
+            type: 'u64';
+          },
+          {
+            name: 'rebatesOutstanding';
+            docs: [
+              'Rebates accrued to open orders accounts and not claimed yet',
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'rebatesAccruedTotal';
+            docs: ['Rebates accrued since creation'];
+            type: 'u64';
+          },
+          {
+            name: 'rebateBps';
+            docs: ['Share of the taker fees rebated'];
+            type: 'u16';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          },
+          {
+            name: 'padding';
+            type: {
+              array: ['u8', 5];
+            };
+          },
+          {
+            name: 'reserved';
+            type: {
+              array: ['u8', 64];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'tokenGate';
+      docs: [
+        'Per-market PDA letting holders of a given token (e.g. a KYC NFT) trade on a',
+        'permissioned market without the `open_orders_admin` co-signing every order.',
+        '',
+        'The holding is checked on every order placement, so selling or moving the',
+        'token revokes access right away.',
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'market';
+            type: 'publicKey';
+          },
+          {
+            name: 'mint';
+            type: 'publicKey';
+          },
+          {
+            name: 'minAmount';
+            docs: [
+              'Minimum balance, in native units, a token account must hold to place orders.',
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          },
+          {
+            name: 'padding';
+            type: {
+              array: ['u8', 7];
+            };
+          },
+          {
+            name: 'reserved';
+            type: {
+              array: ['u8', 64];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'triggerOrder';
+      docs: [
+        'An order of an open orders account waiting for a price condition, e.g. a stop',
+        'loss, take profit or limit-if-touched order.',
+        '',
+        'Created by the owner or delegate with `create_trigger_order`. Once the',
+        'condition is met anyone can place the order with `execute_trigger` and gets the',
+        'rent of this account in return. The order is funded from the free balances of',
+        'the open orders account only, as no token account signs for it.',
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'owner';
+            type: 'publicKey';
+          },
+          {
+            name: 'market';
+            type: 'publicKey';
+          },
+          {
+            name: 'openOrdersAccount';
+            type: 'publicKey';
+          },
+          {
+            name: 'triggerId';
+            docs: [
+              'Chosen by the owner, distinguishes the trigger orders of an account',
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'triggerPriceLots';
+            type: 'i64';
+          },
+          {
+            name: 'priceLots';
+            type: 'i64';
+          },
+          {
+            name: 'maxBaseLots';
+            type: 'i64';
+          },
+          {
+            name: 'maxQuoteLotsIncludingFees';
+            type: 'i64';
+          },
+          {
+            name: 'clientOrderId';
+            type: 'u64';
+          },
+          {
+            name: 'expiryTimestamp';
+            docs: [
+              'Expiry of the placed order, see `PlaceOrderArgs`. Also the last moment the',
+              'trigger can be executed, 0 for never.',
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'condition';
+            docs: ['See [`TriggerCondition`]'];
+            type: 'u8';
+          },
+          {
+            name: 'side';
+            docs: ['See [`Side`]'];
+            type: 'u8';
+          },
+          {
+            name: 'orderType';
+            docs: ['See [`PlaceOrderType`]'];
+            type: 'u8';
+          },
+          {
+            name: 'selfTradeBehavior';
+            docs: ['See [`SelfTradeBehavior`]'];
+            type: 'u8';
+          },
+          {
+            name: 'limit';
+            docs: ['Max orders of the book to fill when executed'];
+            type: 'u8';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          },
+          {
+            name: 'padding';
+            type: {
+              array: ['u8', 2];
+            };
+          },
+          {
+            name: 'reserved';
+            type: {
+              array: ['u8', 64];
+            };
+          },
+        ];
+      };
+    },
+  ];
+  types: [
+    {
+      name: 'OnFillArgs';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'market';
+            type: 'publicKey';
+          },
+          {
+            name: 'fills';
+            type: {
+              vec: {
+                defined: 'FillEvent';
+              };
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'HealthCheckOrder';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'side';
+            type: {
+              defined: 'Side';
+            };
+          },
+          {
+            name: 'priceLots';
+            docs: [
+              'Worst price the order can execute at: the limit price, or the peg limit of',
+              'oracle pegged orders. `None` for market orders and unlimited pegged orders.',
+            ];
+            type: {
+              option: 'i64';
+            };
+          },
+          {
+            name: 'maxBaseLots';
+            type: 'i64';
+          },
+          {
+            name: 'maxQuoteLotsIncludingFees';
+            type: 'i64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'CheckHealthArgs';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'market';
+            type: 'publicKey';
+          },
+          {
+            name: 'openOrdersAccount';
+            type: 'publicKey';
+          },
+          {
+            name: 'orders';
+            type: {
+              vec: {
+                defined: 'HealthCheckOrder';
+              };
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'NonZeroPubkeyOption';
+      docs: [
+        'Like `Option`, but implemented for `Pubkey` to be used with `zero_copy`',
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'key';
+            type: 'publicKey';
+          },
+        ];
+      };
+    },
+    {
+      name: 'OnSettleArgs';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'market';
+            type: 'publicKey';
+          },
+          {
+            name: 'openOrdersAccount';
+            type: 'publicKey';
+          },
+          {
+            name: 'owner';
+            docs: [
+              'Owner of the open orders account, who the funds are settled for',
+            ];
+            type: 'publicKey';
+          },
+          {
+            name: 'baseNative';
+            type: 'u64';
+          },
+          {
+            name: 'quoteNative';
+            type: 'u64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'SignedOrder';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'market';
+            type: 'publicKey';
+          },
+          {
+            name: 'openOrdersAccount';
+            type: 'publicKey';
+          },
+          {
+            name: 'nonce';
+            docs: [
+              'Has to exceed the nonce of the last signed order placed for the account,',
+              'so every signed order is placed once at most',
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'validUntilTs';
+            docs: ['Last moment the order can be placed, 0 for never'];
+            type: 'u64';
+          },
+          {
+            name: 'order';
+            type: {
+              defined: 'PlaceOrderArgs';
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'DepthLevel';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'priceLots';
+            type: 'i64';
+          },
+          {
+            name: 'quantity';
+            docs: ['Base lots resting at `price_lots`'];
+            type: 'i64';
+          },
+          {
+            name: 'cumulativeQuantity';
+            docs: ['Base lots resting at `price_lots` or better'];
+            type: 'i64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'DmmSlot';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'openOrdersAccount';
+            docs: ['Zero for a free slot'];
+            type: 'publicKey';
+          },
+          {
+            name: 'priority';
+            docs: [
+              'Higher priorities win when booting orders from a full book side',
+            ];
+            type: 'u8';
+          },
+          {
+            name: 'padding';
+            type: 'u8';
+          },
+          {
+            name: 'minUptimeBps';
+            docs: ['Least uptime of a quote quality epoch to keep the slot'];
+            type: 'u16';
+          },
+          {
+            name: 'padding2';
+            type: {
+              array: ['u8', 4];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'FillRecord';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'price';
+            type: 'i64';
+          },
+          {
+            name: 'quantity';
+            type: 'i64';
+          },
+          {
+            name: 'timestamp';
+            type: 'u64';
+          },
+          {
+            name: 'eventSeqNum';
+            type: 'u64';
+          },
+          {
+            name: 'maker';
+            type: 'publicKey';
+          },
+          {
+            name: 'taker';
+            type: 'publicKey';
+          },
+          {
+            name: 'takerSide';
+            type: 'u8';
+          },
+          {
+            name: 'padding';
+            type: {
+              array: ['u8', 7];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'MarketRegistryEntry';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'market';
+            type: 'publicKey';
+          },
+          {
+            name: 'baseMint';
+            type: 'publicKey';
+          },
+          {
+            name: 'quoteMint';
+            type: 'publicKey';
+          },
+          {
+            name: 'baseLotSize';
+            type: 'i64';
+          },
+          {
+            name: 'quoteLotSize';
+            type: 'i64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'HourlyStats';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'hour';
+            docs: ['Hours since the unix epoch'];
+            type: 'u64';
+          },
+          {
+            name: 'quoteVolume';
+            docs: ['Native quote traded'];
+            type: 'u64';
+          },
+          {
+            name: 'tradeCount';
+            type: 'u64';
+          },
+          {
+            name: 'highLots';
+            type: 'i64';
+          },
+          {
+            name: 'lowLots';
+            type: 'i64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'Stats24h';
+      docs: [
+        'Totals of the fills of the current and previous 23 hours, see [`MarketStats::last_24h`]',
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'quoteVolume';
+            type: 'u64';
+          },
+          {
+            name: 'tradeCount';
+            type: 'u64';
+          },
+          {
+            name: 'highLots';
+            docs: ['Highest and lowest fill prices, 0 without fills'];
+            type: 'i64';
+          },
+          {
+            name: 'lowLots';
+            type: 'i64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'Position';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'bidsBaseLots';
+            docs: ['Base lots in open bids'];
+            type: 'i64';
+          },
+          {
+            name: 'asksBaseLots';
+            docs: ['Base lots in open asks'];
+            type: 'i64';
+          },
+          {
+            name: 'baseFreeNative';
+            type: 'u64';
+          },
+          {
+            name: 'quoteFreeNative';
+            type: 'u64';
+          },
+          {
+            name: 'lockedMakerFees';
+            type: 'u64';
+          },
+          {
+            name: 'referrerRebatesAvailable';
+            type: 'u64';
+          },
+          {
+            name: 'penaltyHeapCount';
+            docs: [
+              'Count of ixs when events are added to the heap',
+              'To avoid this, send remaining accounts in order to process the events',
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'makerVolume';
+            docs: [
+              'Cumulative maker volume in quote native units (display only)',
+            ];
+            type: 'u128';
+          },
+          {
+            name: 'takerVolume';
+            docs: [
+              'Cumulative taker volume in quote native units (display only)',
+            ];
+            type: 'u128';
+          },
+          {
+            name: 'bidsQuoteLots';
+            docs: ['Quote lots in open bids'];
+            type: 'i64';
+          },
+          {
+            name: 'makerFeesPaid';
+            docs: ['Cumulative fees paid on maker fills in quote native units'];
+            type: 'u64';
+          },
+          {
+            name: 'makerRebatesReceived';
+            docs: [
+              'Cumulative rebates received on maker fills in quote native units',
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'takerFeesPaid';
+            docs: [
+              'Cumulative fees paid on taker trades in quote native units',
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'makerRewardPoints';
+            docs: [
+              'Base lots times seconds at the top of the book not claimed as maker rewards',
+              'yet, see `MakerRewards`',
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'makerRewardsClaimed';
+            docs: ['Cumulative maker rewards claimed in reward native units'];
+            type: 'u64';
+          },
+          {
+            name: 'takerRebatesAccrued';
+            docs: [
+              'Taker fee rebates accrued and not claimed yet, see `TakerRebateCampaign`',
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'makerRebatesAvailable';
+            docs: [
+              'Maker rebates credited to `quote_free_native` since it was last settled,',
+              'see `claim_rebates`',
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'reserved';
+            type: {
+              array: ['u8', 8];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'OpenOrder';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'id';
+            type: 'u128';
+          },
+          {
+            name: 'clientId';
+            type: 'u64';
+          },
+          {
+            name: 'lockedPrice';
+            docs: ["Price at which user's assets were locked"];
+            type: 'i64';
+          },
+          {
+            name: 'isFree';
+            type: 'u8';
+          },
+          {
+            name: 'sideAndTree';
+            type: 'u8';
+          },
+          {
+            name: 'padding';
+            type: {
+              array: ['u8', 6];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'OracleConfig';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'confFilter';
+            type: 'f64';
+          },
+          {
+            name: 'maxStalenessSlots';
+            type: 'i64';
+          },
+          {
+            name: 'reserved';
+            type: {
+              array: ['u8', 72];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'OracleConfigParams';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'confFilter';
+            type: 'f32';
+          },
+          {
+            name: 'maxStalenessSlots';
+            type: {
+              option: 'u32';
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'EventHeapHeader';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'freeHead';
+            type: 'u16';
+          },
+          {
+            name: 'usedHead';
+            type: 'u16';
+          },
+          {
+            name: 'count';
+            type: 'u16';
+          },
+          {
+            name: 'padd';
+            type: 'u16';
+          },
+          {
+            name: 'seqNum';
+            type: 'u64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'EventNode';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'next';
+            type: 'u16';
+          },
+          {
+            name: 'prev';
+            type: 'u16';
+          },
+          {
+            name: 'pad';
+            type: {
+              array: ['u8', 4];
+            };
+          },
+          {
+            name: 'event';
+            type: {
+              defined: 'AnyEvent';
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'AnyEvent';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'eventType';
+            type: 'u8';
+          },
+          {
+            name: 'padding';
+            type: {
+              array: ['u8', 143];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'FillEvent';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'eventType';
+            type: 'u8';
+          },
+          {
+            name: 'takerSide';
+            type: 'u8';
+          },
+          {
+            name: 'makerOut';
+            type: 'u8';
+          },
+          {
+            name: 'makerSlot';
+            type: 'u8';
+          },
+          {
+            name: 'makerSecondsAtTop';
+            docs: [
+              'Seconds the maker order had been at the top of the book, see',
+              '`Market::seconds_at_top`',
+            ];
+            type: 'u32';
+          },
+          {
+            name: 'timestamp';
+            type: 'u64';
+          },
+          {
+            name: 'marketSeqNum';
+            type: 'u64';
+          },
+          {
+            name: 'maker';
+            type: 'publicKey';
+          },
+          {
+            name: 'makerTimestamp';
+            type: 'u64';
+          },
+          {
+            name: 'taker';
+            type: 'publicKey';
+          },
+          {
+            name: 'takerClientOrderId';
+            type: 'u64';
+          },
+          {
+            name: 'price';
+            type: 'i64';
+          },
+          {
+            name: 'pegLimit';
+            type: 'i64';
+          },
+          {
+            name: 'quantity';
+            type: 'i64';
+          },
+          {
+            name: 'makerClientOrderId';
+            type: 'u64';
+          },
+          {
+            name: 'eventSeqNum';
+            docs: ['Per-market sequence number shared by fill and out events'];
+            type: 'u64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'OutEvent';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'eventType';
+            type: 'u8';
+          },
+          {
+            name: 'side';
+            type: 'u8';
+          },
+          {
+            name: 'ownerSlot';
+            type: 'u8';
+          },
+          {
+            name: 'reason';
+            docs: ['OutReason'];
+            type: 'u8';
+          },
+          {
+            name: 'padding0';
+            type: {
+              array: ['u8', 4];
+            };
+          },
+          {
+            name: 'timestamp';
+            type: 'u64';
+          },
+          {
+            name: 'seqNum';
+            type: 'u64';
+          },
+          {
+            name: 'owner';
+            type: 'publicKey';
+          },
+          {
+            name: 'quantity';
+            type: 'i64';
+          },
+          {
+            name: 'eventSeqNum';
+            docs: ['Per-market sequence number shared by fill and out events'];
+            type: 'u64';
+          },
+          {
+            name: 'price';
+            docs: ['See [`LeafNode::price_lots_or_peg_offset`]'];
+            type: 'i64';
+          },
+          {
+            name: 'pegLimit';
+            type: 'i64';
+          },
+          {
+            name: 'clientOrderId';
+            type: 'u64';
+          },
+          {
+            name: 'padding1';
+            type: {
+              array: ['u8', 48];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'InnerNode';
+      docs: [
+        'InnerNodes and LeafNodes compose the binary tree of orders.',
+        '',
+        'Each InnerNode has exactly two children, which are either InnerNodes themselves,',
+        'or LeafNodes. The children share the top `prefix_len` bits of `key`. The left',
+        'child has a 0 in the next bit, and the right a 1.',
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'tag';
+            type: 'u8';
+          },
+          {
+            name: 'padding';
+            type: {
+              array: ['u8', 3];
+            };
+          },
+          {
+            name: 'prefixLen';
+            docs: [
+              'number of highest `key` bits that all children share',
+              "e.g. if it's 2, the two highest bits of `key` will be the same on all children",
+            ];
+            type: 'u32';
+          },
+          {
+            name: 'key';
+            docs: ['only the top `prefix_len` bits of `key` are relevant'];
+            type: 'u128';
+          },
+          {
+            name: 'children';
+            docs: ['indexes into `BookSide::nodes`'];
+            type: {
+              array: ['u32', 2];
+            };
+          },
+          {
+            name: 'childEarliestExpiry';
+            docs: [
+              'The earliest expiry timestamp for the left and right subtrees.',
+              '',
+              'Needed to be able to find and remove expired orders without having to',
+              'iterate through the whole bookside.',
+            ];
+            type: {
+              array: ['u64', 2];
+            };
+          },
+          {
+            name: 'reserved';
+            type: {
+              array: ['u8', 40];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'LeafNode';
+      docs: ['LeafNodes represent an order in the binary tree'];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'tag';
+            docs: ['NodeTag'];
+            type: 'u8';
+          },
+          {
+            name: 'ownerSlot';
+            docs: ["Index into the owning OpenOrdersAccount's OpenOrders"];
+            type: 'u8';
+          },
+          {
+            name: 'timeInForce';
+            docs: [
+              'Time in seconds after `timestamp` at which the order expires.',
+              'A value of 0 means no expiry.',
+            ];
+            type: 'u16';
+          },
+          {
+            name: 'dmmPriority';
+            docs: [
+              '`OpenOrdersAccount::dmm_priority` of the owner when the order was placed',
+            ];
+            type: 'u8';
+          },
+          {
+            name: 'padding';
+            type: {
+              array: ['u8', 3];
+            };
+          },
+          {
+            name: 'key';
+            docs: ['The binary tree key, see new_node_key()'];
+            type: 'u128';
+          },
+          {
+            name: 'owner';
+            docs: ['Address of the owning OpenOrdersAccount'];
+            type: 'publicKey';
+          },
+          {
+            name: 'quantity';
+            docs: ['Number of base lots to buy or sell, always >=1'];
+            type: 'i64';
+          },
+          {
+            name: 'timestamp';
+            docs: ['The time the order was placed'];
+            type: 'u64';
+          },
+          {
+            name: 'pegLimit';
+            docs: [
+              'If the effective price of an oracle pegged order exceeds this limit,',
+              'it will be considered invalid and may be removed.',
+              '',
+              'Only applicable in the oracle_pegged OrderTree',
+            ];
+            type: 'i64';
+          },
+          {
+            name: 'clientOrderId';
+            docs: ['User defined id for this order, used in FillEvents'];
+            type: 'u64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'AnyNode';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'tag';
+            type: 'u8';
+          },
+          {
+            name: 'data';
+            type: {
+              array: ['u8', 79];
+            };
+          },
+          {
+            name: 'forceAlign';
+            type: 'u64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'OrderTreeRoot';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'maybeNode';
+            type: 'u32';
+          },
+          {
+            name: 'leafCount';
+            type: 'u32';
+          },
+        ];
+      };
+    },
+    {
+      name: 'ExpiryBucket';
+      docs: [
+        'Resting orders expiring within the same epoch of `EXPIRY_BUCKET_SECONDS`.',
+        '',
+        'Buckets are reused by epoch modulo `MAX_EXPIRY_BUCKETS`. Orders of epochs sharing',
+        'a bucket are counted together under the earliest epoch, so a bucket is due as',
+        "soon as its first order may have expired. Orders never expiring aren't counted.",
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'epoch';
+            type: 'u64';
+          },
+          {
+            name: 'orderCount';
+            type: 'u32';
+          },
+          {
+            name: 'padding';
+            type: {
+              array: ['u8', 4];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'OrderTreeNodes';
+      docs: [
+        'A binary tree on AnyNode::key()',
+        '',
+        'The key encodes the price in the top 64 bits.',
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'orderTreeType';
+            type: 'u8';
+          },
+          {
+            name: 'padding';
+            type: {
+              array: ['u8', 3];
+            };
+          },
+          {
+            name: 'bumpIndex';
+            type: 'u32';
+          },
+          {
+            name: 'freeListLen';
+            type: 'u32';
+          },
+          {
+            name: 'freeListHead';
+            type: 'u32';
+          },
+          {
+            name: 'expiryBuckets';
+            docs: [
+              'Leaves of all trees using these nodes grouped by expiry, see',
+              '[`OrderTreeNodes::due_expiry_order_count`]',
+            ];
+            type: {
+              array: [
+                {
+                  defined: 'ExpiryBucket';
+                },
+                16,
+              ];
+            };
+          },
+          {
+            name: 'reserved';
+            type: {
+              array: ['u8', 256];
+            };
+          },
+          {
+            name: 'nodes';
+            type: {
+              array: [
+                {
+                  defined: 'AnyNode';
+                },
+                1024,
+              ];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'QuoteQualityEpoch';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'startTimestamp';
+            type: 'u64';
+          },
+          {
+            name: 'samples';
+            type: 'u64';
+          },
+          {
+            name: 'secondsSampled';
+            docs: ['Seconds accounted for by samples'];
+            type: 'u64';
+          },
+          {
+            name: 'secondsQuoting';
+            docs: [
+              'Seconds accounted for by samples where the account was quoting',
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'minBidSizeLots';
+            docs: [
+              'Smallest size within the oracle distance seen by a sample, per side',
+            ];
+            type: 'i64';
+          },
+          {
+            name: 'minAskSizeLots';
+            type: 'i64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'RewardInfo';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'rewardState';
+            docs: ['Reward state'];
+            type: 'u8';
+          },
+          {
+            name: 'openTime';
+            docs: ['Reward open time'];
+            type: 'u64';
+          },
+          {
+            name: 'endTime';
+            docs: ['Reward end time'];
+            type: 'u64';
+          },
+          {
+            name: 'lastUpdateTime';
+            docs: ['Reward last update time'];
+            type: 'u64';
+          },
+          {
+            name: 'emissionsPerSecondX64';
+            docs: [
+              'Q64.64 number indicates how many tokens per second are earned per unit of liquidity.',
+            ];
+            type: 'u128';
+          },
+          {
+            name: 'rewardTotalEmissioned';
+            docs: ['The total amount of reward emissioned'];
+            type: 'u64';
+          },
+          {
+            name: 'rewardClaimed';
+            docs: ['The total amount of claimed reward'];
+            type: 'u64';
+          },
+          {
+            name: 'tokenMint';
+            docs: ['Reward token mint.'];
+            type: 'publicKey';
+          },
+          {
+            name: 'tokenVault';
+            docs: ['Reward vault token account.'];
+            type: 'publicKey';
+          },
+          {
+            name: 'authority';
+            docs: ['The owner that has permission to set reward param'];
+            type: 'publicKey';
+          },
+          {
+            name: 'rewardGrowthGlobalX64';
+            docs: [
+              'Q64.64 number that tracks the total tokens earned per unit of liquidity since the reward',
+              'emissions were turned on.',
+            ];
+            type: 'u128';
+          },
+        ];
+      };
+    },
+    {
+      name: 'SwitchboardDecimal';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'mantissa';
+            docs: [
+              'The part of a floating-point number that represents the significant digits of that number, and that is multiplied by the base, 10, raised to the power of scale to give the actual value of the number.',
+            ];
+            type: 'i128';
+          },
+          {
+            name: 'scale';
+            docs: [
+              'The number of decimal places to move to the left to yield the actual value.',
+            ];
+            type: 'u32';
+          },
+        ];
+      };
+    },
+    {
+      name: 'Hash';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'data';
+            docs: ['The bytes used to derive the hash.'];
+            type: {
+              array: ['u8', 32];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'AggregatorRound';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'numSuccess';
+            docs: [
+              'Maintains the number of successful responses received from nodes.',
+              'Nodes can submit one successful response per round.',
+            ];
+            type: 'u32';
+          },
+          {
+            name: 'numError';
+            docs: ['Number of error responses.'];
+            type: 'u32';
+          },
+          {
+            name: 'isClosed';
+            docs: ['Whether an update request round has ended.'];
+            type: 'bool';
+          },
+          {
+            name: 'roundOpenSlot';
+            docs: [
+              'Maintains the `solana_program::clock::Slot` that the round was opened at.',
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'roundOpenTimestamp';
+            docs: [
+              'Maintains the `solana_program::clock::UnixTimestamp;` the round was opened at.',
+            ];
+            type: 'i64';
+          },
+          {
+            name: 'result';
+            docs: [
+              'Maintains the current median of all successful round responses.',
+            ];
+            type: {
+              defined: 'SwitchboardDecimal';
+            };
+          },
+          {
+            name: 'stdDeviation';
+            docs: ['Standard deviation of the accepted results in the round.'];
+            type: {
+              defined: 'SwitchboardDecimal';
+            };
+          },
+          {
+            name: 'minResponse';
+            docs: ['Maintains the minimum node response this round.'];
+            type: {
+              defined: 'SwitchboardDecimal';
+            };
+          },
+          {
+            name: 'maxResponse';
+            docs: ['Maintains the maximum node response this round.'];
+            type: {
+              defined: 'SwitchboardDecimal';
+            };
+          },
+          {
+            name: 'oraclePubkeysData';
+            docs: ['Pubkeys of the oracles fulfilling this round.'];
+            type: {
+              array: ['publicKey', 16];
+            };
+          },
+          {
+            name: 'mediansData';
+            docs: [
+              'Represents all successful node responses this round. `NaN` if empty.',
+            ];
+            type: {
+              array: [
+                {
+                  defined: 'SwitchboardDecimal';
+                },
+                16,
+              ];
+            };
+          },
+          {
+            name: 'currentPayout';
+            docs: ['Current rewards/slashes oracles have received this round.'];
+            type: {
+              array: ['i64', 16];
+            };
+          },
+          {
+            name: 'mediansFulfilled';
+            docs: ['Keep track of which responses are fulfilled here.'];
+            type: {
+              array: ['bool', 16];
+            };
+          },
+          {
+            name: 'errorsFulfilled';
+            docs: ['Keeps track of which errors are fulfilled here.'];
+            type: {
+              array: ['bool', 16];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'BorshDecimal';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'mantissa';
+            type: 'i128';
+          },
+          {
+            name: 'scale';
+            type: 'u32';
+          },
+        ];
+      };
+    },
+    {
+      name: 'I80F48';
+      docs: [
+        'Nothing in Rust shall use these types. They only exist so that the Anchor IDL',
+        'knows about them and typescript can deserialize it.',
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'val';
+            type: 'i128';
+          },
+        ];
+      };
+    },
+    {
+      name: 'PlaceOrderArgs';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'side';
+            type: {
+              defined: 'Side';
+            };
+          },
+          {
+            name: 'priceLots';
+            type: 'i64';
+          },
+          {
+            name: 'maxBaseLots';
+            type: 'i64';
+          },
+          {
+            name: 'maxQuoteLotsIncludingFees';
+            type: 'i64';
+          },
+          {
+            name: 'clientOrderId';
+            type: 'u64';
+          },
+          {
+            name: 'orderType';
+            type: {
+              defined: 'PlaceOrderType';
+            };
+          },
+          {
+            name: 'expiryTimestamp';
+            type: 'u64';
+          },
+          {
+            name: 'selfTradeBehavior';
+            type: {
+              defined: 'SelfTradeBehavior';
+            };
+          },
+          {
+            name: 'limit';
+            type: 'u8';
+          },
+        ];
+      };
+    },
+    {
+      name: 'MarketQuote';
+      docs: [
+        'Top of the book of a market, see `get_market_quote`.',
+        '',
+        'Prices without a `_lots` suffix are native prices: native quote per native base.',
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'bestBidLots';
+            type: {
+              option: 'i64';
+            };
+          },
+          {
+            name: 'bestBidQuantity';
+            docs: ['Base lots at the best bid'];
+            type: 'i64';
+          },
+          {
+            name: 'bestAskLots';
+            type: {
+              option: 'i64';
+            };
+          },
+          {
+            name: 'bestAskQuantity';
+            docs: ['Base lots at the best ask'];
+            type: 'i64';
+          },
+          {
+            name: 'midPrice';
+            docs: [
+              'Average of the best bid and ask, `None` unless both sides have orders',
+            ];
+            type: {
+              option: {
+                defined: 'I80F48';
+              };
+            };
+          },
+          {
+            name: 'spreadBps';
+            docs: [
+              'Difference between the best ask and bid, relative to the mid price',
+            ];
+            type: {
+              option: 'i64';
+            };
+          },
+          {
+            name: 'oraclePrice';
+            docs: ["`None` without a usable oracle, e.g. when it's stale"];
+            type: {
+              option: {
+                defined: 'I80F48';
+              };
+            };
+          },
+          {
+            name: 'oracleDeviationBps';
+            docs: [
+              'Difference between the mid price and the oracle price, relative to the oracle price',
+            ];
+            type: {
+              option: 'i64';
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'OrderInfo';
+      docs: ['An order of an open orders account, see `find_order`'];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'orderId';
+            type: 'u128';
+          },
+          {
+            name: 'clientOrderId';
+            type: 'u64';
+          },
+          {
+            name: 'side';
+            type: {
+              defined: 'Side';
+            };
+          },
+          {
+            name: 'isOraclePegged';
+            type: 'bool';
+          },
+          {
+            name: 'lockedPrice';
+            docs: [
+              "Price the order's funds are locked at, the peg limit for pegged bids",
+            ];
+            type: 'i64';
+          },
+          {
+            name: 'quantity';
+            docs: [
+              'Base lots left on the book. 0 when the order was filled or expired and the',
+              "event freeing it wasn't consumed yet.",
+            ];
+            type: 'i64';
+          },
+          {
+            name: 'timestamp';
+            type: 'u64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'PlaceMultipleOrdersArgs';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'priceLots';
+            type: 'i64';
+          },
+          {
+            name: 'maxQuoteLotsIncludingFees';
+            type: 'i64';
+          },
+          {
+            name: 'expiryTimestamp';
+            type: 'u64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'ConsumeEventsGroup';
+      docs: ['One market cranked by `consume_events_multiple`'];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'numAccounts';
+            docs: [
+              'Remaining accounts of the market after its `market` and `event_heap`',
+            ];
+            type: 'u8';
+          },
+          {
+            name: 'limit';
+            docs: ['Max events to consume in the market'];
+            type: 'u8';
+          },
+        ];
+      };
+    },
+    {
+      name: 'PlaceOrderPeggedArgs';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'side';
+            type: {
+              defined: 'Side';
+            };
+          },
+          {
+            name: 'priceOffsetLots';
+            type: 'i64';
+          },
+          {
+            name: 'pegLimit';
+            type: 'i64';
+          },
+          {
+            name: 'maxBaseLots';
+            type: 'i64';
+          },
+          {
+            name: 'maxQuoteLotsIncludingFees';
+            type: 'i64';
+          },
+          {
+            name: 'clientOrderId';
+            type: 'u64';
+          },
+          {
+            name: 'orderType';
+            type: {
+              defined: 'PlaceOrderType';
+            };
+          },
+          {
+            name: 'expiryTimestamp';
+            type: 'u64';
+          },
+          {
+            name: 'selfTradeBehavior';
+            type: {
+              defined: 'SelfTradeBehavior';
+            };
+          },
+          {
+            name: 'limit';
+            type: 'u8';
+          },
+        ];
+      };
+    },
+    {
+      name: 'PlaceTakeOrderArgs';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'side';
+            type: {
+              defined: 'Side';
+            };
+          },
+          {
+            name: 'priceLots';
+            type: 'i64';
+          },
+          {
+            name: 'maxBaseLots';
+            type: 'i64';
+          },
+          {
+            name: 'maxQuoteLotsIncludingFees';
+            type: 'i64';
+          },
+          {
+            name: 'clientOrderId';
+            docs: [
+              'Copied to the fill events and logs as `taker_client_order_id`',
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'orderType';
+            type: {
+              defined: 'PlaceOrderType';
+            };
+          },
+          {
+            name: 'limit';
+            type: 'u8';
+          },
+        ];
+      };
+    },
+    {
+      name: 'MarketMetadataArgs';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'name';
+            type: 'string';
+          },
+          {
+            name: 'baseSymbol';
+            type: 'string';
+          },
+          {
+            name: 'quoteSymbol';
+            type: 'string';
+          },
+          {
+            name: 'uri';
+            type: 'string';
+          },
+        ];
+      };
+    },
+    {
+      name: 'TakerStopReason';
+      docs: [
+        'Why a taker order stopped matching before filling its max base lots',
+      ];
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'BookExhausted';
+          },
+          {
+            name: 'PriceLimit';
+          },
+          {
+            name: 'QuoteLimit';
+          },
+          {
+            name: 'MatchLimit';
+          },
+          {
+            name: 'SelfTrade';
+          },
+        ];
+      };
+    },
+    {
+      name: 'MarketState';
+      docs: [
+        'What users can do on a market, set by its admin to handle incidents.',
+        '',
+        'Settling funds and consuming events are always allowed, so users never',
+        'lose access to their balances.',
+      ];
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'Active';
+          },
+          {
+            name: 'PostOnly';
+          },
+          {
+            name: 'CancelOnly';
+          },
+          {
+            name: 'Paused';
+          },
+        ];
+      };
+    },
+    {
+      name: 'BinaryOutcome';
+      docs: [
+        'Resolution of a market trading a binary outcome token, set by its admin.',
+        '',
+        'A resolved market is settled at a price of 0 or 1 quote per base (in ui',
+        "units), recorded in its `settlement_price_lots`. The market doesn't redeem",
+        'outcome tokens itself, it only trades them and publishes the resolution.',
+      ];
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'Disabled';
+          },
+          {
+            name: 'Unresolved';
+          },
+          {
+            name: 'No';
+          },
+          {
+            name: 'Yes';
+          },
+        ];
+      };
+    },
+    {
+      name: 'EvictionPolicy';
+      docs: [
+        'Which order makes room when a new order is posted to a full book side, set by',
+        "the market's admin.",
+      ];
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'EvictWorstPrice';
+          },
+          {
+            name: 'RejectIncoming';
+          },
+          {
+            name: 'EvictOldestOutOfBand';
+          },
+        ];
+      };
+    },
+    {
+      name: 'FeeRounding';
+      docs: [
+        "How a [`Market`]'s taker fees are rounded to native quote units, set by the",
+        "market's admin.",
+      ];
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'Up';
+          },
+          {
+            name: 'Down';
+          },
+        ];
+      };
+    },
+    {
+      name: 'MarketAdmin';
+      docs: [
+        'Admin roles of a market that can be handed over with `set_market_authority`.',
+      ];
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'CollectFee';
+          },
+          {
+            name: 'ConsumeEvents';
+          },
+          {
+            name: 'CloseMarket';
+          },
+        ];
+      };
+    },
+    {
+      name: 'OracleType';
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'Pyth';
+          },
+          {
+            name: 'Stub';
+          },
+          {
+            name: 'SwitchboardV1';
+          },
+          {
+            name: 'SwitchboardV2';
+          },
+          {
+            name: 'RaydiumCLMM';
+          },
+        ];
+      };
+    },
+    {
+      name: 'OrderState';
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'Valid';
+          },
+          {
+            name: 'Invalid';
+          },
+          {
+            name: 'Skipped';
+          },
+        ];
+      };
+    },
+    {
+      name: 'BookSideOrderTree';
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'Fixed';
+          },
+          {
+            name: 'OraclePegged';
+          },
+        ];
+      };
+    },
+    {
+      name: 'EventType';
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'Fill';
+          },
+          {
+            name: 'Out';
+          },
+        ];
+      };
+    },
+    {
+      name: 'OutReason';
+      docs: [
+        'Why an order left the book, stored in [`OutEvent::reason`]',
+        '',
+        'Orders cancelled by their owner are reported with `CancelLog` instead of out',
+        'events and logs.',
+      ];
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'Cancelled';
+          },
+          {
+            name: 'Expired';
+          },
+          {
+            name: 'BootedForSpace';
+          },
+          {
+            name: 'SelfTradeCancel';
+          },
+          {
+            name: 'AdminCancel';
+          },
+        ];
+      };
+    },
+    {
+      name: 'NodeTag';
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'Uninitialized';
+          },
+          {
+            name: 'InnerNode';
+          },
+          {
+            name: 'LeafNode';
+          },
+          {
+            name: 'FreeNode';
+          },
+          {
+            name: 'LastFreeNode';
+          },
+        ];
+      };
+    },
+    {
+      name: 'PlaceOrderType';
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'Limit';
+          },
+          {
+            name: 'ImmediateOrCancel';
+          },
+          {
+            name: 'PostOnly';
+          },
+          {
+            name: 'Market';
+          },
+          {
+            name: 'PostOnlySlide';
+          },
+          {
+            name: 'FillOrKill';
+          },
+        ];
+      };
+    },
+    {
+      name: 'PostOrderType';
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'Limit';
+          },
+          {
+            name: 'PostOnly';
+          },
+          {
+            name: 'PostOnlySlide';
+          },
+        ];
+      };
+    },
+    {
+      name: 'SelfTradeBehavior';
+      docs: [
+        'Self trade behavior controls how taker orders interact with resting limit orders of the same account.',
+        'This setting has no influence on placing a resting or oracle pegged limit order that does not match',
+        "immediately, instead it's the responsibility of the user to correctly configure his taker orders.",
+      ];
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'DecrementTake';
+          },
+          {
+            name: 'CancelProvide';
+          },
+          {
+            name: 'AbortTransaction';
+          },
+        ];
+      };
+    },
+    {
+      name: 'Side';
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'Bid';
+          },
+          {
+            name: 'Ask';
+          },
+        ];
+      };
+    },
+    {
+      name: 'SideAndOrderTree';
+      docs: [
+        "SideAndOrderTree is a storage optimization, so we don't need two bytes for the data",
+      ];
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'BidFixed';
+          },
+          {
+            name: 'AskFixed';
+          },
+          {
+            name: 'BidOraclePegged';
+          },
+          {
+            name: 'AskOraclePegged';
+          },
+        ];
+      };
+    },
+    {
+      name: 'OrderParams';
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'Market';
+          },
+          {
+            name: 'ImmediateOrCancel';
+            fields: [
+              {
+                name: 'priceLots';
+                type: 'i64';
+              },
+            ];
+          },
+          {
+            name: 'Fixed';
+            fields: [
+              {
+                name: 'priceLots';
+                type: 'i64';
+              },
+              {
+                name: 'orderType';
+                type: {
+                  defined: 'PostOrderType';
+                };
+              },
+            ];
+          },
+          {
+            name: 'OraclePegged';
+            fields: [
+              {
+                name: 'priceOffsetLots';
+                type: 'i64';
+              },
+              {
+                name: 'orderType';
+                type: {
+                  defined: 'PostOrderType';
+                };
+              },
+              {
+                name: 'pegLimit';
+                type: 'i64';
+              },
+            ];
+          },
+          {
+            name: 'FillOrKill';
+            fields: [
+              {
+                name: 'priceLots';
+                type: 'i64';
+              },
+            ];
+          },
+        ];
+      };
+    },
+    {
+      name: 'OrderTreeType';
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'Bids';
+          },
+          {
+            name: 'Asks';
+          },
+        ];
+      };
+    },
+    {
+      name: 'AggregatorResolutionMode';
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'ModeRoundResolution';
+          },
+          {
+            name: 'ModeSlidingResolution';
+          },
+        ];
+      };
+    },
+    {
+      name: 'SwitchboardError';
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'InvalidAggregatorRound';
+          },
+          {
+            name: 'InvalidStrDecimalConversion';
+          },
+          {
+            name: 'DecimalConversionError';
+          },
+          {
+            name: 'IntegerOverflowError';
+          },
+          {
+            name: 'AccountDiscriminatorMismatch';
+          },
+          {
+            name: 'VrfEmptyError';
+          },
+          {
+            name: 'VrfCpiError';
+          },
+          {
+            name: 'VrfCpiSignedError';
+          },
+          {
+            name: 'AccountDeserializationError';
+          },
+          {
+            name: 'StaleFeed';
+          },
+          {
+            name: 'ConfidenceIntervalExceeded';
+          },
+          {
+            name: 'InvalidAuthority';
+          },
+          {
+            name: 'AllowedVarianceExceeded';
+          },
+          {
+            name: 'InvalidFunctionInput';
+          },
+          {
+            name: 'PdaDerivationError';
+          },
+          {
+            name: 'IllegalExecuteAttempt';
+          },
+          {
+            name: 'InvalidQuote';
+          },
+          {
+            name: 'InvalidQueue';
+          },
+          {
+            name: 'InvalidEnclaveSigner';
+          },
+          {
+            name: 'InvalidNativeMint';
+          },
+          {
+            name: 'MrEnclavesEmpty';
+          },
+          {
+            name: 'InvalidMrEnclave';
+          },
+          {
+            name: 'FunctionNotReady';
+          },
+          {
+            name: 'UserRequestsDisabled';
+          },
+          {
+            name: 'FunctionRoutinesDisabled';
+          },
+          {
+            name: 'PermissionDenied';
+          },
+          {
+            name: 'ConfigParameterLocked';
+          },
+          {
+            name: 'FunctionServicesDisabled';
+          },
+          {
+            name: 'ServiceDisabled';
+          },
+          {
+            name: 'ServiceWorkerFull';
+          },
+          {
+            name: 'ServiceWorkerEnclaveFull';
+          },
+          {
+            name: 'ServiceAlreadyAssignedToWorker';
+          },
+        ];
+      };
+    },
+    {
+      name: 'TriggerCondition';
+      docs: [
+        'When a [`TriggerOrder`] can be executed.',
+        '',
+        "Oracle conditions compare the market's oracle price. Book conditions compare",
+        'the best opposing price, the one the order would take at: the best ask for',
+        'bids, the best bid for asks. Conditions are never met while the price is',
+        'unavailable.',
+      ];
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'OraclePriceAbove';
+          },
+          {
+            name: 'OraclePriceBelow';
+          },
+          {
+            name: 'BookPriceAbove';
+          },
+          {
+            name: 'BookPriceBelow';
+          },
+        ];
+      };
+    },
+    {
+      name: 'NodeHandle';
+      type: {
+        kind: 'alias';
+        value: 'u32';
+      };
+    },
+  ];
+  events: [
+    {
+      name: 'DepositLog';
+      fields: [
+        {
+          name: 'openOrdersAccount';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'signer';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'baseAmount';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'quoteAmount';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'FillLog';
+      fields: [
+        {
+          name: 'market';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'takerSide';
+          type: 'u8';
+          index: false;
+        },
+        {
+          name: 'makerSlot';
+          type: 'u8';
+          index: false;
+        },
+        {
+          name: 'makerOut';
+          type: 'bool';
+          index: false;
+        },
+        {
+          name: 'timestamp';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'seqNum';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'maker';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'makerClientOrderId';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'makerFee';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'makerTimestamp';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'taker';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'takerClientOrderId';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'takerFeeCeil';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'price';
+          type: 'i64';
+          index: false;
+        },
+        {
+          name: 'quantity';
+          type: 'i64';
+          index: false;
+        },
+        {
+          name: 'baseNative';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'quoteNative';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'eventSeqNum';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'OutLog';
+      fields: [
+        {
+          name: 'market';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'side';
+          type: 'u8';
+          index: false;
+        },
+        {
+          name: 'ownerSlot';
+          type: 'u8';
+          index: false;
+        },
+        {
+          name: 'timestamp';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'seqNum';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'owner';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'quantity';
+          type: 'i64';
+          index: false;
+        },
+        {
+          name: 'eventSeqNum';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'reason';
+          type: 'u8';
+          index: false;
+        },
+        {
+          name: 'price';
+          type: 'i64';
+          index: false;
+        },
+        {
+          name: 'pegLimit';
+          type: 'i64';
+          index: false;
+        },
+        {
+          name: 'clientOrderId';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'PlaceLog';
+      fields: [
+        {
+          name: 'market';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'side';
+          type: 'u8';
+          index: false;
+        },
+        {
+          name: 'seqNum';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'owner';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'clientOrderId';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'orderId';
+          type: {
+            option: 'u128';
+          };
+          index: false;
+        },
+        {
+          name: 'baseTakenNative';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'quoteTakenNative';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'basePostedNative';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'quotePostedNative';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'takerFees';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'makerFees';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'CancelLog';
+      fields: [
+        {
+          name: 'market';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'side';
+          type: 'u8';
+          index: false;
+        },
+        {
+          name: 'seqNum';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'openOrdersAccount';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'orderId';
+          type: 'u128';
+          index: false;
+        },
+        {
+          name: 'clientOrderId';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'quantity';
+          type: 'i64';
+          index: false;
+        },
+        {
+          name: 'price';
+          type: 'i64';
+          index: false;
+        },
+        {
+          name: 'pegLimit';
+          type: 'i64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'TakerSignatureLog';
+      fields: [
+        {
+          name: 'market';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'seqNum';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'MarketMetaDataLog';
+      fields: [
+        {
+          name: 'market';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'name';
+          type: 'string';
+          index: false;
+        },
+        {
+          name: 'baseMint';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'quoteMint';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'baseDecimals';
+          type: 'u8';
+          index: false;
+        },
+        {
+          name: 'quoteDecimals';
+          type: 'u8';
+          index: false;
+        },
+        {
+          name: 'baseLotSize';
+          type: 'i64';
+          index: false;
+        },
+        {
+          name: 'quoteLotSize';
+          type: 'i64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'TotalOrderFillEvent';
+      fields: [
+        {
+          name: 'side';
+          type: 'u8';
+          index: false;
+        },
+        {
+          name: 'taker';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'totalQuantityPaid';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'totalQuantityReceived';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'fees';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'TakerExecutionLog';
+      fields: [
+        {
+          name: 'market';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'side';
+          type: 'u8';
+          index: false;
+        },
+        {
+          name: 'owner';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'clientOrderId';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'maxBaseLots';
+          type: 'i64';
+          index: false;
+        },
+        {
+          name: 'baseLotsFilled';
+          type: 'i64';
+          index: false;
+        },
+        {
+          name: 'quoteLotsFilled';
+          type: 'i64';
+          index: false;
+        },
+        {
+          name: 'averagePriceLots';
+          type: 'i64';
+          index: false;
+        },
+        {
+          name: 'reason';
+          type: 'u8';
+          index: false;
+        },
+        {
+          name: 'aborted';
+          type: 'bool';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'SetDelegateLog';
+      fields: [
+        {
+          name: 'openOrdersAccount';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'delegate';
+          type: {
+            option: 'publicKey';
+          };
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'SettleFundsLog';
+      fields: [
+        {
+          name: 'openOrdersAccount';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'baseNative';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'quoteNative';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'referrerRebate';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'referrer';
+          type: {
+            option: 'publicKey';
+          };
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'ClaimRebatesLog';
+      fields: [
+        {
+          name: 'openOrdersAccount';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'makerRebate';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'referrerRebate';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'receiver';
+          type: 'publicKey';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'ConsumeEventsRewardLog';
+      fields: [
+        {
+          name: 'market';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'cranker';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'eventsConsumed';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'reward';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'ConsumeEventsSkippedLog';
+      fields: [
+        {
+          name: 'market';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'eventsSkipped';
+          type: 'u8';
+          index: false;
+        },
+        {
+          name: 'missingOpenOrdersAccounts';
+          type: {
+            vec: 'publicKey';
+          };
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'SettleExpiredMarketLog';
+      fields: [
+        {
+          name: 'market';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'settlementPriceLots';
+          type: 'i64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'SweepFeesLog';
+      fields: [
+        {
+          name: 'market';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'amount';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'receiver';
+          type: 'publicKey';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'MakerRewardsClaimLog';
+      fields: [
+        {
+          name: 'market';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'openOrdersAccount';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'amount';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'receiver';
+          type: 'publicKey';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'TakerRebatesClaimLog';
+      fields: [
+        {
+          name: 'market';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'openOrdersAccount';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'amount';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'receiver';
+          type: 'publicKey';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'OpenOrdersPositionLog';
+      fields: [
+        {
+          name: 'owner';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'openOrdersAccountNum';
+          type: 'u32';
+          index: false;
+        },
+        {
+          name: 'market';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'bidsBaseLots';
+          type: 'i64';
+          index: false;
+        },
+        {
+          name: 'bidsQuoteLots';
+          type: 'i64';
+          index: false;
+        },
+        {
+          name: 'asksBaseLots';
+          type: 'i64';
+          index: false;
+        },
+        {
+          name: 'baseFreeNative';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'quoteFreeNative';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'lockedMakerFees';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'referrerRebatesAvailable';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'makerVolume';
+          type: 'u128';
+          index: false;
+        },
+        {
+          name: 'takerVolume';
+          type: 'u128';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'MarketInvariantsLog';
+      fields: [
+        {
+          name: 'market';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'openOrdersAccounts';
+          type: 'u32';
+          index: false;
+        },
+        {
+          name: 'pendingFills';
+          type: 'u32';
+          index: false;
+        },
+        {
+          name: 'baseVault';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'baseDepositTotal';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'baseUnaccounted';
+          type: 'i128';
+          index: false;
+        },
+        {
+          name: 'quoteVault';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'quoteDepositTotal';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'feesAvailable';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'quoteUnaccounted';
+          type: 'i128';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'ExecuteTriggerLog';
+      fields: [
+        {
+          name: 'market';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'openOrdersAccount';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'triggerId';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'keeper';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'orderId';
+          type: {
+            option: 'u128';
+          };
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'HeartbeatBountyLog';
+      fields: [
+        {
+          name: 'openOrdersAccount';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'keeper';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'bounty';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'RelayerFeeLog';
+      fields: [
+        {
+          name: 'openOrdersAccount';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'relayer';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'mint';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'relayerFee';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'SweepQuoteDustLog';
+      fields: [
+        {
+          name: 'market';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'amount';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+  ];
+  errors: [
+    {
+      code: 6000;
+      name: 'SomeError';
+      msg: '';
+    },
+    {
+      code: 6001;
+      name: 'InvalidInputNameLength';
+      msg: 'Name lenght above limit';
+    },
+    {
+      code: 6002;
+      name: 'InvalidInputMarketExpired';
+      msg: 'Market cannot be created as expired';
+    },
+    {
+      code: 6003;
+      name: 'InvalidInputMarketFees';
+      msg: 'Taker fees should be positive and if maker fees are negative, greater or equal to their abs value';
+    },
+    {
+      code: 6004;
+      name: 'InvalidInputLots';
+      msg: 'Lots cannot be negative';
+    },
+    {
+      code: 6005;
+      name: 'InvalidInputLotsSize';
+      msg: 'Lots size above market limits';
+    },
+    {
+      code: 6006;
+      name: 'InvalidInputOrdersAmounts';
+      msg: 'Input amounts above limits';
+    },
+    {
+      code: 6007;
+      name: 'InvalidInputCancelSize';
+      msg: 'Price lots should be greater than zero';
+    },
+    {
+      code: 6008;
+      name: 'InvalidInputPriceLots';
+      msg: 'Expected cancel size should be greater than zero';
+    },
+    {
+      code: 6009;
+      name: 'InvalidInputPegLimit';
+      msg: 'Peg limit should be greater than zero';
+    },
+    {
+      code: 6010;
+      name: 'InvalidInputOrderType';
+      msg: 'The order type is invalid. A taker order must be Market or ImmediateOrCancel';
+    },
+    {
+      code: 6011;
+      name: 'InvalidInputOrderId';
+      msg: 'Order id cannot be zero';
+    },
+    {
+      code: 6012;
+      name: 'InvalidInputHeapSlots';
+      msg: 'Slot above heap limit';
+    },
+    {
+      code: 6013;
+      name: 'InvalidOracleTypes';
+      msg: 'Cannot combine two oracles of different providers';
+    },
+    {
+      code: 6014;
+      name: 'InvalidSecondOracle';
+      msg: 'Cannot configure secondary oracle without primary';
+    },
+    {
+      code: 6015;
+      name: 'NoCloseMarketAdmin';
+      msg: 'This market does not have a `close_market_admin` and thus cannot be closed.';
+    },
+    {
+      code: 6016;
+      name: 'InvalidCloseMarketAdmin';
+      msg: "The signer of this transaction is not this market's `close_market_admin`.";
+    },
+    {
+      code: 6017;
+      name: 'InvalidOpenOrdersAdmin';
+      msg: 'The `open_orders_admin` required by this market to sign all instructions that creates orders is missing or is not valid';
+    },
+    {
+      code: 6018;
+      name: 'InvalidConsumeEventsAdmin';
+      msg: 'The `consume_events_admin` required by this market to sign all instructions that consume events is missing or is not valid';
+    },
+    {
+      code: 6019;
+      name: 'InvalidMarketVault';
+      msg: 'Provided `market_vault` is invalid';
+    },
+    {
+      code: 6020;
+      name: 'IndexerActiveOO';
+      msg: 'Cannot be closed due to the existence of open orders accounts';
+    },
+    {
+      code: 6021;
+      name: 'OraclePegInvalidOracleState';
+      msg: 'Cannot place a peg order due to invalid oracle state';
+    },
+    {
+      code: 6022;
+      name: 'UnknownOracleType';
+      msg: 'oracle type cannot be determined';
+    },
+    {
+      code: 6023;
+      name: 'OracleConfidence';
+      msg: 'an oracle does not reach the confidence threshold';
+    },
+    {
+      code: 6024;
+      name: 'OracleStale';
+      msg: 'an oracle is stale';
+    },
+    {
+      code: 6025;
+      name: 'OrderIdNotFound';
+      msg: 'Order id not found on the orderbook';
+    },
+    {
+      code: 6026;
+      name: 'EventHeapContainsElements';
+      msg: "Event heap contains elements and market can't be closed";
+    },
+    {
+      code: 6027;
+      name: 'InvalidOrderPostIOC';
+      msg: 'ImmediateOrCancel is not a PostOrderType';
+    },
+    {
+      code: 6028;
+      name: 'InvalidOrderPostMarket';
+      msg: 'Market is not a PostOrderType';
+    },
+    {
+      code: 6029;
+      name: 'WouldSelfTrade';
+      msg: 'would self trade';
+    },
+    {
+      code: 6030;
+      name: 'MarketHasExpired';
+      msg: 'The Market has already expired.';
+    },
+    {
+      code: 6031;
+      name: 'InvalidPriceLots';
+      msg: 'Price lots should be greater than zero';
+    },
+    {
+      code: 6032;
+      name: 'InvalidOraclePrice';
+      msg: 'Oracle price above market limits';
+    },
+    {
+      code: 6033;
+      name: 'MarketHasNotExpired';
+      msg: 'The Market has not expired yet.';
+    },
+    {
+      code: 6034;
+      name: 'NoOwnerOrDelegate';
+      msg: 'No correct owner or delegate.';
+    },
+    {
+      code: 6035;
+      name: 'NoOwner';
+      msg: 'No correct owner';
+    },
+    {
+      code: 6036;
+      name: 'OpenOrdersFull';
+      msg: 'No free order index in open orders account';
+    },
+    {
+      code: 6037;
+      name: 'BookContainsElements';
+      msg: 'Book contains elements';
+    },
+    {
+      code: 6038;
+      name: 'OpenOrdersOrderNotFound';
+      msg: 'Could not find order in user account';
+    },
+    {
+      code: 6039;
+      name: 'InvalidPostAmount';
+      msg: 'Amount to post above book limits';
+    },
+    {
+      code: 6040;
+      name: 'DisabledOraclePeg';
+      msg: 'Oracle peg orders are not enabled for this market';
+    },
+    {
+      code: 6041;
+      name: 'NonEmptyMarket';
+      msg: 'Cannot close a non-empty market';
+    },
+    {
+      code: 6042;
+      name: 'NonEmptyOpenOrdersPosition';
+      msg: 'Cannot close a non-empty open orders account';
+    },
+    {
+      code: 6043;
+      name: 'WouldExecutePartially';
+      msg: 'Fill-Or-Kill order would generate a partial execution';
+    },
+    {
+      code: 6044;
+      name: 'InvalidInputRemainingAccounts';
+      msg: 'Remaining accounts are missing or not grouped as expected';
+    },
+    {
+      code: 6045;
+      name: 'OpenOrdersAccountNotIndexed';
+      msg: "Open orders account is not registered in the owner's indexer";
+    },
+    {
+      code: 6046;
+      name: 'EventHeapFull';
+      msg: "Event heap is full and its oldest event's open orders account is missing";
+    },
+    {
+      code: 6047;
+      name: 'InvalidFillCallbackProgram';
+      msg: 'Invalid fill callback program';
+    },
+    {
+      code: 6048;
+      name: 'InvalidFillHistory';
+      msg: 'Fill history belongs to another market';
+    },
+    {
+      code: 6049;
+      name: 'InvalidMarketStats';
+      msg: 'Market stats belong to another market';
+    },
+    {
+      code: 6050;
+      name: 'MarketIsPostOnly';
+      msg: 'Market only accepts post only orders';
+    },
+    {
+      code: 6051;
+      name: 'MarketIsCancelOnly';
+      msg: 'Market only allows cancelling orders';
+    },
+    {
+      code: 6052;
+      name: 'MarketIsPaused';
+      msg: 'Market is paused';
+    },
+    {
+      code: 6053;
+      name: 'SettlementPriceUnavailable';
+      msg: 'No valid oracle price to settle the market at';
+    },
+    {
+      code: 6054;
+      name: 'MarketIsSettled';
+      msg: 'Market has been settled';
+    },
+    {
+      code: 6055;
+      name: 'InvalidInputSettlementPrice';
+      msg: 'Settlement price is only given, and should be positive, for markets without an oracle';
+    },
+    {
+      code: 6056;
+      name: 'NotBinaryOutcomeMarket';
+      msg: 'Market is not a binary outcome market';
+    },
+    {
+      code: 6057;
+      name: 'BinaryOutcomeUnresolved';
+      msg: 'Binary outcome market has not been resolved';
+    },
+    {
+      code: 6058;
+      name: 'InvalidMarketAdmin';
+      msg: "Signer doesn't hold this market admin role";
+    },
+    {
+      code: 6059;
+      name: 'InvalidPendingMarketAdmin';
+      msg: "Signer isn't the pending market admin";
+    },
+    {
+      code: 6060;
+      name: 'InvalidMarketRegistryPage';
+      msg: 'Market registry page is full or out of order';
+    },
+    {
+      code: 6061;
+      name: 'MarketAlreadyRegistered';
+      msg: 'Market is already in the market registry';
+    },
+    {
+      code: 6062;
+      name: 'InvalidGovernance';
+      msg: "Signer is not the program's upgrade authority";
+    },
+    {
+      code: 6063;
+      name: 'InvalidDustReceiver';
+      msg: 'Dust receiver must be owned by the collect fee admin';
+    },
+    {
+      code: 6064;
+      name: 'InvalidMerkleProof';
+      msg: "Owner is not in the market's allowlist";
+    },
+    {
+      code: 6065;
+      name: 'InvalidTokenGateAccount';
+      msg: "Token account does not hold enough of the market's gate token";
+    },
+    {
+      code: 6066;
+      name: 'TooManyTakerPrograms';
+      msg: 'Too many taker programs';
+    },
+    {
+      code: 6067;
+      name: 'InvalidBookSidePage';
+      msg: 'Book side page is not the next page of the chain';
+    },
+    {
+      code: 6068;
+      name: 'MissingBookSidePages';
+      msg: "Book side has orders on pages that weren't passed";
+    },
+    {
+      code: 6069;
+      name: 'TooManyBookSidePages';
+      msg: 'Book side has the maximum number of pages';
+    },
+    {
+      code: 6070;
+      name: 'MathOverflow';
+      msg: 'Amount overflows its type';
+    },
+    {
+      code: 6071;
+      name: 'InvalidInputDepthLevels';
+      msg: 'Depth levels above snapshot limit';
+    },
+    {
+      code: 6072;
+      name: 'QuoteQualityWithoutOracle';
+      msg: 'Quote quality tracking requires a market with an oracle';
+    },
+    {
+      code: 6073;
+      name: 'InvalidInputEpochDuration';
+      msg: 'Epoch duration should be greater than zero';
+    },
+    {
+      code: 6074;
+      name: 'InvalidTakerRebateCampaign';
+      msg: 'Taker rebate campaign belongs to another market';
+    },
+    {
+      code: 6075;
+      name: 'InvalidInputTakerRebateCampaign';
+      msg: 'Campaign should end after it starts and rebate at most 10000 bps';
+    },
+    {
+      code: 6076;
+      name: 'TakerRebateCampaignActive';
+      msg: 'Taker rebate campaign is still running';
+    },
+    {
+      code: 6077;
+      name: 'MissingPositionExtension';
+      msg: "Open orders account has a position extension that wasn't passed";
+    },
+    {
+      code: 6078;
+      name: 'PositionExtensionNotClosed';
+      msg: 'Open orders account has a position extension that must be closed first';
+    },
+    {
+      code: 6079;
+      name: 'OrderRateLimitWithoutPositionExtension';
+      msg: 'Rate limited markets require a position extension on the open orders account';
+    },
+    {
+      code: 6080;
+      name: 'OrderRateLimitExceeded';
+      msg: "Open orders account placed too many orders in the market's rate limit window";
+    },
+    {
+      code: 6081;
+      name: 'InvalidInputOrderRateLimit';
+      msg: 'Order rate limit window should be greater than zero';
+    },
+    {
+      code: 6082;
+      name: 'OrderNotionalTooSmall';
+      msg: "Order is worth less than the market's minimum order notional";
+    },
+    {
+      code: 6083;
+      name: 'InvalidInputMaxRestingOrders';
+      msg: 'Max resting orders above the size of open orders accounts';
+    },
+    {
+      code: 6084;
+      name: 'MaxRestingOrdersReached';
+      msg: "Open orders account has the market's max number of resting orders";
+    },
+    {
+      code: 6085;
+      name: 'InvalidSuccessorMarket';
+      msg: 'Successor market should be another market of the same token pair';
+    },
+    {
+      code: 6086;
+      name: 'MigrateAccountWithBids';
+      msg: 'Version 0 open orders accounts need to cancel their bids before being migrated';
+    },
+    {
+      code: 6087;
+      name: 'PriceOutsideBand';
+      msg: 'Order price is outside the range of prices the book accepts';
+    },
+    {
+      code: 6088;
+      name: 'InsufficientFunds';
+      msg: "Token account doesn't hold enough tokens for the order's deposit";
+    },
+    {
+      code: 6089;
+      name: 'BookFull';
+      msg: "Book side is full and the order isn't better than its worst order";
+    },
+    {
+      code: 6090;
+      name: 'InvalidEventType';
+      msg: 'Event heap contains an event of unknown type';
+    },
+    {
+      code: 6091;
+      name: 'InvalidEventHeapSlot';
+      msg: 'Event heap slot is out of range or empty';
+    },
+    {
+      code: 6092;
+      name: 'InvalidInputOracleExponent';
+      msg: 'Oracle exponent should be between -12 and 12';
+    },
+    {
+      code: 6093;
+      name: 'MissingRiskProgramAccount';
+      msg: 'Risk program of the open orders account missing from the remaining accounts';
+    },
+    {
+      code: 6094;
+      name: 'InvalidRiskProgram';
+      msg: 'Invalid risk program';
+    },
+    {
+      code: 6095;
+      name: 'InvalidSettleReceiverProgram';
+      msg: 'Invalid settle receiver program';
+    },
+    {
+      code: 6096;
+      name: 'MissingSettleReceiverAccount';
+      msg: 'Settle receiver of the open orders account missing from the remaining accounts';
+    },
+    {
+      code: 6097;
+      name: 'InvalidSettleReceiverAuthority';
+      msg: 'Settle receiver authority missing or invalid';
+    },
+    {
+      code: 6098;
+      name: 'InvalidSettleReceiverAccount';
+      msg: "The market authority and vaults can't be passed to a settle receiver";
+    },
+    {
+      code: 6099;
+      name: 'TriggerConditionNotMet';
+      msg: 'Trigger order condition is not met';
+    },
+    {
+      code: 6100;
+      name: 'TriggerOrderExpired';
+      msg: 'Trigger order is already expired';
+    },
+    {
+      code: 6101;
+      name: 'InsufficientFreeFunds';
+      msg: "Free funds of the open orders account don't cover the order";
+    },
+    {
+      code: 6102;
+      name: 'ScheduledCancelNotDue';
+      msg: "No cancel is scheduled or its time hasn't come yet";
+    },
+    {
+      code: 6103;
+      name: 'HeartbeatNotEnabled';
+      msg: 'Heartbeat interval not set for the open orders account';
+    },
+    {
+      code: 6104;
+      name: 'InvalidSignedOrderSignature';
+      msg: "The previous instruction isn't an ed25519 signature of the signed order";
+    },
+    {
+      code: 6105;
+      name: 'InvalidSignedOrderSigner';
+      msg: 'Signed order signer is neither owner nor delegate';
+    },
+    {
+      code: 6106;
+      name: 'SignedOrderNonceUsed';
+      msg: 'Signed order nonce already used';
+    },
+    {
+      code: 6107;
+      name: 'SignedOrderExpired';
+      msg: 'Signed order is no longer valid';
+    },
+    {
+      code: 6108;
+      name: 'DmmRegistryFull';
+      msg: 'All designated market maker slots of the market are taken';
+    },
+    {
+      code: 6109;
+      name: 'DmmOrderProtected';
+      msg: 'The worst order of the full book side belongs to a designated market maker of higher priority';
+    },
+    {
+      code: 6110;
+      name: 'NotADmm';
+      msg: 'Open orders account is not a designated market maker of the market';
+    },
+    {
+      code: 6111;
+      name: 'DmmObligationMet';
+      msg: 'Designated market maker met its quoting obligation';
+    },
+    {
+      code: 6112;
+      name: 'InvalidInputEvictionBand';
+      msg: 'Eviction band should be greater than zero';
+    },
+  ];
+};
+
+export const IDL: OpenbookV2 = {
+  version: '0.1.0',
+  name: 'openbook_v2',
+  instructions: [
+    {
+      name: 'createMarket',
+      docs: [
+        'Create a [`Market`](crate::state::Market) for a given token pair.',
+        '',
+        'The payer escrows a refundable [`MARKET_CREATION_BOND`](crate::state::MARKET_CREATION_BOND)',
+        'in the market account.',
+        '',
+        'Orders that would rest on the book with less than `min_order_notional` in quote',
+        'native units are rejected, see `set_min_order_notional`, and open orders accounts',
+        "can't have more than `max_resting_orders` orders on the book, see",
+        '`set_max_resting_orders`.',
+      ],
+      accounts: [
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'marketAuthority',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'bids',
+          isMut: true,
+          isSigner: false,
+          docs: [
+            'Accounts are initialized by client,',
+            'anchor discriminator is set first when ix exits,',
+          ],
+        },
+        {
+          name: 'asks',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'eventHeap',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'payer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'marketBaseVault',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'marketQuoteVault',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'baseMint',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'quoteMint',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'associatedTokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'oracleA',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'oracleB',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'collectFeeAdmin',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'openOrdersAdmin',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'consumeEventsAdmin',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'closeMarketAdmin',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'eventAuthority',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'program',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'name',
+          type: 'string',
+        },
+        {
+          name: 'oracleConfig',
+          type: {
+            defined: 'OracleConfigParams',
+          },
+        },
+        {
+          name: 'quoteLotSize',
+          type: 'i64',
+        },
+        {
+          name: 'baseLotSize',
+          type: 'i64',
+        },
+        {
+          name: 'makerFee',
+          type: 'i64',
+        },
+        {
+          name: 'takerFee',
+          type: 'i64',
+        },
+        {
+          name: 'timeExpiry',
+          type: 'i64',
+        },
+        {
+          name: 'minOrderNotional',
+          type: 'u64',
+        },
+        {
+          name: 'maxRestingOrders',
+          type: 'u8',
+        },
+      ],
+    },
+    {
+      name: 'createCanonicalMarket',
+      docs: [
+        'Create the canonical [`Market`](crate::state::Market) of a token pair and',
+        'lot sizes.',
+        '',
+        'The market address is a PDA of `["CanonicalMarket", base_mint, quote_mint,',
+        'base_lot_size, quote_lot_size]` (lot sizes as little endian bytes), so there',
+        'is at most one per configuration and clients can derive it. Canonical',
+        "markets can't have an open orders admin.",
+      ],
+      accounts: [
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'marketAuthority',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'bids',
+          isMut: true,
+          isSigner: false,
+          docs: [
+            'Accounts are initialized by client,',
+            'anchor discriminator is set first when ix exits,',
+          ],
+        },
+        {
+          name: 'asks',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'eventHeap',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'payer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'marketBaseVault',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'marketQuoteVault',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'baseMint',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'quoteMint',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'associatedTokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'oracleA',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'oracleB',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'collectFeeAdmin',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'openOrdersAdmin',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'consumeEventsAdmin',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'closeMarketAdmin',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'eventAuthority',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'program',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'name',
+          type: 'string',
+        },
+        {
+          name: 'oracleConfig',
+          type: {
+            defined: 'OracleConfigParams',
+          },
+        },
+        {
+          name: 'quoteLotSize',
+          type: 'i64',
+        },
+        {
+          name: 'baseLotSize',
+          type: 'i64',
+        },
+        {
+          name: 'makerFee',
+          type: 'i64',
+        },
+        {
+          name: 'takerFee',
+          type: 'i64',
+        },
+        {
+          name: 'timeExpiry',
+          type: 'i64',
+        },
+        {
+          name: 'minOrderNotional',
+          type: 'u64',
+        },
+        {
+          name: 'maxRestingOrders',
+          type: 'u8',
+        },
+      ],
+    },
+    {
+      name: 'slashMarketBond',
+      docs: [
+        "Move a [`Market`](crate::state::Market)'s creation bond to `destination`,",
+        "as the program's upgrade authority.",
+        '',
+        'Every market escrows [`MARKET_CREATION_BOND`](crate::state::MARKET_CREATION_BOND)',
+        'lamports when created, returned by `close_market` unless slashed.',
+      ],
+      accounts: [
+        {
+          name: 'governance',
+          isMut: false,
+          isSigner: true,
+          docs: ["The program's upgrade authority"],
+        },
+        {
+          name: 'program',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'programData',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'destination',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'createMerkleAllowlist',
+      docs: [
+        'Create the [`MerkleAllowlist`](crate::state::MerkleAllowlist) of a',
+        'permissioned market, as its open orders admin.',
+      ],
+      accounts: [
+        {
+          name: 'openOrdersAdmin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'payer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'market',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'merkleAllowlist',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'root',
+          type: {
+            array: ['u8', 32],
+          },
+        },
+      ],
+    },
+    {
+      name: 'setMerkleAllowlistRoot',
+      docs: [
+        "Replace the root of a market's [`MerkleAllowlist`](crate::state::MerkleAllowlist),",
+        'as its open orders admin.',
+        '',
+        'Open orders accounts that already joined stay allowed until revoked.',
+      ],
+      accounts: [
+        {
+          name: 'openOrdersAdmin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'market',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'merkleAllowlist',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'root',
+          type: {
+            array: ['u8', 32],
+          },
+        },
+      ],
+    },
+    {
+      name: 'joinMerkleAllowlist',
+      docs: [
+        'Prove the owner of an [`OpenOrdersAccount`](crate::state::OpenOrdersAccount)',
+        "is in the market's [`MerkleAllowlist`](crate::state::MerkleAllowlist).",
+        '',
+        'The account can then place orders without the open orders admin signature.',
+      ],
+      accounts: [
+        {
+          name: 'owner',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'openOrdersAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'market',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'merkleAllowlist',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'proof',
+          type: {
+            vec: {
+              array: ['u8', 32],
+            },
+          },
+        },
+      ],
+    },
+    {
+      name: 'revokeMerkleAllowlistApproval',
+      docs: [
+        'Require the open orders admin signature again for an',
+        '[`OpenOrdersAccount`](crate::state::OpenOrdersAccount) that joined the',
+        "market's allowlist, as its open orders admin.",
+      ],
+      accounts: [
+        {
+          name: 'openOrdersAdmin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'market',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'openOrdersAccount',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'createTokenGate',
+      docs: [
+        'Create the [`TokenGate`](crate::state::TokenGate) of a permissioned market,',
+        'as its open orders admin.',
+        '',
+        'Orders can then be placed without the open orders admin signature by',
+        'passing the gate and a token account of the owner holding at least',
+        '`min_amount` native units of `mint`. `min_amount` is at least 1.',
+      ],
+      accounts: [
+        {
+          name: 'openOrdersAdmin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'payer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'market',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'mint',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'tokenGate',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'minAmount',
+          type: 'u64',
+        },
+      ],
+    },
+    {
+      name: 'createTakerProgramAllowlist',
+      docs: [
+        'Create the [`TakerProgramAllowlist`](crate::state::TakerProgramAllowlist)',
+        'of a permissioned market, as its open orders admin.',
+      ],
+      accounts: [
+        {
+          name: 'openOrdersAdmin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'payer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'market',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'takerProgramAllowlist',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'programs',
+          type: {
+            vec: 'publicKey',
+          },
+        },
+      ],
+    },
+    {
+      name: 'setTakerProgramAllowlist',
+      docs: [
+        "Replace the programs of a market's",
+        '[`TakerProgramAllowlist`](crate::state::TakerProgramAllowlist), as its open',
+        'orders admin.',
+      ],
+      accounts: [
+        {
+          name: 'openOrdersAdmin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'market',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'takerProgramAllowlist',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'programs',
+          type: {
+            vec: 'publicKey',
+          },
+        },
+      ],
+    },
+    {
+      name: 'createMarketRegistryPage',
+      docs: [
+        'Create a page of the market registry, see',
+        '[`MarketRegistryPage`](crate::state::MarketRegistryPage).',
+        '',
+        'Anyone can pay for it. Every page but the first one requires the previous',
+        'page to be full.',
+      ],
+      accounts: [
+        {
+          name: 'payer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'previousPage',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+          docs: ['Required for every page but the first one.'],
+        },
+        {
+          name: 'registryPage',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'pageIndex',
+          type: 'u32',
+        },
+      ],
+    },
+    {
+      name: 'registerMarket',
+      docs: [
+        'Add a [`Market`](crate::state::Market) to the market registry.',
+        '',
+        'Permissionless, each market can only be registered once. Clients usually',
+        'do it in the same transaction as `create_market`.',
+      ],
+      accounts: [
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'registryPage',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'closeMarket',
+      docs: [
+        'Close a [`Market`](crate::state::Market) (only',
+        '[`close_market_admin`](crate::state::Market::close_market_admin)).',
+        '',
+        'Sends the dust left in the vaults to the collect fee admin, and the rent',
+        'of the market, its book, event heap and vaults to `sol_destination`.',
+      ],
+      accounts: [
+        {
+          name: 'closeMarketAdmin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'marketAuthority',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'bids',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'asks',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'eventHeap',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'marketBaseVault',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'marketQuoteVault',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'baseDustReceiver',
+          isMut: true,
+          isSigner: false,
+          docs: [
+            "Collect fee admin's account receiving what's left in the base vault",
+          ],
+        },
+        {
+          name: 'quoteDustReceiver',
+          isMut: true,
+          isSigner: false,
+          docs: [
+            "Collect fee admin's account receiving what's left in the quote vault",
+          ],
+        },
+        {
+          name: 'solDestination',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'createOpenOrdersIndexer',
+      docs: [
+        'Create an [`OpenOrdersIndexer`](crate::state::OpenOrdersIndexer) account.',
+      ],
+      accounts: [
+        {
+          name: 'payer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'owner',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'openOrdersIndexer',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'closeOpenOrdersIndexer',
+      docs: [
+        'Close an [`OpenOrdersIndexer`](crate::state::OpenOrdersIndexer) account.',
+      ],
+      accounts: [
+        {
+          name: 'owner',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'openOrdersIndexer',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'solDestination',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'createOpenOrdersAccount',
+      docs: [
+        'Create an [`OpenOrdersAccount`](crate::state::OpenOrdersAccount).',
+      ],
+      accounts: [
+        {
+          name: 'payer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'owner',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'delegateAccount',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'openOrdersIndexer',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'openOrdersAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'market',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'name',
+          type: 'string',
+        },
+      ],
+    },
+    {
+      name: 'closeOpenOrdersAccount',
+      docs: [
+        'Close an [`OpenOrdersAccount`](crate::state::OpenOrdersAccount).',
+      ],
+      accounts: [
+        {
+          name: 'owner',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'openOrdersIndexer',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'openOrdersAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'solDestination',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'createPositionExtension',
+      docs: [
+        'Create the [`PositionExtension`](crate::state::PositionExtension) of an',
+        '[`OpenOrdersAccount`](crate::state::OpenOrdersAccount), tracking its net',
+        'position, cost basis and realized PnL from then on.',
+        '',
+        'The extension has to be passed among the remaining accounts of every order',
+        'the account places afterwards and of every instruction executing its fills,',
+        'like its open orders account: orders fail with `MissingPositionExtension` and',
+        'the fills of makers stay on the event heap without it.',
+      ],
+      accounts: [
+        {
+          name: 'owner',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'payer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'openOrdersAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'positionExtension',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'closePositionExtension',
+      docs: [
+        'Close the [`PositionExtension`](crate::state::PositionExtension) of an',
+        '[`OpenOrdersAccount`](crate::state::OpenOrdersAccount). Required before',
+        'closing the account itself.',
+      ],
+      accounts: [
+        {
+          name: 'owner',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'openOrdersAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'positionExtension',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'solDestination',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'placeOrder',
+      docs: [
+        'Place an order.',
+        '',
+        'Different types of orders have different effects on the order book,',
+        'as described in [`PlaceOrderType`](crate::state::PlaceOrderType).',
+        '',
+        '`price_lots` refers to the price in lots: the number of quote lots',
+        'per base lot. It is ignored for `PlaceOrderType::Market` orders.',
+        '',
+        '`expiry_timestamp` is a unix timestamp for when this order should',
+        'expire. If 0 is passed in, the order will never expire. If the time',
+        'is in the past, the instruction is skipped. Timestamps in the future',
+        'are reduced to now + 65,535s.',
+        '',
+        '`limit` determines the maximum number of orders from the book to fill,',
+        'and can be used to limit CU spent. When the limit is reached, processing',
+        'stops and the instruction succeeds.',
+        '',
+        'Makers whose open orders account is among the remaining accounts get their',
+        'fills applied right away. If they enabled `set_direct_settlement` and a token',
+        'account of theirs for the token the taker pays with is passed too, their',
+        'proceeds are transferred to it directly, see',
+        '[`maker_settlement`](crate::maker_settlement).',
+        '',
+        'The signer, the owner or a delegate such as a session key, pays for',
+        "nothing and isn't writable, so a relayer can pay the transaction fee, see",
+        '`place_order_relayed`.',
+      ],
+      accounts: [
+        {
+          name: 'signer',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'openOrdersAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'openOrdersAdmin',
+          isMut: false,
+          isSigner: true,
+          isOptional: true,
+        },
+        {
+          name: 'userTokenAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'bids',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'asks',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'eventHeap',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'marketVault',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'oracleA',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'oracleB',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'tokenGate',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'gateTokenAccount',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'eventAuthority',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'program',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'args',
+          type: {
+            defined: 'PlaceOrderArgs',
+          },
+        },
+      ],
+      returns: {
+        option: 'u128',
+      },
+    },
+    {
+      name: 'placeOrderRelayed',
+      docs: [
+        'Place an order like `place_order` in a transaction whose fee is paid by',
+        "`relayer`, who gets `relayer_fee` native tokens of the signer's token",
+        'account in exchange.',
+        '',
+        'The signer has to be the owner or delegate of the open orders account, the',
+        "relayer signing doesn't give it any say over the account.",
+      ],
+      accounts: [
+        {
+          name: 'signer',
+          isMut: false,
+          isSigner: true,
+          docs: [
+            'Owner or delegate of the open orders account, e.g. a session key',
+          ],
+        },
+        {
+          name: 'relayer',
+          isMut: false,
+          isSigner: true,
+          docs: [
+            'Pays the transaction fee, receiving `relayer_fee` in exchange',
+          ],
+        },
+        {
+          name: 'relayerTokenAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'openOrdersAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'openOrdersAdmin',
+          isMut: false,
+          isSigner: true,
+          isOptional: true,
+        },
+        {
+          name: 'userTokenAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'bids',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'asks',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'eventHeap',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'marketVault',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'oracleA',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'oracleB',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'tokenGate',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'gateTokenAccount',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'eventAuthority',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'program',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'args',
+          type: {
+            defined: 'PlaceOrderArgs',
+          },
+        },
+        {
+          name: 'relayerFee',
+          type: 'u64',
+        },
+      ],
+      returns: {
+        option: 'u128',
+      },
+    },
+    {
+      name: 'editOrder',
+      docs: ['Edit an order.'],
+      accounts: [
+        {
+          name: 'signer',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'openOrdersAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'openOrdersAdmin',
+          isMut: false,
+          isSigner: true,
+          isOptional: true,
+        },
+        {
+          name: 'userTokenAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'bids',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'asks',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'eventHeap',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'marketVault',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'oracleA',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'oracleB',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'tokenGate',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'gateTokenAccount',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'eventAuthority',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'program',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'clientOrderId',
+          type: 'u64',
+        },
+        {
+          name: 'expectedCancelSize',
+          type: 'i64',
+        },
+        {
+          name: 'placeOrder',
+          type: {
+            defined: 'PlaceOrderArgs',
+          },
+        },
+      ],
+      returns: {
+        option: 'u128',
+      },
+    },
+    {
+      name: 'editOrderPegged',
+      docs: ['Edit an order pegged.'],
+      accounts: [
+        {
+          name: 'signer',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'openOrdersAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'openOrdersAdmin',
+          isMut: false,
+          isSigner: true,
+          isOptional: true,
+        },
+        {
+          name: 'userTokenAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'bids',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'asks',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'eventHeap',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'marketVault',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'oracleA',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'oracleB',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'tokenGate',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'gateTokenAccount',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'eventAuthority',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'program',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'clientOrderId',
+          type: 'u64',
+        },
+        {
+          name: 'expectedCancelSize',
+          type: 'i64',
+        },
+        {
+          name: 'placeOrder',
+          type: {
+            defined: 'PlaceOrderPeggedArgs',
+          },
+        },
+      ],
+      returns: {
+        option: 'u128',
+      },
+    },
+    {
+      name: 'placeOrders',
+      docs: ['Place multiple orders'],
+      accounts: [
+        {
+          name: 'signer',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'openOrdersAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'openOrdersAdmin',
+          isMut: false,
+          isSigner: true,
+          isOptional: true,
+        },
+        {
+          name: 'userQuoteAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'userBaseAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'bids',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'asks',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'eventHeap',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'marketQuoteVault',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'marketBaseVault',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'oracleA',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'oracleB',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'tokenGate',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'gateTokenAccount',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'eventAuthority',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'program',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'ordersType',
+          type: {
+            defined: 'PlaceOrderType',
+          },
+        },
+        {
+          name: 'bids',
+          type: {
+            vec: {
+              defined: 'PlaceMultipleOrdersArgs',
+            },
+          },
+        },
+        {
+          name: 'asks',
+          type: {
+            vec: {
+              defined: 'PlaceMultipleOrdersArgs',
+            },
+          },
+        },
+        {
+          name: 'limit',
+          type: 'u8',
+        },
+      ],
+      returns: {
+        vec: {
+          option: 'u128',
+        },
+      },
+    },
+    {
+      name: 'cancelAllAndPlaceOrders',
+      docs: ['Cancel orders and place multiple orders.'],
+      accounts: [
+        {
+          name: 'signer',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'openOrdersAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'openOrdersAdmin',
+          isMut: false,
+          isSigner: true,
+          isOptional: true,
+        },
+        {
+          name: 'userQuoteAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'userBaseAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'bids',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'asks',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'eventHeap',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'marketQuoteVault',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'marketBaseVault',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'oracleA',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'oracleB',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'tokenGate',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'gateTokenAccount',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'eventAuthority',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'program',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'ordersType',
+          type: {
+            defined: 'PlaceOrderType',
+          },
+        },
+        {
+          name: 'bids',
+          type: {
+            vec: {
+              defined: 'PlaceMultipleOrdersArgs',
+            },
+          },
+        },
+        {
+          name: 'asks',
+          type: {
+            vec: {
+              defined: 'PlaceMultipleOrdersArgs',
+            },
+          },
+        },
+        {
+          name: 'limit',
+          type: 'u8',
+        },
+      ],
+      returns: {
+        vec: {
+          option: 'u128',
+        },
+      },
+    },
+    {
+      name: 'placeOrderPegged',
+      docs: ['Place an oracle-peg order.'],
+      accounts: [
+        {
+          name: 'signer',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'openOrdersAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'openOrdersAdmin',
+          isMut: false,
+          isSigner: true,
+          isOptional: true,
+        },
+        {
+          name: 'userTokenAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'bids',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'asks',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'eventHeap',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'marketVault',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'oracleA',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'oracleB',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'tokenGate',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'gateTokenAccount',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'eventAuthority',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'program',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'args',
+          type: {
+            defined: 'PlaceOrderPeggedArgs',
+          },
+        },
+      ],
+      returns: {
+        option: 'u128',
+      },
+    },
+    {
+      name: 'placeTakeOrder',
+      docs: [
+        'Place an order that shall take existing liquidity off of the book, not',
+        'add a new order off the book.',
+        '',
+        'This type of order allows for instant token settlement for the taker.',
+        '',
+        "On permissioned markets, programs in the market's",
+        '[`TakerProgramAllowlist`](crate::state::TakerProgramAllowlist) can call it',
+        'through CPI without the open orders admin signature.',
+        '',
+        'Makers can be settled directly through the remaining accounts as with',
+        '`place_order`.',
+      ],
+      accounts: [
+        {
+          name: 'signer',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'penaltyPayer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'marketAuthority',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'bids',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'asks',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'marketBaseVault',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'marketQuoteVault',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'eventHeap',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'userBaseAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'userQuoteAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'oracleA',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'oracleB',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'openOrdersAdmin',
+          isMut: false,
+          isSigner: true,
+          isOptional: true,
+        },
+        {
+          name: 'takerProgramAllowlist',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'instructionsSysvar',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'eventAuthority',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'program',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'args',
+          type: {
+            defined: 'PlaceTakeOrderArgs',
+          },
+        },
+      ],
+    },
+    {
+      name: 'placeOrderAndSettle',
+      docs: [
+        'Place an order of an open orders account that only takes liquidity, and',
+        "transfer what it receives straight to the user's token accounts.",
+        '',
+        "Like with `place_take_order` the taker doesn't wait for `consume_events` nor",
+        "calls `settle_funds`, the event heap only keeps the makers' side of the fills.",
+        "Unlike it, the fills count towards the account's volume and referrer rebates,",
+        "and `self_trade_behavior` applies. Other free funds of the account aren't",
+        'touched.',
+        '',
+        'Only `Market`, `ImmediateOrCancel` and `FillOrKill` orders are accepted,',
+        '`expiry_timestamp` is ignored.',
+      ],
+      accounts: [
+        {
+          name: 'signer',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'openOrdersAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'openOrdersAdmin',
+          isMut: false,
+          isSigner: true,
+          isOptional: true,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'marketAuthority',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'bids',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'asks',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'eventHeap',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'marketBaseVault',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'marketQuoteVault',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'userBaseAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'userQuoteAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'oracleA',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'oracleB',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'tokenGate',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'gateTokenAccount',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'eventAuthority',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'program',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'args',
+          type: {
+            defined: 'PlaceOrderArgs',
+          },
+        },
+      ],
+    },
+    {
+      name: 'createTriggerOrder',
+      docs: [
+        'Create a [`TriggerOrder`](crate::state::TriggerOrder), placing `order` for an',
+        'open orders account once `condition` is met for `trigger_price_lots`.',
+        '',
+        '`trigger_id` is chosen by the caller and has to be unique per open orders',
+        "account. The order is only funded from the account's free funds when it's",
+        'executed, and `expiry_timestamp` also ends the trigger.',
+      ],
+      accounts: [
+        {
+          name: 'payer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'signer',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'openOrdersAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'market',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'triggerOrder',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'triggerId',
+          type: 'u64',
+        },
+        {
+          name: 'condition',
+          type: {
+            defined: 'TriggerCondition',
+          },
+        },
+        {
+          name: 'triggerPriceLots',
+          type: 'i64',
+        },
+        {
+          name: 'order',
+          type: {
+            defined: 'PlaceOrderArgs',
+          },
+        },
+      ],
+    },
+    {
+      name: 'cancelTriggerOrder',
+      docs: [
+        "Close a trigger order before it's executed, as the owner or delegate of its",
+        'open orders account.',
+      ],
+      accounts: [
+        {
+          name: 'signer',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'openOrdersAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'triggerOrder',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'solDestination',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'executeTrigger',
+      docs: [
+        'Place the order of a trigger order whose condition is met, closing it.',
+        '',
+        "Anyone can call this and gets the trigger order's rent. Fails if the",
+        "condition isn't met or the free funds of the open orders account don't cover",
+        'the order. Returns the id of the order left on the book, if any. An expired',
+        'trigger order is closed without placing anything.',
+      ],
+      accounts: [
+        {
+          name: 'keeper',
+          isMut: true,
+          isSigner: true,
+          docs: ['Receives the rent of the trigger order'],
+        },
+        {
+          name: 'triggerOrder',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'openOrdersAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'openOrdersAdmin',
+          isMut: false,
+          isSigner: true,
+          isOptional: true,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'bids',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'asks',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'eventHeap',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'oracleA',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'oracleB',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'eventAuthority',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'program',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+      returns: {
+        option: 'u128',
+      },
+    },
+    {
+      name: 'placeSignedOrder',
+      docs: [
+        'Place an order signed off-chain by the owner or delegate of the open orders',
+        'account, on their behalf.',
+        '',
+        'The ed25519 signature of `signed_order` has to be verified by the',
+        'instruction right before this one, see',
+        '[`signed_order`](crate::signed_order). Anyone can submit it, each nonce only',
+        "once, kept in the account's position extension which has to be among the",
+        'remaining accounts. Like trigger orders the order is funded from the free',
+        'funds of the open orders account.',
+      ],
+      accounts: [
+        {
+          name: 'relayer',
+          isMut: false,
+          isSigner: true,
+          docs: [
+            "Submits the order signed by the owner or delegate, doesn't need to be",
+            'either',
+          ],
+        },
+        {
+          name: 'openOrdersAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'openOrdersAdmin',
+          isMut: false,
+          isSigner: true,
+          isOptional: true,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'bids',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'asks',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'eventHeap',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'oracleA',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'oracleB',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'instructionsSysvar',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'tokenGate',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'gateTokenAccount',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'eventAuthority',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'program',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'signedOrder',
+          type: {
+            defined: 'SignedOrder',
+          },
+        },
+      ],
+      returns: {
+        option: 'u128',
+      },
+    },
+    {
+      name: 'consumeEvents',
+      docs: [
+        'Process up to `limit` [events](crate::state::AnyEvent).',
+        '',
+        "When a user places a 'take' order, they do not know beforehand which",
+        "market maker will have placed the 'make' order that they get executed",
+        "against. This prevents them from passing in a market maker's",
+        '[`OpenOrdersAccount`](crate::state::OpenOrdersAccount), which is needed',
+        'to credit/debit the relevant tokens to/from the maker. As such, Openbook',
+        "uses a 'crank' system, where `place_order` only emits events, and",
+        '`consume_events` handles token settlement.',
+        '',
+        'Currently, there are two types of events: [`FillEvent`](crate::state::FillEvent)s',
+        'and [`OutEvent`](crate::state::OutEvent)s.',
+        '',
+        'A `FillEvent` is emitted when an order is filled, and it is handled by',
+        'debiting whatever the taker is selling from the taker and crediting',
+        'it to the maker, and debiting whatever the taker is buying from the',
+        'maker and crediting it to the taker. Note that *no tokens are moved*,',
+        "these are just debits and credits to each party's [`Position`](crate::state::Position).",
+        '',
+        'An `OutEvent` is emitted when a limit order needs to be removed from',
+        'the book during a `place_order` invocation, and it is handled by',
+        'crediting whatever the maker would have sold (quote token in a bid,',
+        'base token in an ask) back to the maker. Its `reason` tells whether the',
+        'order expired or was booted to make room for a better one.',
+        '',
+        'Fill events are processed before out events, so the fills queued behind the',
+        'out events of a mass cancel get processed first.',
+        '',
+        'The open orders accounts of the events go in the remaining accounts, in',
+        "any order. Events whose account is missing stay on the heap, don't count",
+        'towards `limit` and are reported in a',
+        '[`ConsumeEventsSkippedLog`](crate::logs::ConsumeEventsSkippedLog).',
+      ],
+      accounts: [
+        {
+          name: 'consumeEventsAdmin',
+          isMut: false,
+          isSigner: true,
+          isOptional: true,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'eventHeap',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'limit',
+          type: 'u64',
+        },
+      ],
+    },
+    {
+      name: 'consumeGivenEvents',
+      docs: [
+        'Process the [events](crate::state::AnyEvent) at the given positions.',
+      ],
+      accounts: [
+        {
+          name: 'consumeEventsAdmin',
+          isMut: false,
+          isSigner: true,
+          isOptional: true,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'eventHeap',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'slots',
+          type: {
+            vec: 'u64',
+          },
+        },
+      ],
+    },
+    {
+      name: 'consumeEventsForAccounts',
+      docs: [
+        'Process up to `limit` [events](crate::state::AnyEvent) involving the',
+        '[`OpenOrdersAccount`](crate::state::OpenOrdersAccount)s passed as remaining',
+        'accounts, leaving all other events on the heap.',
+        '',
+        'Lets a market maker crank their own fills without waiting for, or paying',
+        "for, everybody else's events.",
+      ],
+      accounts: [
+        {
+          name: 'consumeEventsAdmin',
+          isMut: false,
+          isSigner: true,
+          isOptional: true,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'eventHeap',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'limit',
+          type: 'u64',
+        },
+      ],
+    },
+    {
+      name: 'consumeEventsMultiple',
+      docs: [
+        'Same as `consume_events`, but for several markets at once.',
+        '',
+        'Every market is described by its `market` and `event_heap` remaining',
+        'accounts, followed by the accounts `consume_events` would take as',
+        "remaining accounts: the groups' lengths and limits are in `groups`.",
+        'Markets with a `consume_events_admin` need it as signer.',
+      ],
+      accounts: [
+        {
+          name: 'consumeEventsAdmin',
+          isMut: false,
+          isSigner: true,
+          isOptional: true,
+          docs: [
+            'Needed for the markets with a `consume_events_admin`, which has to be this signer',
+          ],
+        },
+      ],
+      args: [
+        {
+          name: 'groups',
+          type: {
+            vec: {
+              defined: 'ConsumeEventsGroup',
+            },
+          },
+        },
+      ],
+    },
+    {
+      name: 'consumeEventsWithReward',
+      docs: [
+        "Same as `consume_events`, but pays the signing `cranker` the market's",
+        '[`consume_events_reward`](crate::state::Market::consume_events_reward)',
+        'for every processed event.',
+        '',
+        'Rewards are paid in lamports out of the event heap penalties the market',
+        'has collected, and are capped by what is available.',
+      ],
+      accounts: [
+        {
+          name: 'cranker',
+          isMut: true,
+          isSigner: true,
+          docs: ['Receives the reward for the consumed events'],
+        },
+        {
+          name: 'consumeEventsAdmin',
+          isMut: false,
+          isSigner: true,
+          isOptional: true,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'eventHeap',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'limit',
+          type: 'u64',
+        },
+      ],
+    },
+    {
+      name: 'consumeEventsWithFillCallback',
+      docs: [
+        "Same as `consume_events`, but then calls the market's",
+        '[fill callback](crate::fill_callback) program with the processed fills.',
+        '',
+        'The callback is only made here, so crankers can always fall back to',
+        '`consume_events` if it fails.',
+      ],
+      accounts: [
+        {
+          name: 'consumeEventsAdmin',
+          isMut: false,
+          isSigner: true,
+          isOptional: true,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'eventHeap',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'fillCallback',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'fillCallbackAuthority',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'fillCallbackProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'limit',
+          type: 'u64',
+        },
+      ],
+    },
+    {
+      name: 'createBookSidePage',
+      docs: [
+        "Chain a page after the market's bids or asks, for fixed price orders posted once",
+        "they're full. The existing pages of the side go in the remaining accounts, in",
+        'order. Anyone can pay for it, up to [`MAX_BOOK_SIDE_PAGES`](crate::state::MAX_BOOK_SIDE_PAGES) per side.',
+        '',
+        'Once a side has orders on its pages, instructions matching against that side',
+        'need all its pages in their remaining accounts, and cancelling an order on a',
+        'page needs the pages up to it.',
+      ],
+      accounts: [
+        {
+          name: 'market',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'bookSide',
+          isMut: true,
+          isSigner: false,
+          docs: ["The market's bids or asks"],
+        },
+        {
+          name: 'page',
+          isMut: true,
+          isSigner: false,
+          docs: [
+            'Account is initialized by client,',
+            'anchor discriminator is set first when ix exits,',
+          ],
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'defragmentBook',
+      docs: [
+        "Compact the nodes of the market's book sides, moving at most `limit` nodes",
+        'per side, and rebuild their free lists. Anyone can call it.',
+        '',
+        'After heavy order churn the live nodes are spread over the whole book side',
+        'account. Repeat until no more nodes are moved to fully compact a book.',
+      ],
+      accounts: [
+        {
+          name: 'market',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'bids',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'asks',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'limit',
+          type: 'u8',
+        },
+      ],
+    },
+    {
+      name: 'expireOrders',
+      docs: [
+        "Remove up to `limit` expired orders from each of the market's book sides.",
+        'Anyone can call it.',
+        '',
+        'Resting orders are grouped by expiry in',
+        '[`ExpiryBucket`](crate::state::ExpiryBucket)s, so only the orders of the due',
+        'buckets are looked for, earliest expiry first. Their out events are applied',
+        'to the open orders accounts passed as remaining accounts and queued for the',
+        'others.',
+      ],
+      accounts: [
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'bids',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'asks',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'eventHeap',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'limit',
+          type: 'u8',
+        },
+      ],
+    },
+    {
+      name: 'cancelOrder',
+      docs: [
+        'Cancel an order by its `order_id`.',
+        '',
+        "Note that this doesn't emit an [`OutEvent`](crate::state::OutEvent) because a",
+        'maker knows that they will be passing in their own [`OpenOrdersAccount`](crate::state::OpenOrdersAccount).',
+      ],
+      accounts: [
+        {
+          name: 'signer',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'openOrdersAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'bids',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'asks',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'orderId',
+          type: 'u128',
+        },
+      ],
+    },
+    {
+      name: 'cancelOrderByClientOrderId',
+      docs: [
+        'Cancel an order by its `client_order_id`.',
+        '',
+        "Note that this doesn't emit an [`OutEvent`](crate::state::OutEvent) because a",
+        'maker knows that they will be passing in their own [`OpenOrdersAccount`](crate::state::OpenOrdersAccount).',
+      ],
+      accounts: [
+        {
+          name: 'signer',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'openOrdersAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'bids',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'asks',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'clientOrderId',
+          type: 'u64',
+        },
+      ],
+      returns: 'i64',
+    },
+    {
+      name: 'findOrder',
+      docs: [
+        'Look up the order with `client_order_id` of an open orders account, returned',
+        "as the instruction's return data. `None` if the account has no such order.",
+        '',
+        'Lets a bot that lost track of its orders recover their ids, e.g. by simulating',
+        'this instruction. Orders on book side pages are only found with the pages passed',
+        'as remaining accounts, their quantity is 0 otherwise.',
+      ],
+      accounts: [
+        {
+          name: 'openOrdersAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'market',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'bids',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'asks',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'clientOrderId',
+          type: 'u64',
+        },
+      ],
+      returns: {
+        option: {
+          defined: 'OrderInfo',
+        },
+      },
+    },
+    {
+      name: 'getMarketQuote',
+      docs: [
+        'Top of the book of a market along with its oracle price, returned as the',
+        "instruction's return data, see [`MarketQuote`].",
+        '',
+        "Read-only, meant for programs pricing assets against the market's liquidity",
+        'through CPI. Oracle pegged orders are included when the oracle is usable.',
+      ],
+      accounts: [
+        {
+          name: 'market',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'bids',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'asks',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'oracleA',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'oracleB',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [],
+      returns: {
+        defined: 'MarketQuote',
+      },
+    },
+    {
+      name: 'snapshotDepth',
+      docs: [
+        'Write the top `levels` aggregated price levels of each side of the book into',
+        'a [`DepthSnapshot`](crate::state::DepthSnapshot), at most',
+        '[`DEPTH_SNAPSHOT_LEVELS`](crate::state::DEPTH_SNAPSHOT_LEVELS).',
+        '',
+        'Anyone can refresh a snapshot. Oracle pegged orders are included when the',
+        'oracle is usable.',
+      ],
+      accounts: [
+        {
+          name: 'depthSnapshot',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'market',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'bids',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'asks',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'oracleA',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'oracleB',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [
+        {
+          name: 'levels',
+          type: 'u8',
+        },
+      ],
+    },
+    {
+      name: 'cancelAllOrders',
+      docs: ['Cancel up to `limit` orders, optionally filtering by side'],
+      accounts: [
+        {
+          name: 'signer',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'openOrdersAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'bids',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'asks',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'sideOption',
+          type: {
+            option: {
+              defined: 'Side',
+            },
+          },
+        },
+        {
+          name: 'limit',
+          type: 'u8',
+        },
+      ],
+    },
+    {
+      name: 'scheduleCancelAll',
+      docs: [
+        'Let anyone cancel all orders of an open orders account once `cancel_after_ts`',
+        'has passed, as its owner or delegate. Pass 0 to unschedule. The schedule is',
+        "kept in the account's position extension.",
+        '',
+        "A dead man's switch for makers: a bot pushes the time out while it's alive,",
+        'a keeper or automation service cancels its orders when it stops doing so.',
+      ],
+      accounts: [
+        {
+          name: 'signer',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'openOrdersAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'positionExtension',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'cancelAfterTs',
+          type: 'u64',
+        },
+      ],
+    },
+    {
+      name: 'executeScheduledCancel',
+      docs: [
+        'Cancel up to `limit` orders of an open orders account whose scheduled',
+        'cancel is due, see `schedule_cancel_all`. Permissionless.',
+        '',
+        'The schedule is cleared once no orders are left. The call cancelling the',
+        "last orders gets the account's heartbeat bounty, see `set_heartbeat_interval`.",
+      ],
+      accounts: [
+        {
+          name: 'keeper',
+          isMut: true,
+          isSigner: true,
+          docs: ['Receives the heartbeat bounty, if any'],
+        },
+        {
+          name: 'openOrdersAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'positionExtension',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'bids',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'asks',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'limit',
+          type: 'u8',
+        },
+      ],
+    },
+    {
+      name: 'setHeartbeatInterval',
+      docs: [
+        'Make `heartbeat` calls keep the orders of an open orders account alive, as',
+        'its owner or delegate. Pass an `interval` of 0 to disable it.',
+        '',
+        'Once `interval` seconds pass without a heartbeat, anyone can cancel the',
+        "account's orders with `execute_scheduled_cancel` and earn `bounty` lamports,",
+        "escrowed in the account's position extension by the signer. A previous",
+        'bounty is returned to the owner. Starts the first interval right away.',
+      ],
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+          docs: ['Pays the bounty'],
+        },
+        {
+          name: 'owner',
+          isMut: true,
+          isSigner: false,
+          docs: ['Gets the previous bounty back'],
+        },
+        {
+          name: 'openOrdersAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'positionExtension',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'interval',
+          type: 'u32',
+        },
+        {
+          name: 'bounty',
+          type: 'u64',
+        },
+      ],
+    },
+    {
+      name: 'heartbeat',
+      docs: [
+        'Push the scheduled cancel of an open orders account out by its heartbeat',
+        'interval, see `set_heartbeat_interval`.',
+      ],
+      accounts: [
+        {
+          name: 'signer',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'openOrdersAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'positionExtension',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'deposit',
+      docs: [
+        "Deposit a certain amount of `base` and `quote` lamports into one's",
+        '[`Position`](crate::state::Position).',
+        '',
+        'Makers might wish to `deposit`, rather than have actual tokens moved for',
+        'each trade, in order to reduce CUs.',
+      ],
+      accounts: [
+        {
+          name: 'owner',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'userBaseAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'userQuoteAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'openOrdersAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'marketBaseVault',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'marketQuoteVault',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'baseAmount',
+          type: 'u64',
+        },
+        {
+          name: 'quoteAmount',
+          type: 'u64',
+        },
+      ],
+    },
+    {
+      name: 'refill',
+      docs: [
+        'Refill a certain amount of `base` and `quote` lamports. The amount being passed is the',
+        'total lamports that the [`Position`](crate::state::Position) will have.',
+        '',
+        'Makers might wish to `refill`, rather than have actual tokens moved for',
+        'each trade, in order to reduce CUs.',
+      ],
+      accounts: [
+        {
+          name: 'owner',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'userBaseAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'userQuoteAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'openOrdersAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'marketBaseVault',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'marketQuoteVault',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'baseAmount',
+          type: 'u64',
+        },
+        {
+          name: 'quoteAmount',
+          type: 'u64',
+        },
+      ],
+    },
+    {
+      name: 'settleFunds',
+      docs: [
+        'Withdraw any available tokens.',
+        '',
+        "The owner can send them to any token accounts, e.g. a prime broker's or",
+        "another wallet of the same firm, the delegate only to the owner's.",
+        '',
+        'Accounts with a position extension need it among the remaining accounts.',
+        'When it has a settle receiver, the funds are routed into it, see',
+        '[`settle_receiver`](crate::settle_receiver).',
+        '',
+        'Event heap penalties are paid by `penalty_payer`, which can be the relayer',
+        'paying the transaction fee rather than the owner.',
+      ],
+      accounts: [
+        {
+          name: 'owner',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'penaltyPayer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'openOrdersAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'marketAuthority',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'marketBaseVault',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'marketQuoteVault',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'userBaseAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'userQuoteAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'referrerAccount',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'claimRebates',
+      docs: [
+        'Withdraw only the maker and referrer rebates of an open orders account.',
+        '',
+        'The owner can send them to any quote token account, the delegate only to the',
+        "owner's. Maker rebates are paid up to the free quote left, `settle_funds` still",
+        "pays out whatever wasn't claimed.",
+      ],
+      accounts: [
+        {
+          name: 'owner',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'openOrdersAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'marketAuthority',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'marketQuoteVault',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'rebateReceiverAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'settleFundsExpired',
+      docs: [
+        'Withdraw any available tokens when the market is expired (only',
+        '[`close_market_admin`](crate::state::Market::close_market_admin)).',
+        '',
+        "Tokens go to the owner's accounts, or to any accounts if the owner signs too.",
+      ],
+      accounts: [
+        {
+          name: 'closeMarketAdmin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'owner',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'penaltyPayer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'openOrdersAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'marketAuthority',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'marketBaseVault',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'marketQuoteVault',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'userBaseAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'userQuoteAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'referrerAccount',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'settleFundsMultiple',
+      docs: [
+        'Withdraw any available tokens from several markets at once.',
+        '',
+        'Every market is described by seven remaining accounts, in order:',
+        '`open_orders_account`, `market`, `market_authority`, `market_base_vault`,',
+        '`market_quote_vault`, `user_base_account` and `user_quote_account`.',
+        "Referrer rebates are not paid out and go to the market's fees instead.",
+      ],
+      accounts: [
+        {
+          name: 'owner',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'penaltyPayer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'resizeEventHeap',
+      docs: [
+        'Grow the [`EventHeap`](crate::state::EventHeap) by `additional_nodes`, as a',
+        "[`Market`](crate::state::Market)'s admin.",
+        '',
+        'The new nodes are added to the free list right away. Solana limits how',
+        'much an account can grow per instruction, so large resizes need to be',
+        'split over several instructions.',
+      ],
+      accounts: [
+        {
+          name: 'collectFeeAdmin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'payer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'eventHeap',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'additionalNodes',
+          type: 'u16',
+        },
+      ],
+    },
+    {
+      name: 'migrateAccount',
+      docs: [
+        'Upgrade a [`Market`](crate::state::Market) or',
+        '[`OpenOrdersAccount`](crate::state::OpenOrdersAccount) created by an older',
+        'version of the program to the current version.',
+        '',
+        'Layout changes only use reserved space, so accounts keep their size and the',
+        'new fields start zeroed. Version 0 open orders accounts need to cancel their',
+        "bids first, as their `bids_quote_lots` wasn't tracked. Anyone can call it,",
+        "it's a no-op for accounts that are already up to date.",
+      ],
+      accounts: [
+        {
+          name: 'account',
+          isMut: true,
+          isSigner: false,
+          docs: ['instruction.'],
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'setConsumeEventsReward',
+      docs: [
+        'Set the lamports paid per consumed event by `consume_events_with_reward`,',
+        "as a [`Market`](crate::state::Market)'s admin.",
+      ],
+      accounts: [
+        {
+          name: 'collectFeeAdmin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'rewardLamports',
+          type: 'u64',
+        },
+      ],
+    },
+    {
+      name: 'setMaxRestingOrders',
+      docs: [
+        'Cap the resting orders each open orders account can have on a',
+        '[`Market`](crate::state::Market), as its admin, to share the book space',
+        'fairly. 0 removes the cap.',
+        '',
+        "Accounts above a new cap keep their orders but can't post new ones.",
+      ],
+      accounts: [
+        {
+          name: 'collectFeeAdmin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'maxRestingOrders',
+          type: 'u8',
+        },
+      ],
+    },
+    {
+      name: 'setMinOrderNotional',
+      docs: [
+        'Set the smallest quote native value new orders can rest on the book with, as',
+        "a [`Market`](crate::state::Market)'s admin. 0 removes the minimum.",
+        '',
+        "Fills don't count towards it: the remainder of a partially filled order below",
+        "the minimum isn't posted. Resting orders below a new minimum stay on the book.",
+      ],
+      accounts: [
+        {
+          name: 'collectFeeAdmin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'minOrderNotional',
+          type: 'u64',
+        },
+      ],
+    },
+    {
+      name: 'setOrderRateLimit',
+      docs: [
+        'Limit how many new orders each open orders account can place per window of',
+        "`window_seconds`, as a [`Market`](crate::state::Market)'s admin.",
+        '',
+        'Protects the book and event heap of permissionless markets from spam. Orders',
+        "placed without an open orders account, with `place_take_order`, aren't limited.",
+        'A `max_orders` of 0 removes the limit.',
+        '',
+        'The limit is tracked in the [`PositionExtension`](crate::state::PositionExtension)',
+        'of each account, accounts need one to place orders while the market is limited.',
+      ],
+      accounts: [
+        {
+          name: 'collectFeeAdmin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'maxOrders',
+          type: 'u32',
+        },
+        {
+          name: 'windowSeconds',
+          type: 'u32',
+        },
+      ],
+    },
+    {
+      name: 'setEvictionPolicy',
+      docs: [
+        'Choose which order is booted when a new order is posted to a full book side,',
+        "as a [`Market`](crate::state::Market)'s admin, see",
+        '[`EvictionPolicy`](crate::state::EvictionPolicy). `band_bps` only applies to',
+        '`EvictOldestOutOfBand`.',
+      ],
+      accounts: [
+        {
+          name: 'collectFeeAdmin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'policy',
+          type: {
+            defined: 'EvictionPolicy',
+          },
+        },
+        {
+          name: 'bandBps',
+          type: 'u16',
+        },
+      ],
+    },
+    {
+      name: 'setFeeRounding',
+      docs: [
+        'Choose whether taker fees round up or down to native quote units, as a',
+        "[`Market`](crate::state::Market)'s admin, see",
+        '[`FeeRounding`](crate::state::FeeRounding).',
+        '',
+        'Rounding up, the default, the taker pays the fractional remainder to the',
+        "market's fees. Rounding down it stays with the taker. Maker fees and rebates",
+        'always round down.',
+      ],
+      accounts: [
+        {
+          name: 'collectFeeAdmin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'takerFeeRounding',
+          type: {
+            defined: 'FeeRounding',
+          },
+        },
+      ],
+    },
+    {
+      name: 'createMakerRewards',
+      docs: [
+        'Opt a market into maker rewards paid in `reward_mint`, as a',
+        "[`Market`](crate::state::Market)'s admin. See",
+        '[`MakerRewards`](crate::state::MakerRewards) for how they accrue.',
+        '',
+        'The rewards are paid out of the reward vault created along, which anyone',
+        'can fund with a token transfer. Points only accrue from its creation on.',
+      ],
+      accounts: [
+        {
+          name: 'collectFeeAdmin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'payer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'makerRewards',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'rewardMint',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'rewardVault',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'associatedTokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'rewardsPerMillionPoints',
+          type: 'u64',
+        },
+      ],
+    },
+    {
+      name: 'setMakerRewards',
+      docs: [
+        "Change the reward rate of a market's maker rewards, as its admin.",
+        '',
+        'Unclaimed points are paid at the rate in effect when claimed. Pass a rate of',
+        '0 to pause the payouts, points keep accruing.',
+      ],
+      accounts: [
+        {
+          name: 'collectFeeAdmin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'market',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'makerRewards',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'rewardsPerMillionPoints',
+          type: 'u64',
+        },
+      ],
+    },
+    {
+      name: 'claimMakerRewards',
+      docs: [
+        'Transfer the maker rewards of the points accrued by an open orders account,',
+        'as far as the reward vault covers them.',
+      ],
+      accounts: [
+        {
+          name: 'owner',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'openOrdersAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'market',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'makerRewards',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'rewardVault',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'userRewardAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'createQuoteQuality',
+      docs: [
+        "Start tracking the quotes of a designated market maker's open orders account,",
+        "as a [`Market`](crate::state::Market)'s admin. See",
+        '[`QuoteQuality`](crate::state::QuoteQuality).',
+        '',
+        'The account quotes while it has `min_size_lots` on each side within',
+        '`max_oracle_distance_bps` of the oracle. Requires a market with an oracle.',
+      ],
+      accounts: [
+        {
+          name: 'collectFeeAdmin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'payer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'market',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'openOrdersAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'quoteQuality',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'maxOracleDistanceBps',
+          type: 'u16',
+        },
+        {
+          name: 'minSizeLots',
+          type: 'i64',
+        },
+        {
+          name: 'epochDuration',
+          type: 'u64',
+        },
+      ],
+    },
+    {
+      name: 'sampleQuoteQuality',
+      docs: [
+        'Record whether a designated market maker is quoting, crediting it the time',
+        'since the previous sample. Anyone can sample.',
+      ],
+      accounts: [
+        {
+          name: 'quoteQuality',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'market',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'bids',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'asks',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'oracleA',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'oracleB',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'createDmmRegistry',
+      docs: [
+        "Create the [`DmmRegistry`](crate::state::DmmRegistry) of a market's",
+        'designated market makers, as its collect fee admin.',
+      ],
+      accounts: [
+        {
+          name: 'collectFeeAdmin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'payer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'market',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'dmmRegistry',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'setDmmSlot',
+      docs: [
+        'Make an open orders account a designated market maker of `priority`,',
+        'committed to quote `min_uptime_bps` of the time, or remove it with a zero',
+        'priority. Only the collect fee admin can call this.',
+      ],
+      accounts: [
+        {
+          name: 'collectFeeAdmin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'market',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'dmmRegistry',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'openOrdersAccount',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'priority',
+          type: 'u8',
+        },
+        {
+          name: 'minUptimeBps',
+          type: 'u16',
+        },
+      ],
+    },
+    {
+      name: 'enforceDmmObligation',
+      docs: [
+        'Remove a designated market maker whose last complete',
+        '[`QuoteQuality`](crate::state::QuoteQuality) epoch fell short of its',
+        '`min_uptime_bps`. Anyone can call this.',
+      ],
+      accounts: [
+        {
+          name: 'market',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'dmmRegistry',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'openOrdersAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'quoteQuality',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'createTakerRebateCampaign',
+      docs: [
+        'Create a [`TakerRebateCampaign`](crate::state::TakerRebateCampaign) rebating',
+        '`rebate_bps` of the taker fees paid between `start_timestamp` and `end_timestamp`,',
+        "as a [`Market`](crate::state::Market)'s admin.",
+        '',
+        'Takers accrue rebates when they pass the campaign among the remaining accounts',
+        'of their orders, up to the budget funded with `fund_taker_rebate_campaign`.',
+      ],
+      accounts: [
+        {
+          name: 'collectFeeAdmin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'payer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'market',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'takerRebateCampaign',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'quoteMint',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'rebateVault',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'associatedTokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'startTimestamp',
+          type: 'u64',
+        },
+        {
+          name: 'endTimestamp',
+          type: 'u64',
+        },
+        {
+          name: 'rebateBps',
+          type: 'u16',
+        },
+      ],
+    },
+    {
+      name: 'setTakerRebateCampaign',
+      docs: [
+        "Change the window and rebate of a taker rebate campaign, as the market's admin.",
+        '',
+        'Accrued rebates can still be claimed, this can also start a new campaign once',
+        'the previous one ended.',
+      ],
+      accounts: [
+        {
+          name: 'collectFeeAdmin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'market',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'takerRebateCampaign',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'startTimestamp',
+          type: 'u64',
+        },
+        {
+          name: 'endTimestamp',
+          type: 'u64',
+        },
+        {
+          name: 'rebateBps',
+          type: 'u16',
+        },
+      ],
+    },
+    {
+      name: 'fundTakerRebateCampaign',
+      docs: [
+        "Add `amount` quote to the budget of a taker rebate campaign, as the market's admin.",
+      ],
+      accounts: [
+        {
+          name: 'collectFeeAdmin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'market',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'takerRebateCampaign',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'rebateVault',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'adminQuoteAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'amount',
+          type: 'u64',
+        },
+      ],
+    },
+    {
+      name: 'withdrawTakerRebateBudget',
+      docs: [
+        "Withdraw the budget of a taker rebate campaign that wasn't accrued, as the",
+        "market's admin. Not possible while the campaign is running.",
+      ],
+      accounts: [
+        {
+          name: 'collectFeeAdmin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'market',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'takerRebateCampaign',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'rebateVault',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'tokenReceiverAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'claimTakerRebates',
+      docs: ['Transfer the taker rebates accrued by an open orders account.'],
+      accounts: [
+        {
+          name: 'owner',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'openOrdersAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'market',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'takerRebateCampaign',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'rebateVault',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'userQuoteAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'createFillHistory',
+      docs: [
+        'Create the [`FillHistory`](crate::state::FillHistory) of a market.',
+        '',
+        'Anyone can pay for it. Once created, pass it among the remaining accounts',
+        'of order placing instructions to record their fills.',
+      ],
+      accounts: [
+        {
+          name: 'payer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'market',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'fillHistory',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'createMarketStats',
+      docs: [
+        'Create the [`MarketStats`](crate::state::MarketStats) of a market.',
+        '',
+        'Anyone can pay for it. Once created, pass it among the remaining accounts',
+        'of order placing instructions to record their fills.',
+      ],
+      accounts: [
+        {
+          name: 'payer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'market',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'marketStats',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'createDepthSnapshot',
+      docs: [
+        'Create a [`DepthSnapshot`](crate::state::DepthSnapshot) of a market for `owner`,',
+        'to be filled by `snapshot_depth`.',
+      ],
+      accounts: [
+        {
+          name: 'payer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'owner',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'market',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'depthSnapshot',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'setSuccessorMarket',
+      docs: [
+        'Point a [`Market`](crate::state::Market) to the market of the same token pair',
+        'replacing it, as its admin. Omit the successor market to remove the link.',
+        '',
+        "The link is stored in the market's [`MarketMetadata`](crate::state::MarketMetadata),",
+        'see `create_market_metadata`.',
+        '',
+        "Lot sizes can't change, so a market whose prices moved too far is migrated by",
+        'creating a successor with new lot sizes, linking it here for clients to redirect',
+        "their users, and usually switching this market to cancel only. See the client's",
+        '`migration` module to carry the resting orders over.',
+      ],
+      accounts: [
+        {
+          name: 'collectFeeAdmin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'market',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'marketMetadata',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'successorMarket',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+          docs: ['None to unlink the market from its successor'],
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'createFillCallback',
+      docs: [
+        'Register a program to be called with the fills processed by',
+        "`consume_events_with_fill_callback`, as a [`Market`](crate::state::Market)'s admin.",
+        '',
+        'See [`fill_callback`](crate::fill_callback) for the interface. The program is',
+        "kept in the market's [`FillCallback`](crate::state::FillCallback) account.",
+      ],
+      accounts: [
+        {
+          name: 'payer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'collectFeeAdmin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'market',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'fillCallback',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'program',
+          type: 'publicKey',
+        },
+      ],
+    },
+    {
+      name: 'setFillCallback',
+      docs: [
+        "Change the program registered with `create_fill_callback`, as the market's",
+        'admin. Pass `None` to remove it.',
+      ],
+      accounts: [
+        {
+          name: 'collectFeeAdmin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'market',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'fillCallback',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'program',
+          type: {
+            option: 'publicKey',
+          },
+        },
+      ],
+    },
+    {
+      name: 'setMarketAuthority',
+      docs: [
+        "Offer one of a [`Market`](crate::state::Market)'s admin roles to a new key,",
+        'as the current holder of that role.',
+        '',
+        'The new admin takes over by calling `accept_market_authority`. The offer is',
+        'kept in a [`PendingMarketAdmin`](crate::state::PendingMarketAdmin) account,',
+        'so only one transfer can be pending at a time.',
+      ],
+      accounts: [
+        {
+          name: 'payer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'admin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'market',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'pendingMarketAdmin',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'role',
+          type: {
+            defined: 'MarketAdmin',
+          },
+        },
+        {
+          name: 'newAdmin',
+          type: 'publicKey',
+        },
+      ],
+    },
+    {
+      name: 'acceptMarketAuthority',
+      docs: ['Take over the admin role offered by `set_market_authority`.'],
+      accounts: [
+        {
+          name: 'pendingAdmin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'pendingMarketAdmin',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'payer',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'cancelMarketAuthority',
+      docs: [
+        'Withdraw the admin role offered by `set_market_authority`, as the current',
+        'holder of that role.',
+      ],
+      accounts: [
+        {
+          name: 'admin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'market',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'pendingMarketAdmin',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'payer',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'createMarketMetadata',
+      docs: [
+        'Create the [`MarketMetadata`](crate::state::MarketMetadata) of a market,',
+        "as a [`Market`](crate::state::Market)'s admin.",
+      ],
+      accounts: [
+        {
+          name: 'collectFeeAdmin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'payer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'market',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'marketMetadata',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'args',
+          type: {
+            defined: 'MarketMetadataArgs',
+          },
+        },
+      ],
+    },
+    {
+      name: 'setMarketMetadata',
+      docs: [
+        'Update the [`MarketMetadata`](crate::state::MarketMetadata) of a market,',
+        "as a [`Market`](crate::state::Market)'s admin.",
+      ],
+      accounts: [
+        {
+          name: 'collectFeeAdmin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'market',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'marketMetadata',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'args',
+          type: {
+            defined: 'MarketMetadataArgs',
+          },
+        },
+      ],
+    },
+    {
+      name: 'setBinaryOutcome',
+      docs: [
+        'Turn a market into a binary outcome market, or post its resolution once',
+        "expired, as a [`Market`](crate::state::Market)'s admin.",
+        '',
+        'Resolving settles the market at a price of 0 or 1, see [`BinaryOutcome`].',
+        'Resting orders are then cancelled through `settle_expired_market`.',
+      ],
+      accounts: [
+        {
+          name: 'collectFeeAdmin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'outcome',
+          type: {
+            defined: 'BinaryOutcome',
+          },
+        },
+      ],
+    },
+    {
+      name: 'setMarketState',
+      docs: [
+        "Restrict what users can do on a market, as a [`Market`](crate::state::Market)'s admin.",
+        '',
+        'See [`MarketState`] for what each state allows.',
+      ],
+      accounts: [
+        {
+          name: 'collectFeeAdmin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'state',
+          type: {
+            defined: 'MarketState',
+          },
+        },
+      ],
+    },
+    {
+      name: 'settleExpiredMarket',
+      docs: [
+        'Record the settlement price of an expired market and switch it to cancel',
+        'only, as its close market admin.',
+        '',
+        'Markets with an oracle settle at the oracle price and take no',
+        '`settlement_price_lots`, markets without one settle at the given price.',
+        '',
+        'Cancels the resting orders of the open orders accounts passed as remaining',
+        'accounts. Can be called again with other accounts until the book is empty.',
+      ],
+      accounts: [
+        {
+          name: 'closeMarketAdmin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'bids',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'asks',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'oracleA',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'oracleB',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [
+        {
+          name: 'limit',
+          type: 'u8',
+        },
+        {
+          name: 'settlementPriceLots',
+          type: {
+            option: 'i64',
+          },
+        },
+      ],
+    },
+    {
+      name: 'sweepQuoteDust',
+      docs: [
+        'Move the quote tokens of the vault that no account is owed, like rounding',
+        'remainders of fills, to the fees available to `sweep_fees`, so the vault',
+        "balance reconciles exactly with the market's deposits. Anyone can call it.",
+      ],
+      accounts: [
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'marketQuoteVault',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'sweepFees',
+      docs: ["Sweep fees, as a [`Market`](crate::state::Market)'s admin."],
+      accounts: [
+        {
+          name: 'collectFeeAdmin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'marketAuthority',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'marketQuoteVault',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'tokenReceiverAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'verifyMarketInvariants',
+      docs: [
+        "Compare a market's vault balances and deposit totals with what the",
+        '[`OpenOrdersAccount`](crate::state::OpenOrdersAccount)s passed as remaining',
+        'accounts are owed, pending fills included. Anyone can call it.',
+        '',
+        "Doesn't fail on a discrepancy: it's logged, along with the totals in a",
+        '[`MarketInvariantsLog`](crate::logs::MarketInvariantsLog). With all the open',
+        'orders accounts of the market passed, nothing is left unaccounted.',
+      ],
+      accounts: [
+        {
+          name: 'market',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'eventHeap',
+          isMut: true,
+          isSigner: false,
+          docs: [
+            'Only read, writable for loading the nodes added by `resize_event_heap`',
+          ],
+        },
+        {
+          name: 'marketBaseVault',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'marketQuoteVault',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'setDelegate',
+      docs: [
+        'Update the [`delegate`](crate::state::OpenOrdersAccount::delegate) of an open orders account.',
+      ],
+      accounts: [
+        {
+          name: 'owner',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'openOrdersAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'delegateAccount',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'setDirectSettlement',
+      docs: [
+        'Let takers pay the fill proceeds of an open orders account straight to its',
+        "owner's token accounts passed along with it, see `place_order`. Off by default.",
+      ],
+      accounts: [
+        {
+          name: 'owner',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'openOrdersAccount',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'enabled',
+          type: 'bool',
+        },
+      ],
+    },
+    {
+      name: 'setRiskProgram',
+      docs: [
+        'Register a program approving the new orders of an open orders account,',
+        "as its owner. It's kept in the account's position extension, which has to",
+        'be created first.',
+        '',
+        'See [`health_check`](crate::health_check) for the interface. Pass `None`',
+        'to remove it.',
+      ],
+      accounts: [
+        {
+          name: 'owner',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'openOrdersAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'positionExtension',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'program',
+          type: {
+            option: 'publicKey',
+          },
+        },
+      ],
+    },
+    {
+      name: 'setSettleReceiver',
+      docs: [
+        'Register a program `settle_funds` routes the withdrawn tokens of an open',
+        "orders account into, as its owner. Like the risk program, it's kept in the",
+        "account's position extension.",
+        '',
+        'See [`settle_receiver`](crate::settle_receiver) for the interface. Pass',
+        '`None` to remove it.',
+      ],
+      accounts: [
+        {
+          name: 'owner',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'openOrdersAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'positionExtension',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'program',
+          type: {
+            option: 'publicKey',
+          },
+        },
+      ],
+    },
+    {
+      name: 'setMarketExpired',
+      docs: [
+        'Set market to expired before pruning orders and closing the market (only',
+        '[`close_market_admin`](crate::state::Market::close_market_admin)).',
+      ],
+      accounts: [
+        {
+          name: 'closeMarketAdmin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'forceCancelOrders',
+      docs: [
+        "Cancel the orders of an open orders account, as the market's",
+        '[`close_market_admin`](crate::state::Market::close_market_admin), whatever',
+        "the market's state.",
+        '',
+        'For compliance actions on permissioned markets and to clear the book of broken',
+        'accounts before `close_market`. Emits an `OutLog` per cancelled order, the',
+        'account keeps the released funds and can settle them as usual.',
+      ],
+      accounts: [
+        {
+          name: 'closeMarketAdmin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'openOrdersAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'bids',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'asks',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'limit',
+          type: 'u8',
+        },
+      ],
+    },
+    {
+      name: 'pruneOrders',
+      docs: [
+        'Remove orders from the book when the market is expired (only',
+        '[`close_market_admin`](crate::state::Market::close_market_admin)).',
+      ],
+      accounts: [
+        {
+          name: 'closeMarketAdmin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'openOrdersAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'market',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'bids',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'asks',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'limit',
+          type: 'u8',
+        },
+      ],
+    },
+    {
+      name: 'stubOracleCreate',
+      accounts: [
+        {
+          name: 'payer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'owner',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'oracle',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'mint',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'price',
+          type: 'f64',
+        },
+      ],
+    },
+    {
+      name: 'stubOracleClose',
+      accounts: [
+        {
+          name: 'owner',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'oracle',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'solDestination',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'stubOracleSet',
+      accounts: [
+        {
+          name: 'owner',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'oracle',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'price',
+          type: 'f64',
+        },
+      ],
+    },
+    {
+      name: 'stubOracleSetTest',
+      docs: [
+        'Set every field of a stub oracle, to test stale oracles, low confidence',
+        'and prices with an exponent.',
+        '',
+        '`price` and `deviation` are multiplied by 10^`exponent`. A',
+        '`last_update_slot` of 0 means the oracle is never stale.',
+      ],
+      accounts: [
+        {
+          name: 'owner',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'oracle',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'price',
+          type: 'f64',
+        },
+        {
+          name: 'exponent',
+          type: 'i8',
+        },
+        {
+          name: 'deviation',
+          type: 'f64',
+        },
+        {
+          name: 'lastUpdateTs',
+          type: 'i64',
+        },
+        {
+          name: 'lastUpdateSlot',
+          type: 'u64',
+        },
+      ],
+    },
+  ],
+  accounts: [
+    {
+      name: 'depthSnapshot',
+      docs: [
+        "Aggregated top levels of a market's book, written by `snapshot_depth`, so other",
+        'programs can reason about the available liquidity without walking the book.',
+        '',
+        'Each owner has its own snapshot per market, anyone can refresh it. Check `slot`',
+        'before trusting it: a snapshot is only as fresh as its last refresh.',
+      ],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'market',
+            type: 'publicKey',
+          },
+          {
+            name: 'owner',
+            type: 'publicKey',
+          },
+          {
+            name: 'slot',
+            docs: ['Slot of the last refresh, 0 if never refreshed'],
+            type: 'u64',
+          },
+          {
+            name: 'timestamp',
+            type: 'u64',
+          },
+          {
+            name: 'bidsLen',
+            docs: ['Number of levels in use in `bids` and `asks`'],
+            type: 'u8',
+          },
+          {
+            name: 'asksLen',
+            type: 'u8',
+          },
+          {
+            name: 'bump',
+            type: 'u8',
+          },
+          {
+            name: 'padding',
+            type: {
+              array: ['u8', 5],
+            },
+          },
+          {
+            name: 'reserved',
+            type: {
+              array: ['u8', 64],
+            },
+          },
+          {
+            name: 'bids',
+            type: {
+              array: [
+                {
+                  defined: 'DepthLevel',
+                },
+                32,
+              ],
+            },
+          },
+          {
+            name: 'asks',
+            type: {
+              array: [
+                {
+                  defined: 'DepthLevel',
+                },
+                32,
+              ],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: 'dmmRegistry',
+      docs: [
+        'Per-market PDA of the designated market makers, managed by the collect fee',
+        'admin with `set_dmm_slot`.',
+        '',
+        "The slot's priority is copied to the open orders account, see",
+        "`OpenOrdersAccount::dmm_priority`, and from there to the account's orders. Their",
+        "orders aren't booted from a full book side by orders of a lower priority, and",
+        'the accounts are exempt from the order rate limit and the event heap penalty.',
+        'They pay no maker fees, and as takers only pay the rebates of the makers they',
+        'fill.',
+        'In return they commit to quote a `min_uptime_bps` share of the time, measured',
+        'by their [`QuoteQuality`](super::QuoteQuality); anyone can remove an account',
+        'falling short with `enforce_dmm_obligation`.',
+      ],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'market',
+            type: 'publicKey',
+          },
+          {
+            name: 'slots',
+            type: {
+              array: [
+                {
+                  defined: 'DmmSlot',
+                },
+                8,
+              ],
+            },
+          },
+          {
+            name: 'bump',
+            type: 'u8',
+          },
+          {
+            name: 'padding',
+            type: {
+              array: ['u8', 7],
+            },
+          },
+          {
+            name: 'reserved',
+            type: {
+              array: ['u8', 64],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: 'fillCallback',
+      docs: [
+        "Program a [`Market`](crate::state::Market)'s admin registered to be called with",
+        'the fills processed by `consume_events_with_fill_callback`, see',
+        '[`fill_callback`](crate::fill_callback).',
+        '',
+        'PDA of seeds `[b"FillCallback", market]`.',
+      ],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'bump',
+            type: 'u8',
+          },
+          {
+            name: 'market',
+            type: 'publicKey',
+          },
+          {
+            name: 'program',
+            type: {
+              option: 'publicKey',
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: 'fillHistory',
+      docs: [
+        'Optional per-market PDA keeping the last [`FILL_HISTORY_LEN`] fills, so',
+        'clients and other programs can read recent trades without an indexer.',
+        '',
+        'Fills are recorded while matching when the account is passed among the',
+        'remaining accounts of the order placing instruction.',
+      ],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'market',
+            type: 'publicKey',
+          },
+          {
+            name: 'count',
+            docs: [
+              'Total number of fills recorded, the latest one is at `(count - 1) % FILL_HISTORY_LEN`',
+            ],
+            type: 'u64',
+          },
+          {
+            name: 'bump',
+            type: 'u8',
+          },
+          {
+            name: 'padding',
+            type: {
+              array: ['u8', 7],
+            },
+          },
+          {
+            name: 'reserved',
+            type: {
+              array: ['u8', 64],
+            },
+          },
+          {
+            name: 'fills',
+            type: {
+              array: [
+                {
+                  defined: 'FillRecord',
+                },
+                64,
+              ],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: 'makerRewards',
+      docs: [
+        'Opt-in liquidity mining for the makers of a market.',
+        '',
+        'Makers earn points, base lots times seconds at the top of the book, when their',
+        'orders are filled or cancelled. An order is at the top while its fixed price is',
+        'the best of its side, see [`BookSide::seconds_at_top`](super::BookSide::seconds_at_top).',
+        "The points are accrued to the maker's own open orders account, so nobody",
+        'else decides who earns them.',
+        '',
+        '`claim_maker_rewards` pays out the points at `rewards_per_million_points` from',
+        "`reward_vault`, which anyone can fund. Points the vault can't cover yet are",
+        'kept for a later claim.',
+      ],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'market',
+            type: 'publicKey',
+          },
+          {
+            name: 'rewardMint',
+            type: 'publicKey',
+          },
+          {
+            name: 'rewardVault',
+            docs: ['Token account of `reward_mint` owned by this account'],
+            type: 'publicKey',
+          },
+          {
+            name: 'rewardsPerMillionPoints',
+            docs: ['Reward native paid per million points'],
+            type: 'u64',
+          },
+          {
+            name: 'rewardsClaimedTotal',
+            docs: ['Rewards claimed since creation'],
+            type: 'u64',
+          },
+          {
+            name: 'bump',
+            type: 'u8',
+          },
+          {
+            name: 'padding',
+            type: {
+              array: ['u8', 7],
+            },
+          },
+          {
+            name: 'reserved',
+            type: {
+              array: ['u8', 64],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: 'marketMetadata',
+      docs: [
+        'Optional per-market PDA with human readable labels, maintained by the',
+        "market's `collect_fee_admin`.",
+        '',
+        'Strings are stored as fixed size arrays; trailing zero bytes are ignored.',
+      ],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'market',
+            type: 'publicKey',
+          },
+          {
+            name: 'bump',
+            type: 'u8',
+          },
+          {
+            name: 'padding',
+            type: {
+              array: ['u8', 7],
+            },
+          },
+          {
+            name: 'name',
+            type: {
+              array: ['u8', 64],
+            },
+          },
+          {
+            name: 'baseSymbol',
+            type: {
+              array: ['u8', 16],
+            },
+          },
+          {
+            name: 'quoteSymbol',
+            type: {
+              array: ['u8', 16],
+            },
+          },
+          {
+            name: 'uri',
+            docs: ['Link to further off-chain information about the market.'],
+            type: {
+              array: ['u8', 200],
+            },
+          },
+          {
+            name: 'successorMarket',
+            docs: [
+              'Market of the same token pair replacing this one, e.g. with lot sizes fitting',
+              'the current prices better, so clients can redirect their users to it.',
+              'See `set_successor_market`.',
+            ],
+            type: {
+              defined: 'NonZeroPubkeyOption',
+            },
+          },
+          {
+            name: 'reserved',
+            type: {
+              array: ['u8', 32],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: 'marketRegistryPage',
+      docs: [
+        'Page of the global market registry, a PDA of `["MarketRegistry", page_index]`',
+        '(little endian `u32`).',
+        '',
+        'Pages are created in order and only once the previous one is full, so',
+        'clients can list every registered market by reading pages from 0 until',
+        'one is missing or not full.',
+      ],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'pageIndex',
+            type: 'u32',
+          },
+          {
+            name: 'count',
+            type: 'u32',
+          },
+          {
+            name: 'bump',
+            type: 'u8',
+          },
+          {
+            name: 'padding',
+            type: {
+              array: ['u8', 7],
+            },
+          },
+          {
+            name: 'reserved',
+            type: {
+              array: ['u8', 64],
+            },
+          },
+          {
+            name: 'entries',
+            type: {
+              array: [
+                {
+                  defined: 'MarketRegistryEntry',
+                },
+                64,
+              ],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: 'marketStats',
+      docs: [
+        'Optional per-market PDA keeping the fills of the last [`MARKET_STATS_HOURS`] hours',
+        'in hourly buckets, so markets can be compared from on-chain data alone.',
+        '',
+        'Fills are recorded while matching when the account is passed among the',
+        'remaining accounts of the order placing instruction. Buckets are only rotated',
+        'by fills: a bucket of an hour without fills keeps the fills of an older hour,',
+        'which [`MarketStats::last_24h`] skips.',
+      ],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'market',
+            type: 'publicKey',
+          },
+          {
+            name: 'bump',
+            type: 'u8',
+          },
+          {
+            name: 'padding',
+            type: {
+              array: ['u8', 7],
+            },
+          },
+          {
+            name: 'reserved',
+            type: {
+              array: ['u8', 64],
+            },
+          },
+          {
+            name: 'hours',
+            type: {
+              array: [
+                {
+                  defined: 'HourlyStats',
+                },
+                24,
+              ],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: 'market',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'bump',
+            docs: ['PDA bump'],
+            type: 'u8',
+          },
+          {
+            name: 'baseDecimals',
+            docs: [
+              'Number of decimals used for the base token.',
+              '',
+              "Used to convert the oracle's price into a native/native price.",
+            ],
+            type: 'u8',
+          },
+          {
+            name: 'quoteDecimals',
+            type: 'u8',
+          },
+          {
+            name: 'marketState',
+            docs: ['See [`MarketState`].'],
+            type: 'u8',
+          },
+          {
+            name: 'binaryOutcome',
+            docs: ['See [`BinaryOutcome`].'],
+            type: 'u8',
+          },
+          {
+            name: 'registered',
+            docs: [
+              '1 once the market was added to the market registry, see `register_market`.',
+            ],
+            type: 'u8',
+          },
+          {
+            name: 'padding1',
+            type: {
+              array: ['u8', 2],
+            },
+          },
+          {
+            name: 'marketAuthority',
+            type: 'publicKey',
+          },
+          {
+            name: 'timeExpiry',
+            docs: [
+              'No expiry = 0. Market will expire and no trading allowed after time_expiry',
+            ],
+            type: 'i64',
+          },
+          {
+            name: 'collectFeeAdmin',
+            docs: ['Admin who can collect fees from the market'],
+            type: 'publicKey',
+          },
+          {
+            name: 'openOrdersAdmin',
+            docs: ['Admin who must sign off on all order creations'],
+            type: {
+              defined: 'NonZeroPubkeyOption',
+            },
+          },
+          {
+            name: 'consumeEventsAdmin',
+            docs: ['Admin who must sign off on all event consumptions'],
+            type: {
+              defined: 'NonZeroPubkeyOption',
+            },
+          },
+          {
+            name: 'closeMarketAdmin',
+            docs: [
+              'Admin who can set market expired, prune orders and close the market',
+            ],
+            type: {
+              defined: 'NonZeroPubkeyOption',
+            },
+          },
+          {
+            name: 'name',
+            docs: ['Name. Trailing zero bytes are ignored.'],
+            type: {
+              array: ['u8', 16],
+            },
+          },
+          {
+            name: 'bids',
+            docs: ['Address of the BookSide account for bids'],
+            type: 'publicKey',
+          },
+          {
+            name: 'asks',
+            docs: ['Address of the BookSide account for asks'],
+            type: 'publicKey',
+          },
+          {
+            name: 'eventHeap',
+            docs: ['Address of the EventHeap account'],
+            type: 'publicKey',
+          },
+          {
+            name: 'oracleA',
+            docs: ['Oracles account address'],
+            type: {
+              defined: 'NonZeroPubkeyOption',
+            },
+          },
+          {
+            name: 'oracleB',
+            type: {
+              defined: 'NonZeroPubkeyOption',
+            },
+          },
+          {
+            name: 'oracleConfig',
+            docs: ['Oracle configuration'],
+            type: {
+              defined: 'OracleConfig',
+            },
+          },
+          {
+            name: 'quoteLotSize',
+            docs: [
+              'Number of quote native in a quote lot. Must be a power of 10.',
+              '',
+              'Primarily useful for increasing the tick size on the market: A lot price',
+              'of 1 becomes a native price of quote_lot_size/base_lot_size becomes a',
+              'ui price of quote_lot_size*base_decimals/base_lot_size/quote_decimals.',
+            ],
+            type: 'i64',
+          },
+          {
+            name: 'baseLotSize',
+            docs: [
+              'Number of base native in a base lot. Must be a power of 10.',
+              '',
+              'Example: If base decimals for the underlying asset is 6, base lot size',
+              'is 100 and and base position lots is 10_000 then base position native is',
+              '1_000_000 and base position ui is 1.',
+            ],
+            type: 'i64',
+          },
+          {
+            name: 'seqNum',
+            docs: ['Total number of orders seen'],
+            type: 'u64',
+          },
+          {
+            name: 'registrationTime',
+            docs: ['Timestamp in seconds that the market was registered at.'],
+            type: 'i64',
+          },
+          {
+            name: 'makerFee',
+            docs: [
+              'Fees',
+              '',
+              'Fee (in 10^-6) when matching maker orders.',
+              'maker_fee < 0 it means some of the taker_fees goes to the maker',
+              'maker_fee > 0, it means no taker_fee to the maker, and maker fee goes to the referral',
+            ],
+            type: 'i64',
+          },
+          {
+            name: 'takerFee',
+            docs: ['Fee (in 10^-6) for taker orders, always >= 0.'],
+            type: 'i64',
+          },
+          {
+            name: 'feesAccrued',
+            docs: ['Total fees accrued in native quote'],
+            type: 'u128',
+          },
+          {
+            name: 'feesToReferrers',
+            docs: ['Total fees settled in native quote'],
+            type: 'u128',
+          },
+          {
+            name: 'referrerRebatesAccrued',
+            docs: ['Referrer rebates to be distributed'],
+            type: 'u64',
+          },
+          {
+            name: 'feesAvailable',
+            docs: ['Fees generated and available to withdraw via sweep_fees'],
+            type: 'u64',
+          },
+          {
+            name: 'makerVolume',
+            docs: [
+              'Cumulative maker volume (same as taker volume) in quote native units',
+            ],
+            type: 'u128',
+          },
+          {
+            name: 'takerVolumeWoOo',
+            docs: [
+              'Cumulative taker volume in quote native units due to place take orders',
+            ],
+            type: 'u128',
+          },
+          {
+            name: 'baseMint',
+            type: 'publicKey',
+          },
+          {
+            name: 'quoteMint',
+            type: 'publicKey',
+          },
+          {
+            name: 'marketBaseVault',
+            type: 'publicKey',
           },
-        },
-        {
-          name: 'quoteLotSize',
-          type: 'i64',
-        },
-        {
-          name: 'baseLotSize',
-          type: 'i64',
-        },
-        {
-          name: 'makerFee',
-          type: 'i64',
-        },
-        {
-          name: 'takerFee',
-          type: 'i64',
-        },
-        {
-          name: 'timeExpiry',
-          type: 'i64',
-        },
-      ],
+          {
+            name: 'baseDepositTotal',
+            type: 'u64',
+          },
+          {
+            name: 'marketQuoteVault',
+            type: 'publicKey',
+          },
+          {
+            name: 'quoteDepositTotal',
+            type: 'u64',
+          },
+          {
+            name: 'consumeEventsReward',
+            docs: [
+              'Lamports paid per consumed event to whoever calls `consume_events_with_reward`.',
+              '',
+              'Funded out of the event heap penalties collected by the market account.',
+            ],
+            type: 'u64',
+          },
+          {
+            name: 'eventHeapOldestTimestamp',
+            docs: [
+              "Timestamp of the oldest event waiting on the event heap, 0 when it's empty.",
+              '',
+              'Like the other event heap stats, refreshed by every instruction that',
+              'adds or consumes events.',
+            ],
+            type: 'u64',
+          },
+          {
+            name: 'eventHeapCount',
+            docs: ['Number of events waiting on the event heap.'],
+            type: 'u16',
+          },
+          {
+            name: 'eventHeapCapacity',
+            docs: [
+              'Number of events the event heap can hold, including resized nodes.',
+            ],
+            type: 'u16',
+          },
+          {
+            name: 'padding2',
+            type: {
+              array: ['u8', 4],
+            },
+          },
+          {
+            name: 'eventSeqNum',
+            docs: [
+              'Total number of fill and out events generated, processed directly or not.',
+              '',
+              'Each event carries its own number so consumers can detect missed events.',
+            ],
+            type: 'u64',
+          },
+          {
+            name: 'settlementPriceLots',
+            docs: [
+              'Price in lots the market settled at with `settle_expired_market`, 0 until then.',
+            ],
+            type: 'i64',
+          },
+          {
+            name: 'creationBond',
+            docs: [
+              'Lamports escrowed in the market account at creation, on top of its rent.',
+              '',
+              'Returned together with the rent by `close_market`, unless governance slashed',
+              'it with `slash_market_bond` for spam or malicious markets.',
+            ],
+            type: 'u64',
+          },
+          {
+            name: 'makerRewardsStartTs',
+            docs: [
+              "Time the market opted into maker rewards, 0 when it hasn't. See",
+              '[`MakerRewards`](crate::state::MakerRewards).',
+            ],
+            type: 'u64',
+          },
+          {
+            name: 'asksBaseLotsTotal',
+            docs: [
+              'Base lots in the resting asks of all open orders accounts.',
+              '',
+              'This and the following totals sum the positions of all open orders accounts,',
+              'they are only exact for markets created after they were introduced.',
+            ],
+            type: 'i64',
+          },
+          {
+            name: 'bidsQuoteLotsTotal',
+            docs: [
+              'Quote lots locked in the resting bids of all open orders accounts',
+            ],
+            type: 'i64',
+          },
+          {
+            name: 'baseFreeNativeTotal',
+            docs: ['Base native unsettled in all open orders accounts'],
+            type: 'i64',
+          },
+          {
+            name: 'quoteFreeNativeTotal',
+            docs: ['Quote native unsettled in all open orders accounts'],
+            type: 'i64',
+          },
+          {
+            name: 'orderRateLimit',
+            docs: [
+              'Max new orders an open orders account can place per `order_rate_limit_window`,',
+              '0 when unlimited.',
+            ],
+            type: 'u32',
+          },
+          {
+            name: 'orderRateLimitWindow',
+            docs: ['Length of the windows of `order_rate_limit` in seconds'],
+            type: 'u32',
+          },
+          {
+            name: 'minOrderNotional',
+            docs: [
+              "Smallest quote native value of an order resting on the book, at the order's",
+              "price when it's posted. 0 when there's no minimum.",
+              '',
+              'Keeps dust orders that are worth less than the space they take off the book.',
+            ],
+            type: 'u64',
+          },
+          {
+            name: 'maxRestingOrders',
+            docs: [
+              'Max resting orders of an open orders account on this market, 0 when only',
+              'limited by the size of the account.',
+            ],
+            type: 'u8',
+          },
+          {
+            name: 'version',
+            docs: [
+              'Layout version, see `MARKET_VERSION`. 0 for markets created before it was',
+              'introduced.',
+            ],
+            type: 'u8',
+          },
+          {
+            name: 'padding3',
+            type: {
+              array: ['u8', 6],
+            },
+          },
+          {
+            name: 'evictionPolicy',
+            docs: ['See [`EvictionPolicy`].'],
+            type: 'u8',
+          },
+          {
+            name: 'takerFeeRounding',
+            docs: ['See [`FeeRounding`].'],
+            type: 'u8',
+          },
+          {
+            name: 'evictionBandBps',
+            docs: ['Price band of `EvictionPolicy::EvictOldestOutOfBand`'],
+            type: 'u16',
+          },
+          {
+            name: 'reserved',
+            type: {
+              array: ['u8', 12],
+            },
+          },
+        ],
+      },
     },
     {
-      name: 'closeMarket',
+      name: 'merkleAllowlist',
       docs: [
-        'Close a [`Market`](crate::state::Market) (only',
-        '[`close_market_admin`](crate::state::Market::close_market_admin)).',
-      ],
-      accounts: [
-        {
-          name: 'closeMarketAdmin',
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: 'market',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'bids',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'asks',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'eventHeap',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'solDestination',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'tokenProgram',
-          isMut: false,
-          isSigner: false,
-        },
+        'Per-market PDA letting traders of a permissioned market prove once that they',
+        'are allowed to trade, instead of the `open_orders_admin` co-signing every order.',
+        '',
+        'Leaves of the tree are `sha256(owner)`, and each pair of nodes is hashed in',
+        "sorted order so proofs don't need to carry left/right flags.",
       ],
-      args: [],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'market',
+            type: 'publicKey',
+          },
+          {
+            name: 'root',
+            type: {
+              array: ['u8', 32],
+            },
+          },
+          {
+            name: 'bump',
+            type: 'u8',
+          },
+          {
+            name: 'padding',
+            type: {
+              array: ['u8', 7],
+            },
+          },
+          {
+            name: 'reserved',
+            type: {
+              array: ['u8', 64],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: 'openOrdersAccount',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'owner',
+            type: 'publicKey',
+          },
+          {
+            name: 'market',
+            type: 'publicKey',
+          },
+          {
+            name: 'name',
+            type: {
+              array: ['u8', 32],
+            },
+          },
+          {
+            name: 'delegate',
+            type: {
+              defined: 'NonZeroPubkeyOption',
+            },
+          },
+          {
+            name: 'accountNum',
+            type: 'u32',
+          },
+          {
+            name: 'bump',
+            type: 'u8',
+          },
+          {
+            name: 'version',
+            type: 'u8',
+          },
+          {
+            name: 'flags',
+            docs: ['See [`open_orders_flags`].'],
+            type: 'u8',
+          },
+          {
+            name: 'dmmPriority',
+            docs: [
+              "Priority of the account's slot in the market's",
+              "[`DmmRegistry`](crate::state::DmmRegistry), 0 when it isn't a designated",
+              'market maker.',
+            ],
+            type: 'u8',
+          },
+          {
+            name: 'position',
+            type: {
+              defined: 'Position',
+            },
+          },
+          {
+            name: 'openOrders',
+            type: {
+              array: [
+                {
+                  defined: 'OpenOrder',
+                },
+                24,
+              ],
+            },
+          },
+        ],
+      },
     },
     {
-      name: 'createOpenOrdersIndexer',
+      name: 'openOrdersIndexer',
       docs: [
-        'Create an [`OpenOrdersIndexer`](crate::state::OpenOrdersIndexer) account.',
-      ],
-      accounts: [
-        {
-          name: 'payer',
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: 'owner',
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: 'openOrdersIndexer',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'systemProgram',
-          isMut: false,
-          isSigner: false,
-        },
+        'Per-owner PDA listing every live [`OpenOrdersAccount`](crate::state::OpenOrdersAccount),',
+        'so clients can discover them without scanning all program accounts.',
+        '',
+        'Accounts are added on creation and removed on close; `created_counter` only',
+        'ever grows and is used as the `account_num` seed of new open orders accounts.',
       ],
-      args: [],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'bump',
+            type: 'u8',
+          },
+          {
+            name: 'createdCounter',
+            type: 'u32',
+          },
+          {
+            name: 'addresses',
+            type: {
+              vec: 'publicKey',
+            },
+          },
+        ],
+      },
     },
     {
-      name: 'closeOpenOrdersIndexer',
-      docs: [
-        'Close an [`OpenOrdersIndexer`](crate::state::OpenOrdersIndexer) account.',
-      ],
-      accounts: [
-        {
-          name: 'owner',
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: 'openOrdersIndexer',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'solDestination',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'tokenProgram',
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [],
+      name: 'stubOracle',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'owner',
+            type: 'publicKey',
+          },
+          {
+            name: 'mint',
+            type: 'publicKey',
+          },
+          {
+            name: 'price',
+            type: 'f64',
+          },
+          {
+            name: 'lastUpdateTs',
+            type: 'i64',
+          },
+          {
+            name: 'lastUpdateSlot',
+            type: 'u64',
+          },
+          {
+            name: 'deviation',
+            type: 'f64',
+          },
+          {
+            name: 'exponent',
+            docs: ['`price` and `deviation` are multiplied by 10^exponent'],
+            type: 'i8',
+          },
+          {
+            name: 'padding',
+            type: {
+              array: ['u8', 7],
+            },
+          },
+          {
+            name: 'reserved',
+            type: {
+              array: ['u8', 96],
+            },
+          },
+        ],
+      },
     },
     {
-      name: 'createOpenOrdersAccount',
-      docs: [
-        'Create an [`OpenOrdersAccount`](crate::state::OpenOrdersAccount).',
-      ],
-      accounts: [
-        {
-          name: 'payer',
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: 'owner',
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: 'delegateAccount',
-          isMut: false,
-          isSigner: false,
-          isOptional: true,
-        },
-        {
-          name: 'openOrdersIndexer',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'openOrdersAccount',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'market',
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: 'systemProgram',
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: 'name',
-          type: 'string',
-        },
-      ],
+      name: 'bookSide',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'roots',
+            type: {
+              array: [
+                {
+                  defined: 'OrderTreeRoot',
+                },
+                2,
+              ],
+            },
+          },
+          {
+            name: 'reservedRoots',
+            type: {
+              array: [
+                {
+                  defined: 'OrderTreeRoot',
+                },
+                4,
+              ],
+            },
+          },
+          {
+            name: 'nextPage',
+            docs: [
+              'Page holding further fixed price orders once this book side is full, created',
+              'with `create_book_side_page`. Default when there is none.',
+              '',
+              "Pages hold the orders posted while the market's book side was full, it keeps",
+              'the better ones. Instructions matching against a side with paged orders need',
+              'all its pages as remaining accounts.',
+            ],
+            type: 'publicKey',
+          },
+          {
+            name: 'pagedLeafCount',
+            docs: [
+              "Orders resting on the pages after this book side, only tracked on the market's",
+              'book side itself.',
+            ],
+            type: 'u32',
+          },
+          {
+            name: 'padding',
+            type: {
+              array: ['u8', 4],
+            },
+          },
+          {
+            name: 'bestPriceLots',
+            docs: [
+              'Price of the best valid fixed price order, 0 when there is none.',
+              '',
+              'Top of the book as of the last instruction that changed it, for programs and',
+              "clients that don't want to load the whole book side. Oracle pegged orders",
+              "aren't included, their price moves with the oracle.",
+            ],
+            type: 'i64',
+          },
+          {
+            name: 'bestBaseLots',
+            docs: [
+              'Base lots of the valid fixed price orders at `best_price_lots`, 0 when there',
+              'are none.',
+            ],
+            type: 'i64',
+          },
+          {
+            name: 'bestPriceSince',
+            docs: [
+              'Time `best_price_lots` last changed, since when the orders at that price are',
+              'at the top of the book. See `seconds_at_top`.',
+            ],
+            type: 'u64',
+          },
+          {
+            name: 'reserved',
+            type: {
+              array: ['u8', 192],
+            },
+          },
+          {
+            name: 'nodes',
+            type: {
+              defined: 'OrderTreeNodes',
+            },
+          },
+        ],
+      },
     },
     {
-      name: 'closeOpenOrdersAccount',
+      name: 'eventHeap',
       docs: [
-        'Close an [`OpenOrdersAccount`](crate::state::OpenOrdersAccount).',
-      ],
-      accounts: [
-        {
-          name: 'owner',
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: 'openOrdersIndexer',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'openOrdersAccount',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'solDestination',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'systemProgram',
-          isMut: false,
-          isSigner: false,
-        },
+        'Container for the different EventTypes.',
+        '',
+        'Events are stored in a fixed-array of nodes. Free nodes are connected by a single-linked list',
+        'starting at free_head while used nodes form a circular doubly-linked list starting at',
+        'used_head.',
+        '',
+        'The account can be grown with `resize_event_heap`, in which case additional nodes are',
+        'stored right after this struct; use [`EventHeapRefMut`] to access all of them.',
       ],
-      args: [],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'header',
+            type: {
+              defined: 'EventHeapHeader',
+            },
+          },
+          {
+            name: 'nodes',
+            type: {
+              array: [
+                {
+                  defined: 'EventNode',
+                },
+                600,
+              ],
+            },
+          },
+          {
+            name: 'extensionNodes',
+            docs: [
+              'Number of nodes stored after the struct, added by `resize_event_heap`',
+            ],
+            type: 'u16',
+          },
+          {
+            name: 'reserved',
+            type: {
+              array: ['u8', 62],
+            },
+          },
+        ],
+      },
     },
     {
-      name: 'placeOrder',
+      name: 'pendingMarketAdmin',
       docs: [
-        'Place an order.',
-        '',
-        'Different types of orders have different effects on the order book,',
-        'as described in [`PlaceOrderType`](crate::state::PlaceOrderType).',
-        '',
-        '`price_lots` refers to the price in lots: the number of quote lots',
-        'per base lot. It is ignored for `PlaceOrderType::Market` orders.',
-        '',
-        '`expiry_timestamp` is a unix timestamp for when this order should',
-        'expire. If 0 is passed in, the order will never expire. If the time',
-        'is in the past, the instruction is skipped. Timestamps in the future',
-        'are reduced to now + 65,535s.',
+        'Admin role of a [`Market`](crate::state::Market) offered with `set_market_authority`,',
+        'until the new admin takes it over with `accept_market_authority`.',
         '',
-        '`limit` determines the maximum number of orders from the book to fill,',
-        'and can be used to limit CU spent. When the limit is reached, processing',
-        'stops and the instruction succeeds.',
+        'PDA of seeds `[b"PendingMarketAdmin", market]`, so only one handover is pending at',
+        "a time. It's closed when accepted or cancelled, returning the rent to `payer`.",
       ],
-      accounts: [
-        {
-          name: 'signer',
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: 'openOrdersAccount',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'openOrdersAdmin',
-          isMut: false,
-          isSigner: true,
-          isOptional: true,
-        },
-        {
-          name: 'userTokenAccount',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'market',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'bids',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'asks',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'eventHeap',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'marketVault',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'oracleA',
-          isMut: false,
-          isSigner: false,
-          isOptional: true,
-        },
-        {
-          name: 'oracleB',
-          isMut: false,
-          isSigner: false,
-          isOptional: true,
-        },
-        {
-          name: 'tokenProgram',
-          isMut: false,
-          isSigner: false,
-        },
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'bump',
+            type: 'u8',
+          },
+          {
+            name: 'market',
+            type: 'publicKey',
+          },
+          {
+            name: 'role',
+            docs: ['See [`MarketAdmin`].'],
+            type: 'u8',
+          },
+          {
+            name: 'newAdmin',
+            type: 'publicKey',
+          },
+          {
+            name: 'payer',
+            type: 'publicKey',
+          },
+        ],
+      },
+    },
+    {
+      name: 'positionExtension',
+      docs: [
+        "Position data of an open orders account that doesn't fit in its [`Position`](super::Position),",
+        'created by the owner with `create_position_extension`.',
+        '',
+        'Once created, the extension has to be passed among the remaining accounts of',
+        'every instruction executing fills of the account, see [`load_position_extension`].',
       ],
-      args: [
-        {
-          name: 'args',
-          type: {
-            defined: 'PlaceOrderArgs',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'openOrdersAccount',
+            type: 'publicKey',
+          },
+          {
+            name: 'bump',
+            type: 'u8',
+          },
+          {
+            name: 'padding',
+            type: {
+              array: ['u8', 7],
+            },
+          },
+          {
+            name: 'netBaseNative',
+            docs: [
+              'Base native bought minus sold in fills, negative when more was sold',
+            ],
+            type: 'i64',
+          },
+          {
+            name: 'costBasisNative',
+            docs: [
+              'Quote native paid for `net_base_native`, negative for a net sold amount',
+            ],
+            type: 'i64',
+          },
+          {
+            name: 'realizedPnlNative',
+            docs: [
+              'Cumulative profit of fills reducing `net_base_native` against its average',
+              'entry price, in quote native units. Fees are tracked separately.',
+            ],
+            type: 'i64',
+          },
+          {
+            name: 'orderWindowStartTs',
+            docs: [
+              "Start of the current window of the market's `order_rate_limit`",
+            ],
+            type: 'u64',
+          },
+          {
+            name: 'ordersInWindow',
+            docs: ['New orders placed since `order_window_start_ts`'],
+            type: 'u32',
+          },
+          {
+            name: 'padding2',
+            type: {
+              array: ['u8', 4],
+            },
+          },
+          {
+            name: 'riskProgram',
+            docs: [
+              'Program approving new orders of the account through a `check_health` CPI,',
+              'see [`health_check`](crate::health_check)',
+            ],
+            type: {
+              defined: 'NonZeroPubkeyOption',
+            },
+          },
+          {
+            name: 'settleReceiver',
+            docs: [
+              'Program `settle_funds` routes the settled funds into, see',
+              '[`settle_receiver`](crate::settle_receiver)',
+            ],
+            type: {
+              defined: 'NonZeroPubkeyOption',
+            },
+          },
+          {
+            name: 'cancelAllAfterTs',
+            docs: [
+              'Time after which anyone can cancel all orders of the account with',
+              '`execute_scheduled_cancel`, 0 when none is scheduled',
+            ],
+            type: 'u64',
+          },
+          {
+            name: 'heartbeatInterval',
+            docs: [
+              'Seconds each `heartbeat` pushes `cancel_all_after_ts` out by, 0 when disabled',
+            ],
+            type: 'u32',
+          },
+          {
+            name: 'padding3',
+            type: {
+              array: ['u8', 4],
+            },
           },
-        },
+          {
+            name: 'heartbeatBounty',
+            docs: [
+              'Lamports escrowed in the extension for whoever executes the scheduled cancel',
+            ],
+            type: 'u64',
+          },
+          {
+            name: 'signedOrderNonce',
+            docs: [
+              'Nonce of the last order placed with `place_signed_order`, signed orders',
+              'need a higher one',
+            ],
+            type: 'u64',
+          },
+          {
+            name: 'reserved',
+            type: {
+              array: ['u8', 144],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: 'quoteQuality',
+      docs: [
+        "Quoting of a designated market maker's open orders account, measured against",
+        'the terms of its agreement, see `sample_quote_quality`.',
+        '',
+        'The account quotes while it has at least `min_size_lots` on each side within',
+        '`max_oracle_distance_bps` of the oracle price. Each sample credits the time since',
+        'the previous one to the state it observes, so the counterparty of the agreement',
+        'should sample at unpredictable times. Stats are kept per epoch of',
+        '`epoch_duration` seconds, the last complete one stays in `previous_epoch`.',
       ],
-      returns: {
-        option: 'u128',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'market',
+            type: 'publicKey',
+          },
+          {
+            name: 'openOrdersAccount',
+            type: 'publicKey',
+          },
+          {
+            name: 'minSizeLots',
+            type: 'i64',
+          },
+          {
+            name: 'epochDuration',
+            type: 'u64',
+          },
+          {
+            name: 'lastSampleTimestamp',
+            type: 'u64',
+          },
+          {
+            name: 'maxOracleDistanceBps',
+            type: 'u16',
+          },
+          {
+            name: 'bump',
+            type: 'u8',
+          },
+          {
+            name: 'padding',
+            type: {
+              array: ['u8', 5],
+            },
+          },
+          {
+            name: 'currentEpoch',
+            type: {
+              defined: 'QuoteQualityEpoch',
+            },
+          },
+          {
+            name: 'previousEpoch',
+            type: {
+              defined: 'QuoteQualityEpoch',
+            },
+          },
+          {
+            name: 'reserved',
+            type: {
+              array: ['u8', 64],
+            },
+          },
+        ],
       },
     },
     {
-      name: 'editOrder',
-      docs: ['Edit an order.'],
-      accounts: [
-        {
-          name: 'signer',
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: 'openOrdersAccount',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'openOrdersAdmin',
-          isMut: false,
-          isSigner: true,
-          isOptional: true,
-        },
-        {
-          name: 'userTokenAccount',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'market',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'bids',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'asks',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'eventHeap',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'marketVault',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'oracleA',
-          isMut: false,
-          isSigner: false,
-          isOptional: true,
-        },
-        {
-          name: 'oracleB',
-          isMut: false,
-          isSigner: false,
-          isOptional: true,
-        },
-        {
-          name: 'tokenProgram',
-          isMut: false,
-          isSigner: false,
-        },
+      name: 'poolState',
+      docs: [
+        'The pool state',
+        '',
+        'PDA of `[POOL_SEED, config, token_mint_0, token_mint_1]`',
+        '',
       ],
-      args: [
-        {
-          name: 'clientOrderId',
-          type: 'u64',
-        },
-        {
-          name: 'expectedCancelSize',
-          type: 'i64',
-        },
-        {
-          name: 'placeOrder',
-          type: {
-            defined: 'PlaceOrderArgs',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'bump',
+            docs: ['Bump to identify PDA'],
+            type: {
+              array: ['u8', 1],
+            },
+          },
+          {
+            name: 'ammConfig',
+            type: 'publicKey',
+          },
+          {
+            name: 'owner',
+            type: 'publicKey',
+          },
+          {
+            name: 'tokenMint0',
+            docs: [
+              'Token pair of the pool, where token_mint_0 address < token_mint_1 address',
+            ],
+            type: 'publicKey',
+          },
+          {
+            name: 'tokenMint1',
+            type: 'publicKey',
+          },
+          {
+            name: 'tokenVault0',
+            docs: ['Token pair vault'],
+            type: 'publicKey',
+          },
+          {
+            name: 'tokenVault1',
+            type: 'publicKey',
+          },
+          {
+            name: 'observationKey',
+            docs: ['observation account key'],
+            type: 'publicKey',
+          },
+          {
+            name: 'mintDecimals0',
+            docs: ['mint0 and mint1 decimals'],
+            type: 'u8',
+          },
+          {
+            name: 'mintDecimals1',
+            type: 'u8',
+          },
+          {
+            name: 'tickSpacing',
+            docs: ['The minimum number of ticks between initialized ticks'],
+            type: 'u16',
+          },
+          {
+            name: 'liquidity',
+            docs: ['The currently in range liquidity available to the pool.'],
+            type: 'u128',
+          },
+          {
+            name: 'sqrtPriceX64',
+            docs: [
+              'The current price of the pool as a sqrt(token_1/token_0) Q64.64 value',
+            ],
+            type: 'u128',
+          },
+          {
+            name: 'tickCurrent',
+            docs: [
+              'The current tick of the pool, i.e. according to the last tick transition that was run.',
+            ],
+            type: 'i32',
+          },
+          {
+            name: 'observationIndex',
+            docs: ['the most-recently updated index of the observations array'],
+            type: 'u16',
+          },
+          {
+            name: 'observationUpdateDuration',
+            type: 'u16',
+          },
+          {
+            name: 'feeGrowthGlobal0X64',
+            docs: [
+              'The fee growth as a Q64.64 number, i.e. fees of token_0 and token_1 collected per',
+              'unit of liquidity for the entire life of the pool.',
+            ],
+            type: 'u128',
+          },
+          {
+            name: 'feeGrowthGlobal1X64',
+            type: 'u128',
+          },
+          {
+            name: 'protocolFeesToken0',
+            docs: [
+              'The amounts of token_0 and token_1 that are owed to the protocol.',
+            ],
+            type: 'u64',
+          },
+          {
+            name: 'protocolFeesToken1',
+            type: 'u64',
+          },
+          {
+            name: 'swapInAmountToken0',
+            docs: ['The amounts in and out of swap token_0 and token_1'],
+            type: 'u128',
+          },
+          {
+            name: 'swapOutAmountToken1',
+            type: 'u128',
+          },
+          {
+            name: 'swapInAmountToken1',
+            type: 'u128',
+          },
+          {
+            name: 'swapOutAmountToken0',
+            type: 'u128',
+          },
+          {
+            name: 'status',
+            docs: [
+              'Bitwise representation of the state of the pool',
+              'bit0, 1: disable open position and increase liquidity, 0: normal',
+              'bit1, 1: disable decrease liquidity, 0: normal',
+              'bit2, 1: disable collect fee, 0: normal',
+              'bit3, 1: disable collect reward, 0: normal',
+              'bit4, 1: disable swap, 0: normal',
+            ],
+            type: 'u8',
+          },
+          {
+            name: 'padding',
+            docs: ['Leave blank for future use'],
+            type: {
+              array: ['u8', 7],
+            },
+          },
+          {
+            name: 'rewardInfos',
+            type: {
+              array: [
+                {
+                  defined: 'RewardInfo',
+                },
+                3,
+              ],
+            },
           },
-        },
-      ],
-      returns: {
-        option: 'u128',
-      },
-    },
-    {
-      name: 'editOrderPegged',
-      docs: ['Edit an order pegged.'],
-      accounts: [
-        {
-          name: 'signer',
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: 'openOrdersAccount',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'openOrdersAdmin',
-          isMut: false,
-          isSigner: true,
-          isOptional: true,
-        },
-        {
-          name: 'userTokenAccount',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'market',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'bids',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'asks',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'eventHeap',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'marketVault',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'oracleA',
-          isMut: false,
-          isSigner: false,
-          isOptional: true,
-        },
-        {
-          name: 'oracleB',
-          isMut: false,
-          isSigner: false,
-          isOptional: true,
-        },
-        {
-          name: 'tokenProgram',
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: 'clientOrderId',
-          type: 'u64',
-        },
-        {
-          name: 'expectedCancelSize',
-          type: 'i64',
-        },
-        {
-          name: 'placeOrder',
-          type: {
-            defined: 'PlaceOrderPeggedArgs',
+          {
+            name: 'tickArrayBitmap',
+            docs: ['Packed initialized tick array state'],
+            type: {
+              array: ['u64', 16],
+            },
           },
-        },
-      ],
-      returns: {
-        option: 'u128',
+          {
+            name: 'totalFeesToken0',
+            docs: ['except protocol_fee and fund_fee'],
+            type: 'u64',
+          },
+          {
+            name: 'totalFeesClaimedToken0',
+            docs: ['except protocol_fee and fund_fee'],
+            type: 'u64',
+          },
+          {
+            name: 'totalFeesToken1',
+            type: 'u64',
+          },
+          {
+            name: 'totalFeesClaimedToken1',
+            type: 'u64',
+          },
+          {
+            name: 'fundFeesToken0',
+            type: 'u64',
+          },
+          {
+            name: 'fundFeesToken1',
+            type: 'u64',
+          },
+          {
+            name: 'openTime',
+            type: 'u64',
+          },
+          {
+            name: 'padding1',
+            type: {
+              array: ['u64', 25],
+            },
+          },
+          {
+            name: 'padding2',
+            type: {
+              array: ['u64', 32],
+            },
+          },
+        ],
       },
     },
     {
-      name: 'placeOrders',
-      docs: ['Place multiple orders'],
-      accounts: [
-        {
-          name: 'signer',
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: 'openOrdersAccount',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'openOrdersAdmin',
-          isMut: false,
-          isSigner: true,
-          isOptional: true,
-        },
-        {
-          name: 'userQuoteAccount',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'userBaseAccount',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'market',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'bids',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'asks',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'eventHeap',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'marketQuoteVault',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'marketBaseVault',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'oracleA',
-          isMut: false,
-          isSigner: false,
-          isOptional: true,
-        },
-        {
-          name: 'oracleB',
-          isMut: false,
-          isSigner: false,
-          isOptional: true,
-        },
-        {
-          name: 'tokenProgram',
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: 'ordersType',
-          type: {
-            defined: 'PlaceOrderType',
+      name: 'aggregatorAccountData',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'name',
+            docs: ['Name of the aggregator to store on-chain.'],
+            type: {
+              array: ['u8', 32],
+            },
           },
-        },
-        {
-          name: 'bids',
-          type: {
-            vec: {
-              defined: 'PlaceMultipleOrdersArgs',
+          {
+            name: 'metadata',
+            docs: ['Metadata of the aggregator to store on-chain.'],
+            type: {
+              array: ['u8', 128],
+            },
+          },
+          {
+            name: 'reserved1',
+            docs: ['Reserved.'],
+            type: {
+              array: ['u8', 32],
+            },
+          },
+          {
+            name: 'queuePubkey',
+            docs: ['Pubkey of the queue the aggregator belongs to.'],
+            type: 'publicKey',
+          },
+          {
+            name: 'oracleRequestBatchSize',
+            docs: [
+              'CONFIGS',
+              'Number of oracles assigned to an update request.',
+            ],
+            type: 'u32',
+          },
+          {
+            name: 'minOracleResults',
+            docs: [
+              'Minimum number of oracle responses required before a round is validated.',
+            ],
+            type: 'u32',
+          },
+          {
+            name: 'minJobResults',
+            docs: [
+              'Minimum number of job results before an oracle accepts a result.',
+            ],
+            type: 'u32',
+          },
+          {
+            name: 'minUpdateDelaySeconds',
+            docs: [
+              'Minimum number of seconds required between aggregator rounds.',
+            ],
+            type: 'u32',
+          },
+          {
+            name: 'startAfter',
+            docs: [
+              'Unix timestamp for which no feed update will occur before.',
+            ],
+            type: 'i64',
+          },
+          {
+            name: 'varianceThreshold',
+            docs: [
+              'Change percentage required between a previous round and the current round. If variance percentage is not met, reject new oracle responses.',
+            ],
+            type: {
+              defined: 'SwitchboardDecimal',
             },
           },
-        },
-        {
-          name: 'asks',
-          type: {
-            vec: {
-              defined: 'PlaceMultipleOrdersArgs',
+          {
+            name: 'forceReportPeriod',
+            docs: [
+              'Number of seconds for which, even if the variance threshold is not passed, accept new responses from oracles.',
+            ],
+            type: 'i64',
+          },
+          {
+            name: 'expiration',
+            docs: ['Timestamp when the feed is no longer needed.'],
+            type: 'i64',
+          },
+          {
+            name: 'consecutiveFailureCount',
+            docs: [
+              'Counter for the number of consecutive failures before a feed is removed from a queue. If set to 0, failed feeds will remain on the queue.',
+            ],
+            type: 'u64',
+          },
+          {
+            name: 'nextAllowedUpdateTime',
+            docs: ['Timestamp when the next update request will be available.'],
+            type: 'i64',
+          },
+          {
+            name: 'isLocked',
+            docs: [
+              'Flag for whether an aggregators configuration is locked for editing.',
+            ],
+            type: 'bool',
+          },
+          {
+            name: 'crankPubkey',
+            docs: [
+              'Optional, public key of the crank the aggregator is currently using. Event based feeds do not need a crank.',
+            ],
+            type: 'publicKey',
+          },
+          {
+            name: 'latestConfirmedRound',
+            docs: [
+              'Latest confirmed update request result that has been accepted as valid.',
+            ],
+            type: {
+              defined: 'AggregatorRound',
             },
           },
-        },
-        {
-          name: 'limit',
-          type: 'u8',
-        },
-      ],
-      returns: {
-        vec: {
-          option: 'u128',
-        },
-      },
-    },
-    {
-      name: 'cancelAllAndPlaceOrders',
-      docs: ['Cancel orders and place multiple orders.'],
-      accounts: [
-        {
-          name: 'signer',
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: 'openOrdersAccount',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'openOrdersAdmin',
-          isMut: false,
-          isSigner: true,
-          isOptional: true,
-        },
-        {
-          name: 'userQuoteAccount',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'userBaseAccount',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'market',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'bids',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'asks',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'eventHeap',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'marketQuoteVault',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'marketBaseVault',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'oracleA',
-          isMut: false,
-          isSigner: false,
-          isOptional: true,
-        },
-        {
-          name: 'oracleB',
-          isMut: false,
-          isSigner: false,
-          isOptional: true,
-        },
-        {
-          name: 'tokenProgram',
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: 'ordersType',
-          type: {
-            defined: 'PlaceOrderType',
+          {
+            name: 'currentRound',
+            docs: [
+              'Oracle results from the current round of update request that has not been accepted as valid yet.',
+            ],
+            type: {
+              defined: 'AggregatorRound',
+            },
           },
-        },
-        {
-          name: 'bids',
-          type: {
-            vec: {
-              defined: 'PlaceMultipleOrdersArgs',
+          {
+            name: 'jobPubkeysData',
+            docs: [
+              'List of public keys containing the job definitions for how data is sourced off-chain by oracles.',
+            ],
+            type: {
+              array: ['publicKey', 16],
             },
           },
-        },
-        {
-          name: 'asks',
-          type: {
-            vec: {
-              defined: 'PlaceMultipleOrdersArgs',
+          {
+            name: 'jobHashes',
+            docs: [
+              'Used to protect against malicious RPC nodes providing incorrect task definitions to oracles before fulfillment.',
+            ],
+            type: {
+              array: [
+                {
+                  defined: 'Hash',
+                },
+                16,
+              ],
             },
           },
-        },
-        {
-          name: 'limit',
-          type: 'u8',
-        },
-      ],
-      returns: {
-        vec: {
-          option: 'u128',
-        },
-      },
-    },
-    {
-      name: 'placeOrderPegged',
-      docs: ['Place an oracle-peg order.'],
-      accounts: [
-        {
-          name: 'signer',
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: 'openOrdersAccount',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'openOrdersAdmin',
-          isMut: false,
-          isSigner: true,
-          isOptional: true,
-        },
-        {
-          name: 'userTokenAccount',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'market',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'bids',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'asks',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'eventHeap',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'marketVault',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'oracleA',
-          isMut: false,
-          isSigner: false,
-          isOptional: true,
-        },
-        {
-          name: 'oracleB',
-          isMut: false,
-          isSigner: false,
-          isOptional: true,
-        },
-        {
-          name: 'tokenProgram',
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: 'args',
-          type: {
-            defined: 'PlaceOrderPeggedArgs',
+          {
+            name: 'jobPubkeysSize',
+            docs: ['Number of jobs assigned to an oracle.'],
+            type: 'u32',
+          },
+          {
+            name: 'jobsChecksum',
+            docs: [
+              'Used to protect against malicious RPC nodes providing incorrect task definitions to oracles before fulfillment.',
+            ],
+            type: {
+              array: ['u8', 32],
+            },
           },
-        },
-      ],
-      returns: {
-        option: 'u128',
+          {
+            name: 'authority',
+            docs: [
+              'The account delegated as the authority for making account changes.',
+            ],
+            type: 'publicKey',
+          },
+          {
+            name: 'historyBuffer',
+            docs: [
+              'Optional, public key of a history buffer account storing the last N accepted results and their timestamps.',
+            ],
+            type: 'publicKey',
+          },
+          {
+            name: 'previousConfirmedRoundResult',
+            docs: ['The previous confirmed round result.'],
+            type: {
+              defined: 'SwitchboardDecimal',
+            },
+          },
+          {
+            name: 'previousConfirmedRoundSlot',
+            docs: ['The slot when the previous confirmed round was opened.'],
+            type: 'u64',
+          },
+          {
+            name: 'disableCrank',
+            docs: ['Whether an aggregator is permitted to join a crank.'],
+            type: 'bool',
+          },
+          {
+            name: 'jobWeights',
+            docs: [
+              "Job weights used for the weighted median of the aggregator's assigned job accounts.",
+            ],
+            type: {
+              array: ['u8', 16],
+            },
+          },
+          {
+            name: 'creationTimestamp',
+            docs: ['Unix timestamp when the feed was created.'],
+            type: 'i64',
+          },
+          {
+            name: 'resolutionMode',
+            docs: [
+              'Use sliding windoe or round based resolution',
+              'NOTE: This changes result propogation in latest_round_result',
+            ],
+            type: {
+              defined: 'AggregatorResolutionMode',
+            },
+          },
+          {
+            name: 'ebuf',
+            docs: ['Reserved for future info.'],
+            type: {
+              array: ['u8', 138],
+            },
+          },
+        ],
       },
     },
     {
-      name: 'placeTakeOrder',
+      name: 'takerProgramAllowlist',
       docs: [
-        'Place an order that shall take existing liquidity off of the book, not',
-        'add a new order off the book.',
+        'Per-market PDA listing the programs allowed to `place_take_order` on a',
+        'permissioned market through CPI without the `open_orders_admin` signature.',
         '',
-        'This type of order allows for instant token settlement for the taker.',
-      ],
-      accounts: [
-        {
-          name: 'signer',
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: 'penaltyPayer',
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: 'market',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'marketAuthority',
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: 'bids',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'asks',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'marketBaseVault',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'marketQuoteVault',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'eventHeap',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'userBaseAccount',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'userQuoteAccount',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'oracleA',
-          isMut: false,
-          isSigner: false,
-          isOptional: true,
-        },
-        {
-          name: 'oracleB',
-          isMut: false,
-          isSigner: false,
-          isOptional: true,
-        },
-        {
-          name: 'tokenProgram',
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: 'systemProgram',
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: 'openOrdersAdmin',
-          isMut: false,
-          isSigner: true,
-          isOptional: true,
-        },
+        'This lets an aggregator be granted access once instead of whitelisting each',
+        'of its users.',
       ],
-      args: [
-        {
-          name: 'args',
-          type: {
-            defined: 'PlaceTakeOrderArgs',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'market',
+            type: 'publicKey',
           },
-        },
-      ],
+          {
+            name: 'programs',
+            docs: ['Unused slots are zero.'],
+            type: {
+              array: ['publicKey', 8],
+            },
+          },
+          {
+            name: 'bump',
+            type: 'u8',
+          },
+          {
+            name: 'padding',
+            type: {
+              array: ['u8', 7],
+            },
+          },
+          {
+            name: 'reserved',
+            type: {
+              array: ['u8', 64],
+            },
+          },
+        ],
+      },
     },
     {
-      name: 'consumeEvents',
+      name: 'takerRebateCampaign',
       docs: [
-        'Process up to `limit` [events](crate::state::AnyEvent).',
-        '',
-        "When a user places a 'take' order, they do not know beforehand which",
-        "market maker will have placed the 'make' order that they get executed",
-        "against. This prevents them from passing in a market maker's",
-        '[`OpenOrdersAccount`](crate::state::OpenOrdersAccount), which is needed',
-        'to credit/debit the relevant tokens to/from the maker. As such, Openbook',
-        "uses a 'crank' system, where `place_order` only emits events, and",
-        '`consume_events` handles token settlement.',
-        '',
-        'Currently, there are two types of events: [`FillEvent`](crate::state::FillEvent)s',
-        'and [`OutEvent`](crate::state::OutEvent)s.',
+        'Promotional rebate of a share of the taker fees of a market, funded by its admin.',
         '',
-        'A `FillEvent` is emitted when an order is filled, and it is handled by',
-        'debiting whatever the taker is selling from the taker and crediting',
-        'it to the maker, and debiting whatever the taker is buying from the',
-        'maker and crediting it to the taker. Note that *no tokens are moved*,',
-        "these are just debits and credits to each party's [`Position`](crate::state::Position).",
+        'While the campaign runs, takers with an open orders account accrue `rebate_bps`',
+        'of the taker fees they pay, as long as the campaign account is among the remaining',
+        'accounts of the order. Rebates are paid in quote out of `rebate_vault` with',
+        '`claim_taker_rebates`.',
         '',
-        'An `OutEvent` is emitted when a limit order needs to be removed from',
-        'the book during a `place_order` invocation, and it is handled by',
-        'crediting whatever the maker would have sold (quote token in a bid,',
-        'base token in an ask) back to the maker.',
-      ],
-      accounts: [
-        {
-          name: 'consumeEventsAdmin',
-          isMut: false,
-          isSigner: true,
-          isOptional: true,
-        },
-        {
-          name: 'market',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'eventHeap',
-          isMut: true,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: 'limit',
-          type: 'u64',
-        },
+        'Only the budget funded with `fund_taker_rebate_campaign` is rebated, rebates of',
+        'fills past it are dropped.',
       ],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'market',
+            type: 'publicKey',
+          },
+          {
+            name: 'rebateVault',
+            docs: ['Quote token account owned by this account'],
+            type: 'publicKey',
+          },
+          {
+            name: 'startTimestamp',
+            type: 'u64',
+          },
+          {
+            name: 'endTimestamp',
+            type: 'u64',
+          },
+          {
+            name: 'budgetRemaining',
+            docs: ['Funded rebates not accrued yet'],
+            type: 'u64',
+          },
+          {
+            name: 'rebatesOutstanding',
+            docs: [
+              'Rebates accrued to open orders accounts and not claimed yet',
+            ],
+            type: 'u64',
+          },
+          {
+            name: 'rebatesAccruedTotal',
+            docs: ['Rebates accrued since creation'],
+            type: 'u64',
+          },
+          {
+            name: 'rebateBps',
+            docs: ['Share of the taker fees rebated'],
+            type: 'u16',
+          },
+          {
+            name: 'bump',
+            type: 'u8',
+          },
+          {
+            name: 'padding',
+            type: {
+              array: ['u8', 5],
+            },
+          },
+          {
+            name: 'reserved',
+            type: {
+              array: ['u8', 64],
+            },
+          },
+        ],
+      },
     },
     {
-      name: 'consumeGivenEvents',
+      name: 'tokenGate',
       docs: [
-        'Process the [events](crate::state::AnyEvent) at the given positions.',
-      ],
-      accounts: [
-        {
-          name: 'consumeEventsAdmin',
-          isMut: false,
-          isSigner: true,
-          isOptional: true,
-        },
-        {
-          name: 'market',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'eventHeap',
-          isMut: true,
-          isSigner: false,
-        },
+        'Per-market PDA letting holders of a given token (e.g. a KYC NFT) trade on a',
+        'permissioned market without the `open_orders_admin` co-signing every order.',
+        '',
+        'The holding is checked on every order placement, so selling or moving the',
+        'token revokes access right away.',
       ],
-      args: [
-        {
-          name: 'slots',
-          type: {
-            vec: 'u64',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'market',
+            type: 'publicKey',
           },
-        },
-      ],
+          {
+            name: 'mint',
+            type: 'publicKey',
+          },
+          {
+            name: 'minAmount',
+            docs: [
+              'Minimum balance, in native units, a token account must hold to place orders.',
+            ],
+            type: 'u64',
+          },
+          {
+            name: 'bump',
+            type: 'u8',
+          },
+          {
+            name: 'padding',
+            type: {
+              array: ['u8', 7],
+            },
+          },
+          {
+            name: 'reserved',
+            type: {
+              array: ['u8', 64],
+            },
+          },
+        ],
+      },
     },
     {
-      name: 'cancelOrder',
+      name: 'triggerOrder',
       docs: [
-        'Cancel an order by its `order_id`.',
+        'An order of an open orders account waiting for a price condition, e.g. a stop',
+        'loss, take profit or limit-if-touched order.',
         '',
-        "Note that this doesn't emit an [`OutEvent`](crate::state::OutEvent) because a",
-        'maker knows that they will be passing in their own [`OpenOrdersAccount`](crate::state::OpenOrdersAccount).',
-      ],
-      accounts: [
-        {
-          name: 'signer',
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: 'openOrdersAccount',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'market',
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: 'bids',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'asks',
-          isMut: true,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: 'orderId',
-          type: 'u128',
-        },
+        'Created by the owner or delegate with `create_trigger_order`. Once the',
+        'condition is met anyone can place the order with `execute_trigger` and gets the',
+        'rent of this account in return. The order is funded from the free balances of',
+        'the open orders account only, as no token account signs for it.',
       ],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'owner',
+            type: 'publicKey',
+          },
+          {
+            name: 'market',
+            type: 'publicKey',
+          },
+          {
+            name: 'openOrdersAccount',
+            type: 'publicKey',
+          },
+          {
+            name: 'triggerId',
+            docs: [
+              'Chosen by the owner, distinguishes the trigger orders of an account',
+            ],
+            type: 'u64',
+          },
+          {
+            name: 'triggerPriceLots',
+            type: 'i64',
+          },
+          {
+            name: 'priceLots',
+            type: 'i64',
+          },
+          {
+            name: 'maxBaseLots',
+            type: 'i64',
+          },
+          {
+            name: 'maxQuoteLotsIncludingFees',
+            type: 'i64',
+          },
+          {
+            name: 'clientOrderId',
+            type: 'u64',
+          },
+          {
+            name: 'expiryTimestamp',
+            docs: [
+              'Expiry of the placed order, see `PlaceOrderArgs`. Also the last moment the',
+              'trigger can be executed, 0 for never.',
+            ],
+            type: 'u64',
+          },
+          {
+            name: 'condition',
+            docs: ['See [`TriggerCondition`]'],
+            type: 'u8',
+          },
+          {
+            name: 'side',
+            docs: ['See [`Side`]'],
+            type: 'u8',
+          },
+          {
+            name: 'orderType',
+            docs: ['See [`PlaceOrderType`]'],
+            type: 'u8',
+          },
+          {
+            name: 'selfTradeBehavior',
+            docs: ['See [`SelfTradeBehavior`]'],
+            type: 'u8',
+          },
+          {
+            name: 'limit',
+            docs: ['Max orders of the book to fill when executed'],
+            type: 'u8',
+          },
+          {
+            name: 'bump',
+            type: 'u8',
+          },
+          {
+            name: 'padding',
+            type: {
+              array: ['u8', 2],
+            },
+          },
+          {
+            name: 'reserved',
+            type: {
+              array: ['u8', 64],
+            },
+          },
+        ],
+      },
     },
+  ],
+  types: [
     {
-      name: 'cancelOrderByClientOrderId',
-      docs: [
-        'Cancel an order by its `client_order_id`.',
-        '',
-        "Note that this doesn't emit an [`OutEvent`](crate::state::OutEvent) because a",
-        'maker knows that they will be passing in their own [`OpenOrdersAccount`](crate::state::OpenOrdersAccount).',
-      ],
-      accounts: [
-        {
-          name: 'signer',
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: 'openOrdersAccount',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'market',
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: 'bids',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'asks',
-          isMut: true,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: 'clientOrderId',
-          type: 'u64',
-        },
-      ],
-      returns: 'i64',
+      name: 'OnFillArgs',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'market',
+            type: 'publicKey',
+          },
+          {
+            name: 'fills',
+            type: {
+              vec: {
+                defined: 'FillEvent',
+              },
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: 'HealthCheckOrder',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'side',
+            type: {
+              defined: 'Side',
+            },
+          },
+          {
+            name: 'priceLots',
+            docs: [
+              'Worst price the order can execute at: the limit price, or the peg limit of',
+              'oracle pegged orders. `None` for market orders and unlimited pegged orders.',
+            ],
+            type: {
+              option: 'i64',
+            },
+          },
+          {
+            name: 'maxBaseLots',
+            type: 'i64',
+          },
+          {
+            name: 'maxQuoteLotsIncludingFees',
+            type: 'i64',
+          },
+        ],
+      },
     },
     {
-      name: 'cancelAllOrders',
-      docs: ['Cancel up to `limit` orders, optionally filtering by side'],
-      accounts: [
-        {
-          name: 'signer',
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: 'openOrdersAccount',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'market',
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: 'bids',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'asks',
-          isMut: true,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: 'sideOption',
-          type: {
-            option: {
-              defined: 'Side',
+      name: 'CheckHealthArgs',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'market',
+            type: 'publicKey',
+          },
+          {
+            name: 'openOrdersAccount',
+            type: 'publicKey',
+          },
+          {
+            name: 'orders',
+            type: {
+              vec: {
+                defined: 'HealthCheckOrder',
+              },
             },
           },
-        },
-        {
-          name: 'limit',
-          type: 'u8',
-        },
-      ],
+        ],
+      },
     },
     {
-      name: 'deposit',
+      name: 'NonZeroPubkeyOption',
       docs: [
-        "Deposit a certain amount of `base` and `quote` lamports into one's",
-        '[`Position`](crate::state::Position).',
-        '',
-        'Makers might wish to `deposit`, rather than have actual tokens moved for',
-        'each trade, in order to reduce CUs.',
-      ],
-      accounts: [
-        {
-          name: 'owner',
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: 'userBaseAccount',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'userQuoteAccount',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'openOrdersAccount',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'market',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'marketBaseVault',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'marketQuoteVault',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'tokenProgram',
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: 'baseAmount',
-          type: 'u64',
-        },
-        {
-          name: 'quoteAmount',
-          type: 'u64',
-        },
+        'Like `Option`, but implemented for `Pubkey` to be used with `zero_copy`',
       ],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'key',
+            type: 'publicKey',
+          },
+        ],
+      },
     },
     {
-      name: 'refill',
-      docs: [
-        'Refill a certain amount of `base` and `quote` lamports. The amount being passed is the',
-        'total lamports that the [`Position`](crate::state::Position) will have.',
-        '',
-        'Makers might wish to `refill`, rather than have actual tokens moved for',
-        'each trade, in order to reduce CUs.',
-      ],
-      accounts: [
-        {
-          name: 'owner',
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: 'userBaseAccount',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'userQuoteAccount',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'openOrdersAccount',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'market',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'marketBaseVault',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'marketQuoteVault',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'tokenProgram',
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: 'baseAmount',
-          type: 'u64',
-        },
-        {
-          name: 'quoteAmount',
-          type: 'u64',
-        },
-      ],
+      name: 'OnSettleArgs',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'market',
+            type: 'publicKey',
+          },
+          {
+            name: 'openOrdersAccount',
+            type: 'publicKey',
+          },
+          {
+            name: 'owner',
+            docs: [
+              'Owner of the open orders account, who the funds are settled for',
+            ],
+            type: 'publicKey',
+          },
+          {
+            name: 'baseNative',
+            type: 'u64',
+          },
+          {
+            name: 'quoteNative',
+            type: 'u64',
+          },
+        ],
+      },
+    },
+    {
+      name: 'SignedOrder',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'market',
+            type: 'publicKey',
+          },
+          {
+            name: 'openOrdersAccount',
+            type: 'publicKey',
+          },
+          {
+            name: 'nonce',
+            docs: [
+              'Has to exceed the nonce of the last signed order placed for the account,',
+              'so every signed order is placed once at most',
+            ],
+            type: 'u64',
+          },
+          {
+            name: 'validUntilTs',
+            docs: ['Last moment the order can be placed, 0 for never'],
+            type: 'u64',
+          },
+          {
+            name: 'order',
+            type: {
+              defined: 'PlaceOrderArgs',
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: 'DepthLevel',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'priceLots',
+            type: 'i64',
+          },
+          {
+            name: 'quantity',
+            docs: ['Base lots resting at `price_lots`'],
+            type: 'i64',
+          },
+          {
+            name: 'cumulativeQuantity',
+            docs: ['Base lots resting at `price_lots` or better'],
+            type: 'i64',
+          },
+        ],
+      },
     },
     {
-      name: 'settleFunds',
-      docs: ['Withdraw any available tokens.'],
-      accounts: [
-        {
-          name: 'owner',
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: 'penaltyPayer',
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: 'openOrdersAccount',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'market',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'marketAuthority',
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: 'marketBaseVault',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'marketQuoteVault',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'userBaseAccount',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'userQuoteAccount',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'referrerAccount',
-          isMut: true,
-          isSigner: false,
-          isOptional: true,
-        },
-        {
-          name: 'tokenProgram',
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: 'systemProgram',
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [],
+      name: 'DmmSlot',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'openOrdersAccount',
+            docs: ['Zero for a free slot'],
+            type: 'publicKey',
+          },
+          {
+            name: 'priority',
+            docs: [
+              'Higher priorities win when booting orders from a full book side',
+            ],
+            type: 'u8',
+          },
+          {
+            name: 'padding',
+            type: 'u8',
+          },
+          {
+            name: 'minUptimeBps',
+            docs: ['Least uptime of a quote quality epoch to keep the slot'],
+            type: 'u16',
+          },
+          {
+            name: 'padding2',
+            type: {
+              array: ['u8', 4],
+            },
+          },
+        ],
+      },
     },
     {
-      name: 'settleFundsExpired',
-      docs: [
-        'Withdraw any available tokens when the market is expired (only',
-        '[`close_market_admin`](crate::state::Market::close_market_admin)).',
-      ],
-      accounts: [
-        {
-          name: 'closeMarketAdmin',
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: 'owner',
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: 'penaltyPayer',
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: 'openOrdersAccount',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'market',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'marketAuthority',
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: 'marketBaseVault',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'marketQuoteVault',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'userBaseAccount',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'userQuoteAccount',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'referrerAccount',
-          isMut: true,
-          isSigner: false,
-          isOptional: true,
-        },
-        {
-          name: 'tokenProgram',
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: 'systemProgram',
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [],
+      name: 'FillRecord',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'price',
+            type: 'i64',
+          },
+          {
+            name: 'quantity',
+            type: 'i64',
+          },
+          {
+            name: 'timestamp',
+            type: 'u64',
+          },
+          {
+            name: 'eventSeqNum',
+            type: 'u64',
+          },
+          {
+            name: 'maker',
+            type: 'publicKey',
+          },
+          {
+            name: 'taker',
+            type: 'publicKey',
+          },
+          {
+            name: 'takerSide',
+            type: 'u8',
+          },
+          {
+            name: 'padding',
+            type: {
+              array: ['u8', 7],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: 'MarketRegistryEntry',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'market',
+            type: 'publicKey',
+          },
+          {
+            name: 'baseMint',
+            type: 'publicKey',
+          },
+          {
+            name: 'quoteMint',
+            type: 'publicKey',
+          },
+          {
+            name: 'baseLotSize',
+            type: 'i64',
+          },
+          {
+            name: 'quoteLotSize',
+            type: 'i64',
+          },
+        ],
+      },
+    },
+    {
+      name: 'HourlyStats',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'hour',
+            docs: ['Hours since the unix epoch'],
+            type: 'u64',
+          },
+          {
+            name: 'quoteVolume',
+            docs: ['Native quote traded'],
+            type: 'u64',
+          },
+          {
+            name: 'tradeCount',
+            type: 'u64',
+          },
+          {
+            name: 'highLots',
+            type: 'i64',
+          },
+          {
+            name: 'lowLots',
+            type: 'i64',
+          },
+        ],
+      },
     },
     {
-      name: 'sweepFees',
-      docs: ["Sweep fees, as a [`Market`](crate::state::Market)'s admin."],
-      accounts: [
-        {
-          name: 'collectFeeAdmin',
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: 'market',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'marketAuthority',
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: 'marketQuoteVault',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'tokenReceiverAccount',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'tokenProgram',
-          isMut: false,
-          isSigner: false,
-        },
+      name: 'Stats24h',
+      docs: [
+        'Totals of the fills of the current and previous 23 hours, see [`MarketStats::last_24h`]',
       ],
-      args: [],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'quoteVolume',
+            type: 'u64',
+          },
+          {
+            name: 'tradeCount',
+            type: 'u64',
+          },
+          {
+            name: 'highLots',
+            docs: ['Highest and lowest fill prices, 0 without fills'],
+            type: 'i64',
+          },
+          {
+            name: 'lowLots',
+            type: 'i64',
+          },
+        ],
+      },
     },
     {
-      name: 'setDelegate',
-      docs: [
-        'Update the [`delegate`](crate::state::OpenOrdersAccount::delegate) of an open orders account.',
-      ],
-      accounts: [
-        {
-          name: 'owner',
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: 'openOrdersAccount',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'delegateAccount',
-          isMut: false,
-          isSigner: false,
-          isOptional: true,
-        },
-      ],
-      args: [],
+      name: 'Position',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'bidsBaseLots',
+            docs: ['Base lots in open bids'],
+            type: 'i64',
+          },
+          {
+            name: 'asksBaseLots',
+            docs: ['Base lots in open asks'],
+            type: 'i64',
+          },
+          {
+            name: 'baseFreeNative',
+            type: 'u64',
+          },
+          {
+            name: 'quoteFreeNative',
+            type: 'u64',
+          },
+          {
+            name: 'lockedMakerFees',
+            type: 'u64',
+          },
+          {
+            name: 'referrerRebatesAvailable',
+            type: 'u64',
+          },
+          {
+            name: 'penaltyHeapCount',
+            docs: [
+              'Count of ixs when events are added to the heap',
+              'To avoid this, send remaining accounts in order to process the events',
+            ],
+            type: 'u64',
+          },
+          {
+            name: 'makerVolume',
+            docs: [
+              'Cumulative maker volume in quote native units (display only)',
+            ],
+            type: 'u128',
+          },
+          {
+            name: 'takerVolume',
+            docs: [
+              'Cumulative taker volume in quote native units (display only)',
+            ],
+            type: 'u128',
+          },
+          {
+            name: 'bidsQuoteLots',
+            docs: ['Quote lots in open bids'],
+            type: 'i64',
+          },
+          {
+            name: 'makerFeesPaid',
+            docs: ['Cumulative fees paid on maker fills in quote native units'],
+            type: 'u64',
+          },
+          {
+            name: 'makerRebatesReceived',
+            docs: [
+              'Cumulative rebates received on maker fills in quote native units',
+            ],
+            type: 'u64',
+          },
+          {
+            name: 'takerFeesPaid',
+            docs: [
+              'Cumulative fees paid on taker trades in quote native units',
+            ],
+            type: 'u64',
+          },
+          {
+            name: 'makerRewardPoints',
+            docs: [
+              'Base lots times seconds at the top of the book not claimed as maker rewards',
+              'yet, see `MakerRewards`',
+            ],
+            type: 'u64',
+          },
+          {
+            name: 'makerRewardsClaimed',
+            docs: ['Cumulative maker rewards claimed in reward native units'],
+            type: 'u64',
+          },
+          {
+            name: 'takerRebatesAccrued',
+            docs: [
+              'Taker fee rebates accrued and not claimed yet, see `TakerRebateCampaign`',
+            ],
+            type: 'u64',
+          },
+          {
+            name: 'makerRebatesAvailable',
+            docs: [
+              'Maker rebates credited to `quote_free_native` since it was last settled,',
+              'see `claim_rebates`',
+            ],
+            type: 'u64',
+          },
+          {
+            name: 'reserved',
+            type: {
+              array: ['u8', 8],
+            },
+          },
+        ],
+      },
     },
     {
-      name: 'setMarketExpired',
-      docs: [
-        'Set market to expired before pruning orders and closing the market (only',
-        '[`close_market_admin`](crate::state::Market::close_market_admin)).',
-      ],
-      accounts: [
-        {
-          name: 'closeMarketAdmin',
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: 'market',
-          isMut: true,
-          isSigner: false,
-        },
-      ],
-      args: [],
+      name: 'OpenOrder',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'id',
+            type: 'u128',
+          },
+          {
+            name: 'clientId',
+            type: 'u64',
+          },
+          {
+            name: 'lockedPrice',
+            docs: ["Price at which user's assets were locked"],
+            type: 'i64',
+          },
+          {
+            name: 'isFree',
+            type: 'u8',
+          },
+          {
+            name: 'sideAndTree',
+            type: 'u8',
+          },
+          {
+            name: 'padding',
+            type: {
+              array: ['u8', 6],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: 'OracleConfig',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'confFilter',
+            type: 'f64',
+          },
+          {
+            name: 'maxStalenessSlots',
+            type: 'i64',
+          },
+          {
+            name: 'reserved',
+            type: {
+              array: ['u8', 72],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: 'OracleConfigParams',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'confFilter',
+            type: 'f32',
+          },
+          {
+            name: 'maxStalenessSlots',
+            type: {
+              option: 'u32',
+            },
+          },
+        ],
+      },
     },
     {
-      name: 'pruneOrders',
-      docs: [
-        'Remove orders from the book when the market is expired (only',
-        '[`close_market_admin`](crate::state::Market::close_market_admin)).',
-      ],
-      accounts: [
-        {
-          name: 'closeMarketAdmin',
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: 'openOrdersAccount',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'market',
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: 'bids',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'asks',
-          isMut: true,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: 'limit',
-          type: 'u8',
-        },
-      ],
+      name: 'EventHeapHeader',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'freeHead',
+            type: 'u16',
+          },
+          {
+            name: 'usedHead',
+            type: 'u16',
+          },
+          {
+            name: 'count',
+            type: 'u16',
+          },
+          {
+            name: 'padd',
+            type: 'u16',
+          },
+          {
+            name: 'seqNum',
+            type: 'u64',
+          },
+        ],
+      },
     },
     {
-      name: 'stubOracleCreate',
-      accounts: [
-        {
-          name: 'payer',
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: 'owner',
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: 'oracle',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'mint',
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: 'systemProgram',
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: 'price',
-          type: 'f64',
-        },
-      ],
+      name: 'EventNode',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'next',
+            type: 'u16',
+          },
+          {
+            name: 'prev',
+            type: 'u16',
+          },
+          {
+            name: 'pad',
+            type: {
+              array: ['u8', 4],
+            },
+          },
+          {
+            name: 'event',
+            type: {
+              defined: 'AnyEvent',
+            },
+          },
+        ],
+      },
     },
     {
-      name: 'stubOracleClose',
-      accounts: [
-        {
-          name: 'owner',
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: 'oracle',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'solDestination',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'tokenProgram',
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [],
+      name: 'AnyEvent',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'eventType',
+            type: 'u8',
+          },
+          {
+            name: 'padding',
+            type: {
+              array: ['u8', 143],
+            },
+          },
+        ],
+      },
     },
     {
-      name: 'stubOracleSet',
-      accounts: [
-        {
-          name: 'owner',
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: 'oracle',
-          isMut: true,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: 'price',
-          type: 'f64',
-        },
-      ],
+      name: 'FillEvent',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'eventType',
+            type: 'u8',
+          },
+          {
+            name: 'takerSide',
+            type: 'u8',
+          },
+          {
+            name: 'makerOut',
+            type: 'u8',
+          },
+          {
+            name: 'makerSlot',
+            type: 'u8',
+          },
+          {
+            name: 'makerSecondsAtTop',
+            docs: [
+              'Seconds the maker order had been at the top of the book, see',
+              '`Market::seconds_at_top`',
+            ],
+            type: 'u32',
+          },
+          {
+            name: 'timestamp',
+            type: 'u64',
+          },
+          {
+            name: 'marketSeqNum',
+            type: 'u64',
+          },
+          {
+            name: 'maker',
+            type: 'publicKey',
+          },
+          {
+            name: 'makerTimestamp',
+            type: 'u64',
+          },
+          {
+            name: 'taker',
+            type: 'publicKey',
+          },
+          {
+            name: 'takerClientOrderId',
+            type: 'u64',
+          },
+          {
+            name: 'price',
+            type: 'i64',
+          },
+          {
+            name: 'pegLimit',
+            type: 'i64',
+          },
+          {
+            name: 'quantity',
+            type: 'i64',
+          },
+          {
+            name: 'makerClientOrderId',
+            type: 'u64',
+          },
+          {
+            name: 'eventSeqNum',
+            docs: ['Per-market sequence number shared by fill and out events'],
+            type: 'u64',
+          },
+        ],
+      },
     },
-  ],
-  accounts: [
     {
-      name: 'market',
+      name: 'OutEvent',
       type: {
         kind: 'struct',
         fields: [
           {
-            name: 'bump',
-            docs: ['PDA bump'],
+            name: 'eventType',
             type: 'u8',
           },
           {
-            name: 'baseDecimals',
-            docs: [
-              'Number of decimals used for the base token.',
-              '',
-              "Used to convert the oracle's price into a native/native price.",
-            ],
+            name: 'side',
             type: 'u8',
           },
           {
-            name: 'quoteDecimals',
+            name: 'ownerSlot',
             type: 'u8',
           },
           {
-            name: 'padding1',
+            name: 'reason',
+            docs: ['OutReason'],
+            type: 'u8',
+          },
+          {
+            name: 'padding0',
             type: {
-              array: ['u8', 5],
+              array: ['u8', 4],
             },
           },
           {
-            name: 'marketAuthority',
-            type: 'publicKey',
+            name: 'timestamp',
+            type: 'u64',
           },
           {
-            name: 'timeExpiry',
-            docs: [
-              'No expiry = 0. Market will expire and no trading allowed after time_expiry',
-            ],
-            type: 'i64',
+            name: 'seqNum',
+            type: 'u64',
           },
           {
-            name: 'collectFeeAdmin',
-            docs: ['Admin who can collect fees from the market'],
+            name: 'owner',
             type: 'publicKey',
           },
           {
-            name: 'openOrdersAdmin',
-            docs: ['Admin who must sign off on all order creations'],
-            type: {
-              defined: 'NonZeroPubkeyOption',
-            },
+            name: 'quantity',
+            type: 'i64',
           },
           {
-            name: 'consumeEventsAdmin',
-            docs: ['Admin who must sign off on all event consumptions'],
-            type: {
-              defined: 'NonZeroPubkeyOption',
-            },
+            name: 'eventSeqNum',
+            docs: ['Per-market sequence number shared by fill and out events'],
+            type: 'u64',
           },
           {
-            name: 'closeMarketAdmin',
-            docs: [
-              'Admin who can set market expired, prune orders and close the market',
-            ],
-            type: {
-              defined: 'NonZeroPubkeyOption',
-            },
+            name: 'price',
+            docs: ['See [`LeafNode::price_lots_or_peg_offset`]'],
+            type: 'i64',
           },
           {
-            name: 'name',
-            docs: ['Name. Trailing zero bytes are ignored.'],
-            type: {
-              array: ['u8', 16],
-            },
+            name: 'pegLimit',
+            type: 'i64',
           },
           {
-            name: 'bids',
-            docs: ['Address of the BookSide account for bids'],
-            type: 'publicKey',
+            name: 'clientOrderId',
+            type: 'u64',
           },
           {
-            name: 'asks',
-            docs: ['Address of the BookSide account for asks'],
-            type: 'publicKey',
+            name: 'padding1',
+            type: {
+              array: ['u8', 48],
+            },
           },
+        ],
+      },
+    },
+    {
+      name: 'InnerNode',
+      docs: [
+        'InnerNodes and LeafNodes compose the binary tree of orders.',
+        '',
+        'Each InnerNode has exactly two children, which are either InnerNodes themselves,',
+        'or LeafNodes. The children share the top `prefix_len` bits of `key`. The left',
+        'child has a 0 in the next bit, and the right a 1.',
+      ],
+      type: {
+        kind: 'struct',
+        fields: [
           {
-            name: 'eventHeap',
-            docs: ['Address of the EventHeap account'],
-            type: 'publicKey',
+            name: 'tag',
+            type: 'u8',
           },
           {
-            name: 'oracleA',
-            docs: ['Oracles account address'],
+            name: 'padding',
             type: {
-              defined: 'NonZeroPubkeyOption',
+              array: ['u8', 3],
             },
           },
           {
-            name: 'oracleB',
-            type: {
-              defined: 'NonZeroPubkeyOption',
-            },
+            name: 'prefixLen',
+            docs: [
+              'number of highest `key` bits that all children share',
+              "e.g. if it's 2, the two highest bits of `key` will be the same on all children",
+            ],
+            type: 'u32',
           },
           {
-            name: 'oracleConfig',
-            docs: ['Oracle configuration'],
+            name: 'key',
+            docs: ['only the top `prefix_len` bits of `key` are relevant'],
+            type: 'u128',
+          },
+          {
+            name: 'children',
+            docs: ['indexes into `BookSide::nodes`'],
             type: {
-              defined: 'OracleConfig',
+              array: ['u32', 2],
             },
           },
           {
-            name: 'quoteLotSize',
+            name: 'childEarliestExpiry',
             docs: [
-              'Number of quote native in a quote lot. Must be a power of 10.',
+              'The earliest expiry timestamp for the left and right subtrees.',
               '',
-              'Primarily useful for increasing the tick size on the market: A lot price',
-              'of 1 becomes a native price of quote_lot_size/base_lot_size becomes a',
-              'ui price of quote_lot_size*base_decimals/base_lot_size/quote_decimals.',
+              'Needed to be able to find and remove expired orders without having to',
+              'iterate through the whole bookside.',
             ],
-            type: 'i64',
+            type: {
+              array: ['u64', 2],
+            },
           },
           {
-            name: 'baseLotSize',
-            docs: [
-              'Number of base native in a base lot. Must be a power of 10.',
-              '',
-              'Example: If base decimals for the underlying asset is 6, base lot size',
-              'is 100 and and base position lots is 10_000 then base position native is',
-              '1_000_000 and base position ui is 1.',
-            ],
-            type: 'i64',
+            name: 'reserved',
+            type: {
+              array: ['u8', 40],
+            },
           },
+        ],
+      },
+    },
+    {
+      name: 'LeafNode',
+      docs: ['LeafNodes represent an order in the binary tree'],
+      type: {
+        kind: 'struct',
+        fields: [
           {
-            name: 'seqNum',
-            docs: ['Total number of orders seen'],
-            type: 'u64',
+            name: 'tag',
+            docs: ['NodeTag'],
+            type: 'u8',
           },
           {
-            name: 'registrationTime',
-            docs: ['Timestamp in seconds that the market was registered at.'],
-            type: 'i64',
+            name: 'ownerSlot',
+            docs: ["Index into the owning OpenOrdersAccount's OpenOrders"],
+            type: 'u8',
           },
           {
-            name: 'makerFee',
+            name: 'timeInForce',
             docs: [
-              'Fees',
-              '',
-              'Fee (in 10^-6) when matching maker orders.',
-              'maker_fee < 0 it means some of the taker_fees goes to the maker',
-              'maker_fee > 0, it means no taker_fee to the maker, and maker fee goes to the referral',
+              'Time in seconds after `timestamp` at which the order expires.',
+              'A value of 0 means no expiry.',
             ],
-            type: 'i64',
+            type: 'u16',
           },
           {
-            name: 'takerFee',
-            docs: ['Fee (in 10^-6) for taker orders, always >= 0.'],
-            type: 'i64',
+            name: 'dmmPriority',
+            docs: [
+              '`OpenOrdersAccount::dmm_priority` of the owner when the order was placed',
+            ],
+            type: 'u8',
           },
           {
-            name: 'feesAccrued',
-            docs: ['Total fees accrued in native quote'],
-            type: 'u128',
+            name: 'padding',
+            type: {
+              array: ['u8', 3],
+            },
           },
           {
-            name: 'feesToReferrers',
-            docs: ['Total fees settled in native quote'],
+            name: 'key',
+            docs: ['The binary tree key, see new_node_key()'],
             type: 'u128',
           },
           {
-            name: 'referrerRebatesAccrued',
-            docs: ['Referrer rebates to be distributed'],
-            type: 'u64',
+            name: 'owner',
+            docs: ['Address of the owning OpenOrdersAccount'],
+            type: 'publicKey',
           },
           {
-            name: 'feesAvailable',
-            docs: ['Fees generated and available to withdraw via sweep_fees'],
-            type: 'u64',
+            name: 'quantity',
+            docs: ['Number of base lots to buy or sell, always >=1'],
+            type: 'i64',
           },
           {
-            name: 'makerVolume',
-            docs: [
-              'Cumulative maker volume (same as taker volume) in quote native units',
-            ],
-            type: 'u128',
+            name: 'timestamp',
+            docs: ['The time the order was placed'],
+            type: 'u64',
           },
           {
-            name: 'takerVolumeWoOo',
+            name: 'pegLimit',
             docs: [
-              'Cumulative taker volume in quote native units due to place take orders',
+              'If the effective price of an oracle pegged order exceeds this limit,',
+              'it will be considered invalid and may be removed.',
+              '',
+              'Only applicable in the oracle_pegged OrderTree',
             ],
-            type: 'u128',
-          },
-          {
-            name: 'baseMint',
-            type: 'publicKey',
+            type: 'i64',
           },
           {
-            name: 'quoteMint',
-            type: 'publicKey',
+            name: 'clientOrderId',
+            docs: ['User defined id for this order, used in FillEvents'],
+            type: 'u64',
           },
+        ],
+      },
+    },
+    {
+      name: 'AnyNode',
+      type: {
+        kind: 'struct',
+        fields: [
           {
-            name: 'marketBaseVault',
-            type: 'publicKey',
+            name: 'tag',
+            type: 'u8',
           },
           {
-            name: 'baseDepositTotal',
-            type: 'u64',
+            name: 'data',
+            type: {
+              array: ['u8', 79],
+            },
           },
           {
-            name: 'marketQuoteVault',
-            type: 'publicKey',
+            name: 'forceAlign',
+            type: 'u64',
           },
+        ],
+      },
+    },
+    {
+      name: 'OrderTreeRoot',
+      type: {
+        kind: 'struct',
+        fields: [
           {
-            name: 'quoteDepositTotal',
-            type: 'u64',
+            name: 'maybeNode',
+            type: 'u32',
           },
           {
-            name: 'reserved',
-            type: {
-              array: ['u8', 128],
-            },
+            name: 'leafCount',
+            type: 'u32',
           },
         ],
       },
     },
     {
-      name: 'openOrdersAccount',
+      name: 'ExpiryBucket',
+      docs: [
+        'Resting orders expiring within the same epoch of `EXPIRY_BUCKET_SECONDS`.',
+        '',
+        'Buckets are reused by epoch modulo `MAX_EXPIRY_BUCKETS`. Orders of epochs sharing',
+        'a bucket are counted together under the earliest epoch, so a bucket is due as',
+        "soon as its first order may have expired. Orders never expiring aren't counted.",
+      ],
       type: {
         kind: 'struct',
         fields: [
           {
-            name: 'owner',
-            type: 'publicKey',
+            name: 'epoch',
+            type: 'u64',
           },
           {
-            name: 'market',
-            type: 'publicKey',
+            name: 'orderCount',
+            type: 'u32',
           },
           {
-            name: 'name',
+            name: 'padding',
             type: {
-              array: ['u8', 32],
+              array: ['u8', 4],
             },
           },
+        ],
+      },
+    },
+    {
+      name: 'OrderTreeNodes',
+      docs: [
+        'A binary tree on AnyNode::key()',
+        '',
+        'The key encodes the price in the top 64 bits.',
+      ],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'orderTreeType',
+            type: 'u8',
+          },
           {
-            name: 'delegate',
+            name: 'padding',
             type: {
-              defined: 'NonZeroPubkeyOption',
+              array: ['u8', 3],
             },
           },
           {
-            name: 'accountNum',
+            name: 'bumpIndex',
             type: 'u32',
           },
           {
-            name: 'bump',
-            type: 'u8',
+            name: 'freeListLen',
+            type: 'u32',
           },
           {
-            name: 'version',
-            type: 'u8',
+            name: 'freeListHead',
+            type: 'u32',
           },
           {
-            name: 'padding',
+            name: 'expiryBuckets',
+            docs: [
+              'Leaves of all trees using these nodes grouped by expiry, see',
+              '[`OrderTreeNodes::due_expiry_order_count`]',
+            ],
             type: {
-              array: ['u8', 2],
+              array: [
+                {
+                  defined: 'ExpiryBucket',
+                },
+                16,
+              ],
             },
           },
           {
-            name: 'position',
+            name: 'reserved',
             type: {
-              defined: 'Position',
+              array: ['u8', 256],
             },
           },
           {
-            name: 'openOrders',
+            name: 'nodes',
             type: {
               array: [
                 {
-                  defined: 'OpenOrder',
+                  defined: 'AnyNode',
                 },
-                24,
+                1024,
               ],
             },
           },
@@ -5702,1194 +19629,1610 @@ export const IDL: OpenbookV2 = {
       },
     },
     {
-      name: 'openOrdersIndexer',
+      name: 'QuoteQualityEpoch',
       type: {
         kind: 'struct',
         fields: [
           {
-            name: 'bump',
-            type: 'u8',
+            name: 'startTimestamp',
+            type: 'u64',
           },
           {
-            name: 'createdCounter',
-            type: 'u32',
+            name: 'samples',
+            type: 'u64',
           },
           {
-            name: 'addresses',
-            type: {
-              vec: 'publicKey',
-            },
+            name: 'secondsSampled',
+            docs: ['Seconds accounted for by samples'],
+            type: 'u64',
+          },
+          {
+            name: 'secondsQuoting',
+            docs: [
+              'Seconds accounted for by samples where the account was quoting',
+            ],
+            type: 'u64',
+          },
+          {
+            name: 'minBidSizeLots',
+            docs: [
+              'Smallest size within the oracle distance seen by a sample, per side',
+            ],
+            type: 'i64',
+          },
+          {
+            name: 'minAskSizeLots',
+            type: 'i64',
           },
         ],
       },
     },
     {
-      name: 'stubOracle',
+      name: 'RewardInfo',
       type: {
         kind: 'struct',
         fields: [
           {
-            name: 'owner',
+            name: 'rewardState',
+            docs: ['Reward state'],
+            type: 'u8',
+          },
+          {
+            name: 'openTime',
+            docs: ['Reward open time'],
+            type: 'u64',
+          },
+          {
+            name: 'endTime',
+            docs: ['Reward end time'],
+            type: 'u64',
+          },
+          {
+            name: 'lastUpdateTime',
+            docs: ['Reward last update time'],
+            type: 'u64',
+          },
+          {
+            name: 'emissionsPerSecondX64',
+            docs: [
+              'Q64.64 number indicates how many tokens per second are earned per unit of liquidity.',
+            ],
+            type: 'u128',
+          },
+          {
+            name: 'rewardTotalEmissioned',
+            docs: ['The total amount of reward emissioned'],
+            type: 'u64',
+          },
+          {
+            name: 'rewardClaimed',
+            docs: ['The total amount of claimed reward'],
+            type: 'u64',
+          },
+          {
+            name: 'tokenMint',
+            docs: ['Reward token mint.'],
             type: 'publicKey',
           },
           {
-            name: 'mint',
+            name: 'tokenVault',
+            docs: ['Reward vault token account.'],
             type: 'publicKey',
           },
           {
-            name: 'price',
-            type: 'f64',
+            name: 'authority',
+            docs: ['The owner that has permission to set reward param'],
+            type: 'publicKey',
           },
           {
-            name: 'lastUpdateTs',
-            type: 'i64',
+            name: 'rewardGrowthGlobalX64',
+            docs: [
+              'Q64.64 number that tracks the total tokens earned per unit of liquidity since the reward',
+              'emissions were turned on.',
+            ],
+            type: 'u128',
           },
+        ],
+      },
+    },
+    {
+      name: 'SwitchboardDecimal',
+      type: {
+        kind: 'struct',
+        fields: [
           {
-            name: 'lastUpdateSlot',
-            type: 'u64',
+            name: 'mantissa',
+            docs: [
+              'The part of a floating-point number that represents the significant digits of that number, and that is multiplied by the base, 10, raised to the power of scale to give the actual value of the number.',
+            ],
+            type: 'i128',
           },
           {
-            name: 'deviation',
-            type: 'f64',
+            name: 'scale',
+            docs: [
+              'The number of decimal places to move to the left to yield the actual value.',
+            ],
+            type: 'u32',
           },
+        ],
+      },
+    },
+    {
+      name: 'Hash',
+      type: {
+        kind: 'struct',
+        fields: [
           {
-            name: 'reserved',
+            name: 'data',
+            docs: ['The bytes used to derive the hash.'],
             type: {
-              array: ['u8', 104],
+              array: ['u8', 32],
             },
           },
         ],
       },
     },
     {
-      name: 'bookSide',
+      name: 'AggregatorRound',
       type: {
         kind: 'struct',
         fields: [
           {
-            name: 'roots',
+            name: 'numSuccess',
+            docs: [
+              'Maintains the number of successful responses received from nodes.',
+              'Nodes can submit one successful response per round.',
+            ],
+            type: 'u32',
+          },
+          {
+            name: 'numError',
+            docs: ['Number of error responses.'],
+            type: 'u32',
+          },
+          {
+            name: 'isClosed',
+            docs: ['Whether an update request round has ended.'],
+            type: 'bool',
+          },
+          {
+            name: 'roundOpenSlot',
+            docs: [
+              'Maintains the `solana_program::clock::Slot` that the round was opened at.',
+            ],
+            type: 'u64',
+          },
+          {
+            name: 'roundOpenTimestamp',
+            docs: [
+              'Maintains the `solana_program::clock::UnixTimestamp;` the round was opened at.',
+            ],
+            type: 'i64',
+          },
+          {
+            name: 'result',
+            docs: [
+              'Maintains the current median of all successful round responses.',
+            ],
             type: {
-              array: [
-                {
-                  defined: 'OrderTreeRoot',
-                },
-                2,
-              ],
+              defined: 'SwitchboardDecimal',
             },
           },
           {
-            name: 'reservedRoots',
+            name: 'stdDeviation',
+            docs: ['Standard deviation of the accepted results in the round.'],
             type: {
-              array: [
-                {
-                  defined: 'OrderTreeRoot',
-                },
-                4,
-              ],
+              defined: 'SwitchboardDecimal',
             },
           },
           {
-            name: 'reserved',
+            name: 'minResponse',
+            docs: ['Maintains the minimum node response this round.'],
             type: {
-              array: ['u8', 256],
+              defined: 'SwitchboardDecimal',
             },
           },
           {
-            name: 'nodes',
+            name: 'maxResponse',
+            docs: ['Maintains the maximum node response this round.'],
             type: {
-              defined: 'OrderTreeNodes',
+              defined: 'SwitchboardDecimal',
             },
           },
-        ],
-      },
-    },
-    {
-      name: 'eventHeap',
-      docs: [
-        'Container for the different EventTypes.',
-        '',
-        'Events are stored in a fixed-array of nodes. Free nodes are connected by a single-linked list',
-        'starting at free_head while used nodes form a circular doubly-linked list starting at',
-        'used_head.',
-      ],
-      type: {
-        kind: 'struct',
-        fields: [
           {
-            name: 'header',
+            name: 'oraclePubkeysData',
+            docs: ['Pubkeys of the oracles fulfilling this round.'],
             type: {
-              defined: 'EventHeapHeader',
+              array: ['publicKey', 16],
             },
           },
           {
-            name: 'nodes',
+            name: 'mediansData',
+            docs: [
+              'Represents all successful node responses this round. `NaN` if empty.',
+            ],
             type: {
               array: [
                 {
-                  defined: 'EventNode',
+                  defined: 'SwitchboardDecimal',
                 },
-                600,
+                16,
               ],
             },
           },
           {
-            name: 'reserved',
-            type: {
-              array: ['u8', 64],
-            },
+            name: 'currentPayout',
+            docs: ['Current rewards/slashes oracles have received this round.'],
+            type: {
+              array: ['i64', 16],
+            },
+          },
+          {
+            name: 'mediansFulfilled',
+            docs: ['Keep track of which responses are fulfilled here.'],
+            type: {
+              array: ['bool', 16],
+            },
+          },
+          {
+            name: 'errorsFulfilled',
+            docs: ['Keeps track of which errors are fulfilled here.'],
+            type: {
+              array: ['bool', 16],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: 'BorshDecimal',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'mantissa',
+            type: 'i128',
+          },
+          {
+            name: 'scale',
+            type: 'u32',
           },
         ],
       },
     },
-  ],
-  types: [
     {
-      name: 'NonZeroPubkeyOption',
+      name: 'I80F48',
       docs: [
-        'Like `Option`, but implemented for `Pubkey` to be used with `zero_copy`',
+        'Nothing in Rust shall use these types. They only exist so that the Anchor IDL',
+        'knows about them and typescript can deserialize it.',
       ],
       type: {
         kind: 'struct',
         fields: [
           {
-            name: 'key',
-            type: 'publicKey',
+            name: 'val',
+            type: 'i128',
           },
         ],
       },
     },
     {
-      name: 'Position',
+      name: 'PlaceOrderArgs',
       type: {
         kind: 'struct',
         fields: [
           {
-            name: 'bidsBaseLots',
-            docs: ['Base lots in open bids'],
-            type: 'i64',
+            name: 'side',
+            type: {
+              defined: 'Side',
+            },
           },
           {
-            name: 'asksBaseLots',
-            docs: ['Base lots in open asks'],
+            name: 'priceLots',
             type: 'i64',
           },
           {
-            name: 'baseFreeNative',
-            type: 'u64',
+            name: 'maxBaseLots',
+            type: 'i64',
           },
           {
-            name: 'quoteFreeNative',
-            type: 'u64',
+            name: 'maxQuoteLotsIncludingFees',
+            type: 'i64',
           },
           {
-            name: 'lockedMakerFees',
+            name: 'clientOrderId',
             type: 'u64',
           },
           {
-            name: 'referrerRebatesAvailable',
-            type: 'u64',
+            name: 'orderType',
+            type: {
+              defined: 'PlaceOrderType',
+            },
           },
           {
-            name: 'penaltyHeapCount',
-            docs: [
-              'Count of ixs when events are added to the heap',
-              'To avoid this, send remaining accounts in order to process the events',
-            ],
+            name: 'expiryTimestamp',
             type: 'u64',
           },
           {
-            name: 'makerVolume',
-            docs: [
-              'Cumulative maker volume in quote native units (display only)',
-            ],
-            type: 'u128',
-          },
-          {
-            name: 'takerVolume',
-            docs: [
-              'Cumulative taker volume in quote native units (display only)',
-            ],
-            type: 'u128',
-          },
-          {
-            name: 'bidsQuoteLots',
-            docs: ['Quote lots in open bids'],
-            type: 'i64',
-          },
-          {
-            name: 'reserved',
+            name: 'selfTradeBehavior',
             type: {
-              array: ['u8', 64],
+              defined: 'SelfTradeBehavior',
             },
           },
+          {
+            name: 'limit',
+            type: 'u8',
+          },
         ],
       },
     },
     {
-      name: 'OpenOrder',
+      name: 'MarketQuote',
+      docs: [
+        'Top of the book of a market, see `get_market_quote`.',
+        '',
+        'Prices without a `_lots` suffix are native prices: native quote per native base.',
+      ],
       type: {
         kind: 'struct',
         fields: [
           {
-            name: 'id',
-            type: 'u128',
-          },
-          {
-            name: 'clientId',
-            type: 'u64',
+            name: 'bestBidLots',
+            type: {
+              option: 'i64',
+            },
           },
           {
-            name: 'lockedPrice',
-            docs: ["Price at which user's assets were locked"],
+            name: 'bestBidQuantity',
+            docs: ['Base lots at the best bid'],
             type: 'i64',
           },
           {
-            name: 'isFree',
-            type: 'u8',
+            name: 'bestAskLots',
+            type: {
+              option: 'i64',
+            },
           },
           {
-            name: 'sideAndTree',
-            type: 'u8',
+            name: 'bestAskQuantity',
+            docs: ['Base lots at the best ask'],
+            type: 'i64',
           },
           {
-            name: 'padding',
+            name: 'midPrice',
+            docs: [
+              'Average of the best bid and ask, `None` unless both sides have orders',
+            ],
             type: {
-              array: ['u8', 6],
+              option: {
+                defined: 'I80F48',
+              },
             },
           },
-        ],
-      },
-    },
-    {
-      name: 'OracleConfig',
-      type: {
-        kind: 'struct',
-        fields: [
           {
-            name: 'confFilter',
-            type: 'f64',
+            name: 'spreadBps',
+            docs: [
+              'Difference between the best ask and bid, relative to the mid price',
+            ],
+            type: {
+              option: 'i64',
+            },
           },
           {
-            name: 'maxStalenessSlots',
-            type: 'i64',
+            name: 'oraclePrice',
+            docs: ["`None` without a usable oracle, e.g. when it's stale"],
+            type: {
+              option: {
+                defined: 'I80F48',
+              },
+            },
           },
           {
-            name: 'reserved',
+            name: 'oracleDeviationBps',
+            docs: [
+              'Difference between the mid price and the oracle price, relative to the oracle price',
+            ],
             type: {
-              array: ['u8', 72],
+              option: 'i64',
             },
           },
         ],
       },
     },
     {
-      name: 'OracleConfigParams',
+      name: 'OrderInfo',
+      docs: ['An order of an open orders account, see `find_order`'],
       type: {
         kind: 'struct',
         fields: [
           {
-            name: 'confFilter',
-            type: 'f32',
+            name: 'orderId',
+            type: 'u128',
           },
           {
-            name: 'maxStalenessSlots',
-            type: {
-              option: 'u32',
-            },
+            name: 'clientOrderId',
+            type: 'u64',
           },
-        ],
-      },
-    },
-    {
-      name: 'EventHeapHeader',
-      type: {
-        kind: 'struct',
-        fields: [
           {
-            name: 'freeHead',
-            type: 'u16',
+            name: 'side',
+            type: {
+              defined: 'Side',
+            },
           },
           {
-            name: 'usedHead',
-            type: 'u16',
+            name: 'isOraclePegged',
+            type: 'bool',
           },
           {
-            name: 'count',
-            type: 'u16',
+            name: 'lockedPrice',
+            docs: [
+              "Price the order's funds are locked at, the peg limit for pegged bids",
+            ],
+            type: 'i64',
           },
           {
-            name: 'padd',
-            type: 'u16',
+            name: 'quantity',
+            docs: [
+              'Base lots left on the book. 0 when the order was filled or expired and the',
+              "event freeing it wasn't consumed yet.",
+            ],
+            type: 'i64',
           },
           {
-            name: 'seqNum',
+            name: 'timestamp',
             type: 'u64',
           },
         ],
       },
     },
     {
-      name: 'EventNode',
+      name: 'PlaceMultipleOrdersArgs',
       type: {
         kind: 'struct',
         fields: [
           {
-            name: 'next',
-            type: 'u16',
-          },
-          {
-            name: 'prev',
-            type: 'u16',
+            name: 'priceLots',
+            type: 'i64',
           },
           {
-            name: 'pad',
-            type: {
-              array: ['u8', 4],
-            },
+            name: 'maxQuoteLotsIncludingFees',
+            type: 'i64',
           },
           {
-            name: 'event',
-            type: {
-              defined: 'AnyEvent',
-            },
+            name: 'expiryTimestamp',
+            type: 'u64',
           },
         ],
       },
     },
     {
-      name: 'AnyEvent',
+      name: 'ConsumeEventsGroup',
+      docs: ['One market cranked by `consume_events_multiple`'],
       type: {
         kind: 'struct',
         fields: [
           {
-            name: 'eventType',
+            name: 'numAccounts',
+            docs: [
+              'Remaining accounts of the market after its `market` and `event_heap`',
+            ],
             type: 'u8',
           },
           {
-            name: 'padding',
-            type: {
-              array: ['u8', 143],
-            },
+            name: 'limit',
+            docs: ['Max events to consume in the market'],
+            type: 'u8',
           },
         ],
       },
     },
     {
-      name: 'FillEvent',
+      name: 'PlaceOrderPeggedArgs',
       type: {
         kind: 'struct',
         fields: [
           {
-            name: 'eventType',
-            type: 'u8',
+            name: 'side',
+            type: {
+              defined: 'Side',
+            },
           },
           {
-            name: 'takerSide',
-            type: 'u8',
+            name: 'priceOffsetLots',
+            type: 'i64',
           },
           {
-            name: 'makerOut',
-            type: 'u8',
+            name: 'pegLimit',
+            type: 'i64',
           },
           {
-            name: 'makerSlot',
-            type: 'u8',
+            name: 'maxBaseLots',
+            type: 'i64',
           },
           {
-            name: 'padding',
-            type: {
-              array: ['u8', 4],
-            },
+            name: 'maxQuoteLotsIncludingFees',
+            type: 'i64',
           },
           {
-            name: 'timestamp',
+            name: 'clientOrderId',
             type: 'u64',
           },
           {
-            name: 'seqNum',
-            type: 'u64',
+            name: 'orderType',
+            type: {
+              defined: 'PlaceOrderType',
+            },
           },
           {
-            name: 'maker',
-            type: 'publicKey',
+            name: 'expiryTimestamp',
+            type: 'u64',
           },
           {
-            name: 'makerTimestamp',
-            type: 'u64',
+            name: 'selfTradeBehavior',
+            type: {
+              defined: 'SelfTradeBehavior',
+            },
           },
           {
-            name: 'taker',
-            type: 'publicKey',
+            name: 'limit',
+            type: 'u8',
           },
+        ],
+      },
+    },
+    {
+      name: 'PlaceTakeOrderArgs',
+      type: {
+        kind: 'struct',
+        fields: [
           {
-            name: 'takerClientOrderId',
-            type: 'u64',
+            name: 'side',
+            type: {
+              defined: 'Side',
+            },
           },
           {
-            name: 'price',
+            name: 'priceLots',
             type: 'i64',
           },
           {
-            name: 'pegLimit',
+            name: 'maxBaseLots',
             type: 'i64',
           },
           {
-            name: 'quantity',
+            name: 'maxQuoteLotsIncludingFees',
             type: 'i64',
           },
           {
-            name: 'makerClientOrderId',
+            name: 'clientOrderId',
+            docs: [
+              'Copied to the fill events and logs as `taker_client_order_id`',
+            ],
             type: 'u64',
           },
           {
-            name: 'reserved',
+            name: 'orderType',
             type: {
-              array: ['u8', 8],
+              defined: 'PlaceOrderType',
             },
           },
+          {
+            name: 'limit',
+            type: 'u8',
+          },
         ],
       },
     },
     {
-      name: 'OutEvent',
+      name: 'MarketMetadataArgs',
       type: {
         kind: 'struct',
         fields: [
           {
-            name: 'eventType',
-            type: 'u8',
+            name: 'name',
+            type: 'string',
           },
           {
-            name: 'side',
-            type: 'u8',
+            name: 'baseSymbol',
+            type: 'string',
           },
           {
-            name: 'ownerSlot',
-            type: 'u8',
+            name: 'quoteSymbol',
+            type: 'string',
           },
           {
-            name: 'padding0',
-            type: {
-              array: ['u8', 5],
-            },
+            name: 'uri',
+            type: 'string',
           },
+        ],
+      },
+    },
+    {
+      name: 'TakerStopReason',
+      docs: [
+        'Why a taker order stopped matching before filling its max base lots',
+      ],
+      type: {
+        kind: 'enum',
+        variants: [
           {
-            name: 'timestamp',
-            type: 'u64',
+            name: 'BookExhausted',
           },
           {
-            name: 'seqNum',
-            type: 'u64',
+            name: 'PriceLimit',
           },
           {
-            name: 'owner',
-            type: 'publicKey',
+            name: 'QuoteLimit',
           },
           {
-            name: 'quantity',
-            type: 'i64',
+            name: 'MatchLimit',
           },
           {
-            name: 'padding1',
-            type: {
-              array: ['u8', 80],
-            },
+            name: 'SelfTrade',
           },
         ],
       },
     },
     {
-      name: 'InnerNode',
+      name: 'MarketState',
       docs: [
-        'InnerNodes and LeafNodes compose the binary tree of orders.',
+        'What users can do on a market, set by its admin to handle incidents.',
         '',
-        'Each InnerNode has exactly two children, which are either InnerNodes themselves,',
-        'or LeafNodes. The children share the top `prefix_len` bits of `key`. The left',
-        'child has a 0 in the next bit, and the right a 1.',
+        'Settling funds and consuming events are always allowed, so users never',
+        'lose access to their balances.',
       ],
       type: {
-        kind: 'struct',
-        fields: [
+        kind: 'enum',
+        variants: [
           {
-            name: 'tag',
-            type: 'u8',
+            name: 'Active',
           },
           {
-            name: 'padding',
-            type: {
-              array: ['u8', 3],
-            },
+            name: 'PostOnly',
           },
           {
-            name: 'prefixLen',
-            docs: [
-              'number of highest `key` bits that all children share',
-              "e.g. if it's 2, the two highest bits of `key` will be the same on all children",
-            ],
-            type: 'u32',
+            name: 'CancelOnly',
           },
           {
-            name: 'key',
-            docs: ['only the top `prefix_len` bits of `key` are relevant'],
-            type: 'u128',
+            name: 'Paused',
           },
+        ],
+      },
+    },
+    {
+      name: 'BinaryOutcome',
+      docs: [
+        'Resolution of a market trading a binary outcome token, set by its admin.',
+        '',
+        'A resolved market is settled at a price of 0 or 1 quote per base (in ui',
+        "units), recorded in its `settlement_price_lots`. The market doesn't redeem",
+        'outcome tokens itself, it only trades them and publishes the resolution.',
+      ],
+      type: {
+        kind: 'enum',
+        variants: [
           {
-            name: 'children',
-            docs: ['indexes into `BookSide::nodes`'],
-            type: {
-              array: ['u32', 2],
-            },
+            name: 'Disabled',
           },
           {
-            name: 'childEarliestExpiry',
-            docs: [
-              'The earliest expiry timestamp for the left and right subtrees.',
-              '',
-              'Needed to be able to find and remove expired orders without having to',
-              'iterate through the whole bookside.',
-            ],
-            type: {
-              array: ['u64', 2],
-            },
+            name: 'Unresolved',
           },
           {
-            name: 'reserved',
-            type: {
-              array: ['u8', 40],
-            },
+            name: 'No',
+          },
+          {
+            name: 'Yes',
           },
         ],
       },
     },
     {
-      name: 'LeafNode',
-      docs: ['LeafNodes represent an order in the binary tree'],
+      name: 'EvictionPolicy',
+      docs: [
+        'Which order makes room when a new order is posted to a full book side, set by',
+        "the market's admin.",
+      ],
       type: {
-        kind: 'struct',
-        fields: [
+        kind: 'enum',
+        variants: [
           {
-            name: 'tag',
-            docs: ['NodeTag'],
-            type: 'u8',
+            name: 'EvictWorstPrice',
           },
           {
-            name: 'ownerSlot',
-            docs: ["Index into the owning OpenOrdersAccount's OpenOrders"],
-            type: 'u8',
+            name: 'RejectIncoming',
           },
           {
-            name: 'timeInForce',
-            docs: [
-              'Time in seconds after `timestamp` at which the order expires.',
-              'A value of 0 means no expiry.',
-            ],
-            type: 'u16',
+            name: 'EvictOldestOutOfBand',
           },
+        ],
+      },
+    },
+    {
+      name: 'FeeRounding',
+      docs: [
+        "How a [`Market`]'s taker fees are rounded to native quote units, set by the",
+        "market's admin.",
+      ],
+      type: {
+        kind: 'enum',
+        variants: [
           {
-            name: 'padding',
-            type: {
-              array: ['u8', 4],
-            },
+            name: 'Up',
           },
           {
-            name: 'key',
-            docs: ['The binary tree key, see new_node_key()'],
-            type: 'u128',
+            name: 'Down',
+          },
+        ],
+      },
+    },
+    {
+      name: 'MarketAdmin',
+      docs: [
+        'Admin roles of a market that can be handed over with `set_market_authority`.',
+      ],
+      type: {
+        kind: 'enum',
+        variants: [
+          {
+            name: 'CollectFee',
           },
           {
-            name: 'owner',
-            docs: ['Address of the owning OpenOrdersAccount'],
-            type: 'publicKey',
+            name: 'ConsumeEvents',
           },
           {
-            name: 'quantity',
-            docs: ['Number of base lots to buy or sell, always >=1'],
-            type: 'i64',
+            name: 'CloseMarket',
           },
+        ],
+      },
+    },
+    {
+      name: 'OracleType',
+      type: {
+        kind: 'enum',
+        variants: [
           {
-            name: 'timestamp',
-            docs: ['The time the order was placed'],
-            type: 'u64',
+            name: 'Pyth',
           },
           {
-            name: 'pegLimit',
-            docs: [
-              'If the effective price of an oracle pegged order exceeds this limit,',
-              'it will be considered invalid and may be removed.',
-              '',
-              'Only applicable in the oracle_pegged OrderTree',
-            ],
-            type: 'i64',
+            name: 'Stub',
           },
           {
-            name: 'clientOrderId',
-            docs: ['User defined id for this order, used in FillEvents'],
-            type: 'u64',
+            name: 'SwitchboardV1',
+          },
+          {
+            name: 'SwitchboardV2',
+          },
+          {
+            name: 'RaydiumCLMM',
           },
         ],
       },
     },
     {
-      name: 'AnyNode',
+      name: 'OrderState',
       type: {
-        kind: 'struct',
-        fields: [
+        kind: 'enum',
+        variants: [
           {
-            name: 'tag',
-            type: 'u8',
+            name: 'Valid',
           },
           {
-            name: 'data',
-            type: {
-              array: ['u8', 87],
-            },
+            name: 'Invalid',
+          },
+          {
+            name: 'Skipped',
           },
         ],
       },
     },
     {
-      name: 'OrderTreeRoot',
+      name: 'BookSideOrderTree',
       type: {
-        kind: 'struct',
-        fields: [
+        kind: 'enum',
+        variants: [
           {
-            name: 'maybeNode',
-            type: 'u32',
+            name: 'Fixed',
           },
           {
-            name: 'leafCount',
-            type: 'u32',
+            name: 'OraclePegged',
           },
         ],
       },
     },
     {
-      name: 'OrderTreeNodes',
-      docs: [
-        'A binary tree on AnyNode::key()',
-        '',
-        'The key encodes the price in the top 64 bits.',
-      ],
+      name: 'EventType',
       type: {
-        kind: 'struct',
-        fields: [
+        kind: 'enum',
+        variants: [
           {
-            name: 'orderTreeType',
-            type: 'u8',
+            name: 'Fill',
           },
           {
-            name: 'padding',
-            type: {
-              array: ['u8', 3],
-            },
+            name: 'Out',
           },
+        ],
+      },
+    },
+    {
+      name: 'OutReason',
+      docs: [
+        'Why an order left the book, stored in [`OutEvent::reason`]',
+        '',
+        'Orders cancelled by their owner are reported with `CancelLog` instead of out',
+        'events and logs.',
+      ],
+      type: {
+        kind: 'enum',
+        variants: [
           {
-            name: 'bumpIndex',
-            type: 'u32',
+            name: 'Cancelled',
           },
           {
-            name: 'freeListLen',
-            type: 'u32',
+            name: 'Expired',
           },
           {
-            name: 'freeListHead',
-            type: 'u32',
+            name: 'BootedForSpace',
           },
           {
-            name: 'reserved',
-            type: {
-              array: ['u8', 512],
-            },
+            name: 'SelfTradeCancel',
           },
           {
-            name: 'nodes',
-            type: {
-              array: [
-                {
-                  defined: 'AnyNode',
-                },
-                1024,
-              ],
-            },
+            name: 'AdminCancel',
           },
         ],
       },
     },
     {
-      name: 'I80F48',
-      docs: [
-        'Nothing in Rust shall use these types. They only exist so that the Anchor IDL',
-        'knows about them and typescript can deserialize it.',
-      ],
+      name: 'NodeTag',
       type: {
-        kind: 'struct',
-        fields: [
+        kind: 'enum',
+        variants: [
           {
-            name: 'val',
-            type: 'i128',
+            name: 'Uninitialized',
+          },
+          {
+            name: 'InnerNode',
+          },
+          {
+            name: 'LeafNode',
+          },
+          {
+            name: 'FreeNode',
+          },
+          {
+            name: 'LastFreeNode',
           },
         ],
       },
     },
     {
-      name: 'PlaceOrderArgs',
+      name: 'PlaceOrderType',
       type: {
-        kind: 'struct',
-        fields: [
+        kind: 'enum',
+        variants: [
           {
-            name: 'side',
-            type: {
-              defined: 'Side',
-            },
+            name: 'Limit',
           },
           {
-            name: 'priceLots',
-            type: 'i64',
+            name: 'ImmediateOrCancel',
           },
           {
-            name: 'maxBaseLots',
-            type: 'i64',
+            name: 'PostOnly',
           },
           {
-            name: 'maxQuoteLotsIncludingFees',
-            type: 'i64',
+            name: 'Market',
           },
           {
-            name: 'clientOrderId',
-            type: 'u64',
+            name: 'PostOnlySlide',
           },
           {
-            name: 'orderType',
-            type: {
-              defined: 'PlaceOrderType',
-            },
+            name: 'FillOrKill',
           },
+        ],
+      },
+    },
+    {
+      name: 'PostOrderType',
+      type: {
+        kind: 'enum',
+        variants: [
           {
-            name: 'expiryTimestamp',
-            type: 'u64',
+            name: 'Limit',
           },
           {
-            name: 'selfTradeBehavior',
-            type: {
-              defined: 'SelfTradeBehavior',
-            },
+            name: 'PostOnly',
           },
           {
-            name: 'limit',
-            type: 'u8',
+            name: 'PostOnlySlide',
           },
         ],
       },
     },
     {
-      name: 'PlaceMultipleOrdersArgs',
+      name: 'SelfTradeBehavior',
+      docs: [
+        'Self trade behavior controls how taker orders interact with resting limit orders of the same account.',
+        'This setting has no influence on placing a resting or oracle pegged limit order that does not match',
+        "immediately, instead it's the responsibility of the user to correctly configure his taker orders.",
+      ],
       type: {
-        kind: 'struct',
-        fields: [
+        kind: 'enum',
+        variants: [
           {
-            name: 'priceLots',
-            type: 'i64',
+            name: 'DecrementTake',
           },
           {
-            name: 'maxQuoteLotsIncludingFees',
-            type: 'i64',
+            name: 'CancelProvide',
           },
           {
-            name: 'expiryTimestamp',
-            type: 'u64',
+            name: 'AbortTransaction',
           },
         ],
       },
     },
     {
-      name: 'PlaceOrderPeggedArgs',
+      name: 'Side',
       type: {
-        kind: 'struct',
-        fields: [
+        kind: 'enum',
+        variants: [
           {
-            name: 'side',
-            type: {
-              defined: 'Side',
-            },
+            name: 'Bid',
           },
           {
-            name: 'priceOffsetLots',
-            type: 'i64',
+            name: 'Ask',
+          },
+        ],
+      },
+    },
+    {
+      name: 'SideAndOrderTree',
+      docs: [
+        "SideAndOrderTree is a storage optimization, so we don't need two bytes for the data",
+      ],
+      type: {
+        kind: 'enum',
+        variants: [
+          {
+            name: 'BidFixed',
           },
           {
-            name: 'pegLimit',
-            type: 'i64',
+            name: 'AskFixed',
           },
           {
-            name: 'maxBaseLots',
-            type: 'i64',
+            name: 'BidOraclePegged',
           },
           {
-            name: 'maxQuoteLotsIncludingFees',
-            type: 'i64',
+            name: 'AskOraclePegged',
           },
+        ],
+      },
+    },
+    {
+      name: 'OrderParams',
+      type: {
+        kind: 'enum',
+        variants: [
           {
-            name: 'clientOrderId',
-            type: 'u64',
+            name: 'Market',
           },
           {
-            name: 'orderType',
-            type: {
-              defined: 'PlaceOrderType',
-            },
+            name: 'ImmediateOrCancel',
+            fields: [
+              {
+                name: 'priceLots',
+                type: 'i64',
+              },
+            ],
           },
           {
-            name: 'expiryTimestamp',
-            type: 'u64',
+            name: 'Fixed',
+            fields: [
+              {
+                name: 'priceLots',
+                type: 'i64',
+              },
+              {
+                name: 'orderType',
+                type: {
+                  defined: 'PostOrderType',
+                },
+              },
+            ],
           },
           {
-            name: 'selfTradeBehavior',
-            type: {
-              defined: 'SelfTradeBehavior',
-            },
+            name: 'OraclePegged',
+            fields: [
+              {
+                name: 'priceOffsetLots',
+                type: 'i64',
+              },
+              {
+                name: 'orderType',
+                type: {
+                  defined: 'PostOrderType',
+                },
+              },
+              {
+                name: 'pegLimit',
+                type: 'i64',
+              },
+            ],
           },
           {
-            name: 'limit',
-            type: 'u8',
+            name: 'FillOrKill',
+            fields: [
+              {
+                name: 'priceLots',
+                type: 'i64',
+              },
+            ],
           },
         ],
       },
     },
     {
-      name: 'PlaceTakeOrderArgs',
+      name: 'OrderTreeType',
       type: {
-        kind: 'struct',
-        fields: [
-          {
-            name: 'side',
-            type: {
-              defined: 'Side',
-            },
-          },
-          {
-            name: 'priceLots',
-            type: 'i64',
-          },
+        kind: 'enum',
+        variants: [
           {
-            name: 'maxBaseLots',
-            type: 'i64',
+            name: 'Bids',
           },
           {
-            name: 'maxQuoteLotsIncludingFees',
-            type: 'i64',
+            name: 'Asks',
           },
+        ],
+      },
+    },
+    {
+      name: 'AggregatorResolutionMode',
+      type: {
+        kind: 'enum',
+        variants: [
           {
-            name: 'orderType',
-            type: {
-              defined: 'PlaceOrderType',
-            },
+            name: 'ModeRoundResolution',
           },
           {
-            name: 'limit',
-            type: 'u8',
+            name: 'ModeSlidingResolution',
           },
         ],
       },
     },
     {
-      name: 'OracleType',
+      name: 'SwitchboardError',
       type: {
         kind: 'enum',
         variants: [
           {
-            name: 'Pyth',
+            name: 'InvalidAggregatorRound',
+          },
+          {
+            name: 'InvalidStrDecimalConversion',
+          },
+          {
+            name: 'DecimalConversionError',
+          },
+          {
+            name: 'IntegerOverflowError',
+          },
+          {
+            name: 'AccountDiscriminatorMismatch',
+          },
+          {
+            name: 'VrfEmptyError',
+          },
+          {
+            name: 'VrfCpiError',
           },
           {
-            name: 'Stub',
+            name: 'VrfCpiSignedError',
           },
           {
-            name: 'SwitchboardV1',
+            name: 'AccountDeserializationError',
           },
           {
-            name: 'SwitchboardV2',
+            name: 'StaleFeed',
           },
           {
-            name: 'RaydiumCLMM',
+            name: 'ConfidenceIntervalExceeded',
           },
-        ],
-      },
-    },
-    {
-      name: 'OrderState',
-      type: {
-        kind: 'enum',
-        variants: [
           {
-            name: 'Valid',
+            name: 'InvalidAuthority',
           },
           {
-            name: 'Invalid',
+            name: 'AllowedVarianceExceeded',
           },
           {
-            name: 'Skipped',
+            name: 'InvalidFunctionInput',
           },
-        ],
-      },
-    },
-    {
-      name: 'BookSideOrderTree',
-      type: {
-        kind: 'enum',
-        variants: [
           {
-            name: 'Fixed',
+            name: 'PdaDerivationError',
           },
           {
-            name: 'OraclePegged',
+            name: 'IllegalExecuteAttempt',
           },
-        ],
-      },
-    },
-    {
-      name: 'EventType',
-      type: {
-        kind: 'enum',
-        variants: [
           {
-            name: 'Fill',
+            name: 'InvalidQuote',
           },
           {
-            name: 'Out',
+            name: 'InvalidQueue',
           },
-        ],
-      },
-    },
-    {
-      name: 'NodeTag',
-      type: {
-        kind: 'enum',
-        variants: [
           {
-            name: 'Uninitialized',
+            name: 'InvalidEnclaveSigner',
           },
           {
-            name: 'InnerNode',
+            name: 'InvalidNativeMint',
           },
           {
-            name: 'LeafNode',
+            name: 'MrEnclavesEmpty',
           },
           {
-            name: 'FreeNode',
+            name: 'InvalidMrEnclave',
           },
           {
-            name: 'LastFreeNode',
+            name: 'FunctionNotReady',
           },
-        ],
-      },
-    },
-    {
-      name: 'PlaceOrderType',
-      type: {
-        kind: 'enum',
-        variants: [
           {
-            name: 'Limit',
+            name: 'UserRequestsDisabled',
           },
           {
-            name: 'ImmediateOrCancel',
+            name: 'FunctionRoutinesDisabled',
           },
           {
-            name: 'PostOnly',
+            name: 'PermissionDenied',
           },
           {
-            name: 'Market',
+            name: 'ConfigParameterLocked',
           },
           {
-            name: 'PostOnlySlide',
+            name: 'FunctionServicesDisabled',
           },
           {
-            name: 'FillOrKill',
+            name: 'ServiceDisabled',
           },
-        ],
-      },
-    },
-    {
-      name: 'PostOrderType',
-      type: {
-        kind: 'enum',
-        variants: [
           {
-            name: 'Limit',
+            name: 'ServiceWorkerFull',
           },
           {
-            name: 'PostOnly',
+            name: 'ServiceWorkerEnclaveFull',
           },
           {
-            name: 'PostOnlySlide',
+            name: 'ServiceAlreadyAssignedToWorker',
           },
         ],
       },
     },
     {
-      name: 'SelfTradeBehavior',
+      name: 'TriggerCondition',
       docs: [
-        'Self trade behavior controls how taker orders interact with resting limit orders of the same account.',
-        'This setting has no influence on placing a resting or oracle pegged limit order that does not match',
-        "immediately, instead it's the responsibility of the user to correctly configure his taker orders.",
+        'When a [`TriggerOrder`] can be executed.',
+        '',
+        "Oracle conditions compare the market's oracle price. Book conditions compare",
+        'the best opposing price, the one the order would take at: the best ask for',
+        'bids, the best bid for asks. Conditions are never met while the price is',
+        'unavailable.',
       ],
       type: {
         kind: 'enum',
         variants: [
           {
-            name: 'DecrementTake',
+            name: 'OraclePriceAbove',
           },
           {
-            name: 'CancelProvide',
+            name: 'OraclePriceBelow',
           },
           {
-            name: 'AbortTransaction',
+            name: 'BookPriceAbove',
+          },
+          {
+            name: 'BookPriceBelow',
           },
         ],
       },
     },
     {
-      name: 'Side',
-      type: {
-        kind: 'enum',
-        variants: [
-          {
-            name: 'Bid',
-          },
-          {
-            name: 'Ask',
+      name: 'NodeHandle',
+      type: {
+        kind: 'alias',
+        value: 'u32',
+      },
+    },
+  ],
+  events: [
+    {
+      name: 'DepositLog',
+      fields: [
+        {
+          name: 'openOrdersAccount',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'signer',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'baseAmount',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'quoteAmount',
+          type: 'u64',
+          index: false,
+        },
+      ],
+    },
+    {
+      name: 'FillLog',
+      fields: [
+        {
+          name: 'market',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'takerSide',
+          type: 'u8',
+          index: false,
+        },
+        {
+          name: 'makerSlot',
+          type: 'u8',
+          index: false,
+        },
+        {
+          name: 'makerOut',
+          type: 'bool',
+          index: false,
+        },
+        {
+          name: 'timestamp',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'seqNum',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'maker',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'makerClientOrderId',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'makerFee',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'makerTimestamp',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'taker',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'takerClientOrderId',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'takerFeeCeil',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'price',
+          type: 'i64',
+          index: false,
+        },
+        {
+          name: 'quantity',
+          type: 'i64',
+          index: false,
+        },
+        {
+          name: 'baseNative',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'quoteNative',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'eventSeqNum',
+          type: 'u64',
+          index: false,
+        },
+      ],
+    },
+    {
+      name: 'OutLog',
+      fields: [
+        {
+          name: 'market',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'side',
+          type: 'u8',
+          index: false,
+        },
+        {
+          name: 'ownerSlot',
+          type: 'u8',
+          index: false,
+        },
+        {
+          name: 'timestamp',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'seqNum',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'owner',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'quantity',
+          type: 'i64',
+          index: false,
+        },
+        {
+          name: 'eventSeqNum',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'reason',
+          type: 'u8',
+          index: false,
+        },
+        {
+          name: 'price',
+          type: 'i64',
+          index: false,
+        },
+        {
+          name: 'pegLimit',
+          type: 'i64',
+          index: false,
+        },
+        {
+          name: 'clientOrderId',
+          type: 'u64',
+          index: false,
+        },
+      ],
+    },
+    {
+      name: 'PlaceLog',
+      fields: [
+        {
+          name: 'market',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'side',
+          type: 'u8',
+          index: false,
+        },
+        {
+          name: 'seqNum',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'owner',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'clientOrderId',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'orderId',
+          type: {
+            option: 'u128',
           },
-        ],
-      },
+          index: false,
+        },
+        {
+          name: 'baseTakenNative',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'quoteTakenNative',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'basePostedNative',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'quotePostedNative',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'takerFees',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'makerFees',
+          type: 'u64',
+          index: false,
+        },
+      ],
     },
     {
-      name: 'SideAndOrderTree',
-      docs: [
-        "SideAndOrderTree is a storage optimization, so we don't need two bytes for the data",
+      name: 'CancelLog',
+      fields: [
+        {
+          name: 'market',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'side',
+          type: 'u8',
+          index: false,
+        },
+        {
+          name: 'seqNum',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'openOrdersAccount',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'orderId',
+          type: 'u128',
+          index: false,
+        },
+        {
+          name: 'clientOrderId',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'quantity',
+          type: 'i64',
+          index: false,
+        },
+        {
+          name: 'price',
+          type: 'i64',
+          index: false,
+        },
+        {
+          name: 'pegLimit',
+          type: 'i64',
+          index: false,
+        },
       ],
-      type: {
-        kind: 'enum',
-        variants: [
-          {
-            name: 'BidFixed',
-          },
-          {
-            name: 'AskFixed',
-          },
-          {
-            name: 'BidOraclePegged',
-          },
-          {
-            name: 'AskOraclePegged',
-          },
-        ],
-      },
     },
     {
-      name: 'OrderParams',
-      type: {
-        kind: 'enum',
-        variants: [
-          {
-            name: 'Market',
-          },
-          {
-            name: 'ImmediateOrCancel',
-            fields: [
-              {
-                name: 'price_lots',
-                type: 'i64',
-              },
-            ],
-          },
-          {
-            name: 'Fixed',
-            fields: [
-              {
-                name: 'price_lots',
-                type: 'i64',
-              },
-              {
-                name: 'order_type',
-                type: {
-                  defined: 'PostOrderType',
-                },
-              },
-            ],
-          },
-          {
-            name: 'OraclePegged',
-            fields: [
-              {
-                name: 'price_offset_lots',
-                type: 'i64',
-              },
-              {
-                name: 'order_type',
-                type: {
-                  defined: 'PostOrderType',
-                },
-              },
-              {
-                name: 'peg_limit',
-                type: 'i64',
-              },
-            ],
-          },
-          {
-            name: 'FillOrKill',
-            fields: [
-              {
-                name: 'price_lots',
-                type: 'i64',
-              },
-            ],
-          },
-        ],
-      },
+      name: 'TakerSignatureLog',
+      fields: [
+        {
+          name: 'market',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'seqNum',
+          type: 'u64',
+          index: false,
+        },
+      ],
     },
     {
-      name: 'OrderTreeType',
-      type: {
-        kind: 'enum',
-        variants: [
-          {
-            name: 'Bids',
-          },
-          {
-            name: 'Asks',
-          },
-        ],
-      },
+      name: 'MarketMetaDataLog',
+      fields: [
+        {
+          name: 'market',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'name',
+          type: 'string',
+          index: false,
+        },
+        {
+          name: 'baseMint',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'quoteMint',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'baseDecimals',
+          type: 'u8',
+          index: false,
+        },
+        {
+          name: 'quoteDecimals',
+          type: 'u8',
+          index: false,
+        },
+        {
+          name: 'baseLotSize',
+          type: 'i64',
+          index: false,
+        },
+        {
+          name: 'quoteLotSize',
+          type: 'i64',
+          index: false,
+        },
+      ],
     },
-  ],
-  events: [
     {
-      name: 'DepositLog',
+      name: 'TotalOrderFillEvent',
       fields: [
         {
-          name: 'openOrdersAccount',
-          type: 'publicKey',
+          name: 'side',
+          type: 'u8',
           index: false,
         },
         {
-          name: 'signer',
+          name: 'taker',
           type: 'publicKey',
           index: false,
         },
         {
-          name: 'baseAmount',
+          name: 'totalQuantityPaid',
           type: 'u64',
           index: false,
         },
         {
-          name: 'quoteAmount',
+          name: 'totalQuantityReceived',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'fees',
           type: 'u64',
           index: false,
         },
       ],
     },
     {
-      name: 'FillLog',
+      name: 'TakerExecutionLog',
       fields: [
         {
           name: 'market',
@@ -6897,79 +21240,190 @@ export const IDL: OpenbookV2 = {
           index: false,
         },
         {
-          name: 'takerSide',
+          name: 'side',
           type: 'u8',
           index: false,
         },
         {
-          name: 'makerSlot',
+          name: 'owner',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'clientOrderId',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'maxBaseLots',
+          type: 'i64',
+          index: false,
+        },
+        {
+          name: 'baseLotsFilled',
+          type: 'i64',
+          index: false,
+        },
+        {
+          name: 'quoteLotsFilled',
+          type: 'i64',
+          index: false,
+        },
+        {
+          name: 'averagePriceLots',
+          type: 'i64',
+          index: false,
+        },
+        {
+          name: 'reason',
           type: 'u8',
           index: false,
         },
         {
-          name: 'makerOut',
+          name: 'aborted',
           type: 'bool',
           index: false,
         },
+      ],
+    },
+    {
+      name: 'SetDelegateLog',
+      fields: [
+        {
+          name: 'openOrdersAccount',
+          type: 'publicKey',
+          index: false,
+        },
         {
-          name: 'timestamp',
+          name: 'delegate',
+          type: {
+            option: 'publicKey',
+          },
+          index: false,
+        },
+      ],
+    },
+    {
+      name: 'SettleFundsLog',
+      fields: [
+        {
+          name: 'openOrdersAccount',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'baseNative',
           type: 'u64',
           index: false,
         },
         {
-          name: 'seqNum',
+          name: 'quoteNative',
           type: 'u64',
           index: false,
         },
         {
-          name: 'maker',
+          name: 'referrerRebate',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'referrer',
+          type: {
+            option: 'publicKey',
+          },
+          index: false,
+        },
+      ],
+    },
+    {
+      name: 'ClaimRebatesLog',
+      fields: [
+        {
+          name: 'openOrdersAccount',
           type: 'publicKey',
           index: false,
         },
         {
-          name: 'makerClientOrderId',
+          name: 'makerRebate',
           type: 'u64',
           index: false,
         },
         {
-          name: 'makerFee',
+          name: 'referrerRebate',
           type: 'u64',
           index: false,
         },
         {
-          name: 'makerTimestamp',
-          type: 'u64',
+          name: 'receiver',
+          type: 'publicKey',
           index: false,
         },
+      ],
+    },
+    {
+      name: 'ConsumeEventsRewardLog',
+      fields: [
         {
-          name: 'taker',
+          name: 'market',
           type: 'publicKey',
           index: false,
         },
         {
-          name: 'takerClientOrderId',
+          name: 'cranker',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'eventsConsumed',
           type: 'u64',
           index: false,
         },
         {
-          name: 'takerFeeCeil',
+          name: 'reward',
           type: 'u64',
           index: false,
         },
+      ],
+    },
+    {
+      name: 'ConsumeEventsSkippedLog',
+      fields: [
+        {
+          name: 'market',
+          type: 'publicKey',
+          index: false,
+        },
         {
-          name: 'price',
-          type: 'i64',
+          name: 'eventsSkipped',
+          type: 'u8',
           index: false,
         },
         {
-          name: 'quantity',
+          name: 'missingOpenOrdersAccounts',
+          type: {
+            vec: 'publicKey',
+          },
+          index: false,
+        },
+      ],
+    },
+    {
+      name: 'SettleExpiredMarketLog',
+      fields: [
+        {
+          name: 'market',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'settlementPriceLots',
           type: 'i64',
           index: false,
         },
       ],
     },
     {
-      name: 'MarketMetaDataLog',
+      name: 'SweepFeesLog',
       fields: [
         {
           name: 'market',
@@ -6977,123 +21431,189 @@ export const IDL: OpenbookV2 = {
           index: false,
         },
         {
-          name: 'name',
-          type: 'string',
+          name: 'amount',
+          type: 'u64',
           index: false,
         },
         {
-          name: 'baseMint',
+          name: 'receiver',
           type: 'publicKey',
           index: false,
         },
+      ],
+    },
+    {
+      name: 'MakerRewardsClaimLog',
+      fields: [
         {
-          name: 'quoteMint',
+          name: 'market',
           type: 'publicKey',
           index: false,
         },
         {
-          name: 'baseDecimals',
-          type: 'u8',
+          name: 'openOrdersAccount',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'amount',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'receiver',
+          type: 'publicKey',
+          index: false,
+        },
+      ],
+    },
+    {
+      name: 'TakerRebatesClaimLog',
+      fields: [
+        {
+          name: 'market',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'openOrdersAccount',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'amount',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'receiver',
+          type: 'publicKey',
+          index: false,
+        },
+      ],
+    },
+    {
+      name: 'OpenOrdersPositionLog',
+      fields: [
+        {
+          name: 'owner',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'openOrdersAccountNum',
+          type: 'u32',
+          index: false,
+        },
+        {
+          name: 'market',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'bidsBaseLots',
+          type: 'i64',
+          index: false,
+        },
+        {
+          name: 'bidsQuoteLots',
+          type: 'i64',
+          index: false,
+        },
+        {
+          name: 'asksBaseLots',
+          type: 'i64',
+          index: false,
+        },
+        {
+          name: 'baseFreeNative',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'quoteFreeNative',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'lockedMakerFees',
+          type: 'u64',
           index: false,
         },
         {
-          name: 'quoteDecimals',
-          type: 'u8',
+          name: 'referrerRebatesAvailable',
+          type: 'u64',
           index: false,
         },
         {
-          name: 'baseLotSize',
-          type: 'i64',
+          name: 'makerVolume',
+          type: 'u128',
           index: false,
         },
         {
-          name: 'quoteLotSize',
-          type: 'i64',
+          name: 'takerVolume',
+          type: 'u128',
           index: false,
         },
       ],
     },
     {
-      name: 'TotalOrderFillEvent',
+      name: 'MarketInvariantsLog',
       fields: [
         {
-          name: 'side',
-          type: 'u8',
-          index: false,
-        },
-        {
-          name: 'taker',
+          name: 'market',
           type: 'publicKey',
           index: false,
         },
         {
-          name: 'totalQuantityPaid',
-          type: 'u64',
+          name: 'openOrdersAccounts',
+          type: 'u32',
           index: false,
         },
         {
-          name: 'totalQuantityReceived',
-          type: 'u64',
+          name: 'pendingFills',
+          type: 'u32',
           index: false,
         },
         {
-          name: 'fees',
+          name: 'baseVault',
           type: 'u64',
           index: false,
         },
-      ],
-    },
-    {
-      name: 'SetDelegateLog',
-      fields: [
-        {
-          name: 'openOrdersAccount',
-          type: 'publicKey',
-          index: false,
-        },
         {
-          name: 'delegate',
-          type: {
-            option: 'publicKey',
-          },
+          name: 'baseDepositTotal',
+          type: 'u64',
           index: false,
         },
-      ],
-    },
-    {
-      name: 'SettleFundsLog',
-      fields: [
         {
-          name: 'openOrdersAccount',
-          type: 'publicKey',
+          name: 'baseUnaccounted',
+          type: 'i128',
           index: false,
         },
         {
-          name: 'baseNative',
+          name: 'quoteVault',
           type: 'u64',
           index: false,
         },
         {
-          name: 'quoteNative',
+          name: 'quoteDepositTotal',
           type: 'u64',
           index: false,
         },
         {
-          name: 'referrerRebate',
+          name: 'feesAvailable',
           type: 'u64',
           index: false,
         },
         {
-          name: 'referrer',
-          type: {
-            option: 'publicKey',
-          },
+          name: 'quoteUnaccounted',
+          type: 'i128',
           index: false,
         },
       ],
     },
     {
-      name: 'SweepFeesLog',
+      name: 'ExecuteTriggerLog',
       fields: [
         {
           name: 'market',
@@ -7101,80 +21621,87 @@ export const IDL: OpenbookV2 = {
           index: false,
         },
         {
-          name: 'amount',
+          name: 'openOrdersAccount',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'triggerId',
           type: 'u64',
           index: false,
         },
         {
-          name: 'receiver',
+          name: 'keeper',
           type: 'publicKey',
           index: false,
         },
+        {
+          name: 'orderId',
+          type: {
+            option: 'u128',
+          },
+          index: false,
+        },
       ],
     },
     {
-      name: 'OpenOrdersPositionLog',
+      name: 'HeartbeatBountyLog',
       fields: [
         {
-          name: 'owner',
+          name: 'openOrdersAccount',
           type: 'publicKey',
           index: false,
         },
         {
-          name: 'openOrdersAccountNum',
-          type: 'u32',
-          index: false,
-        },
-        {
-          name: 'market',
+          name: 'keeper',
           type: 'publicKey',
           index: false,
         },
         {
-          name: 'bidsBaseLots',
-          type: 'i64',
+          name: 'bounty',
+          type: 'u64',
           index: false,
         },
+      ],
+    },
+    {
+      name: 'RelayerFeeLog',
+      fields: [
         {
-          name: 'bidsQuoteLots',
-          type: 'i64',
+          name: 'openOrdersAccount',
+          type: 'publicKey',
           index: false,
         },
         {
-          name: 'asksBaseLots',
-          type: 'i64',
+          name: 'relayer',
+          type: 'publicKey',
           index: false,
         },
         {
-          name: 'baseFreeNative',
-          type: 'u64',
+          name: 'mint',
+          type: 'publicKey',
           index: false,
         },
         {
-          name: 'quoteFreeNative',
+          name: 'relayerFee',
           type: 'u64',
           index: false,
         },
+      ],
+    },
+    {
+      name: 'SweepQuoteDustLog',
+      fields: [
         {
-          name: 'lockedMakerFees',
-          type: 'u64',
+          name: 'market',
+          type: 'publicKey',
           index: false,
         },
         {
-          name: 'referrerRebatesAvailable',
+          name: 'amount',
           type: 'u64',
           index: false,
         },
-        {
-          name: 'makerVolume',
-          type: 'u128',
-          index: false,
-        },
-        {
-          name: 'takerVolume',
-          type: 'u128',
-          index: false,
-        },
       ],
     },
   ],
@@ -7399,5 +21926,350 @@ export const IDL: OpenbookV2 = {
       name: 'WouldExecutePartially',
       msg: 'Fill-Or-Kill order would generate a partial execution',
     },
+    {
+      code: 6044,
+      name: 'InvalidInputRemainingAccounts',
+      msg: 'Remaining accounts are missing or not grouped as expected',
+    },
+    {
+      code: 6045,
+      name: 'OpenOrdersAccountNotIndexed',
+      msg: "Open orders account is not registered in the owner's indexer",
+    },
+    {
+      code: 6046,
+      name: 'EventHeapFull',
+      msg: "Event heap is full and its oldest event's open orders account is missing",
+    },
+    {
+      code: 6047,
+      name: 'InvalidFillCallbackProgram',
+      msg: 'Invalid fill callback program',
+    },
+    {
+      code: 6048,
+      name: 'InvalidFillHistory',
+      msg: 'Fill history belongs to another market',
+    },
+    {
+      code: 6049,
+      name: 'InvalidMarketStats',
+      msg: 'Market stats belong to another market',
+    },
+    {
+      code: 6050,
+      name: 'MarketIsPostOnly',
+      msg: 'Market only accepts post only orders',
+    },
+    {
+      code: 6051,
+      name: 'MarketIsCancelOnly',
+      msg: 'Market only allows cancelling orders',
+    },
+    {
+      code: 6052,
+      name: 'MarketIsPaused',
+      msg: 'Market is paused',
+    },
+    {
+      code: 6053,
+      name: 'SettlementPriceUnavailable',
+      msg: 'No valid oracle price to settle the market at',
+    },
+    {
+      code: 6054,
+      name: 'MarketIsSettled',
+      msg: 'Market has been settled',
+    },
+    {
+      code: 6055,
+      name: 'InvalidInputSettlementPrice',
+      msg: 'Settlement price is only given, and should be positive, for markets without an oracle',
+    },
+    {
+      code: 6056,
+      name: 'NotBinaryOutcomeMarket',
+      msg: 'Market is not a binary outcome market',
+    },
+    {
+      code: 6057,
+      name: 'BinaryOutcomeUnresolved',
+      msg: 'Binary outcome market has not been resolved',
+    },
+    {
+      code: 6058,
+      name: 'InvalidMarketAdmin',
+      msg: "Signer doesn't hold this market admin role",
+    },
+    {
+      code: 6059,
+      name: 'InvalidPendingMarketAdmin',
+      msg: "Signer isn't the pending market admin",
+    },
+    {
+      code: 6060,
+      name: 'InvalidMarketRegistryPage',
+      msg: 'Market registry page is full or out of order',
+    },
+    {
+      code: 6061,
+      name: 'MarketAlreadyRegistered',
+      msg: 'Market is already in the market registry',
+    },
+    {
+      code: 6062,
+      name: 'InvalidGovernance',
+      msg: "Signer is not the program's upgrade authority",
+    },
+    {
+      code: 6063,
+      name: 'InvalidDustReceiver',
+      msg: 'Dust receiver must be owned by the collect fee admin',
+    },
+    {
+      code: 6064,
+      name: 'InvalidMerkleProof',
+      msg: "Owner is not in the market's allowlist",
+    },
+    {
+      code: 6065,
+      name: 'InvalidTokenGateAccount',
+      msg: "Token account does not hold enough of the market's gate token",
+    },
+    {
+      code: 6066,
+      name: 'TooManyTakerPrograms',
+      msg: 'Too many taker programs',
+    },
+    {
+      code: 6067,
+      name: 'InvalidBookSidePage',
+      msg: 'Book side page is not the next page of the chain',
+    },
+    {
+      code: 6068,
+      name: 'MissingBookSidePages',
+      msg: "Book side has orders on pages that weren't passed",
+    },
+    {
+      code: 6069,
+      name: 'TooManyBookSidePages',
+      msg: 'Book side has the maximum number of pages',
+    },
+    {
+      code: 6070,
+      name: 'MathOverflow',
+      msg: 'Amount overflows its type',
+    },
+    {
+      code: 6071,
+      name: 'InvalidInputDepthLevels',
+      msg: 'Depth levels above snapshot limit',
+    },
+    {
+      code: 6072,
+      name: 'QuoteQualityWithoutOracle',
+      msg: 'Quote quality tracking requires a market with an oracle',
+    },
+    {
+      code: 6073,
+      name: 'InvalidInputEpochDuration',
+      msg: 'Epoch duration should be greater than zero',
+    },
+    {
+      code: 6074,
+      name: 'InvalidTakerRebateCampaign',
+      msg: 'Taker rebate campaign belongs to another market',
+    },
+    {
+      code: 6075,
+      name: 'InvalidInputTakerRebateCampaign',
+      msg: 'Campaign should end after it starts and rebate at most 10000 bps',
+    },
+    {
+      code: 6076,
+      name: 'TakerRebateCampaignActive',
+      msg: 'Taker rebate campaign is still running',
+    },
+    {
+      code: 6077,
+      name: 'MissingPositionExtension',
+      msg: "Open orders account has a position extension that wasn't passed",
+    },
+    {
+      code: 6078,
+      name: 'PositionExtensionNotClosed',
+      msg: 'Open orders account has a position extension that must be closed first',
+    },
+    {
+      code: 6079,
+      name: 'OrderRateLimitWithoutPositionExtension',
+      msg: 'Rate limited markets require a position extension on the open orders account',
+    },
+    {
+      code: 6080,
+      name: 'OrderRateLimitExceeded',
+      msg: "Open orders account placed too many orders in the market's rate limit window",
+    },
+    {
+      code: 6081,
+      name: 'InvalidInputOrderRateLimit',
+      msg: 'Order rate limit window should be greater than zero',
+    },
+    {
+      code: 6082,
+      name: 'OrderNotionalTooSmall',
+      msg: "Order is worth less than the market's minimum order notional",
+    },
+    {
+      code: 6083,
+      name: 'InvalidInputMaxRestingOrders',
+      msg: 'Max resting orders above the size of open orders accounts',
+    },
+    {
+      code: 6084,
+      name: 'MaxRestingOrdersReached',
+      msg: "Open orders account has the market's max number of resting orders",
+    },
+    {
+      code: 6085,
+      name: 'InvalidSuccessorMarket',
+      msg: 'Successor market should be another market of the same token pair',
+    },
+    {
+      code: 6086,
+      name: 'MigrateAccountWithBids',
+      msg: 'Version 0 open orders accounts need to cancel their bids before being migrated',
+    },
+    {
+      code: 6087,
+      name: 'PriceOutsideBand',
+      msg: 'Order price is outside the range of prices the book accepts',
+    },
+    {
+      code: 6088,
+      name: 'InsufficientFunds',
+      msg: "Token account doesn't hold enough tokens for the order's deposit",
+    },
+    {
+      code: 6089,
+      name: 'BookFull',
+      msg: "Book side is full and the order isn't better than its worst order",
+    },
+    {
+      code: 6090,
+      name: 'InvalidEventType',
+      msg: 'Event heap contains an event of unknown type',
+    },
+    {
+      code: 6091,
+      name: 'InvalidEventHeapSlot',
+      msg: 'Event heap slot is out of range or empty',
+    },
+    {
+      code: 6092,
+      name: 'InvalidInputOracleExponent',
+      msg: 'Oracle exponent should be between -12 and 12',
+    },
+    {
+      code: 6093,
+      name: 'MissingRiskProgramAccount',
+      msg: 'Risk program of the open orders account missing from the remaining accounts',
+    },
+    {
+      code: 6094,
+      name: 'InvalidRiskProgram',
+      msg: 'Invalid risk program',
+    },
+    {
+      code: 6095,
+      name: 'InvalidSettleReceiverProgram',
+      msg: 'Invalid settle receiver program',
+    },
+    {
+      code: 6096,
+      name: 'MissingSettleReceiverAccount',
+      msg: 'Settle receiver of the open orders account missing from the remaining accounts',
+    },
+    {
+      code: 6097,
+      name: 'InvalidSettleReceiverAuthority',
+      msg: 'Settle receiver authority missing or invalid',
+    },
+    {
+      code: 6098,
+      name: 'InvalidSettleReceiverAccount',
+      msg: "The market authority and vaults can't be passed to a settle receiver",
+    },
+    {
+      code: 6099,
+      name: 'TriggerConditionNotMet',
+      msg: 'Trigger order condition is not met',
+    },
+    {
+      code: 6100,
+      name: 'TriggerOrderExpired',
+      msg: 'Trigger order is already expired',
+    },
+    {
+      code: 6101,
+      name: 'InsufficientFreeFunds',
+      msg: "Free funds of the open orders account don't cover the order",
+    },
+    {
+      code: 6102,
+      name: 'ScheduledCancelNotDue',
+      msg: "No cancel is scheduled or its time hasn't come yet",
+    },
+    {
+      code: 6103,
+      name: 'HeartbeatNotEnabled',
+      msg: 'Heartbeat interval not set for the open orders account',
+    },
+    {
+      code: 6104,
+      name: 'InvalidSignedOrderSignature',
+      msg: "The previous instruction isn't an ed25519 signature of the signed order",
+    },
+    {
+      code: 6105,
+      name: 'InvalidSignedOrderSigner',
+      msg: 'Signed order signer is neither owner nor delegate',
+    },
+    {
+      code: 6106,
+      name: 'SignedOrderNonceUsed',
+      msg: 'Signed order nonce already used',
+    },
+    {
+      code: 6107,
+      name: 'SignedOrderExpired',
+      msg: 'Signed order is no longer valid',
+    },
+    {
+      code: 6108,
+      name: 'DmmRegistryFull',
+      msg: 'All designated market maker slots of the market are taken',
+    },
+    {
+      code: 6109,
+      name: 'DmmOrderProtected',
+      msg: 'The worst order of the full book side belongs to a designated market maker of higher priority',
+    },
+    {
+      code: 6110,
+      name: 'NotADmm',
+      msg: 'Open orders account is not a designated market maker of the market',
+    },
+    {
+      code: 6111,
+      name: 'DmmObligationMet',
+      msg: 'Designated market maker met its quoting obligation',
+    },
+    {
+      code: 6112,
+      name: 'InvalidInputEvictionBand',
+      msg: 'Eviction band should be greater than zero',
+    },
   ],
 };
@@ -29,6 +29,12 @@ export const SelfTradeBehaviorUtils = {
   AbortTransaction: { abortTransaction: {} },
 };
 
+/// The key of a `NonZeroPubkeyOption`, or null when it's unset so the optional
+/// account it stands for is left out of instructions
+export function nonZeroPubkey(option: { key: PublicKey }): PublicKey | null {
+  return option.key.equals(PublicKey.default) ? null : option.key;
+}
+
 ///
 /// numeric helpers
 ///